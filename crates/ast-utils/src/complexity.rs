@@ -0,0 +1,488 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::*;
+
+/// The complexity of a function-like body.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Complexity {
+    /// The number of linearly independent paths through the body.
+    pub cyclomatic: usize,
+    /// How hard the body is to understand, penalizing nested control flow.
+    pub cognitive: usize,
+    /// The constructs that increase the cyclomatic complexity.
+    pub cyclomatic_increments: Vec<ComplexityIncrement>,
+    /// The constructs that increase the cognitive complexity.
+    pub cognitive_increments: Vec<ComplexityIncrement>,
+}
+
+/// A construct that increases the complexity of a function-like body.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ComplexityIncrement {
+    pub span: Span,
+    /// The name of the construct, e.g. `foreach` or `&&`.
+    pub construct: &'static str,
+    /// The increment, including the nesting penalty, if any.
+    pub value: usize,
+    /// The nesting level of the construct, only relevant to cognitive complexity.
+    pub nesting: usize,
+}
+
+/// Computes the complexity of the given function, method, or closure body.
+///
+/// Nested closures, arrow functions, functions, and class-likes are not taken into account,
+/// as their complexity is measured separately.
+pub fn get_block_complexity(block: &Block) -> Complexity {
+    let mut walker = ComplexityWalker::default();
+    for statement in block.statements.iter() {
+        walker.walk_statement(statement, &mut ());
+    }
+
+    walker.finish()
+}
+
+/// Computes the complexity of the given arrow function body.
+///
+/// See [`get_block_complexity`] for details.
+pub fn get_expression_complexity(expression: &Expression) -> Complexity {
+    let mut walker = ComplexityWalker::default();
+    walker.walk_expression(expression, &mut ());
+
+    walker.finish()
+}
+
+#[derive(Debug, Default)]
+struct ComplexityWalker {
+    nesting: usize,
+    cyclomatic_increments: Vec<ComplexityIncrement>,
+    cognitive_increments: Vec<ComplexityIncrement>,
+    /// The spans of logical operations continuing a sequence of the same operator.
+    continued_sequences: Vec<Span>,
+}
+
+impl ComplexityWalker {
+    fn finish(self) -> Complexity {
+        Complexity {
+            cyclomatic: 1 + self.cyclomatic_increments.iter().map(|increment| increment.value).sum::<usize>(),
+            cognitive: self.cognitive_increments.iter().map(|increment| increment.value).sum(),
+            cyclomatic_increments: self.cyclomatic_increments,
+            cognitive_increments: self.cognitive_increments,
+        }
+    }
+
+    fn decision(&mut self, span: Span, construct: &'static str) {
+        self.cyclomatic_increments.push(ComplexityIncrement { span, construct, value: 1, nesting: 0 });
+    }
+
+    /// Records a construct that breaks the linear flow, without any nesting penalty.
+    fn flow_break(&mut self, span: Span, construct: &'static str) {
+        self.cognitive_increments.push(ComplexityIncrement { span, construct, value: 1, nesting: self.nesting });
+    }
+
+    /// Records a construct that breaks the linear flow and increases the nesting level.
+    fn nested_flow_break(&mut self, span: Span, construct: &'static str) {
+        self.cognitive_increments.push(ComplexityIncrement {
+            span,
+            construct,
+            value: 1 + self.nesting,
+            nesting: self.nesting,
+        });
+
+        self.nesting += 1;
+    }
+}
+
+impl MutWalker<()> for ComplexityWalker {
+    fn walk_in_if(&mut self, r#if: &If, _: &mut ()) {
+        self.decision(r#if.r#if.span, "if");
+        self.nested_flow_break(r#if.r#if.span, "if");
+    }
+
+    fn walk_out_if(&mut self, _: &If, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_if_statement_body_else_if_clause(&mut self, clause: &IfStatementBodyElseIfClause, _: &mut ()) {
+        self.decision(clause.elseif.span, "elseif");
+        self.flow_break(clause.elseif.span, "elseif");
+    }
+
+    fn walk_in_if_colon_delimited_body_else_if_clause(
+        &mut self,
+        clause: &IfColonDelimitedBodyElseIfClause,
+        _: &mut (),
+    ) {
+        self.decision(clause.elseif.span, "elseif");
+        self.flow_break(clause.elseif.span, "elseif");
+    }
+
+    fn walk_if_statement_body_else_clause(&mut self, clause: &IfStatementBodyElseClause, context: &mut ()) {
+        // `else if` is treated as `elseif`, without increasing the nesting level.
+        let Statement::If(r#if) = &clause.statement else {
+            self.flow_break(clause.r#else.span, "else");
+            self.walk_statement(&clause.statement, context);
+
+            return;
+        };
+
+        let span = clause.r#else.span.join(r#if.r#if.span);
+
+        self.decision(span, "else if");
+        self.flow_break(span, "else if");
+        self.walk_expression(&r#if.condition, context);
+        self.walk_if_body(&r#if.body, context);
+    }
+
+    fn walk_in_if_colon_delimited_body_else_clause(&mut self, clause: &IfColonDelimitedBodyElseClause, _: &mut ()) {
+        self.flow_break(clause.r#else.span, "else");
+    }
+
+    fn walk_in_for(&mut self, r#for: &For, _: &mut ()) {
+        self.decision(r#for.r#for.span, "for");
+        self.nested_flow_break(r#for.r#for.span, "for");
+    }
+
+    fn walk_out_for(&mut self, _: &For, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_foreach(&mut self, foreach: &Foreach, _: &mut ()) {
+        self.decision(foreach.foreach.span, "foreach");
+        self.nested_flow_break(foreach.foreach.span, "foreach");
+    }
+
+    fn walk_out_foreach(&mut self, _: &Foreach, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_while(&mut self, r#while: &While, _: &mut ()) {
+        self.decision(r#while.r#while.span, "while");
+        self.nested_flow_break(r#while.r#while.span, "while");
+    }
+
+    fn walk_out_while(&mut self, _: &While, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_do_while(&mut self, do_while: &DoWhile, _: &mut ()) {
+        self.decision(do_while.r#do.span, "do-while");
+        self.nested_flow_break(do_while.r#do.span, "do-while");
+    }
+
+    fn walk_out_do_while(&mut self, _: &DoWhile, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_switch(&mut self, switch: &Switch, _: &mut ()) {
+        self.nested_flow_break(switch.switch.span, "switch");
+    }
+
+    fn walk_out_switch(&mut self, _: &Switch, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_switch_expression_case(&mut self, case: &SwitchExpressionCase, _: &mut ()) {
+        self.decision(case.case.span, "case");
+    }
+
+    fn walk_in_match(&mut self, r#match: &Match, _: &mut ()) {
+        self.nested_flow_break(r#match.r#match.span, "match");
+    }
+
+    fn walk_out_match(&mut self, _: &Match, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_match_expression_arm(&mut self, arm: &MatchExpressionArm, _: &mut ()) {
+        self.decision(arm.span(), "match arm");
+    }
+
+    fn walk_in_try_catch_clause(&mut self, clause: &TryCatchClause, _: &mut ()) {
+        self.decision(clause.r#catch.span, "catch");
+        self.nested_flow_break(clause.r#catch.span, "catch");
+    }
+
+    fn walk_out_try_catch_clause(&mut self, _: &TryCatchClause, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_conditional(&mut self, conditional: &Conditional, _: &mut ()) {
+        self.decision(conditional.question_mark, "?:");
+        self.nested_flow_break(conditional.question_mark, "?:");
+    }
+
+    fn walk_out_conditional(&mut self, _: &Conditional, _: &mut ()) {
+        self.nesting -= 1;
+    }
+
+    fn walk_in_binary(&mut self, binary: &Binary, _: &mut ()) {
+        let construct = match binary.operator {
+            BinaryOperator::NullCoalesce(_) => {
+                self.decision(binary.operator.span(), "??");
+
+                return;
+            }
+            BinaryOperator::Elvis(_) => {
+                self.decision(binary.operator.span(), "?:");
+                self.flow_break(binary.operator.span(), "?:");
+
+                return;
+            }
+            BinaryOperator::And(_) => "&&",
+            BinaryOperator::Or(_) => "||",
+            BinaryOperator::LowAnd(_) => "and",
+            BinaryOperator::LowOr(_) => "or",
+            BinaryOperator::LowXor(_) => "xor",
+            _ => return,
+        };
+
+        self.decision(binary.operator.span(), construct);
+
+        // A sequence of the same logical operator, e.g. `$a && $b && $c`, only counts once.
+        let span = binary.span();
+        match self.continued_sequences.iter().position(|continued| *continued == span) {
+            Some(position) => {
+                self.continued_sequences.swap_remove(position);
+            }
+            None => {
+                self.flow_break(binary.operator.span(), construct);
+            }
+        }
+
+        for operand in [binary.lhs.as_ref(), binary.rhs.as_ref()] {
+            if let Expression::Binary(operand) = operand {
+                if operand.operator.is_same_as(&binary.operator) {
+                    self.continued_sequences.push(operand.span());
+                }
+            }
+        }
+    }
+
+    fn walk_in_goto(&mut self, goto: &Goto, _: &mut ()) {
+        self.flow_break(goto.goto.span, "goto");
+    }
+
+    fn walk_in_break(&mut self, r#break: &Break, _: &mut ()) {
+        if is_multi_level(&r#break.level) {
+            self.flow_break(r#break.r#break.span, "break");
+        }
+    }
+
+    fn walk_in_continue(&mut self, r#continue: &Continue, _: &mut ()) {
+        if is_multi_level(&r#continue.level) {
+            self.flow_break(r#continue.r#continue.span, "continue");
+        }
+    }
+
+    fn walk_function(&mut self, _: &Function, _: &mut ()) {}
+
+    fn walk_closure(&mut self, _: &Closure, _: &mut ()) {}
+
+    fn walk_arrow_function(&mut self, _: &ArrowFunction, _: &mut ()) {}
+
+    fn walk_anonymous_class(&mut self, _: &AnonymousClass, _: &mut ()) {}
+
+    fn walk_class(&mut self, _: &Class, _: &mut ()) {}
+
+    fn walk_interface(&mut self, _: &Interface, _: &mut ()) {}
+
+    fn walk_trait(&mut self, _: &Trait, _: &mut ()) {}
+
+    fn walk_enum(&mut self, _: &Enum, _: &mut ()) {}
+}
+
+/// Determines whether a `break` or `continue` level jumps out of more than one structure.
+fn is_multi_level(level: &Option<Expression>) -> bool {
+    match level {
+        Some(Expression::Literal(Literal::Integer(integer))) => integer.value.is_none_or(|value| value > 1),
+        Some(_) => true,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use mago_interner::ThreadedInterner;
+    use mago_lexer::input::Input;
+    use mago_source::SourceIdentifier;
+
+    /// Computes the complexity of the body of the first function in the given code.
+    fn get_complexity(code: &str) -> Complexity {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse(&interner, Input::new(SourceIdentifier::dummy(), code.as_bytes()));
+        assert!(error.is_none(), "unexpected parse error: {:?}", error);
+
+        let function = program
+            .statements
+            .iter()
+            .find_map(|statement| match statement {
+                Statement::Function(function) => Some(function),
+                _ => None,
+            })
+            .expect("the code should declare a function");
+
+        get_block_complexity(&function.body)
+    }
+
+    fn get_cognitive_increments(complexity: &Complexity) -> Vec<(&'static str, usize)> {
+        complexity.cognitive_increments.iter().map(|increment| (increment.construct, increment.value)).collect()
+    }
+
+    #[test]
+    fn test_linear_body() {
+        let complexity = get_complexity("<?php function f() { $a = 1; return $a + 2; }");
+
+        assert_eq!(complexity.cyclomatic, 1);
+        assert_eq!(complexity.cognitive, 0);
+    }
+
+    #[test]
+    fn test_nesting_penalty() {
+        let complexity = get_complexity(
+            "<?php function f($items) {
+                foreach ($items as $item) {
+                    if ($item) {
+                        while (true) {}
+                    } else {
+                        return;
+                    }
+                }
+            }",
+        );
+
+        assert_eq!(complexity.cyclomatic, 4);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("foreach", 1), ("if", 2), ("while", 3), ("else", 1)]);
+        assert_eq!(complexity.cognitive, 7);
+    }
+
+    #[test]
+    fn test_else_if_does_not_nest() {
+        let complexity = get_complexity(
+            "<?php function f($a) {
+                if ($a === 1) {
+                } elseif ($a === 2) {
+                } else if ($a === 3) {
+                } else {
+                }
+            }",
+        );
+
+        assert_eq!(complexity.cyclomatic, 4);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("if", 1), ("elseif", 1), ("else if", 1), ("else", 1)]);
+    }
+
+    #[test]
+    fn test_boolean_operator_sequences() {
+        // Each operator is a decision, but a sequence of the same operator only breaks the flow once.
+        let complexity = get_complexity("<?php function f($a, $b, $c, $d) { return $a && $b && $c || $d; }");
+
+        assert_eq!(complexity.cyclomatic, 4);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("||", 1), ("&&", 1)]);
+
+        let complexity = get_complexity("<?php function f($a, $b, $c) { return $a && ($b || $c) && $a; }");
+
+        assert_eq!(complexity.cyclomatic, 4);
+        assert_eq!(complexity.cognitive, 2);
+    }
+
+    #[test]
+    fn test_null_coalescing_is_not_a_flow_break() {
+        let complexity = get_complexity("<?php function f($a, $b) { return $a ?? $b; }");
+
+        assert_eq!(complexity.cyclomatic, 2);
+        assert_eq!(complexity.cognitive, 0);
+    }
+
+    #[test]
+    fn test_match_scoring() {
+        let complexity = get_complexity(
+            "<?php function f($a) {
+                return match ($a) {
+                    1, 2 => 'low',
+                    3 => 'high',
+                    default => 'unknown',
+                };
+            }",
+        );
+
+        // Each arm with conditions is a decision, while the match as a whole breaks the flow once.
+        assert_eq!(complexity.cyclomatic, 3);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("match", 1)]);
+    }
+
+    #[test]
+    fn test_switch_scoring() {
+        let complexity = get_complexity(
+            "<?php function f($a) {
+                switch ($a) {
+                    case 1:
+                    case 2:
+                        break;
+                    default:
+                        break;
+                }
+            }",
+        );
+
+        assert_eq!(complexity.cyclomatic, 3);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("switch", 1)]);
+    }
+
+    #[test]
+    fn test_catch_scoring() {
+        let complexity = get_complexity(
+            "<?php function f() {
+                try {
+                    if (true) {}
+                } catch (A $e) {
+                    if (true) {}
+                } catch (B $e) {
+                } finally {
+                }
+            }",
+        );
+
+        // `try` and `finally` do not add to the complexity, but each `catch` does, and nests its body.
+        assert_eq!(complexity.cyclomatic, 5);
+        assert_eq!(get_cognitive_increments(&complexity), vec![("if", 1), ("catch", 1), ("if", 2), ("catch", 1)]);
+    }
+
+    #[test]
+    fn test_multi_level_break() {
+        let complexity = get_complexity(
+            "<?php function f($items) {
+                foreach ($items as $item) {
+                    while (true) {
+                        break 2;
+                    }
+
+                    continue;
+                }
+            }",
+        );
+
+        assert_eq!(get_cognitive_increments(&complexity), vec![("foreach", 1), ("while", 2), ("break", 1)]);
+    }
+
+    #[test]
+    fn test_nested_function_likes_are_excluded() {
+        let complexity = get_complexity(
+            "<?php function f($items) {
+                $filter = function ($item) {
+                    if ($item) {
+                        return true;
+                    }
+
+                    return false;
+                };
+
+                return array_filter($items, fn($item) => $item ? 1 : 0);
+            }",
+        );
+
+        assert_eq!(complexity.cyclomatic, 1);
+        assert_eq!(complexity.cognitive, 0);
+    }
+}
@@ -3,6 +3,7 @@ use mago_ast::*;
 use crate::control_flow::ControlFlow;
 
 pub mod assignment;
+pub mod complexity;
 pub mod condition;
 pub mod control_flow;
 pub mod definition;
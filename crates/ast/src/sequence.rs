@@ -151,7 +151,11 @@ impl<T: HasSpan> TokenSeparatedSequence<T> {
     }
 
     pub fn has_trailing_token(&self) -> bool {
-        self.tokens.last().is_some_and(|token| token.span.start >= self.last_span().unwrap().end)
+        match (self.tokens.last(), self.inner.last()) {
+            (Some(token), Some(node)) => token.span.start >= node.span().end,
+            (Some(_), None) => true,
+            _ => false,
+        }
     }
 
     pub fn iter(&self) -> Iter<'_, T> {
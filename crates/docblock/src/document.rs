@@ -51,6 +51,12 @@ pub struct Tag {
     pub name: StringIdentifier,
     pub kind: TagKind,
     pub description: StringIdentifier,
+    /// The span of the description, starting at its first character.
+    ///
+    /// Continuation lines of a multi-line description are joined with `\n` after their
+    /// `*` prefix is removed, so offsets within the description only map directly onto
+    /// the source for its first line.
+    pub description_span: Span,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -109,6 +115,9 @@ pub enum TagKind {
     Extends,
     Implements,
     Use,
+    TemplateExtends,
+    TemplateImplements,
+    TemplateUse,
     NotDeprecated,
     PhpstanImpure,
     PhpstanPure,
@@ -128,6 +137,9 @@ pub enum TagKind {
     PsalmMethod,
     PsalmIgnoreVar,
     PsalmSuppress,
+    Assert,
+    AssertIfTrue,
+    AssertIfFalse,
     PsalmAssert,
     PsalmAssertIfTrue,
     PsalmAssertIfFalse,
@@ -176,6 +188,9 @@ pub enum TagKind {
     PhpstanParam,
     PhpstanReturn,
     PhpstanVar,
+    PhpstanExtends,
+    PhpstanImplements,
+    PhpstanUse,
     PhpstanReadOnly,
     PhpstanImmutable,
     Other,
@@ -253,6 +268,9 @@ impl TagKind {
             | Self::PhpstanParam
             | Self::PhpstanReturn
             | Self::PhpstanVar
+            | Self::PhpstanExtends
+            | Self::PhpstanImplements
+            | Self::PhpstanUse
             | Self::PhpstanReadOnly
             | Self::PhpstanImmutable => Some(TagVendor::Phpstan),
             _ => None,
@@ -283,12 +301,24 @@ impl TagKind {
             Self::PsalmReadOnly => Some(Self::ReadOnly),
             Self::PsalmImmutable => Some(Self::Immutable),
             Self::PsalmPure => Some(Self::Pure),
+            Self::PsalmAssert => Some(Self::Assert),
+            Self::PsalmAssertIfTrue => Some(Self::AssertIfTrue),
+            Self::PsalmAssertIfFalse => Some(Self::AssertIfFalse),
             Self::PhpstanTemplate => Some(Self::Template),
             Self::PhpstanTemplateCovariant => Some(Self::TemplateCovariant),
             Self::PhpstanTemplateContravariant => Some(Self::TemplateContravariant),
             Self::PhpstanParam => Some(Self::Param),
             Self::PhpstanReturn => Some(Self::Return),
+            Self::PhpstanAssert => Some(Self::Assert),
+            Self::PhpstanAssertIfTrue => Some(Self::AssertIfTrue),
+            Self::PhpstanAssertIfFalse => Some(Self::AssertIfFalse),
             Self::PhpstanVar => Some(Self::Var),
+            Self::PhpstanExtends => Some(Self::Extends),
+            Self::PhpstanImplements => Some(Self::Implements),
+            Self::PhpstanUse => Some(Self::Use),
+            Self::TemplateExtends => Some(Self::Extends),
+            Self::TemplateImplements => Some(Self::Implements),
+            Self::TemplateUse => Some(Self::Use),
             Self::PhpstanReadOnly => Some(Self::ReadOnly),
             Self::PhpstanImmutable => Some(Self::Immutable),
             _ => None,
@@ -298,7 +328,10 @@ impl TagKind {
     pub fn is_repeatable(&self) -> bool {
         matches!(
             self,
-            Self::Author
+            Self::Assert
+                | Self::AssertIfTrue
+                | Self::AssertIfFalse
+                | Self::Author
                 | Self::Deprecated
                 | Self::Example
                 | Self::Ignore
@@ -393,6 +426,9 @@ where
             "extends" => TagKind::Extends,
             "implements" => TagKind::Implements,
             "use" => TagKind::Use,
+            "template-extends" => TagKind::TemplateExtends,
+            "template-implements" => TagKind::TemplateImplements,
+            "template-use" => TagKind::TemplateUse,
             "not-deprecated" => TagKind::NotDeprecated,
             "notdeprecated" => TagKind::NotDeprecated,
             "phpstan-impure" => TagKind::PhpstanImpure,
@@ -419,6 +455,11 @@ where
             "psalm-ignore-var" => TagKind::PsalmIgnoreVar,
             "psalmignorevar" => TagKind::PsalmIgnoreVar,
             "psalm-suppress" => TagKind::PsalmSuppress,
+            "assert" => TagKind::Assert,
+            "assert-if-true" => TagKind::AssertIfTrue,
+            "assertiftrue" => TagKind::AssertIfTrue,
+            "assert-if-false" => TagKind::AssertIfFalse,
+            "assertiffalse" => TagKind::AssertIfFalse,
             "psalm-assert" => TagKind::PsalmAssert,
             "psalm-assert-if-true" => TagKind::PsalmAssertIfTrue,
             "psalm-assertiftrue" => TagKind::PsalmAssertIfTrue,
@@ -488,6 +529,9 @@ where
             "phpstan-require-extends" => TagKind::PhpstanRequireExtends,
             "phpstan-require-implements" => TagKind::PhpstanRequireImplements,
             "phpstan-template" => TagKind::PhpstanTemplate,
+            "phpstan-extends" => TagKind::PhpstanExtends,
+            "phpstan-implements" => TagKind::PhpstanImplements,
+            "phpstan-use" => TagKind::PhpstanUse,
             "phpstan-template-covariant" => TagKind::PhpstanTemplateCovariant,
             "phpstan-template-contravariant" => TagKind::PhpstanTemplateContravariant,
             "phpstan-param" => TagKind::PhpstanParam,
@@ -21,6 +21,9 @@ pub enum ParseError {
     MissingWhitespaceAfterOpeningAsterisk(Span),
     MissingWhitespaceBeforeClosingAsterisk(Span),
     ExpectedLine(Span),
+    ExpectedType(Span),
+    UnexpectedCharacterInType(Span, char),
+    UnclosedTypeDelimiter(Span, char),
 }
 
 impl HasSpan for ParseError {
@@ -41,6 +44,9 @@ impl HasSpan for ParseError {
             ParseError::MissingWhitespaceAfterOpeningAsterisk(span) => *span,
             ParseError::MissingWhitespaceBeforeClosingAsterisk(span) => *span,
             ParseError::ExpectedLine(span) => *span,
+            ParseError::ExpectedType(span) => *span,
+            ParseError::UnexpectedCharacterInType(span, _) => *span,
+            ParseError::UnclosedTypeDelimiter(span, _) => *span,
         }
     }
 }
@@ -95,6 +101,15 @@ impl std::fmt::Display for ParseError {
             ParseError::ExpectedLine(_) => {
                 write!(f, "Missing expected line.")
             }
+            ParseError::ExpectedType(_) => {
+                write!(f, "Expected a type.")
+            }
+            ParseError::UnexpectedCharacterInType(_, character) => {
+                write!(f, "Unexpected character `{}` in type.", character)
+            }
+            ParseError::UnclosedTypeDelimiter(_, delimiter) => {
+                write!(f, "Missing closing `{}` in type.", delimiter)
+            }
         }
     }
 }
@@ -117,6 +132,9 @@ impl ParseError {
             ParseError::MissingWhitespaceAfterOpeningAsterisk(_) => "Missing whitespace after the opening '/**' in a single-line docblock.",
             ParseError::MissingWhitespaceBeforeClosingAsterisk(_) => "Missing whitespace before the closing '*/' in a single-line docblock.",
             ParseError::ExpectedLine(_) => "A line or tag was expected in the docblock but none was found.",
+            ParseError::ExpectedType(_) => "A type was expected in the docblock tag but none was found.",
+            ParseError::UnexpectedCharacterInType(_, _) => "The type in the docblock tag contains an unexpected character.",
+            ParseError::UnclosedTypeDelimiter(_, _) => "The type in the docblock tag is missing a closing delimiter.",
         }
     }
 
@@ -137,6 +155,9 @@ impl ParseError {
             ParseError::MissingWhitespaceAfterOpeningAsterisk(_) => "Insert a space between '/**' and the text in the single-line docblock.",
             ParseError::MissingWhitespaceBeforeClosingAsterisk(_) => "Insert a space between the text and '*/' in the single-line docblock.",
             ParseError::ExpectedLine(_) => "Ensure that the docblock contains at least one line of text or a tag.",
+            ParseError::ExpectedType(_) => "Add a valid type to the docblock tag.",
+            ParseError::UnexpectedCharacterInType(_, _) => "Remove the unexpected character or correct the type syntax.",
+            ParseError::UnclosedTypeDelimiter(_, _) => "Add the missing closing delimiter to complete the type.",
         }
     }
 }
@@ -112,7 +112,11 @@ fn parse_tag(
 
     let tag_span = Span::new(span.start, end_span.end);
 
-    let tag = Tag { span: tag_span, name: tag_name, kind, description: description_id };
+    // the description is a suffix of the line, even if it is empty.
+    let description_offset = content.len() - description_part.len();
+    let description_span = Span::new(span.start.forward(description_offset), end_span.end);
+
+    let tag = Tag { span: tag_span, name: tag_name, kind, description: description_id, description_span };
 
     Ok((tag, i))
 }
@@ -421,7 +425,11 @@ fn parse_inline_tag(tag_content: &str, span: Span, interner: &ThreadedInterner)
     let name = interner.intern(tag_name_str);
     let description = interner.intern(description_part);
 
-    Ok(Tag { span, name, kind, description })
+    // the tag content starts after the opening `{@`.
+    let description_offset = 2 + tag_content.trim_end().len() - description_part.len();
+    let description_span = Span::new(span.start.forward(description_offset), span.end.backward(1));
+
+    Ok(Tag { span, name, kind, description, description_span })
 }
 
 fn parse_annotation(
@@ -1,6 +1,8 @@
+use mago_ast::Sequence;
 use mago_ast::Trivia;
 use mago_ast::TriviaKind;
 use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::document::Document;
@@ -10,6 +12,7 @@ mod internal;
 
 pub mod document;
 pub mod error;
+pub mod r#type;
 
 #[inline]
 pub fn parse_trivia(interner: &ThreadedInterner, trivia: &Trivia) -> Result<Document, ParseError> {
@@ -27,6 +30,48 @@ pub fn parse_phpdoc_with_span(interner: &ThreadedInterner, content: &str, span:
     internal::parser::parse_document(tokens.as_slice(), interner)
 }
 
+/// Finds the docblock comment attached to the given node.
+///
+/// A docblock is attached to a node if it is the last comment before the node,
+/// and only whitespace separates the two.
+///
+/// # Parameters
+///
+/// - `trivia`: The trivia of the program containing the node.
+/// - `node`: The node to find the docblock for.
+///
+/// # Returns
+///
+/// The docblock trivia, if any.
+pub fn get_docblock_for_node<'a>(trivia: &'a Sequence<Trivia>, node: &impl HasSpan) -> Option<&'a Trivia> {
+    let node_start = node.span().start.offset;
+
+    let mut cursor = node_start;
+    for trivia in trivia.iter().rev() {
+        if trivia.span.start.offset >= node_start {
+            continue;
+        }
+
+        if trivia.span.end.offset != cursor {
+            return None;
+        }
+
+        match trivia.kind {
+            TriviaKind::WhiteSpace => {
+                cursor = trivia.span.start.offset;
+            }
+            TriviaKind::DocBlockComment => {
+                return Some(trivia);
+            }
+            _ => {
+                return None;
+            }
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -627,4 +672,32 @@ mod tests {
         assert_eq!(name, "SimpleAnnotation");
         assert!(annotation.arguments.is_none());
     }
+
+    #[test]
+    fn test_tag_without_description() {
+        let interner = ThreadedInterner::new();
+        let phpdoc = r#"/**
+         * @internal
+         * @param string
+         */"#;
+        let span = Span::new(Position::dummy(0), Position::dummy(phpdoc.len()));
+        let document = parse_phpdoc_with_span(&interner, phpdoc, span).expect("Failed to parse PHPDoc");
+
+        assert_eq!(document.elements.len(), 2);
+
+        let Element::Tag(tag) = &document.elements[0] else {
+            panic!("Expected Element::Tag, got {:?}", document.elements[0]);
+        };
+
+        assert_eq!(interner.lookup(&tag.name), "internal");
+        assert_eq!(interner.lookup(&tag.description), "");
+        assert_eq!(tag.description_span.start, tag.span.end);
+
+        let Element::Tag(tag) = &document.elements[1] else {
+            panic!("Expected Element::Tag, got {:?}", document.elements[1]);
+        };
+
+        assert_eq!(interner.lookup(&tag.description), "string");
+        assert_eq!(&phpdoc[tag.description_span.start.offset..tag.description_span.end.offset], "string");
+    }
 }
@@ -0,0 +1,887 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::HasSpan;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::error::ParseError;
+
+/// Represents a type expression found within a docblock tag, such as `array<string, int>` or `?Foo`.
+///
+/// Unlike native type hints, docblock types are not part of the PHP grammar, and support
+/// a much richer syntax ( generics, shapes, callable signatures, literals, conditionals, etc. ).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Type {
+    /// A named type, optionally followed by generic parameters.
+    ///
+    /// For example, `int`, `Foo\Bar`, `array<string, int>`, or `class-string<T>`.
+    Reference(ReferenceType),
+
+    /// A nullable type, for example `?Foo`.
+    Nullable(NullableType),
+
+    /// A union of types, for example `int|string`.
+    Union(CompositeType),
+
+    /// An intersection of types, for example `Foo&Bar`.
+    Intersection(CompositeType),
+
+    /// An array of the inner type, for example `Foo[]`.
+    Array(ArrayType),
+
+    /// An array, list, or object shape, for example `array{foo: string, bar?: int}`.
+    Shape(ShapeType),
+
+    /// A callable signature, for example `callable(string, int): bool` or `Closure(): void`.
+    Callable(CallableType),
+
+    /// A literal value, for example `'foo'`, `42`, or `1.5`.
+    Literal(LiteralType),
+
+    /// A class constant reference, for example `Foo::BAR` or `Foo::BAR_*`.
+    ClassConstant(ClassConstantType),
+
+    /// A conditional type, for example `($foo is string ? int : float)`.
+    Conditional(ConditionalType),
+
+    /// A variable reference, for example `$this` or `$foo` ( used within conditional types ).
+    Variable(VariableType),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceType {
+    pub span: Span,
+    pub name: String,
+    pub parameters: Vec<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NullableType {
+    pub span: Span,
+    pub inner: Box<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompositeType {
+    pub span: Span,
+    pub types: Vec<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArrayType {
+    pub span: Span,
+    pub inner: Box<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeType {
+    pub span: Span,
+    /// The name preceding the shape, e.g. `array`, `list`, `non-empty-array`, or `object`.
+    pub name: String,
+    pub fields: Vec<ShapeField>,
+    /// The additional entries allowed by the shape, if it is unsealed ( `...` or `...<K, V>` ).
+    pub additional_fields: Option<Vec<Type>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeField {
+    pub key: Option<ShapeFieldKey>,
+    pub value: Type,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShapeFieldKey {
+    String(String),
+    Integer(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallableType {
+    pub span: Span,
+    /// The name preceding the signature, e.g. `callable`, `pure-callable`, or `Closure`.
+    pub name: String,
+    pub parameters: Vec<CallableParameter>,
+    pub return_type: Option<Box<Type>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallableParameter {
+    pub parameter_type: Option<Type>,
+    pub name: Option<String>,
+    pub is_by_reference: bool,
+    pub is_variadic: bool,
+    pub is_optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LiteralType {
+    String { span: Span, value: String },
+    Integer { span: Span, value: i64 },
+    Float { span: Span, value: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassConstantType {
+    pub span: Span,
+    pub class: String,
+    /// The constant name, which may contain a `*` wildcard.
+    pub constant: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionalType {
+    pub span: Span,
+    pub subject: Box<Type>,
+    pub negated: bool,
+    pub target: Box<Type>,
+    pub then: Box<Type>,
+    pub otherwise: Box<Type>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariableType {
+    pub span: Span,
+    pub name: String,
+}
+
+impl HasSpan for Type {
+    fn span(&self) -> Span {
+        match self {
+            Type::Reference(t) => t.span,
+            Type::Nullable(t) => t.span,
+            Type::Union(t) => t.span,
+            Type::Intersection(t) => t.span,
+            Type::Array(t) => t.span,
+            Type::Shape(t) => t.span,
+            Type::Callable(t) => t.span,
+            Type::Literal(LiteralType::String { span, .. }) => *span,
+            Type::Literal(LiteralType::Integer { span, .. }) => *span,
+            Type::Literal(LiteralType::Float { span, .. }) => *span,
+            Type::ClassConstant(t) => t.span,
+            Type::Conditional(t) => t.span,
+            Type::Variable(t) => t.span,
+        }
+    }
+}
+
+/// Parses a type expression at the beginning of the given input.
+///
+/// Parsing stops at the end of the type expression, which allows callers to
+/// parse the remainder of a tag description ( e.g. the variable name of a `@param` tag ).
+///
+/// # Parameters
+///
+/// - `input`: The text to parse, typically the description of a docblock tag.
+/// - `position`: The position of the first character of `input` in the source.
+///
+/// # Returns
+///
+/// The parsed type, and the number of bytes consumed from `input`.
+pub fn parse_type(input: &str, position: Position) -> Result<(Type, usize), ParseError> {
+    let mut parser = TypeParser { input, offset: 0, position };
+
+    parser.skip_whitespace();
+    let parsed = parser.parse()?;
+
+    Ok((parsed, parser.offset))
+}
+
+struct TypeParser<'a> {
+    input: &'a str,
+    offset: usize,
+    position: Position,
+}
+
+impl TypeParser<'_> {
+    fn parse(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        let subject = self.parse_union()?;
+
+        let checkpoint = self.offset;
+        self.skip_whitespace();
+        if !self.consume_keyword("is") {
+            self.offset = checkpoint;
+
+            return Ok(subject);
+        }
+
+        // A conditional type is only recognized if it is complete, otherwise the `is`
+        // belongs to the description that follows the type.
+        match self.parse_conditional_rest(start, subject.clone()) {
+            Ok(conditional) => Ok(conditional),
+            Err(_) => {
+                self.offset = checkpoint;
+
+                Ok(subject)
+            }
+        }
+    }
+
+    fn parse_conditional_rest(&mut self, start: usize, subject: Type) -> Result<Type, ParseError> {
+        self.skip_whitespace();
+        let negated = self.consume_keyword("not");
+        self.skip_whitespace();
+        let target = self.parse_union()?;
+        self.skip_whitespace();
+        self.expect('?')?;
+        self.skip_whitespace();
+        let then = self.parse()?;
+        self.skip_whitespace();
+        self.expect(':')?;
+        self.skip_whitespace();
+        let otherwise = self.parse()?;
+
+        Ok(Type::Conditional(ConditionalType {
+            span: self.span_from(start),
+            subject: Box::new(subject),
+            negated,
+            target: Box::new(target),
+            then: Box::new(then),
+            otherwise: Box::new(otherwise),
+        }))
+    }
+
+    fn parse_union(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        let first = self.parse_intersection()?;
+        let mut types = vec![first];
+
+        loop {
+            let checkpoint = self.offset;
+            self.skip_whitespace();
+            if self.peek() != Some('|') {
+                self.offset = checkpoint;
+
+                break;
+            }
+
+            self.offset += 1;
+            self.skip_whitespace();
+            types.push(self.parse_intersection()?);
+        }
+
+        if types.len() == 1 {
+            return Ok(types.remove(0));
+        }
+
+        Ok(Type::Union(CompositeType { span: self.span_from(start), types }))
+    }
+
+    fn parse_intersection(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        let first = self.parse_postfix()?;
+        let mut types = vec![first];
+
+        loop {
+            let checkpoint = self.offset;
+            self.skip_whitespace();
+            if self.peek() != Some('&') {
+                self.offset = checkpoint;
+
+                break;
+            }
+
+            self.offset += 1;
+            self.skip_whitespace();
+
+            // `Foo &$bar` and `Foo &...$bar` denote by-reference parameters, not intersections.
+            if matches!(self.peek(), Some('$') | Some('.')) {
+                self.offset = checkpoint;
+
+                break;
+            }
+
+            types.push(self.parse_postfix()?);
+        }
+
+        if types.len() == 1 {
+            return Ok(types.remove(0));
+        }
+
+        Ok(Type::Intersection(CompositeType { span: self.span_from(start), types }))
+    }
+
+    fn parse_postfix(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        let mut inner = self.parse_primary()?;
+
+        while self.rest().starts_with("[]") {
+            self.offset += 2;
+            inner = Type::Array(ArrayType { span: self.span_from(start), inner: Box::new(inner) });
+        }
+
+        Ok(inner)
+    }
+
+    fn parse_primary(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+
+        match self.peek() {
+            None => Err(ParseError::ExpectedType(self.span_from(start))),
+            Some('?') => {
+                self.offset += 1;
+                self.skip_whitespace();
+                let inner = self.parse_postfix()?;
+
+                Ok(Type::Nullable(NullableType { span: self.span_from(start), inner: Box::new(inner) }))
+            }
+            Some('(') => {
+                self.offset += 1;
+                self.skip_whitespace();
+                let inner = self.parse()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+
+                Ok(inner)
+            }
+            Some('$') => {
+                self.offset += 1;
+                let name = self.read_identifier();
+                if name.is_empty() {
+                    return Err(ParseError::ExpectedType(self.span_from(start)));
+                }
+
+                Ok(Type::Variable(VariableType { span: self.span_from(start), name: format!("${}", name) }))
+            }
+            Some(quote @ ('\'' | '"')) => {
+                let value = self.read_quoted(quote)?;
+
+                Ok(Type::Literal(LiteralType::String { span: self.span_from(start), value }))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' => self.parse_number(),
+            Some(c) if is_identifier_start(c) => self.parse_named(),
+            Some(c) => Err(ParseError::UnexpectedCharacterInType(self.span_for(start, c.len_utf8()), c)),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        if matches!(self.peek(), Some('-') | Some('+')) {
+            self.offset += 1;
+        }
+
+        let digits_start = self.offset;
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '_' {
+                self.offset += 1;
+            } else if c == '.' && !is_float && !self.rest().starts_with("...") {
+                is_float = true;
+                self.offset += 1;
+            } else {
+                break;
+            }
+        }
+
+        if self.offset == digits_start {
+            return Err(ParseError::ExpectedType(self.span_from(start)));
+        }
+
+        let text = self.input[start..self.offset].replace('_', "");
+        let span = self.span_from(start);
+
+        if is_float {
+            match text.parse::<f64>() {
+                Ok(value) => Ok(Type::Literal(LiteralType::Float { span, value })),
+                Err(_) => Err(ParseError::ExpectedType(span)),
+            }
+        } else {
+            match text.parse::<i64>() {
+                Ok(value) => Ok(Type::Literal(LiteralType::Integer { span, value })),
+                Err(_) => Err(ParseError::ExpectedType(span)),
+            }
+        }
+    }
+
+    fn parse_named(&mut self) -> Result<Type, ParseError> {
+        let start = self.offset;
+        let name = self.read_identifier();
+
+        if self.rest().starts_with("::") {
+            self.offset += 2;
+            let constant_start = self.offset;
+            while let Some(c) = self.peek() {
+                if c.is_alphanumeric() || c == '_' || c == '*' {
+                    self.offset += c.len_utf8();
+                } else {
+                    break;
+                }
+            }
+
+            if self.offset == constant_start {
+                return Err(ParseError::ExpectedType(self.span_from(start)));
+            }
+
+            return Ok(Type::ClassConstant(ClassConstantType {
+                span: self.span_from(start),
+                class: name,
+                constant: self.input[constant_start..self.offset].to_string(),
+            }));
+        }
+
+        match self.peek() {
+            Some('<') => {
+                self.offset += 1;
+                let parameters = self.parse_type_list('>')?;
+
+                Ok(Type::Reference(ReferenceType { span: self.span_from(start), name, parameters }))
+            }
+            Some('{') if is_shape_name(&name) => self.parse_shape(start, name),
+            Some('(') if is_callable_name(&name) => self.parse_callable(start, name),
+            _ => Ok(Type::Reference(ReferenceType { span: self.span_from(start), name, parameters: vec![] })),
+        }
+    }
+
+    fn parse_type_list(&mut self, closing: char) -> Result<Vec<Type>, ParseError> {
+        let open = self.offset - 1;
+        let mut types = vec![];
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(closing) {
+                self.offset += 1;
+
+                break;
+            }
+
+            if self.peek().is_none() {
+                return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), closing));
+            }
+
+            types.push(self.parse()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.offset += 1;
+                }
+                Some(c) if c == closing => {
+                    self.offset += 1;
+
+                    break;
+                }
+                Some(c) => {
+                    return Err(ParseError::UnexpectedCharacterInType(self.span_for(self.offset, c.len_utf8()), c));
+                }
+                None => {
+                    return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), closing));
+                }
+            }
+        }
+
+        Ok(types)
+    }
+
+    fn parse_shape(&mut self, start: usize, name: String) -> Result<Type, ParseError> {
+        let open = self.offset;
+        self.offset += 1;
+
+        let mut fields = vec![];
+        let mut additional_fields = None;
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.offset += 1;
+
+                    break;
+                }
+                None => {
+                    return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), '}'));
+                }
+                _ => {}
+            }
+
+            if self.rest().starts_with("...") {
+                self.offset += 3;
+                self.skip_whitespace();
+                if self.peek() == Some('<') {
+                    self.offset += 1;
+                    additional_fields = Some(self.parse_type_list('>')?);
+                } else {
+                    additional_fields = Some(vec![]);
+                }
+            } else {
+                fields.push(self.parse_shape_field()?);
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.offset += 1;
+                }
+                Some('}') => {
+                    self.offset += 1;
+
+                    break;
+                }
+                Some(c) => {
+                    return Err(ParseError::UnexpectedCharacterInType(self.span_for(self.offset, c.len_utf8()), c));
+                }
+                None => {
+                    return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), '}'));
+                }
+            }
+        }
+
+        Ok(Type::Shape(ShapeType { span: self.span_from(start), name, fields, additional_fields }))
+    }
+
+    fn parse_shape_field(&mut self) -> Result<ShapeField, ParseError> {
+        let checkpoint = self.offset;
+
+        let key = match self.peek() {
+            Some(quote @ ('\'' | '"')) => Some(ShapeFieldKey::String(self.read_quoted(quote)?)),
+            Some(c) if c.is_ascii_digit() || c == '-' => {
+                let number_start = self.offset;
+                if c == '-' {
+                    self.offset += 1;
+                }
+
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    self.offset += 1;
+                }
+
+                self.input[number_start..self.offset].parse::<i64>().ok().map(ShapeFieldKey::Integer)
+            }
+            Some(c) if is_identifier_start(c) => {
+                let key = self.read_identifier();
+
+                Some(ShapeFieldKey::String(key))
+            }
+            _ => None,
+        };
+
+        if key.is_some() {
+            self.skip_whitespace();
+            let optional = self.peek() == Some('?');
+            if optional {
+                self.offset += 1;
+                self.skip_whitespace();
+            }
+
+            if self.peek() == Some(':') && !self.rest().starts_with("::") {
+                self.offset += 1;
+                self.skip_whitespace();
+
+                return Ok(ShapeField { key, value: self.parse()?, optional });
+            }
+        }
+
+        self.offset = checkpoint;
+
+        Ok(ShapeField { key: None, value: self.parse()?, optional: false })
+    }
+
+    fn parse_callable(&mut self, start: usize, name: String) -> Result<Type, ParseError> {
+        let open = self.offset;
+        self.offset += 1;
+
+        let mut parameters = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(')') => {
+                    self.offset += 1;
+
+                    break;
+                }
+                None => {
+                    return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), ')'));
+                }
+                _ => {}
+            }
+
+            parameters.push(self.parse_callable_parameter()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.offset += 1;
+                }
+                Some(')') => {
+                    self.offset += 1;
+
+                    break;
+                }
+                Some(c) => {
+                    return Err(ParseError::UnexpectedCharacterInType(self.span_for(self.offset, c.len_utf8()), c));
+                }
+                None => {
+                    return Err(ParseError::UnclosedTypeDelimiter(self.span_for(open, 1), ')'));
+                }
+            }
+        }
+
+        let checkpoint = self.offset;
+        self.skip_whitespace();
+        let return_type = if self.peek() == Some(':') {
+            self.offset += 1;
+            self.skip_whitespace();
+
+            Some(Box::new(self.parse_postfix()?))
+        } else {
+            self.offset = checkpoint;
+
+            None
+        };
+
+        Ok(Type::Callable(CallableType { span: self.span_from(start), name, parameters, return_type }))
+    }
+
+    fn parse_callable_parameter(&mut self) -> Result<CallableParameter, ParseError> {
+        let parameter_type = match self.peek() {
+            Some('&') | Some('$') | Some('.') => None,
+            _ => Some(self.parse()?),
+        };
+
+        self.skip_whitespace();
+        let is_by_reference = self.peek() == Some('&');
+        if is_by_reference {
+            self.offset += 1;
+            self.skip_whitespace();
+        }
+
+        let is_variadic = self.rest().starts_with("...");
+        if is_variadic {
+            self.offset += 3;
+            self.skip_whitespace();
+        }
+
+        let name = if self.peek() == Some('$') {
+            self.offset += 1;
+
+            Some(format!("${}", self.read_identifier()))
+        } else {
+            None
+        };
+
+        self.skip_whitespace();
+        let is_optional = self.peek() == Some('=');
+        if is_optional {
+            self.offset += 1;
+        }
+
+        Ok(CallableParameter { parameter_type, name, is_by_reference, is_variadic, is_optional })
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.offset;
+        while let Some(c) = self.peek() {
+            if is_identifier_part(c) {
+                self.offset += c.len_utf8();
+            } else if c == '-' && self.input[self.offset + 1..].starts_with(|c: char| c.is_alphabetic()) {
+                // Allows pseudo types such as `non-empty-string`, but not `int->`.
+                self.offset += 1;
+            } else {
+                break;
+            }
+        }
+
+        self.input[start..self.offset].to_string()
+    }
+
+    fn read_quoted(&mut self, quote: char) -> Result<String, ParseError> {
+        let start = self.offset;
+        self.offset += 1;
+
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            self.offset += c.len_utf8();
+
+            if c == '\\' {
+                if let Some(escaped) = self.peek() {
+                    self.offset += escaped.len_utf8();
+                    value.push(escaped);
+                }
+            } else if c == quote {
+                return Ok(value);
+            } else {
+                value.push(c);
+            }
+        }
+
+        Err(ParseError::UnclosedTypeDelimiter(self.span_for(start, 1), quote))
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let rest = self.rest();
+        if rest.len() > keyword.len()
+            && rest[..keyword.len()].eq_ignore_ascii_case(keyword)
+            && rest[keyword.len()..].starts_with(char::is_whitespace)
+        {
+            self.offset += keyword.len();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.offset += 1;
+
+                Ok(())
+            }
+            Some(c) => Err(ParseError::UnexpectedCharacterInType(self.span_for(self.offset, c.len_utf8()), c)),
+            None => Err(ParseError::ExpectedType(self.span_for(self.offset, 0))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.offset += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    #[inline]
+    fn rest(&self) -> &str {
+        &self.input[self.offset..]
+    }
+
+    #[inline]
+    fn span_from(&self, start: usize) -> Span {
+        Span::new(self.position.forward(start), self.position.forward(self.offset))
+    }
+
+    #[inline]
+    fn span_for(&self, start: usize, length: usize) -> Span {
+        Span::new(self.position.forward(start), self.position.forward(start + length))
+    }
+}
+
+#[inline]
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '\\'
+}
+
+#[inline]
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '\\'
+}
+
+#[inline]
+fn is_shape_name(name: &str) -> bool {
+    matches!(name.to_ascii_lowercase().as_str(), "array" | "list" | "non-empty-array" | "non-empty-list" | "object")
+}
+
+#[inline]
+fn is_callable_name(name: &str) -> bool {
+    matches!(
+        name.to_ascii_lowercase().trim_start_matches('\\'),
+        "callable" | "pure-callable" | "closure" | "pure-closure"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> (Type, usize) {
+        parse_type(input, Position::dummy(0)).expect("failed to parse type")
+    }
+
+    #[test]
+    fn test_parse_generic_reference() {
+        let (parsed, consumed) = parse("array<string, list<int>> $foo");
+
+        assert_eq!(consumed, 24);
+        let Type::Reference(reference) = parsed else {
+            panic!("expected a reference type, got {:?}", parsed);
+        };
+
+        assert_eq!(reference.name, "array");
+        assert_eq!(reference.parameters.len(), 2);
+        assert!(matches!(&reference.parameters[1], Type::Reference(r) if r.name == "list" && r.parameters.len() == 1));
+    }
+
+    #[test]
+    fn test_parse_union_stops_before_by_reference_variable() {
+        let (parsed, consumed) = parse("?Foo|non-empty-string &$bar");
+
+        assert_eq!(consumed, 21);
+        let Type::Union(union) = parsed else {
+            panic!("expected a union type, got {:?}", parsed);
+        };
+
+        assert!(matches!(&union.types[0], Type::Nullable(_)));
+        assert!(matches!(&union.types[1], Type::Reference(r) if r.name == "non-empty-string"));
+    }
+
+    #[test]
+    fn test_parse_shape() {
+        let (parsed, _) = parse("array{foo: string, 'bar'?: int[], 0: Foo::BAR_*, ...<string, mixed>}");
+
+        let Type::Shape(shape) = parsed else {
+            panic!("expected a shape type, got {:?}", parsed);
+        };
+
+        assert_eq!(shape.fields.len(), 3);
+        assert_eq!(shape.fields[0].key, Some(ShapeFieldKey::String("foo".to_string())));
+        assert!(shape.fields[1].optional);
+        assert!(matches!(&shape.fields[1].value, Type::Array(_)));
+        assert_eq!(shape.fields[2].key, Some(ShapeFieldKey::Integer(0)));
+        assert!(matches!(&shape.fields[2].value, Type::ClassConstant(c) if c.constant == "BAR_*"));
+        assert_eq!(shape.additional_fields.as_ref().map(|f| f.len()), Some(2));
+    }
+
+    #[test]
+    fn test_parse_callable() {
+        let (parsed, _) = parse("Closure(int, string=, Foo &...$rest): ?bool");
+
+        let Type::Callable(callable) = parsed else {
+            panic!("expected a callable type, got {:?}", parsed);
+        };
+
+        assert_eq!(callable.parameters.len(), 3);
+        assert!(callable.parameters[1].is_optional);
+        assert!(callable.parameters[2].is_by_reference);
+        assert!(callable.parameters[2].is_variadic);
+        assert_eq!(callable.parameters[2].name.as_deref(), Some("$rest"));
+        assert!(matches!(callable.return_type.as_deref(), Some(Type::Nullable(_))));
+    }
+
+    #[test]
+    fn test_parse_conditional() {
+        let (parsed, _) = parse("($foo is not string ? int : -1.5)");
+
+        let Type::Conditional(conditional) = parsed else {
+            panic!("expected a conditional type, got {:?}", parsed);
+        };
+
+        assert!(conditional.negated);
+        assert!(matches!(conditional.subject.as_ref(), Type::Variable(v) if v.name == "$foo"));
+        assert!(
+            matches!(conditional.otherwise.as_ref(), Type::Literal(LiteralType::Float { value, .. }) if *value == -1.5)
+        );
+    }
+
+    #[test]
+    fn test_parse_type_followed_by_description() {
+        let (parsed, consumed) = parse("int is the number of items");
+
+        assert_eq!(consumed, 3);
+        assert!(matches!(parsed, Type::Reference(r) if r.name == "int"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse_type("array<int", Position::dummy(0)), Err(ParseError::UnclosedTypeDelimiter(_, '>'))));
+        assert!(matches!(parse_type("", Position::dummy(0)), Err(ParseError::ExpectedType(_))));
+        assert!(matches!(parse_type("@foo", Position::dummy(0)), Err(ParseError::UnexpectedCharacterInType(_, '@'))));
+    }
+}
@@ -634,12 +634,23 @@ fn fix_overlapping_operations(operations: &mut Vec<FixOperation>) {
             FixOperation::Replace { range, .. } => {
                 let mut should_add = true;
                 for existing_op in &filtered_operations {
-                    if let FixOperation::Delete { range: delete_range, .. } = existing_op {
-                        if delete_range.start <= range.start && delete_range.end >= range.end {
+                    match existing_op {
+                        FixOperation::Delete { range: delete_range, .. }
+                            if delete_range.start <= range.start && delete_range.end >= range.end =>
+                        {
                             // `Replace` falls within a `Delete`, so ignore `Replace`
                             should_add = false;
                             break;
                         }
+                        FixOperation::Replace { range: replace_range, .. }
+                            if range.start < replace_range.end && replace_range.start < range.end =>
+                        {
+                            // `Replace` overlaps a previous `Replace`, applying both would corrupt the
+                            // content, so ignore `Replace`
+                            should_add = false;
+                            break;
+                        }
+                        _ => {}
                     }
                 }
                 if should_add {
@@ -824,6 +835,17 @@ mod tests {
         // "brown fox" deleted, "cat" inserted
     }
 
+    #[test]
+    fn test_overlapping_replaces() {
+        let content = "use B;\nuse A;\nuse C;";
+        let mut fix = FixPlan::new();
+        fix.replace(0..20, "use A;\nuse B;\nuse C;", SafetyClassification::Safe);
+        fix.replace(0..20, "use B;\nuse A;", SafetyClassification::Safe);
+        fix.replace(14..20, "use D;", SafetyClassification::Safe);
+        let result = fix.execute(content);
+        assert_eq!(result.get_fixed(), "use A;\nuse B;\nuse C;");
+    }
+
     #[test]
     fn test_insert_at_zero() {
         // Insert at the beginning of the content
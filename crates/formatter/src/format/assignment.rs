@@ -269,10 +269,8 @@ fn should_break_after_operator<'a>(f: &Formatter<'a>, rhs_expression: &'a Expres
             return conditional.condition.is_binary()
                 && !should_inline_logical_or_coalesce_expression(&conditional.condition);
         }
-        Expression::AnonymousClass(anonymous_class) => {
-            if !anonymous_class.attributes.is_empty() {
-                return true;
-            }
+        Expression::AnonymousClass(anonymous_class) if !anonymous_class.attributes.is_empty() => {
+            return true;
         }
         _ => {}
     }
@@ -384,17 +384,13 @@ fn is_simple_call_argument<'a>(node: &'a Expression, depth: usize) -> bool {
         Expression::ArrayAccess(array_access) => {
             is_simple_call_argument(&array_access.array, depth) && is_simple_call_argument(&array_access.index, depth)
         }
-        Expression::Instantiation(instantiation) => {
-            if is_simple_call_argument(&instantiation.class, depth) {
-                match &instantiation.arguments {
-                    Some(argument_list) => {
-                        argument_list.arguments.len() <= depth
-                            && argument_list.arguments.iter().map(|a| a.value()).all(is_child_simple)
-                    }
-                    None => true,
+        Expression::Instantiation(instantiation) if is_simple_call_argument(&instantiation.class, depth) => {
+            match &instantiation.arguments {
+                Some(argument_list) => {
+                    argument_list.arguments.len() <= depth
+                        && argument_list.arguments.iter().map(|a| a.value()).all(is_child_simple)
                 }
-            } else {
-                false
+                None => true,
             }
         }
         _ => false,
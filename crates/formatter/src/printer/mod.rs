@@ -361,8 +361,8 @@ impl<'a> Printer<'a> {
                 }
                 Document::Group(group) => {
                     let mode = if group.should_break { Mode::Break } else { mode };
-                    if group.expanded_states.is_some() && mode.is_break() {
-                        queue.push_front((mode, group.expanded_states.as_ref().unwrap().last().unwrap()));
+                    if let (Some(expanded_states), true) = (&group.expanded_states, mode.is_break()) {
+                        queue.push_front((mode, expanded_states.last().unwrap()));
                     } else {
                         for d in group.contents.iter().rev() {
                             queue.push_front((mode, d));
@@ -0,0 +1,85 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+use mago_source::error::SourceError;
+
+use crate::test_format;
+
+#[test]
+pub fn test_assignment_of_anonymous_class() -> Result<(), SourceError> {
+    let code = indoc! {r#"
+        <?php
+
+        $handler = new #[Deprecated] class($dependency) implements Handler {
+            public function handle(): void {}
+        };
+
+        $plain = new class { };
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $handler =
+            new
+            #[Deprecated] class($dependency) implements Handler {
+                public function handle(): void
+                {
+                }
+            };
+
+        $plain = new class {
+        };
+    "#};
+
+    test_format(code, expected, FormatSettings::default())
+}
+
+#[test]
+pub fn test_instantiation_after_first_closure_argument() -> Result<(), SourceError> {
+    let code = indoc! {r#"
+        <?php
+
+        $mapped = map(function ($item) { return $item->transform($configuration, $environment); }, new ArrayIterator($items));
+
+        $filtered = filter(function ($item) { return $item->isValid($configuration); }, new ArrayIterator($items, $flags, $extra));
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $mapped = map(function ($item) {
+            return $item->transform($configuration, $environment);
+        }, new ArrayIterator($items));
+
+        $filtered = filter(
+            function ($item) {
+                return $item->isValid($configuration);
+            },
+            new ArrayIterator($items, $flags, $extra),
+        );
+    "#};
+
+    test_format(code, expected, FormatSettings::default())
+}
+
+#[test]
+pub fn test_instantiation_arguments() -> Result<(), SourceError> {
+    let code = indoc! {r#"
+        <?php
+
+        $result = process(new Configuration($environment, $debug), new Logger($channel), new Repository($connection, $cache, $logger));
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $result = process(
+            new Configuration($environment, $debug),
+            new Logger($channel),
+            new Repository($connection, $cache, $logger),
+        );
+    "#};
+
+    test_format(code, expected, FormatSettings::default())
+}
@@ -1 +1,2 @@
+pub mod expression;
 pub mod string;
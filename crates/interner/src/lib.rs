@@ -4,11 +4,16 @@ use std::sync::Arc;
 use lasso::Key;
 use lasso::Rodeo;
 use lasso::ThreadedRodeo;
-use serde::Deserialize;
-use serde::Serialize;
+
+pub use crate::portable::StringTable;
+
+mod portable;
 
 /// An string identifier that is used to represent an interned string.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+///
+/// Identifiers are serialized as their numeric value, which is only meaningful within the interner
+/// that created them; see [`StringTable`] for persisting them across interners.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct StringIdentifier(pub(crate) usize);
 
 impl StringIdentifier {
@@ -0,0 +1,146 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::StringIdentifier;
+use crate::ThreadedInterner;
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// The state of an ongoing export or import on the current thread.
+enum State {
+    /// Collects the identifiers that are serialized.
+    Exporting(HashSet<StringIdentifier>),
+    /// Maps the identifiers that are deserialized to identifiers of the importing interner.
+    Importing(HashMap<usize, StringIdentifier>),
+}
+
+/// A table of the strings referenced by a serialized value.
+///
+/// String identifiers are only meaningful within the interner that created them, so a value
+/// containing identifiers can not be deserialized by another process as is. Storing the table
+/// alongside the value allows remapping its identifiers to the ones of the importing interner.
+///
+/// See [`ThreadedInterner::export`] and [`ThreadedInterner::import`].
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StringTable {
+    strings: Vec<(usize, String)>,
+}
+
+impl StringTable {
+    /// Returns the number of strings in the table.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+impl ThreadedInterner {
+    /// Runs the given serialization, returning its result along with the table of the strings
+    /// whose identifiers were serialized by it.
+    ///
+    /// The serialization must happen on the current thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `serialize` - The function serializing a value containing string identifiers of this interner.
+    pub fn export<T>(&self, serialize: impl FnOnce() -> T) -> (T, StringTable) {
+        let (result, state) = with_state(State::Exporting(HashSet::new()), serialize);
+        let State::Exporting(identifiers) = state else {
+            unreachable!("the state of an export can not change");
+        };
+
+        let mut strings = identifiers
+            .into_iter()
+            .map(|identifier| (identifier.value(), self.lookup(&identifier).to_string()))
+            .collect::<Vec<_>>();
+
+        strings.sort_unstable();
+
+        (result, StringTable { strings })
+    }
+
+    /// Runs the given deserialization, remapping the string identifiers it deserializes from the
+    /// ones in the given table to the ones of this interner.
+    ///
+    /// The deserialization must happen on the current thread, and fails if it encounters an
+    /// identifier that is not in the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table returned when the value was exported.
+    /// * `deserialize` - The function deserializing the value.
+    pub fn import<T>(&self, table: &StringTable, deserialize: impl FnOnce() -> T) -> T {
+        let identifiers = table
+            .strings
+            .iter()
+            .map(|(identifier, string)| (*identifier, self.intern(string)))
+            .collect::<HashMap<_, _>>();
+
+        let (result, _) = with_state(State::Importing(identifiers), deserialize);
+
+        result
+    }
+}
+
+impl Serialize for StringIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !self.is_empty() {
+            STATE.with_borrow_mut(|state| {
+                if let Some(State::Exporting(identifiers)) = state {
+                    identifiers.insert(*self);
+                }
+            });
+        }
+
+        serializer.serialize_newtype_struct("StringIdentifier", &self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for StringIdentifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "StringIdentifier")]
+        struct RawStringIdentifier(usize);
+
+        let RawStringIdentifier(value) = RawStringIdentifier::deserialize(deserializer)?;
+        if value == 0 {
+            return Ok(StringIdentifier::empty());
+        }
+
+        STATE.with_borrow(|state| match state {
+            Some(State::Importing(identifiers)) => identifiers.get(&value).copied().ok_or_else(|| {
+                D::Error::custom(format!("string identifier `{}` is missing from the string table", value))
+            }),
+            _ => Ok(StringIdentifier(value)),
+        })
+    }
+}
+
+/// Runs the given function with the given state set for the current thread, returning its
+/// result along with the final state.
+fn with_state<T>(state: State, function: impl FnOnce() -> T) -> (T, State) {
+    let previous = STATE.replace(Some(state));
+    let result = function();
+    let state = STATE.replace(previous).expect("the state must be set while the function runs");
+
+    (result, state)
+}
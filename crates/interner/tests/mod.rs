@@ -82,3 +82,41 @@ fn test_interner_multithreaded() {
         assert_eq!(first_set, set);
     }
 }
+
+#[test]
+fn test_export_and_import() {
+    let interner = ThreadedInterner::new();
+    let hello = interner.intern("hello");
+    let world = interner.intern("world");
+    interner.intern("unused");
+
+    let identifiers = vec![world, StringIdentifier::empty(), hello, world];
+    let (serialized, table) = interner.export(|| serde_json::to_string(&identifiers).unwrap());
+
+    assert_eq!(table.len(), 2);
+
+    let other_interner = ThreadedInterner::new();
+    other_interner.intern("unrelated");
+
+    let imported: Vec<StringIdentifier> = other_interner.import(&table, || serde_json::from_str(&serialized).unwrap());
+
+    assert_eq!(imported.len(), 4);
+    assert_eq!(other_interner.lookup(&imported[0]), "world");
+    assert_eq!(imported[1], StringIdentifier::empty());
+    assert_eq!(other_interner.lookup(&imported[2]), "hello");
+    assert_eq!(imported[0], imported[3]);
+}
+
+#[test]
+fn test_import_fails_on_unknown_identifier() {
+    let interner = ThreadedInterner::new();
+    let hello = interner.intern("hello");
+
+    let serialized = serde_json::to_string(&vec![hello]).unwrap();
+
+    let other_interner = ThreadedInterner::new();
+    let imported: Result<Vec<StringIdentifier>, _> =
+        other_interner.import(&Default::default(), || serde_json::from_str(&serialized));
+
+    assert!(imported.is_err());
+}
@@ -15,7 +15,7 @@ pub const CAST_TYPES: [(&[u8], TokenKind); 12] = [
     (b"(unset)", TokenKind::UnsetCast),
 ];
 
-pub const KEYWORD_TYPES: [(&[u8], TokenKind); 84] = [
+pub const KEYWORD_TYPES: [(&[u8], TokenKind); 85] = [
     (b"eval", TokenKind::Eval),
     (b"die", TokenKind::Die),
     (b"empty", TokenKind::Empty),
@@ -39,6 +39,7 @@ pub const KEYWORD_TYPES: [(&[u8], TokenKind); 84] = [
     (b"abstract", TokenKind::Abstract),
     (b"array", TokenKind::Array),
     (b"as", TokenKind::As),
+    (b"callable", TokenKind::Callable),
     (b"break", TokenKind::Break),
     (b"case", TokenKind::Case),
     (b"catch", TokenKind::Catch),
@@ -185,9 +185,9 @@ impl<'a, 'i> Lexer<'a, 'i> {
                 }
             }
             LexerMode::Script => {
+                let start = self.input.position();
                 let whitespaces = self.input.consume_whitespaces();
                 if !whitespaces.is_empty() {
-                    let start = self.input.position();
                     let buffer = whitespaces;
                     let end = self.input.position();
 
@@ -892,10 +892,7 @@ impl<'a, 'i> Lexer<'a, 'i> {
 
                             self.token(TokenKind::CloseTag, buffer, start, end)
                         } else {
-                            return Some(Err(SyntaxError::UnexpectedToken(
-                                self.input.read(1)[0],
-                                self.input.position(),
-                            )));
+                            Some(Err(SyntaxError::UnexpectedToken(self.input.read(1)[0], self.input.position())))
                         }
                     }
                     _ => unreachable!(),
@@ -729,6 +729,56 @@ fn test_escape_in_string() -> Result<(), SyntaxError> {
     })
 }
 
+#[test]
+fn test_whitespace_span() -> Result<(), SyntaxError> {
+    let interner = ThreadedInterner::new();
+    let input = Input::new(SourceIdentifier::dummy(), b"<?php  \n\t$a;");
+    let mut lexer = Lexer::new(&interner, input);
+
+    let open_tag = lexer.advance().expect("expected an open tag")?;
+    assert_eq!(open_tag.kind, TokenKind::OpenTag);
+
+    let whitespace = lexer.advance().expect("expected whitespace")?;
+    assert_eq!(whitespace.kind, TokenKind::Whitespace);
+    assert_eq!(interner.lookup(&whitespace.value), "  \n\t");
+    assert_eq!(whitespace.span.start.offset, 5);
+    assert_eq!(whitespace.span.end.offset, 9);
+
+    let variable = lexer.advance().expect("expected a variable")?;
+    assert_eq!(variable.kind, TokenKind::Variable);
+    assert_eq!(variable.span.start.offset, 9);
+
+    Ok(())
+}
+
+#[test]
+fn test_callable_type() -> Result<(), SyntaxError> {
+    let code = b"<?php function f(callable $c): CALLABLE {}";
+
+    let expected = vec![
+        TokenKind::OpenTag,
+        TokenKind::Whitespace,
+        TokenKind::Function,
+        TokenKind::Whitespace,
+        TokenKind::Identifier,
+        TokenKind::LeftParenthesis,
+        TokenKind::Callable,
+        TokenKind::Whitespace,
+        TokenKind::Variable,
+        TokenKind::RightParenthesis,
+        TokenKind::Colon,
+        TokenKind::Whitespace,
+        TokenKind::Callable,
+        TokenKind::Whitespace,
+        TokenKind::LeftBrace,
+        TokenKind::RightBrace,
+    ];
+
+    test_lexer(code, expected).map_err(|err| {
+        panic!("unexpected error: {}", err);
+    })
+}
+
 fn test_lexer(code: &[u8], expected_kinds: Vec<TokenKind>) -> Result<(), SyntaxError> {
     let interner = ThreadedInterner::new();
     let input = Input::new(SourceIdentifier::dummy(), code);
@@ -756,7 +806,12 @@ fn test_lexer(code: &[u8], expected_kinds: Vec<TokenKind>) -> Result<(), SyntaxE
 
     let mut found = String::new();
     for token in tokens.iter() {
-        found.push_str(interner.lookup(&token.value));
+        let value = interner.lookup(&token.value);
+
+        assert_eq!(found.len(), token.span.start.offset, "unexpected start offset for token {:?}", token.kind);
+        assert_eq!(value.len(), token.span.end.offset - token.span.start.offset);
+
+        found.push_str(value);
     }
 
     assert_eq!(code, found.as_bytes());
@@ -764,7 +819,7 @@ fn test_lexer(code: &[u8], expected_kinds: Vec<TokenKind>) -> Result<(), SyntaxE
     Ok(())
 }
 
-pub const KEYWORD_TYPES: [(&[u8], TokenKind); 84] = [
+pub const KEYWORD_TYPES: [(&[u8], TokenKind); 85] = [
     (b"eval", TokenKind::Eval),
     (b"die", TokenKind::Die),
     (b"empty", TokenKind::Empty),
@@ -788,6 +843,7 @@ pub const KEYWORD_TYPES: [(&[u8], TokenKind); 84] = [
     (b"abstract", TokenKind::Abstract),
     (b"array", TokenKind::Array),
     (b"as", TokenKind::As),
+    (b"callable", TokenKind::Callable),
     (b"break", TokenKind::Break),
     (b"case", TokenKind::Case),
     (b"catch", TokenKind::Catch),
@@ -1,5 +1,16 @@
+use mago_ast::Expression;
 use mago_ast::Identifier;
+use mago_reference::ReferenceIndex;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::constant::ConstantReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::CodebaseReflection;
 use mago_semantics::Semantics;
+use mago_trinary::Trinary;
+use mago_typing::variable::VariableTypes;
 use toml::value::Value;
 
 use mago_ast::Hint;
@@ -20,16 +31,35 @@ use crate::rule::ConfiguredRule;
 pub struct Context<'a> {
     pub interner: &'a ThreadedInterner,
     pub semantics: &'a Semantics,
+    pub codebase: &'a CodebaseReflection,
+    pub references: Option<&'a ReferenceIndex>,
+    pub variables: VariableTypes,
     pub issues: IssueCollection,
 }
 
 impl<'a> Context<'a> {
-    pub fn new(interner: &'a ThreadedInterner, semantics: &'a Semantics) -> Self {
-        Self { interner, semantics, issues: IssueCollection::default() }
+    pub fn new(
+        interner: &'a ThreadedInterner,
+        semantics: &'a Semantics,
+        codebase: &'a CodebaseReflection,
+        references: Option<&'a ReferenceIndex>,
+    ) -> Self {
+        let variables = mago_typing::infer_variable_types(interner, semantics, codebase);
+
+        Self { interner, semantics, codebase, references, variables, issues: IssueCollection::default() }
     }
 
-    pub fn for_rule<'b>(&'b mut self, rule: &'b ConfiguredRule) -> LintContext<'b> {
-        LintContext { rule, interner: self.interner, semantics: self.semantics, issues: &mut self.issues }
+    pub fn for_rule<'b>(&'b mut self, rule: &'b ConfiguredRule, rules: &'b [ConfiguredRule]) -> LintContext<'b> {
+        LintContext {
+            rule,
+            rules,
+            interner: self.interner,
+            semantics: self.semantics,
+            codebase: self.codebase,
+            references: self.references,
+            variables: &self.variables,
+            issues: &mut self.issues,
+        }
     }
 
     pub fn take_issue_collection(self) -> IssueCollection {
@@ -40,8 +70,13 @@ impl<'a> Context<'a> {
 #[derive(Debug)]
 pub struct LintContext<'a> {
     pub rule: &'a ConfiguredRule,
+    /// All the rules enabled for this run, including the current one.
+    pub rules: &'a [ConfiguredRule],
     pub interner: &'a ThreadedInterner,
     pub semantics: &'a Semantics,
+    pub codebase: &'a CodebaseReflection,
+    pub references: Option<&'a ReferenceIndex>,
+    pub variables: &'a VariableTypes,
     pub issues: &'a mut IssueCollection,
 }
 
@@ -51,6 +86,11 @@ impl LintContext<'_> {
         self.rule.level
     }
 
+    /// Determines whether the rule with the given name is enabled for this run.
+    pub fn is_rule_enabled(&self, rule_name: &str) -> bool {
+        self.rules.iter().any(|configured_rule| configured_rule.rule.get_name() == rule_name)
+    }
+
     /// Retrieves the value of a rule-specific option.
     pub fn option(&self, option_name: &'static str) -> Option<&Value> {
         self.rule.settings.get_option(option_name)
@@ -162,3 +202,77 @@ impl LintContext<'_> {
         (class_like_kind, class_like_name, class_like_fqcn, class_like_span)
     }
 }
+
+impl<'a> LintContext<'a> {
+    /// Resolves the type of the given expression, using the codebase to look up the types of symbols,
+    /// and the inferred variable types of the file to look up the types of variables.
+    pub fn resolve_kind(&self, expression: &Expression) -> TypeKind {
+        mago_typing::resolve_kind_with_variables(
+            self.interner,
+            self.semantics,
+            self.codebase,
+            self.variables,
+            expression,
+        )
+    }
+
+    /// Determines whether the `input` type is contained by ( i.e. is a subtype of ) the `container` type.
+    pub fn is_contained_by(&self, input: &TypeKind, container: &TypeKind) -> Trinary {
+        mago_typing::is_contained_by(self.interner, self.codebase, input, container)
+    }
+
+    /// Retrieves the reflection of the function referred to by the given identifier, if it exists.
+    ///
+    /// Unqualified function names that are not imported fall back to the global namespace, as they do in PHP.
+    pub fn get_function_reflection(&self, identifier: &Identifier) -> Option<&'a FunctionLikeReflection> {
+        let names = &self.semantics.names;
+
+        self.codebase
+            .get_function(names.get(identifier))
+            .or_else(|| self.codebase.get_function(&names.get_global_fallback(identifier)?))
+    }
+
+    /// Retrieves the reflection of the constant referred to by the given identifier, if it exists.
+    ///
+    /// Unqualified constant names that are not imported fall back to the global namespace, as they do in PHP.
+    pub fn get_constant_reflection(&self, identifier: &Identifier) -> Option<&'a ConstantReflection> {
+        let names = &self.semantics.names;
+
+        self.codebase
+            .get_constant(names.get(identifier))
+            .or_else(|| self.codebase.get_constant(&names.get_global_fallback(identifier)?))
+    }
+
+    /// Retrieves the reflection of the class-like referred to by the class expression of a static
+    /// access, e.g. `Foo`, `self`, `static`, or `parent` in `Foo::bar()`.
+    pub fn get_class_like_reflection(&self, class: &Expression) -> Option<&'a ClassLikeReflection> {
+        match class {
+            Expression::Identifier(identifier) => {
+                self.codebase.get_named_class_like(self.semantics.names.get(identifier))
+            }
+            Expression::Self_(keyword) | Expression::Static(keyword) => self.codebase.get_enclosing_class_like(keyword),
+            Expression::Parent(keyword) => {
+                let class_like = self.codebase.get_enclosing_class_like(keyword)?;
+                let parent = class_like.inheritance.direct_extended_class?;
+
+                self.codebase.get_named_class_like(&parent.value)
+            }
+            _ => match self.resolve_kind(class) {
+                TypeKind::Object(object) => self.get_object_reflection(&object),
+                _ => None,
+            },
+        }
+    }
+
+    /// Retrieves the reflection of the class-like of the given object type, if it is known.
+    pub fn get_object_reflection(&self, object: &ObjectTypeKind) -> Option<&'a ClassLikeReflection> {
+        match object {
+            ObjectTypeKind::NamedObject { name, .. } => self.codebase.get_named_class_like(name),
+            ObjectTypeKind::AnonymousObject { span } => {
+                self.codebase.get_class_like(ClassLikeName::AnonymousClass(*span))
+            }
+            ObjectTypeKind::EnumCase { enum_name, .. } => self.codebase.get_enum(enum_name),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,356 @@
+/// The keywords that must be followed by an expression.
+const EXPRESSION_KEYWORDS: &[&str] =
+    &["SELECT", "WHERE", "AND", "OR", "NOT", "ON", "WITH", "HAVING", "SET", "BY", "FROM", "JOIN", "UPDATE", "AS"];
+
+/// The keywords that start a clause, or combine conditions, and therefore cannot follow a keyword
+/// expecting an expression.
+const CLAUSE_KEYWORDS: &[&str] =
+    &["SELECT", "FROM", "WHERE", "AND", "OR", "JOIN", "INNER", "LEFT", "ON", "WITH", "GROUP", "ORDER", "HAVING", "SET"];
+
+/// An error found in a DQL query.
+#[derive(Debug, Clone)]
+pub struct DqlError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl DqlError {
+    fn new(message: impl Into<String>, start: usize, end: usize) -> Self {
+        Self { message: message.into(), start, end: end.max(start + 1) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    String,
+    Number,
+    Parameter,
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+    Operator,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+/// Validates a DQL query for obvious syntax errors, such as unbalanced parentheses, unterminated strings,
+/// missing clauses, or keywords missing their expression.
+///
+/// This is not a complete DQL parser: queries that pass this validation may still be rejected by Doctrine.
+pub fn validate_dql(query: &[u8]) -> Option<DqlError> {
+    let tokens = match tokenize(query) {
+        Ok(tokens) => tokens,
+        Err(error) => return Some(error),
+    };
+
+    let Some(first) = tokens.first() else {
+        return Some(DqlError::new("The query is empty.", 0, query.len()));
+    };
+
+    let word = |token: &Token| {
+        (token.kind == TokenKind::Word).then(|| String::from_utf8_lossy(&query[token.start..token.end]).to_uppercase())
+    };
+
+    let statement = word(first).unwrap_or_default();
+    if !matches!(statement.as_str(), "SELECT" | "UPDATE" | "DELETE") {
+        return Some(DqlError::new(
+            "A DQL query must start with `SELECT`, `UPDATE`, or `DELETE`.",
+            first.start,
+            first.end,
+        ));
+    }
+
+    let mut depth = 0usize;
+    let mut opening_parentheses = vec![];
+    let mut top_level_keywords = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        let next = tokens.get(index + 1);
+
+        match token.kind {
+            TokenKind::LeftParenthesis => {
+                depth += 1;
+                opening_parentheses.push(token);
+            }
+            TokenKind::RightParenthesis => {
+                if depth == 0 {
+                    return Some(DqlError::new("Unmatched closing parenthesis.", token.start, token.end));
+                }
+
+                depth -= 1;
+                opening_parentheses.pop();
+            }
+            TokenKind::Comma => {
+                let is_misplaced = match next {
+                    None => true,
+                    Some(next) => {
+                        matches!(next.kind, TokenKind::Comma | TokenKind::RightParenthesis)
+                            || word(next).is_some_and(|next| CLAUSE_KEYWORDS.contains(&next.as_str()))
+                    }
+                };
+
+                if is_misplaced {
+                    return Some(DqlError::new("Unexpected `,`.", token.start, token.end));
+                }
+            }
+            TokenKind::Word => {
+                let keyword = word(token).unwrap_or_default();
+                if depth == 0 {
+                    top_level_keywords.push(keyword.clone());
+                }
+
+                if !EXPRESSION_KEYWORDS.contains(&keyword.as_str()) {
+                    continue;
+                }
+
+                let is_missing_expression = match next {
+                    None => true,
+                    Some(next) => {
+                        matches!(next.kind, TokenKind::Comma | TokenKind::RightParenthesis)
+                            || word(next).is_some_and(|next| CLAUSE_KEYWORDS.contains(&next.as_str()))
+                    }
+                };
+
+                if is_missing_expression {
+                    return Some(DqlError::new(
+                        format!("Expected an expression after `{}`.", keyword),
+                        token.start,
+                        token.end,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(parenthesis) = opening_parentheses.last() {
+        return Some(DqlError::new("Unclosed parenthesis.", parenthesis.start, parenthesis.end));
+    }
+
+    let has_keyword = |keyword: &str| top_level_keywords.iter().any(|word| word == keyword);
+    match statement.as_str() {
+        "SELECT" if !has_keyword("FROM") => {
+            Some(DqlError::new("A `SELECT` query must have a `FROM` clause.", first.start, first.end))
+        }
+        "UPDATE" if !has_keyword("SET") => {
+            Some(DqlError::new("An `UPDATE` query must have a `SET` clause.", first.start, first.end))
+        }
+        _ => None,
+    }
+}
+
+fn tokenize(query: &[u8]) -> Result<Vec<Token>, DqlError> {
+    let mut tokens = vec![];
+    let mut index = 0;
+
+    while index < query.len() {
+        let start = index;
+        let byte = query[index];
+
+        let kind = match byte {
+            b if b.is_ascii_whitespace() => {
+                index += 1;
+
+                continue;
+            }
+            b'\'' => {
+                index += 1;
+                loop {
+                    match query.get(index) {
+                        None => return Err(DqlError::new("Unterminated string literal.", start, query.len())),
+                        // Quotes are escaped by doubling them.
+                        Some(b'\'') if query.get(index + 1) == Some(&b'\'') => index += 2,
+                        Some(b'\'') => {
+                            index += 1;
+
+                            break;
+                        }
+                        Some(_) => index += 1,
+                    }
+                }
+
+                TokenKind::String
+            }
+            b'(' => {
+                index += 1;
+
+                TokenKind::LeftParenthesis
+            }
+            b')' => {
+                index += 1;
+
+                TokenKind::RightParenthesis
+            }
+            b',' => {
+                index += 1;
+
+                TokenKind::Comma
+            }
+            b':' => {
+                index += 1;
+                while index < query.len() && is_word_byte(query[index]) {
+                    index += 1;
+                }
+
+                if index == start + 1 {
+                    return Err(DqlError::new("Expected a parameter name after `:`.", start, index));
+                }
+
+                TokenKind::Parameter
+            }
+            b'?' => {
+                index += 1;
+                while index < query.len() && query[index].is_ascii_digit() {
+                    index += 1;
+                }
+
+                TokenKind::Parameter
+            }
+            b'0'..=b'9' => {
+                while index < query.len() && (query[index].is_ascii_alphanumeric() || query[index] == b'.') {
+                    index += 1;
+                }
+
+                TokenKind::Number
+            }
+            b if is_word_byte(b) || b == b'\\' => {
+                while index < query.len() && (is_word_byte(query[index]) || matches!(query[index], b'\\' | b'.')) {
+                    index += 1;
+                }
+
+                TokenKind::Word
+            }
+            _ => {
+                index += 1;
+
+                TokenKind::Operator
+            }
+        };
+
+        tokens.push(Token { kind, start, end: index });
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_error(query: &str) -> Option<(String, &str)> {
+        validate_dql(query.as_bytes()).map(|error| (error.message, &query[error.start..error.end.min(query.len())]))
+    }
+
+    #[test]
+    fn test_valid_queries() {
+        let queries = [
+            "SELECT u FROM App\\Entity\\User u",
+            "select u from App\\Entity\\User u where u.id = :id",
+            "SELECT u, p FROM App:User u LEFT JOIN u.posts p WITH p.published = true WHERE u.id IN (?1, ?2)",
+            "SELECT COUNT(u.id) AS total FROM User u GROUP BY u.role HAVING COUNT(u.id) > 1 ORDER BY total DESC",
+            "SELECT u FROM User u WHERE u.name = 'O''Brien' AND (u.age >= 18 OR u.verified = 1)",
+            "SELECT u FROM User u WHERE u.id NOT IN (SELECT b.user FROM Ban b)",
+            "UPDATE User u SET u.active = false, u.updatedAt = CURRENT_TIMESTAMP() WHERE u.id = :id",
+            "DELETE FROM User u WHERE u.createdAt < :date",
+            "  SELECT\n    u\nFROM\n    User u\n",
+        ];
+
+        for query in queries {
+            assert_eq!(get_error(query), None, "`{}` should be valid", query);
+        }
+    }
+
+    #[test]
+    fn test_statement() {
+        assert_eq!(get_error(""), Some(("The query is empty.".to_string(), "")));
+        assert_eq!(get_error("   ").map(|(message, _)| message).as_deref(), Some("The query is empty."));
+        assert_eq!(
+            get_error("INSERT INTO User u"),
+            Some(("A DQL query must start with `SELECT`, `UPDATE`, or `DELETE`.".to_string(), "INSERT"))
+        );
+        assert_eq!(
+            get_error("(SELECT u FROM User u)"),
+            Some(("A DQL query must start with `SELECT`, `UPDATE`, or `DELETE`.".to_string(), "("))
+        );
+    }
+
+    #[test]
+    fn test_missing_clauses() {
+        assert_eq!(
+            get_error("SELECT u WHERE u.id = 1"),
+            Some(("A `SELECT` query must have a `FROM` clause.".to_string(), "SELECT"))
+        );
+        assert_eq!(
+            get_error("UPDATE User u WHERE u.id = 1"),
+            Some(("An `UPDATE` query must have a `SET` clause.".to_string(), "UPDATE"))
+        );
+
+        // Clauses of subqueries do not count.
+        assert_eq!(
+            get_error("SELECT (SELECT COUNT(p) FROM Post p) WHERE 1 = 1").map(|(message, _)| message).as_deref(),
+            Some("A `SELECT` query must have a `FROM` clause.")
+        );
+    }
+
+    #[test]
+    fn test_missing_expressions() {
+        assert_eq!(
+            get_error("SELECT u FROM User u WHERE"),
+            Some(("Expected an expression after `WHERE`.".to_string(), "WHERE"))
+        );
+        assert_eq!(
+            get_error("SELECT u FROM User u WHERE u.id = 1 AND ORDER BY u.id"),
+            Some(("Expected an expression after `AND`.".to_string(), "AND"))
+        );
+        assert_eq!(
+            get_error("SELECT FROM User u"),
+            Some(("Expected an expression after `SELECT`.".to_string(), "SELECT"))
+        );
+        assert_eq!(
+            get_error("SELECT u FROM User u ORDER BY"),
+            Some(("Expected an expression after `BY`.".to_string(), "BY"))
+        );
+    }
+
+    #[test]
+    fn test_commas() {
+        assert_eq!(get_error("SELECT u, FROM User u"), Some(("Unexpected `,`.".to_string(), ",")));
+        assert_eq!(get_error("SELECT u,, p FROM User u"), Some(("Unexpected `,`.".to_string(), ",")));
+        assert_eq!(get_error("SELECT u FROM User u WHERE u.id IN (1, 2,)"), Some(("Unexpected `,`.".to_string(), ",")));
+        assert_eq!(get_error("SELECT u FROM User u ORDER BY u.id,"), Some(("Unexpected `,`.".to_string(), ",")));
+    }
+
+    #[test]
+    fn test_parentheses() {
+        assert_eq!(get_error("SELECT u FROM User u WHERE (u.id = 1"), Some(("Unclosed parenthesis.".to_string(), "(")));
+        assert_eq!(
+            get_error("SELECT u FROM User u WHERE u.id = 1)"),
+            Some(("Unmatched closing parenthesis.".to_string(), ")"))
+        );
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            get_error("SELECT u FROM User u WHERE u.name = 'foo"),
+            Some(("Unterminated string literal.".to_string(), "'foo"))
+        );
+        assert_eq!(
+            get_error("SELECT u FROM User u WHERE u.id = : id"),
+            Some(("Expected a parameter name after `:`.".to_string(), ":"))
+        );
+
+        // Keywords and parentheses within strings are ignored.
+        assert_eq!(get_error("SELECT u FROM User u WHERE u.name = 'WHERE ('"), None);
+    }
+}
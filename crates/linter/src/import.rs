@@ -0,0 +1,544 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::Range;
+
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::context::LintContext;
+
+/// The kind of symbol imported by a `use` statement, in the order import groups are expected to appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ImportKind {
+    ClassLike,
+    Function,
+    Constant,
+}
+
+/// A single item of a `use` statement.
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub kind: ImportKind,
+    /// The fully qualified name of the imported symbol, without a leading backslash.
+    pub name: String,
+    /// The name the symbol is imported as, either explicitly or implicitly.
+    pub alias: String,
+    /// The span of the item, including its alias.
+    pub span: Span,
+    /// The item as written within its statement, including the `function` or `const` keyword of mixed lists.
+    pub text: String,
+    /// The index of the statement declaring the item within its block.
+    pub statement: usize,
+}
+
+impl Import {
+    /// Determines whether both items import the same symbol under the same alias.
+    ///
+    /// Names and aliases of class-likes and functions are case-insensitive, while those of constants are not.
+    pub fn is_same_as(&self, other: &Import) -> bool {
+        if self.kind != other.kind {
+            return false;
+        }
+
+        match self.kind {
+            ImportKind::Constant => self.name == other.name && self.alias == other.alias,
+            _ => self.name.eq_ignore_ascii_case(&other.name) && self.alias.eq_ignore_ascii_case(&other.alias),
+        }
+    }
+
+    /// Compares the items by kind, then by name, segment by segment and ignoring case.
+    pub fn compare(&self, other: &Import) -> Ordering {
+        self.kind.cmp(&other.kind).then_with(|| {
+            let lhs = self.name.split('\\').map(|segment| segment.to_ascii_lowercase());
+            let rhs = other.name.split('\\').map(|segment| segment.to_ascii_lowercase());
+
+            lhs.cmp(rhs)
+        })
+    }
+}
+
+/// A run of consecutive `use` statements.
+#[derive(Debug)]
+pub struct ImportBlock<'a> {
+    pub statements: Vec<&'a Use>,
+    pub imports: Vec<Import>,
+    pub span: Span,
+    /// The span of the namespace the imports apply to, or of the whole program.
+    pub scope: Span,
+}
+
+impl ImportBlock<'_> {
+    /// Computes the rewrite of the block fixing the issues of all the enabled import rules at once: unused
+    /// and duplicate imports are removed, and the remaining ones are sorted.
+    ///
+    /// Every import rule reports this same rewrite, as separate rewrites of the same block would overlap,
+    /// and only one of them could be applied per run. Returns `None` if the block cannot be rendered.
+    pub fn rewrite(&self, context: &LintContext<'_>) -> Option<(Range<usize>, String)> {
+        let usage = context.is_rule_enabled("unused-import").then(|| ImportUsage::collect(context, self.scope));
+        let duplicates =
+            if context.is_rule_enabled("duplicate-import") { self.get_duplicate_imports() } else { vec![] };
+
+        let rendered = self.render(
+            context,
+            |import| {
+                usage.as_ref().is_none_or(|usage| usage.is_used(import))
+                    && !duplicates.iter().any(|(_, duplicate)| duplicate.span == import.span)
+            },
+            context.is_rule_enabled("ordered-imports"),
+        )?;
+
+        Some((self.get_replacement_range(context, &rendered), rendered))
+    }
+
+    /// Renders the block, keeping only the items accepted by `keep`, and ordering the statements and
+    /// their items when `sort` is set.
+    ///
+    /// Statements whose items are left untouched keep their original text, and unless sorting, the original
+    /// whitespace between them. Returns `None` if the block contains comments, or a statement that is not
+    /// terminated by a semicolon, as those cannot be preserved.
+    pub fn render(&self, context: &LintContext<'_>, keep: impl Fn(&Import) -> bool, sort: bool) -> Option<String> {
+        let content = context.lookup(&context.semantics.source.content);
+
+        let has_comments = context.semantics.program.trivia.iter().any(|trivia| {
+            !matches!(trivia.kind, TriviaKind::WhiteSpace)
+                && trivia.span.start.offset >= self.span.start.offset
+                && trivia.span.end.offset <= self.span.end.offset
+        });
+
+        if has_comments || self.statements.iter().any(|r#use| !matches!(r#use.terminator, Terminator::Semicolon(_))) {
+            return None;
+        }
+
+        let indentation = get_indentation(content, self.span);
+
+        let mut rendered = vec![];
+        for (index, r#use) in self.statements.iter().enumerate() {
+            let mut items = self.imports.iter().filter(|import| import.statement == index).collect::<Vec<_>>();
+            let count = items.len();
+
+            items.retain(|import| keep(import));
+            if items.is_empty() {
+                continue;
+            }
+
+            let mut reordered = false;
+            if sort {
+                let original = items.clone();
+                items.sort_by(|a, b| a.compare(b));
+                reordered = items.iter().zip(original).any(|(a, b)| a.span != b.span);
+            }
+
+            let text = if items.len() == count && !reordered {
+                content[r#use.span().start.offset..r#use.span().end.offset].to_string()
+            } else {
+                render_statement(context, r#use, &items, &indentation)
+            };
+
+            rendered.push((index, get_statement_kind(r#use), items[0], text));
+        }
+
+        let mut result = String::new();
+        if sort {
+            rendered.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.2.compare(b.2)));
+
+            let mut previous_kind = None;
+            for (_, kind, _, text) in rendered {
+                match previous_kind {
+                    Some(previous_kind) if previous_kind == kind => {
+                        result.push('\n');
+                        result.push_str(&indentation);
+                    }
+                    Some(_) => {
+                        result.push_str("\n\n");
+                        result.push_str(&indentation);
+                    }
+                    None => {}
+                }
+
+                result.push_str(&text);
+                previous_kind = Some(kind);
+            }
+        } else {
+            for (position, (index, _, _, text)) in rendered.into_iter().enumerate() {
+                if position > 0 {
+                    let previous = self.statements[index - 1].span();
+                    let current = self.statements[index].span();
+
+                    result.push_str(&content[previous.end.offset..current.start.offset]);
+                }
+
+                result.push_str(&text);
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns the items importing the same symbol under the same alias as a previous item, along with
+    /// that previous item.
+    pub fn get_duplicate_imports(&self) -> Vec<(&Import, &Import)> {
+        let mut duplicates = vec![];
+        for (index, import) in self.imports.iter().enumerate() {
+            if let Some(original) = self.imports[..index].iter().find(|other| other.is_same_as(import)) {
+                duplicates.push((original, import));
+            }
+        }
+
+        duplicates
+    }
+
+    /// Returns the items that are out of order, either within their statement, or because their statement
+    /// should come before the previous one.
+    pub fn get_misplaced_imports(&self) -> Vec<&Import> {
+        let mut misplaced = vec![];
+        let mut previous: Option<(ImportKind, &Import)> = None;
+
+        for (index, r#use) in self.statements.iter().enumerate() {
+            let items = self.imports.iter().filter(|import| import.statement == index).collect::<Vec<_>>();
+            for pair in items.windows(2) {
+                if pair[0].compare(pair[1]).is_gt() {
+                    misplaced.push(pair[1]);
+                }
+            }
+
+            let Some(first) = items.iter().copied().min_by(|a, b| a.compare(b)) else {
+                continue;
+            };
+
+            let kind = get_statement_kind(r#use);
+            if let Some((previous_kind, previous_first)) = previous {
+                if previous_kind.cmp(&kind).then_with(|| previous_first.compare(first)).is_gt() {
+                    misplaced.push(items[0]);
+                }
+            }
+
+            previous = Some((kind, first));
+        }
+
+        misplaced
+    }
+
+    /// Returns the range to replace with the rendering of the block.
+    ///
+    /// When nothing is left of the block, the whitespace following it is removed as well, up to the
+    /// indentation of the next statement.
+    pub fn get_replacement_range(&self, context: &LintContext<'_>, rendered: &str) -> Range<usize> {
+        let range = self.span.to_range();
+        if !rendered.is_empty() {
+            return range;
+        }
+
+        let content = context.lookup(&context.semantics.source.content);
+        let trailing = &content[range.end..];
+        let whitespace = &trailing[..trailing.len() - trailing.trim_start().len()];
+
+        match whitespace.rfind('\n') {
+            Some(offset) => range.start..range.end + offset + 1,
+            None => range,
+        }
+    }
+}
+
+/// The names referenced within a namespace, used to determine which of its imports are used.
+#[derive(Debug, Default)]
+pub struct ImportUsage {
+    /// The lowercase unqualified names, and first segments of qualified names, that may refer to a class-like.
+    class_likes: HashSet<String>,
+    /// The unqualified names used in code, as written.
+    names: HashSet<String>,
+}
+
+impl ImportUsage {
+    /// Collects the names used within the given scope, in code as well as in docblocks.
+    pub fn collect(context: &LintContext<'_>, scope: Span) -> Self {
+        let content = context.lookup(&context.semantics.source.content);
+        let in_scope = |offset: usize| offset >= scope.start.offset && offset < scope.end.offset;
+
+        let mut usage = Self::default();
+        for (offset, _) in context.semantics.names.all() {
+            if !in_scope(*offset) {
+                continue;
+            }
+
+            let name = read_name(&content[*offset..]);
+            if name.starts_with('\\') {
+                continue;
+            }
+
+            match name.split_once('\\') {
+                Some((first, _)) => {
+                    usage.class_likes.insert(first.to_ascii_lowercase());
+                }
+                None => {
+                    usage.class_likes.insert(name.to_ascii_lowercase());
+                    usage.names.insert(name.to_string());
+                }
+            }
+        }
+
+        for trivia in context.semantics.program.trivia.iter() {
+            if !matches!(trivia.kind, TriviaKind::DocBlockComment) || !in_scope(trivia.span.start.offset) {
+                continue;
+            }
+
+            for word in context.lookup(&trivia.value).split(|c: char| !is_name_character(c)) {
+                usage.add_class_like_reference(word);
+            }
+        }
+
+        let mut walker = TraitAdaptationNames::default();
+        walker.walk_program(&context.semantics.program, &mut ());
+        for span in walker.spans {
+            if in_scope(span.start.offset) {
+                usage.add_class_like_reference(&content[span.start.offset..span.end.offset]);
+            }
+        }
+
+        usage
+    }
+
+    pub fn is_used(&self, import: &Import) -> bool {
+        match import.kind {
+            ImportKind::ClassLike => self.class_likes.contains(&import.alias.to_ascii_lowercase()),
+            ImportKind::Function => self.names.iter().any(|name| name.eq_ignore_ascii_case(&import.alias)),
+            ImportKind::Constant => self.names.contains(&import.alias),
+        }
+    }
+
+    fn add_class_like_reference(&mut self, name: &str) {
+        if name.is_empty() || name.starts_with('\\') {
+            return;
+        }
+
+        let first = name.split('\\').next().unwrap_or(name);
+
+        self.class_likes.insert(first.to_ascii_lowercase());
+    }
+}
+
+/// Collects the blocks of consecutive `use` statements of the program, and of each of its namespaces.
+pub fn get_import_blocks<'a>(program: &'a Program, context: &LintContext<'_>) -> Vec<ImportBlock<'a>> {
+    let mut blocks = vec![];
+
+    collect_blocks(program.statements.iter(), program.span(), context, &mut blocks);
+    for statement in program.statements.iter() {
+        if let Statement::Namespace(namespace) = statement {
+            collect_blocks(namespace.statements().iter(), namespace.span(), context, &mut blocks);
+        }
+    }
+
+    blocks
+}
+
+fn collect_blocks<'a>(
+    statements: impl Iterator<Item = &'a Statement>,
+    scope: Span,
+    context: &LintContext<'_>,
+    blocks: &mut Vec<ImportBlock<'a>>,
+) {
+    let mut current: Vec<&'a Use> = vec![];
+    for statement in statements {
+        match statement {
+            Statement::Use(r#use) => current.push(r#use),
+            _ => {
+                if !current.is_empty() {
+                    blocks.push(create_block(std::mem::take(&mut current), scope, context));
+                }
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        blocks.push(create_block(current, scope, context));
+    }
+}
+
+fn create_block<'a>(statements: Vec<&'a Use>, scope: Span, context: &LintContext<'_>) -> ImportBlock<'a> {
+    let content = context.lookup(&context.semantics.source.content);
+    let span = statements[0].span().join(statements[statements.len() - 1].span());
+
+    let mut imports = vec![];
+    for (index, r#use) in statements.iter().enumerate() {
+        let (prefix, items) = match &r#use.items {
+            UseItems::Sequence(sequence) => {
+                (None, sequence.items.iter().map(|item| (None, item, item.span())).collect::<Vec<_>>())
+            }
+            UseItems::TypedSequence(sequence) => (
+                None,
+                sequence.items.iter().map(|item| (Some(&sequence.r#type), item, item.span())).collect::<Vec<_>>(),
+            ),
+            UseItems::TypedList(list) => (
+                Some(&list.namespace),
+                list.items.iter().map(|item| (Some(&list.r#type), item, item.span())).collect::<Vec<_>>(),
+            ),
+            UseItems::MixedList(list) => (
+                Some(&list.namespace),
+                list.items.iter().map(|item| (item.r#type.as_ref(), &item.item, item.span())).collect::<Vec<_>>(),
+            ),
+        };
+
+        let prefix = prefix.map(|prefix| context.lookup(&prefix.value()).trim_matches('\\'));
+
+        for (r#type, item, written_span) in items {
+            let item_name = context.lookup(&item.name.value()).trim_start_matches('\\');
+            let name = match prefix {
+                Some(prefix) => format!("{}\\{}", prefix, item_name),
+                None => item_name.to_string(),
+            };
+
+            let alias = match &item.alias {
+                Some(alias) => context.lookup(&alias.identifier.value).to_string(),
+                None => name.rsplit('\\').next().unwrap_or(&name).to_string(),
+            };
+
+            let kind = match r#type {
+                None => ImportKind::ClassLike,
+                Some(UseType::Function(_)) => ImportKind::Function,
+                Some(UseType::Const(_)) => ImportKind::Constant,
+            };
+
+            imports.push(Import {
+                kind,
+                name,
+                alias,
+                span: item.span(),
+                text: content[written_span.start.offset..written_span.end.offset].to_string(),
+                statement: index,
+            });
+        }
+    }
+
+    ImportBlock { statements, imports, span, scope }
+}
+
+fn render_statement(context: &LintContext<'_>, r#use: &Use, items: &[&Import], indentation: &str) -> String {
+    let content = context.lookup(&context.semantics.source.content);
+    let original = &content[r#use.span().start.offset..r#use.span().end.offset];
+
+    let render_list = |items: &[&Import]| {
+        if original.contains('\n') {
+            let mut list = String::from("{\n");
+            for item in items {
+                list.push_str(indentation);
+                list.push_str("    ");
+                list.push_str(&item.text);
+                list.push_str(",\n");
+            }
+
+            list.push_str(indentation);
+            list.push('}');
+            list
+        } else {
+            format!("{{{}}}", items.iter().map(|item| item.text.as_str()).collect::<Vec<_>>().join(", "))
+        }
+    };
+
+    // A list left with a single item is written as a plain statement, e.g. `use Foo\Bar;` rather than `use Foo\{Bar};`.
+    if let (
+        UseItems::TypedList(TypedUseItemList { namespace, .. })
+        | UseItems::MixedList(MixedUseItemList { namespace, .. }),
+        [item],
+    ) = (&r#use.items, items)
+    {
+        let keyword = match item.kind {
+            ImportKind::ClassLike => "",
+            ImportKind::Function => "function ",
+            ImportKind::Constant => "const ",
+        };
+
+        return format!(
+            "use {}{}\\{};",
+            keyword,
+            context.lookup(&namespace.value()),
+            &content[item.span.start.offset..item.span.end.offset]
+        );
+    }
+
+    let sequence = items.iter().map(|item| item.text.as_str()).collect::<Vec<_>>().join(", ");
+
+    match &r#use.items {
+        UseItems::Sequence(_) => format!("use {};", sequence),
+        UseItems::TypedSequence(sequence_items) => {
+            format!("use {} {};", get_type_keyword(&sequence_items.r#type), sequence)
+        }
+        UseItems::TypedList(list) => format!(
+            "use {} {}\\{};",
+            get_type_keyword(&list.r#type),
+            context.lookup(&list.namespace.value()),
+            render_list(items)
+        ),
+        UseItems::MixedList(list) => {
+            format!("use {}\\{};", context.lookup(&list.namespace.value()), render_list(items))
+        }
+    }
+}
+
+fn get_statement_kind(r#use: &Use) -> ImportKind {
+    let r#type = match &r#use.items {
+        UseItems::TypedSequence(sequence) => &sequence.r#type,
+        UseItems::TypedList(list) => &list.r#type,
+        UseItems::Sequence(_) | UseItems::MixedList(_) => return ImportKind::ClassLike,
+    };
+
+    match r#type {
+        UseType::Function(_) => ImportKind::Function,
+        UseType::Const(_) => ImportKind::Constant,
+    }
+}
+
+fn get_type_keyword(r#type: &UseType) -> &'static str {
+    match r#type {
+        UseType::Function(_) => "function",
+        UseType::Const(_) => "const",
+    }
+}
+
+fn get_indentation(content: &str, span: Span) -> String {
+    let line_start = content[..span.start.offset].rfind('\n').map(|offset| offset + 1).unwrap_or(0);
+    let prefix = &content[line_start..span.start.offset];
+
+    if prefix.chars().all(|c| c == ' ' || c == '\t') {
+        prefix.to_string()
+    } else {
+        String::new()
+    }
+}
+
+fn read_name(content: &str) -> &str {
+    let end = content.char_indices().find(|(_, c)| !is_name_character(*c)).map(|(offset, _)| offset);
+
+    &content[..end.unwrap_or(content.len())]
+}
+
+fn is_name_character(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '\\' || !c.is_ascii()
+}
+
+/// Collects the trait names of `insteadof` adaptations and absolute method references, which are not
+/// recorded by the name resolver.
+#[derive(Debug, Default)]
+struct TraitAdaptationNames {
+    spans: Vec<Span>,
+}
+
+impl MutWalker<()> for TraitAdaptationNames {
+    fn walk_in_trait_use_precedence_adaptation(
+        &mut self,
+        trait_use_precedence_adaptation: &TraitUsePrecedenceAdaptation,
+        _context: &mut (),
+    ) {
+        for trait_name in trait_use_precedence_adaptation.trait_names.iter() {
+            self.spans.push(trait_name.span());
+        }
+    }
+
+    fn walk_in_trait_use_absolute_method_reference(
+        &mut self,
+        trait_use_absolute_method_reference: &TraitUseAbsoluteMethodReference,
+        _context: &mut (),
+    ) {
+        self.spans.push(trait_use_absolute_method_reference.trait_name.span());
+    }
+}
@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::sync::RwLock;
 
 use mago_interner::ThreadedInterner;
+use mago_reference::ReferenceIndex;
+use mago_reflection::CodebaseReflection;
 use mago_reporting::IssueCollection;
 use mago_semantics::Semantics;
 
@@ -14,7 +16,12 @@ use crate::settings::Settings;
 
 pub mod consts;
 pub mod context;
+pub(crate) mod dql;
+pub(crate) mod import;
+pub(crate) mod literal;
+pub(crate) mod pcre;
 pub mod plugin;
+pub(crate) mod printf;
 pub mod rule;
 pub mod settings;
 
@@ -145,6 +152,16 @@ impl Linter {
         });
     }
 
+    /// Determines whether any of the enabled rules requires the reference index of the codebase.
+    ///
+    /// Building the reference index requires walking every source of the codebase, so it should only
+    /// be built, and passed to [`Linter::lint`], when this method returns `true`.
+    pub fn requires_references(&self) -> bool {
+        let configured_rules = self.rules.read().expect("Unable to read rules: poisoned lock");
+
+        configured_rules.iter().any(|configured_rule| configured_rule.rule.requires_references())
+    }
+
     /// Lints the given semantics.
     ///
     /// This method will lint the given semantics and return a collection of issues.
@@ -152,16 +169,25 @@ impl Linter {
     /// # Parameters
     ///
     /// - `semantics`: The semantics to lint.
+    /// - `codebase`: The reflection of the entire codebase, used by rules that need to look up
+    ///   symbols defined outside of the given source.
+    /// - `references`: The reference index of the entire codebase, if it was built, used by rules
+    ///   that need to know where symbols are referenced.
     ///
     /// # Returns
     ///
     /// A collection of issues.
-    pub fn lint(&self, semantics: &Semantics) -> IssueCollection {
+    pub fn lint(
+        &self,
+        semantics: &Semantics,
+        codebase: &CodebaseReflection,
+        references: Option<&ReferenceIndex>,
+    ) -> IssueCollection {
         let source_name = self.interner.lookup(&semantics.source.identifier.value());
 
         tracing::debug!("Linting source `{}`...", source_name);
 
-        let mut context = Context::new(&self.interner, semantics);
+        let mut context = Context::new(&self.interner, semantics, codebase, references);
 
         let configured_rules = self.rules.read().expect("Unable to read rules: poisoned lock");
 
@@ -170,7 +196,7 @@ impl Linter {
         for configured_rule in configured_rules.iter() {
             tracing::trace!("Running rule `{}`...", configured_rule.rule.get_name());
 
-            let mut lint_context = context.for_rule(configured_rule);
+            let mut lint_context = context.for_rule(configured_rule, &configured_rules);
 
             configured_rule.rule.as_ref().lint(&semantics.program, &mut lint_context);
         }
@@ -0,0 +1,115 @@
+use mago_ast::*;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::context::LintContext;
+
+/// The value of a string literal, along with the position of each of its bytes in the source.
+#[derive(Debug, Clone)]
+pub struct DecodedString {
+    pub value: Vec<u8>,
+    /// The source position of each byte of the value, followed by the position of the closing quote.
+    positions: Vec<Position>,
+}
+
+impl DecodedString {
+    /// Decodes the given string literal, resolving its escape sequences.
+    pub fn decode(literal: &LiteralString, context: &LintContext<'_>) -> Option<Self> {
+        let raw = context.lookup(&literal.value).as_bytes();
+        if raw.len() < 2 {
+            return None;
+        }
+
+        let source = literal.span.start.source;
+        let start = literal.span.start.offset + 1;
+        let raw = &raw[1..raw.len() - 1];
+
+        let mut value = Vec::with_capacity(raw.len());
+        let mut positions = Vec::with_capacity(raw.len() + 1);
+        let mut push = |bytes: &[u8], offset: usize| {
+            for byte in bytes {
+                value.push(*byte);
+                positions.push(Position::new(source, start + offset));
+            }
+        };
+
+        let mut index = 0;
+        while index < raw.len() {
+            let byte = raw[index];
+            if byte != b'\\' || index + 1 == raw.len() {
+                push(&[byte], index);
+                index += 1;
+
+                continue;
+            }
+
+            let next = raw[index + 1];
+            let (decoded, length): (Vec<u8>, usize) = match literal.kind {
+                LiteralStringKind::SingleQuoted => match next {
+                    b'\\' | b'\'' => (vec![next], 2),
+                    _ => (vec![byte], 1),
+                },
+                LiteralStringKind::DoubleQuoted => match next {
+                    b'n' => (vec![b'\n'], 2),
+                    b't' => (vec![b'\t'], 2),
+                    b'r' => (vec![b'\r'], 2),
+                    b'v' => (vec![0x0B], 2),
+                    b'e' => (vec![0x1B], 2),
+                    b'f' => (vec![0x0C], 2),
+                    b'\\' | b'$' | b'"' => (vec![next], 2),
+                    b'0'..=b'7' => {
+                        let digits = raw[index + 1..].iter().take(3).take_while(|b| matches!(b, b'0'..=b'7')).count();
+                        let octal = std::str::from_utf8(&raw[index + 1..index + 1 + digits]).unwrap_or("0");
+
+                        (vec![u32::from_str_radix(octal, 8).unwrap_or(0) as u8], 1 + digits)
+                    }
+                    b'x' => {
+                        let digits = raw[index + 2..].iter().take(2).take_while(|b| b.is_ascii_hexdigit()).count();
+                        if digits == 0 {
+                            (vec![byte], 1)
+                        } else {
+                            let hex = std::str::from_utf8(&raw[index + 2..index + 2 + digits]).unwrap_or("0");
+
+                            (vec![u8::from_str_radix(hex, 16).unwrap_or(0)], 2 + digits)
+                        }
+                    }
+                    b'u' if raw.get(index + 2) == Some(&b'{') => {
+                        let length = raw[index + 3..].iter().position(|b| *b == b'}')?;
+                        let hex = std::str::from_utf8(&raw[index + 3..index + 3 + length]).ok()?;
+                        let character = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+                        let mut buffer = [0; 4];
+
+                        (character.encode_utf8(&mut buffer).as_bytes().to_vec(), 4 + length)
+                    }
+                    _ => (vec![byte], 1),
+                },
+            };
+
+            push(&decoded, index);
+            index += length;
+        }
+
+        positions.push(Position::new(source, start + raw.len()));
+
+        Some(Self { value, positions })
+    }
+
+    /// Returns the span of the given range of bytes of the value.
+    pub fn span(&self, from: usize, to: usize) -> Span {
+        let from = from.min(self.value.len());
+        let to = to.clamp(from, self.value.len());
+        if from == to {
+            let end = self.positions[from].forward(1);
+
+            return Span::new(self.positions[from], end);
+        }
+
+        // Escape sequences decode to a single position; extend the span to the start of the next byte.
+        let mut end = to;
+        while end < self.value.len() && self.positions[end] == self.positions[to - 1] {
+            end += 1;
+        }
+
+        Span::new(self.positions[from], self.positions[end])
+    }
+}
@@ -0,0 +1,1363 @@
+/// The names of the POSIX character classes supported within character classes, e.g. `[[:alpha:]]`.
+const POSIX_CLASSES: [&str; 14] = [
+    "alpha", "alnum", "ascii", "blank", "cntrl", "digit", "graph", "lower", "print", "punct", "space", "upper", "word",
+    "xdigit",
+];
+
+/// The maximum length of the name of a named subpattern.
+const MAXIMUM_NAME_LENGTH: usize = 32;
+
+/// The maximum value of the numbers of a `{n,m}` quantifier.
+const MAXIMUM_REPEAT: usize = 65535;
+
+/// A problem found in a pattern, spanning a range of its bytes.
+#[derive(Debug, Clone)]
+pub struct PatternIssue {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The result of parsing a regular expression pattern, as accepted by the `preg_*` functions.
+#[derive(Debug, Default)]
+pub struct Pattern {
+    /// The number of capturing groups of the pattern.
+    pub captures: usize,
+    /// The first error found in the pattern, which would make it fail to compile.
+    pub error: Option<PatternIssue>,
+    /// The nested quantifiers of the pattern that may cause catastrophic backtracking.
+    pub backtracking: Vec<PatternIssue>,
+}
+
+/// Parses the given pattern, including its delimiters and modifiers.
+///
+/// The parser follows the syntax of PCRE2, as bundled with PHP, closely enough to report the errors
+/// it would report when compiling the pattern, but does not compile it.
+pub fn parse_pattern(pattern: &[u8]) -> Pattern {
+    let start = pattern.iter().take_while(|byte| byte.is_ascii_whitespace() || **byte == 0x0B).count();
+    let Some(&delimiter) = pattern.get(start) else {
+        return Pattern { error: Some(issue("Empty regular expression.", 0, pattern.len())), ..Default::default() };
+    };
+
+    if delimiter.is_ascii_alphanumeric() || delimiter == b'\\' || delimiter == 0 {
+        return Pattern {
+            error: Some(issue("Delimiter must not be alphanumeric, backslash, or NUL.", start, start + 1)),
+            ..Default::default()
+        };
+    }
+
+    let closing = match delimiter {
+        b'(' => b')',
+        b'[' => b']',
+        b'{' => b'}',
+        b'<' => b'>',
+        _ => delimiter,
+    };
+
+    let mut position = start + 1;
+    let mut depth = 1;
+    let end = loop {
+        match pattern.get(position) {
+            None => {
+                return Pattern {
+                    error: Some(issue(
+                        format!("No ending delimiter `{}` found.", char::from(closing)),
+                        start,
+                        pattern.len(),
+                    )),
+                    ..Default::default()
+                };
+            }
+            Some(b'\\') => position += 2,
+            Some(&byte) if byte == closing => {
+                depth -= 1;
+                if depth == 0 || closing == delimiter {
+                    break position;
+                }
+
+                position += 1;
+            }
+            Some(&byte) if byte == delimiter => {
+                depth += 1;
+                position += 1;
+            }
+            Some(_) => position += 1,
+        }
+    };
+
+    let mut parser = Parser::new(pattern, start + 1, end);
+    for (offset, modifier) in pattern.iter().enumerate().skip(end + 1) {
+        match modifier {
+            b'i' | b'm' | b's' | b'A' | b'D' | b'S' | b'U' | b'X' | b' ' | b'\n' | b'\r' => {}
+            b'x' => parser.options.extended = true,
+            b'n' => parser.options.no_auto_capture = true,
+            b'J' => parser.duplicate_names = true,
+            b'u' => parser.utf = true,
+            b'e' => parser.error(
+                "The `e` modifier is no longer supported, use `preg_replace_callback()` instead.",
+                offset,
+                offset + 1,
+            ),
+            _ => parser.error(format!("Unknown modifier `{}`.", char::from(*modifier)), offset, offset + 1),
+        }
+    }
+
+    parser.parse()
+}
+
+fn issue(message: impl Into<String>, start: usize, end: usize) -> PatternIssue {
+    PatternIssue { message: message.into(), start, end }
+}
+
+/// The options of a pattern that can be changed within it, and affect parsing.
+#[derive(Debug, Clone, Copy, Default)]
+struct Options {
+    extended: bool,
+    no_auto_capture: bool,
+}
+
+/// A quantifier, such as `*`, `+?` or `{2,5}+`.
+#[derive(Debug, Clone, Copy)]
+struct Quantifier {
+    minimum: usize,
+    maximum: Option<usize>,
+    possessive: bool,
+    end: usize,
+}
+
+/// An item of a pattern that a quantifier can apply to.
+#[derive(Debug, Clone, Copy)]
+struct Element {
+    start: usize,
+    /// Whether the item always consumes input, in a single way.
+    fixed: bool,
+    /// Whether the item contains a quantifier that repeats without bound, and can give back what it consumed.
+    unbounded: bool,
+    /// Whether the item is a group with an alternative that can match the same input in many ways.
+    ambiguous: bool,
+    /// The number of backtracking issues reported before the item.
+    reported: usize,
+    quantified: bool,
+}
+
+/// A summary of the alternatives of a group.
+#[derive(Debug, Clone, Copy)]
+struct Alternatives {
+    fixed: bool,
+    unbounded: bool,
+    ambiguous: bool,
+    count: usize,
+}
+
+/// The items of an alternative parsed so far.
+#[derive(Debug, Default)]
+struct Alternative {
+    fixed: bool,
+    unbounded: bool,
+    /// The last item of the alternative, which a following quantifier applies to.
+    last: Option<Element>,
+}
+
+impl Alternative {
+    fn push(&mut self, element: Element) {
+        if let Some(last) = self.last.replace(element) {
+            self.fixed |= last.fixed;
+            self.unbounded |= last.unbounded;
+        }
+    }
+
+    fn close(&mut self, alternatives: &mut Alternatives) {
+        if let Some(last) = self.last.take() {
+            self.fixed |= last.fixed;
+            self.unbounded |= last.unbounded;
+        }
+
+        alternatives.fixed &= self.fixed;
+        alternatives.unbounded |= self.unbounded;
+        alternatives.ambiguous |= self.unbounded && !self.fixed;
+        (self.fixed, self.unbounded) = (false, false);
+    }
+}
+
+/// A reference to a subpattern, checked once all the subpatterns are known.
+#[derive(Debug, Clone)]
+enum Reference {
+    Number(usize),
+    Name(String),
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    end: usize,
+    options: Options,
+    duplicate_names: bool,
+    utf: bool,
+    captures: usize,
+    names: Vec<String>,
+    references: Vec<(Reference, usize, usize)>,
+    error: Option<PatternIssue>,
+    backtracking: Vec<PatternIssue>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(bytes: &'a [u8], start: usize, end: usize) -> Self {
+        Self {
+            bytes,
+            position: start,
+            end,
+            options: Options::default(),
+            duplicate_names: false,
+            utf: false,
+            captures: 0,
+            names: vec![],
+            references: vec![],
+            error: None,
+            backtracking: vec![],
+        }
+    }
+
+    fn parse(mut self) -> Pattern {
+        self.parse_alternatives(None, false);
+
+        for (reference, start, end) in std::mem::take(&mut self.references) {
+            let exists = match &reference {
+                Reference::Number(number) => *number <= self.captures,
+                Reference::Name(name) => self.names.contains(name),
+            };
+
+            if !exists {
+                let name = match reference {
+                    Reference::Number(number) => number.to_string(),
+                    Reference::Name(name) => name,
+                };
+
+                self.error(format!("Reference to non-existent subpattern `{}`.", name), start, end);
+            }
+        }
+
+        let backtracking = if self.error.is_none() { self.backtracking } else { vec![] };
+
+        Pattern { captures: self.captures, error: self.error, backtracking }
+    }
+
+    fn error(&mut self, message: impl Into<String>, start: usize, end: usize) {
+        if self.error.is_none() {
+            self.error = Some(issue(message, start, end.max(start + 1)));
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        if self.position + offset < self.end {
+            Some(self.bytes[self.position + offset])
+        } else {
+            None
+        }
+    }
+
+    fn find(&self, byte: u8) -> Option<usize> {
+        self.bytes[self.position.min(self.end)..self.end]
+            .iter()
+            .position(|b| *b == byte)
+            .map(|index| self.position + index)
+    }
+
+    fn count_while(&self, predicate: impl Fn(u8) -> bool) -> usize {
+        self.bytes[self.position.min(self.end)..self.end].iter().take_while(|byte| predicate(**byte)).count()
+    }
+
+    fn read_number(&mut self, maximum_digits: usize) -> Option<usize> {
+        let digits = self.count_while(|byte| byte.is_ascii_digit()).min(maximum_digits);
+        if digits == 0 {
+            return None;
+        }
+
+        let number = self.bytes[self.position..self.position + digits]
+            .iter()
+            .fold(0usize, |number, digit| number.saturating_mul(10).saturating_add((digit - b'0') as usize));
+
+        self.position += digits;
+
+        Some(number)
+    }
+
+    /// Reads a single character, decoding it as UTF-8 in UTF mode.
+    fn read_character(&mut self) -> u32 {
+        let byte = self.bytes[self.position];
+        self.position += 1;
+        if !self.utf || byte < 0x80 {
+            return byte as u32;
+        }
+
+        let length = self.count_while(|byte| (0x80..0xC0).contains(&byte)).min(3);
+        let character = std::str::from_utf8(&self.bytes[self.position - 1..self.position + length])
+            .ok()
+            .and_then(|character| character.chars().next());
+
+        self.position += length;
+
+        character.map(|character| character as u32).unwrap_or(byte as u32)
+    }
+
+    fn element(&self, start: usize, fixed: bool) -> Element {
+        Element {
+            start,
+            fixed,
+            unbounded: false,
+            ambiguous: false,
+            reported: self.backtracking.len(),
+            quantified: false,
+        }
+    }
+
+    fn group(&self, start: usize, reported: usize, alternatives: Alternatives, atomic: bool) -> Element {
+        Element {
+            start,
+            fixed: alternatives.fixed,
+            unbounded: alternatives.unbounded && !atomic,
+            ambiguous: alternatives.ambiguous && !atomic,
+            reported,
+            quantified: false,
+        }
+    }
+
+    /// Parses a sequence of alternatives, up to and including the closing parenthesis of the group opened at `opening`.
+    fn parse_alternatives(&mut self, opening: Option<usize>, branch_reset: bool) -> Alternatives {
+        let options = self.options;
+        let captures = self.captures;
+        let mut highest_captures = captures;
+
+        let mut alternatives = Alternatives { fixed: true, unbounded: false, ambiguous: false, count: 1 };
+        let mut alternative = Alternative::default();
+
+        loop {
+            let Some(byte) = self.peek() else {
+                if let Some(opening) = opening {
+                    self.error("Missing closing parenthesis.", opening, opening + 1);
+                }
+
+                break;
+            };
+
+            if self.options.extended && (byte.is_ascii_whitespace() || byte == 0x0B) {
+                self.position += 1;
+
+                continue;
+            }
+
+            if self.options.extended && byte == b'#' {
+                self.position = self.find(b'\n').map_or(self.end, |newline| newline + 1);
+
+                continue;
+            }
+
+            match byte {
+                b')' if opening.is_some() => {
+                    self.position += 1;
+
+                    break;
+                }
+                b')' => {
+                    self.error("Unmatched closing parenthesis.", self.position, self.position + 1);
+                    self.position += 1;
+                }
+                b'|' => {
+                    self.position += 1;
+                    alternative.close(&mut alternatives);
+                    alternatives.count += 1;
+                    if branch_reset {
+                        highest_captures = highest_captures.max(self.captures);
+                        self.captures = captures;
+                    }
+                }
+                b'*' | b'+' | b'?' | b'{' => {
+                    let start = self.position;
+                    let Some(quantifier) = self.parse_quantifier() else {
+                        // A brace that does not start a quantifier is a literal.
+                        self.position += 1;
+                        alternative.push(self.element(start, true));
+
+                        continue;
+                    };
+
+                    match alternative.last.as_mut() {
+                        Some(element) if !element.quantified => {
+                            self.quantify(element, quantifier);
+                        }
+                        _ if byte == b'{' => {
+                            alternative.push(self.element(start, true));
+                        }
+                        _ => {
+                            self.error("Quantifier does not follow a repeatable item.", start, quantifier.end);
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(element) = self.parse_item() {
+                        alternative.push(element);
+                    }
+                }
+            }
+        }
+
+        alternative.close(&mut alternatives);
+        if branch_reset {
+            self.captures = self.captures.max(highest_captures);
+        }
+
+        self.options = options;
+
+        alternatives
+    }
+
+    fn quantify(&mut self, element: &mut Element, quantifier: Quantifier) {
+        if quantifier.maximum.is_none()
+            && !quantifier.possessive
+            && element.ambiguous
+            && self.backtracking.len() == element.reported
+        {
+            self.backtracking.push(issue(
+                "Nested quantifiers may cause catastrophic backtracking.",
+                element.start,
+                quantifier.end,
+            ));
+        }
+
+        element.fixed = element.fixed
+            && quantifier.minimum >= 1
+            && (quantifier.possessive || quantifier.maximum == Some(quantifier.minimum));
+        element.unbounded = !quantifier.possessive && (element.unbounded || quantifier.maximum.is_none());
+        element.ambiguous = false;
+        element.quantified = true;
+    }
+
+    /// Parses a quantifier, returning `None` for a brace that does not start one.
+    fn parse_quantifier(&mut self) -> Option<Quantifier> {
+        let start = self.position;
+        let (minimum, maximum) = match self.bytes[start] {
+            b'*' => (0, None),
+            b'+' => (1, None),
+            b'?' => (0, Some(1)),
+            _ => {
+                self.position += 1;
+                let Some(minimum) = self.read_number(usize::MAX) else {
+                    self.position = start;
+
+                    return None;
+                };
+
+                let maximum = match self.peek() {
+                    Some(b'}') => Some(minimum),
+                    Some(b',') => {
+                        self.position += 1;
+
+                        self.read_number(usize::MAX)
+                    }
+                    _ => None,
+                };
+
+                if self.peek() != Some(b'}') || (maximum.is_none() && self.bytes[self.position - 1] != b',') {
+                    self.position = start;
+
+                    return None;
+                }
+
+                if minimum > MAXIMUM_REPEAT || maximum.is_some_and(|maximum| maximum > MAXIMUM_REPEAT) {
+                    self.error("Number too big in `{}` quantifier.", start, self.position + 1);
+                } else if maximum.is_some_and(|maximum| maximum < minimum) {
+                    self.error("Numbers out of order in `{}` quantifier.", start, self.position + 1);
+                }
+
+                (minimum, maximum)
+            }
+        };
+
+        self.position += 1;
+
+        let possessive = self.peek() == Some(b'+');
+        if matches!(self.peek(), Some(b'+' | b'?')) {
+            self.position += 1;
+        }
+
+        Some(Quantifier { minimum, maximum, possessive, end: self.position })
+    }
+
+    /// Parses a single item, returning `None` for items that quantifiers do not apply to, such as comments.
+    fn parse_item(&mut self) -> Option<Element> {
+        let start = self.position;
+        let byte = self.bytes[start];
+        match byte {
+            b'(' => {
+                self.position += 1;
+
+                self.parse_group(start)
+            }
+            b'[' => {
+                self.position += 1;
+                self.parse_class(start);
+
+                Some(self.element(start, true))
+            }
+            b'\\' => {
+                self.position += 1;
+
+                self.parse_escape(start)
+            }
+            b'^' | b'$' => {
+                self.position += 1;
+
+                Some(self.element(start, false))
+            }
+            _ => {
+                self.read_character();
+
+                Some(self.element(start, true))
+            }
+        }
+    }
+
+    /// Parses a group, after its opening parenthesis.
+    fn parse_group(&mut self, start: usize) -> Option<Element> {
+        let reported = self.backtracking.len();
+        match self.peek() {
+            Some(b'*') => {
+                self.position += 1;
+
+                self.parse_verb(start)
+            }
+            Some(b'?') => {
+                self.position += 1;
+
+                self.parse_extended_group(start)
+            }
+            _ => {
+                if !self.options.no_auto_capture {
+                    self.captures += 1;
+                }
+
+                let alternatives = self.parse_alternatives(Some(start), false);
+
+                Some(self.group(start, reported, alternatives, false))
+            }
+        }
+    }
+
+    /// Parses a verb, such as `(*FAIL)`, or an alphabetic assertion, such as `(*pla:...)`, after its `(*`.
+    fn parse_verb(&mut self, start: usize) -> Option<Element> {
+        let reported = self.backtracking.len();
+        let length = self.count_while(|byte| byte.is_ascii_alphanumeric() || byte == b'_');
+        let name = String::from_utf8_lossy(&self.bytes[self.position..self.position + length]).to_string();
+
+        if self.peek_at(length) == Some(b':')
+            && !name.is_empty()
+            && name.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+        {
+            self.position += length + 1;
+            let alternatives = self.parse_alternatives(Some(start), false);
+
+            return if name == "atomic" {
+                Some(self.group(start, reported, alternatives, true))
+            } else {
+                Some(self.element(start, false))
+            };
+        }
+
+        let Some(closing) = self.find(b')') else {
+            self.error("Missing closing parenthesis.", start, start + 1);
+            self.position = self.end;
+
+            return None;
+        };
+
+        self.position = closing + 1;
+
+        Some(self.element(start, false))
+    }
+
+    /// Parses a group starting with `(?`, after it.
+    fn parse_extended_group(&mut self, start: usize) -> Option<Element> {
+        let reported = self.backtracking.len();
+        let Some(byte) = self.peek() else {
+            self.error("Missing closing parenthesis.", start, start + 1);
+
+            return None;
+        };
+
+        match byte {
+            b'#' => {
+                match self.find(b')') {
+                    Some(closing) => self.position = closing + 1,
+                    None => {
+                        self.error("Missing `)` after `(?#` comment.", start, self.end);
+                        self.position = self.end;
+                    }
+                }
+
+                None
+            }
+            b':' | b'|' | b'>' => {
+                self.position += 1;
+                let alternatives = self.parse_alternatives(Some(start), byte == b'|');
+
+                Some(self.group(start, reported, alternatives, byte == b'>'))
+            }
+            b'=' | b'!' => {
+                self.position += 1;
+                self.parse_alternatives(Some(start), false);
+
+                Some(self.element(start, false))
+            }
+            b'<' if matches!(self.peek_at(1), Some(b'=' | b'!')) => {
+                self.position += 2;
+                self.parse_alternatives(Some(start), false);
+
+                Some(self.element(start, false))
+            }
+            b'<' | b'\'' => {
+                self.position += 1;
+
+                self.parse_named_group(start, if byte == b'<' { b'>' } else { b'\'' })
+            }
+            b'P' => match self.peek_at(1) {
+                Some(b'<') => {
+                    self.position += 2;
+
+                    self.parse_named_group(start, b'>')
+                }
+                Some(b'=' | b'>') => {
+                    self.position += 2;
+                    self.parse_name_reference(b')');
+
+                    Some(self.element(start, false))
+                }
+                _ => {
+                    self.error("Unrecognized character after `(?P`.", self.position, self.position + 2);
+
+                    None
+                }
+            },
+            b'&' => {
+                self.position += 1;
+                self.parse_name_reference(b')');
+
+                Some(self.element(start, false))
+            }
+            b'R' if self.peek_at(1) == Some(b')') => {
+                self.position += 2;
+
+                Some(self.element(start, false))
+            }
+            b'0'..=b'9' => {
+                let number = self.read_number(usize::MAX).unwrap_or(0);
+                self.expect_closing_parenthesis(start);
+                if number > 0 {
+                    self.references.push((Reference::Number(number), start, self.position));
+                }
+
+                Some(self.element(start, false))
+            }
+            b'+' | b'-' if self.peek_at(1).is_some_and(|byte| byte.is_ascii_digit()) => {
+                self.position += 1;
+                let number = self.read_number(usize::MAX).unwrap_or(0);
+                self.expect_closing_parenthesis(start);
+                self.push_relative_reference(byte == b'+', number, start);
+
+                Some(self.element(start, false))
+            }
+            b'(' => {
+                self.position += 1;
+
+                self.parse_conditional(start)
+            }
+            b'C' => {
+                match self.find(b')') {
+                    Some(closing) => self.position = closing + 1,
+                    None => {
+                        self.error("Missing closing parenthesis.", start, start + 1);
+                        self.position = self.end;
+                    }
+                }
+
+                None
+            }
+            _ => self.parse_option_setting(start),
+        }
+    }
+
+    fn expect_closing_parenthesis(&mut self, start: usize) {
+        if self.peek() == Some(b')') {
+            self.position += 1;
+        } else {
+            self.error("Missing closing parenthesis.", start, self.position);
+        }
+    }
+
+    /// Parses an option setting, such as `(?i)` or `(?x-s:...)`, after its `(?`.
+    fn parse_option_setting(&mut self, start: usize) -> Option<Element> {
+        let reported = self.backtracking.len();
+        let mut options = self.options;
+        let mut negated = false;
+        if self.peek() == Some(b'^') {
+            self.position += 1;
+            options = Options::default();
+        }
+
+        loop {
+            match self.peek() {
+                Some(b'-') if !negated => negated = true,
+                Some(b'x') => options.extended = !negated,
+                Some(b'n') => options.no_auto_capture = !negated,
+                Some(b'i' | b'm' | b's' | b'J' | b'U') => {}
+                Some(b')') => {
+                    self.position += 1;
+                    self.options = options;
+
+                    return None;
+                }
+                Some(b':') => {
+                    self.position += 1;
+                    let saved = std::mem::replace(&mut self.options, options);
+                    let alternatives = self.parse_alternatives(Some(start), false);
+                    self.options = saved;
+
+                    return Some(self.group(start, reported, alternatives, false));
+                }
+                Some(_) => {
+                    self.error("Unrecognized character after `(?` or `(?-`.", self.position, self.position + 1);
+                    self.position = self.find(b')').map_or(self.end, |closing| closing + 1);
+
+                    return None;
+                }
+                None => {
+                    self.error("Missing closing parenthesis.", start, start + 1);
+
+                    return None;
+                }
+            }
+
+            self.position += 1;
+        }
+    }
+
+    /// Parses a conditional group, after its `(?(`.
+    fn parse_conditional(&mut self, start: usize) -> Option<Element> {
+        let reported = self.backtracking.len();
+        if matches!(self.peek(), Some(b'?' | b'*')) {
+            // The condition is an assertion.
+            self.position -= 1;
+            self.parse_item();
+        } else {
+            let condition_start = self.position;
+            let Some(closing) = self.find(b')') else {
+                self.error("Missing closing parenthesis.", start, start + 1);
+                self.position = self.end;
+
+                return None;
+            };
+
+            let condition = &self.bytes[condition_start..closing];
+            let is_name = |name: &[u8]| {
+                !name.is_empty()
+                    && !name[0].is_ascii_digit()
+                    && name.iter().all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_' || *byte >= 0x80)
+            };
+
+            match condition {
+                [b'+' | b'-', digits @ ..] if !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) => {
+                    self.position = condition_start + 1;
+                    let number = self.read_number(usize::MAX).unwrap_or(0);
+                    self.push_relative_reference(condition[0] == b'+', number, condition_start);
+                }
+                digits if !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) => {
+                    let number = self.read_number(usize::MAX).unwrap_or(0);
+                    if number == 0 {
+                        self.error("Invalid condition `(?(0)`.", condition_start, closing);
+                    } else {
+                        self.references.push((Reference::Number(number), condition_start, closing));
+                    }
+                }
+                [b'<', name @ .., b'>'] | [b'\'', name @ .., b'\''] if is_name(name) => {
+                    self.push_name_reference(name, condition_start, closing);
+                }
+                [b'R', ..] | b"DEFINE" => {}
+                name if is_name(name) => {
+                    self.push_name_reference(name, condition_start, closing);
+                }
+                _ => {
+                    self.error("Malformed number or name after `(?(`.", condition_start, closing);
+                }
+            }
+
+            self.position = closing + 1;
+        }
+
+        let alternatives = self.parse_alternatives(Some(start), false);
+        if alternatives.count > 2 {
+            self.error("Conditional subpattern contains more than two branches.", start, self.position);
+        }
+
+        Some(Element { fixed: false, ..self.group(start, reported, alternatives, false) })
+    }
+
+    /// Parses a named capturing group, after the opening delimiter of its name.
+    fn parse_named_group(&mut self, start: usize, terminator: u8) -> Option<Element> {
+        let reported = self.backtracking.len();
+        let name_start = self.position;
+        let (name, name_end) = self.parse_name(terminator);
+        if !self.duplicate_names && self.names.contains(&name) {
+            self.error(format!("Two named subpatterns have the same name `{}`.", name), name_start, name_end);
+        }
+
+        self.names.push(name);
+
+        self.captures += 1;
+        let alternatives = self.parse_alternatives(Some(start), false);
+
+        Some(self.group(start, reported, alternatives, false))
+    }
+
+    /// Parses a subpattern name, up to and including its terminator.
+    fn parse_name(&mut self, terminator: u8) -> (String, usize) {
+        let start = self.position;
+        let length = self.count_while(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80);
+        let name = String::from_utf8_lossy(&self.bytes[start..start + length]).to_string();
+        self.position += length;
+
+        if length == 0 && self.peek() != Some(terminator) {
+            self.error("Subpattern name expected.", start, start + 1);
+        } else if length == 0 || self.bytes[start].is_ascii_digit() {
+            self.error("Subpattern name must start with a non-digit.", start, start + 1);
+        } else if self.peek() != Some(terminator) {
+            self.error("Syntax error in subpattern name (missing terminator?).", self.position, self.position + 1);
+        } else if length > MAXIMUM_NAME_LENGTH {
+            self.error(
+                format!("Subpattern name is too long (maximum {} characters).", MAXIMUM_NAME_LENGTH),
+                start,
+                self.position,
+            );
+        }
+
+        if self.peek() == Some(terminator) {
+            self.position += 1;
+        }
+
+        (name, start + length)
+    }
+
+    fn parse_name_reference(&mut self, terminator: u8) {
+        let start = self.position;
+        let (name, end) = self.parse_name(terminator);
+
+        self.references.push((Reference::Name(name), start, end));
+    }
+
+    fn push_name_reference(&mut self, name: &[u8], start: usize, end: usize) {
+        self.references.push((Reference::Name(String::from_utf8_lossy(name).to_string()), start, end));
+    }
+
+    fn push_relative_reference(&mut self, forward: bool, number: usize, start: usize) {
+        if number == 0 {
+            self.error("A numbered reference must not be zero.", start, self.position);
+        } else if forward {
+            self.references.push((Reference::Number(self.captures + number), start, self.position));
+        } else if number > self.captures {
+            self.error(format!("Reference to non-existent subpattern `-{}`.", number), start, self.position);
+        }
+    }
+
+    /// Parses an escape sequence outside of a character class, after its backslash.
+    fn parse_escape(&mut self, start: usize) -> Option<Element> {
+        let Some(byte) = self.peek() else {
+            self.error("Pattern ends with a backslash.", start, start + 1);
+
+            return None;
+        };
+
+        self.position += 1;
+        match byte {
+            b'1'..=b'9' => {
+                self.position -= 1;
+                let digits_start = self.position;
+                let number = self.read_number(usize::MAX).unwrap_or(0);
+                if number < 10 || number <= self.captures {
+                    self.references.push((Reference::Number(number), start, self.position));
+
+                    return Some(self.element(start, true));
+                }
+
+                // Otherwise, the escape is an octal character code, if any.
+                self.position = digits_start;
+                let octal = self.count_while(|byte| (b'0'..=b'7').contains(&byte)).min(3);
+                self.position += octal.max(1);
+            }
+            b'0' => {
+                self.position += self.count_while(|byte| (b'0'..=b'7').contains(&byte)).min(2);
+            }
+            b'g' => {
+                self.parse_g_reference(start);
+
+                return Some(self.element(start, false));
+            }
+            b'k' => {
+                let terminator = match self.peek() {
+                    Some(b'<') => b'>',
+                    Some(b'\'') => b'\'',
+                    Some(b'{') => b'}',
+                    _ => {
+                        self.error(
+                            "`\\k` is not followed by a braced, angle-bracketed, or quoted name.",
+                            start,
+                            self.position,
+                        );
+
+                        return None;
+                    }
+                };
+
+                self.position += 1;
+                self.parse_name_reference(terminator);
+
+                return Some(self.element(start, true));
+            }
+            b'x' => self.skip_hexadecimal(),
+            b'o' => self.skip_braced_octal(start),
+            b'c' => self.skip_control(start),
+            b'p' | b'P' => self.skip_property(start),
+            b'Q' => {
+                let end = self.bytes[self.position..self.end].windows(2).position(|window| window == b"\\E");
+                let quoted = end.unwrap_or(self.end - self.position);
+                self.position += quoted + if end.is_some() { 2 } else { 0 };
+
+                return if quoted == 0 { None } else { Some(self.element(start, true)) };
+            }
+            b'E' => return None,
+            b'b' | b'B' | b'A' | b'z' | b'Z' | b'G' | b'K' => return Some(self.element(start, false)),
+            b'a' | b'C' | b'd' | b'D' | b'e' | b'f' | b'h' | b'H' | b'n' | b'N' | b'r' | b'R' | b's' | b'S' | b't'
+            | b'v' | b'V' | b'w' | b'W' | b'X' => {}
+            b'F' | b'L' | b'l' | b'U' | b'u' => {
+                self.error(format!("PCRE does not support `\\{}`.", char::from(byte)), start, self.position);
+            }
+            _ if byte.is_ascii_alphabetic() => {
+                self.error(format!("Unrecognized escape sequence `\\{}`.", char::from(byte)), start, self.position);
+            }
+            _ => {
+                self.position -= 1;
+                self.read_character();
+            }
+        }
+
+        Some(self.element(start, true))
+    }
+
+    /// Parses a `\g` reference or subroutine call, after its `\g`.
+    fn parse_g_reference(&mut self, start: usize) {
+        let terminator = match self.peek() {
+            Some(b'{') => Some(b'}'),
+            Some(b'<') => Some(b'>'),
+            Some(b'\'') => Some(b'\''),
+            _ => None,
+        };
+
+        // Subroutine calls, unlike back-references, may refer to the whole pattern or to later groups.
+        let subroutine = matches!(terminator, Some(b'>' | b'\''));
+        if terminator.is_some() {
+            self.position += 1;
+        }
+
+        let sign = match self.peek() {
+            Some(byte @ (b'+' | b'-')) => {
+                self.position += 1;
+
+                Some(byte)
+            }
+            _ => None,
+        };
+
+        match self.read_number(usize::MAX) {
+            Some(number) => {
+                match sign {
+                    Some(b'-') => self.push_relative_reference(false, number, start),
+                    Some(_) if subroutine => self.push_relative_reference(true, number, start),
+                    Some(_) => {
+                        self.error("Invalid relative reference after `\\g`.", start, self.position);
+                    }
+                    None if number == 0 && !subroutine => {
+                        self.error("A numbered reference must not be zero.", start, self.position);
+                    }
+                    None if number == 0 => {}
+                    None => self.references.push((Reference::Number(number), start, self.position)),
+                }
+
+                if let Some(terminator) = terminator {
+                    if self.peek() == Some(terminator) {
+                        self.position += 1;
+                    } else {
+                        self.error("Syntax error in subpattern number (missing terminator?).", start, self.position);
+                    }
+                }
+            }
+            None => match terminator {
+                Some(terminator) if sign.is_none() => self.parse_name_reference(terminator),
+                _ => {
+                    self.error(
+                        "`\\g` is not followed by a braced, angle-bracketed, or quoted name or number, or by a plain number.",
+                        start,
+                        self.position,
+                    );
+                }
+            },
+        }
+    }
+
+    fn skip_hexadecimal(&mut self) {
+        if self.peek() == Some(b'{') {
+            if let Some(closing) = self.find(b'}') {
+                self.position = closing + 1;
+            }
+        } else {
+            self.position += self.count_while(|byte| byte.is_ascii_hexdigit()).min(2);
+        }
+    }
+
+    fn skip_braced_octal(&mut self, start: usize) {
+        match (self.peek(), self.find(b'}')) {
+            (Some(b'{'), Some(closing)) => self.position = closing + 1,
+            _ => self.error("Missing opening brace after `\\o`.", start, self.position),
+        }
+    }
+
+    fn skip_control(&mut self, start: usize) {
+        match self.peek() {
+            Some(byte) if (0x20..0x7F).contains(&byte) => self.position += 1,
+            Some(_) => self.error("`\\c` must be followed by a printable ASCII character.", start, self.position + 1),
+            None => self.error("`\\c` at end of pattern.", start, self.position),
+        }
+    }
+
+    fn skip_property(&mut self, start: usize) {
+        match self.peek() {
+            Some(b'{') => match self.find(b'}') {
+                Some(closing) => self.position = closing + 1,
+                None => self.error("Malformed `\\p` or `\\P` sequence.", start, self.end),
+            },
+            Some(_) => self.position += 1,
+            None => self.error("Malformed `\\p` or `\\P` sequence.", start, self.position),
+        }
+    }
+
+    /// Parses a character class, after its opening bracket.
+    fn parse_class(&mut self, start: usize) {
+        if self.peek() == Some(b'^') {
+            self.position += 1;
+        }
+
+        let mut first = true;
+        let mut range_start: Option<(u32, usize)> = None;
+        loop {
+            let Some(byte) = self.peek() else {
+                self.error("Missing terminating `]` for character class.", start, start + 1);
+
+                return;
+            };
+
+            if byte == b']' && !first {
+                self.position += 1;
+
+                return;
+            }
+
+            first = false;
+            let item_start = self.position;
+            let character = match byte {
+                b'[' if matches!(self.peek_at(1), Some(b':' | b'.' | b'=')) && self.parse_posix_class() => None,
+                b'\\' => {
+                    self.position += 1;
+
+                    self.parse_class_escape(item_start)
+                }
+                _ => Some(self.read_character()),
+            };
+
+            if let Some((low, low_start)) = range_start.take() {
+                match character {
+                    Some(high) if high < low => {
+                        self.error("Range out of order in character class.", low_start, self.position);
+                    }
+                    None => {
+                        self.error("Invalid range in character class.", low_start, self.position);
+                    }
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            if self.peek() == Some(b'-') && !matches!(self.peek_at(1), Some(b']') | None) {
+                match character {
+                    Some(low) => range_start = Some((low, item_start)),
+                    None => self.error("Invalid range in character class.", item_start, self.position + 1),
+                }
+
+                self.position += 1;
+            }
+        }
+    }
+
+    /// Parses a POSIX class, such as `[:alpha:]`, returning `false` if the bracket does not start one.
+    fn parse_posix_class(&mut self) -> bool {
+        let kind = self.bytes[self.position + 1];
+        let negated = self.peek_at(2) == Some(b'^');
+        let name_start = self.position + 2 + negated as usize;
+        let length = self.bytes[name_start.min(self.end)..self.end]
+            .iter()
+            .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'_')
+            .count();
+
+        let name_end = name_start + length;
+        if name_end + 1 >= self.end || self.bytes[name_end] != kind || self.bytes[name_end + 1] != b']' {
+            return false;
+        }
+
+        let start = self.position;
+        self.position = name_end + 2;
+
+        let name = String::from_utf8_lossy(&self.bytes[name_start..name_end]).to_string();
+        if kind != b':' {
+            self.error("POSIX collating elements are not supported.", start, self.position);
+        } else if !POSIX_CLASSES.contains(&name.as_str()) {
+            self.error(format!("Unknown POSIX class name `{}`.", name), start, self.position);
+        }
+
+        true
+    }
+
+    /// Parses an escape sequence within a character class, after its backslash, returning the
+    /// character it represents, if it represents a single character.
+    fn parse_class_escape(&mut self, start: usize) -> Option<u32> {
+        let byte = self.peek()?;
+        self.position += 1;
+
+        let character = match byte {
+            b'd' | b'D' | b'w' | b'W' | b's' | b'S' | b'h' | b'H' | b'v' | b'V' => return None,
+            b'p' | b'P' => {
+                self.skip_property(start);
+
+                return None;
+            }
+            b'Q' | b'E' => {
+                let end = self.bytes[self.position..self.end].windows(2).position(|window| window == b"\\E");
+                if byte == b'Q' {
+                    self.position += end.map_or(self.end - self.position, |end| end + 2);
+                }
+
+                return None;
+            }
+            b'b' => 0x08,
+            b'n' => 0x0A,
+            b'r' => 0x0D,
+            b't' => 0x09,
+            b'f' => 0x0C,
+            b'e' => 0x1B,
+            b'a' => 0x07,
+            b'0'..=b'7' => {
+                self.position -= 1;
+                let digits = self.count_while(|byte| (b'0'..=b'7').contains(&byte)).min(3);
+                let octal = &self.bytes[self.position..self.position + digits];
+                self.position += digits;
+
+                octal.iter().fold(0u32, |value, digit| value * 8 + (digit - b'0') as u32)
+            }
+            b'x' => {
+                let digits_start = self.position + (self.peek() == Some(b'{')) as usize;
+                self.skip_hexadecimal();
+                let digits = self.bytes[digits_start..self.position]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_hexdigit())
+                    .map(|byte| char::from(*byte))
+                    .collect::<String>();
+
+                u32::from_str_radix(&digits, 16).unwrap_or(0)
+            }
+            b'o' => {
+                let digits_start = self.position + 1;
+                self.skip_braced_octal(start);
+                let digits = self.bytes[digits_start.min(self.position)..self.position]
+                    .iter()
+                    .take_while(|byte| (b'0'..=b'7').contains(*byte))
+                    .map(|byte| char::from(*byte))
+                    .collect::<String>();
+
+                u32::from_str_radix(&digits, 8).unwrap_or(0)
+            }
+            b'c' => {
+                self.skip_control(start);
+
+                (self.bytes[self.position - 1].to_ascii_uppercase() ^ 0x40) as u32
+            }
+            b'A' | b'B' | b'C' | b'G' | b'K' | b'N' | b'R' | b'X' | b'Z' | b'z' | b'g' | b'k' => {
+                self.error(
+                    format!("Escape sequence `\\{}` is invalid in a character class.", char::from(byte)),
+                    start,
+                    self.position,
+                );
+
+                return None;
+            }
+            b'F' | b'L' | b'l' | b'U' | b'u' => {
+                self.error(format!("PCRE does not support `\\{}`.", char::from(byte)), start, self.position);
+
+                return None;
+            }
+            _ if byte.is_ascii_alphabetic() => {
+                self.error(format!("Unrecognized escape sequence `\\{}`.", char::from(byte)), start, self.position);
+
+                return None;
+            }
+            _ => {
+                self.position -= 1;
+
+                self.read_character()
+            }
+        };
+
+        Some(character)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_error(pattern: &str) -> Option<String> {
+        parse_pattern(pattern.as_bytes()).error.map(|error| error.message)
+    }
+
+    #[test]
+    fn test_valid_patterns() {
+        let patterns = [
+            "/foo/",
+            "/^[a-z0-9_-]{3,16}$/i",
+            "/(\\d+)-(\\d+)/",
+            "/(?<year>\\d{4})-(?<month>\\d{2})/",
+            "/(?P<name>\\w+)(?P=name)/",
+            "/(?:a|b)+?c*+/",
+            "/(?i)foo(?-i:bar)/",
+            "/(?=foo)(?!bar)(?<=baz)(?<!qux)/",
+            "/(?#comment)foo/",
+            "/(a)?(?(1)b|c)/",
+            "/[[:alpha:][:digit:]]+/",
+            "/[^\\]\\\\]/",
+            "/\\p{Lu}\\P{L}\\x{41}\\o{101}\\cA/u",
+            "/(a)\\1\\g{1}\\g{-1}\\k<x>(?<x>b)/",
+            "/\\/path\\/to/",
+            "/a # comment\n b/x",
+        ];
+
+        for pattern in patterns {
+            assert_eq!(get_error(pattern), None, "`{}` should be valid", pattern);
+        }
+    }
+
+    #[test]
+    fn test_delimiters() {
+        assert_eq!(get_error("#foo#"), None);
+        assert_eq!(get_error("~foo~i"), None);
+        assert_eq!(get_error("  /foo/"), None);
+        assert_eq!(get_error("(foo)"), None);
+        assert_eq!(get_error("{fo{1,2}}"), None);
+        assert_eq!(get_error("[a[b]c]"), None);
+        assert_eq!(get_error("<foo>"), None);
+
+        assert_eq!(get_error("").as_deref(), Some("Empty regular expression."));
+        assert_eq!(get_error("   ").as_deref(), Some("Empty regular expression."));
+        assert_eq!(get_error("foo").as_deref(), Some("Delimiter must not be alphanumeric, backslash, or NUL."));
+        assert_eq!(get_error("\\foo\\").as_deref(), Some("Delimiter must not be alphanumeric, backslash, or NUL."));
+        assert_eq!(get_error("/foo").as_deref(), Some("No ending delimiter `/` found."));
+        assert_eq!(get_error("/foo\\/").as_deref(), Some("No ending delimiter `/` found."));
+        assert_eq!(get_error("(foo").as_deref(), Some("No ending delimiter `)` found."));
+    }
+
+    #[test]
+    fn test_modifiers() {
+        assert_eq!(get_error("/foo/imsxADSUXJun"), None);
+        assert_eq!(get_error("/foo/i\n"), None);
+
+        assert_eq!(get_error("/foo/z").as_deref(), Some("Unknown modifier `z`."));
+        assert_eq!(
+            get_error("/foo/e").as_deref(),
+            Some("The `e` modifier is no longer supported, use `preg_replace_callback()` instead.")
+        );
+
+        // The `x` modifier ignores whitespace and comments.
+        assert_eq!(get_error("/a #)\n/x"), None);
+        assert_eq!(get_error("/a #)\n/").as_deref(), Some("Unmatched closing parenthesis."));
+
+        // The `J` modifier allows duplicate names.
+        assert_eq!(get_error("/(?<a>x)|(?<a>y)/J"), None);
+        assert_eq!(get_error("/(?<a>x)|(?<a>y)/").as_deref(), Some("Two named subpatterns have the same name `a`."));
+
+        // The `n` modifier disables automatic capturing.
+        assert_eq!(parse_pattern(b"/(a)(b)/n").captures, 0);
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let patterns = [
+            ("/(foo/", "Missing closing parenthesis."),
+            ("/foo)/", "Unmatched closing parenthesis."),
+            ("/*foo/", "Quantifier does not follow a repeatable item."),
+            ("/a{3,2}/", "Numbers out of order in `{}` quantifier."),
+            ("/a{70000}/", "Number too big in `{}` quantifier."),
+            ("/[a-/", "Missing terminating `]` for character class."),
+            ("/[z-a]/", "Range out of order in character class."),
+            ("/[[:foo:]]/", "Unknown POSIX class name `foo`."),
+            ("/(?<1a>x)/", "Subpattern name must start with a non-digit."),
+            ("/(?<>x)/", "Subpattern name must start with a non-digit."),
+            ("/(?P<-x>a)/", "Subpattern name expected."),
+            ("/(a)\\2/", "Reference to non-existent subpattern `2`."),
+            ("/\\k<missing>/", "Reference to non-existent subpattern `missing`."),
+            ("/\\g{0}/", "A numbered reference must not be zero."),
+            ("/(?(1)a|b|c)/", "Conditional subpattern contains more than two branches."),
+            ("/(?#comment/", "Missing `)` after `(?#` comment."),
+            ("/\\L/", "PCRE does not support `\\L`."),
+            ("/\\i/", "Unrecognized escape sequence `\\i`."),
+            ("/(?Q)/", "Unrecognized character after `(?` or `(?-`."),
+        ];
+
+        for (pattern, message) in patterns {
+            assert_eq!(get_error(pattern).as_deref(), Some(message), "`{}` should be invalid", pattern);
+        }
+    }
+
+    #[test]
+    fn test_error_span() {
+        let error = parse_pattern(b"/ab)/").error.unwrap();
+
+        assert_eq!((error.start, error.end), (3, 4));
+
+        let error = parse_pattern(b"/foo/iz").error.unwrap();
+
+        assert_eq!((error.start, error.end), (6, 7));
+    }
+
+    #[test]
+    fn test_captures() {
+        assert_eq!(parse_pattern(b"/foo/").captures, 0);
+        assert_eq!(parse_pattern(b"/(a)(?:b)(?<c>c)(?=d)/").captures, 2);
+        assert_eq!(parse_pattern(b"/((a)(b))/").captures, 3);
+        assert_eq!(parse_pattern(b"/\\(a\\)[(]/").captures, 0);
+    }
+
+    #[test]
+    fn test_backtracking() {
+        assert_eq!(parse_pattern(b"/(a+)+b/").backtracking.len(), 1);
+        assert_eq!(parse_pattern(b"/(\\w*)*$/").backtracking.len(), 1);
+        assert_eq!(parse_pattern(b"/(a+)b/").backtracking.len(), 0);
+        assert_eq!(parse_pattern(b"/(?>a+)+b/").backtracking.len(), 0);
+        assert_eq!(parse_pattern(b"/(ab)+/").backtracking.len(), 0);
+    }
+}
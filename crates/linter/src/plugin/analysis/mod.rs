@@ -0,0 +1,47 @@
+use crate::plugin::analysis::rules::call_arguments::CallArgumentsRule;
+use crate::plugin::analysis::rules::deprecated::DeprecatedRule;
+use crate::plugin::analysis::rules::format_string::FormatStringRule;
+use crate::plugin::analysis::rules::inheritance::InheritanceRule;
+use crate::plugin::analysis::rules::match_exhaustiveness::MatchExhaustivenessRule;
+use crate::plugin::analysis::rules::member_access::MemberAccessRule;
+use crate::plugin::analysis::rules::readonly_property::ReadonlyPropertyRule;
+use crate::plugin::analysis::rules::regex_backtracking::RegexBacktrackingRule;
+use crate::plugin::analysis::rules::regex_pattern::RegexPatternRule;
+use crate::plugin::analysis::rules::return_type::ReturnTypeRule;
+use crate::plugin::analysis::rules::unused_private_member::UnusedPrivateMemberRule;
+use crate::plugin::analysis::rules::unused_symbol::UnusedSymbolRule;
+
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rules;
+
+#[derive(Debug)]
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn get_name(&self) -> &'static str {
+        "analysis"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(CallArgumentsRule),
+            Box::new(ReturnTypeRule),
+            Box::new(MemberAccessRule),
+            Box::new(InheritanceRule),
+            Box::new(DeprecatedRule),
+            Box::new(MatchExhaustivenessRule),
+            Box::new(ReadonlyPropertyRule),
+            Box::new(UnusedPrivateMemberRule),
+            Box::new(UnusedSymbolRule),
+            Box::new(RegexPatternRule),
+            Box::new(RegexBacktrackingRule),
+            Box::new(FormatStringRule),
+        ]
+    }
+}
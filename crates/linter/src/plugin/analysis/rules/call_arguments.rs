@@ -0,0 +1,294 @@
+use ahash::HashMap;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::r#type::kind::union_kind;
+use mago_reporting::*;
+use mago_span::*;
+use mago_trinary::Trinary;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct CallArgumentsRule;
+
+impl Rule for CallArgumentsRule {
+    fn get_name(&self) -> &'static str {
+        "call-arguments"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for CallArgumentsRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Some(function_like) = get_callee_reflection(&function_call.function, context) else {
+            return;
+        };
+
+        check_arguments(&function_like, Some(&function_call.arguments), function_call.span(), context);
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Some(method) = get_method_reflection(&method_call.object, &method_call.method, context) else {
+            return;
+        };
+
+        check_arguments(&method, Some(&method_call.arguments), method_call.span(), context);
+    }
+
+    fn walk_in_null_safe_method_call<'ast>(
+        &self,
+        null_safe_method_call: &'ast NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_method_reflection(&null_safe_method_call.object, &null_safe_method_call.method, context)
+        else {
+            return;
+        };
+
+        check_arguments(&method, Some(&null_safe_method_call.arguments), null_safe_method_call.span(), context);
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_static_method_reflection(&static_method_call.class, &static_method_call.method, context)
+        else {
+            return;
+        };
+
+        check_arguments(&method, Some(&static_method_call.arguments), static_method_call.span(), context);
+    }
+
+    fn walk_in_instantiation<'ast>(&self, instantiation: &'ast Instantiation, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.get_class_like_reflection(&instantiation.class) else {
+            return;
+        };
+
+        let constructor_name = context.interner.intern("__construct");
+        let Some(constructor) = context.codebase.get_method(class_like, &constructor_name) else {
+            return;
+        };
+
+        check_arguments(&constructor, instantiation.arguments.as_ref(), instantiation.span(), context);
+    }
+}
+
+fn check_arguments(
+    function_like: &FunctionLikeReflection,
+    argument_list: Option<&ArgumentList>,
+    call_span: Span,
+    context: &mut LintContext<'_>,
+) {
+    let function_name = get_function_like_display_name(function_like, context);
+    let parameters = &function_like.parameters;
+    let variadic_parameter = parameters.last().filter(|parameter| parameter.is_variadic);
+
+    let mut assigned = vec![false; parameters.len()];
+    let mut named_arguments: HashMap<StringIdentifier, Span> = HashMap::default();
+    let mut has_unpacked_arguments = false;
+    let mut positional_arguments = 0;
+    let mut extra_arguments = vec![];
+
+    for argument in argument_list.iter().flat_map(|list| list.arguments.iter()) {
+        match argument {
+            Argument::Positional(argument) => {
+                if argument.ellipsis.is_some() {
+                    has_unpacked_arguments = true;
+
+                    continue;
+                }
+
+                let index = positional_arguments;
+                positional_arguments += 1;
+
+                match parameters.get(index) {
+                    Some(parameter) if !parameter.is_variadic => {
+                        assigned[index] = true;
+
+                        check_argument_type(&function_name, parameter, &argument.value, context);
+                    }
+                    _ => match variadic_parameter {
+                        Some(parameter) => check_argument_type(&function_name, parameter, &argument.value, context),
+                        None => extra_arguments.push(argument.span()),
+                    },
+                }
+            }
+            Argument::Named(argument) => {
+                let name = context.lookup(&argument.name.value);
+                let parameter_name = context.interner.intern(format!("${}", name));
+
+                if let Some(previous_span) = named_arguments.get(&parameter_name) {
+                    let issue = Issue::new(context.level(), format!("Duplicate named argument `{}`.", name))
+                        .with_annotation(
+                            Annotation::primary(argument.name.span())
+                                .with_message(format!("Argument `{}` is passed again here.", name)),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(*previous_span)
+                                .with_message(format!("Argument `{}` was first passed here.", name)),
+                        )
+                        .with_help("Remove the duplicate argument.");
+
+                    context.report(issue);
+
+                    continue;
+                }
+
+                named_arguments.insert(parameter_name, argument.name.span());
+                if argument.ellipsis.is_some() {
+                    has_unpacked_arguments = true;
+                }
+
+                let Some(index) =
+                    parameters.iter().position(|parameter| !parameter.is_variadic && parameter.name == parameter_name)
+                else {
+                    match variadic_parameter {
+                        Some(parameter) => check_argument_type(&function_name, parameter, &argument.value, context),
+                        None => {
+                            let issue = Issue::new(
+                                context.level(),
+                                format!("Unknown named argument `{}` passed to `{}`.", name, function_name),
+                            )
+                            .with_annotation(
+                                Annotation::primary(argument.name.span())
+                                    .with_message(format!("`{}` does not have a parameter named `{}`.", function_name, name)),
+                            )
+                            .with_annotation(
+                                Annotation::secondary(function_like.span)
+                                    .with_message(format!("`{}` is defined here.", function_name)),
+                            )
+                            .with_help("Use one of the parameter names of the called function, or pass the argument positionally.");
+
+                            context.report(issue);
+                        }
+                    }
+
+                    continue;
+                };
+
+                if assigned[index] {
+                    let issue = Issue::new(
+                        context.level(),
+                        format!("Named argument `{}` overwrites a previous argument.", name),
+                    )
+                    .with_annotation(
+                        Annotation::primary(argument.name.span())
+                            .with_message(format!("Parameter `${}` has already been passed positionally.", name)),
+                    )
+                    .with_help("Remove the named argument, or the positional argument it overwrites.");
+
+                    context.report(issue);
+
+                    continue;
+                }
+
+                assigned[index] = true;
+                if argument.ellipsis.is_none() {
+                    check_argument_type(&function_name, &parameters[index], &argument.value, context);
+                }
+            }
+        }
+    }
+
+    if let (Some(first), Some(last)) = (extra_arguments.first(), extra_arguments.last()) {
+        let issue = Issue::new(context.level(), format!("Too many arguments passed to `{}`.", function_name))
+            .with_annotation(Annotation::primary(first.join(*last)).with_message(format!(
+                "`{}` expects at most {} argument(s), {} given.",
+                function_name,
+                parameters.len(),
+                positional_arguments
+            )))
+            .with_annotation(
+                Annotation::secondary(function_like.span).with_message(format!("`{}` is defined here.", function_name)),
+            )
+            .with_help("Remove the extra arguments.");
+
+        context.report(issue);
+    }
+
+    if has_unpacked_arguments {
+        return;
+    }
+
+    let missing_parameters = parameters
+        .iter()
+        .enumerate()
+        .filter(|(index, parameter)| !assigned[*index] && !parameter.is_variadic && parameter.default.is_none())
+        .map(|(_, parameter)| format!("`{}`", context.lookup(&parameter.name)))
+        .collect::<Vec<_>>();
+
+    if missing_parameters.is_empty() {
+        return;
+    }
+
+    let issue = Issue::new(context.level(), format!("Too few arguments passed to `{}`.", function_name))
+        .with_annotation(
+            Annotation::primary(argument_list.map(|list| list.span()).unwrap_or(call_span))
+                .with_message(format!("Missing value for parameter(s) {}.", missing_parameters.join(", "))),
+        )
+        .with_annotation(
+            Annotation::secondary(function_like.span).with_message(format!("`{}` is defined here.", function_name)),
+        )
+        .with_help("Pass a value for each required parameter.");
+
+    context.report(issue);
+}
+
+fn check_argument_type(
+    function_name: &str,
+    parameter: &FunctionLikeParameterReflection,
+    value: &Expression,
+    context: &mut LintContext<'_>,
+) {
+    let Some(parameter_type) = parameter.get_type_reflection() else {
+        return;
+    };
+
+    let argument_kind = context.resolve_kind(value);
+    let accepted_kind = get_accepted_kind(&parameter_type.kind, is_strict_types_enabled(context));
+    let incompatible_kinds = get_incompatible_kinds(&argument_kind, &accepted_kind, context);
+    if incompatible_kinds.is_empty() {
+        return;
+    }
+
+    let parameter_name = context.lookup(&parameter.name);
+    let argument_key = argument_kind.get_key(context.interner);
+    let (message, annotation) = if context.is_contained_by(&argument_kind, &accepted_kind) == Trinary::False {
+        (
+            format!("Invalid argument type for parameter `{}` of `{}`.", parameter_name, function_name),
+            format!("This argument is of type `{}`.", argument_key),
+        )
+    } else {
+        let incompatible_key = union_kind(incompatible_kinds).get_key(context.interner);
+
+        (
+            format!(
+                "Possibly invalid argument type for parameter `{}` of `{}`: `{}` is not accepted.",
+                parameter_name, function_name, incompatible_key
+            ),
+            format!("This argument is of type `{}`, which includes `{}`.", argument_key, incompatible_key),
+        )
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(value.span()).with_message(annotation))
+        .with_annotation(Annotation::secondary(parameter_type.span).with_message(format!(
+            "Parameter `{}` expects `{}`.",
+            parameter_name,
+            parameter_type.kind.get_key(context.interner)
+        )))
+        .with_help(format!("Pass a value of type `{}` instead.", parameter_type.kind.get_key(context.interner)));
+
+    context.report(issue);
+}
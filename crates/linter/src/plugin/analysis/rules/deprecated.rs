@@ -0,0 +1,230 @@
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::deprecation::DeprecationReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DeprecatedRule;
+
+impl Rule for DeprecatedRule {
+    fn get_name(&self) -> &'static str {
+        "deprecated"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DeprecatedRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Some(function_like) = get_callee_reflection(&function_call.function, context) else {
+            return;
+        };
+
+        check_call(&function_like, function_call.function.span(), context);
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Some(method) = get_method_reflection(&method_call.object, &method_call.method, context) else {
+            return;
+        };
+
+        check_call(&method, method_call.method.span(), context);
+    }
+
+    fn walk_in_null_safe_method_call<'ast>(
+        &self,
+        null_safe_method_call: &'ast NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_method_reflection(&null_safe_method_call.object, &null_safe_method_call.method, context)
+        else {
+            return;
+        };
+
+        check_call(&method, null_safe_method_call.method.span(), context);
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_static_method_reflection(&static_method_call.class, &static_method_call.method, context)
+        else {
+            return;
+        };
+
+        check_call(&method, static_method_call.method.span(), context);
+    }
+
+    fn walk_in_instantiation<'ast>(&self, instantiation: &'ast Instantiation, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.get_class_like_reflection(&instantiation.class) else {
+            return;
+        };
+
+        if let Some(deprecation) = &class_like.deprecation {
+            let name = class_like.name.get_key(context.interner);
+
+            report(
+                format!("Instantiation of deprecated class `{}`.", name),
+                name,
+                deprecation,
+                instantiation.class.span(),
+                context,
+            );
+        }
+
+        let constructor_name = context.interner.intern("__construct");
+        if let Some(constructor) = context.codebase.get_method(class_like, &constructor_name) {
+            check_call(&constructor, instantiation.class.span(), context);
+        }
+    }
+
+    fn walk_in_extends<'ast>(&self, extends: &'ast Extends, context: &mut LintContext<'a>) {
+        for parent in extends.types.iter() {
+            check_inheritance(parent, "Extension of", context);
+        }
+    }
+
+    fn walk_in_implements<'ast>(&self, implements: &'ast Implements, context: &mut LintContext<'a>) {
+        for interface in implements.types.iter() {
+            check_inheritance(interface, "Implementation of", context);
+        }
+    }
+
+    fn walk_in_trait_use<'ast>(&self, trait_use: &'ast TraitUse, context: &mut LintContext<'a>) {
+        for trait_name in trait_use.trait_names.iter() {
+            check_inheritance(trait_name, "Use of", context);
+        }
+    }
+
+    fn walk_in_class_constant_access<'ast>(
+        &self,
+        class_constant_access: &'ast ClassConstantAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        let ClassLikeConstantSelector::Identifier(constant) = &class_constant_access.constant else {
+            return;
+        };
+
+        let Some(class_like) = context.get_class_like_reflection(&class_constant_access.class) else {
+            return;
+        };
+
+        let class_name = class_like.name.get_key(context.interner);
+        let constant_name = context.lookup(&constant.value);
+        let name = format!("{}::{}", class_name, constant_name);
+
+        if let Some(case) = class_like.get_enum_case(&constant.value) {
+            if let Some(deprecation) = &case.deprecation {
+                report(format!("Use of deprecated enum case `{}`.", name), name, deprecation, constant.span, context);
+            }
+        } else if let Some(class_like_constant) = class_like.get_constant(&constant.value) {
+            if let Some(deprecation) = &class_like_constant.deprecation {
+                report(format!("Use of deprecated constant `{}`.", name), name, deprecation, constant.span, context);
+            }
+        }
+    }
+
+    fn walk_in_expression<'ast>(&self, expression: &'ast Expression, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = expression else {
+            return;
+        };
+
+        let Some(constant) = context.get_constant_reflection(identifier) else {
+            return;
+        };
+
+        if let Some(deprecation) = &constant.deprecation {
+            let name = context.lookup(&constant.name.value).to_string();
+
+            report(format!("Use of deprecated constant `{}`.", name), name, deprecation, identifier.span(), context);
+        }
+    }
+}
+
+fn check_call(function_like: &FunctionLikeReflection, span: Span, context: &mut LintContext<'_>) {
+    let Some(deprecation) = &function_like.deprecation else {
+        return;
+    };
+
+    let name = format!("{}()", get_function_like_display_name(function_like, context));
+    let message = if function_like.is_method() {
+        format!("Call to deprecated method `{}`.", name)
+    } else {
+        format!("Call to deprecated function `{}`.", name)
+    };
+
+    report(message, name, deprecation, span, context);
+}
+
+fn check_inheritance(identifier: &Identifier, usage: &str, context: &mut LintContext<'_>) {
+    let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(identifier)) else {
+        return;
+    };
+
+    let Some(deprecation) = &class_like.deprecation else {
+        return;
+    };
+
+    let name = class_like.name.get_key(context.interner);
+
+    report(
+        format!("{} deprecated {} `{}`.", usage, get_class_like_kind(class_like), name),
+        name,
+        deprecation,
+        identifier.span(),
+        context,
+    );
+}
+
+fn report(
+    message: String,
+    name: String,
+    deprecation: &DeprecationReflection,
+    span: Span,
+    context: &mut LintContext<'_>,
+) {
+    // Deprecated symbols are free to use other deprecated symbols, as they are going away as well.
+    if is_in_deprecated_scope(span, context) {
+        return;
+    }
+
+    let mut issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(span).with_message(format!("`{}` is deprecated.", name)))
+        .with_annotation(
+            Annotation::secondary(deprecation.span).with_message(format!("`{}` is marked as deprecated here.", name)),
+        );
+
+    if let Some(deprecation_message) = deprecation.message {
+        issue = issue.with_note(context.lookup(&deprecation_message).to_string());
+    }
+
+    context.report(issue.with_help("Replace this usage, as the deprecated symbol may be removed in a future version."));
+}
+
+fn is_in_deprecated_scope(span: Span, context: &LintContext<'_>) -> bool {
+    context.codebase.get_enclosing_function_like(&span).is_some_and(|function_like| function_like.deprecation.is_some())
+        || context.codebase.get_enclosing_class_like(&span).is_some_and(|class_like| class_like.deprecation.is_some())
+}
+
+fn get_class_like_kind(class_like: &ClassLikeReflection) -> &'static str {
+    if class_like.is_interface() {
+        "interface"
+    } else if class_like.is_trait() {
+        "trait"
+    } else if class_like.is_enum() {
+        "enum"
+    } else {
+        "class"
+    }
+}
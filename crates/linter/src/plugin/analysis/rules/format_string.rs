@@ -0,0 +1,331 @@
+use mago_ast::*;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::literal::DecodedString;
+use crate::plugin::analysis::rules::utils::*;
+use crate::printf::parse_printf_format;
+use crate::printf::parse_scanf_format;
+use crate::printf::Format;
+use crate::printf::Placeholder;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct FormatStringRule;
+
+/// How the values of a format string are passed to a function.
+#[derive(Debug, Clone, Copy)]
+enum Values {
+    /// The values are passed as arguments, starting at the given position.
+    Variadic(usize),
+    /// The values are passed as an array, at the given position.
+    Array(usize),
+}
+
+impl Rule for FormatStringRule {
+    fn get_name(&self) -> &'static str {
+        "format-string"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for FormatStringRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let name = context.lookup_function_name(identifier).to_ascii_lowercase();
+        let (format_position, values, is_scanf) = match name.as_str() {
+            "sprintf" | "printf" => (0, Values::Variadic(1), false),
+            "fprintf" => (1, Values::Variadic(2), false),
+            "vsprintf" | "vprintf" => (0, Values::Array(1), false),
+            "vfprintf" => (1, Values::Array(2), false),
+            "sscanf" | "fscanf" => (1, Values::Variadic(2), true),
+            _ => return,
+        };
+
+        let Some(Expression::Literal(Literal::String(literal))) =
+            get_argument(&function_call.arguments, format_position, "format", context)
+        else {
+            return;
+        };
+
+        let Some(decoded) = DecodedString::decode(literal, context) else {
+            return;
+        };
+
+        let format = if is_scanf { parse_scanf_format(&decoded.value) } else { parse_printf_format(&decoded.value) };
+        let call = FormatCall { name, function_call, literal, decoded, format, values, is_scanf };
+
+        if let Some(error) = &call.format.error {
+            context.report(
+                Issue::new(context.level(), format!("Invalid format string passed to `{}()`.", call.name))
+                    .with_annotations([
+                        Annotation::primary(call.decoded.span(error.start, error.end))
+                            .with_message(error.message.as_str()),
+                        Annotation::secondary(literal.span),
+                    ])
+                    .with_note(format!("`{}()` throws a `ValueError` when given an invalid format string.", call.name))
+                    .with_help("Fix the format string, or escape literal percent signs as `%%`."),
+            );
+
+            return;
+        }
+
+        if !check_mixed_placeholders(&call, context) && is_scanf {
+            return;
+        }
+
+        let Some(arguments) = get_values(function_call, values, context) else {
+            return;
+        };
+
+        // Without variables, `sscanf()` and `fscanf()` return the parsed values as an array.
+        if is_scanf && arguments.is_empty() {
+            return;
+        }
+
+        check_argument_count(&call, &arguments, context);
+        if !is_scanf {
+            check_argument_types(&call, &arguments, context);
+        }
+    }
+}
+
+/// A call to a function accepting a format string, along with its parsed format string.
+struct FormatCall<'ast> {
+    name: String,
+    function_call: &'ast FunctionCall,
+    literal: &'ast LiteralString,
+    decoded: DecodedString,
+    format: Format,
+    values: Values,
+    is_scanf: bool,
+}
+
+impl FormatCall<'_> {
+    fn placeholder_span(&self, placeholder: &Placeholder) -> Span {
+        self.decoded.span(placeholder.start, placeholder.end)
+    }
+}
+
+/// Returns the values of the format string passed to the function, if they are known.
+fn get_values<'ast>(
+    function_call: &'ast FunctionCall,
+    values: Values,
+    context: &LintContext<'_>,
+) -> Option<Vec<&'ast Expression>> {
+    match values {
+        Values::Variadic(position) => {
+            let mut arguments = vec![];
+            for (index, argument) in function_call.arguments.arguments.iter().enumerate() {
+                match argument {
+                    Argument::Positional(argument) if argument.ellipsis.is_none() => {
+                        if index >= position {
+                            arguments.push(&argument.value);
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+
+            Some(arguments)
+        }
+        Values::Array(position) => {
+            let elements = match get_argument(&function_call.arguments, position, "values", context)? {
+                Expression::Array(array) => &array.elements,
+                Expression::LegacyArray(array) => &array.elements,
+                _ => return None,
+            };
+
+            let mut arguments = vec![];
+            for element in elements.iter() {
+                match element {
+                    ArrayElement::KeyValue(element) => arguments.push(element.value.as_ref()),
+                    ArrayElement::Value(element) => arguments.push(element.value.as_ref()),
+                    _ => return None,
+                }
+            }
+
+            Some(arguments)
+        }
+    }
+}
+
+/// Reports format strings mixing positional and sequential placeholders, returning `false` if it does.
+fn check_mixed_placeholders(call: &FormatCall<'_>, context: &mut LintContext<'_>) -> bool {
+    let positional = call.format.placeholders.iter().find(|placeholder| placeholder.positional);
+    let sequential = call.format.placeholders.iter().find(|placeholder| !placeholder.positional);
+    let (Some(positional), Some(sequential)) = (positional, sequential) else {
+        return true;
+    };
+
+    let note = if call.is_scanf {
+        format!("`{}()` throws a `ValueError` when positional and sequential placeholders are mixed.", call.name)
+    } else {
+        "Sequential placeholders take the arguments in order, regardless of the positional placeholders.".to_string()
+    };
+
+    context.report(
+        Issue::new(
+            context.level(),
+            format!("Format string passed to `{}()` mixes positional and sequential placeholders.", call.name),
+        )
+        .with_annotations([
+            Annotation::primary(call.placeholder_span(positional))
+                .with_message("This placeholder refers to its argument by position..."),
+            Annotation::primary(call.placeholder_span(sequential))
+                .with_message("...while this one takes the next argument."),
+        ])
+        .with_note(note)
+        .with_help("Use either positional placeholders, such as `%1$s`, or sequential placeholders, such as `%s`."),
+    );
+
+    false
+}
+
+fn check_argument_count(call: &FormatCall<'_>, arguments: &[&Expression], context: &mut LintContext<'_>) {
+    let placeholders = &call.format.placeholders;
+    let required = placeholders.iter().map(|placeholder| placeholder.argument + 1).max().unwrap_or(0);
+    if required > arguments.len() {
+        let (value, note) = match call.values {
+            Values::Variadic(_) if call.is_scanf => (
+                "variable",
+                format!(
+                    "`{}()` throws a `ValueError` when the number of variables does not match the number of placeholders.",
+                    call.name
+                ),
+            ),
+            Values::Variadic(_) => (
+                "argument",
+                format!("`{}()` throws an `ArgumentCountError` when given too few arguments.", call.name),
+            ),
+            Values::Array(_) => ("value", format!("`{}()` throws a `ValueError` when given too few values.", call.name)),
+        };
+
+        let mut issue = Issue::new(
+            context.level(),
+            format!(
+                "Format string passed to `{}()` requires {} {}{}, but {} {} given.",
+                call.name,
+                required,
+                value,
+                if required == 1 { "" } else { "s" },
+                arguments.len(),
+                if arguments.len() == 1 { "is" } else { "are" }
+            ),
+        );
+
+        for placeholder in placeholders.iter().filter(|placeholder| placeholder.argument >= arguments.len()) {
+            issue = issue.with_annotation(
+                Annotation::primary(call.placeholder_span(placeholder))
+                    .with_message(format!("This placeholder has no corresponding {}.", value)),
+            );
+        }
+
+        context.report(
+            issue.with_annotation(Annotation::secondary(call.function_call.span())).with_note(note).with_help(format!(
+                "Pass {} {} for each placeholder, or remove the extra placeholders.",
+                if value == "argument" { "an" } else { "a" },
+                value
+            )),
+        );
+    }
+
+    // Unlike missing values, extra values are silently ignored by the function, so they are only reported
+    // as a warning, unless the rule is configured with a lower level.
+    let unused_level = context.level().max(Level::Warning);
+    for (index, argument) in arguments.iter().enumerate() {
+        if placeholders.iter().any(|placeholder| placeholder.argument == index) {
+            continue;
+        }
+
+        context.report(
+            Issue::new(unused_level, format!("Value passed to `{}()` is not used by the format string.", call.name))
+                .with_annotations([
+                    Annotation::primary(argument.span()).with_message("This value is never used."),
+                    Annotation::secondary(call.literal.span)
+                        .with_message(format!("The format string has no placeholder for value #{}.", index + 1)),
+                ])
+                .with_help("Remove the value, or add a placeholder for it."),
+        );
+    }
+}
+
+fn check_argument_types(call: &FormatCall<'_>, arguments: &[&Expression], context: &mut LintContext<'_>) {
+    for placeholder in call.format.placeholders.iter() {
+        let Some(argument) = arguments.get(placeholder.argument) else {
+            continue;
+        };
+
+        let kind = context.resolve_kind(argument);
+        if is_compatible(&kind, placeholder, context) {
+            continue;
+        }
+
+        let expected = match placeholder.specifier {
+            b's' => "a string",
+            b'e' | b'E' | b'f' | b'F' | b'g' | b'G' | b'h' | b'H' => "a float",
+            _ => "an integer",
+        };
+
+        let kind = kind.get_key(context.interner);
+        let text = String::from_utf8_lossy(&call.decoded.value[placeholder.start..placeholder.end]);
+
+        context.report(
+            Issue::new(
+                context.level(),
+                format!("Argument of type `{}` is not compatible with the `{}` placeholder.", kind, text),
+            )
+            .with_annotations([
+                Annotation::primary(argument.span()).with_message(format!("This argument is of type `{}`.", kind)),
+                Annotation::secondary(call.placeholder_span(placeholder))
+                    .with_message(format!("This placeholder expects {}.", expected)),
+            ])
+            .with_help(format!("Pass {} instead.", expected)),
+        );
+    }
+}
+
+/// Determines whether a value of the given kind can be formatted by the given placeholder.
+///
+/// Only kinds that are known to be incompatible are rejected: arrays, which are formatted as `Array`,
+/// `0` or `1`, and objects, which cannot be converted to numbers, nor to strings unless they are stringable.
+fn is_compatible(kind: &TypeKind, placeholder: &Placeholder, context: &LintContext<'_>) -> bool {
+    if kind.is_array() {
+        return false;
+    }
+
+    if !kind.is_object() {
+        return true;
+    }
+
+    placeholder.specifier == b's' && is_stringable(kind, context)
+}
+
+fn is_stringable(kind: &TypeKind, context: &LintContext<'_>) -> bool {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().all(|kind| is_stringable(kind, context)),
+        TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => {
+            let Some(class_like) = context.codebase.get_named_class_like(name) else {
+                return true;
+            };
+
+            class_like
+                .methods
+                .appering_members
+                .keys()
+                .any(|method| context.interner.lookup(method).eq_ignore_ascii_case("__toString"))
+        }
+        TypeKind::Object(ObjectTypeKind::EnumCase { .. } | ObjectTypeKind::Generator { .. })
+        | TypeKind::Callable(CallableTypeKind::Closure { .. }) => false,
+        _ => true,
+    }
+}
@@ -0,0 +1,870 @@
+use std::borrow::Cow;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::constant::ClassLikeConstantReflection;
+use mago_reflection::class_like::member::ClassLikeMemberVisibilityReflection;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_trinary::Trinary;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Methods every enum provides natively, which may be required by the interfaces it implements.
+const NATIVE_ENUM_METHODS: &[&str] = &["cases", "from", "tryFrom"];
+
+#[derive(Clone, Debug)]
+pub struct InheritanceRule;
+
+impl Rule for InheritanceRule {
+    fn get_name(&self) -> &'static str {
+        "inheritance"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for InheritanceRule {
+    fn walk_in_class<'ast>(&self, class: &'ast Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&class.name)) else {
+            return;
+        };
+
+        check_class_like(class_like, class.span(), class.name.span, &class.members, context);
+    }
+
+    fn walk_in_anonymous_class<'ast>(&self, anonymous_class: &'ast AnonymousClass, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_class_like(ClassLikeName::AnonymousClass(anonymous_class.span()))
+        else {
+            return;
+        };
+
+        check_class_like(
+            class_like,
+            anonymous_class.span(),
+            anonymous_class.class.span,
+            &anonymous_class.members,
+            context,
+        );
+    }
+
+    fn walk_in_interface<'ast>(&self, interface: &'ast Interface, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&interface.name))
+        else {
+            return;
+        };
+
+        check_class_like(class_like, interface.span(), interface.name.span, &interface.members, context);
+    }
+
+    fn walk_in_enum<'ast>(&self, r#enum: &'ast Enum, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&r#enum.name)) else {
+            return;
+        };
+
+        check_class_like(class_like, r#enum.span(), r#enum.name.span, &r#enum.members, context);
+    }
+}
+
+fn check_class_like(
+    class_like: &ClassLikeReflection,
+    span: Span,
+    name_span: Span,
+    members: &Sequence<ClassLikeMember>,
+    context: &mut LintContext<'_>,
+) {
+    // Another class-like with the same name may be defined elsewhere; only check the one defined here.
+    if class_like.span != span {
+        return;
+    }
+
+    check_extended_class(class_like, context);
+
+    for member in members.iter() {
+        if let ClassLikeMember::Method(method) = member {
+            check_method_overrides(class_like, method, context);
+        }
+    }
+
+    check_constant_overrides(class_like, context);
+    check_property_overrides(class_like, context);
+
+    let is_concrete = match class_like.name {
+        ClassLikeName::Class(_) => !class_like.is_abstract,
+        ClassLikeName::Enum(_) | ClassLikeName::AnonymousClass(_) => true,
+        ClassLikeName::Interface(_) | ClassLikeName::Trait(_) => false,
+    };
+
+    if is_concrete {
+        check_missing_implementations(class_like, name_span, context);
+    }
+}
+
+fn check_extended_class(class_like: &ClassLikeReflection, context: &mut LintContext<'_>) {
+    let Some(parent_name) = class_like.inheritance.direct_extended_class else {
+        return;
+    };
+
+    let Some(parent) = context.codebase.get_class(&parent_name.value) else {
+        return;
+    };
+
+    if !parent.is_final {
+        return;
+    }
+
+    let class_name = class_like.name.get_key(context.interner);
+    let parent_class_name = parent.name.get_key(context.interner);
+
+    let issue = Issue::new(
+        context.level(),
+        format!("Class `{}` cannot extend final class `{}`.", class_name, parent_class_name),
+    )
+    .with_annotation(
+        Annotation::primary(parent_name.span).with_message(format!("`{}` is extended here.", parent_class_name)),
+    )
+    .with_annotation(
+        Annotation::secondary(parent.span).with_message(format!("`{}` is declared final here.", parent_class_name)),
+    )
+    .with_help(format!("Remove the `final` modifier from `{}`, or stop extending it.", parent_class_name));
+
+    context.report(issue);
+}
+
+fn check_method_overrides(class_like: &ClassLikeReflection, method: &Method, context: &mut LintContext<'_>) {
+    let Some(child) = class_like.get_method(&method.name.value) else {
+        return;
+    };
+
+    let is_constructor = context.lookup(&method.name.value).eq_ignore_ascii_case("__construct");
+    for (parent_class_like, parent) in get_overridden_methods(class_like, &method.name.value, context) {
+        if parent.is_final {
+            report_final_method_override(child, &parent, context);
+        }
+
+        // The signature of a constructor is only enforced if it is declared by an interface, or is abstract.
+        if is_constructor && !parent.is_abstract && !parent_class_like.is_interface() {
+            continue;
+        }
+
+        check_static_modifier(child, &parent, context);
+        check_visibility(child, &parent, context);
+        check_parameters(child, &parent, context);
+        check_return_type(child, &parent, context);
+    }
+}
+
+/// Returns the methods overridden by the method with the given name of the given class-like,
+/// along with the class-likes they were found in.
+fn get_overridden_methods<'a>(
+    class_like: &ClassLikeReflection,
+    method_name: &StringIdentifier,
+    context: &LintContext<'a>,
+) -> Vec<(&'a ClassLikeReflection, Cow<'a, FunctionLikeReflection>)> {
+    let mut overridden: Vec<(&'a ClassLikeReflection, Cow<'a, FunctionLikeReflection>)> = vec![];
+    for parent in get_parent_class_likes(class_like, context) {
+        let Some(method) = get_overridable_method(parent, method_name, context) else {
+            continue;
+        };
+
+        // The same method may be reached through several interfaces, e.g. one extended by another.
+        if overridden.iter().any(|(_, existing)| existing.name == method.name) {
+            continue;
+        }
+
+        overridden.push((parent, method));
+    }
+
+    overridden
+}
+
+fn get_overridable_method<'a>(
+    class_like: &'a ClassLikeReflection,
+    method_name: &StringIdentifier,
+    context: &LintContext<'a>,
+) -> Option<Cow<'a, FunctionLikeReflection>> {
+    let method = context.codebase.get_method(class_like, method_name)?;
+    if method.is_magic || method.visibility_reflection.is_some_and(|visibility| visibility.is_private()) {
+        return None;
+    }
+
+    Some(method)
+}
+
+fn report_final_method_override(
+    child: &FunctionLikeReflection,
+    parent: &FunctionLikeReflection,
+    context: &mut LintContext<'_>,
+) {
+    let parent_name = parent.name.get_key(context.interner);
+
+    let issue = Issue::new(context.level(), format!("Cannot override final method `{}()`.", parent_name))
+        .with_annotation(
+            Annotation::primary(get_method_name_span(child))
+                .with_message(format!("`{}()` is overridden here.", parent_name)),
+        )
+        .with_annotation(
+            Annotation::secondary(parent.span).with_message(format!("`{}()` is declared final here.", parent_name)),
+        )
+        .with_help("Remove this method, or remove the `final` modifier from the overridden method.");
+
+    context.report(issue);
+}
+
+fn check_static_modifier(
+    child: &FunctionLikeReflection,
+    parent: &FunctionLikeReflection,
+    context: &mut LintContext<'_>,
+) {
+    if child.is_static == parent.is_static {
+        return;
+    }
+
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let (message, help) = if parent.is_static {
+        (
+            format!("Non-static method `{}()` cannot override static method `{}()`.", child_name, parent_name),
+            "Make this method static.",
+        )
+    } else {
+        (
+            format!("Static method `{}()` cannot override non-static method `{}()`.", child_name, parent_name),
+            "Remove the `static` modifier from this method.",
+        )
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(get_method_name_span(child)).with_message("This method is overriding."))
+        .with_annotation(
+            Annotation::secondary(parent.span).with_message(format!("`{}()` is declared here.", parent_name)),
+        )
+        .with_help(help);
+
+    context.report(issue);
+}
+
+fn check_visibility(child: &FunctionLikeReflection, parent: &FunctionLikeReflection, context: &mut LintContext<'_>) {
+    let child_visibility = get_visibility_rank(child.visibility_reflection);
+    let parent_visibility = get_visibility_rank(parent.visibility_reflection);
+    if child_visibility >= parent_visibility {
+        return;
+    }
+
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let parent_visibility_name = get_visibility_name(parent.visibility_reflection);
+
+    let span =
+        child.visibility_reflection.map(|visibility| visibility.span()).unwrap_or_else(|| get_method_name_span(child));
+
+    let issue = Issue::new(
+        context.level(),
+        format!(
+            "Method `{}()` cannot be {}, as it overrides {} method `{}()`.",
+            child_name,
+            get_visibility_name(child.visibility_reflection),
+            parent_visibility_name,
+            parent_name
+        ),
+    )
+    .with_annotation(
+        Annotation::primary(span)
+            .with_message(format!("This method is declared {}.", get_visibility_name(child.visibility_reflection))),
+    )
+    .with_annotation(
+        Annotation::secondary(parent.span)
+            .with_message(format!("`{}()` is declared {} here.", parent_name, parent_visibility_name)),
+    )
+    .with_help(format!("Declare this method as {}.", parent_visibility_name));
+
+    context.report(issue);
+}
+
+fn check_parameters(child: &FunctionLikeReflection, parent: &FunctionLikeReflection, context: &mut LintContext<'_>) {
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+
+    let child_required = child.parameters.iter().filter(|parameter| is_required(parameter)).count();
+    let parent_required = parent.parameters.iter().filter(|parameter| is_required(parameter)).count();
+    if child_required > parent_required {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Method `{}()` requires {} argument(s), but the overridden `{}()` only requires {}.",
+                child_name, child_required, parent_name, parent_required
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(get_method_name_span(child)).with_message("This method requires more arguments."),
+        )
+        .with_annotation(
+            Annotation::secondary(parent.span).with_message(format!("`{}()` is declared here.", parent_name)),
+        )
+        .with_help("Give the additional parameters a default value.");
+
+        context.report(issue);
+    }
+
+    let child_is_variadic = child.parameters.last().is_some_and(|parameter| parameter.is_variadic);
+    let parent_is_variadic = parent.parameters.last().is_some_and(|parameter| parameter.is_variadic);
+    if !child_is_variadic && (child.parameters.len() < parent.parameters.len() || parent_is_variadic) {
+        let issue = Issue::new(
+            context.level(),
+            format!("Method `{}()` accepts fewer arguments than the overridden `{}()`.", child_name, parent_name),
+        )
+        .with_annotation(
+            Annotation::primary(get_method_name_span(child)).with_message("This method accepts fewer arguments."),
+        )
+        .with_annotation(
+            Annotation::secondary(parent.span).with_message(format!("`{}()` is declared here.", parent_name)),
+        )
+        .with_help(format!("Declare all the parameters of `{}()` in this method.", parent_name));
+
+        context.report(issue);
+    }
+
+    for (index, parent_parameter) in parent.parameters.iter().enumerate() {
+        let child_parameter = match child.parameters.get(index) {
+            Some(child_parameter) => child_parameter,
+            None if child_is_variadic => &child.parameters[child.parameters.len() - 1],
+            None => break,
+        };
+
+        check_parameter(child, child_parameter, parent, parent_parameter, context);
+    }
+}
+
+fn check_parameter(
+    child: &FunctionLikeReflection,
+    child_parameter: &FunctionLikeParameterReflection,
+    parent: &FunctionLikeReflection,
+    parent_parameter: &FunctionLikeParameterReflection,
+    context: &mut LintContext<'_>,
+) {
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let child_parameter_name = context.lookup(&child_parameter.name).to_string();
+    let parent_parameter_name = context.lookup(&parent_parameter.name).to_string();
+    let child_parameter_span = get_parameter_span(child, child_parameter);
+
+    if child_parameter.is_passed_by_reference != parent_parameter.is_passed_by_reference {
+        let (message, help) = if parent_parameter.is_passed_by_reference {
+            (
+                format!(
+                    "Parameter `{}` of `{}()` must be passed by reference, as it is in the overridden `{}()`.",
+                    child_parameter_name, child_name, parent_name
+                ),
+                format!("Declare `{}` as passed by reference using `&`.", child_parameter_name),
+            )
+        } else {
+            (
+                format!(
+                    "Parameter `{}` of `{}()` cannot be passed by reference, as it is not in the overridden `{}()`.",
+                    child_parameter_name, child_name, parent_name
+                ),
+                format!("Remove the `&` from `{}`.", child_parameter_name),
+            )
+        };
+
+        let issue = Issue::new(context.level(), message)
+            .with_annotation(Annotation::primary(child_parameter_span).with_message("This parameter is declared here."))
+            .with_annotation(Annotation::secondary(get_parameter_span(parent, parent_parameter)).with_message(format!(
+                "Parameter `{}` of `{}()` is declared here.",
+                parent_parameter_name, parent_name
+            )))
+            .with_help(help);
+
+        context.report(issue);
+    }
+
+    // Only native types are enforced by PHP; an untyped parameter accepts anything.
+    let Some(child_type) = &child_parameter.type_reflection else {
+        return;
+    };
+
+    let child_kind = get_parameter_kind(child_parameter, &child_type.kind);
+    let parent_kind = match &parent_parameter.type_reflection {
+        Some(parent_type) => get_parameter_kind(parent_parameter, &parent_type.kind),
+        None => mixed_kind(false),
+    };
+
+    if !is_incompatible(&parent_kind, &child_kind, context) {
+        return;
+    }
+
+    let child_type_name = child_kind.get_key(context.interner);
+    let parent_type_name = parent_kind.get_key(context.interner);
+
+    let issue = Issue::new(
+        context.level(),
+        format!(
+            "Parameter `{}` of `{}()` must accept `{}`, as it overrides `{}()`.",
+            child_parameter_name, child_name, parent_type_name, parent_name
+        ),
+    )
+    .with_annotation(
+        Annotation::primary(child_type.span).with_message(format!("This parameter accepts `{}`.", child_type_name)),
+    )
+    .with_annotation(Annotation::secondary(get_parameter_span(parent, parent_parameter)).with_message(format!(
+        "Parameter `{}` of `{}()` accepts `{}`.",
+        parent_parameter_name, parent_name, parent_type_name
+    )))
+    .with_help(format!("Widen the type of `{}` to accept `{}`.", child_parameter_name, parent_type_name));
+
+    context.report(issue);
+}
+
+fn check_return_type(child: &FunctionLikeReflection, parent: &FunctionLikeReflection, context: &mut LintContext<'_>) {
+    let Some(parent_return_type) = &parent.return_type_reflection else {
+        return;
+    };
+
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let parent_kind = &parent_return_type.type_reflection.kind;
+    let parent_type_name = parent_kind.get_key(context.interner);
+
+    let issue = match &child.return_type_reflection {
+        None => Issue::new(
+            context.level(),
+            format!(
+                "Method `{}()` must declare a return type compatible with `{}`, as it overrides `{}()`.",
+                child_name, parent_type_name, parent_name
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(get_method_name_span(child))
+                .with_message("This method does not declare a return type."),
+        ),
+        Some(child_return_type) => {
+            let child_kind = &child_return_type.type_reflection.kind;
+            let narrows_static = is_static_narrowed(child_kind, parent_kind);
+            if !narrows_static && !is_incompatible(child_kind, parent_kind, context) {
+                return;
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                format!(
+                    "Return type of `{}()` must be compatible with `{}`, as it overrides `{}()`.",
+                    child_name, parent_type_name, parent_name
+                ),
+            )
+            .with_annotation(
+                Annotation::primary(child_return_type.type_reflection.span)
+                    .with_message(format!("This method returns `{}`.", child_kind.get_key(context.interner))),
+            );
+
+            if narrows_static {
+                issue.with_note("`static` refers to the class of the object, which may be a descendant of the overriding class, so it can only be overridden by `static`.")
+            } else {
+                issue
+            }
+        }
+    }
+    .with_annotation(
+        Annotation::secondary(parent_return_type.type_reflection.span)
+            .with_message(format!("`{}()` returns `{}`.", parent_name, parent_type_name)),
+    )
+    .with_help(format!("Declare a return type that is a subtype of `{}`.", parent_type_name));
+
+    context.report(issue);
+}
+
+fn check_constant_overrides(class_like: &ClassLikeReflection, context: &mut LintContext<'_>) {
+    let mut constants = class_like
+        .constants
+        .values()
+        .filter(|constant| constant.name.class_like == class_like.name)
+        .collect::<Vec<_>>();
+    constants.sort_by_key(|constant| constant.item_span);
+
+    let parents = get_parent_class_likes(class_like, context);
+    for child in constants {
+        let mut overridden: Vec<&ClassLikeConstantReflection> = vec![];
+        for parent_class_like in parents.iter() {
+            let Some(parent) = parent_class_like.get_constant(&child.name.member.value) else {
+                continue;
+            };
+
+            if parent.visibility_reflection.is_some_and(|visibility| visibility.is_private())
+                || overridden.iter().any(|existing| existing.name == parent.name)
+            {
+                continue;
+            }
+
+            overridden.push(parent);
+        }
+
+        for parent in overridden {
+            check_constant_override(child, parent, context);
+        }
+    }
+}
+
+fn check_constant_override(
+    child: &ClassLikeConstantReflection,
+    parent: &ClassLikeConstantReflection,
+    context: &mut LintContext<'_>,
+) {
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+
+    if parent.is_final {
+        let issue = Issue::new(context.level(), format!("Cannot override final constant `{}`.", parent_name))
+            .with_annotation(
+                Annotation::primary(child.name.member.span)
+                    .with_message(format!("`{}` is overridden here.", parent_name)),
+            )
+            .with_annotation(
+                Annotation::secondary(parent.item_span)
+                    .with_message(format!("`{}` is declared final here.", parent_name)),
+            )
+            .with_help("Remove this constant, or remove the `final` modifier from the overridden constant.");
+
+        context.report(issue);
+    }
+
+    if get_visibility_rank(child.visibility_reflection) >= get_visibility_rank(parent.visibility_reflection) {
+        return;
+    }
+
+    let child_visibility_name = get_visibility_name(child.visibility_reflection);
+    let parent_visibility_name = get_visibility_name(parent.visibility_reflection);
+    let span = child.visibility_reflection.map(|visibility| visibility.span()).unwrap_or(child.name.member.span);
+
+    let issue = Issue::new(
+        context.level(),
+        format!(
+            "Constant `{}` cannot be {}, as it overrides {} constant `{}`.",
+            child_name, child_visibility_name, parent_visibility_name, parent_name
+        ),
+    )
+    .with_annotation(
+        Annotation::primary(span).with_message(format!("This constant is declared {}.", child_visibility_name)),
+    )
+    .with_annotation(
+        Annotation::secondary(parent.item_span)
+            .with_message(format!("`{}` is declared {} here.", parent_name, parent_visibility_name)),
+    )
+    .with_help(format!("Declare this constant as {}.", parent_visibility_name));
+
+    context.report(issue);
+}
+
+fn check_property_overrides(class_like: &ClassLikeReflection, context: &mut LintContext<'_>) {
+    let mut properties = class_like
+        .properties
+        .members
+        .values()
+        .filter(|property| property.name.class_like == class_like.name)
+        .collect::<Vec<_>>();
+    properties.sort_by_key(|property| property.item_span);
+
+    let parents = get_parent_class_likes(class_like, context);
+    for child in properties {
+        let mut overridden: Vec<Cow<'_, PropertyReflection>> = vec![];
+        for parent_class_like in parents.iter() {
+            let Some(parent) = context.codebase.get_property(parent_class_like, &child.name.member.value) else {
+                continue;
+            };
+
+            if parent.read_visibility_reflection.is_some_and(|visibility| visibility.is_private())
+                || overridden.iter().any(|existing| existing.name == parent.name)
+            {
+                continue;
+            }
+
+            overridden.push(parent);
+        }
+
+        for parent in overridden {
+            check_property_visibility(child, &parent, context);
+            check_property_type(child, &parent, context);
+        }
+    }
+}
+
+fn check_property_visibility(child: &PropertyReflection, parent: &PropertyReflection, context: &mut LintContext<'_>) {
+    if get_visibility_rank(child.read_visibility_reflection) >= get_visibility_rank(parent.read_visibility_reflection) {
+        return;
+    }
+
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let child_visibility_name = get_visibility_name(child.read_visibility_reflection);
+    let parent_visibility_name = get_visibility_name(parent.read_visibility_reflection);
+    let span = child.read_visibility_reflection.map(|visibility| visibility.span()).unwrap_or(child.name.member.span);
+
+    let issue = Issue::new(
+        context.level(),
+        format!(
+            "Property `{}` cannot be {}, as it overrides {} property `{}`.",
+            child_name, child_visibility_name, parent_visibility_name, parent_name
+        ),
+    )
+    .with_annotation(
+        Annotation::primary(span).with_message(format!("This property is declared {}.", child_visibility_name)),
+    )
+    .with_annotation(
+        Annotation::secondary(parent.item_span)
+            .with_message(format!("`{}` is declared {} here.", parent_name, parent_visibility_name)),
+    )
+    .with_help(format!("Declare this property as {}.", parent_visibility_name));
+
+    context.report(issue);
+}
+
+/// Checks that the native type of the given property is the same as the one of the property it overrides,
+/// as property types are invariant.
+fn check_property_type(child: &PropertyReflection, parent: &PropertyReflection, context: &mut LintContext<'_>) {
+    let is_invariant = match (&child.type_reflection, &parent.type_reflection) {
+        (None, None) => true,
+        (Some(child_type), Some(parent_type)) => {
+            !is_incompatible(&child_type.kind, &parent_type.kind, context)
+                && !is_incompatible(&parent_type.kind, &child_type.kind, context)
+        }
+        _ => false,
+    };
+
+    if is_invariant {
+        return;
+    }
+
+    let child_name = child.name.get_key(context.interner);
+    let parent_name = parent.name.get_key(context.interner);
+    let (span, child_type_name) = match &child.type_reflection {
+        Some(child_type) => (child_type.span, format!("`{}`", child_type.kind.get_key(context.interner))),
+        None => (child.name.member.span, "no type".to_string()),
+    };
+
+    let (message, parent_annotation, help) = match &parent.type_reflection {
+        Some(parent_type) => {
+            let parent_type_name = parent_type.kind.get_key(context.interner);
+
+            (
+                format!(
+                    "Type of property `{}` must be `{}`, as it overrides `{}`.",
+                    child_name, parent_type_name, parent_name
+                ),
+                Annotation::secondary(parent_type.span)
+                    .with_message(format!("`{}` is declared as `{}` here.", parent_name, parent_type_name)),
+                format!("Declare this property as `{}`.", parent_type_name),
+            )
+        }
+        None => (
+            format!(
+                "Property `{}` cannot declare a type, as it overrides untyped property `{}`.",
+                child_name, parent_name
+            ),
+            Annotation::secondary(parent.item_span)
+                .with_message(format!("`{}` is declared without a type here.", parent_name)),
+            "Remove the type of this property.".to_string(),
+        ),
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(span).with_message(format!("This property has {}.", child_type_name)))
+        .with_annotation(parent_annotation)
+        .with_note("Property types are invariant: an overriding property must have the same type.")
+        .with_help(help);
+
+    context.report(issue);
+}
+
+/// Returns the class-likes the given class-like inherits members from: its parent class, and the
+/// interfaces it implements or extends that are not already implemented by its parent class, as
+/// the members of the parent class are already checked against them.
+fn get_parent_class_likes<'a>(
+    class_like: &ClassLikeReflection,
+    context: &LintContext<'a>,
+) -> Vec<&'a ClassLikeReflection> {
+    let mut parents = vec![];
+    let mut covered_interfaces = vec![];
+    if let Some(parent) = class_like
+        .inheritance
+        .direct_extended_class
+        .and_then(|parent_name| context.codebase.get_named_class_like(&parent_name.value))
+    {
+        covered_interfaces.extend(parent.inheritance.all_implemented_interfaces.iter().map(|name| name.value));
+        parents.push(parent);
+    }
+
+    for interface_name in
+        class_like.inheritance.all_implemented_interfaces.iter().chain(&class_like.inheritance.all_extended_interfaces)
+    {
+        if covered_interfaces.contains(&interface_name.value) {
+            continue;
+        }
+
+        if let Some(interface) = context.codebase.get_named_class_like(&interface_name.value) {
+            parents.push(interface);
+        }
+    }
+
+    parents
+}
+
+fn check_missing_implementations(class_like: &ClassLikeReflection, name_span: Span, context: &mut LintContext<'_>) {
+    let mut missing: Vec<Cow<'_, FunctionLikeReflection>> = vec![];
+
+    for (method_name, declaring_class_like_name) in &class_like.methods.declaring_members {
+        if *declaring_class_like_name == class_like.name {
+            continue;
+        }
+
+        let Some(method) = context.codebase.get_method(class_like, method_name) else {
+            continue;
+        };
+
+        if method.is_abstract && !method.is_magic {
+            missing.push(method);
+        }
+    }
+
+    for interface_name in &class_like.inheritance.all_implemented_interfaces {
+        let Some(interface) = context.codebase.get_named_class_like(&interface_name.value) else {
+            continue;
+        };
+
+        for method_name in interface.methods.appering_members.keys() {
+            if has_method(class_like, method_name, context) {
+                continue;
+            }
+
+            let name = context.lookup(method_name);
+            if class_like.is_enum() && NATIVE_ENUM_METHODS.iter().any(|native| native.eq_ignore_ascii_case(name)) {
+                continue;
+            }
+
+            let Some(method) = context.codebase.get_method(interface, method_name) else {
+                continue;
+            };
+
+            if !method.is_magic && !missing.iter().any(|existing| existing.name == method.name) {
+                missing.push(method);
+            }
+        }
+    }
+
+    missing.sort_by_key(|method| method.span);
+
+    let class_name = class_like.name.get_key(context.interner);
+    for method in missing {
+        let method_name = method.name.get_key(context.interner);
+
+        let issue = Issue::new(
+            context.level(),
+            format!("`{}` must implement abstract method `{}()`.", class_name, method_name),
+        )
+        .with_annotation(
+            Annotation::primary(name_span).with_message(format!("`{}()` is not implemented.", method_name)),
+        )
+        .with_annotation(
+            Annotation::secondary(method.span).with_message(format!("`{}()` is declared here.", method_name)),
+        )
+        .with_help(format!("Implement `{}()`, or declare `{}` as abstract.", method_name, class_name));
+
+        context.report(issue);
+    }
+}
+
+/// Determines whether the given class-like has a method with the given name, which, like in PHP,
+/// is compared case-insensitively.
+fn has_method(class_like: &ClassLikeReflection, method_name: &StringIdentifier, context: &LintContext<'_>) -> bool {
+    if class_like.methods.appering_members.contains_key(method_name) {
+        return true;
+    }
+
+    let name = context.lookup(method_name);
+
+    class_like.methods.appering_members.keys().any(|other| context.lookup(other).eq_ignore_ascii_case(name))
+}
+
+/// Determines whether the `input` type is known not to be contained by the `container` type.
+///
+/// Unlike an argument, which only needs to possibly match, every member of a union in a
+/// signature must be contained by the type it is compared against.
+fn is_incompatible(input: &TypeKind, container: &TypeKind, context: &LintContext<'_>) -> bool {
+    match input {
+        TypeKind::Union { kinds } => kinds.iter().any(|kind| is_incompatible(kind, container, context)),
+        _ => context.is_contained_by(input, container) == Trinary::False,
+    }
+}
+
+/// Determines whether the given return type of an overriding method narrows the `static` return type of
+/// the overridden method to a class, such as `self`, which PHP rejects.
+fn is_static_narrowed(child: &TypeKind, parent: &TypeKind) -> bool {
+    let get_objects = |kind: &TypeKind| match kind {
+        TypeKind::Union { kinds } => kinds.iter().filter(|kind| kind.is_object()).cloned().collect::<Vec<_>>(),
+        kind if kind.is_object() => vec![kind.clone()],
+        _ => vec![],
+    };
+
+    let is_static = |kind: &TypeKind| matches!(kind, TypeKind::Object(ObjectTypeKind::Static { .. }));
+
+    let parent_objects = get_objects(parent);
+    if parent_objects.is_empty() || !parent_objects.iter().all(is_static) {
+        return false;
+    }
+
+    get_objects(child).iter().any(|kind| !is_static(kind))
+}
+
+fn is_required(parameter: &FunctionLikeParameterReflection) -> bool {
+    !parameter.is_variadic && parameter.default.is_none()
+}
+
+/// Returns the type accepted by the given parameter, including `null` if it defaults to `null`,
+/// which makes its type implicitly nullable.
+fn get_parameter_kind(parameter: &FunctionLikeParameterReflection, kind: &TypeKind) -> TypeKind {
+    match &parameter.default {
+        Some(default) if matches!(default.type_reflection.kind, TypeKind::Value(ValueTypeKind::Null)) => {
+            union_kind(vec![kind.clone(), null_kind()])
+        }
+        _ => kind.clone(),
+    }
+}
+
+fn get_visibility_rank(visibility: Option<ClassLikeMemberVisibilityReflection>) -> u8 {
+    match visibility {
+        Some(ClassLikeMemberVisibilityReflection::Private { .. }) => 0,
+        Some(ClassLikeMemberVisibilityReflection::Protected { .. }) => 1,
+        Some(ClassLikeMemberVisibilityReflection::Public { .. }) | None => 2,
+    }
+}
+
+fn get_visibility_name(visibility: Option<ClassLikeMemberVisibilityReflection>) -> &'static str {
+    match visibility {
+        Some(ClassLikeMemberVisibilityReflection::Private { .. }) => "private",
+        Some(ClassLikeMemberVisibilityReflection::Protected { .. }) => "protected",
+        Some(ClassLikeMemberVisibilityReflection::Public { .. }) | None => "public",
+    }
+}
+
+fn get_method_name_span(method: &FunctionLikeReflection) -> Span {
+    match method.name {
+        mago_reflection::identifier::FunctionLikeName::Method(_, name) => name.span,
+        _ => method.span,
+    }
+}
+
+/// Returns the span of the given parameter, which is the span of its type if it has one, or the
+/// span of the method otherwise.
+fn get_parameter_span(method: &FunctionLikeReflection, parameter: &FunctionLikeParameterReflection) -> Span {
+    match &parameter.type_reflection {
+        Some(type_reflection) => type_reflection.span,
+        None => get_method_name_span(method),
+    }
+}
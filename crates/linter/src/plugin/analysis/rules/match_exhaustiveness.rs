@@ -0,0 +1,258 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct MatchExhaustivenessRule;
+
+/// A value that the subject of a `match` expression can take, among a finite set of values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchValue {
+    Case { enum_name: StringIdentifier, case_name: StringIdentifier },
+    Integer(i64),
+    String(StringIdentifier),
+    True,
+    False,
+    Null,
+}
+
+impl Rule for MatchExhaustivenessRule {
+    fn get_name(&self) -> &'static str {
+        "match-exhaustiveness"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MatchExhaustivenessRule {
+    fn walk_in_match<'ast>(&self, r#match: &'ast Match, context: &mut LintContext<'a>) {
+        let subject_kind = context.resolve_kind(&r#match.expression);
+        let Some(possible_values) = get_possible_values(&subject_kind, context) else {
+            return;
+        };
+
+        let mut handled_values: Vec<MatchValue> = vec![];
+        let mut is_exhaustive = false;
+        for arm in r#match.arms.iter() {
+            let MatchArm::Expression(arm) = arm else {
+                is_exhaustive = true;
+
+                continue;
+            };
+
+            for condition in arm.conditions.iter() {
+                let Some(value) = get_condition_value(condition, context) else {
+                    // The condition could match any value, so we can't tell which values are left unhandled.
+                    is_exhaustive = true;
+
+                    continue;
+                };
+
+                if !possible_values.contains(&value) {
+                    report_unreachable_condition(r#match, condition, value, &subject_kind, false, context);
+                } else if handled_values.contains(&value) {
+                    report_unreachable_condition(r#match, condition, value, &subject_kind, true, context);
+                } else {
+                    handled_values.push(value);
+                }
+            }
+        }
+
+        if is_exhaustive {
+            return;
+        }
+
+        let unhandled_values =
+            possible_values.into_iter().filter(|value| !handled_values.contains(value)).collect::<Vec<_>>();
+
+        if unhandled_values.is_empty() {
+            return;
+        }
+
+        report_unhandled_values(r#match, &subject_kind, &unhandled_values, context);
+    }
+}
+
+fn report_unreachable_condition(
+    r#match: &Match,
+    condition: &Expression,
+    value: MatchValue,
+    subject_kind: &TypeKind,
+    is_duplicate: bool,
+    context: &mut LintContext<'_>,
+) {
+    let value_name = get_value_code(value, context);
+
+    let issue = if is_duplicate {
+        Issue::new(
+            context.level(),
+            format!("Match arm condition `{}` is already handled by a previous arm.", value_name),
+        )
+        .with_annotation(Annotation::primary(condition.span()).with_message("This condition can never match."))
+        .with_help("Remove this condition, as the previous arm always takes precedence.")
+    } else {
+        let subject_type = subject_kind.get_key(context.interner);
+
+        Issue::new(
+            context.level(),
+            format!("Match arm condition `{}` can never match a subject of type `{}`.", value_name, subject_type),
+        )
+        .with_annotation(Annotation::primary(condition.span()).with_message("This condition can never match."))
+        .with_annotation(
+            Annotation::secondary(r#match.expression.span())
+                .with_message(format!("The subject is of type `{}`.", subject_type)),
+        )
+        .with_help("Remove this condition, or fix the type of the subject.")
+    };
+
+    context.report(issue);
+}
+
+fn report_unhandled_values(
+    r#match: &Match,
+    subject_kind: &TypeKind,
+    unhandled_values: &[MatchValue],
+    context: &mut LintContext<'_>,
+) {
+    let subject_type = subject_kind.get_key(context.interner);
+    let unhandled_codes = unhandled_values.iter().map(|value| get_value_code(*value, context)).collect::<Vec<_>>();
+
+    let issue = Issue::new(
+        context.level(),
+        format!("Match expression does not handle all possible values of type `{}`.", subject_type),
+    )
+    .with_annotation(Annotation::primary(r#match.expression.span()).with_message(format!(
+        "This subject can also be {}.",
+        unhandled_codes.iter().map(|code| format!("`{}`", code)).collect::<Vec<_>>().join(", ")
+    )))
+    .with_annotation(
+        Annotation::secondary(r#match.r#match.span).with_message("This match expression is not exhaustive."),
+    )
+    .with_note("Unhandled values cause an `UnhandledMatchError` to be thrown at runtime.")
+    .with_help("Add arms for the unhandled values, or a `default` arm.");
+
+    let code = context.lookup(&context.semantics.source.content);
+    let is_single_line = !code[r#match.left_brace.start.offset..r#match.right_brace.end.offset].contains('\n');
+    let (offset, needs_separator, indentation) = match r#match.arms.last() {
+        Some(last_arm) => (
+            r#match.arms.last_span().map(|span| span.end.offset).unwrap_or_else(|| last_arm.span().end.offset),
+            !r#match.arms.has_trailing_token(),
+            get_line_indentation(code, last_arm.span().start.offset),
+        ),
+        None => (
+            r#match.left_brace.end.offset,
+            false,
+            format!("{}    ", get_line_indentation(code, r#match.r#match.span.start.offset)),
+        ),
+    };
+
+    let mut arms = if needs_separator { String::from(",") } else { String::new() };
+    for (index, unhandled_code) in unhandled_codes.iter().enumerate() {
+        if is_single_line {
+            arms.push(' ');
+        } else {
+            arms.push('\n');
+            arms.push_str(&indentation);
+        }
+
+        arms.push_str(unhandled_code);
+        arms.push_str(" => throw new \\LogicException('Not implemented.')");
+
+        // Single-line matches don't get a trailing comma after the last arm.
+        if !is_single_line || index + 1 < unhandled_codes.len() {
+            arms.push(',');
+        }
+    }
+
+    context.report_with_fix(issue, |plan| {
+        plan.insert(offset, arms, SafetyClassification::PotentiallyUnsafe);
+    });
+}
+
+/// Returns the finite set of values of the given type, or `None` if it can't be enumerated.
+fn get_possible_values(kind: &TypeKind, context: &LintContext<'_>) -> Option<Vec<MatchValue>> {
+    let mut values = vec![];
+    match kind {
+        TypeKind::Union { kinds } => {
+            for kind in kinds {
+                for value in get_possible_values(kind, context)? {
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+            }
+        }
+        TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => {
+            let r#enum = context.codebase.get_enum(name)?;
+
+            let mut cases = r#enum.cases.members.values().collect::<Vec<_>>();
+            cases.sort_by_key(|case| case.span);
+
+            values.extend(cases.into_iter().map(|case| MatchValue::Case {
+                enum_name: r#enum.name.inner().map(|name| name.value).unwrap_or(*name),
+                case_name: case.name.member.value,
+            }));
+        }
+        TypeKind::Scalar(ScalarTypeKind::Bool) => {
+            values.extend([MatchValue::True, MatchValue::False]);
+        }
+        _ => {
+            values.push(get_value(kind, context)?);
+        }
+    }
+
+    Some(values)
+}
+
+/// Returns the value of the given arm condition, if it is a single known value.
+fn get_condition_value(condition: &Expression, context: &LintContext<'_>) -> Option<MatchValue> {
+    get_value(&context.resolve_kind(condition), context)
+}
+
+fn get_value(kind: &TypeKind, context: &LintContext<'_>) -> Option<MatchValue> {
+    Some(match kind {
+        TypeKind::Object(ObjectTypeKind::EnumCase { enum_name, case_name }) => {
+            let enum_name = context.codebase.get_enum(enum_name)?.name.inner().map(|name| name.value)?;
+
+            MatchValue::Case { enum_name, case_name: *case_name }
+        }
+        TypeKind::Value(ValueTypeKind::Integer { value }) => MatchValue::Integer(*value),
+        TypeKind::Value(ValueTypeKind::String { value, .. }) => MatchValue::String(*value),
+        TypeKind::Value(ValueTypeKind::True) => MatchValue::True,
+        TypeKind::Value(ValueTypeKind::False) => MatchValue::False,
+        TypeKind::Value(ValueTypeKind::Null) => MatchValue::Null,
+        _ => return None,
+    })
+}
+
+/// Returns the PHP code for the given value, for use in messages and in the inserted arms.
+fn get_value_code(value: MatchValue, context: &LintContext<'_>) -> String {
+    match value {
+        MatchValue::Case { enum_name, case_name } => {
+            format!("\\{}::{}", context.lookup(&enum_name), context.lookup(&case_name))
+        }
+        MatchValue::Integer(value) => value.to_string(),
+        MatchValue::String(value) => {
+            format!("'{}'", context.lookup(&value).replace('\\', "\\\\").replace('\'', "\\'"))
+        }
+        MatchValue::True => "true".to_string(),
+        MatchValue::False => "false".to_string(),
+        MatchValue::Null => "null".to_string(),
+    }
+}
+
+/// Returns the leading whitespace of the line containing the given offset.
+fn get_line_indentation(code: &str, offset: usize) -> String {
+    let line_start = code[..offset].rfind('\n').map(|position| position + 1).unwrap_or(0);
+
+    code[line_start..].chars().take_while(|character| *character == ' ' || *character == '\t').collect()
+}
@@ -0,0 +1,486 @@
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::member::ClassLikeMemberVisibilityReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct MemberAccessRule;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MemberKind {
+    Method,
+    Property,
+    Constant,
+}
+
+impl MemberKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MemberKind::Method => "method",
+            MemberKind::Property => "property",
+            MemberKind::Constant => "constant",
+        }
+    }
+
+    fn as_title(&self) -> &'static str {
+        match self {
+            MemberKind::Method => "Method",
+            MemberKind::Property => "Property",
+            MemberKind::Constant => "Constant",
+        }
+    }
+}
+
+impl Rule for MemberAccessRule {
+    fn get_name(&self) -> &'static str {
+        "member-access"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MemberAccessRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        check_member_access(&method_call.object, &method_call.method, MemberKind::Method, false, context);
+    }
+
+    fn walk_in_null_safe_method_call<'ast>(
+        &self,
+        null_safe_method_call: &'ast NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        check_member_access(
+            &null_safe_method_call.object,
+            &null_safe_method_call.method,
+            MemberKind::Method,
+            false,
+            context,
+        );
+    }
+
+    fn walk_assignment<'ast>(&self, assignment: &'ast Assignment, context: &mut LintContext<'a>) {
+        // Writing to an undeclared property creates it, which is reported by the `dynamic-property-creation`
+        // rule of the deprecation plugin, so only the visibility of the written property is checked here.
+        if let (AssignmentOperator::Assign(_), Expression::Access(access)) =
+            (&assignment.operator, assignment.lhs.as_ref())
+        {
+            if let Access::Property(property_access) = access.as_ref() {
+                check_member_access(
+                    &property_access.object,
+                    &property_access.property,
+                    MemberKind::Property,
+                    true,
+                    context,
+                );
+
+                self.walk_expression(&property_access.object, context);
+                self.walk_class_like_member_selector(&property_access.property, context);
+                self.walk_assignment_operator(&assignment.operator, context);
+                self.walk_expression(&assignment.rhs, context);
+
+                return;
+            }
+        }
+
+        self.walk_expression(&assignment.lhs, context);
+        self.walk_assignment_operator(&assignment.operator, context);
+        self.walk_expression(&assignment.rhs, context);
+    }
+
+    fn walk_in_property_access<'ast>(&self, property_access: &'ast PropertyAccess, context: &mut LintContext<'a>) {
+        check_member_access(&property_access.object, &property_access.property, MemberKind::Property, false, context);
+    }
+
+    fn walk_in_null_safe_property_access<'ast>(
+        &self,
+        null_safe_property_access: &'ast NullSafePropertyAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        check_member_access(
+            &null_safe_property_access.object,
+            &null_safe_property_access.property,
+            MemberKind::Property,
+            false,
+            context,
+        );
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        if let ClassLikeMemberSelector::Identifier(identifier) = &static_method_call.method {
+            let name = context.lookup(&identifier.value).to_string();
+
+            check_static_member_access(&static_method_call.class, &name, identifier.span, MemberKind::Method, context);
+        }
+    }
+
+    fn walk_in_static_property_access<'ast>(
+        &self,
+        static_property_access: &'ast StaticPropertyAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        if let Variable::Direct(variable) = &static_property_access.property {
+            let name = context.lookup(&variable.name).trim_start_matches('$').to_string();
+
+            check_static_member_access(
+                &static_property_access.class,
+                &name,
+                variable.span,
+                MemberKind::Property,
+                context,
+            );
+        }
+    }
+
+    fn walk_in_class_constant_access<'ast>(
+        &self,
+        class_constant_access: &'ast ClassConstantAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        if let ClassLikeConstantSelector::Identifier(identifier) = &class_constant_access.constant {
+            let name = context.lookup(&identifier.value).to_string();
+            if name.eq_ignore_ascii_case("class") {
+                return;
+            }
+
+            check_static_member_access(
+                &class_constant_access.class,
+                &name,
+                identifier.span,
+                MemberKind::Constant,
+                context,
+            );
+        }
+    }
+}
+
+fn check_member_access(
+    object: &Expression,
+    selector: &ClassLikeMemberSelector,
+    kind: MemberKind,
+    is_write: bool,
+    context: &mut LintContext<'_>,
+) {
+    let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+        return;
+    };
+
+    let Some(class_likes) = get_receiver_class_likes(object, context) else {
+        return;
+    };
+
+    let member_name = context.lookup(&identifier.value).to_string();
+    let mut members = vec![];
+    let mut may_exist = false;
+    for class_like in &class_likes {
+        if has_unknown_ancestors(class_like, context)
+            || has_magic_accessor(class_like, kind, context)
+            || is_builtin_member(class_like, &member_name, kind)
+        {
+            may_exist = true;
+
+            continue;
+        }
+
+        if let Some(member) = find_member(class_like, &member_name, kind, context) {
+            members.push((*class_like, member));
+        }
+    }
+
+    // For objects of several possible types, only report members that are defined by none of them,
+    // as the type of the object is often narrowed before the access.
+    if members.is_empty() && !may_exist && !is_write {
+        let Some(class_like) = class_likes.first() else {
+            return;
+        };
+
+        let object_type = get_kind_display_name(&context.resolve_kind(object), context);
+        let class_name = get_class_like_display_name(class_like.name, context);
+        let message = match kind {
+            MemberKind::Method => format!("Call to undefined method `{}::{}()`.", class_name, member_name),
+            MemberKind::Property => format!("Access to undefined property `{}::${}`.", class_name, member_name),
+            MemberKind::Constant => format!("Access to undefined constant `{}::{}`.", class_name, member_name),
+        };
+
+        let mut issue = Issue::new(context.level(), message)
+            .with_annotation(Annotation::primary(identifier.span()).with_message(format!(
+                "{} `{}` does not exist on `{}`.",
+                kind.as_title(),
+                member_name,
+                object_type
+            )))
+            .with_annotation(
+                Annotation::secondary(object.span())
+                    .with_message(format!("This expression is of type `{}`.", object_type)),
+            );
+
+        for class_like in &class_likes {
+            issue =
+                issue.with_annotation(Annotation::secondary(class_like.span).with_message(format!(
+                    "`{}` is defined here.",
+                    get_class_like_display_name(class_like.name, context)
+                )));
+        }
+
+        context.report(issue);
+
+        return;
+    }
+
+    for (class_like, (appearing_class_like, visibility)) in members {
+        if !is_accessible(object, appearing_class_like, visibility, context) {
+            report_inaccessible_member(
+                class_like,
+                appearing_class_like,
+                visibility,
+                kind,
+                &member_name,
+                identifier.span(),
+                context,
+            );
+        }
+    }
+}
+
+/// Checks the visibility of a member accessed statically, e.g. `Foo::bar()`, `Foo::$bar`, or `Foo::BAR`.
+///
+/// Only the visibility of the member is checked, as static accesses through `static` may refer to members
+/// declared by descendants.
+fn check_static_member_access(
+    class: &Expression,
+    member_name: &str,
+    span: Span,
+    kind: MemberKind,
+    context: &mut LintContext<'_>,
+) {
+    let Some(class_like) = context.get_class_like_reflection(class) else {
+        return;
+    };
+
+    let Some((appearing_class_like, visibility)) = find_member(class_like, member_name, kind, context) else {
+        return;
+    };
+
+    if !is_accessible(class, appearing_class_like, visibility, context) {
+        report_inaccessible_member(class_like, appearing_class_like, visibility, kind, member_name, span, context);
+    }
+}
+
+fn report_inaccessible_member(
+    class_like: &ClassLikeReflection,
+    appearing_class_like: ClassLikeName,
+    visibility: ClassLikeMemberVisibilityReflection,
+    kind: MemberKind,
+    member_name: &str,
+    span: Span,
+    context: &mut LintContext<'_>,
+) {
+    let visibility_name = match visibility {
+        ClassLikeMemberVisibilityReflection::Private { .. } => "private",
+        _ => "protected",
+    };
+
+    // Private members are only accessible from the class declaring them, which may be a parent
+    // of the class of the object.
+    let class_name = match visibility {
+        ClassLikeMemberVisibilityReflection::Private { .. } => {
+            get_class_like_display_name(appearing_class_like, context)
+        }
+        _ => get_class_like_display_name(class_like.name, context),
+    };
+
+    let member = match kind {
+        MemberKind::Method => format!("{}()", member_name),
+        MemberKind::Property => format!("${}", member_name),
+        MemberKind::Constant => member_name.to_string(),
+    };
+
+    let issue = Issue::new(
+        context.level(),
+        format!("Cannot access {} {} `{}::{}` from this scope.", visibility_name, kind.as_str(), class_name, member),
+    )
+    .with_annotation(Annotation::primary(span).with_message(format!(
+        "{} `{}` is {}.",
+        kind.as_title(),
+        member_name,
+        visibility_name
+    )))
+    .with_annotation(Annotation::secondary(visibility.span()).with_message(format!(
+        "{} `{}` is declared {} here.",
+        kind.as_title(),
+        member_name,
+        visibility_name
+    )))
+    .with_help(format!(
+        "Only access {} members from within the class{} that declares them.",
+        visibility_name,
+        if visibility_name == "protected" { ", or its parents and descendants," } else { "" }
+    ));
+
+    context.report(issue);
+}
+
+/// Determines whether any ancestor of the given class-like is missing from the codebase, in which case its members
+/// cannot be fully known.
+fn has_unknown_ancestors(class_like: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    let inheritance = &class_like.inheritance;
+
+    inheritance
+        .all_extended_classes
+        .iter()
+        .chain(inheritance.all_implemented_interfaces.iter())
+        .chain(inheritance.all_extended_interfaces.iter())
+        .any(|ancestor| context.codebase.get_named_class_like(&ancestor.value).is_none())
+        || class_like.used_traits.iter().any(|name| context.codebase.get_named_class_like(name).is_none())
+}
+
+fn has_magic_accessor(class_like: &ClassLikeReflection, kind: MemberKind, context: &LintContext<'_>) -> bool {
+    let magic_methods: &[&str] = match kind {
+        MemberKind::Method => &["__call"],
+        MemberKind::Property => &["__get", "__set"],
+        MemberKind::Constant => &[],
+    };
+
+    magic_methods
+        .iter()
+        .any(|magic_method| find_member(class_like, magic_method, MemberKind::Method, context).is_some())
+}
+
+/// Finds the member with the given name, returning the class-like it appears in and its visibility.
+///
+/// Method names are matched case-insensitively, as they are in PHP.
+fn find_member<'a>(
+    class_like: &'a ClassLikeReflection,
+    name: &str,
+    kind: MemberKind,
+    context: &LintContext<'a>,
+) -> Option<(ClassLikeName, ClassLikeMemberVisibilityReflection)> {
+    let (member_name, appearing_class_like) = match kind {
+        MemberKind::Constant => class_like
+            .constants
+            .iter()
+            .find(|(member_name, _)| context.interner.lookup(member_name) == name)
+            .map(|(member_name, constant)| (member_name, &constant.name.class_like))?,
+        MemberKind::Method => class_like
+            .methods
+            .appering_members
+            .iter()
+            .find(|(member_name, _)| context.interner.lookup(member_name).eq_ignore_ascii_case(name))?,
+        MemberKind::Property => {
+            let property_name = format!("${}", name);
+
+            class_like
+                .properties
+                .appering_members
+                .iter()
+                .find(|(member_name, _)| context.interner.lookup(member_name) == property_name)?
+        }
+    };
+
+    let visibility = get_member_visibility(class_like, *appearing_class_like, member_name, kind, context)
+        .unwrap_or(ClassLikeMemberVisibilityReflection::Public { span: class_like.span });
+
+    Some((*appearing_class_like, visibility))
+}
+
+fn get_member_visibility(
+    class_like: &ClassLikeReflection,
+    appearing_class_like: ClassLikeName,
+    member_name: &StringIdentifier,
+    kind: MemberKind,
+    context: &LintContext<'_>,
+) -> Option<ClassLikeMemberVisibilityReflection> {
+    let declaring_class_like = match kind {
+        MemberKind::Method => class_like.methods.declaring_members.get(member_name),
+        MemberKind::Property => class_like.properties.declaring_members.get(member_name),
+        MemberKind::Constant => None,
+    };
+
+    [Some(appearing_class_like), declaring_class_like.copied()].into_iter().flatten().find_map(|name| {
+        let owner = context.codebase.get_class_like(name)?;
+
+        match kind {
+            MemberKind::Method => owner.get_method(member_name)?.visibility_reflection,
+            MemberKind::Property => owner.get_property(member_name)?.read_visibility_reflection,
+            MemberKind::Constant => owner.get_constant(member_name)?.visibility_reflection,
+        }
+    })
+}
+
+/// Determines whether a member with the given visibility, appearing in the given class-like, is accessible
+/// from the scope of the given expression.
+fn is_accessible(
+    expression: &Expression,
+    appearing_class_like: ClassLikeName,
+    visibility: ClassLikeMemberVisibilityReflection,
+    context: &LintContext<'_>,
+) -> bool {
+    if visibility.is_public() {
+        return true;
+    }
+
+    let Some(scope) = context.codebase.get_enclosing_class_like(expression) else {
+        return false;
+    };
+
+    // The class-like using a trait is not known from within the trait.
+    if scope.is_trait() || scope.name == appearing_class_like {
+        return true;
+    }
+
+    // Members of a trait, including private ones, are copied into the class-likes using it.
+    if appearing_class_like.inner().is_some_and(|name| scope.used_traits.contains(&name.value)) {
+        return true;
+    }
+
+    if visibility.is_private() {
+        return false;
+    }
+
+    let Some(appearing_class_like) = context.codebase.get_class_like(appearing_class_like) else {
+        return true;
+    };
+
+    is_same_or_descendant(scope, appearing_class_like) || is_same_or_descendant(appearing_class_like, scope)
+}
+
+fn is_same_or_descendant(class_like: &ClassLikeReflection, ancestor: &ClassLikeReflection) -> bool {
+    let Some(ancestor_name) = ancestor.name.inner() else {
+        return class_like.name == ancestor.name;
+    };
+
+    class_like.name == ancestor.name
+        || class_like.inheritance.all_extended_classes.iter().any(|name| name.value == ancestor_name.value)
+        || class_like.used_traits.contains(&ancestor_name.value)
+}
+
+/// Determines whether the given member is provided by PHP itself, such as the `name` and `value`
+/// properties of enum cases.
+fn is_builtin_member(class_like: &ClassLikeReflection, name: &str, kind: MemberKind) -> bool {
+    if !class_like.is_enum() {
+        return false;
+    }
+
+    match kind {
+        MemberKind::Property => name == "name" || (name == "value" && class_like.backing_type.is_some()),
+        MemberKind::Method => {
+            name.eq_ignore_ascii_case("cases")
+                || (class_like.backing_type.is_some()
+                    && (name.eq_ignore_ascii_case("from") || name.eq_ignore_ascii_case("tryFrom")))
+        }
+        MemberKind::Constant => false,
+    }
+}
@@ -0,0 +1,13 @@
+pub mod call_arguments;
+pub mod deprecated;
+pub mod format_string;
+pub mod inheritance;
+pub mod match_exhaustiveness;
+pub mod member_access;
+pub mod readonly_property;
+pub mod regex_backtracking;
+pub mod regex_pattern;
+pub mod return_type;
+pub mod unused_private_member;
+pub mod unused_symbol;
+pub mod utils;
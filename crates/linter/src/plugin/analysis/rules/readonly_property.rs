@@ -0,0 +1,765 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::MutWalker;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReadonlyPropertyRule;
+
+impl Rule for ReadonlyPropertyRule {
+    fn get_name(&self) -> &'static str {
+        "readonly-property"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ReadonlyPropertyRule {
+    fn walk_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let mut walker = ReadonlyWalker {
+            class_likes: vec![],
+            function_likes: vec![FunctionLikeState::default()],
+            written_accesses: HashSet::default(),
+            guarded_accesses: HashSet::default(),
+        };
+
+        walker.walk_program(program, context);
+    }
+}
+
+/// The way a property is written to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Write {
+    Assignment,
+    Unset,
+    Modification(Modification),
+}
+
+/// A write that requires the property to be initialized already.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Modification {
+    CompoundAssignment,
+    Increment,
+    Decrement,
+    ArrayWrite,
+    Reference,
+}
+
+impl Modification {
+    fn get_message(&self, property_name: &str) -> String {
+        match self {
+            Modification::CompoundAssignment => {
+                format!("Cannot modify readonly property `{}` using a compound assignment.", property_name)
+            }
+            Modification::Increment => format!("Cannot increment readonly property `{}`.", property_name),
+            Modification::Decrement => format!("Cannot decrement readonly property `{}`.", property_name),
+            Modification::ArrayWrite => format!("Cannot modify the elements of readonly property `{}`.", property_name),
+            Modification::Reference => format!("Cannot take a reference to readonly property `{}`.", property_name),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum FunctionLikeKind {
+    Constructor,
+    Clone,
+    #[default]
+    Other,
+}
+
+#[derive(Debug, Default)]
+struct FunctionLikeState {
+    kind: FunctionLikeKind,
+
+    /// The variables holding a clone of another object.
+    cloned_variables: HashSet<StringIdentifier>,
+}
+
+#[derive(Debug)]
+struct ClassLikeState<'a> {
+    reflection: Option<&'a ClassLikeReflection>,
+
+    /// The properties initialized by the top-level statements of the constructor, and where.
+    initializations: HashMap<StringIdentifier, Span>,
+
+    /// The names of the properties written to within the class-like.
+    written_properties: HashSet<StringIdentifier>,
+
+    /// Whether the class-like writes to `$this` properties with dynamic names.
+    has_dynamic_writes: bool,
+
+    /// The names of the `$this` properties read within the class-like, and where.
+    reads: Vec<(StringIdentifier, Span)>,
+}
+
+struct ReadonlyWalker<'a> {
+    class_likes: Vec<ClassLikeState<'a>>,
+    function_likes: Vec<FunctionLikeState>,
+    written_accesses: HashSet<Span>,
+    guarded_accesses: HashSet<Span>,
+}
+
+impl<'a> ReadonlyWalker<'a> {
+    fn enter_class_like(&mut self, members: &Sequence<ClassLikeMember>, left_brace: Span, context: &LintContext<'a>) {
+        let mut initializations = HashMap::default();
+        for member in members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBody::Concrete(block) = &method.body else {
+                continue;
+            };
+
+            if !context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+                continue;
+            }
+
+            // Only top-level statements always run, so writes nested in control structures are not initializations.
+            for statement in block.statements.iter() {
+                let Statement::Expression(ExpressionStatement {
+                    expression: Expression::AssignmentOperation(assignment),
+                    ..
+                }) = statement
+                else {
+                    continue;
+                };
+
+                if !matches!(assignment.operator, AssignmentOperator::Assign(_)) {
+                    continue;
+                }
+
+                let Expression::Access(access) = assignment.lhs.as_ref() else {
+                    continue;
+                };
+
+                let Access::Property(property_access) = access.as_ref() else {
+                    continue;
+                };
+
+                let ClassLikeMemberSelector::Identifier(property) = &property_access.property else {
+                    continue;
+                };
+
+                if is_this(&property_access.object, context) {
+                    initializations.entry(property.value).or_insert_with(|| property_access.span());
+                }
+            }
+        }
+
+        self.class_likes.push(ClassLikeState {
+            reflection: context.codebase.get_enclosing_class_like(&left_brace),
+            initializations,
+            written_properties: HashSet::default(),
+            has_dynamic_writes: false,
+            reads: vec![],
+        });
+    }
+
+    fn exit_class_like(
+        &mut self,
+        node: &impl HasSpan,
+        attributes: &Sequence<AttributeList>,
+        members: &Sequence<ClassLikeMember>,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(state) = self.class_likes.pop() else {
+            return;
+        };
+
+        let Some(reflection) = state.reflection else {
+            return;
+        };
+
+        // Properties of classes that use traits, or that are hydrated by libraries, may be initialized elsewhere.
+        if state.has_dynamic_writes
+            || !attributes.is_empty()
+            || has_annotations(node, context)
+            || members.iter().any(|member| matches!(member, ClassLikeMember::TraitUse(_)))
+        {
+            return;
+        }
+
+        for member in members.iter() {
+            let ClassLikeMember::Property(Property::Plain(property)) = member else {
+                continue;
+            };
+
+            if property.hint.is_none()
+                || !property.attributes.is_empty()
+                || property.modifiers.contains_static()
+                || has_annotations(property, context)
+            {
+                continue;
+            }
+
+            // Only private properties, and readonly properties of final classes, can't be initialized by a child class.
+            let is_private = matches!(property.modifiers.get_first_read_visibility(), Some(Modifier::Private(_)));
+            let is_readonly = property.modifiers.contains_readonly() || reflection.is_readonly;
+            if !(is_private || (is_readonly && reflection.is_final)) {
+                continue;
+            }
+
+            for item in property.items.iter() {
+                let PropertyItem::Abstract(item) = item else {
+                    continue;
+                };
+
+                let name = context.lookup(&item.variable.name)[1..].to_string();
+                if state.written_properties.iter().any(|written| context.lookup(written) == name) {
+                    continue;
+                }
+
+                let reads = state
+                    .reads
+                    .iter()
+                    .filter(|(read, _)| context.lookup(read) == name)
+                    .map(|(_, span)| *span)
+                    .collect::<Vec<_>>();
+
+                report_uninitialized_reads(reflection, &name, item.variable.span, &reads, context);
+            }
+        }
+    }
+
+    fn check_target(&mut self, target: &Expression, write: Write, context: &mut LintContext<'a>) {
+        match target {
+            Expression::Parenthesized(parenthesized) => self.check_target(&parenthesized.expression, write, context),
+            Expression::Access(access) => {
+                if let Access::Property(property_access) = access.as_ref() {
+                    self.check_property_write(property_access, write, context);
+                }
+            }
+            Expression::ArrayAccess(ArrayAccess { array, .. }) | Expression::ArrayAppend(ArrayAppend { array, .. }) => {
+                let write = match write {
+                    Write::Modification(Modification::Reference) => write,
+                    _ => Write::Modification(Modification::ArrayWrite),
+                };
+
+                self.check_target(array, write, context);
+            }
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. })
+                if write == Write::Assignment =>
+            {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => self.check_target(&element.value, write, context),
+                        ArrayElement::Value(element) => self.check_target(&element.value, write, context),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_property_write(&mut self, property_access: &PropertyAccess, write: Write, context: &mut LintContext<'a>) {
+        self.written_accesses.insert(property_access.span());
+
+        let ClassLikeMemberSelector::Identifier(property) = &property_access.property else {
+            if is_this(&property_access.object, context) {
+                if let Some(state) = self.class_likes.last_mut() {
+                    state.has_dynamic_writes = true;
+                }
+            }
+
+            return;
+        };
+
+        if write != Write::Unset {
+            if let Some(state) = self.class_likes.last_mut() {
+                state.written_properties.insert(property.value);
+            }
+        }
+
+        let Some(class_likes) = get_receiver_class_likes(&property_access.object, context) else {
+            return;
+        };
+
+        let property_name = context.interner.intern(format!("${}", context.lookup(&property.value)));
+        for class_like in class_likes {
+            let Some(declaring_class_like) = class_like
+                .properties
+                .declaring_members
+                .get(&property_name)
+                .and_then(|name| context.codebase.get_class_like(*name))
+            else {
+                continue;
+            };
+
+            let Some(property_reflection) = declaring_class_like.get_property(&property_name) else {
+                continue;
+            };
+
+            // The class-like using a trait is not known from within the trait.
+            if !property_reflection.is_readonly || declaring_class_like.is_trait() {
+                continue;
+            }
+
+            let is_reported = self.check_readonly_property_write(
+                property_access,
+                property,
+                write,
+                declaring_class_like,
+                property_reflection,
+                context,
+            );
+
+            if is_reported {
+                break;
+            }
+        }
+    }
+
+    fn check_readonly_property_write(
+        &self,
+        property_access: &PropertyAccess,
+        property: &LocalIdentifier,
+        write: Write,
+        declaring_class_like: &ClassLikeReflection,
+        property_reflection: &PropertyReflection,
+        context: &mut LintContext<'a>,
+    ) -> bool {
+        let property_name =
+            format!("{}::${}", declaring_class_like.name.get_key(context.interner), context.lookup(&property.value));
+
+        let scope = context.codebase.get_enclosing_class_like(&property_access.arrow);
+        let is_in_scope = scope.is_some_and(|scope| {
+            scope.is_trait()
+                || scope.name == declaring_class_like.name
+                || declaring_class_like.name.inner().is_some_and(|declaring_name| {
+                    scope.inheritance.all_extended_classes.iter().any(|name| name.value == declaring_name.value)
+                })
+        });
+
+        if !is_in_scope {
+            let issue = Issue::new(
+                context.level(),
+                format!("Cannot modify readonly property `{}` from outside of its class.", property_name),
+            )
+            .with_annotation(
+                Annotation::primary(property_access.span())
+                    .with_message(format!("Property `{}` is modified here.", property_name)),
+            )
+            .with_annotation(get_readonly_annotation(declaring_class_like, property_reflection, context))
+            .with_help("Readonly properties can only be initialized from within the class that declares them.");
+
+            context.report(issue);
+
+            return true;
+        }
+
+        let modification = match write {
+            Write::Unset => return false,
+            Write::Modification(modification) => modification,
+            Write::Assignment => {
+                return self.check_readonly_property_assignment(
+                    property_access,
+                    property,
+                    &property_name,
+                    declaring_class_like,
+                    property_reflection,
+                    context,
+                );
+            }
+        };
+
+        let issue = Issue::new(context.level(), modification.get_message(&property_name))
+            .with_annotation(
+                Annotation::primary(property_access.span())
+                    .with_message(format!("Property `{}` is modified here.", property_name)),
+            )
+            .with_annotation(get_readonly_annotation(declaring_class_like, property_reflection, context))
+            .with_note("Readonly properties cannot be modified once initialized, not even indirectly.")
+            .with_help("Assign a new value to the property during initialization instead, or remove `readonly`.");
+
+        context.report(issue);
+
+        true
+    }
+
+    fn check_readonly_property_assignment(
+        &self,
+        property_access: &PropertyAccess,
+        property: &LocalIdentifier,
+        property_name: &str,
+        declaring_class_like: &ClassLikeReflection,
+        property_reflection: &PropertyReflection,
+        context: &mut LintContext<'a>,
+    ) -> bool {
+        let function_like = self.function_likes.last();
+
+        // Since PHP 8.3, readonly properties can be re-initialized while cloning.
+        if function_like.is_some_and(|function_like| function_like.kind == FunctionLikeKind::Clone) {
+            return false;
+        }
+
+        let initialization = if property_reflection.is_promoted {
+            Some(property_reflection.definition_span)
+        } else {
+            self.class_likes
+                .last()
+                .filter(|state| state.reflection.is_some_and(|reflection| reflection.name == declaring_class_like.name))
+                .and_then(|state| state.initializations.get(&property.value))
+                .copied()
+        };
+
+        let Some(initialization) = initialization else {
+            return false;
+        };
+
+        let span = property_access.span();
+        let issue = if is_this(&property_access.object, context) {
+            let is_in_constructor =
+                function_like.is_some_and(|function_like| function_like.kind == FunctionLikeKind::Constructor);
+
+            // This is the initialization itself, or a write that happens before it.
+            if initialization == span || (is_in_constructor && span.start < initialization.start) {
+                return false;
+            }
+
+            Issue::new(context.level(), format!("Cannot modify readonly property `{}`, as it is already initialized.", property_name))
+                .with_annotation(
+                    Annotation::primary(span).with_message(format!("Property `{}` is modified here.", property_name)),
+                )
+                .with_annotation(Annotation::secondary(initialization).with_message(if property_reflection.is_promoted {
+                    "The property is initialized by the constructor, as it is promoted.".to_string()
+                } else {
+                    "The property is initialized here.".to_string()
+                }))
+                .with_help("Readonly properties can only be initialized once, consider removing `readonly`, or creating a new instance instead.")
+        } else {
+            let Expression::Variable(Variable::Direct(variable)) = &property_access.object else {
+                return false;
+            };
+
+            if !function_like.is_some_and(|function_like| function_like.cloned_variables.contains(&variable.name)) {
+                return false;
+            }
+
+            Issue::new(
+                context.level(),
+                format!("Cannot modify readonly property `{}` of a cloned object.", property_name),
+            )
+            .with_annotation(
+                Annotation::primary(span).with_message(format!("Property `{}` is modified here.", property_name)),
+            )
+            .with_annotation(
+                Annotation::secondary(initialization).with_message("The property is already initialized here."),
+            )
+            .with_note(
+                "Clones keep the initialized readonly properties of the original object, so they cannot be modified.",
+            )
+            .with_help("Create a new instance instead, or re-initialize the property within `__clone()`.")
+        };
+
+        context.report(issue);
+
+        true
+    }
+
+    fn check_arguments(
+        &mut self,
+        function_like: Option<&FunctionLikeReflection>,
+        arguments: &ArgumentList,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(function_like) = function_like else {
+            return;
+        };
+
+        for (index, argument) in arguments.arguments.iter().enumerate() {
+            let parameter = match argument {
+                Argument::Positional(_) => function_like.parameters.get(index),
+                Argument::Named(argument) => {
+                    function_like.parameters.iter().find(|parameter| parameter.name == argument.name.value)
+                }
+            }
+            .or_else(|| function_like.parameters.last().filter(|parameter| parameter.is_variadic));
+
+            if parameter.is_some_and(|parameter| parameter.is_passed_by_reference) {
+                self.check_target(argument.value(), Write::Modification(Modification::Reference), context);
+            }
+        }
+    }
+
+    fn guard(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.guard(&parenthesized.expression),
+            Expression::ArrayAccess(array_access) => self.guard(&array_access.array),
+            Expression::Access(access) => match access.as_ref() {
+                Access::Property(property_access) => {
+                    self.guarded_accesses.insert(property_access.span());
+                    self.guard(&property_access.object);
+                }
+                Access::NullSafeProperty(null_safe_property_access) => self.guard(&null_safe_property_access.object),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl<'a> MutWalker<LintContext<'a>> for ReadonlyWalker<'a> {
+    fn walk_in_class(&mut self, class: &Class, context: &mut LintContext<'a>) {
+        self.enter_class_like(&class.members, class.left_brace, context);
+    }
+
+    fn walk_out_class(&mut self, class: &Class, context: &mut LintContext<'a>) {
+        self.exit_class_like(class, &class.attributes, &class.members, context);
+    }
+
+    fn walk_in_anonymous_class(&mut self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        self.enter_class_like(&anonymous_class.members, anonymous_class.left_brace, context);
+    }
+
+    fn walk_out_anonymous_class(&mut self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        self.exit_class_like(anonymous_class, &anonymous_class.attributes, &anonymous_class.members, context);
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        self.enter_class_like(&r#trait.members, r#trait.left_brace, context);
+    }
+
+    fn walk_out_trait(&mut self, _trait: &Trait, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        self.enter_class_like(&r#enum.members, r#enum.left_brace, context);
+    }
+
+    fn walk_out_enum(&mut self, _enum: &Enum, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_method(&mut self, method: &Method, context: &mut LintContext<'a>) {
+        let name = context.lookup(&method.name.value);
+        let kind = if name.eq_ignore_ascii_case("__construct") {
+            FunctionLikeKind::Constructor
+        } else if name.eq_ignore_ascii_case("__clone") {
+            FunctionLikeKind::Clone
+        } else {
+            FunctionLikeKind::Other
+        };
+
+        self.function_likes.push(FunctionLikeState { kind, cloned_variables: HashSet::default() });
+    }
+
+    fn walk_out_method(&mut self, _method: &Method, _context: &mut LintContext<'a>) {
+        self.function_likes.pop();
+    }
+
+    fn walk_in_function(&mut self, _function: &Function, _context: &mut LintContext<'a>) {
+        self.function_likes.push(FunctionLikeState::default());
+    }
+
+    fn walk_out_function(&mut self, _function: &Function, _context: &mut LintContext<'a>) {
+        self.function_likes.pop();
+    }
+
+    fn walk_in_closure(&mut self, _closure: &Closure, _context: &mut LintContext<'a>) {
+        self.function_likes.push(FunctionLikeState::default());
+    }
+
+    fn walk_out_closure(&mut self, _closure: &Closure, _context: &mut LintContext<'a>) {
+        self.function_likes.pop();
+    }
+
+    fn walk_in_assignment(&mut self, assignment: &Assignment, context: &mut LintContext<'a>) {
+        let is_assign = matches!(assignment.operator, AssignmentOperator::Assign(_));
+
+        if let (Expression::Variable(Variable::Direct(variable)), Some(function_like)) =
+            (assignment.lhs.as_ref(), self.function_likes.last_mut())
+        {
+            if is_assign && matches!(assignment.rhs.as_ref(), Expression::Clone(_)) {
+                function_like.cloned_variables.insert(variable.name);
+            } else {
+                function_like.cloned_variables.remove(&variable.name);
+            }
+        }
+
+        let write = if is_assign { Write::Assignment } else { Write::Modification(Modification::CompoundAssignment) };
+
+        self.check_target(&assignment.lhs, write, context);
+    }
+
+    fn walk_in_unary_prefix(&mut self, unary_prefix: &UnaryPrefix, context: &mut LintContext<'a>) {
+        let modification = match unary_prefix.operator {
+            UnaryPrefixOperator::PreIncrement(_) => Modification::Increment,
+            UnaryPrefixOperator::PreDecrement(_) => Modification::Decrement,
+            UnaryPrefixOperator::Reference(_) => Modification::Reference,
+            _ => return,
+        };
+
+        self.check_target(&unary_prefix.operand, Write::Modification(modification), context);
+    }
+
+    fn walk_in_unary_postfix(&mut self, unary_postfix: &UnaryPostfix, context: &mut LintContext<'a>) {
+        let modification = match unary_postfix.operator {
+            UnaryPostfixOperator::PostIncrement(_) => Modification::Increment,
+            UnaryPostfixOperator::PostDecrement(_) => Modification::Decrement,
+        };
+
+        self.check_target(&unary_postfix.operand, Write::Modification(modification), context);
+    }
+
+    fn walk_in_foreach(&mut self, foreach: &Foreach, context: &mut LintContext<'a>) {
+        let value = match &foreach.target {
+            ForeachTarget::Value(target) => &target.value,
+            ForeachTarget::KeyValue(target) => {
+                self.check_target(&target.key, Write::Assignment, context);
+
+                &target.value
+            }
+        };
+
+        // Iterating by reference takes a reference to the iterated array.
+        if matches!(value, Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), .. })) {
+            self.check_target(&foreach.expression, Write::Modification(Modification::Reference), context);
+        } else {
+            self.check_target(value, Write::Assignment, context);
+        }
+    }
+
+    fn walk_in_unset(&mut self, unset: &Unset, context: &mut LintContext<'a>) {
+        for value in unset.values.iter() {
+            self.check_target(value, Write::Unset, context);
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, context: &mut LintContext<'a>) {
+        let function_like = get_callee_reflection(&function_call.function, context);
+
+        self.check_arguments(function_like.as_deref(), &function_call.arguments, context);
+    }
+
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, context: &mut LintContext<'a>) {
+        let method = get_method_reflection(&method_call.object, &method_call.method, context);
+
+        self.check_arguments(method.as_deref(), &method_call.arguments, context);
+    }
+
+    fn walk_in_null_safe_method_call(
+        &mut self,
+        null_safe_method_call: &NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let method = get_method_reflection(&null_safe_method_call.object, &null_safe_method_call.method, context);
+
+        self.check_arguments(method.as_deref(), &null_safe_method_call.arguments, context);
+    }
+
+    fn walk_in_static_method_call(&mut self, static_method_call: &StaticMethodCall, context: &mut LintContext<'a>) {
+        let method = get_static_method_reflection(&static_method_call.class, &static_method_call.method, context);
+
+        self.check_arguments(method.as_deref(), &static_method_call.arguments, context);
+    }
+
+    fn walk_in_construct(&mut self, construct: &Construct, _context: &mut LintContext<'a>) {
+        match construct {
+            Construct::Isset(isset) => {
+                for value in isset.values.iter() {
+                    self.guard(value);
+                }
+            }
+            Construct::Empty(empty) => self.guard(&empty.value),
+            _ => {}
+        }
+    }
+
+    fn walk_in_binary(&mut self, binary: &Binary, _context: &mut LintContext<'a>) {
+        if binary.operator.is_null_coalesce() {
+            self.guard(&binary.lhs);
+        }
+    }
+
+    fn walk_in_property_access(&mut self, property_access: &PropertyAccess, context: &mut LintContext<'a>) {
+        let span = property_access.span();
+        if self.written_accesses.contains(&span) || self.guarded_accesses.contains(&span) {
+            return;
+        }
+
+        let ClassLikeMemberSelector::Identifier(property) = &property_access.property else {
+            return;
+        };
+
+        if !is_this(&property_access.object, context) {
+            return;
+        }
+
+        if let Some(state) = self.class_likes.last_mut() {
+            state.reads.push((property.value, span));
+        }
+    }
+}
+
+fn report_uninitialized_reads(
+    class_like: &ClassLikeReflection,
+    property_name: &str,
+    declaration: Span,
+    reads: &[Span],
+    context: &mut LintContext<'_>,
+) {
+    let Some((first_read, other_reads)) = reads.split_first() else {
+        return;
+    };
+
+    let property_name = format!("{}::${}", class_like.name.get_key(context.interner), property_name);
+
+    let mut issue =
+        Issue::new(context.level(), format!("Property `{}` is read, but never initialized.", property_name))
+            .with_annotation(
+                Annotation::primary(*first_read).with_message("Reading an uninitialized property throws an `Error`."),
+            );
+
+    for read in other_reads {
+        issue = issue.with_annotation(Annotation::secondary(*read).with_message("The property is also read here."));
+    }
+
+    let issue = issue
+        .with_annotation(
+            Annotation::secondary(declaration)
+                .with_message(format!("Property `{}` is declared here, without a default value.", property_name)),
+        )
+        .with_help("Initialize the property in the constructor, or give it a default value.");
+
+    context.report(issue);
+}
+
+fn get_readonly_annotation(
+    class_like: &ClassLikeReflection,
+    property: &PropertyReflection,
+    context: &LintContext<'_>,
+) -> Annotation {
+    let annotation = Annotation::secondary(property.definition_span);
+
+    if class_like.is_readonly {
+        annotation.with_message(format!(
+            "The property is readonly, as class `{}` is declared readonly.",
+            class_like.name.get_key(context.interner)
+        ))
+    } else {
+        annotation.with_message("The property is declared readonly here.")
+    }
+}
+
+fn is_this(expression: &Expression, context: &LintContext<'_>) -> bool {
+    matches!(expression, Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this")
+}
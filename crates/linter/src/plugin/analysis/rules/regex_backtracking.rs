@@ -0,0 +1,58 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::literal::DecodedString;
+use crate::pcre::parse_pattern;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct RegexBacktrackingRule;
+
+impl Rule for RegexBacktrackingRule {
+    fn get_name(&self) -> &'static str {
+        "regex-backtracking"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RegexBacktrackingRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Some((name, patterns)) = get_regex_patterns(function_call, context) else {
+            return;
+        };
+
+        for literal in patterns {
+            let Some(decoded) = DecodedString::decode(literal, context) else {
+                continue;
+            };
+
+            for backtracking in parse_pattern(&decoded.value).backtracking {
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("Regular expression pattern passed to `{}()` may cause catastrophic backtracking.", name),
+                    )
+                    .with_annotations([
+                        Annotation::primary(decoded.span(backtracking.start, backtracking.end))
+                            .with_message(backtracking.message),
+                        Annotation::secondary(literal.span),
+                    ])
+                    .with_note(
+                        "When a match fails, the engine tries every way of splitting the input between the nested quantifiers, \
+                        which takes exponential time; once the backtracking limit is hit, the function returns `false` or `null`.",
+                    )
+                    .with_help(
+                        "Make the inner quantifier possessive (e.g. `(?:a++)*`), use an atomic group (e.g. `(?>a+)*`), \
+                        or rewrite the pattern so that the repetitions cannot overlap.",
+                    ),
+                );
+            }
+        }
+    }
+}
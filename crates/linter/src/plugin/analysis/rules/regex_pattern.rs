@@ -0,0 +1,131 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::literal::DecodedString;
+use crate::pcre::parse_pattern;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct RegexPatternRule;
+
+impl Rule for RegexPatternRule {
+    fn get_name(&self) -> &'static str {
+        "regex-pattern"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RegexPatternRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Some((name, patterns)) = get_regex_patterns(function_call, context) else {
+            return;
+        };
+
+        for literal in patterns.iter() {
+            let Some(decoded) = DecodedString::decode(literal, context) else {
+                continue;
+            };
+
+            let pattern = parse_pattern(&decoded.value);
+            let Some(error) = pattern.error else {
+                // Replacements only refer to the groups of a single pattern, rather than an array of patterns.
+                let is_single = matches!(
+                    get_argument(&function_call.arguments, 0, "pattern", context),
+                    Some(Expression::Literal(Literal::String(_)))
+                );
+
+                if is_single && matches!(name.as_str(), "preg_replace" | "preg_filter") {
+                    check_replacement(function_call, &name, pattern.captures, context);
+                }
+
+                continue;
+            };
+
+            context.report(
+                Issue::new(context.level(), format!("Invalid regular expression pattern passed to `{}()`.", name))
+                    .with_annotations([
+                        Annotation::primary(decoded.span(error.start, error.end)).with_message(error.message),
+                        Annotation::secondary(literal.span).with_message("This pattern fails to compile."),
+                    ])
+                    .with_note(format!(
+                        "When given an invalid pattern, `{}()` emits a warning and returns `{}`.",
+                        name,
+                        if name.starts_with("preg_replace") || name == "preg_filter" { "null" } else { "false" }
+                    ))
+                    .with_help("Fix the pattern so that it compiles."),
+            );
+        }
+    }
+}
+
+/// Checks that the group references of a literal replacement refer to groups of the pattern.
+fn check_replacement(function_call: &FunctionCall, name: &str, captures: usize, context: &mut LintContext<'_>) {
+    let Some(Expression::Literal(Literal::String(literal))) =
+        get_argument(&function_call.arguments, 1, "replacement", context)
+    else {
+        return;
+    };
+
+    let Some(decoded) = DecodedString::decode(literal, context) else {
+        return;
+    };
+
+    let value = &decoded.value;
+    let mut index = 0;
+    while index < value.len() {
+        let start = index;
+        let digits_start = match (value[index], value.get(index + 1)) {
+            (b'\\', Some(b'\\')) => {
+                index += 2;
+
+                continue;
+            }
+            (b'\\' | b'$', Some(digit)) if digit.is_ascii_digit() => index + 1,
+            (b'$', Some(b'{')) if value.get(index + 2).is_some_and(u8::is_ascii_digit) => index + 2,
+            _ => {
+                index += 1;
+
+                continue;
+            }
+        };
+
+        let digits = value[digits_start..].iter().take(2).take_while(|digit| digit.is_ascii_digit()).count();
+        let group = value[digits_start..digits_start + digits]
+            .iter()
+            .fold(0usize, |group, digit| group * 10 + (digit - b'0') as usize);
+
+        index = digits_start + digits;
+        if value[start] == b'$' && digits_start == start + 2 {
+            if value.get(index) != Some(&b'}') {
+                continue;
+            }
+
+            index += 1;
+        }
+
+        if group <= captures {
+            continue;
+        }
+
+        let reference = String::from_utf8_lossy(&value[start..index]).to_string();
+        context.report(
+            Issue::new(
+                context.level(),
+                format!("Replacement passed to `{}()` refers to a non-existent group `{}`.", name, reference),
+            )
+            .with_annotation(Annotation::primary(decoded.span(start, index)).with_message(format!(
+                "The pattern has {} capturing group{}.",
+                captures,
+                if captures == 1 { "" } else { "s" }
+            )))
+            .with_note("References to non-existent groups are replaced with an empty string.")
+            .with_help("Refer to an existing group, or add the missing group to the pattern."),
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use mago_ast::*;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::r#type::kind::*;
+use mago_reflection::r#type::TypeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_trinary::Trinary;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReturnTypeRule;
+
+impl Rule for ReturnTypeRule {
+    fn get_name(&self) -> &'static str {
+        "return-type"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ReturnTypeRule {
+    fn walk_in_function<'ast>(&self, function: &'ast Function, context: &mut LintContext<'a>) {
+        check_missing_return(&function.body, context);
+    }
+
+    fn walk_in_method<'ast>(&self, method: &'ast Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(body) = &method.body else {
+            return;
+        };
+
+        check_missing_return(body, context);
+    }
+
+    fn walk_in_closure<'ast>(&self, closure: &'ast Closure, context: &mut LintContext<'a>) {
+        check_missing_return(&closure.body, context);
+    }
+
+    fn walk_in_return<'ast>(&self, r#return: &'ast Return, context: &mut LintContext<'a>) {
+        let Some(function_like) = context.codebase.get_enclosing_function_like(r#return) else {
+            return;
+        };
+
+        let Some(declared_type) = get_declared_return_type(function_like) else {
+            return;
+        };
+
+        let (returned_kind, span) = match &r#return.value {
+            Some(value) => (context.resolve_kind(value), value.span()),
+            None => (void_kind(), r#return.span()),
+        };
+
+        check_returned_kind(function_like, &declared_type, returned_kind, span, context);
+    }
+
+    fn walk_in_arrow_function<'ast>(&self, arrow_function: &'ast ArrowFunction, context: &mut LintContext<'a>) {
+        let name = FunctionLikeName::ArrowFunction(arrow_function.span());
+        let Some(function_like) = context.codebase.get_function_like(name) else {
+            return;
+        };
+
+        let Some(declared_type) = get_declared_return_type(function_like) else {
+            return;
+        };
+
+        let returned_kind = context.resolve_kind(&arrow_function.expression);
+
+        check_returned_kind(function_like, &declared_type, returned_kind, arrow_function.expression.span(), context);
+    }
+
+    fn walk_in_yield_value<'ast>(&self, yield_value: &'ast YieldValue, context: &mut LintContext<'a>) {
+        let Some(value) = &yield_value.value else {
+            return;
+        };
+
+        check_yield(None, value, yield_value.span(), context);
+    }
+
+    fn walk_in_yield_pair<'ast>(&self, yield_pair: &'ast YieldPair, context: &mut LintContext<'a>) {
+        check_yield(Some(&yield_pair.key), &yield_pair.value, yield_pair.span(), context);
+    }
+}
+
+/// Returns the type against which returned values of the given function-like should be checked.
+///
+/// For generators, this is the `TReturn` type of the declared `Generator`, if any.
+fn get_declared_return_type(function_like: &FunctionLikeReflection) -> Option<TypeReflection> {
+    let declared_type = function_like.get_return_type_reflection()?;
+
+    if function_like.has_yield {
+        let (_, _, r#return) = get_generator_parameters(&declared_type.kind)?;
+
+        return Some(TypeReflection { kind: r#return?, inferred: declared_type.inferred, span: declared_type.span });
+    }
+
+    match declared_type.kind {
+        // Returning from `void` and `never` functions is already validated by the semantics checker.
+        TypeKind::Void | TypeKind::Never | TypeKind::Mixed { .. } => None,
+        _ => Some(declared_type.clone()),
+    }
+}
+
+/// Extracts the key, value, and return types yielded by a generator declared with the given type.
+///
+/// Returns `None` if the given type does not describe what the generator yields.
+fn get_generator_parameters(kind: &TypeKind) -> Option<(Option<TypeKind>, TypeKind, Option<TypeKind>)> {
+    match kind {
+        TypeKind::Object(ObjectTypeKind::Generator { key, value, r#return, .. }) => {
+            Some((Some(*key.clone()), *value.clone(), Some(*r#return.clone())))
+        }
+        TypeKind::Iterable { key, value } => Some((Some(*key.clone()), *value.clone(), None)),
+        TypeKind::Object(ObjectTypeKind::NamedObject { type_parameters, .. }) => match type_parameters.as_slice() {
+            [value] => Some((None, value.clone(), None)),
+            [key, value] => Some((Some(key.clone()), value.clone(), None)),
+            [key, value, _] => Some((Some(key.clone()), value.clone(), None)),
+            [key, value, _, r#return] => Some((Some(key.clone()), value.clone(), Some(r#return.clone()))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn check_returned_kind(
+    function_like: &FunctionLikeReflection,
+    declared_type: &TypeReflection,
+    returned_kind: TypeKind,
+    span: Span,
+    context: &mut LintContext<'_>,
+) {
+    let accepted_kind = get_accepted_kind(&declared_type.kind, is_strict_types_enabled(context));
+    let incompatible_kinds = get_incompatible_kinds(&returned_kind, &accepted_kind, context);
+    if incompatible_kinds.is_empty() {
+        return;
+    }
+
+    let function_name = get_function_like_display_name(function_like, context);
+    let declared_kind = declared_type.kind.get_key(context.interner);
+    let returned_key = returned_kind.get_key(context.interner);
+    let (message, annotation) = if context.is_contained_by(&returned_kind, &accepted_kind) == Trinary::False {
+        (
+            format!("`{}` returns `{}`, but is declared to return `{}`.", function_name, returned_key, declared_kind),
+            format!("This value is of type `{}`.", returned_key),
+        )
+    } else {
+        let incompatible_key = union_kind(incompatible_kinds).get_key(context.interner);
+
+        (
+            format!(
+                "`{}` may return `{}`, but is declared to return `{}`.",
+                function_name, incompatible_key, declared_kind
+            ),
+            format!("This value is of type `{}`, which includes `{}`.", returned_key, incompatible_key),
+        )
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(span).with_message(annotation))
+        .with_annotation(
+            Annotation::secondary(declared_type.span)
+                .with_message(format!("The declared return type is `{}`.", declared_kind)),
+        )
+        .with_help(format!("Return a value of type `{}`, or update the declared return type.", declared_kind));
+
+    context.report(issue);
+}
+
+fn check_yield(key: Option<&Expression>, value: &Expression, span: Span, context: &mut LintContext<'_>) {
+    let Some(function_like) = context.codebase.get_enclosing_function_like(&span) else {
+        return;
+    };
+
+    let Some(declared_type) = function_like.get_return_type_reflection() else {
+        return;
+    };
+
+    let Some((declared_key, declared_value, _)) = get_generator_parameters(&declared_type.kind) else {
+        return;
+    };
+
+    let strict_types = is_strict_types_enabled(context);
+    let mut yielded = vec![("value", value, declared_value)];
+    if let (Some(key), Some(declared_key)) = (key, declared_key) {
+        yielded.push(("key", key, declared_key));
+    }
+
+    for (what, expression, declared_kind) in yielded {
+        let yielded_kind = context.resolve_kind(expression);
+        let accepted_kind = get_accepted_kind(&declared_kind, strict_types);
+        let incompatible_kinds = get_incompatible_kinds(&yielded_kind, &accepted_kind, context);
+        if incompatible_kinds.is_empty() {
+            continue;
+        }
+
+        let function_name = get_function_like_display_name(function_like, context);
+        let yielded_key = yielded_kind.get_key(context.interner);
+        let declared_key = declared_kind.get_key(context.interner);
+        let (message, annotation) = if context.is_contained_by(&yielded_kind, &accepted_kind) == Trinary::False {
+            (
+                format!(
+                    "`{}` yields a {} of type `{}`, but is declared to yield {}s of type `{}`.",
+                    function_name, what, yielded_key, what, declared_key
+                ),
+                format!("This {} is of type `{}`.", what, yielded_key),
+            )
+        } else {
+            let incompatible_key = union_kind(incompatible_kinds).get_key(context.interner);
+
+            (
+                format!(
+                    "`{}` may yield a {} of type `{}`, but is declared to yield {}s of type `{}`.",
+                    function_name, what, incompatible_key, what, declared_key
+                ),
+                format!("This {} is of type `{}`, which includes `{}`.", what, yielded_key, incompatible_key),
+            )
+        };
+
+        let issue = Issue::new(context.level(), message)
+            .with_annotation(Annotation::primary(expression.span()).with_message(annotation))
+            .with_annotation(Annotation::secondary(declared_type.span).with_message(format!(
+                "The declared return type is `{}`.",
+                declared_type.kind.get_key(context.interner)
+            )))
+            .with_help(format!("Yield a {} of type `{}`, or update the declared return type.", what, declared_key));
+
+        context.report(issue);
+    }
+}
+
+fn check_missing_return(body: &Block, context: &mut LintContext<'_>) {
+    let Some(function_like) = context.codebase.get_enclosing_function_like(&body.left_brace) else {
+        return;
+    };
+
+    if function_like.has_yield || statements_always_terminate(body.statements.iter(), context) {
+        return;
+    }
+
+    let function_name = get_function_like_display_name(function_like, context);
+    if let Some(return_type) = &function_like.return_type_reflection {
+        let declared_kind = &return_type.type_reflection.kind;
+        let issue = match declared_kind {
+            TypeKind::Void | TypeKind::Mixed { .. } => return,
+            TypeKind::Never => Issue::new(
+                context.level(),
+                format!("`{}` is declared to never return, but may return implicitly.", function_name),
+            )
+            .with_annotation(
+                Annotation::primary(body.right_brace).with_message("Execution may reach the end of the body here."),
+            )
+            .with_annotation(
+                Annotation::secondary(return_type.span).with_message("The return type is declared as `never`."),
+            )
+            .with_help("Make sure every code path throws an exception or exits."),
+            _ => Issue::new(context.level(), format!("Not all code paths of `{}` return a value.", function_name))
+                .with_annotation(
+                    Annotation::primary(body.right_brace)
+                        .with_message("Execution may reach the end of the body here, implicitly returning `null`."),
+                )
+                .with_annotation(Annotation::secondary(return_type.span).with_message(format!(
+                    "The declared return type is `{}`.",
+                    declared_kind.get_key(context.interner)
+                )))
+                .with_help("Add a `return` statement at the end of the body, or throw an exception."),
+        };
+
+        context.report(issue);
+
+        return;
+    }
+
+    let Some(docblock_return_type) = &function_like.docblock_return_type_reflection else {
+        return;
+    };
+
+    if matches!(docblock_return_type.kind, TypeKind::Void | TypeKind::Mixed { .. })
+        || context.is_contained_by(&null_kind(), &docblock_return_type.kind) != Trinary::False
+    {
+        return;
+    }
+
+    let issue = Issue::new(context.level(), format!("Not all code paths of `{}` return a value.", function_name))
+        .with_annotation(
+            Annotation::primary(body.right_brace)
+                .with_message("Execution may reach the end of the body here, implicitly returning `null`."),
+        )
+        .with_annotation(Annotation::secondary(docblock_return_type.span).with_message(format!(
+            "The documented return type is `{}`.",
+            docblock_return_type.kind.get_key(context.interner)
+        )))
+        .with_help("Add a `return` statement at the end of the body, or throw an exception.");
+
+    context.report(issue);
+}
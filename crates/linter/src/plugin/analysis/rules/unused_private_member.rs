@@ -0,0 +1,443 @@
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::MutWalker;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::analysis::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnusedPrivateMemberRule;
+
+impl Rule for UnusedPrivateMemberRule {
+    fn get_name(&self) -> &'static str {
+        "unused-private-member"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnusedPrivateMemberRule {
+    fn walk_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let mut usage = MemberUsage::default();
+        usage.walk_program(program, context);
+
+        let mut walker = UnusedMemberWalker { usage };
+        walker.walk_program(program, context);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MemberKind {
+    Method,
+    Property,
+    Constant,
+}
+
+/// The names of the members accessed within a file.
+///
+/// Private members can only be accessed from within the class-like declaring them, so the accesses
+/// are collected by name, regardless of the type of the object or class-like they are accessed on.
+#[derive(Debug, Default)]
+struct MemberUsage {
+    /// The lowercased names of the methods called, and where.
+    methods: Vec<(String, Span)>,
+
+    /// The names of the properties accessed, without the leading `$`.
+    properties: HashSet<String>,
+
+    /// The names of the class-like constants and enum cases accessed.
+    constants: HashSet<String>,
+
+    /// The lowercased values of the string literals, which may refer to members, e.g. `[$this, 'method']`.
+    strings: HashSet<String>,
+
+    has_dynamic_method_access: bool,
+    has_dynamic_property_access: bool,
+    has_dynamic_constant_access: bool,
+}
+
+impl MemberUsage {
+    fn add_method(&mut self, selector: &ClassLikeMemberSelector, context: &LintContext<'_>) {
+        match selector {
+            ClassLikeMemberSelector::Identifier(identifier) => {
+                self.methods.push((context.lookup(&identifier.value).to_ascii_lowercase(), identifier.span));
+            }
+            _ => {
+                self.has_dynamic_method_access = true;
+            }
+        }
+    }
+
+    fn add_property(&mut self, selector: &ClassLikeMemberSelector, context: &LintContext<'_>) {
+        match selector {
+            ClassLikeMemberSelector::Identifier(identifier) => {
+                self.properties.insert(context.lookup(&identifier.value).to_string());
+            }
+            _ => {
+                self.has_dynamic_property_access = true;
+            }
+        }
+    }
+
+    fn is_method_used(&self, name: &str, declaration: Span) -> bool {
+        let name = name.to_ascii_lowercase();
+
+        // Recursive calls from within the method itself do not make it used.
+        self.methods.iter().any(|(method, span)| *method == name && !declaration.contains(span))
+            || self.strings.contains(&name)
+    }
+
+    fn is_property_used(&self, name: &str) -> bool {
+        self.properties.contains(name) || self.strings.contains(&name.to_ascii_lowercase())
+    }
+
+    fn is_constant_used(&self, name: &str) -> bool {
+        let lowercase_name = name.to_ascii_lowercase();
+        let suffix = format!("::{}", lowercase_name);
+
+        self.constants.contains(name)
+            || self.strings.iter().any(|string| *string == lowercase_name || string.ends_with(&suffix))
+    }
+}
+
+impl<'a> MutWalker<LintContext<'a>> for MemberUsage {
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, context: &mut LintContext<'a>) {
+        self.add_method(&method_call.method, context);
+    }
+
+    fn walk_in_null_safe_method_call(
+        &mut self,
+        null_safe_method_call: &NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        self.add_method(&null_safe_method_call.method, context);
+    }
+
+    fn walk_in_static_method_call(&mut self, static_method_call: &StaticMethodCall, context: &mut LintContext<'a>) {
+        self.add_method(&static_method_call.method, context);
+    }
+
+    fn walk_in_method_closure_creation(
+        &mut self,
+        method_closure_creation: &MethodClosureCreation,
+        context: &mut LintContext<'a>,
+    ) {
+        self.add_method(&method_closure_creation.method, context);
+    }
+
+    fn walk_in_static_method_closure_creation(
+        &mut self,
+        static_method_closure_creation: &StaticMethodClosureCreation,
+        context: &mut LintContext<'a>,
+    ) {
+        self.add_method(&static_method_closure_creation.method, context);
+    }
+
+    fn walk_in_trait_use_method_reference(
+        &mut self,
+        trait_use_method_reference: &TraitUseMethodReference,
+        context: &mut LintContext<'a>,
+    ) {
+        let identifier = match trait_use_method_reference {
+            TraitUseMethodReference::Identifier(identifier) => identifier,
+            TraitUseMethodReference::Absolute(reference) => &reference.method_name,
+        };
+
+        self.methods.push((context.lookup(&identifier.value).to_ascii_lowercase(), identifier.span));
+    }
+
+    fn walk_in_property_access(&mut self, property_access: &PropertyAccess, context: &mut LintContext<'a>) {
+        self.add_property(&property_access.property, context);
+    }
+
+    fn walk_in_null_safe_property_access(
+        &mut self,
+        null_safe_property_access: &NullSafePropertyAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        self.add_property(&null_safe_property_access.property, context);
+    }
+
+    fn walk_in_static_property_access(
+        &mut self,
+        static_property_access: &StaticPropertyAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        match &static_property_access.property {
+            Variable::Direct(variable) => {
+                self.properties.insert(context.lookup(&variable.name)[1..].to_string());
+            }
+            _ => {
+                self.has_dynamic_property_access = true;
+            }
+        }
+    }
+
+    fn walk_in_class_constant_access(
+        &mut self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut LintContext<'a>,
+    ) {
+        match &class_constant_access.constant {
+            ClassLikeConstantSelector::Identifier(identifier) => {
+                self.constants.insert(context.lookup(&identifier.value).to_string());
+            }
+            ClassLikeConstantSelector::Expression(_) => {
+                self.has_dynamic_constant_access = true;
+            }
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        match context.lookup_function_name(identifier).to_ascii_lowercase().as_str() {
+            "get_object_vars" | "get_class_vars" => {
+                self.has_dynamic_property_access = true;
+            }
+            "get_class_methods" => {
+                self.has_dynamic_method_access = true;
+            }
+            "constant" => {
+                let is_literal = function_call
+                    .arguments
+                    .arguments
+                    .first()
+                    .is_some_and(|argument| matches!(argument.value(), Expression::Literal(Literal::String(_))));
+
+                if !is_literal {
+                    self.has_dynamic_constant_access = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn walk_in_instantiation(&mut self, instantiation: &Instantiation, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = &instantiation.class else {
+            return;
+        };
+
+        // Members may be accessed using the reflection API, e.g. `new ReflectionClass($this)`.
+        if context.lookup_name(identifier).trim_start_matches('\\').starts_with("Reflection") {
+            self.has_dynamic_method_access = true;
+            self.has_dynamic_property_access = true;
+            self.has_dynamic_constant_access = true;
+        }
+    }
+
+    fn walk_in_literal_string(&mut self, literal_string: &LiteralString, context: &mut LintContext<'a>) {
+        let value = context.lookup(&literal_string.value);
+        if value.len() < 2 {
+            return;
+        }
+
+        self.strings.insert(value[1..value.len() - 1].to_ascii_lowercase());
+    }
+}
+
+struct UnusedMemberWalker {
+    usage: MemberUsage,
+}
+
+impl UnusedMemberWalker {
+    fn check_class_like(
+        &self,
+        left_brace: Span,
+        attributes: &Sequence<AttributeList>,
+        members: &Sequence<ClassLikeMember>,
+        context: &mut LintContext<'_>,
+    ) {
+        let Some(reflection) = context.codebase.get_enclosing_class_like(&left_brace) else {
+            return;
+        };
+
+        // Members of class-likes hydrated by libraries may be accessed using reflection.
+        if attributes.iter().any(|attribute_list| !attribute_list.attributes.is_empty()) {
+            return;
+        }
+
+        // Members of class-likes sharing a trait with another file may be accessed from that file, in which case
+        // the accessed member names of the entire codebase are used, if they are available.
+        let is_shared = is_sharing_traits_across_files(reflection, context);
+        if is_shared && context.references.is_none() {
+            return;
+        }
+
+        let is_accessed_elsewhere = |name: &str, context: &LintContext<'_>| {
+            is_shared
+                && context
+                    .references
+                    .is_some_and(|references| references.is_member_name_accessed(context.interner, name))
+        };
+
+        for member in members.iter() {
+            match member {
+                ClassLikeMember::Method(method) => {
+                    if method.modifiers.contains_private()
+                        && !self.usage.has_dynamic_method_access
+                        && method.attributes.is_empty()
+                        && !has_annotations(method, context)
+                    {
+                        let name = context.lookup(&method.name.value).to_string();
+
+                        if !name.starts_with("__")
+                            && !self.usage.is_method_used(&name, method.span())
+                            && !is_accessed_elsewhere(&name, context)
+                        {
+                            report(MemberKind::Method, &name, method.name.span, context);
+                        }
+                    }
+
+                    if !context.lookup(&method.name.value).eq_ignore_ascii_case("__construct")
+                        || self.usage.has_dynamic_property_access
+                    {
+                        continue;
+                    }
+
+                    for parameter in method.parameters.parameters.iter() {
+                        if !parameter.modifiers.contains_private()
+                            || parameter.hooks.is_some()
+                            || !parameter.attributes.is_empty()
+                        {
+                            continue;
+                        }
+
+                        let name = context.lookup(&parameter.variable.name)[1..].to_string();
+                        if !self.usage.is_property_used(&name) && !is_accessed_elsewhere(&name, context) {
+                            report(MemberKind::Property, &name, parameter.variable.span, context);
+                        }
+                    }
+                }
+                ClassLikeMember::Property(Property::Plain(property)) => {
+                    if !property.modifiers.contains_private()
+                        || self.usage.has_dynamic_property_access
+                        || !property.attributes.is_empty()
+                        || has_annotations(property, context)
+                    {
+                        continue;
+                    }
+
+                    for item in property.items.iter() {
+                        let variable = item.variable();
+                        let name = context.lookup(&variable.name)[1..].to_string();
+
+                        if !self.usage.is_property_used(&name) && !is_accessed_elsewhere(&name, context) {
+                            report(MemberKind::Property, &name, variable.span, context);
+                        }
+                    }
+                }
+                ClassLikeMember::Constant(constant) => {
+                    if !constant.modifiers.contains_private()
+                        || self.usage.has_dynamic_constant_access
+                        || !constant.attributes.is_empty()
+                    {
+                        continue;
+                    }
+
+                    for item in constant.items.iter() {
+                        let name = context.lookup(&item.name.value).to_string();
+
+                        if !self.usage.is_constant_used(&name) && !is_accessed_elsewhere(&name, context) {
+                            report(MemberKind::Constant, &name, item.name.span, context);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'a> MutWalker<LintContext<'a>> for UnusedMemberWalker {
+    fn walk_in_class(&mut self, class: &Class, context: &mut LintContext<'a>) {
+        self.check_class_like(class.left_brace, &class.attributes, &class.members, context);
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        self.check_class_like(r#trait.left_brace, &r#trait.attributes, &r#trait.members, context);
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        self.check_class_like(r#enum.left_brace, &r#enum.attributes, &r#enum.members, context);
+    }
+
+    fn walk_in_anonymous_class(&mut self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        self.check_class_like(
+            anonymous_class.left_brace,
+            &anonymous_class.attributes,
+            &anonymous_class.members,
+            context,
+        );
+    }
+}
+
+/// Determines whether the given class-like uses a trait declared in another file, or is a trait used by
+/// a class-like declared in another file, in which case its private members may be accessed from that file.
+fn is_sharing_traits_across_files(reflection: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    let source = context.semantics.source.identifier;
+    let is_declared_elsewhere = |name: &StringIdentifier| {
+        context.codebase.get_named_class_like(name).is_none_or(|class_like| class_like.span.start.source != source)
+    };
+
+    if reflection.used_traits.iter().any(is_declared_elsewhere) {
+        return true;
+    }
+
+    if !reflection.is_trait() {
+        return false;
+    }
+
+    let Some(trait_name) = reflection.name.inner() else {
+        return false;
+    };
+
+    let trait_name = context.lookup(&trait_name.value);
+
+    context.codebase.class_like_reflections.values().any(|class_like| {
+        class_like.span.start.source != source
+            && class_like
+                .used_traits
+                .iter()
+                .any(|used_trait| context.lookup(used_trait).eq_ignore_ascii_case(trait_name))
+    })
+}
+
+fn report(kind: MemberKind, name: &str, span: Span, context: &mut LintContext<'_>) {
+    let (_, _, class_like_fqcn, _) = context.get_class_like_details(&span);
+
+    let (message, annotation, help) = match kind {
+        MemberKind::Method => (
+            format!("Private method `{}::{}()` is never used.", class_like_fqcn, name),
+            format!("Method `{}` is never called.", name),
+            "Remove the unused method.",
+        ),
+        MemberKind::Property => (
+            format!("Private property `{}::${}` is never used.", class_like_fqcn, name),
+            format!("Property `${}` is never accessed.", name),
+            "Remove the unused property.",
+        ),
+        MemberKind::Constant => (
+            format!("Private constant `{}::{}` is never used.", class_like_fqcn, name),
+            format!("Constant `{}` is never accessed.", name),
+            "Remove the unused constant.",
+        ),
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(span).with_message(annotation))
+        .with_help(help);
+
+    context.report(issue);
+}
@@ -0,0 +1,262 @@
+use mago_ast::*;
+use mago_reference::symbol::Symbol;
+use mago_reference::ReferenceIndex;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnusedSymbolRule;
+
+impl Rule for UnusedSymbolRule {
+    fn get_name(&self) -> &'static str {
+        "unused-symbol"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        None
+    }
+
+    fn requires_references(&self) -> bool {
+        true
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnusedSymbolRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        check_class_like("Class", &class.name, &class.members, context);
+    }
+
+    fn walk_in_interface(&self, interface: &Interface, context: &mut LintContext<'a>) {
+        check_class_like("Interface", &interface.name, &interface.members, context);
+    }
+
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        check_class_like("Trait", &r#trait.name, &r#trait.members, context);
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        check_class_like("Enum", &r#enum.name, &r#enum.members, context);
+    }
+
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'a>) {
+        let Some(references) = context.references else {
+            return;
+        };
+
+        let name = context.lookup_name(&function.name).to_string();
+        let Some(reflection) = context.codebase.get_function(context.semantics.names.get(&function.name)) else {
+            return;
+        };
+
+        let FunctionLikeName::Function(function_name) = reflection.name else {
+            return;
+        };
+
+        if is_entry_point(&name, context)
+            || is_referenced(references, &Symbol::Function(function_name.value), reflection.span)
+            || references.is_string_referenced(context.interner, &name)
+        {
+            return;
+        }
+
+        report("Function", &format!("{}()", name), function.name.span, context);
+    }
+}
+
+fn check_class_like(
+    kind: &'static str,
+    name: &LocalIdentifier,
+    members: &Sequence<ClassLikeMember>,
+    context: &mut LintContext<'_>,
+) {
+    let Some(references) = context.references else {
+        return;
+    };
+
+    let Some(reflection) = context.codebase.get_named_class_like(context.semantics.names.get(name)) else {
+        return;
+    };
+
+    let class_like_name = context.lookup_name(name).to_string();
+    let is_entry_point = is_class_like_entry_point(reflection, members, context);
+
+    if !is_entry_point
+        && !is_referenced(references, &Symbol::ClassLike(reflection.name), reflection.span)
+        && !references.is_string_referenced(context.interner, &class_like_name)
+    {
+        report(kind, &class_like_name, name.span, context);
+
+        // The methods of an unused class-like are not reported separately.
+        return;
+    }
+
+    // Entry points are called by frameworks, and members of traits and interfaces are
+    // referenced through the class-likes using or implementing them.
+    if is_entry_point || reflection.is_trait() || reflection.is_interface() {
+        return;
+    }
+
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        if method.modifiers.contains_private()
+            || method.modifiers.contains_protected()
+            || method.modifiers.contains_abstract()
+        {
+            continue;
+        }
+
+        let method_name = context.lookup(&method.name.value).to_string();
+        if method_name.starts_with("__") || is_method_used(reflection, method, &method_name, references, context) {
+            continue;
+        }
+
+        report("Method", &format!("{}::{}()", class_like_name, method_name), method.name.span, context);
+    }
+}
+
+/// Determines whether the given method, or a method it overrides or is overridden by, is referenced.
+fn is_method_used(
+    class_like: &ClassLikeReflection,
+    method: &Method,
+    method_name: &str,
+    references: &ReferenceIndex,
+    context: &LintContext<'_>,
+) -> bool {
+    if references.is_string_referenced(context.interner, method_name)
+        || references.is_member_name_accessed_on_unresolved(context.interner, method_name)
+    {
+        return true;
+    }
+
+    if is_referenced(references, &Symbol::Method(class_like.name, method.name.value), method.span()) {
+        return true;
+    }
+
+    let inheritance = &class_like.inheritance;
+    let ancestors = inheritance
+        .all_extended_classes
+        .iter()
+        .chain(inheritance.all_implemented_interfaces.iter())
+        .chain(inheritance.all_extended_interfaces.iter())
+        .map(|name| name.value)
+        .chain(class_like.used_traits.iter().copied());
+
+    let descendants = class_like
+        .name
+        .inner()
+        .and_then(|name| context.codebase.all_classlike_descendants.get(&name.value))
+        .into_iter()
+        .flatten()
+        .copied();
+
+    for related_class_like_name in ancestors.chain(descendants) {
+        let Some(related_class_like) = context.codebase.get_named_class_like(&related_class_like_name) else {
+            // The method may implement a method of an unknown class-like.
+            return true;
+        };
+
+        let related_method = related_class_like
+            .methods
+            .members
+            .keys()
+            .find(|related_method_name| context.lookup(related_method_name).eq_ignore_ascii_case(method_name));
+
+        let Some(related_method) = related_method else {
+            continue;
+        };
+
+        // The method implements a method of an external class-like, which may be called by external code.
+        if related_class_like.span.start.source.is_external() {
+            return true;
+        }
+
+        if references.is_referenced(&Symbol::Method(related_class_like.name, *related_method)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Determines whether the given symbol is referenced from outside of its own declaration.
+fn is_referenced(references: &ReferenceIndex, symbol: &Symbol, declaration: Span) -> bool {
+    references.get_references(symbol).iter().any(|span| !declaration.contains(span))
+}
+
+/// Determines whether the given class-like is an entry point, i.e. whether its name, the name of one
+/// of its ancestors, or the name of one of its attributes or of the attributes of its methods, matches
+/// one of the configured entry point patterns.
+fn is_class_like_entry_point(
+    reflection: &ClassLikeReflection,
+    members: &Sequence<ClassLikeMember>,
+    context: &LintContext<'_>,
+) -> bool {
+    let inheritance = &reflection.inheritance;
+    let mut names = inheritance
+        .all_extended_classes
+        .iter()
+        .chain(inheritance.all_implemented_interfaces.iter())
+        .chain(inheritance.all_extended_interfaces.iter())
+        .chain(reflection.attribute_reflections.iter().map(|attribute| &attribute.name))
+        .map(|name| context.lookup(&name.value).to_string())
+        .chain(reflection.name.inner().map(|name| context.lookup(&name.value).to_string()))
+        .chain(
+            members
+                .iter()
+                .filter_map(|member| match member {
+                    ClassLikeMember::Method(method) => Some(method),
+                    _ => None,
+                })
+                .flat_map(|method| method.attributes.iter().flat_map(|attribute_list| attribute_list.attributes.iter()))
+                .map(|attribute| context.lookup_name(&attribute.name).to_string()),
+        );
+
+    names.any(|name| is_entry_point(&name, context))
+}
+
+fn is_entry_point(name: &str, context: &LintContext<'_>) -> bool {
+    let Some(patterns) = context.option("entry-points").and_then(|value| value.as_array()) else {
+        return false;
+    };
+
+    patterns.iter().filter_map(|pattern| pattern.as_str()).any(|pattern| {
+        matches_pattern(&pattern.trim_start_matches('\\').to_ascii_lowercase(), &name.to_ascii_lowercase())
+    })
+}
+
+/// Matches the given name against a pattern, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+
+            (0..=name.len())
+                .filter(|index| name.is_char_boundary(*index))
+                .any(|index| matches_pattern(rest, &name[index..]))
+        }
+    }
+}
+
+fn report(kind: &'static str, name: &str, span: Span, context: &mut LintContext<'_>) {
+    let issue = Issue::new(context.level(), format!("{} `{}` is never used.", kind, name))
+        .with_annotation(Annotation::primary(span).with_message(format!("{} `{}` is never referenced.", kind, name)))
+        .with_note("Only references from the analyzed sources are taken into account.")
+        .with_help(format!(
+            "Remove the unused {}, or add it to the `entry-points` option of this rule if it is used by a framework.",
+            kind.to_ascii_lowercase()
+        ));
+
+    context.report(issue);
+}
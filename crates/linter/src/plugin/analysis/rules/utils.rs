@@ -0,0 +1,450 @@
+use std::borrow::Cow;
+
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::r#type::kind::*;
+use mago_span::HasSpan;
+use mago_trinary::Trinary;
+
+use crate::consts::ANONYMOUS_CLASS_NAME;
+use crate::context::LintContext;
+
+/// Retrieves the reflection of the function-like called by the given callee expression of a function call.
+///
+/// This covers named functions, first-class callables (e.g. `strlen(...)`, `$foo->bar(...)`, `Foo::bar(...)`),
+/// and closures or arrow functions that are called directly.
+pub fn get_callee_reflection<'a>(
+    callee: &Expression,
+    context: &LintContext<'a>,
+) -> Option<Cow<'a, FunctionLikeReflection>> {
+    match callee {
+        Expression::Parenthesized(parenthesized) => get_callee_reflection(&parenthesized.expression, context),
+        Expression::Identifier(identifier) => context.get_function_reflection(identifier).map(Cow::Borrowed),
+        Expression::Closure(closure) => {
+            context.codebase.get_function_like(FunctionLikeName::Closure(closure.span())).map(Cow::Borrowed)
+        }
+        Expression::ArrowFunction(arrow_function) => context
+            .codebase
+            .get_function_like(FunctionLikeName::ArrowFunction(arrow_function.span()))
+            .map(Cow::Borrowed),
+        Expression::ClosureCreation(closure_creation) => match closure_creation.as_ref() {
+            ClosureCreation::Function(function_closure_creation) => match &function_closure_creation.function {
+                Expression::Identifier(identifier) => context.get_function_reflection(identifier).map(Cow::Borrowed),
+                _ => None,
+            },
+            ClosureCreation::Method(method_closure_creation) => {
+                get_method_reflection(&method_closure_creation.object, &method_closure_creation.method, context)
+            }
+            ClosureCreation::StaticMethod(static_method_closure_creation) => get_static_method_reflection(
+                &static_method_closure_creation.class,
+                &static_method_closure_creation.method,
+                context,
+            ),
+        },
+        _ => None,
+    }
+}
+
+/// Retrieves the reflection of the method called on the given object expression, if the type of the object is known.
+pub fn get_method_reflection<'a>(
+    object: &Expression,
+    method: &ClassLikeMemberSelector,
+    context: &LintContext<'a>,
+) -> Option<Cow<'a, FunctionLikeReflection>> {
+    let ClassLikeMemberSelector::Identifier(method) = method else {
+        return None;
+    };
+
+    let TypeKind::Object(object) = context.resolve_kind(object) else {
+        return None;
+    };
+
+    let class_like = context.get_object_reflection(&object)?;
+
+    context.codebase.get_method(class_like, &method.value)
+}
+
+/// Retrieves the reflection of the method called statically on the given class expression.
+pub fn get_static_method_reflection<'a>(
+    class: &Expression,
+    method: &ClassLikeMemberSelector,
+    context: &LintContext<'a>,
+) -> Option<Cow<'a, FunctionLikeReflection>> {
+    let ClassLikeMemberSelector::Identifier(method) = method else {
+        return None;
+    };
+
+    let class_like = context.get_class_like_reflection(class)?;
+
+    context.codebase.get_method(class_like, &method.value)
+}
+
+/// Returns the class-likes the given object expression may be an instance of.
+///
+/// Returns `None` unless every possible type of the object is a known class-like.
+pub fn get_receiver_class_likes<'a>(
+    object: &Expression,
+    context: &LintContext<'a>,
+) -> Option<Vec<&'a ClassLikeReflection>> {
+    let kinds = match context.resolve_kind(object) {
+        TypeKind::Union { kinds } => kinds,
+        kind => vec![kind],
+    };
+
+    let mut class_likes = vec![];
+    for kind in kinds {
+        match kind {
+            TypeKind::Value(ValueTypeKind::Null) => {}
+            TypeKind::Object(object) => {
+                let class_like = match object {
+                    ObjectTypeKind::Static { scope }
+                    | ObjectTypeKind::Self_ { scope }
+                    | ObjectTypeKind::Parent { scope } => context.codebase.get_named_class_like(&scope),
+                    object => context.get_object_reflection(&object),
+                }?;
+
+                // Traits may rely on members of the class-likes using them.
+                if class_like.is_trait() {
+                    return None;
+                }
+
+                class_likes.push(class_like);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(class_likes)
+}
+
+/// Returns a human-readable name of the given function-like, for use in issue messages.
+pub fn get_function_like_display_name(function_like: &FunctionLikeReflection, context: &LintContext<'_>) -> String {
+    match function_like.name {
+        FunctionLikeName::Closure(_) => "closure".to_string(),
+        FunctionLikeName::ArrowFunction(_) => "arrow function".to_string(),
+        name => name.get_key(context.interner),
+    }
+}
+
+/// Returns a human-readable name of the given class-like, for use in issue messages.
+pub fn get_class_like_display_name(name: ClassLikeName, context: &LintContext<'_>) -> String {
+    match name {
+        ClassLikeName::AnonymousClass(_) => ANONYMOUS_CLASS_NAME.to_string(),
+        name => name.get_key(context.interner),
+    }
+}
+
+/// Returns a human-readable representation of the given type, for use in issue messages.
+///
+/// Unlike the key of the type, this does not include the location of anonymous classes.
+pub fn get_kind_display_name(kind: &TypeKind, context: &LintContext<'_>) -> String {
+    match kind {
+        TypeKind::Union { kinds } => {
+            kinds.iter().map(|kind| get_kind_display_name(kind, context)).collect::<Vec<_>>().join("|")
+        }
+        TypeKind::Object(ObjectTypeKind::AnonymousObject { .. }) => ANONYMOUS_CLASS_NAME.to_string(),
+        kind => kind.get_key(context.interner),
+    }
+}
+
+/// Determines whether the current source declares `strict_types=1`.
+pub fn is_strict_types_enabled(context: &LintContext<'_>) -> bool {
+    context.semantics.program.statements.iter().any(|statement| {
+        let Statement::Declare(declare) = statement else {
+            return false;
+        };
+
+        declare.items.iter().any(|item| {
+            context.lookup(&item.name.value) == "strict_types"
+                && matches!(&item.value, Expression::Literal(Literal::Integer(integer)) if integer.value == Some(1))
+        })
+    })
+}
+
+/// Returns the type of the values accepted where the given type is expected, e.g. by a parameter or a return type.
+///
+/// Integers are always accepted where floats are expected, and in coercive typing mode, scalar values
+/// are converted to the expected scalar type.
+pub fn get_accepted_kind(kind: &TypeKind, strict_types: bool) -> TypeKind {
+    match kind {
+        TypeKind::Union { kinds } => {
+            union_kind(kinds.iter().map(|kind| get_accepted_kind(kind, strict_types)).collect())
+        }
+        TypeKind::Scalar(
+            ScalarTypeKind::Bool | ScalarTypeKind::Float | ScalarTypeKind::Integer { .. } | ScalarTypeKind::String,
+        ) if !strict_types => TypeKind::Scalar(ScalarTypeKind::Scalar),
+        TypeKind::Scalar(ScalarTypeKind::Float) => union_kind(vec![float_kind(), integer_kind()]),
+        _ => kind.clone(),
+    }
+}
+
+/// Returns the members of the given type that are not contained by the accepted type, such as `null`
+/// for a `?string` value where a `string` is expected.
+///
+/// Members that may be contained by the accepted type, such as `mixed`, are not returned.
+pub fn get_incompatible_kinds(kind: &TypeKind, accepted_kind: &TypeKind, context: &LintContext<'_>) -> Vec<TypeKind> {
+    match kind {
+        TypeKind::Union { kinds } => {
+            kinds.iter().flat_map(|kind| get_incompatible_kinds(kind, accepted_kind, context)).collect()
+        }
+        kind if context.is_contained_by(kind, accepted_kind) == Trinary::False => vec![kind.clone()],
+        _ => vec![],
+    }
+}
+
+/// Determines whether the execution of the given statements always terminates, i.e. never reaches
+/// the end of the statements, by returning, throwing, exiting, or looping forever.
+pub fn statements_always_terminate<'a>(
+    statements: impl IntoIterator<Item = &'a Statement>,
+    context: &LintContext<'_>,
+) -> bool {
+    statements.into_iter().any(|statement| statement_always_terminates(statement, context))
+}
+
+/// Determines whether the execution of the given statement always terminates.
+pub fn statement_always_terminates(statement: &Statement, context: &LintContext<'_>) -> bool {
+    match statement {
+        Statement::Return(_) | Statement::Goto(_) => true,
+        Statement::Block(block) => statements_always_terminate(block.statements.iter(), context),
+        Statement::Expression(statement) => expression_always_terminates(&statement.expression, context),
+        Statement::If(r#if) => match &r#if.body {
+            IfBody::Statement(body) => {
+                let Some(else_clause) = &body.else_clause else {
+                    return false;
+                };
+
+                statement_always_terminates(&body.statement, context)
+                    && body.else_if_clauses.iter().all(|clause| statement_always_terminates(&clause.statement, context))
+                    && statement_always_terminates(&else_clause.statement, context)
+            }
+            IfBody::ColonDelimited(body) => {
+                let Some(else_clause) = &body.else_clause else {
+                    return false;
+                };
+
+                statements_always_terminate(body.statements.iter(), context)
+                    && body
+                        .else_if_clauses
+                        .iter()
+                        .all(|clause| statements_always_terminate(clause.statements.iter(), context))
+                    && statements_always_terminate(else_clause.statements.iter(), context)
+            }
+        },
+        Statement::Try(r#try) => {
+            let finally_terminates = r#try
+                .finally_clause
+                .as_ref()
+                .is_some_and(|finally| statements_always_terminate(finally.block.statements.iter(), context));
+
+            finally_terminates
+                || (statements_always_terminate(r#try.block.statements.iter(), context)
+                    && r#try
+                        .catch_clauses
+                        .iter()
+                        .all(|catch| statements_always_terminate(catch.block.statements.iter(), context)))
+        }
+        Statement::Switch(switch) => {
+            let cases = switch.body.cases();
+            let has_default_case = cases.iter().any(|case| matches!(case, SwitchCase::Default(_)));
+            let Some(last_case) = cases.last() else {
+                return false;
+            };
+
+            // Without any `break` out of the switch, every case falls through to the last one.
+            has_default_case
+                && !cases
+                    .iter()
+                    .any(|case| case.statements().iter().any(|statement| statement_breaks_out(statement, 1)))
+                && statements_always_terminate(last_case.statements().iter(), context)
+        }
+        Statement::While(r#while) => {
+            let breaks_out = match &r#while.body {
+                WhileBody::Statement(statement) => statement_breaks_out(statement, 1),
+                WhileBody::ColonDelimited(body) => statements_break_out(body.statements.iter(), 1),
+            };
+
+            !breaks_out && is_always_true(&r#while.condition)
+        }
+        Statement::For(r#for) => {
+            let breaks_out = match &r#for.body {
+                ForBody::Statement(statement) => statement_breaks_out(statement, 1),
+                ForBody::ColonDelimited(body) => statements_break_out(body.statements.iter(), 1),
+            };
+
+            !breaks_out && r#for.conditions.iter().last().is_none_or(is_always_true)
+        }
+        Statement::DoWhile(do_while) => {
+            !statement_breaks_out(&do_while.statement, 1)
+                && (is_always_true(&do_while.condition) || statement_always_terminates(&do_while.statement, context))
+        }
+        _ => false,
+    }
+}
+
+fn expression_always_terminates(expression: &Expression, context: &LintContext<'_>) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => expression_always_terminates(&parenthesized.expression, context),
+        Expression::Throw(_) => true,
+        Expression::Construct(construct) => matches!(construct.as_ref(), Construct::Exit(_) | Construct::Die(_)),
+        Expression::Call(_) => matches!(context.resolve_kind(expression), TypeKind::Never),
+        _ => false,
+    }
+}
+
+/// Determines whether the given statement contains a `break` or `continue` that exits the
+/// loop or switch it is nested in at the given depth.
+fn statement_breaks_out(statement: &Statement, depth: usize) -> bool {
+    match statement {
+        Statement::Break(r#break) => get_level(&r#break.level) >= depth,
+        Statement::Continue(r#continue) => get_level(&r#continue.level) >= depth,
+        Statement::Block(block) => block.statements.iter().any(|statement| statement_breaks_out(statement, depth)),
+        Statement::If(r#if) => match &r#if.body {
+            IfBody::Statement(body) => {
+                statement_breaks_out(&body.statement, depth)
+                    || body.else_if_clauses.iter().any(|clause| statement_breaks_out(&clause.statement, depth))
+                    || body.else_clause.as_ref().is_some_and(|clause| statement_breaks_out(&clause.statement, depth))
+            }
+            IfBody::ColonDelimited(body) => {
+                body.statements.iter().any(|statement| statement_breaks_out(statement, depth))
+                    || body
+                        .else_if_clauses
+                        .iter()
+                        .any(|clause| clause.statements.iter().any(|statement| statement_breaks_out(statement, depth)))
+                    || body
+                        .else_clause
+                        .as_ref()
+                        .is_some_and(|clause| clause.statements.iter().any(|s| statement_breaks_out(s, depth)))
+            }
+        },
+        Statement::Try(r#try) => {
+            r#try.block.statements.iter().any(|statement| statement_breaks_out(statement, depth))
+                || r#try
+                    .catch_clauses
+                    .iter()
+                    .any(|catch| catch.block.statements.iter().any(|statement| statement_breaks_out(statement, depth)))
+                || r#try.finally_clause.as_ref().is_some_and(|finally| {
+                    finally.block.statements.iter().any(|statement| statement_breaks_out(statement, depth))
+                })
+        }
+        Statement::Switch(switch) => {
+            statements_break_out(switch.body.cases().iter().flat_map(|case| case.statements()), depth + 1)
+        }
+        Statement::While(r#while) => match &r#while.body {
+            WhileBody::Statement(statement) => statement_breaks_out(statement, depth + 1),
+            WhileBody::ColonDelimited(body) => statements_break_out(body.statements.iter(), depth + 1),
+        },
+        Statement::For(r#for) => match &r#for.body {
+            ForBody::Statement(statement) => statement_breaks_out(statement, depth + 1),
+            ForBody::ColonDelimited(body) => statements_break_out(body.statements.iter(), depth + 1),
+        },
+        Statement::Foreach(foreach) => match &foreach.body {
+            ForeachBody::Statement(statement) => statement_breaks_out(statement, depth + 1),
+            ForeachBody::ColonDelimited(body) => statements_break_out(body.statements.iter(), depth + 1),
+        },
+        Statement::DoWhile(do_while) => statement_breaks_out(&do_while.statement, depth + 1),
+        _ => false,
+    }
+}
+
+fn statements_break_out<'a>(statements: impl IntoIterator<Item = &'a Statement>, depth: usize) -> bool {
+    statements.into_iter().any(|statement| statement_breaks_out(statement, depth))
+}
+
+fn get_level(level: &Option<Expression>) -> usize {
+    match level {
+        Some(Expression::Literal(Literal::Integer(LiteralInteger { value: Some(value), .. }))) => *value as usize,
+        _ => 1,
+    }
+}
+
+fn is_always_true(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_always_true(&parenthesized.expression),
+        Expression::Literal(Literal::True(_)) => true,
+        Expression::Literal(Literal::Integer(LiteralInteger { value: Some(value), .. })) => *value != 0,
+        _ => false,
+    }
+}
+
+/// Determines whether the docblock of the given node contains annotations, such as `@ORM\Column`,
+/// which are commonly used by libraries that access members using reflection.
+pub fn has_annotations(node: &impl HasSpan, context: &LintContext<'_>) -> bool {
+    let Some(docblock) = mago_docblock::get_docblock_for_node(&context.semantics.program.trivia, node) else {
+        return false;
+    };
+
+    context
+        .lookup(&docblock.value)
+        .split('@')
+        .skip(1)
+        .any(|annotation| annotation.starts_with(|character: char| character.is_ascii_uppercase()))
+}
+
+/// Returns the string literals passed as regular expression patterns in the given call, if it is a
+/// call to one of the `preg_*` functions, along with the name of the function, in lowercase.
+///
+/// Patterns passed in an array literal are returned as well, including the keys of the array passed to
+/// `preg_replace_callback_array()`.
+pub fn get_regex_patterns<'ast>(
+    function_call: &'ast FunctionCall,
+    context: &LintContext<'_>,
+) -> Option<(String, Vec<&'ast LiteralString>)> {
+    let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+        return None;
+    };
+
+    let name = context.lookup_function_name(identifier).to_ascii_lowercase();
+    if !matches!(
+        name.as_str(),
+        "preg_match"
+            | "preg_match_all"
+            | "preg_replace"
+            | "preg_replace_callback"
+            | "preg_replace_callback_array"
+            | "preg_filter"
+            | "preg_split"
+            | "preg_grep"
+    ) {
+        return None;
+    }
+
+    let pattern = get_argument(&function_call.arguments, 0, "pattern", context)?;
+    let mut patterns = vec![];
+    match pattern {
+        Expression::Literal(Literal::String(literal)) => patterns.push(literal),
+        Expression::Array(Array { elements, .. }) | Expression::LegacyArray(LegacyArray { elements, .. }) => {
+            for element in elements.iter() {
+                let pattern = match element {
+                    ArrayElement::KeyValue(element) if name == "preg_replace_callback_array" => &element.key,
+                    ArrayElement::KeyValue(element) => &element.value,
+                    ArrayElement::Value(element) => &element.value,
+                    _ => continue,
+                };
+
+                if let Expression::Literal(Literal::String(literal)) = pattern.as_ref() {
+                    patterns.push(literal);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Some((name, patterns))
+}
+
+/// Returns the value of the argument at the given position, or with the given name, of a call.
+pub fn get_argument<'ast>(
+    arguments: &'ast ArgumentList,
+    position: usize,
+    name: &str,
+    context: &LintContext<'_>,
+) -> Option<&'ast Expression> {
+    arguments.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+        Argument::Positional(argument) if index == position && argument.ellipsis.is_none() => Some(&argument.value),
+        Argument::Named(argument) if context.lookup(&argument.name.value) == name => Some(&argument.value),
+        _ => None,
+    })
+}
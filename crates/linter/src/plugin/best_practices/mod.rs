@@ -1,4 +1,6 @@
 use crate::plugin::best_practices::rules::combine_consecutive_issets::CombineConsecutiveIssetsRule;
+use crate::plugin::best_practices::rules::complexity::ComplexityMetric;
+use crate::plugin::best_practices::rules::complexity::ComplexityRule;
 use crate::plugin::best_practices::rules::disallowed_functions::DisallowedFunctionsRule;
 use crate::plugin::best_practices::rules::excessive_nesting::ExcessiveNesting;
 use crate::plugin::best_practices::rules::loop_does_not_iterate::LoopDoesNotIterateRule;
@@ -32,6 +34,8 @@ impl Plugin for BestPracticesPlugin {
             Box::new(DisallowedFunctionsRule),
             Box::new(NoUnusedParameterRule),
             Box::new(ExcessiveNesting),
+            Box::new(ComplexityRule::new(ComplexityMetric::Cyclomatic)),
+            Box::new(ComplexityRule::new(ComplexityMetric::Cognitive)),
             Box::new(LoopDoesNotIterateRule),
             Box::new(NoGotoRule),
             Box::new(NoDebugSymbolsRule),
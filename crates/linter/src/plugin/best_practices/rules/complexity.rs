@@ -0,0 +1,139 @@
+use mago_ast::*;
+use mago_ast_utils::complexity::get_block_complexity;
+use mago_ast_utils::complexity::get_expression_complexity;
+use mago_ast_utils::complexity::Complexity;
+use mago_ast_utils::complexity::ComplexityIncrement;
+use mago_reporting::*;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// The complexity metric checked by a [`ComplexityRule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComplexityMetric {
+    /// The number of linearly independent paths through a function-like.
+    Cyclomatic,
+    /// How hard a function-like is to understand, penalizing nested control flow.
+    Cognitive,
+}
+
+impl ComplexityMetric {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ComplexityMetric::Cyclomatic => "cyclomatic",
+            ComplexityMetric::Cognitive => "cognitive",
+        }
+    }
+
+    fn get_default_threshold(&self) -> i64 {
+        match self {
+            ComplexityMetric::Cyclomatic => 10,
+            ComplexityMetric::Cognitive => 15,
+        }
+    }
+
+    /// Returns the value of the metric, along with the constructs contributing to it.
+    fn measure<'c>(&self, complexity: &'c Complexity) -> (usize, &'c [ComplexityIncrement]) {
+        match self {
+            ComplexityMetric::Cyclomatic => (complexity.cyclomatic, &complexity.cyclomatic_increments),
+            ComplexityMetric::Cognitive => (complexity.cognitive, &complexity.cognitive_increments),
+        }
+    }
+}
+
+/// Reports function-likes whose complexity, according to the given metric, exceeds the `threshold` option.
+#[derive(Clone, Debug)]
+pub struct ComplexityRule {
+    metric: ComplexityMetric,
+}
+
+impl ComplexityRule {
+    pub const fn new(metric: ComplexityMetric) -> Self {
+        Self { metric }
+    }
+
+    fn check(&self, subject: String, span: Span, complexity: Complexity, context: &mut LintContext<'_>) {
+        let threshold = context
+            .option("threshold")
+            .and_then(|value| value.as_integer())
+            .unwrap_or_else(|| self.metric.get_default_threshold());
+
+        let (value, increments) = self.metric.measure(&complexity);
+        if value as i64 <= threshold {
+            return;
+        }
+
+        let metric = self.metric.as_str();
+        let mut annotations =
+            vec![Annotation::primary(span)
+                .with_message(format!("{} has a {} complexity of {}.", subject, metric, value))];
+
+        for increment in increments {
+            let message = if increment.value > 1 {
+                format!("+{} for `{}` (nesting level {}).", increment.value, increment.construct, increment.nesting)
+            } else {
+                format!("+1 for `{}`.", increment.construct)
+            };
+
+            annotations.push(Annotation::secondary(increment.span).with_message(message));
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "{} has a {} complexity of {}, which exceeds the threshold of {}.",
+                subject, metric, value, threshold
+            ),
+        )
+        .with_annotations(annotations)
+        .with_note(format!("The {} complexity is increased by each of the annotated constructs.", metric))
+        .with_help(
+            "Consider extracting parts of the logic into separate functions or methods, or simplifying conditions.",
+        );
+
+        context.report(issue);
+    }
+}
+
+impl Rule for ComplexityRule {
+    fn get_name(&self) -> &'static str {
+        match self.metric {
+            ComplexityMetric::Cyclomatic => "cyclomatic-complexity",
+            ComplexityMetric::Cognitive => "cognitive-complexity",
+        }
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ComplexityRule {
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'a>) {
+        let subject = format!("Function `{}`", context.lookup(&function.name.value));
+
+        self.check(subject, function.name.span, get_block_complexity(&function.body), context);
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        let subject = format!("Method `{}`", context.lookup(&method.name.value));
+
+        self.check(subject, method.name.span, get_block_complexity(block), context);
+    }
+
+    fn walk_in_closure(&self, closure: &Closure, context: &mut LintContext<'a>) {
+        self.check("Closure".to_string(), closure.function.span, get_block_complexity(&closure.body), context);
+    }
+
+    fn walk_in_arrow_function(&self, arrow_function: &ArrowFunction, context: &mut LintContext<'a>) {
+        let complexity = get_expression_complexity(&arrow_function.expression);
+
+        self.check("Arrow function".to_string(), arrow_function.r#fn.span, complexity, context);
+    }
+}
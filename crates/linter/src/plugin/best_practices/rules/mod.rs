@@ -1,4 +1,5 @@
 pub mod combine_consecutive_issets;
+pub mod complexity;
 pub mod disallowed_functions;
 pub mod excessive_nesting;
 pub mod loop_does_not_iterate;
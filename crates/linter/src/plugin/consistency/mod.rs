@@ -3,6 +3,7 @@ use crate::plugin::consistency::rules::lowercase_hint::LowercaseHintRule;
 use crate::plugin::consistency::rules::lowercase_keyword::LowercaseKeywordRule;
 use crate::plugin::consistency::rules::no_function_aliases::NoFunctionAliasesRule;
 use crate::plugin::consistency::rules::no_tag_pair_terminator::NoTagPairTerminatorRule;
+use crate::plugin::consistency::rules::ordered_imports::OrderedImportsRule;
 use crate::plugin::consistency::rules::require_block_statement_body::RequireBlockStatementBodyRule;
 
 use crate::plugin::Plugin;
@@ -29,6 +30,7 @@ impl Plugin for ConsistencyPlugin {
             Box::new(LowercaseKeywordRule),
             Box::new(NoFunctionAliasesRule),
             Box::new(NoTagPairTerminatorRule),
+            Box::new(OrderedImportsRule),
             Box::new(RequireBlockStatementBodyRule),
         ]
     }
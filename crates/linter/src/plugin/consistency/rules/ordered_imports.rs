@@ -0,0 +1,54 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::import::get_import_blocks;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct OrderedImportsRule;
+
+impl Rule for OrderedImportsRule {
+    fn get_name(&self) -> &'static str {
+        "ordered-imports"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Note)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for OrderedImportsRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        for block in get_import_blocks(program, context) {
+            let misplaced = block.get_misplaced_imports();
+            if misplaced.is_empty() {
+                continue;
+            }
+
+            let mut issue = Issue::new(context.level(), "Imports are not ordered.")
+                .with_annotation(Annotation::secondary(block.span).with_message("This import block is not ordered."));
+
+            for import in misplaced {
+                issue = issue.with_annotation(
+                    Annotation::primary(import.span)
+                        .with_message(format!("`{}` should be imported earlier.", import.name)),
+                );
+            }
+
+            let issue = issue
+                .with_note("Imports should be grouped by kind, class-likes first, then functions, then constants.")
+                .with_note("Imports of each group should be sorted alphabetically.")
+                .with_help("Sort the imports.");
+
+            match block.rewrite(context) {
+                Some((range, rendered)) => {
+                    context.report_with_fix(issue, |plan| plan.replace(range, rendered, SafetyClassification::Safe));
+                }
+                None => context.report(issue),
+            }
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use crate::plugin::deprecation::rules::php80::optional_parameter_before_required::OptionalParameterBeforeRequiredRule;
+use crate::plugin::deprecation::rules::php81::mysqli_client_info::MysqliClientInfoRule;
+use crate::plugin::deprecation::rules::php81::null_to_internal_parameter::NullToInternalParameterRule;
+use crate::plugin::deprecation::rules::php81::strftime::StrftimeRule;
+use crate::plugin::deprecation::rules::php82::dollar_brace_interpolation::DollarBraceInterpolationRule;
+use crate::plugin::deprecation::rules::php82::dynamic_property_creation::DynamicPropertyCreationRule;
 use crate::plugin::deprecation::rules::php82::return_by_reference_from_void_function::ReturnByReferenceFromVoidFunctionRule;
+use crate::plugin::deprecation::rules::php82::utf8_encode_decode::Utf8EncodeDecodeRule;
+use crate::plugin::deprecation::rules::php83::get_class_without_arguments::GetClassWithoutArgumentsRule;
+use crate::plugin::deprecation::rules::php84::e_strict_constant::EStrictConstantRule;
 use crate::plugin::deprecation::rules::php84::implicitly_nullable_parameter::ImplicitlyNullableParameterRule;
+use crate::plugin::deprecation::rules::php84::mysqli_connection_functions::MysqliConnectionFunctionsRule;
 use crate::plugin::deprecation::rules::php84::underscore_classname::UnderscoreClassNameRule;
 
 use crate::plugin::Plugin;
@@ -24,11 +33,22 @@ impl Plugin for DeprecationPlugin {
         vec![
             // PHP 8.0
             Box::new(OptionalParameterBeforeRequiredRule),
+            // PHP 8.1
+            Box::new(NullToInternalParameterRule),
+            Box::new(StrftimeRule),
+            Box::new(MysqliClientInfoRule),
             // PHP 8.2
             Box::new(ReturnByReferenceFromVoidFunctionRule),
+            Box::new(DollarBraceInterpolationRule),
+            Box::new(DynamicPropertyCreationRule),
+            Box::new(Utf8EncodeDecodeRule),
+            // PHP 8.3
+            Box::new(GetClassWithoutArgumentsRule),
             // PHP 8.4
             Box::new(ImplicitlyNullableParameterRule),
             Box::new(UnderscoreClassNameRule),
+            Box::new(EStrictConstantRule),
+            Box::new(MysqliConnectionFunctionsRule),
         ]
     }
 }
@@ -1,12 +1,29 @@
+pub mod utils;
+
 pub mod php80 {
     pub mod optional_parameter_before_required;
 }
 
+pub mod php81 {
+    pub mod mysqli_client_info;
+    pub mod null_to_internal_parameter;
+    pub mod strftime;
+}
+
 pub mod php82 {
+    pub mod dollar_brace_interpolation;
+    pub mod dynamic_property_creation;
     pub mod return_by_reference_from_void_function;
+    pub mod utf8_encode_decode;
+}
+
+pub mod php83 {
+    pub mod get_class_without_arguments;
 }
 
 pub mod php84 {
+    pub mod e_strict_constant;
     pub mod implicitly_nullable_parameter;
+    pub mod mysqli_connection_functions;
     pub mod underscore_classname;
 }
@@ -0,0 +1,85 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::deprecation::rules::utils::get_method_name;
+use crate::plugin::deprecation::rules::utils::is_instance_of;
+use crate::rule::Rule;
+
+const MYSQLI: &str = "mysqli";
+const MYSQLI_GET_CLIENT_INFO: &str = "mysqli_get_client_info";
+const GET_CLIENT_INFO: &str = "get_client_info";
+
+#[derive(Clone, Debug)]
+pub struct MysqliClientInfoRule;
+
+impl Rule for MysqliClientInfoRule {
+    fn get_name(&self) -> &'static str {
+        "mysqli-client-info"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MysqliClientInfoRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        // Detect `mysqli_get_client_info($mysqli)`.
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        if !context.lookup_function_name(identifier).eq_ignore_ascii_case(MYSQLI_GET_CLIENT_INFO) {
+            return;
+        }
+
+        let [Argument::Positional(argument)] = function_call.arguments.arguments.as_slice() else {
+            return;
+        };
+
+        if argument.ellipsis.is_some() {
+            return;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            "Passing a connection to `mysqli_get_client_info()` is deprecated since PHP 8.1.",
+        )
+        .with_annotation(Annotation::primary(argument.span()).with_message("The connection is passed here."))
+        .with_note("The client information does not depend on the connection, which is ignored.")
+        .with_help("Call `mysqli_get_client_info()` without any arguments.")
+        .with_link("https://www.php.net/manual/en/migration81.deprecated.php");
+
+        context.report_with_fix(issue, |plan| {
+            plan.delete(argument.span().to_range(), SafetyClassification::Safe);
+        });
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        // Detect `$mysqli->get_client_info()`.
+        if !get_method_name(&method_call.method, context).is_some_and(|name| name.eq_ignore_ascii_case(GET_CLIENT_INFO))
+            || !is_instance_of(&method_call.object, MYSQLI, context)
+        {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Method `mysqli::get_client_info()` is deprecated since PHP 8.1.")
+            .with_annotation(
+                Annotation::primary(method_call.span()).with_message("`mysqli::get_client_info()` is called here."),
+            )
+            .with_note("The client information does not depend on the connection.")
+            .with_help("Call `mysqli_get_client_info()` without any arguments instead.")
+            .with_link("https://www.php.net/manual/en/migration81.deprecated.php");
+
+        let range = method_call.span().to_range();
+
+        context.report_with_fix(issue, |plan| {
+            // The connection expression is dropped, along with any side effects it may have.
+            plan.replace(range, "mysqli_get_client_info()", SafetyClassification::PotentiallyUnsafe);
+        });
+    }
+}
@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NullToInternalParameterRule;
+
+impl Rule for NullToInternalParameterRule {
+    fn get_name(&self) -> &'static str {
+        "null-to-internal-parameter"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NullToInternalParameterRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let Some(function) = context.get_function_reflection(identifier) else {
+            return;
+        };
+
+        check_arguments(function, &function_call.arguments, context);
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Some(method) = get_method_reflection(&method_call.object, &method_call.method, context) else {
+            return;
+        };
+
+        check_arguments(&method, &method_call.arguments, context);
+    }
+
+    fn walk_in_null_safe_method_call<'ast>(
+        &self,
+        null_safe_method_call: &'ast NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_method_reflection(&null_safe_method_call.object, &null_safe_method_call.method, context)
+        else {
+            return;
+        };
+
+        check_arguments(&method, &null_safe_method_call.arguments, context);
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let ClassLikeMemberSelector::Identifier(method) = &static_method_call.method else {
+            return;
+        };
+
+        let Some(class_like) = context.get_class_like_reflection(&static_method_call.class) else {
+            return;
+        };
+
+        let Some(method) = context.codebase.get_method(class_like, &method.value) else {
+            return;
+        };
+
+        check_arguments(&method, &static_method_call.arguments, context);
+    }
+}
+
+fn check_arguments(function_like: &FunctionLikeReflection, arguments: &ArgumentList, context: &mut LintContext<'_>) {
+    if !is_built_in(function_like) {
+        return;
+    }
+
+    let mut position = 0;
+    for argument in arguments.arguments.iter() {
+        let (parameter, value) = match argument {
+            Argument::Positional(argument) => {
+                if argument.ellipsis.is_some() {
+                    return;
+                }
+
+                position += 1;
+
+                let parameter = function_like
+                    .parameters
+                    .get(position - 1)
+                    .or_else(|| function_like.parameters.last().filter(|parameter| parameter.is_variadic));
+
+                (parameter, &argument.value)
+            }
+            Argument::Named(argument) => {
+                let name = format!("${}", context.lookup(&argument.name.value));
+                let parameter =
+                    function_like.parameters.iter().find(|parameter| context.lookup(&parameter.name) == name);
+
+                (parameter, &argument.value)
+            }
+        };
+
+        if let Some(parameter) = parameter {
+            check_argument(function_like, parameter, value, context);
+        }
+    }
+}
+
+fn check_argument(
+    function_like: &FunctionLikeReflection,
+    parameter: &FunctionLikeParameterReflection,
+    value: &Expression,
+    context: &mut LintContext<'_>,
+) {
+    // Only the native type of the parameter determines whether PHP coerces the argument.
+    let Some(parameter_type) = &parameter.type_reflection else {
+        return;
+    };
+
+    if !is_scalar(&parameter_type.kind) || !context.is_contained_by(&null_kind(), &parameter_type.kind).is_false() {
+        return;
+    }
+
+    if !matches!(context.resolve_kind(value), TypeKind::Value(ValueTypeKind::Null)) {
+        return;
+    }
+
+    let kind = if function_like.is_method() { "method" } else { "function" };
+    let function_name = function_like.name.get_key(context.interner);
+    let parameter_name = context.lookup(&parameter.name);
+    let parameter_type_name = parameter_type.kind.get_key(context.interner);
+
+    let mut issue = Issue::new(
+        context.level(),
+        format!(
+            "Passing `null` to non-nullable parameter `{}` of internal {} `{}()` is deprecated since PHP 8.1.",
+            parameter_name, kind, function_name
+        ),
+    )
+    .with_annotation(Annotation::primary(value.span()).with_message("This argument is `null`."))
+    .with_note(format!(
+        "Parameter `{}` is of type `{}`, to which internal {}s used to silently coerce `null`.",
+        parameter_name, parameter_type_name, kind
+    ));
+
+    let coerced_value = get_coerced_value(&parameter_type.kind);
+    if let Some(coerced_value) = coerced_value {
+        issue = issue.with_help(format!(
+            "Pass `{}` explicitly, which is the value `null` was coerced to, or handle `null` before the call.",
+            coerced_value
+        ));
+    } else {
+        issue = issue.with_help("Handle `null` before the call, or pass a value of the expected type.");
+    }
+
+    let issue = issue.with_link("https://www.php.net/manual/en/migration81.deprecated.php");
+
+    match (value, coerced_value) {
+        (Expression::Literal(Literal::Null(_)), Some(coerced_value)) => {
+            context.report_with_fix(issue, |plan| {
+                // The coerced value is what PHP passes today, but code after the call may tell `null` apart.
+                plan.replace(value.span().to_range(), coerced_value, SafetyClassification::PotentiallyUnsafe);
+            });
+        }
+        _ => context.report(issue),
+    }
+}
+
+/// Determines whether the given function or method is built into PHP, i.e. declared by a built-in source,
+/// such as the PhpStorm stubs.
+///
+/// Functions and methods declared in PHP code, including those of other external sources such as the packages
+/// of the `vendor` directory, reject `null` for scalar parameters with a `TypeError`; only internal ones used
+/// to silently coerce it.
+fn is_built_in(function_like: &FunctionLikeReflection) -> bool {
+    function_like.span.start.source.is_built_in()
+}
+
+/// Retrieves the reflection of the method called on the given object expression, if the type of the object is known.
+fn get_method_reflection<'a>(
+    object: &Expression,
+    method: &ClassLikeMemberSelector,
+    context: &LintContext<'a>,
+) -> Option<Cow<'a, FunctionLikeReflection>> {
+    let ClassLikeMemberSelector::Identifier(method) = method else {
+        return None;
+    };
+
+    let TypeKind::Object(object) = context.resolve_kind(object) else {
+        return None;
+    };
+
+    let class_like = context.get_object_reflection(&object)?;
+
+    context.codebase.get_method(class_like, &method.value)
+}
+
+/// Determines whether the given type only consists of scalar types, the ones internal functions coerced `null` to.
+fn is_scalar(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Scalar(_) | TypeKind::Value(ValueTypeKind::True | ValueTypeKind::False) => true,
+        TypeKind::Union { kinds } => kinds.iter().all(is_scalar),
+        _ => false,
+    }
+}
+
+/// Returns the value `null` is coerced to when passed to a parameter of the given type.
+fn get_coerced_value(kind: &TypeKind) -> Option<&'static str> {
+    match kind {
+        TypeKind::Scalar(ScalarTypeKind::String) => Some("''"),
+        TypeKind::Scalar(ScalarTypeKind::Integer { .. }) => Some("0"),
+        TypeKind::Scalar(ScalarTypeKind::Float) => Some("0.0"),
+        TypeKind::Scalar(ScalarTypeKind::Bool) => Some("false"),
+        _ => None,
+    }
+}
@@ -0,0 +1,47 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+const DEPRECATED_FUNCTIONS: [&str; 2] = ["strftime", "gmstrftime"];
+
+#[derive(Clone, Debug)]
+pub struct StrftimeRule;
+
+impl Rule for StrftimeRule {
+    fn get_name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for StrftimeRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.lookup_function_name(identifier);
+        let Some(function_name) = DEPRECATED_FUNCTIONS.iter().find(|name| name.eq_ignore_ascii_case(function_name))
+        else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), format!("Function `{}()` is deprecated since PHP 8.1.", function_name))
+            .with_annotation(
+                Annotation::primary(function_call.span())
+                    .with_message(format!("`{}()` is called here.", function_name)),
+            )
+            .with_note("Its behavior depends on the locale of the operating system, and it is not available on all platforms.")
+            .with_help("Use `date()` or `DateTimeInterface::format()` for locale-independent formatting, or `IntlDateFormatter::format()` for locale-aware formatting.")
+            .with_link("https://www.php.net/manual/en/migration81.deprecated.php");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,69 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DollarBraceInterpolationRule;
+
+impl Rule for DollarBraceInterpolationRule {
+    fn get_name(&self) -> &'static str {
+        "dollar-brace-interpolation"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DollarBraceInterpolationRule {
+    fn walk_in_string_part(&self, string_part: &StringPart, context: &mut LintContext<'a>) {
+        // Detect `"${name}"`, `"${name['key']}"` and `"${expression}"`.
+        let StringPart::Expression(expression) = string_part else {
+            return;
+        };
+
+        let Expression::Variable(Variable::Indirect(variable)) = expression.as_ref() else {
+            return;
+        };
+
+        // Within strings, `${name}` and `${name[...]}` refer to the variable `$name`, while any other
+        // expression is evaluated to get the name of the variable, as in a variable variable.
+        let is_variable_name = match variable.expression.as_ref() {
+            Expression::Identifier(Identifier::Local(_)) => true,
+            Expression::ArrayAccess(access) => {
+                matches!(access.array.as_ref(), Expression::Identifier(Identifier::Local(_)))
+            }
+            _ => false,
+        };
+
+        let (message, help) = if is_variable_name {
+            ("Using `${}` in strings is deprecated since PHP 8.2.", "Use `{$name}` instead of `${name}`.")
+        } else {
+            (
+                "Using `${expr}` ( variable variables ) in strings is deprecated since PHP 8.2.",
+                "Use `{${expr}}` instead of `${expr}`.",
+            )
+        };
+
+        let issue = Issue::new(context.level(), message)
+            .with_annotation(Annotation::primary(variable.span()).with_message("This interpolation uses `${`."))
+            .with_help(help)
+            .with_link("https://www.php.net/manual/en/migration82.deprecated.php");
+
+        context.report_with_fix(issue, |plan| {
+            let safety = SafetyClassification::Safe;
+
+            if is_variable_name {
+                plan.replace(variable.dollar_left_brace.to_range(), "{$", safety);
+            } else {
+                plan.insert(variable.dollar_left_brace.start.offset, "{", safety);
+                plan.insert(variable.right_brace.end.offset, "}", safety);
+            }
+        });
+    }
+}
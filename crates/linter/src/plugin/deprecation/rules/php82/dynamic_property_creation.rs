@@ -0,0 +1,125 @@
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+const ALLOW_DYNAMIC_PROPERTIES: &str = "AllowDynamicProperties";
+const STD_CLASS: &str = "stdClass";
+const SET: &str = "__set";
+
+#[derive(Clone, Debug)]
+pub struct DynamicPropertyCreationRule;
+
+impl Rule for DynamicPropertyCreationRule {
+    fn get_name(&self) -> &'static str {
+        "dynamic-property-creation"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DynamicPropertyCreationRule {
+    fn walk_in_assignment(&self, assignment: &Assignment, context: &mut LintContext<'a>) {
+        // Detect `$object->undeclared = $value;`.
+        let Expression::Access(access) = assignment.lhs.as_ref() else {
+            return;
+        };
+
+        let Access::Property(PropertyAccess {
+            object, property: ClassLikeMemberSelector::Identifier(property), ..
+        }) = access.as_ref()
+        else {
+            return;
+        };
+
+        let Some(class_like) = get_receiver_class_like(object, context) else {
+            return;
+        };
+
+        if !class_like.is_class() || allows_dynamic_properties(class_like, context) {
+            return;
+        }
+
+        let property_name = context.lookup(&property.value);
+        let is_declared =
+            class_like.properties.appering_members.keys().any(|name| &context.lookup(name)[1..] == property_name);
+
+        if is_declared {
+            return;
+        }
+
+        let class_name = class_like.name.get_key(context.interner);
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Creation of dynamic property `{}::${}` is deprecated since PHP 8.2.", class_name, property_name),
+        )
+        .with_annotation(
+            Annotation::primary(property.span())
+                .with_message(format!("Property `${}` is not declared in `{}`.", property_name, class_name)),
+        )
+        .with_annotation(
+            Annotation::secondary(class_like.span).with_message(format!("`{}` is defined here.", class_name)),
+        )
+        .with_note("Dynamic properties can only be created on classes marked with the `#[\\AllowDynamicProperties]` attribute, on `stdClass`, or on classes implementing `__set()`.")
+        .with_help(format!(
+            "Declare the property `${}` in `{}`, or mark the class with the `#[\\AllowDynamicProperties]` attribute.",
+            property_name, class_name
+        ))
+        .with_link("https://www.php.net/manual/en/migration82.deprecated.php");
+
+        context.report(issue);
+    }
+}
+
+/// Returns the class-like the given object expression is an instance of, if it is known.
+fn get_receiver_class_like<'a>(object: &Expression, context: &LintContext<'a>) -> Option<&'a ClassLikeReflection> {
+    let TypeKind::Object(object) = context.resolve_kind(object) else {
+        return None;
+    };
+
+    match object {
+        ObjectTypeKind::Static { scope } | ObjectTypeKind::Self_ { scope } | ObjectTypeKind::Parent { scope } => {
+            context.codebase.get_named_class_like(&scope)
+        }
+        object => context.get_object_reflection(&object),
+    }
+}
+
+/// Determines whether dynamic properties can be created on instances of the given class.
+///
+/// This is the case for `stdClass`, classes marked with the `#[\AllowDynamicProperties]` attribute, their descendants,
+/// and classes implementing `__set()`. Classes with unknown ancestors are assumed to allow them.
+fn allows_dynamic_properties(class_like: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    let set = context.interner.intern(SET);
+    if context.codebase.get_method(class_like, &set).is_some() {
+        return true;
+    }
+
+    let is_allowed = |class_like: &ClassLikeReflection| {
+        class_like.name.get_key(context.interner).eq_ignore_ascii_case(STD_CLASS)
+            || class_like
+                .attribute_reflections
+                .iter()
+                .any(|attribute| context.lookup(&attribute.name.value).eq_ignore_ascii_case(ALLOW_DYNAMIC_PROPERTIES))
+    };
+
+    if is_allowed(class_like) {
+        return true;
+    }
+
+    class_like.inheritance.all_extended_classes.iter().any(|parent| {
+        if context.lookup(&parent.value).eq_ignore_ascii_case(STD_CLASS) {
+            return true;
+        }
+
+        context.codebase.get_named_class_like(&parent.value).is_none_or(is_allowed)
+    })
+}
@@ -0,0 +1,72 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+const UTF8_ENCODE: &str = "utf8_encode";
+const UTF8_DECODE: &str = "utf8_decode";
+const MB_CONVERT_ENCODING: &str = "mb_convert_encoding";
+
+#[derive(Clone, Debug)]
+pub struct Utf8EncodeDecodeRule;
+
+impl Rule for Utf8EncodeDecodeRule {
+    fn get_name(&self) -> &'static str {
+        "utf8-encode-decode"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for Utf8EncodeDecodeRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.lookup_function_name(identifier);
+        let (function_name, encodings) = if function_name.eq_ignore_ascii_case(UTF8_ENCODE) {
+            (UTF8_ENCODE, "'UTF-8', 'ISO-8859-1'")
+        } else if function_name.eq_ignore_ascii_case(UTF8_DECODE) {
+            (UTF8_DECODE, "'ISO-8859-1', 'UTF-8'")
+        } else {
+            return;
+        };
+
+        let replacement = format!("{}($string, {})", MB_CONVERT_ENCODING, encodings);
+
+        let issue = Issue::new(context.level(), format!("Function `{}()` is deprecated since PHP 8.2.", function_name))
+            .with_annotation(
+                Annotation::primary(function_call.span())
+                    .with_message(format!("`{}()` is called here.", function_name)),
+            )
+            .with_note(
+                "Its name suggests that it handles any encoding, while it only converts between ISO-8859-1 and UTF-8.",
+            )
+            .with_help(format!("Use `{}` instead.", replacement))
+            .with_link("https://www.php.net/manual/en/migration82.deprecated.php");
+
+        let argument = match function_call.arguments.arguments.as_slice() {
+            [Argument::Positional(argument)] if argument.ellipsis.is_none() => argument,
+            _ => {
+                context.report(issue);
+
+                return;
+            }
+        };
+
+        context.report_with_fix(issue, |plan| {
+            // Mark the fix as potentially unsafe, as `mb_convert_encoding()` requires the `mbstring` extension.
+            let safety = SafetyClassification::PotentiallyUnsafe;
+
+            plan.replace(identifier.span().to_range(), MB_CONVERT_ENCODING, safety);
+            plan.insert(argument.span().end.offset, format!(", {}", encodings), safety);
+        });
+    }
+}
@@ -0,0 +1,72 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+const GET_CLASS: &str = "get_class";
+const GET_PARENT_CLASS: &str = "get_parent_class";
+
+#[derive(Clone, Debug)]
+pub struct GetClassWithoutArgumentsRule;
+
+impl Rule for GetClassWithoutArgumentsRule {
+    fn get_name(&self) -> &'static str {
+        "get-class-without-arguments"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for GetClassWithoutArgumentsRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        if !function_call.arguments.arguments.is_empty() {
+            return;
+        }
+
+        let function_name = context.lookup_function_name(identifier);
+        let (function_name, replacement) = if function_name.eq_ignore_ascii_case(GET_CLASS) {
+            (GET_CLASS, "self::class")
+        } else if function_name.eq_ignore_ascii_case(GET_PARENT_CLASS) {
+            (GET_PARENT_CLASS, "parent::class")
+        } else {
+            return;
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Calling `{}()` without arguments is deprecated since PHP 8.3.", function_name),
+        )
+        .with_annotation(
+            Annotation::primary(function_call.span()).with_message(format!("`{}()` is called here.", function_name)),
+        )
+        .with_help(format!("Use `{}` instead.", replacement))
+        .with_link("https://www.php.net/manual/en/migration83.deprecated.php");
+
+        // `get_parent_class()` returns `false` when the class has no parent, while `parent::class` is an error.
+        let is_equivalent = match context.codebase.get_enclosing_class_like(function_call) {
+            Some(_) if function_name == GET_CLASS => true,
+            Some(class_like) => class_like.is_class() && class_like.inheritance.direct_extended_class.is_some(),
+            None => false,
+        };
+
+        if !is_equivalent {
+            context.report(issue);
+
+            return;
+        }
+
+        context.report_with_fix(issue, |plan| {
+            plan.replace(function_call.span().to_range(), replacement, SafetyClassification::Safe);
+        });
+    }
+}
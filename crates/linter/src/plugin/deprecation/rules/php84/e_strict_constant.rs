@@ -0,0 +1,42 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+const E_STRICT: &str = "E_STRICT";
+
+#[derive(Clone, Debug)]
+pub struct EStrictConstantRule;
+
+impl Rule for EStrictConstantRule {
+    fn get_name(&self) -> &'static str {
+        "e-strict-constant"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for EStrictConstantRule {
+    fn walk_in_expression(&self, expression: &Expression, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = expression else {
+            return;
+        };
+
+        if context.lookup_function_name(identifier) != E_STRICT {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Constant `E_STRICT` is deprecated since PHP 8.4.")
+            .with_annotation(Annotation::primary(identifier.span()).with_message("`E_STRICT` is used here."))
+            .with_note("No error of the `E_STRICT` level has been raised since PHP 8.0, and `E_ALL` has included it since PHP 5.4.")
+            .with_help("Remove the use of `E_STRICT`.")
+            .with_link("https://www.php.net/manual/en/migration84.deprecated.php");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,91 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::deprecation::rules::utils::get_method_name;
+use crate::plugin::deprecation::rules::utils::is_instance_of;
+use crate::rule::Rule;
+
+const MYSQLI: &str = "mysqli";
+
+/// The deprecated methods of `mysqli`, along with their procedural counterparts and help messages.
+const DEPRECATED_FUNCTIONS: [(&str, &str, &str); 3] = [
+    (
+        "ping",
+        "mysqli_ping",
+        "Connections are no longer reconnected automatically, so checking whether they are alive is no longer useful.",
+    ),
+    ("kill", "mysqli_kill", "Use the SQL `KILL` command instead."),
+    ("refresh", "mysqli_refresh", "Use the SQL `FLUSH` command instead."),
+];
+
+#[derive(Clone, Debug)]
+pub struct MysqliConnectionFunctionsRule;
+
+impl Rule for MysqliConnectionFunctionsRule {
+    fn get_name(&self) -> &'static str {
+        "mysqli-connection-functions"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MysqliConnectionFunctionsRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        // Detect `mysqli_ping($mysqli)`, `mysqli_kill($mysqli, $id)`, and `mysqli_refresh($mysqli, $flags)`.
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.lookup_function_name(identifier);
+        let Some((_, function_name, help)) =
+            DEPRECATED_FUNCTIONS.iter().find(|(_, name, _)| name.eq_ignore_ascii_case(function_name))
+        else {
+            return;
+        };
+
+        report(
+            format!("Function `{}()` is deprecated since PHP 8.4.", function_name),
+            function_call.span(),
+            help,
+            context,
+        );
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        // Detect `$mysqli->ping()`, `$mysqli->kill($id)`, and `$mysqli->refresh($flags)`.
+        let Some(method_name) = get_method_name(&method_call.method, context) else {
+            return;
+        };
+
+        let Some((method_name, _, help)) =
+            DEPRECATED_FUNCTIONS.iter().find(|(name, _, _)| name.eq_ignore_ascii_case(method_name))
+        else {
+            return;
+        };
+
+        if !is_instance_of(&method_call.object, MYSQLI, context) {
+            return;
+        }
+
+        report(
+            format!("Method `mysqli::{}()` is deprecated since PHP 8.4.", method_name),
+            method_call.span(),
+            help,
+            context,
+        );
+    }
+}
+
+fn report(message: String, span: Span, help: &str, context: &mut LintContext<'_>) {
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(span).with_message("The deprecated function is called here."))
+        .with_help(help)
+        .with_link("https://www.php.net/manual/en/migration84.deprecated.php");
+
+    context.report(issue);
+}
@@ -0,0 +1,37 @@
+use mago_ast::*;
+use mago_reflection::r#type::kind::*;
+
+use crate::context::LintContext;
+
+/// Determines whether the given expression is an instance of the given class, or of one of its descendants.
+///
+/// The class name is matched case-insensitively, and must be fully qualified without a leading backslash.
+pub fn is_instance_of(object: &Expression, class_name: &str, context: &LintContext<'_>) -> bool {
+    let TypeKind::Object(object) = context.resolve_kind(object) else {
+        return false;
+    };
+
+    if let ObjectTypeKind::NamedObject { name, .. } = &object {
+        if context.lookup(name).eq_ignore_ascii_case(class_name) {
+            return true;
+        }
+    }
+
+    let Some(class_like) = context.get_object_reflection(&object) else {
+        return false;
+    };
+
+    class_like
+        .inheritance
+        .all_extended_classes
+        .iter()
+        .any(|parent| context.lookup(&parent.value).eq_ignore_ascii_case(class_name))
+}
+
+/// Returns the name of the method called by the given selector, if it is not dynamic.
+pub fn get_method_name<'a>(selector: &ClassLikeMemberSelector, context: &'a LintContext<'_>) -> Option<&'a str> {
+    match selector {
+        ClassLikeMemberSelector::Identifier(identifier) => Some(context.lookup(&identifier.value)),
+        _ => None,
+    }
+}
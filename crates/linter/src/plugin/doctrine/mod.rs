@@ -0,0 +1,33 @@
+use crate::plugin::doctrine::rules::mapping::association_mapping::AssociationMappingRule;
+use crate::plugin::doctrine::rules::mapping::collection_initialization::CollectionInitializationRule;
+use crate::plugin::doctrine::rules::mapping::column_type::ColumnTypeRule;
+use crate::plugin::doctrine::rules::query::dql_syntax::DqlSyntaxRule;
+
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rules;
+
+#[derive(Debug)]
+pub struct DoctrinePlugin;
+
+impl Plugin for DoctrinePlugin {
+    fn get_name(&self) -> &'static str {
+        "doctrine"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            // Mapping
+            Box::new(ColumnTypeRule),
+            Box::new(AssociationMappingRule),
+            Box::new(CollectionInitializationRule),
+            // Query
+            Box::new(DqlSyntaxRule),
+        ]
+    }
+}
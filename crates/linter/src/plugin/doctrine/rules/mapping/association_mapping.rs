@@ -0,0 +1,266 @@
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::doctrine::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct AssociationMappingRule;
+
+impl Rule for AssociationMappingRule {
+    fn get_name(&self) -> &'static str {
+        "association-mapping"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for AssociationMappingRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_mapped_class_like(class, context) else {
+            return;
+        };
+
+        for property in get_declared_properties(class_like) {
+            check_property(class_like, property, context);
+        }
+    }
+}
+
+fn check_property(class_like: &ClassLikeReflection, property: &PropertyReflection, context: &mut LintContext<'_>) {
+    let Some(association) = get_association(property, context) else {
+        return;
+    };
+
+    let property_name = context.lookup(&property.name.member.value).to_string();
+    let association_name = association.kind.as_str();
+
+    let Some(target_entity) = association.target_entity else {
+        if association.kind.is_to_many() {
+            let issue = Issue::new(
+                context.level(),
+                format!("Association `{}` does not declare its target entity.", property_name),
+            )
+            .with_annotation(
+                Annotation::primary(association.attribute.span)
+                    .with_message(format!("`{}` requires a `targetEntity`.", association_name)),
+            )
+            .with_note(
+                "The target entity of a collection-valued association cannot be inferred from the property type.",
+            )
+            .with_help("Pass the class of the target entity to `targetEntity`.");
+
+            context.report(issue);
+        }
+
+        return;
+    };
+
+    let target_entity_name = context.lookup(&target_entity).to_string();
+    let Some(target) = context.codebase.get_named_class_like(&target_entity) else {
+        let issue = Issue::new(
+            context.level(),
+            format!("Association `{}` targets `{}`, which does not exist.", property_name, target_entity_name),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("`{}` is not defined.", target_entity_name)),
+        )
+        .with_help("Make sure the target entity is spelled correctly, and is imported into the current namespace.");
+
+        context.report(issue);
+
+        return;
+    };
+
+    if !is_entity(target, context) {
+        let issue = Issue::new(
+            context.level(),
+            format!("Association `{}` targets `{}`, which is not an entity.", property_name, target_entity_name),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("`{}` is not an entity.", target_entity_name)),
+        )
+        .with_annotation(Annotation::secondary(target.span).with_message("The target is declared here."))
+        .with_help(format!("Add `#[ORM\\Entity]` to `{}`, or target an entity instead.", target_entity_name));
+
+        context.report(issue);
+
+        return;
+    }
+
+    if association.kind == AssociationKind::OneToMany && association.mapped_by.is_none() {
+        let issue = Issue::new(
+            context.level(),
+            format!("Association `{}` does not declare the property it is mapped by.", property_name),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span).with_message("`OneToMany` requires a `mappedBy`."),
+        )
+        .with_note("A `OneToMany` association is always the inverse side of a `ManyToOne` association.")
+        .with_help(format!(
+            "Pass the name of the `ManyToOne` property of `{}` to `mappedBy`, or use a `ManyToMany` association.",
+            target_entity_name
+        ));
+
+        context.report(issue);
+
+        return;
+    }
+
+    let (argument_name, reference, expected_back_reference) = match (association.mapped_by, association.inversed_by) {
+        (Some(mapped_by), _) => ("mappedBy", mapped_by, "inversedBy"),
+        (None, Some(inversed_by)) => ("inversedBy", inversed_by, "mappedBy"),
+        (None, None) => return,
+    };
+
+    let reference_name = context.lookup(&reference).to_string();
+    let target_property_name = context.interner.intern(format!("${}", reference_name));
+    let Some(target_property) = context.codebase.get_property(target, &target_property_name) else {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Association `{}` is {} `{}::${}`, which does not exist.",
+                property_name,
+                if argument_name == "mappedBy" { "mapped by" } else { "inversed by" },
+                target_entity_name,
+                reference_name
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("`{}` refers to an undefined property.", argument_name)),
+        )
+        .with_annotation(Annotation::secondary(target.span).with_message("The target entity is declared here."))
+        .with_help(format!("Pass the name of a property of `{}` to `{}`.", target_entity_name, argument_name));
+
+        context.report(issue);
+
+        return;
+    };
+
+    let Some(target_association) = get_association(&target_property, context) else {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Association `{}` refers to `{}::${}`, which is not an association.",
+                property_name, target_entity_name, reference_name
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("`{}` refers to a property that is not an association.", argument_name)),
+        )
+        .with_annotation(
+            Annotation::secondary(target_property.item_span).with_message("The referenced property is declared here."),
+        )
+        .with_help(format!(
+            "Map `{}::${}` as a `{}` association.",
+            target_entity_name,
+            reference_name,
+            association.kind.get_opposite().as_str()
+        ));
+
+        context.report(issue);
+
+        return;
+    };
+
+    if target_association.kind != association.kind.get_opposite() {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Association `{}` is a `{}`, but `{}::${}` is a `{}`.",
+                property_name,
+                association_name,
+                target_entity_name,
+                reference_name,
+                target_association.kind.as_str()
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("This is a `{}` association.", association_name)),
+        )
+        .with_annotation(
+            Annotation::secondary(target_association.attribute.span)
+                .with_message(format!("This is a `{}` association.", target_association.kind.as_str())),
+        )
+        .with_help(format!(
+            "The other side of a `{}` association must be a `{}` association.",
+            association_name,
+            association.kind.get_opposite().as_str()
+        ));
+
+        context.report(issue);
+
+        return;
+    }
+
+    let back_reference =
+        if argument_name == "mappedBy" { target_association.inversed_by } else { target_association.mapped_by };
+
+    if !is_back_reference(class_like, property, back_reference, target_association.target_entity, context) {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Association `{}` and `{}::${}` are not mapped as two sides of the same association.",
+                property_name, target_entity_name, reference_name
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(association.attribute.span)
+                .with_message(format!("`{}` refers to `{}::${}`.", argument_name, target_entity_name, reference_name)),
+        )
+        .with_annotation(Annotation::secondary(target_association.attribute.span).with_message(format!(
+            "This association should declare `{}: '{}'`.",
+            expected_back_reference,
+            context.lookup(&property.name.member.value).trim_start_matches('$')
+        )))
+        .with_help(format!(
+            "Make `{}` on `{}::${}` refer back to `{}`.",
+            expected_back_reference, target_entity_name, reference_name, property_name
+        ));
+
+        context.report(issue);
+    }
+}
+
+/// Determines whether the other side of an association refers back to the given property.
+fn is_back_reference(
+    class_like: &ClassLikeReflection,
+    property: &PropertyReflection,
+    back_reference: Option<StringIdentifier>,
+    back_reference_target: Option<StringIdentifier>,
+    context: &LintContext<'_>,
+) -> bool {
+    let Some(back_reference) = back_reference else {
+        return false;
+    };
+
+    if context.lookup(&back_reference) != context.lookup(&property.name.member.value).trim_start_matches('$') {
+        return false;
+    }
+
+    // The back reference may target a parent of the class, such as a mapped superclass.
+    let Some(back_reference_target) = back_reference_target else {
+        return true;
+    };
+
+    let back_reference_target = context.lookup(&back_reference_target);
+
+    class_like.name.get_key(context.interner).eq_ignore_ascii_case(back_reference_target)
+        || class_like
+            .inheritance
+            .all_extended_classes
+            .iter()
+            .any(|parent| context.lookup(&parent.value).eq_ignore_ascii_case(back_reference_target))
+}
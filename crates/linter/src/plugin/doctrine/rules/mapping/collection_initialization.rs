@@ -0,0 +1,128 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::doctrine::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct CollectionInitializationRule;
+
+impl Rule for CollectionInitializationRule {
+    fn get_name(&self) -> &'static str {
+        "collection-initialization"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for CollectionInitializationRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_mapped_class_like(class, context) else {
+            return;
+        };
+
+        let constructor = class.members.iter().find_map(|member| match member {
+            ClassLikeMember::Method(method)
+                if context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") =>
+            {
+                Some(method)
+            }
+            _ => None,
+        });
+
+        // Without a constructor of its own, the class may rely on the constructor of its parent.
+        if constructor.is_none() && class_like.inheritance.direct_extended_class.is_some() {
+            return;
+        }
+
+        let initialized_properties =
+            constructor.map(|constructor| get_initialized_properties(constructor, context)).unwrap_or_default();
+
+        for property in get_declared_properties(class_like) {
+            let property_name = context.lookup(&property.name.member.value).to_string();
+            if property.is_promoted
+                || initialized_properties.iter().any(|name| name == property_name.trim_start_matches('$'))
+            {
+                continue;
+            }
+
+            let Some(association) = get_association(property, context) else {
+                continue;
+            };
+
+            if !association.kind.is_to_many() {
+                continue;
+            }
+
+            let mut issue = Issue::new(
+                context.level(),
+                format!("Collection `{}` is not initialized in the constructor.", property_name),
+            )
+            .with_annotation(
+                Annotation::primary(property.item_span)
+                    .with_message(format!("`{}` is a `{}` association.", property_name, association.kind.as_str())),
+            )
+            .with_note("Doctrine does not call the constructor when loading entities, but does initialize their collections; new entities must initialize them.")
+            .with_help(format!(
+                "Initialize the collection in the constructor using `$this->{} = new ArrayCollection();`.",
+                property_name.trim_start_matches('$')
+            ));
+
+            if let Some(constructor) = constructor {
+                issue = issue.with_annotation(
+                    Annotation::secondary(constructor.span()).with_message("The constructor is defined here."),
+                );
+            }
+
+            context.report(issue);
+        }
+    }
+}
+
+/// Returns the names of the properties assigned to `$this` in the top-level statements of the given constructor.
+///
+/// Only top-level statements always run, so assignments nested in control structures are not considered.
+fn get_initialized_properties(constructor: &Method, context: &LintContext<'_>) -> Vec<String> {
+    let MethodBody::Concrete(block) = &constructor.body else {
+        return vec![];
+    };
+
+    block
+        .statements
+        .iter()
+        .filter_map(|statement| {
+            let Statement::Expression(ExpressionStatement {
+                expression: Expression::AssignmentOperation(assignment),
+                ..
+            }) = statement
+            else {
+                return None;
+            };
+
+            if !matches!(assignment.operator, AssignmentOperator::Assign(_)) {
+                return None;
+            }
+
+            let Expression::Access(access) = assignment.lhs.as_ref() else {
+                return None;
+            };
+
+            let Access::Property(property_access) = access.as_ref() else {
+                return None;
+            };
+
+            let (Expression::Variable(Variable::Direct(object)), ClassLikeMemberSelector::Identifier(property)) =
+                (&property_access.object, &property_access.property)
+            else {
+                return None;
+            };
+
+            (context.lookup(&object.name) == "$this").then(|| context.lookup(&property.value).to_string())
+        })
+        .collect()
+}
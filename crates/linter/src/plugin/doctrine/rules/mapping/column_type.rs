@@ -0,0 +1,125 @@
+use mago_ast::*;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::doctrine::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ColumnTypeRule;
+
+impl Rule for ColumnTypeRule {
+    fn get_name(&self) -> &'static str {
+        "column-type"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ColumnTypeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_mapped_class_like(class, context) else {
+            return;
+        };
+
+        for property in get_declared_properties(class_like) {
+            check_property(property, context);
+        }
+    }
+}
+
+fn check_property(property: &PropertyReflection, context: &mut LintContext<'_>) {
+    let Some(column) = get_attribute(&property.attribut_reflections, COLUMN, context) else {
+        return;
+    };
+
+    // Without a native type, Doctrine infers the column type from the docblock, or defaults to `string`.
+    let Some(type_reflection) = &property.type_reflection else {
+        return;
+    };
+
+    let Some((property_types, property_is_nullable)) = get_property_type_names(&type_reflection.kind, context) else {
+        return;
+    };
+
+    let property_name = context.lookup(&property.name.member.value).to_string();
+    let column_is_nullable = match get_attribute_argument(column, Some(6), "nullable", context) {
+        Some(TypeKind::Value(ValueTypeKind::True)) => true,
+        Some(TypeKind::Value(ValueTypeKind::False)) | None => false,
+        // The nullability is not known statically.
+        Some(_) => return,
+    };
+
+    if let Some(type_name) =
+        get_attribute_argument(column, Some(1), "type", context).and_then(|kind| get_column_type_name(kind, context))
+    {
+        if let Some(expected_types) = get_column_php_types(&type_name) {
+            let is_compatible = property_types.iter().all(|property_type| {
+                expected_types.iter().any(|expected_type| expected_type.eq_ignore_ascii_case(property_type))
+            });
+
+            if !property_types.is_empty() && !is_compatible {
+                let issue = Issue::new(
+                    context.level(),
+                    format!(
+                        "Property `{}` is mapped to a column of type `{}`, but is declared as `{}`.",
+                        property_name,
+                        type_name,
+                        property_types.join("|")
+                    ),
+                )
+                .with_annotation(
+                    Annotation::primary(column.span).with_message(format!("The column is of type `{}`.", type_name)),
+                )
+                .with_annotation(
+                    Annotation::secondary(type_reflection.span)
+                        .with_message(format!("The property is declared as `{}`.", property_types.join("|"))),
+                )
+                .with_note(format!(
+                    "Doctrine hydrates values of columns of type `{}` as `{}`.",
+                    type_name,
+                    expected_types.join("|")
+                ))
+                .with_help("Change the type of the property, or the type of the column, so that they match.");
+
+                context.report(issue);
+            }
+        }
+    }
+
+    if column_is_nullable && !property_is_nullable {
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Property `{}` is mapped to a nullable column, but its type does not accept `null`.",
+                property_name
+            ),
+        )
+        .with_annotation(Annotation::primary(column.span).with_message("The column is nullable."))
+        .with_annotation(Annotation::secondary(type_reflection.span).with_message("This type does not accept `null`."))
+        .with_help("Make the type of the property nullable, or remove `nullable: true` from the column.");
+
+        context.report(issue);
+    } else if !column_is_nullable && property_is_nullable {
+        // Identifiers are commonly `null` until the entity is persisted.
+        if get_attribute(&property.attribut_reflections, ID, context).is_some() {
+            return;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Property `{}` accepts `null`, but is mapped to a column that is not nullable.", property_name),
+        )
+        .with_annotation(Annotation::primary(column.span).with_message("The column is not nullable."))
+        .with_annotation(Annotation::secondary(type_reflection.span).with_message("This type accepts `null`."))
+        .with_note("Flushing the entity while the property is `null` will fail with a database error.")
+        .with_help("Add `nullable: true` to the column, or make the type of the property non-nullable.");
+
+        context.report(issue);
+    }
+}
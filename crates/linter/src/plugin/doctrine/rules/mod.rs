@@ -0,0 +1,11 @@
+pub mod utils;
+
+pub mod mapping {
+    pub mod association_mapping;
+    pub mod collection_initialization;
+    pub mod column_type;
+}
+
+pub mod query {
+    pub mod dql_syntax;
+}
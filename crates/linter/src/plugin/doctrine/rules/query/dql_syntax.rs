@@ -0,0 +1,93 @@
+use mago_ast::*;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::dql::validate_dql;
+use crate::literal::DecodedString;
+use crate::rule::Rule;
+
+const ENTITY_MANAGER_INTERFACE: &str = "Doctrine\\ORM\\EntityManagerInterface";
+
+#[derive(Clone, Debug)]
+pub struct DqlSyntaxRule;
+
+impl Rule for DqlSyntaxRule {
+    fn get_name(&self) -> &'static str {
+        "dql-syntax"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DqlSyntaxRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return;
+        };
+
+        if !context.lookup(&method.value).eq_ignore_ascii_case("createQuery")
+            || !is_entity_manager(method_call, context)
+        {
+            return;
+        }
+
+        let Some(Expression::Literal(Literal::String(literal))) =
+            method_call.arguments.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+                Argument::Positional(argument) if index == 0 && argument.ellipsis.is_none() => Some(&argument.value),
+                Argument::Named(argument) if context.lookup(&argument.name.value) == "dql" => Some(&argument.value),
+                _ => None,
+            })
+        else {
+            return;
+        };
+
+        let Some(decoded) = DecodedString::decode(literal, context) else {
+            return;
+        };
+
+        let Some(error) = validate_dql(&decoded.value) else {
+            return;
+        };
+
+        context.report(
+            Issue::new(context.level(), "Invalid DQL query passed to `createQuery()`.")
+                .with_annotations([
+                    Annotation::primary(decoded.span(error.start, error.end)).with_message(error.message.as_str()),
+                    Annotation::secondary(literal.span),
+                ])
+                .with_note("Doctrine throws a `QueryException` when the query is executed.")
+                .with_help("Fix the syntax of the query."),
+        );
+    }
+}
+
+/// Determines whether the object `createQuery()` is called on may be an entity manager.
+///
+/// Objects of unknown types are assumed to be entity managers, as `createQuery()` is rarely defined elsewhere.
+fn is_entity_manager(method_call: &MethodCall, context: &LintContext<'_>) -> bool {
+    let TypeKind::Object(object) = context.resolve_kind(&method_call.object) else {
+        return true;
+    };
+
+    let ObjectTypeKind::NamedObject { name, .. } = &object else {
+        return true;
+    };
+
+    if context.lookup(name).to_ascii_lowercase().contains("entitymanager") {
+        return true;
+    }
+
+    let Some(class_like) = context.get_object_reflection(&object) else {
+        return false;
+    };
+
+    class_like
+        .inheritance
+        .all_implemented_interfaces
+        .iter()
+        .any(|interface| context.lookup(&interface.value).eq_ignore_ascii_case(ENTITY_MANAGER_INTERFACE))
+}
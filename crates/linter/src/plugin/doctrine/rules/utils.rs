@@ -0,0 +1,282 @@
+use mago_ast::Class;
+use mago_interner::StringIdentifier;
+use mago_reflection::attribute::AttributeArgumentReflection;
+use mago_reflection::attribute::AttributeReflection;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::r#type::kind::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+
+pub const ENTITY: &str = "Doctrine\\ORM\\Mapping\\Entity";
+pub const MAPPED_SUPERCLASS: &str = "Doctrine\\ORM\\Mapping\\MappedSuperclass";
+pub const EMBEDDABLE: &str = "Doctrine\\ORM\\Mapping\\Embeddable";
+pub const COLUMN: &str = "Doctrine\\ORM\\Mapping\\Column";
+pub const ID: &str = "Doctrine\\ORM\\Mapping\\Id";
+pub const TYPES: &str = "Doctrine\\DBAL\\Types\\Types";
+
+/// The kind of an association between two entities, as declared by its mapping attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssociationKind {
+    ManyToOne,
+    OneToMany,
+    OneToOne,
+    ManyToMany,
+}
+
+impl AssociationKind {
+    fn from_attribute_name(name: &str) -> Option<Self> {
+        let name = name.strip_prefix("Doctrine\\ORM\\Mapping\\")?;
+
+        match name {
+            "ManyToOne" => Some(Self::ManyToOne),
+            "OneToMany" => Some(Self::OneToMany),
+            "OneToOne" => Some(Self::OneToOne),
+            "ManyToMany" => Some(Self::ManyToMany),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ManyToOne => "ManyToOne",
+            Self::OneToMany => "OneToMany",
+            Self::OneToOne => "OneToOne",
+            Self::ManyToMany => "ManyToMany",
+        }
+    }
+
+    /// Returns whether the association holds a collection of entities.
+    pub fn is_to_many(&self) -> bool {
+        matches!(self, Self::OneToMany | Self::ManyToMany)
+    }
+
+    /// Returns the kind of the association on the other side of a bidirectional association of this kind.
+    pub fn get_opposite(&self) -> Self {
+        match self {
+            Self::ManyToOne => Self::OneToMany,
+            Self::OneToMany => Self::ManyToOne,
+            Self::OneToOne => Self::OneToOne,
+            Self::ManyToMany => Self::ManyToMany,
+        }
+    }
+
+    /// Returns the position of the given parameter in the constructor of the attribute, if it is the same
+    /// across the supported versions of Doctrine ORM.
+    fn get_parameter_position(&self, parameter: &str) -> Option<usize> {
+        match (self, parameter) {
+            (Self::ManyToOne, "targetEntity") => Some(0),
+            (Self::ManyToOne, "inversedBy") => Some(3),
+            (Self::OneToOne | Self::ManyToMany, "targetEntity") => Some(0),
+            (Self::OneToOne | Self::ManyToMany, "mappedBy") => Some(1),
+            (Self::OneToOne | Self::ManyToMany, "inversedBy") => Some(2),
+            // The order of the parameters of `OneToMany` changed in Doctrine ORM 3.
+            _ => None,
+        }
+    }
+}
+
+/// An association declared by a mapping attribute on a property.
+#[derive(Debug, Clone, Copy)]
+pub struct Association<'a> {
+    pub kind: AssociationKind,
+    pub attribute: &'a AttributeReflection,
+    /// The fully qualified name of the target entity, either declared or inferred from the property type.
+    pub target_entity: Option<StringIdentifier>,
+    pub mapped_by: Option<StringIdentifier>,
+    pub inversed_by: Option<StringIdentifier>,
+}
+
+/// Determines whether the given class-like is an entity.
+pub fn is_entity(class_like: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    get_attribute(&class_like.attribute_reflections, ENTITY, context).is_some()
+}
+
+/// Determines whether the properties of the given class-like are mapped to columns, which is the case
+/// for entities, mapped superclasses, and embeddables.
+pub fn is_mapped(class_like: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    [ENTITY, MAPPED_SUPERCLASS, EMBEDDABLE]
+        .iter()
+        .any(|name| get_attribute(&class_like.attribute_reflections, name, context).is_some())
+}
+
+/// Returns the reflection of the given class, if its properties are mapped to columns.
+///
+/// Another class with the same name may be defined elsewhere, in which case the reflection may not be the one
+/// of the given class, so `None` is returned as well.
+pub fn get_mapped_class_like<'a>(class: &Class, context: &LintContext<'a>) -> Option<&'a ClassLikeReflection> {
+    let class_like = context.codebase.get_named_class_like(context.semantics.names.get(&class.name))?;
+
+    (class_like.span == class.span() && is_mapped(class_like, context)).then_some(class_like)
+}
+
+/// Returns the non-static properties declared by the given class-like, in the order of their declaration.
+pub fn get_declared_properties(class_like: &ClassLikeReflection) -> Vec<&PropertyReflection> {
+    let mut properties =
+        class_like.properties.members.values().filter(|property| !property.is_static).collect::<Vec<_>>();
+
+    properties.sort_by_key(|property| property.item_span.start.offset);
+    properties
+}
+
+/// Returns the attribute with the given fully qualified name, if it is present.
+pub fn get_attribute<'a>(
+    attributes: &'a [AttributeReflection],
+    name: &str,
+    context: &LintContext<'_>,
+) -> Option<&'a AttributeReflection> {
+    attributes.iter().find(|attribute| context.lookup(&attribute.name.value).eq_ignore_ascii_case(name))
+}
+
+/// Returns the type of the argument passed at the given position, or with the given name, to an attribute.
+pub fn get_attribute_argument<'a>(
+    attribute: &'a AttributeReflection,
+    position: Option<usize>,
+    name: &str,
+    context: &LintContext<'_>,
+) -> Option<&'a TypeKind> {
+    let arguments = attribute.arguments.as_ref()?;
+
+    arguments.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+        AttributeArgumentReflection::Positional { value_type_reflection, .. } if Some(index) == position => {
+            Some(&value_type_reflection.kind)
+        }
+        AttributeArgumentReflection::Named { name: argument_name, value_type_reflection, .. }
+            if context.lookup(&argument_name.value) == name =>
+        {
+            Some(&value_type_reflection.kind)
+        }
+        _ => None,
+    })
+}
+
+/// Returns the value of the given string literal type.
+pub fn get_string_value(kind: &TypeKind) -> Option<StringIdentifier> {
+    match kind {
+        TypeKind::Value(ValueTypeKind::String { value, .. }) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the association declared on the given property, if any.
+pub fn get_association<'a>(property: &'a PropertyReflection, context: &LintContext<'_>) -> Option<Association<'a>> {
+    let (kind, attribute) = property.attribut_reflections.iter().find_map(|attribute| {
+        AssociationKind::from_attribute_name(context.lookup(&attribute.name.value)).map(|kind| (kind, attribute))
+    })?;
+
+    let argument = |name: &str| get_attribute_argument(attribute, kind.get_parameter_position(name), name, context);
+
+    let declared_target_entity = argument("targetEntity").and_then(|target| match target {
+        TypeKind::Scalar(ScalarTypeKind::ClassString(Some(name))) => Some(*name),
+        TypeKind::Value(ValueTypeKind::String { value, .. }) => {
+            let name = context.lookup(value);
+
+            Some(context.interner.intern(name.strip_prefix('\\').unwrap_or(name)))
+        }
+        _ => None,
+    });
+
+    // The target entity of single-valued associations defaults to the type of the property.
+    let target_entity = declared_target_entity.or_else(|| {
+        if kind.is_to_many() {
+            return None;
+        }
+
+        match property.type_reflection.as_ref().map(|type_reflection| &type_reflection.kind) {
+            Some(TypeKind::Object(ObjectTypeKind::NamedObject { name, .. })) => Some(*name),
+            Some(TypeKind::Union { kinds }) => match kinds.as_slice() {
+                [TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }), TypeKind::Value(ValueTypeKind::Null)]
+                | [TypeKind::Value(ValueTypeKind::Null), TypeKind::Object(ObjectTypeKind::NamedObject { name, .. })] => {
+                    Some(*name)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    });
+
+    Some(Association {
+        kind,
+        attribute,
+        target_entity,
+        mapped_by: argument("mappedBy").and_then(get_string_value),
+        inversed_by: argument("inversedBy").and_then(get_string_value),
+    })
+}
+
+/// Returns the name of the Doctrine type of a column, as passed to the `type` argument of `#[ORM\Column]`.
+///
+/// Both string literals, and the constants of `Doctrine\DBAL\Types\Types` are supported.
+pub fn get_column_type_name(kind: &TypeKind, context: &LintContext<'_>) -> Option<String> {
+    match kind {
+        TypeKind::Value(ValueTypeKind::String { value, .. }) => Some(context.lookup(value).to_string()),
+        TypeKind::Value(ValueTypeKind::ClassLikeConstant { class_like, constant }) => {
+            if !class_like.get_key(context.interner).eq_ignore_ascii_case(TYPES) {
+                return None;
+            }
+
+            // The values of the constants are their lowercase names, without the `_MUTABLE` suffix.
+            let name = context.lookup(constant).to_ascii_lowercase();
+
+            Some(name.strip_suffix("_mutable").map(|name| name.to_string()).unwrap_or(name))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the PHP types a property mapped to a column of the given Doctrine type may be declared with.
+///
+/// Returns `None` for types whose values are not known, such as `json`, or custom types.
+pub fn get_column_php_types(type_name: &str) -> Option<&'static [&'static str]> {
+    Some(match type_name {
+        "string" | "text" | "ascii_string" | "guid" | "decimal" => &["string"],
+        "integer" | "smallint" => &["int"],
+        "bigint" => &["int", "string"],
+        "float" | "smallfloat" => &["float"],
+        "boolean" => &["bool"],
+        "date" | "datetime" | "datetimetz" | "time" => &["DateTime", "DateTimeInterface"],
+        "date_immutable" | "datetime_immutable" | "datetimetz_immutable" | "time_immutable" => {
+            &["DateTimeImmutable", "DateTimeInterface"]
+        }
+        "dateinterval" => &["DateInterval"],
+        "array" | "simple_array" => &["array"],
+        _ => return None,
+    })
+}
+
+/// Returns the names of the types that make up the given native property type, and whether it accepts `null`.
+///
+/// Returns `None` if the type is, or contains, a type that cannot be compared with a column type, such as `mixed`.
+pub fn get_property_type_names(kind: &TypeKind, context: &LintContext<'_>) -> Option<(Vec<String>, bool)> {
+    let mut names = vec![];
+    let mut nullable = false;
+
+    let kinds = match kind {
+        TypeKind::Union { kinds } => kinds.as_slice(),
+        kind => std::slice::from_ref(kind),
+    };
+
+    for kind in kinds {
+        let name = match kind {
+            TypeKind::Value(ValueTypeKind::Null) => {
+                nullable = true;
+
+                continue;
+            }
+            TypeKind::Scalar(ScalarTypeKind::String) => "string".to_string(),
+            TypeKind::Scalar(ScalarTypeKind::Integer { .. }) => "int".to_string(),
+            TypeKind::Scalar(ScalarTypeKind::Float) => "float".to_string(),
+            TypeKind::Scalar(ScalarTypeKind::Bool) | TypeKind::Value(ValueTypeKind::True | ValueTypeKind::False) => {
+                "bool".to_string()
+            }
+            TypeKind::Array(_) => "array".to_string(),
+            TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => context.lookup(name).to_string(),
+            _ => return None,
+        };
+
+        names.push(name);
+    }
+
+    Some((names, nullable))
+}
@@ -2,6 +2,12 @@ use crate::plugin::migration::rules::php80::str_contains::StrContainsRule;
 use crate::plugin::migration::rules::php80::str_starts_with::StrStartsWithRule;
 use crate::plugin::migration::rules::php81::explicit_octal_notation::ExplicitOctalNotationRule;
 use crate::plugin::migration::rules::php82::readonly_class_promotion::ReadonlyClassPromotionRule;
+use crate::plugin::migration::rules::php83::json_validate::JsonValidateRule;
+use crate::plugin::migration::rules::php83::override_attribute::OverrideAttributeRule;
+use crate::plugin::migration::rules::php83::typed_class_constant::TypedClassConstantRule;
+use crate::plugin::migration::rules::php84::array_find::ArrayFindRule;
+use crate::plugin::migration::rules::php84::new_without_parentheses::NewWithoutParenthesesRule;
+use crate::plugin::migration::rules::php84::property_hooks::PropertyHooksRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -29,6 +35,14 @@ impl Plugin for MigrationPlugin {
             Box::new(ExplicitOctalNotationRule),
             // PHP 8.2
             Box::new(ReadonlyClassPromotionRule),
+            // PHP 8.3
+            Box::new(TypedClassConstantRule),
+            Box::new(OverrideAttributeRule),
+            Box::new(JsonValidateRule),
+            // PHP 8.4
+            Box::new(NewWithoutParenthesesRule),
+            Box::new(ArrayFindRule),
+            Box::new(PropertyHooksRule),
         ]
     }
 }
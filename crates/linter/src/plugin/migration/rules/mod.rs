@@ -1,3 +1,5 @@
+pub mod utils;
+
 pub mod php80 {
     pub mod str_contains;
     pub mod str_starts_with;
@@ -10,3 +12,15 @@ pub mod php81 {
 pub mod php82 {
     pub mod readonly_class_promotion;
 }
+
+pub mod php83 {
+    pub mod json_validate;
+    pub mod override_attribute;
+    pub mod typed_class_constant;
+}
+
+pub mod php84 {
+    pub mod array_find;
+    pub mod new_without_parentheses;
+    pub mod property_hooks;
+}
@@ -0,0 +1,151 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::migration::rules::utils::get_removal_range;
+use crate::plugin::migration::rules::utils::get_source_text;
+use crate::rule::Rule;
+
+const JSON_DECODE: &str = "json_decode";
+const JSON_LAST_ERROR: &str = "json_last_error";
+const JSON_ERROR_NONE: &str = "JSON_ERROR_NONE";
+
+#[derive(Clone, Debug)]
+pub struct JsonValidateRule;
+
+impl Rule for JsonValidateRule {
+    fn get_name(&self) -> &'static str {
+        "json-validate"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for JsonValidateRule {
+    fn walk_in_block(&self, block: &Block, context: &mut LintContext<'a>) {
+        // Detect `json_decode($json);` immediately followed by a `json_last_error() === JSON_ERROR_NONE` check.
+        for statements in block.statements.as_slice().windows(2) {
+            let Statement::Expression(ExpressionStatement {
+                expression: Expression::Call(Call::Function(json_decode)),
+                ..
+            }) = &statements[0]
+            else {
+                continue;
+            };
+
+            if !is_function_call(json_decode, JSON_DECODE, context) {
+                continue;
+            }
+
+            let Some(arguments) = get_json_validate_arguments(json_decode, context) else {
+                continue;
+            };
+
+            let condition = match &statements[1] {
+                Statement::Return(Return { value: Some(value), .. }) => value,
+                Statement::Expression(ExpressionStatement { expression, .. }) => expression,
+                Statement::If(r#if) => &r#if.condition,
+                _ => continue,
+            };
+
+            let Some((check, is_negated)) = find_error_check(condition, context) else {
+                continue;
+            };
+
+            let issue = Issue::new(context.level(), "Use `json_validate()` to check whether a string is valid JSON.")
+                .with_annotation(
+                    Annotation::primary(json_decode.span())
+                        .with_message("The string is decoded only to check for errors..."),
+                )
+                .with_annotation(Annotation::secondary(check.span()).with_message("...which happens here."))
+                .with_note("Since PHP 8.3, `json_validate()` checks whether a string is valid JSON without decoding it, using less memory and time.")
+                .with_help(format!("Replace the call to `json_decode()` and the error check with `{}json_validate({})`.", if is_negated { "!" } else { "" }, arguments))
+                .with_link("https://www.php.net/manual/en/function.json-validate.php");
+
+            let code = context.lookup(&context.semantics.source.content);
+            let removal = get_removal_range(code, statements[0].span().start.offset, statements[0].span().end.offset);
+            let replacement = format!("{}json_validate({})", if is_negated { "!" } else { "" }, arguments);
+
+            context.report_with_fix(issue, |plan| {
+                // Mark the fix as potentially unsafe due to possible redefinition of the functions in the namespace.
+                let safety = SafetyClassification::PotentiallyUnsafe;
+
+                plan.delete(removal, safety);
+                plan.replace(check.span().to_range(), replacement, safety);
+            });
+        }
+    }
+}
+
+fn is_function_call(function_call: &FunctionCall, name: &str, context: &LintContext<'_>) -> bool {
+    let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+        return false;
+    };
+
+    context.lookup_function_name(identifier).eq_ignore_ascii_case(name)
+}
+
+/// Returns the arguments of the `json_validate()` call equivalent to the given `json_decode()` call.
+///
+/// `json_decode($json, $associative, $depth)` validates as `json_validate($json, $depth)`; calls passing
+/// flags are not supported, as `json_validate()` rejects most of the flags accepted by `json_decode()`.
+fn get_json_validate_arguments(json_decode: &FunctionCall, context: &LintContext<'_>) -> Option<String> {
+    let mut arguments = vec![];
+    for argument in json_decode.arguments.arguments.iter() {
+        match argument {
+            Argument::Positional(argument) if argument.ellipsis.is_none() => arguments.push(&argument.value),
+            _ => return None,
+        }
+    }
+
+    match arguments.as_slice() {
+        [json] | [json, _] => Some(get_source_text(*json, context).to_string()),
+        [json, _, depth] => Some(format!("{}, {}", get_source_text(*json, context), get_source_text(*depth, context))),
+        _ => None,
+    }
+}
+
+/// Finds a `json_last_error() === JSON_ERROR_NONE` check within the given expression,
+/// returning it along with whether it checks for an error rather than for its absence.
+fn find_error_check<'ast>(expression: &'ast Expression, context: &LintContext<'_>) -> Option<(&'ast Binary, bool)> {
+    match expression {
+        Expression::Binary(binary) => {
+            let is_negated = match binary.operator {
+                BinaryOperator::Identical(_) | BinaryOperator::Equal(_) => false,
+                BinaryOperator::NotIdentical(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => {
+                    true
+                }
+                BinaryOperator::And(_)
+                | BinaryOperator::Or(_)
+                | BinaryOperator::LowAnd(_)
+                | BinaryOperator::LowOr(_) => {
+                    return find_error_check(&binary.lhs, context).or_else(|| find_error_check(&binary.rhs, context));
+                }
+                _ => return None,
+            };
+
+            let is_check = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+                (Expression::Call(Call::Function(call)), Expression::Identifier(constant))
+                | (Expression::Identifier(constant), Expression::Call(Call::Function(call))) => {
+                    call.arguments.arguments.is_empty()
+                        && is_function_call(call, JSON_LAST_ERROR, context)
+                        && context.lookup_function_name(constant) == JSON_ERROR_NONE
+                }
+                _ => false,
+            };
+
+            is_check.then_some((binary, is_negated))
+        }
+        Expression::Parenthesized(parenthesized) => find_error_check(&parenthesized.expression, context),
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) => {
+            find_error_check(operand, context)
+        }
+        Expression::AssignmentOperation(assignment) => find_error_check(&assignment.rhs, context),
+        _ => None,
+    }
+}
@@ -0,0 +1,158 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::migration::rules::utils::get_line_indentation;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct OverrideAttributeRule;
+
+impl Rule for OverrideAttributeRule {
+    fn get_name(&self) -> &'static str {
+        "override-attribute"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for OverrideAttributeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&class.name)) else {
+            return;
+        };
+
+        check_class_like(class_like, class.span(), &class.members, context);
+    }
+
+    fn walk_in_anonymous_class(&self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_class_like(ClassLikeName::AnonymousClass(anonymous_class.span()))
+        else {
+            return;
+        };
+
+        check_class_like(class_like, anonymous_class.span(), &anonymous_class.members, context);
+    }
+
+    fn walk_in_interface(&self, interface: &Interface, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&interface.name))
+        else {
+            return;
+        };
+
+        check_class_like(class_like, interface.span(), &interface.members, context);
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        let Some(class_like) = context.codebase.get_named_class_like(context.semantics.names.get(&r#enum.name)) else {
+            return;
+        };
+
+        check_class_like(class_like, r#enum.span(), &r#enum.members, context);
+    }
+}
+
+fn check_class_like(
+    class_like: &ClassLikeReflection,
+    span: Span,
+    members: &Sequence<ClassLikeMember>,
+    context: &mut LintContext<'_>,
+) {
+    // Another class-like with the same name may be defined elsewhere; only check the one defined here.
+    if class_like.span != span {
+        return;
+    }
+
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        // Constructors are not subject to signature compatibility, marking them as overrides adds nothing.
+        if context.lookup(&method.name.value).eq_ignore_ascii_case("__construct")
+            || has_override_attribute(method, context)
+        {
+            continue;
+        }
+
+        let Some((parent_class_like, parent)) = get_overridden_method(class_like, &method.name.value, context) else {
+            continue;
+        };
+
+        let method_name = context.lookup(&method.name.value);
+        let parent_name = parent.name.get_key(context.interner);
+        let verb =
+            if parent_class_like.is_interface() && !class_like.is_interface() { "implements" } else { "overrides" };
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Method `{}()` {} `{}()`, but is not marked with the `#[\\Override]` attribute.", method_name, verb, parent_name),
+        )
+        .with_annotation(Annotation::primary(method.name.span()).with_message(format!("This method {} a parent method.", verb)))
+        .with_annotation(Annotation::secondary(parent.span).with_message(format!("`{}()` is declared here.", parent_name)))
+        .with_note("Since PHP 8.3, the `#[\\Override]` attribute makes PHP verify that the method overrides a parent method, catching methods left behind when the parent method is renamed or removed.")
+        .with_help("Add the `#[\\Override]` attribute to the method.")
+        .with_link("https://www.php.net/manual/en/class.override.php");
+
+        let code = context.lookup(&context.semantics.source.content);
+        let offset = method.span().start.offset;
+        let attribute = format!("#[\\Override]\n{}", get_line_indentation(code, offset));
+
+        context.report_with_fix(issue, |plan| {
+            plan.insert(offset, attribute, SafetyClassification::Safe);
+        });
+    }
+}
+
+fn has_override_attribute(method: &Method, context: &LintContext<'_>) -> bool {
+    method
+        .attributes
+        .iter()
+        .flat_map(|attribute_list| attribute_list.attributes.iter())
+        .any(|attribute| context.lookup_name(&attribute.name).eq_ignore_ascii_case("Override"))
+}
+
+/// Returns the method overridden by the method with the given name of the given class-like,
+/// along with the class-like it was found in.
+///
+/// Only parent classes and interfaces are considered: the methods of the traits used by the class-like itself,
+/// even abstract ones, do not satisfy the `#[\Override]` attribute.
+fn get_overridden_method<'a>(
+    class_like: &ClassLikeReflection,
+    method_name: &StringIdentifier,
+    context: &LintContext<'a>,
+) -> Option<(&'a ClassLikeReflection, FunctionLikeReflection)> {
+    let parents = class_like
+        .inheritance
+        .direct_extended_class
+        .iter()
+        .chain(&class_like.inheritance.all_implemented_interfaces)
+        .chain(&class_like.inheritance.all_extended_interfaces);
+
+    for parent_name in parents {
+        let Some(parent) = context.codebase.get_named_class_like(&parent_name.value) else {
+            continue;
+        };
+
+        let Some(method) = context.codebase.get_method(parent, method_name) else {
+            continue;
+        };
+
+        if method.is_magic || method.visibility_reflection.is_some_and(|visibility| visibility.is_private()) {
+            continue;
+        }
+
+        return Some((parent, method.into_owned()));
+    }
+
+    None
+}
@@ -0,0 +1,135 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct TypedClassConstantRule;
+
+impl Rule for TypedClassConstantRule {
+    fn get_name(&self) -> &'static str {
+        "typed-class-constant"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for TypedClassConstantRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        check_constants(&class.members, class.modifiers.contains_final(), context);
+    }
+
+    fn walk_in_anonymous_class(&self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        // Anonymous classes cannot be extended.
+        check_constants(&anonymous_class.members, true, context);
+    }
+
+    fn walk_in_interface(&self, interface: &Interface, context: &mut LintContext<'a>) {
+        check_constants(&interface.members, false, context);
+    }
+
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        check_constants(&r#trait.members, false, context);
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        // Enums are implicitly final.
+        check_constants(&r#enum.members, true, context);
+    }
+}
+
+fn check_constants(members: &Sequence<ClassLikeMember>, is_final: bool, context: &mut LintContext<'_>) {
+    // The `require-constant-type` rule of the strictness plugin already reports every untyped constant.
+    if context.is_rule_enabled("require-constant-type") {
+        return;
+    }
+
+    for member in members.iter() {
+        let ClassLikeMember::Constant(constant) = member else {
+            continue;
+        };
+
+        if constant.hint.is_some() {
+            continue;
+        }
+
+        // The items of a declaration share its type, so the type of all their values must be the same.
+        let mut types = constant.items.iter().map(|item| get_value_type(&item.value));
+        let Some(Some(value_type)) = types.next() else {
+            continue;
+        };
+
+        if !types.all(|other| other == Some(value_type)) {
+            continue;
+        }
+
+        let names =
+            constant.items.iter().map(|item| format!("`{}`", context.lookup(&item.name.value))).collect::<Vec<_>>();
+
+        let issue = Issue::new(
+            context.level(),
+            if names.len() == 1 {
+                format!("Class constant {} can declare its type.", names[0])
+            } else {
+                format!("Class constants {} can declare their type.", names.join(", "))
+            },
+        )
+        .with_annotation(Annotation::primary(constant.span()).with_message(format!(
+            "This constant always holds {}.",
+            match value_type {
+                "int" => "an integer",
+                "float" => "a float",
+                "string" => "a string",
+                "bool" => "a boolean",
+                _ => "an array",
+            }
+        )))
+        .with_note(
+            "Since PHP 8.3, the type of class constants can be declared, and is enforced when they are overridden.",
+        )
+        .with_help(format!(
+            "Declare the constant as `const {} {} = ...`.",
+            value_type,
+            context.lookup(&constant.first_item().name.value)
+        ))
+        .with_link("https://www.php.net/manual/en/migration83.new-features.php");
+
+        // Once typed, a constant can only be overridden by a constant of a compatible type.
+        let safety = if is_final || constant.modifiers.contains_final() || constant.modifiers.contains_private() {
+            SafetyClassification::Safe
+        } else {
+            SafetyClassification::PotentiallyUnsafe
+        };
+
+        context.report_with_fix(issue, |plan| {
+            plan.insert(constant.r#const.span.end.offset, format!(" {}", value_type), safety);
+        });
+    }
+}
+
+/// Returns the type of the given constant value, if it is evident from the value itself.
+fn get_value_type(value: &Expression) -> Option<&'static str> {
+    match value {
+        Expression::Literal(Literal::Integer(_)) => Some("int"),
+        Expression::Literal(Literal::Float(_)) => Some("float"),
+        Expression::Literal(Literal::String(_)) => Some("string"),
+        Expression::Literal(Literal::True(_) | Literal::False(_)) => Some("bool"),
+        Expression::Array(_) | Expression::LegacyArray(_) => Some("array"),
+        Expression::UnaryPrefix(UnaryPrefix {
+            operator: UnaryPrefixOperator::Negation(_) | UnaryPrefixOperator::Plus(_),
+            operand,
+        }) => match operand.as_ref() {
+            Expression::Literal(Literal::Integer(_)) => Some("int"),
+            Expression::Literal(Literal::Float(_)) => Some("float"),
+            _ => None,
+        },
+        Expression::Parenthesized(parenthesized) => get_value_type(&parenthesized.expression),
+        _ => None,
+    }
+}
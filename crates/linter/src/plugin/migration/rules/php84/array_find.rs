@@ -0,0 +1,163 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::migration::rules::utils::get_source_text;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ArrayFindRule;
+
+impl Rule for ArrayFindRule {
+    fn get_name(&self) -> &'static str {
+        "array-find"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ArrayFindRule {
+    fn walk_in_block(&self, block: &Block, context: &mut LintContext<'a>) {
+        // Detect `foreach ($items as $item) { if ($condition) { return $item; } } return null;` and similar.
+        for statements in block.statements.as_slice().windows(2) {
+            let Statement::Foreach(foreach) = &statements[0] else {
+                continue;
+            };
+
+            let Statement::Return(Return { value: Some(fallback), .. }) = &statements[1] else {
+                continue;
+            };
+
+            let (key, value) = match &foreach.target {
+                ForeachTarget::Value(target) => (None, &target.value),
+                ForeachTarget::KeyValue(target) => match &target.key {
+                    Expression::Variable(Variable::Direct(key)) => (Some(key), &target.value),
+                    _ => continue,
+                },
+            };
+
+            let Expression::Variable(Variable::Direct(value)) = value else {
+                continue;
+            };
+
+            // The functions only accept arrays, while the loop also accepts traversable objects.
+            if !context.resolve_kind(&foreach.expression).is_array() {
+                continue;
+            }
+
+            let Some((condition, result)) = get_conditional_return(foreach) else {
+                continue;
+            };
+
+            let (function, condition) = match (result, fallback) {
+                (Expression::Literal(Literal::True(_)), Expression::Literal(Literal::False(_))) => {
+                    ("array_any", get_source_text(condition, context).to_string())
+                }
+                (Expression::Literal(Literal::False(_)), Expression::Literal(Literal::True(_))) => {
+                    ("array_all", negate(condition, context))
+                }
+                (Expression::Variable(Variable::Direct(variable)), Expression::Literal(Literal::Null(_))) => {
+                    if variable.name == value.name {
+                        ("array_find", get_source_text(condition, context).to_string())
+                    } else if key.is_some_and(|key| key.name == variable.name) {
+                        ("array_find_key", get_source_text(condition, context).to_string())
+                    } else {
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+
+            let parameters = match key {
+                Some(key) => format!("{}, {}", context.lookup(&value.name), context.lookup(&key.name)),
+                None => context.lookup(&value.name).to_string(),
+            };
+
+            let replacement = format!(
+                "return {}({}, fn({}) => {})",
+                function,
+                get_source_text(&foreach.expression, context),
+                parameters,
+                condition
+            );
+
+            let issue = Issue::new(context.level(), format!("Loop can be replaced with a call to `{}()`.", function))
+                .with_annotation(
+                    Annotation::primary(foreach.foreach.span.join(foreach.right_parenthesis))
+                        .with_message("This loop searches for an element matching a condition..."),
+                )
+                .with_annotation(
+                    Annotation::secondary(statements[1].span())
+                        .with_message("...and this is returned when no element matches."),
+                )
+                .with_note("Since PHP 8.4, `array_find()`, `array_find_key()`, `array_any()`, and `array_all()` search an array for elements matching a condition.")
+                .with_help(format!("Replace the loop with `{};`.", replacement))
+                .with_link("https://www.php.net/manual/en/migration84.new-functions.php");
+
+            let range = foreach.span().start.offset..fallback.span().end.offset;
+
+            context.report_with_fix(issue, |plan| {
+                plan.replace(range, replacement, SafetyClassification::PotentiallyUnsafe);
+            });
+        }
+    }
+}
+
+/// Returns the condition and the returned value of a loop body consisting of a single
+/// `if ($condition) { return $result; }` statement.
+fn get_conditional_return(foreach: &Foreach) -> Option<(&Expression, &Expression)> {
+    let ForeachBody::Statement(statement) = &foreach.body else {
+        return None;
+    };
+
+    let Statement::If(r#if) = get_single_statement(statement)? else {
+        return None;
+    };
+
+    let IfBody::Statement(body) = &r#if.body else {
+        return None;
+    };
+
+    if !body.else_if_clauses.is_empty() || body.else_clause.is_some() {
+        return None;
+    }
+
+    let Statement::Return(Return { value: Some(result), .. }) = get_single_statement(&body.statement)? else {
+        return None;
+    };
+
+    Some((&r#if.condition, result))
+}
+
+/// Returns the given statement, or the only statement of the given block.
+fn get_single_statement(statement: &Statement) -> Option<&Statement> {
+    match statement {
+        Statement::Block(block) => match block.statements.as_slice() {
+            [statement] => get_single_statement(statement),
+            _ => None,
+        },
+        statement => Some(statement),
+    }
+}
+
+/// Returns the source code of the negation of the given condition.
+fn negate(condition: &Expression, context: &LintContext<'_>) -> String {
+    match condition {
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) => {
+            get_source_text(operand.as_ref(), context).to_string()
+        }
+        Expression::Variable(_)
+        | Expression::Identifier(_)
+        | Expression::Literal(_)
+        | Expression::Parenthesized(_)
+        | Expression::Call(_)
+        | Expression::Access(_)
+        | Expression::ArrayAccess(_) => format!("!{}", get_source_text(condition, context)),
+        _ => format!("!({})", get_source_text(condition, context)),
+    }
+}
@@ -0,0 +1,73 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NewWithoutParenthesesRule;
+
+impl Rule for NewWithoutParenthesesRule {
+    fn get_name(&self) -> &'static str {
+        "new-without-parentheses"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NewWithoutParenthesesRule {
+    fn walk_in_expression(&self, expression: &Expression, context: &mut LintContext<'a>) {
+        // Detect `(new Foo())->bar()`, `(new Foo())::BAR`, `(new Foo())['bar']`, and similar.
+        let object = match expression {
+            Expression::Call(Call::Function(call)) => call.function.as_ref(),
+            Expression::Call(Call::Method(call)) => call.object.as_ref(),
+            Expression::Call(Call::NullSafeMethod(call)) => call.object.as_ref(),
+            Expression::Call(Call::StaticMethod(call)) => call.class.as_ref(),
+            Expression::Access(access) => match access.as_ref() {
+                Access::Property(access) => &access.object,
+                Access::NullSafeProperty(access) => &access.object,
+                Access::StaticProperty(access) => &access.class,
+                Access::ClassConstant(access) => &access.class,
+            },
+            Expression::ArrayAccess(access) => access.array.as_ref(),
+            _ => return,
+        };
+
+        let Expression::Parenthesized(parenthesized) = object else {
+            return;
+        };
+
+        let Expression::Instantiation(instantiation) = parenthesized.expression.as_ref() else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "Parentheses around `new` expressions are no longer needed.")
+            .with_annotation(
+                Annotation::primary(parenthesized.span())
+                    .with_message("The newly created instance is accessed here."),
+            )
+            .with_note("Since PHP 8.4, the members of a newly created instance can be accessed without wrapping the `new` expression in parentheses, as long as the constructor arguments are.")
+            .with_help(if instantiation.arguments.is_some() {
+                "Remove the parentheses around the `new` expression."
+            } else {
+                "Remove the parentheses around the `new` expression, and add parentheses after the class name."
+            })
+            .with_link("https://www.php.net/manual/en/migration84.new-features.php");
+
+        context.report_with_fix(issue, |plan| {
+            let safety = SafetyClassification::Safe;
+
+            plan.delete(parenthesized.left_parenthesis.to_range(), safety);
+            if instantiation.arguments.is_none() {
+                plan.insert(instantiation.class.span().end.offset, "()", safety);
+            }
+
+            plan.delete(parenthesized.right_parenthesis.to_range(), safety);
+        });
+    }
+}
@@ -0,0 +1,292 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct PropertyHooksRule;
+
+impl Rule for PropertyHooksRule {
+    fn get_name(&self) -> &'static str {
+        "property-hooks"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+/// How a property accessed through a getter, and possibly a setter, can be exposed instead.
+enum Replacement<'ast> {
+    /// The property is made public, and its write visibility is kept private, as in `public private(set)`.
+    AsymmetricVisibility,
+    /// The property is made public, as it is readonly, or its setter assigns it without further logic.
+    Public,
+    /// The property is made public, and the body of its setter is moved to a `set` hook.
+    SetHook(&'ast Method),
+}
+
+impl<'a> Walker<LintContext<'a>> for PropertyHooksRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        // Readonly properties cannot have hooks, nor asymmetric visibility.
+        if class.modifiers.contains_readonly() {
+            return;
+        }
+
+        // Magic `__get()` and `__set()` methods may rely on the properties being inaccessible.
+        let has_magic_accessors = class.members.iter().any(|member| match member {
+            ClassLikeMember::Method(method) => {
+                let name = context.lookup(&method.name.value);
+
+                name.eq_ignore_ascii_case("__get") || name.eq_ignore_ascii_case("__set")
+            }
+            _ => false,
+        });
+
+        if has_magic_accessors {
+            return;
+        }
+
+        let class_like = context.codebase.get_named_class_like(context.semantics.names.get(&class.name));
+        for member in class.members.iter() {
+            if let ClassLikeMember::Property(Property::Plain(property)) = member {
+                check_property(property, &class.members, class_like, context);
+            }
+        }
+    }
+}
+
+fn check_property(
+    property: &PlainProperty,
+    members: &Sequence<ClassLikeMember>,
+    class_like: Option<&ClassLikeReflection>,
+    context: &mut LintContext<'_>,
+) {
+    let [item] = property.items.as_slice() else {
+        return;
+    };
+
+    // Asymmetric visibility and hooks require the property to be typed.
+    if property.hint.is_none() || property.modifiers.contains_static() || property.modifiers.contains_private_set() {
+        return;
+    }
+
+    let visibility = match property.modifiers.get_first_read_visibility() {
+        Some(visibility @ (Modifier::Private(_) | Modifier::Protected(_))) => visibility,
+        _ => return,
+    };
+
+    let name = &context.lookup(&item.variable().name)[1..];
+    let Some(getter) = find_accessor(members, &["get", "is"], name, 0, class_like, context) else {
+        return;
+    };
+
+    if !is_trivial_getter(getter, name, context) {
+        return;
+    }
+
+    let is_readonly = property.modifiers.contains_readonly();
+    let setter = find_accessor(members, &["set"], name, 1, class_like, context);
+    let replacement = match setter {
+        None if is_readonly => Replacement::Public,
+        // The parser does not support `protected(set)`, only `private(set)`.
+        None if matches!(visibility, Modifier::Private(_)) => Replacement::AsymmetricVisibility,
+        Some(setter) if !is_readonly => match get_setter_statements(setter, name, context) {
+            Some([_]) => Replacement::Public,
+            Some(_) => Replacement::SetHook(setter),
+            None => return,
+        },
+        _ => return,
+    };
+
+    let getter_name = context.lookup(&getter.name.value);
+    let (message, help) = match (&replacement, setter) {
+        (Replacement::AsymmetricVisibility, _) => (
+            format!("Getter `{}()` can be replaced with asymmetric visibility.", getter_name),
+            format!(
+                "Declare the property as `public private(set)`, then replace the calls to `{}()` with property accesses, and remove it.",
+                getter_name
+            ),
+        ),
+        (Replacement::Public, None) => (
+            format!("Getter `{}()` can be replaced with a public readonly property.", getter_name),
+            format!(
+                "Declare the property as `public`, then replace the calls to `{}()` with property accesses, and remove it.",
+                getter_name
+            ),
+        ),
+        (Replacement::Public, Some(setter)) => (
+            format!(
+                "Getter `{}()` and setter `{}()` can be replaced with a public property.",
+                getter_name,
+                context.lookup(&setter.name.value)
+            ),
+            "Declare the property as `public`, then replace the calls to the getter and setter with property accesses, and remove them.".to_string(),
+        ),
+        (Replacement::SetHook(setter), _) => (
+            format!(
+                "Getter `{}()` and setter `{}()` can be replaced with a property hook.",
+                getter_name,
+                context.lookup(&setter.name.value)
+            ),
+            "Declare the property as `public`, move the body of the setter to a `set` hook, then replace the calls to the getter and setter with property accesses, and remove them.".to_string(),
+        ),
+    };
+
+    let mut issue = Issue::new(context.level(), message)
+        .with_annotation(
+            Annotation::primary(property.span()).with_message(format!("Property `${}` is declared here.", name)),
+        )
+        .with_annotation(Annotation::secondary(getter.span()).with_message("This getter only returns the property."));
+
+    if let Some(setter) = setter {
+        issue = issue
+            .with_annotation(Annotation::secondary(setter.span()).with_message("This setter assigns the property."));
+    }
+
+    let issue = issue
+        .with_note("Since PHP 8.4, asymmetric visibility and property hooks let properties be read publicly while controlling how they are written, making trivial getters and setters unnecessary.")
+        .with_help(help)
+        .with_link("https://www.php.net/manual/en/migration84.new-features.php");
+
+    let visibility_replacement = match replacement {
+        Replacement::AsymmetricVisibility => "public private(set)",
+        Replacement::Public => "public",
+        // A `set` hook would run the logic of the setter a second time when the setter is called.
+        Replacement::SetHook(_) => {
+            context.report(issue);
+
+            return;
+        }
+    };
+
+    // Only the visibility is changed, as the callers of the accessors are not updated; the accessors
+    // keep working, but widening the visibility conflicts with child classes redeclaring the property.
+    context.report_with_fix(issue, |plan| {
+        plan.replace(visibility.span().to_range(), visibility_replacement, SafetyClassification::PotentiallyUnsafe)
+    });
+}
+
+/// Finds the public, non-static, concrete accessor method of the given property, with the given prefixes and
+/// number of parameters.
+///
+/// Methods overriding or implementing a parent method are ignored, as they cannot be removed.
+fn find_accessor<'ast>(
+    members: &'ast Sequence<ClassLikeMember>,
+    prefixes: &[&str],
+    property_name: &str,
+    parameters: usize,
+    class_like: Option<&ClassLikeReflection>,
+    context: &LintContext<'_>,
+) -> Option<&'ast Method> {
+    let normalized_name = property_name.replace('_', "");
+
+    members.iter().find_map(|member| {
+        let ClassLikeMember::Method(method) = member else {
+            return None;
+        };
+
+        let method_name = context.lookup(&method.name.value);
+        let is_accessor = prefixes.iter().any(|prefix| {
+            method_name.len() == prefix.len() + normalized_name.len()
+                && method_name[..prefix.len()].eq_ignore_ascii_case(prefix)
+                && method_name[prefix.len()..].eq_ignore_ascii_case(&normalized_name)
+        });
+
+        if !is_accessor
+            || method.modifiers.contains_static()
+            || method
+                .modifiers
+                .get_first_visibility()
+                .is_some_and(|visibility| !matches!(visibility, Modifier::Public(_)))
+            || method.parameters.parameters.len() != parameters
+            || !matches!(method.body, MethodBody::Concrete(_))
+            || class_like
+                .is_some_and(|class_like| class_like.methods.overriden_members.contains_key(&method.name.value))
+        {
+            return None;
+        }
+
+        Some(method)
+    })
+}
+
+/// Determines whether the given getter only returns the given property, as in `return $this->name;`.
+fn is_trivial_getter(getter: &Method, property_name: &str, context: &LintContext<'_>) -> bool {
+    let MethodBody::Concrete(block) = &getter.body else {
+        return false;
+    };
+
+    let [Statement::Return(Return { value: Some(value), .. })] = block.statements.as_slice() else {
+        return false;
+    };
+
+    is_property_access(value, property_name, context)
+}
+
+/// Returns the statements of the given setter, if it ends by assigning its parameter to the given property,
+/// as in `$this->name = $name;`, and returns nothing.
+fn get_setter_statements<'ast>(
+    setter: &'ast Method,
+    property_name: &str,
+    context: &LintContext<'_>,
+) -> Option<&'ast [Statement]> {
+    let parameter = setter.parameters.parameters.first()?;
+    if parameter.ampersand.is_some()
+        || parameter.ellipsis.is_some()
+        || !parameter.modifiers.is_empty()
+        || parameter.hooks.is_some()
+    {
+        return None;
+    }
+
+    if setter.return_type_hint.as_ref().is_some_and(|hint| !matches!(hint.hint, Hint::Void(_))) {
+        return None;
+    }
+
+    let MethodBody::Concrete(block) = &setter.body else {
+        return None;
+    };
+
+    let statements = block.statements.as_slice();
+    let Some(Statement::Expression(ExpressionStatement {
+        expression: Expression::AssignmentOperation(assignment),
+        ..
+    })) = statements.last()
+    else {
+        return None;
+    };
+
+    let is_assignment = matches!(assignment.operator, AssignmentOperator::Assign(_))
+        && is_property_access(&assignment.lhs, property_name, context)
+        && matches!(assignment.rhs.as_ref(), Expression::Variable(Variable::Direct(variable)) if variable.name == parameter.variable.name);
+
+    // Setters returning a value, such as fluent setters, cannot be replaced.
+    let returns_value =
+        statements.iter().any(|statement| matches!(statement, Statement::Return(Return { value: Some(_), .. })));
+
+    (is_assignment && !returns_value).then_some(statements)
+}
+
+/// Determines whether the given expression accesses the given property of `$this`.
+fn is_property_access(expression: &Expression, property_name: &str, context: &LintContext<'_>) -> bool {
+    let Expression::Access(access) = expression else {
+        return false;
+    };
+
+    let Access::Property(PropertyAccess {
+        object: Expression::Variable(Variable::Direct(object)),
+        property: ClassLikeMemberSelector::Identifier(property),
+        ..
+    }) = access.as_ref()
+    else {
+        return false;
+    };
+
+    context.lookup(&object.name) == "$this" && context.lookup(&property.value) == property_name
+}
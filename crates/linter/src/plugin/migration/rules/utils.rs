@@ -0,0 +1,39 @@
+use std::ops::Range;
+
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+
+/// Returns the source code of the given node.
+pub fn get_source_text<'a>(node: &impl HasSpan, context: &LintContext<'a>) -> &'a str {
+    let span = node.span();
+
+    &context.interner.lookup(&context.semantics.source.content)[span.start.offset..span.end.offset]
+}
+
+/// Returns the indentation of the line containing the given offset.
+pub fn get_line_indentation(code: &str, offset: usize) -> String {
+    let line_start = code[..offset].rfind('\n').map(|position| position + 1).unwrap_or(0);
+
+    code[line_start..].chars().take_while(|character| *character == ' ' || *character == '\t').collect()
+}
+
+/// Returns the range to delete in order to remove the code between the given offsets.
+///
+/// If the code is the only content of its lines, the range covers the whole lines, including the
+/// indentation before the code and the line break after it.
+pub fn get_removal_range(code: &str, start: usize, end: usize) -> Range<usize> {
+    let line_start = code[..start].rfind('\n').map(|position| position + 1).unwrap_or(0);
+    if !code[line_start..start].trim().is_empty() {
+        return start..end;
+    }
+
+    let rest = &code[end..];
+    let trailing = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    match rest[trailing..].chars().next() {
+        Some('\n') => line_start..end + trailing + 1,
+        Some('\r') if rest[trailing..].starts_with("\r\n") => line_start..end + trailing + 2,
+        None => line_start..code.len(),
+        _ => start..end,
+    }
+}
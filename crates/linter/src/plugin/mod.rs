@@ -2,10 +2,12 @@ use std::fmt::Debug;
 
 use crate::rule::Rule;
 
+pub mod analysis;
 pub mod best_practices;
 pub mod comment;
 pub mod consistency;
 pub mod deprecation;
+pub mod doctrine;
 pub mod laravel;
 pub mod migration;
 pub mod naming;
@@ -14,14 +16,17 @@ pub mod redundancy;
 pub mod safety;
 pub mod strictness;
 pub mod symfony;
+pub mod taint;
 
 #[macro_export]
 macro_rules! foreach_plugin {
     ($do:expr) => {
+        $do($crate::plugin::analysis::AnalysisPlugin);
         $do($crate::plugin::best_practices::BestPracticesPlugin);
         $do($crate::plugin::comment::CommentPlugin);
         $do($crate::plugin::consistency::ConsistencyPlugin);
         $do($crate::plugin::deprecation::DeprecationPlugin);
+        $do($crate::plugin::doctrine::DoctrinePlugin);
         $do($crate::plugin::laravel::LaravelPlugin);
         $do($crate::plugin::migration::MigrationPlugin);
         $do($crate::plugin::naming::NamingPlugin);
@@ -30,6 +35,7 @@ macro_rules! foreach_plugin {
         $do($crate::plugin::safety::SafetyPlugin);
         $do($crate::plugin::strictness::StrictnessPlugin);
         $do($crate::plugin::symfony::SymfonyPlugin);
+        $do($crate::plugin::taint::TaintPlugin);
     };
 }
 
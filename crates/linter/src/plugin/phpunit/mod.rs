@@ -1,4 +1,9 @@
 use crate::plugin::phpunit::rules::consistency::assertions_style::AssertionsStyleRule;
+use crate::plugin::phpunit::rules::consistency::prefer_attributes::PreferAttributesRule;
+use crate::plugin::phpunit::rules::correctness::data_provider::DataProviderRule;
+use crate::plugin::phpunit::rules::correctness::final_class_mock::FinalClassMockRule;
+use crate::plugin::phpunit::rules::correctness::missing_assertions::MissingAssertionsRule;
+use crate::plugin::phpunit::rules::strictness::dedicated_assertions::DedicatedAssertionsRule;
 use crate::plugin::phpunit::rules::strictness::strict_assertions::StrictAssertionsRule;
 
 use crate::plugin::Plugin;
@@ -19,6 +24,14 @@ impl Plugin for PHPUnitPlugin {
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(AssertionsStyleRule), Box::new(StrictAssertionsRule)]
+        vec![
+            Box::new(AssertionsStyleRule),
+            Box::new(PreferAttributesRule),
+            Box::new(DataProviderRule),
+            Box::new(FinalClassMockRule),
+            Box::new(MissingAssertionsRule),
+            Box::new(DedicatedAssertionsRule),
+            Box::new(StrictAssertionsRule),
+        ]
     }
 }
@@ -0,0 +1,232 @@
+use std::ops::Range;
+
+use mago_ast::*;
+use mago_docblock::document::Document;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::phpunit::rules::utils::*;
+use crate::rule::Rule;
+
+const ATTRIBUTES_NAMESPACE: &str = "\\PHPUnit\\Framework\\Attributes\\";
+
+#[derive(Clone, Debug)]
+pub struct PreferAttributesRule;
+
+/// An annotation that can be replaced with an attribute.
+#[derive(Debug)]
+struct Migration<'a> {
+    tag: &'a Tag,
+    attribute: String,
+}
+
+impl Rule for PreferAttributesRule {
+    fn get_name(&self) -> &'static str {
+        "prefer-attributes"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PreferAttributesRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_declared_class_like(class, context) else {
+            return;
+        };
+
+        if !is_test_case(class_like, context) {
+            return;
+        }
+
+        check_annotations(class, true, context);
+
+        for member in class.members.iter() {
+            if let ClassLikeMember::Method(method) = member {
+                check_annotations(method, false, context);
+            }
+        }
+    }
+}
+
+fn check_annotations(node: &impl HasSpan, is_class: bool, context: &mut LintContext<'_>) {
+    let Some((docblock, document)) = get_docblock(node, context) else {
+        return;
+    };
+
+    let migrations = document
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            Element::Tag(tag) => {
+                get_attribute_for_tag(tag, is_class, context).map(|attribute| Migration { tag, attribute })
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if migrations.is_empty() {
+        return;
+    }
+
+    let mut issue = Issue::new(context.level(), "PHPUnit annotations are deprecated in favor of attributes.")
+        .with_note(
+            "Annotations in docblocks are deprecated since PHPUnit 11, and are no longer supported by PHPUnit 12.",
+        )
+        .with_help("Replace the annotations with their equivalent attributes.")
+        .with_link("https://docs.phpunit.de/en/11.0/attributes.html");
+
+    for (index, migration) in migrations.iter().enumerate() {
+        let annotation = if index == 0 {
+            Annotation::primary(migration.tag.span)
+        } else {
+            Annotation::secondary(migration.tag.span)
+        };
+
+        issue = issue.with_annotation(annotation.with_message(format!(
+            "Use `#[{}]` instead.",
+            migration.attribute.trim_start_matches(ATTRIBUTES_NAMESPACE)
+        )));
+    }
+
+    let code = context.lookup(&context.semantics.source.content);
+    let node_start = node.span().start.offset;
+    let indentation = get_line_indentation(code, node_start);
+    let attributes = migrations.iter().map(|migration| format!("#[{}]", migration.attribute)).collect::<Vec<_>>();
+
+    match get_removal(&document, &migrations, docblock.span, code) {
+        Removal::Docblock => context.report_with_fix(issue, |plan| {
+            plan.replace(
+                docblock.span.to_range(),
+                attributes.join(&format!("\n{}", indentation)),
+                SafetyClassification::PotentiallyUnsafe,
+            );
+        }),
+        Removal::Lines(ranges) => context.report_with_fix(issue, |plan| {
+            for range in ranges {
+                plan.delete(range, SafetyClassification::PotentiallyUnsafe);
+            }
+
+            let mut inserted = String::new();
+            for attribute in attributes {
+                inserted.push_str(&attribute);
+                inserted.push('\n');
+                inserted.push_str(&indentation);
+            }
+
+            plan.insert(node_start, inserted, SafetyClassification::PotentiallyUnsafe);
+        }),
+        Removal::Unsupported => context.report(issue),
+    }
+}
+
+/// How the migrated annotations are removed from their docblock.
+#[derive(Debug)]
+enum Removal {
+    /// Nothing but empty lines would remain in the docblock, so it is removed entirely.
+    Docblock,
+    /// The lines of the annotations, along with trailing empty lines, are removed.
+    Lines(Vec<Range<usize>>),
+    /// The annotations share their lines with other content, such as the opening of the docblock.
+    Unsupported,
+}
+
+fn get_removal(document: &Document, migrations: &[Migration<'_>], docblock: Span, code: &str) -> Removal {
+    let is_migrated = |element: &Element| match element {
+        Element::Tag(tag) => migrations.iter().any(|migration| std::ptr::eq(migration.tag, tag)),
+        _ => false,
+    };
+
+    let Some(last_kept) =
+        document.elements.iter().rposition(|element| !is_migrated(element) && !matches!(element, Element::Line(_)))
+    else {
+        return Removal::Docblock;
+    };
+
+    let mut ranges = vec![];
+    for (index, element) in document.elements.iter().enumerate() {
+        let span = match element {
+            Element::Tag(tag) if is_migrated(element) => tag.span,
+            // Empty lines left at the end of the docblock would only separate its content from nothing.
+            Element::Line(span) if index > last_kept => *span,
+            _ => continue,
+        };
+
+        let range = get_line_range(code, span.start.offset, span.end.offset);
+        if range.start <= docblock.start.offset || range.end >= docblock.end.offset {
+            return Removal::Unsupported;
+        }
+
+        ranges.push(range);
+    }
+
+    Removal::Lines(ranges)
+}
+
+/// Returns the attribute, without the surrounding `#[` and `]`, equivalent to the given annotation.
+fn get_attribute_for_tag(tag: &Tag, is_class: bool, context: &LintContext<'_>) -> Option<String> {
+    let name = context.lookup(&tag.name);
+    let description = context.lookup(&tag.description);
+    let mut words = description.split_whitespace();
+    let argument = words.next();
+
+    // Annotations followed by anything else than their argument cannot be migrated as-is.
+    if words.next().is_some() {
+        return None;
+    }
+
+    let (attribute, arguments) = match (name, argument, is_class) {
+        ("test", None, false) => ("Test", String::new()),
+        ("doesNotPerformAssertions", None, _) => ("DoesNotPerformAssertions", String::new()),
+        ("coversNothing", None, _) => ("CoversNothing", String::new()),
+        ("group", Some(group), _) => ("Group", quote(group)),
+        ("depends", Some(method), false) if is_method_name(method) => ("Depends", quote(method.trim_end_matches("()"))),
+        ("dataProvider", Some(method), false) => match method.split_once("::") {
+            None if is_method_name(method) => ("DataProvider", quote(method.trim_end_matches("()"))),
+            Some((class, method)) if is_class_name(class) && is_method_name(method) => {
+                ("DataProviderExternal", format!("{}::class, {}", class, quote(method.trim_end_matches("()"))))
+            }
+            _ => return None,
+        },
+        ("covers", Some(target), true) => match target.strip_prefix("::") {
+            Some(function) if is_method_name(function) => ("CoversFunction", quote(function.trim_end_matches("()"))),
+            None if is_class_name(target) => ("CoversClass", format!("{}::class", target)),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if arguments.is_empty() {
+        Some(format!("{}{}", ATTRIBUTES_NAMESPACE, attribute))
+    } else {
+        Some(format!("{}{}({})", ATTRIBUTES_NAMESPACE, attribute, arguments))
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn is_method_name(name: &str) -> bool {
+    is_identifier(name.trim_end_matches("()"))
+}
+
+/// Determines whether the given name is a class name whose meaning does not change when moved from
+/// the docblock to an attribute, as both are resolved against the imports of the file.
+fn is_class_name(name: &str) -> bool {
+    let name = name.strip_prefix('\\').unwrap_or(name);
+
+    name.split('\\').all(is_identifier)
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
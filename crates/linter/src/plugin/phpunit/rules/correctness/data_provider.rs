@@ -0,0 +1,281 @@
+use mago_ast::*;
+use mago_ast_utils::find_returns_in_block;
+use mago_fixer::SafetyClassification;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::literal::DecodedString;
+use crate::plugin::phpunit::rules::utils::*;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DataProviderRule;
+
+/// A data provider referenced by a test, either using the `#[DataProvider]` attribute, or the
+/// `@dataProvider` annotation.
+#[derive(Debug)]
+struct DataProviderReference {
+    name: String,
+    span: Span,
+}
+
+impl Rule for DataProviderRule {
+    fn get_name(&self) -> &'static str {
+        "data-provider"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DataProviderRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_declared_class_like(class, context) else {
+            return;
+        };
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(test) = member else {
+                continue;
+            };
+
+            for reference in get_data_provider_references(test, context) {
+                check_data_provider(class, class_like, test, &reference, context);
+            }
+        }
+    }
+}
+
+fn check_data_provider(
+    class: &Class,
+    class_like: &ClassLikeReflection,
+    test: &Method,
+    reference: &DataProviderReference,
+    context: &mut LintContext<'_>,
+) {
+    let test_name = context.lookup(&test.name.value).to_string();
+    let provider_name = context.interner.intern(&reference.name);
+
+    let Some(provider) = context.codebase.get_method(class_like, &provider_name) else {
+        let issue = Issue::new(
+            context.level(),
+            format!("Data provider `{}()` of test `{}()` does not exist.", reference.name, test_name),
+        )
+        .with_annotation(Annotation::primary(reference.span).with_message("The data provider is referenced here."))
+        .with_help(format!("Define a `public static function {}(): iterable` method.", reference.name));
+
+        context.report(issue);
+
+        return;
+    };
+
+    // The declaration of the provider, if it is declared in this class rather than inherited.
+    let declaration = class.members.iter().find_map(|member| match member {
+        ClassLikeMember::Method(method) if context.lookup(&method.name.value).eq_ignore_ascii_case(&reference.name) => {
+            Some(method)
+        }
+        _ => None,
+    });
+
+    let is_public = provider.visibility_reflection.is_none_or(|visibility| visibility.is_public());
+    if !is_public || !provider.is_static {
+        let issue = Issue::new(
+            context.level(),
+            format!("Data provider `{}()` of test `{}()` must be public and static.", reference.name, test_name),
+        )
+        .with_annotation(Annotation::primary(provider.span).with_message("The data provider is declared here."))
+        .with_annotation(Annotation::secondary(reference.span).with_message("The data provider is referenced here."))
+        .with_note(
+            "Non-static data providers are deprecated since PHPUnit 10, and are no longer supported by PHPUnit 11.",
+        )
+        .with_help("Declare the data provider as `public static`.");
+
+        match declaration {
+            Some(declaration) => context.report_with_fix(issue, |plan| {
+                let visibility = declaration.modifiers.get_first_visibility();
+                if let Some(visibility) = visibility.filter(|_| !is_public) {
+                    plan.replace(visibility.span().to_range(), "public", SafetyClassification::Safe);
+                }
+
+                let mut prefix = String::new();
+                if visibility.is_none() {
+                    prefix.push_str("public ");
+                }
+
+                // Static methods cannot use `$this`, so the provider may need further changes.
+                let mut safety = SafetyClassification::Safe;
+                if !provider.is_static {
+                    prefix.push_str("static ");
+                    safety = SafetyClassification::PotentiallyUnsafe;
+                }
+
+                if !prefix.is_empty() {
+                    plan.insert(declaration.function.span.start.offset, prefix, safety);
+                }
+            }),
+            None => context.report(issue),
+        }
+    }
+
+    let Some(declaration) = declaration else {
+        return;
+    };
+
+    let parameters = &test.parameters.parameters;
+    let is_variadic = parameters.iter().any(|parameter| parameter.ellipsis.is_some());
+    let maximum = parameters.iter().filter(|parameter| parameter.ellipsis.is_none()).count();
+    let minimum =
+        parameters.iter().filter(|parameter| parameter.ellipsis.is_none() && parameter.default_value.is_none()).count();
+
+    for data_set in get_data_sets(declaration) {
+        let elements = match data_set {
+            Expression::Array(array) => &array.elements,
+            Expression::LegacyArray(array) => &array.elements,
+            Expression::Literal(_) => {
+                let issue = Issue::new(
+                    context.level(),
+                    format!("Data provider `{}()` provides a data set that is not an array.", reference.name),
+                )
+                .with_annotation(Annotation::primary(data_set.span()).with_message("This data set is not an array."))
+                .with_annotation(
+                    Annotation::secondary(reference.span).with_message("The data provider is referenced here."),
+                )
+                .with_help(format!("Wrap the value in an array, with one element per parameter of `{}()`.", test_name));
+
+                context.report(issue);
+
+                continue;
+            }
+            _ => continue,
+        };
+
+        // The number of arguments passed by spreading is not known.
+        if elements.iter().any(|element| matches!(element, ArrayElement::Variadic(_))) {
+            continue;
+        }
+
+        let count = elements.len();
+        if count >= minimum && (count <= maximum || is_variadic) {
+            continue;
+        }
+
+        let expected = if is_variadic {
+            format!("at least {}", minimum)
+        } else if minimum == maximum {
+            minimum.to_string()
+        } else {
+            format!("between {} and {}", minimum, maximum)
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Data set provided by `{}()` has {} value(s), but test `{}()` expects {}.",
+                reference.name, count, test_name, expected
+            ),
+        )
+        .with_annotation(
+            Annotation::primary(data_set.span()).with_message(format!("This data set has {} value(s).", count)),
+        )
+        .with_annotation(
+            Annotation::secondary(test.parameters.span())
+                .with_message(format!("`{}()` expects {} argument(s).", test_name, expected)),
+        )
+        .with_help("Make the data set match the parameters of the test.");
+
+        context.report(issue);
+    }
+}
+
+/// Returns the data providers referenced by the given test, within the same class.
+fn get_data_provider_references(test: &Method, context: &LintContext<'_>) -> Vec<DataProviderReference> {
+    let mut references = vec![];
+
+    for attribute in test.attributes.iter().flat_map(|attribute_list| attribute_list.attributes.iter()) {
+        if !context.lookup_name(&attribute.name).eq_ignore_ascii_case(DATA_PROVIDER_ATTRIBUTE) {
+            continue;
+        }
+
+        let Some(arguments) = &attribute.arguments else {
+            continue;
+        };
+
+        let argument = arguments.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+            Argument::Positional(argument) if index == 0 => Some(&argument.value),
+            Argument::Named(argument) if context.lookup(&argument.name.value) == "methodName" => Some(&argument.value),
+            _ => None,
+        });
+
+        let Some(Expression::Literal(Literal::String(literal))) = argument else {
+            continue;
+        };
+
+        let Some(decoded) = DecodedString::decode(literal, context) else {
+            continue;
+        };
+
+        references.push(DataProviderReference {
+            name: String::from_utf8_lossy(&decoded.value).into_owned(),
+            span: attribute.span(),
+        });
+    }
+
+    if let Some((_, document)) = get_docblock(test, context) {
+        for tag in get_tags(&document, "dataProvider", context) {
+            let name = context.lookup(&tag.description).split_whitespace().next().unwrap_or_default();
+
+            // Data providers declared in other classes are not checked.
+            if name.is_empty() || name.contains("::") {
+                continue;
+            }
+
+            references.push(DataProviderReference { name: name.trim_end_matches("()").to_string(), span: tag.span });
+        }
+    }
+
+    references
+}
+
+/// Returns the data sets provided by the given data provider, when they are written as array literals
+/// returned by the provider, or yielded by its top-level statements.
+fn get_data_sets(provider: &Method) -> Vec<&Expression> {
+    let MethodBody::Concrete(block) = &provider.body else {
+        return vec![];
+    };
+
+    let mut data_sets = vec![];
+    for r#return in find_returns_in_block(block) {
+        let elements = match &r#return.value {
+            Some(Expression::Array(array)) => &array.elements,
+            Some(Expression::LegacyArray(array)) => &array.elements,
+            _ => continue,
+        };
+
+        for element in elements.iter() {
+            match element {
+                ArrayElement::KeyValue(element) => data_sets.push(element.value.as_ref()),
+                ArrayElement::Value(element) => data_sets.push(element.value.as_ref()),
+                _ => {}
+            }
+        }
+    }
+
+    for statement in block.statements.iter() {
+        let Statement::Expression(ExpressionStatement { expression: Expression::Yield(r#yield), .. }) = statement
+        else {
+            continue;
+        };
+
+        match r#yield.as_ref() {
+            Yield::Value(YieldValue { value: Some(value), .. }) => data_sets.push(value),
+            Yield::Pair(YieldPair { value, .. }) => data_sets.push(value),
+            _ => {}
+        }
+    }
+
+    data_sets
+}
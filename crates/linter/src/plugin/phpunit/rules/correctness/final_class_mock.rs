@@ -0,0 +1,90 @@
+use mago_ast::*;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// The methods of `PHPUnit\Framework\TestCase` creating test doubles of the class passed as their first argument.
+const MOCKING_METHODS: [&str; 6] =
+    ["createMock", "createStub", "createPartialMock", "createConfiguredMock", "createTestProxy", "getMockBuilder"];
+
+#[derive(Clone, Debug)]
+pub struct FinalClassMockRule;
+
+impl Rule for FinalClassMockRule {
+    fn get_name(&self) -> &'static str {
+        "final-class-mock"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for FinalClassMockRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Expression::Variable(Variable::Direct(variable)) = method_call.object.as_ref() else {
+            return;
+        };
+
+        if context.lookup(&variable.name) != "$this" {
+            return;
+        }
+
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return;
+        };
+
+        let method_name = context.lookup(&method.value);
+        if !MOCKING_METHODS.iter().any(|mocking_method| mocking_method.eq_ignore_ascii_case(method_name)) {
+            return;
+        }
+
+        let Some(Argument::Positional(argument)) = method_call.arguments.arguments.first() else {
+            return;
+        };
+
+        let TypeKind::Scalar(ScalarTypeKind::ClassString(Some(class_name))) = context.resolve_kind(&argument.value)
+        else {
+            return;
+        };
+
+        let Some(class_like) = context.codebase.get_named_class_like(&class_name) else {
+            return;
+        };
+
+        let kind = if class_like.is_enum() {
+            "an enum"
+        } else if class_like.is_class() && class_like.is_final {
+            "a final class"
+        } else {
+            return;
+        };
+
+        let method_name = method_name.to_string();
+        let class_name = context.lookup(&class_name).to_string();
+        let mut issue =
+            Issue::new(context.level(), format!("Cannot create a test double of {} `{}`.", kind, class_name))
+                .with_annotation(
+                    Annotation::primary(argument.value.span()).with_message(format!("`{}` is {}.", class_name, kind)),
+                );
+
+        if class_like.span.start.source.is_user_defined() {
+            issue = issue.with_annotation(
+                Annotation::secondary(class_like.span).with_message(format!("`{}` is declared here.", class_name)),
+            );
+        }
+
+        let issue = issue
+            .with_note(format!(
+                "`{}()` creates a subclass of the given class, which is not possible for {}.",
+                method_name, kind
+            ))
+            .with_help("Use a real instance, or create the test double from an interface implemented by the class.");
+
+        context.report(issue);
+    }
+}
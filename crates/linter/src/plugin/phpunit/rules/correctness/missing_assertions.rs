@@ -0,0 +1,112 @@
+use mago_ast::*;
+use mago_ast_utils::reference::find_method_references_in_block;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::phpunit::rules::utils::*;
+use crate::rule::Rule;
+
+/// Methods that end a test, or mark it as skipped or incomplete, which counts as an outcome on its own.
+const OUTCOME_METHODS: [&str; 4] = ["fail", "markTestIncomplete", "markTestSkipped", "addToAssertionCount"];
+
+#[derive(Clone, Debug)]
+pub struct MissingAssertionsRule;
+
+impl Rule for MissingAssertionsRule {
+    fn get_name(&self) -> &'static str {
+        "missing-assertions"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MissingAssertionsRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(class_like) = get_declared_class_like(class, context) else {
+            return;
+        };
+
+        if !is_test_case(class_like, context) {
+            return;
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBody::Concrete(block) = &method.body else {
+                continue;
+            };
+
+            if !is_test_method(method, context) || !is_missing_assertions(class_like, method, block, context) {
+                continue;
+            }
+
+            let name = context.lookup(&method.name.value);
+            let issue = Issue::new(context.level(), format!("Test `{}()` does not perform any assertions.", name))
+                .with_annotation(Annotation::primary(method.name.span).with_message("This test asserts nothing."))
+                .with_note("PHPUnit reports tests without assertions as risky.")
+                .with_help(
+                    "Add assertions to the test, or mark it with `#[DoesNotPerformAssertions]` if it only \
+                     verifies that no exception is thrown.",
+                );
+
+            context.report(issue);
+        }
+    }
+}
+
+fn is_missing_assertions(
+    class_like: &ClassLikeReflection,
+    method: &Method,
+    block: &Block,
+    context: &LintContext<'_>,
+) -> bool {
+    if get_attribute(&method.attributes, DOES_NOT_PERFORM_ASSERTIONS_ATTRIBUTE, context).is_some() {
+        return false;
+    }
+
+    if get_docblock(method, context)
+        .is_some_and(|(_, document)| get_tags(&document, "doesNotPerformAssertions", context).next().is_some())
+    {
+        return false;
+    }
+
+    let references = find_method_references_in_block(block, &|reference| {
+        let ClassLikeMemberSelector::Identifier(identifier) = reference.get_selector() else {
+            // A dynamic call may perform assertions.
+            return true;
+        };
+
+        let name = context.lookup(&identifier.value);
+        let lowercase_name = name.to_ascii_lowercase();
+
+        // Assertions, expectations such as `expectException()` or `$mock->expects()`, and outcomes.
+        if lowercase_name.starts_with("assert")
+            || lowercase_name.starts_with("expect")
+            || OUTCOME_METHODS.iter().any(|outcome| outcome.eq_ignore_ascii_case(name))
+        {
+            return true;
+        }
+
+        // Helpers declared by the test case itself may perform assertions.
+        let is_own_helper = match reference.get_class_or_object() {
+            Expression::Variable(Variable::Direct(variable)) => context.lookup(&variable.name) == "$this",
+            Expression::Static(_) | Expression::Self_(_) => true,
+            _ => false,
+        };
+
+        is_own_helper
+            && context
+                .codebase
+                .get_method(class_like, &identifier.value)
+                .is_some_and(|helper| helper.span.start.source.is_user_defined())
+    });
+
+    references.is_empty()
+}
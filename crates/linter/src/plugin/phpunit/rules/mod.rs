@@ -2,8 +2,16 @@ mod utils;
 
 pub mod consistency {
     pub mod assertions_style;
+    pub mod prefer_attributes;
+}
+
+pub mod correctness {
+    pub mod data_provider;
+    pub mod final_class_mock;
+    pub mod missing_assertions;
 }
 
 pub mod strictness {
+    pub mod dedicated_assertions;
     pub mod strict_assertions;
 }
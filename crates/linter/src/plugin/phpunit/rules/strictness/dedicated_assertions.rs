@@ -0,0 +1,117 @@
+use mago_ast::*;
+use mago_ast_utils::reference::MethodReference;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::phpunit::rules::utils::*;
+use crate::rule::Rule;
+
+/// A PHPUnit rule that enforces the use of dedicated assertions when comparing with booleans or `null`.
+#[derive(Clone, Debug)]
+pub struct DedicatedAssertionsRule;
+
+impl Rule for DedicatedAssertionsRule {
+    fn get_name(&self) -> &'static str {
+        "dedicated-assertions"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DedicatedAssertionsRule {
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        if !is_test_method(method, context) {
+            return;
+        }
+
+        for reference in find_assertion_references_in_method(method, context) {
+            let (selector, arguments) = match reference {
+                MethodReference::MethodCall(call) => (&call.method, &call.arguments),
+                MethodReference::StaticMethodCall(call) => (&call.method, &call.arguments),
+                _ => continue,
+            };
+
+            let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+                continue;
+            };
+
+            let name = context.lookup(&identifier.value);
+            let is_negated = match name {
+                "assertEquals" => false,
+                "assertNotEquals" => true,
+                _ => continue,
+            };
+
+            // Named arguments may be passed in any order, and are left untouched.
+            let Some(values) = arguments
+                .arguments
+                .iter()
+                .map(|argument| match argument {
+                    Argument::Positional(argument) if argument.ellipsis.is_none() => Some(&argument.value),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            let (constant_position, constant) = match values.as_slice() {
+                [Expression::Literal(constant @ (Literal::True(_) | Literal::False(_) | Literal::Null(_))), _, ..] => {
+                    (0, constant)
+                }
+                [_, Expression::Literal(constant @ (Literal::True(_) | Literal::False(_) | Literal::Null(_))), ..] => {
+                    (1, constant)
+                }
+                _ => continue,
+            };
+
+            let (constant_name, dedicated_name) = match (constant, is_negated) {
+                (Literal::True(_), false) => ("true", "assertTrue"),
+                (Literal::False(_), false) => ("false", "assertFalse"),
+                (Literal::Null(_), false) => ("null", "assertNull"),
+                (Literal::True(_), true) => ("true", "assertNotTrue"),
+                (Literal::False(_), true) => ("false", "assertNotFalse"),
+                (_, _) => ("null", "assertNotNull"),
+            };
+
+            let name = name.to_string();
+            let message = format!("Use `{}()` instead of `{}()` with `{}`.", dedicated_name, name, constant_name);
+            let issue = Issue::new(context.level(), message)
+                .with_annotation(
+                    Annotation::primary(reference.span())
+                        .with_message(format!("`{}()` compares loosely with `{}` here.", name, constant_name)),
+                )
+                .with_note(format!(
+                    "`{}()` compares using `==`, so values such as `0`, `''`, or `[]` are considered equal to `false` \
+                     or `null`.",
+                    name
+                ))
+                .with_help(format!("Replace this assertion with `{}()`.", dedicated_name));
+
+            let code = context.lookup(&context.semantics.source.content);
+            let replacement = format!(
+                "{}({})",
+                dedicated_name,
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|(position, _)| *position != constant_position)
+                    .map(|(_, value)| &code[value.span().to_range()])
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+
+            // Replace the whole call at once, so that it does not conflict with other fixes of the assertion.
+            let range = identifier.span.join(arguments.right_parenthesis).to_range();
+
+            context.report_with_fix(issue, |plan| {
+                plan.replace(range, replacement, SafetyClassification::PotentiallyUnsafe);
+            });
+        }
+    }
+}
@@ -1,8 +1,20 @@
+use std::ops::Range;
+
 use mago_ast::*;
 use mago_ast_utils::reference::*;
+use mago_docblock::document::Document;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_span::HasSpan;
 
 use crate::context::LintContext;
 
+pub const TEST_CASE: &str = "PHPUnit\\Framework\\TestCase";
+pub const TEST_ATTRIBUTE: &str = "PHPUnit\\Framework\\Attributes\\Test";
+pub const DATA_PROVIDER_ATTRIBUTE: &str = "PHPUnit\\Framework\\Attributes\\DataProvider";
+pub const DOES_NOT_PERFORM_ASSERTIONS_ATTRIBUTE: &str = "PHPUnit\\Framework\\Attributes\\DoesNotPerformAssertions";
+
 pub const TESTING_METHODS: [&str; 57] = [
     "anything",
     "arrayHasKey",
@@ -296,3 +308,87 @@ pub fn find_testing_or_assertion_references_in_method<'a>(
         ASSERTION_METHODS.contains(&name) || TESTING_METHODS.contains(&name)
     })
 }
+
+/// Returns the reflection of the given class.
+///
+/// Another class with the same name may be defined elsewhere, in which case the reflection may not be the one
+/// of the given class, so `None` is returned.
+pub fn get_declared_class_like<'a>(class: &Class, context: &LintContext<'a>) -> Option<&'a ClassLikeReflection> {
+    let class_like = context.codebase.get_named_class_like(context.semantics.names.get(&class.name))?;
+
+    (class_like.span == class.span()).then_some(class_like)
+}
+
+/// Determines whether the given class-like is a test case.
+///
+/// Besides `PHPUnit\Framework\TestCase`, classes extending any class whose name ends with `TestCase` are
+/// considered test cases, as frameworks commonly provide their own, such as Symfony's `KernelTestCase`.
+pub fn is_test_case(class_like: &ClassLikeReflection, context: &LintContext<'_>) -> bool {
+    class_like.inheritance.all_extended_classes.iter().any(|parent| {
+        let name = context.lookup(&parent.value);
+
+        name.eq_ignore_ascii_case(TEST_CASE) || name.to_ascii_lowercase().ends_with("testcase")
+    })
+}
+
+/// Determines whether the given method is a test, either by its name, by the `#[Test]` attribute,
+/// or by the `@test` annotation.
+pub fn is_test_method(method: &Method, context: &LintContext<'_>) -> bool {
+    let name = context.lookup(&method.name.value);
+    if name.starts_with("test") && name.chars().nth(4).is_some_and(|c| c == '_' || c.is_uppercase()) {
+        return true;
+    }
+
+    if get_attribute(&method.attributes, TEST_ATTRIBUTE, context).is_some() {
+        return true;
+    }
+
+    get_docblock(method, context).is_some_and(|(_, document)| get_tags(&document, "test", context).next().is_some())
+}
+
+/// Returns the attribute with the given fully qualified name, if it is present.
+pub fn get_attribute<'a>(
+    attribute_lists: &'a Sequence<AttributeList>,
+    name: &str,
+    context: &LintContext<'_>,
+) -> Option<&'a Attribute> {
+    attribute_lists
+        .iter()
+        .flat_map(|attribute_list| attribute_list.attributes.iter())
+        .find(|attribute| context.lookup_name(&attribute.name).eq_ignore_ascii_case(name))
+}
+
+/// Returns the docblock attached to the given node, along with its parsed document.
+pub fn get_docblock<'a>(node: &impl HasSpan, context: &LintContext<'a>) -> Option<(&'a Trivia, Document)> {
+    let trivia = mago_docblock::get_docblock_for_node(&context.semantics.program.trivia, node)?;
+    let document = mago_docblock::parse_trivia(context.interner, trivia).ok()?;
+
+    Some((trivia, document))
+}
+
+/// Returns the tags of the given document with the given name, without the leading `@`.
+pub fn get_tags<'a>(
+    document: &'a Document,
+    name: &'a str,
+    context: &'a LintContext<'_>,
+) -> impl Iterator<Item = &'a Tag> + 'a {
+    document.elements.iter().filter_map(move |element| match element {
+        Element::Tag(tag) if context.lookup(&tag.name) == name => Some(tag),
+        _ => None,
+    })
+}
+
+/// Returns the range covering the lines between the given offsets, including their line break.
+pub fn get_line_range(code: &str, start: usize, end: usize) -> Range<usize> {
+    let line_start = code[..start].rfind('\n').map(|position| position + 1).unwrap_or(0);
+    let line_end = code[end..].find('\n').map(|position| end + position + 1).unwrap_or(code.len());
+
+    line_start..line_end
+}
+
+/// Returns the indentation of the line containing the given offset.
+pub fn get_line_indentation(code: &str, offset: usize) -> String {
+    let line_start = code[..offset].rfind('\n').map(|position| position + 1).unwrap_or(0);
+
+    code[line_start..].chars().take_while(|character| *character == ' ' || *character == '\t').collect()
+}
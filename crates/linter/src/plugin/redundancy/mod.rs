@@ -1,3 +1,4 @@
+use crate::plugin::redundancy::rules::duplicate_import::DuplicateImportRule;
 use crate::plugin::redundancy::rules::redundant_block::RedundantBlockRule;
 use crate::plugin::redundancy::rules::redundant_closing_tag::RedudnantClosingTagRule;
 use crate::plugin::redundancy::rules::redundant_continue::RedundantContinueRule;
@@ -8,6 +9,7 @@ use crate::plugin::redundancy::rules::redundant_method_override::RedundantMethod
 use crate::plugin::redundancy::rules::redundant_noop::RedundantNoopRule;
 use crate::plugin::redundancy::rules::redundant_parentheses::RedundantParenthesesRule;
 use crate::plugin::redundancy::rules::redundant_string_concat::RedundantStringConcatRule;
+use crate::plugin::redundancy::rules::unused_import::UnusedImportRule;
 use crate::plugin::Plugin;
 use crate::rule::Rule;
 
@@ -37,6 +39,8 @@ impl Plugin for RedundancyPlugin {
             Box::new(RedundantFinalMethodModifierRule),
             Box::new(RedundantLabelRule),
             Box::new(RedundantIfStatementRule),
+            Box::new(UnusedImportRule),
+            Box::new(DuplicateImportRule),
         ]
     }
 }
@@ -0,0 +1,62 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::import::get_import_blocks;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DuplicateImportRule;
+
+impl Rule for DuplicateImportRule {
+    fn get_name(&self) -> &'static str {
+        "duplicate-import"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DuplicateImportRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        for block in get_import_blocks(program, context) {
+            let duplicates = block.get_duplicate_imports();
+            if duplicates.is_empty() {
+                continue;
+            }
+
+            let mut issue = Issue::new(
+                context.level(),
+                if duplicates.len() == 1 {
+                    format!("Duplicate import `{}`.", duplicates[0].1.name)
+                } else {
+                    format!("{} duplicate imports.", duplicates.len())
+                },
+            );
+
+            for (original, duplicate) in &duplicates {
+                issue = issue
+                    .with_annotation(
+                        Annotation::primary(duplicate.span)
+                            .with_message(format!("`{}` is imported again here.", duplicate.name)),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(original.span)
+                            .with_message(format!("`{}` is first imported here.", original.name)),
+                    );
+            }
+
+            let issue = issue.with_help("Remove the duplicate imports.");
+
+            match block.rewrite(context) {
+                Some((range, rendered)) => {
+                    context.report_with_fix(issue, |plan| plan.replace(range, rendered, SafetyClassification::Safe));
+                }
+                None => context.report(issue),
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod duplicate_import;
 pub mod redundant_block;
 pub mod redundant_closing_tag;
 pub mod redundant_continue;
@@ -8,3 +9,4 @@ pub mod redundant_method_override;
 pub mod redundant_noop;
 pub mod redundant_parentheses;
 pub mod redundant_string_concat;
+pub mod unused_import;
@@ -0,0 +1,68 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::import::get_import_blocks;
+use crate::import::ImportKind;
+use crate::import::ImportUsage;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnusedImportRule;
+
+impl Rule for UnusedImportRule {
+    fn get_name(&self) -> &'static str {
+        "unused-import"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnusedImportRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        for block in get_import_blocks(program, context) {
+            let usage = ImportUsage::collect(context, block.scope);
+            let unused = block.imports.iter().filter(|import| !usage.is_used(import)).collect::<Vec<_>>();
+            if unused.is_empty() {
+                continue;
+            }
+
+            let mut issue = Issue::new(
+                context.level(),
+                if unused.len() == 1 {
+                    format!("Unused import `{}`.", unused[0].name)
+                } else {
+                    format!("{} unused imports.", unused.len())
+                },
+            );
+
+            for import in &unused {
+                let kind = match import.kind {
+                    ImportKind::ClassLike => "class-like",
+                    ImportKind::Function => "function",
+                    ImportKind::Constant => "constant",
+                };
+
+                issue = issue.with_annotation(
+                    Annotation::primary(import.span)
+                        .with_message(format!("The {} `{}` is imported but never used.", kind, import.name)),
+                );
+            }
+
+            let issue = issue
+                .with_note("Names within docblocks are considered as usages of the imported class-likes.")
+                .with_help("Remove the unused imports.");
+
+            match block.rewrite(context) {
+                Some((range, rendered)) => {
+                    context.report_with_fix(issue, |plan| plan.replace(range, rendered, SafetyClassification::Safe));
+                }
+                None => context.report(issue),
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::plugin::taint::rules::command_injection::CommandInjectionRule;
+use crate::plugin::taint::rules::cross_site_scripting::CrossSiteScriptingRule;
+use crate::plugin::taint::rules::path_traversal::PathTraversalRule;
+use crate::plugin::taint::rules::sql_injection::SqlInjectionRule;
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rules;
+
+#[derive(Debug)]
+pub struct TaintPlugin;
+
+impl Plugin for TaintPlugin {
+    fn get_name(&self) -> &'static str {
+        "taint"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(SqlInjectionRule),
+            Box::new(CrossSiteScriptingRule),
+            Box::new(CommandInjectionRule),
+            Box::new(PathTraversalRule),
+        ]
+    }
+}
@@ -0,0 +1,48 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::taint::rules::utils::analyze;
+use crate::plugin::taint::rules::utils::Sink;
+use crate::plugin::taint::rules::utils::TaintConfiguration;
+use crate::rule::Rule;
+
+const SINKS: &[Sink] = &[
+    ("exec", Some(0)),
+    ("passthru", Some(0)),
+    ("pcntl_exec", None),
+    ("popen", Some(0)),
+    ("proc_open", Some(0)),
+    ("shell_exec", Some(0)),
+    ("system", Some(0)),
+];
+
+const SANITIZERS: &[&str] = &["escapeshellarg", "escapeshellcmd"];
+
+#[derive(Clone, Debug)]
+pub struct CommandInjectionRule;
+
+impl Rule for CommandInjectionRule {
+    fn get_name(&self) -> &'static str {
+        "command-injection"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for CommandInjectionRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let configuration = TaintConfiguration::new(
+            context,
+            "command injection",
+            "Escape arguments using `escapeshellarg()`, or avoid passing user input to shell commands.",
+            SINKS,
+            SANITIZERS,
+        );
+
+        analyze(program, &configuration, context);
+    }
+}
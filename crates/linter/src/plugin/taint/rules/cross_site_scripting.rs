@@ -0,0 +1,48 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::taint::rules::utils::analyze;
+use crate::plugin::taint::rules::utils::Sink;
+use crate::plugin::taint::rules::utils::TaintConfiguration;
+use crate::rule::Rule;
+
+const SINKS: &[Sink] = &[
+    ("echo", None),
+    ("print", None),
+    ("exit", None),
+    ("die", None),
+    ("printf", None),
+    ("vprintf", None),
+    ("print_r", Some(0)),
+];
+
+const SANITIZERS: &[&str] = &["htmlentities", "htmlspecialchars", "rawurlencode", "strip_tags", "urlencode"];
+
+#[derive(Clone, Debug)]
+pub struct CrossSiteScriptingRule;
+
+impl Rule for CrossSiteScriptingRule {
+    fn get_name(&self) -> &'static str {
+        "cross-site-scripting"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for CrossSiteScriptingRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let configuration = TaintConfiguration::new(
+            context,
+            "cross-site scripting",
+            "Escape user input for the context it is output in, e.g. using `htmlspecialchars()` for HTML.",
+            SINKS,
+            SANITIZERS,
+        );
+
+        analyze(program, &configuration, context);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod command_injection;
+pub mod cross_site_scripting;
+pub mod path_traversal;
+pub mod sql_injection;
+pub mod utils;
@@ -0,0 +1,59 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::taint::rules::utils::analyze;
+use crate::plugin::taint::rules::utils::Sink;
+use crate::plugin::taint::rules::utils::TaintConfiguration;
+use crate::rule::Rule;
+
+const SINKS: &[Sink] = &[
+    ("include", None),
+    ("include_once", None),
+    ("require", None),
+    ("require_once", None),
+    ("copy", None),
+    ("file", Some(0)),
+    ("file_get_contents", Some(0)),
+    ("file_put_contents", Some(0)),
+    ("fopen", Some(0)),
+    ("mkdir", Some(0)),
+    ("move_uploaded_file", Some(1)),
+    ("opendir", Some(0)),
+    ("parse_ini_file", Some(0)),
+    ("readfile", Some(0)),
+    ("rename", None),
+    ("rmdir", Some(0)),
+    ("scandir", Some(0)),
+    ("unlink", Some(0)),
+];
+
+const SANITIZERS: &[&str] = &["basename"];
+
+#[derive(Clone, Debug)]
+pub struct PathTraversalRule;
+
+impl Rule for PathTraversalRule {
+    fn get_name(&self) -> &'static str {
+        "path-traversal"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PathTraversalRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let configuration = TaintConfiguration::new(
+            context,
+            "path traversal",
+            "Restrict paths built from user input, e.g. using `basename()`, or validate them against a list of allowed paths.",
+            SINKS,
+            SANITIZERS,
+        );
+
+        analyze(program, &configuration, context);
+    }
+}
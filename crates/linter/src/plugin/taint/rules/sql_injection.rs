@@ -0,0 +1,74 @@
+use mago_ast::*;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::taint::rules::utils::analyze;
+use crate::plugin::taint::rules::utils::Sink;
+use crate::plugin::taint::rules::utils::TaintConfiguration;
+use crate::rule::Rule;
+
+const SINKS: &[Sink] = &[
+    ("mysql_query", Some(0)),
+    ("mysqli_query", Some(1)),
+    ("mysqli_real_query", Some(1)),
+    ("mysqli_multi_query", Some(1)),
+    ("mysqli_prepare", Some(1)),
+    ("pg_query", None),
+    ("pg_send_query", Some(1)),
+    ("pg_prepare", Some(2)),
+    ("mysqli::query", Some(0)),
+    ("mysqli::real_query", Some(0)),
+    ("mysqli::multi_query", Some(0)),
+    ("mysqli::prepare", Some(0)),
+    ("PDO::query", Some(0)),
+    ("PDO::exec", Some(0)),
+    ("PDO::prepare", Some(0)),
+    ("SQLite3::query", Some(0)),
+    ("SQLite3::exec", Some(0)),
+    ("SQLite3::querySingle", Some(0)),
+    ("SQLite3::prepare", Some(0)),
+    ("Doctrine\\DBAL\\Connection::executeQuery", Some(0)),
+    ("Doctrine\\DBAL\\Connection::executeStatement", Some(0)),
+    ("Doctrine\\DBAL\\Connection::prepare", Some(0)),
+    ("Doctrine\\ORM\\EntityManagerInterface::createQuery", Some(0)),
+    ("Doctrine\\ORM\\EntityManagerInterface::createNativeQuery", Some(0)),
+];
+
+const SANITIZERS: &[&str] = &[
+    "mysqli_real_escape_string",
+    "mysqli::real_escape_string",
+    "pg_escape_identifier",
+    "pg_escape_literal",
+    "pg_escape_string",
+    "PDO::quote",
+    "SQLite3::escapeString",
+    "Doctrine\\DBAL\\Connection::quote",
+];
+
+#[derive(Clone, Debug)]
+pub struct SqlInjectionRule;
+
+impl Rule for SqlInjectionRule {
+    fn get_name(&self) -> &'static str {
+        "sql-injection"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for SqlInjectionRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        let configuration = TaintConfiguration::new(
+            context,
+            "SQL injection",
+            "Use prepared statements with bound parameters instead of building queries from user input.",
+            SINKS,
+            SANITIZERS,
+        );
+
+        analyze(program, &configuration, context);
+    }
+}
@@ -0,0 +1,1234 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::context::LintContext;
+use crate::literal::DecodedString;
+use crate::printf::parse_printf_format;
+
+/// The superglobals containing data controlled by the user.
+const SUPERGLOBALS: &[&str] = &["$_GET", "$_POST", "$_REQUEST", "$_COOKIE", "$_FILES", "$_SERVER"];
+
+/// The class-likes representing an HTTP request, whose data is controlled by the user.
+const REQUEST_CLASSES: &[&str] = &[
+    "symfony\\component\\httpfoundation\\request",
+    "illuminate\\http\\request",
+    "psr\\http\\message\\requestinterface",
+    "psr\\http\\message\\serverrequestinterface",
+];
+
+/// The functions returning data controlled by the user.
+const SOURCE_FUNCTIONS: &[&str] = &["apache_request_headers", "getallheaders"];
+
+/// The functions whose result never carries user data, as it is a number, a boolean, or a hash.
+const UNTAINTED_FUNCTIONS: &[&str] = &[
+    "abs",
+    "array_key_exists",
+    "boolval",
+    "ceil",
+    "count",
+    "crc32",
+    "ctype_alnum",
+    "ctype_alpha",
+    "ctype_digit",
+    "floatval",
+    "floor",
+    "hash",
+    "hash_hmac",
+    "in_array",
+    "intval",
+    "is_array",
+    "is_bool",
+    "is_float",
+    "is_int",
+    "is_numeric",
+    "is_string",
+    "md5",
+    "mb_strlen",
+    "password_hash",
+    "preg_match",
+    "preg_match_all",
+    "random_int",
+    "round",
+    "sha1",
+    "sizeof",
+    "str_contains",
+    "str_ends_with",
+    "str_starts_with",
+    "stripos",
+    "strlen",
+    "strpos",
+    "strrpos",
+    "strtotime",
+    "uniqid",
+];
+
+/// The conversion specifiers of `sprintf()` formatting their argument as a number or a character,
+/// which cannot carry user data in a harmful form.
+const NUMERIC_SPECIFIERS: &[u8] = b"bcdeEfFgGouxX";
+
+/// The maximum number of steps recorded in the path of a tainted value.
+const MAXIMUM_PATH_LENGTH: usize = 12;
+
+/// The maximum number of origins tracked for a single value.
+const MAXIMUM_ORIGINS: usize = 4;
+
+/// The number of passes computing the summaries of the function-likes of a program before reporting,
+/// allowing summaries of function-likes declared after their callers to be used.
+const SUMMARY_PASSES: usize = 2;
+
+/// A function, method, or language construct that must not receive user input.
+///
+/// When `argument` is set, only the argument at that position is checked, e.g. the query of
+/// `mysqli_query($connection, $query)`, but not its connection.
+pub type Sink = (&'static str, Option<usize>);
+
+/// The sources, sinks, and sanitizers of a taint analysis.
+///
+/// Functions are referred to by their fully qualified name, e.g. `mysqli_query`, and methods by the
+/// fully qualified name of their class-like followed by `::` and their name, e.g. `PDO::query`. The
+/// language constructs `echo`, `print`, `exit`, `die`, `eval`, `include`, `include_once`, `require`,
+/// and `require_once` may be used as sinks, while backtick strings are treated as `shell_exec` calls.
+#[derive(Debug)]
+pub struct TaintConfiguration {
+    /// The vulnerability the tainted data leads to, e.g. `SQL injection`.
+    pub vulnerability: &'static str,
+    pub help: &'static str,
+    pub sources: Vec<String>,
+    /// The lowercase names of the sinks, their names as configured, and the argument they check, if any.
+    pub sinks: Vec<(String, String, Option<usize>)>,
+    pub sanitizers: Vec<String>,
+}
+
+impl TaintConfiguration {
+    /// Creates a configuration with the given default sinks and sanitizers, extended with the `sources`,
+    /// `sinks`, and `sanitizers` options of the rule.
+    ///
+    /// Configured sinks have all of their arguments checked.
+    pub fn new(
+        context: &LintContext<'_>,
+        vulnerability: &'static str,
+        help: &'static str,
+        sinks: &[Sink],
+        sanitizers: &[&str],
+    ) -> Self {
+        let option = |name: &'static str| {
+            context
+                .option(name)
+                .and_then(|value| value.as_array())
+                .map(|values| values.iter().filter_map(|value| value.as_str()).map(normalize).collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+
+        let mut sources = SOURCE_FUNCTIONS.iter().map(|source| normalize(source)).collect::<Vec<_>>();
+        sources.extend(option("sources"));
+
+        let mut configured_sinks =
+            sinks.iter().map(|(sink, argument)| (normalize(sink), sink.to_string(), *argument)).collect::<Vec<_>>();
+        if let Some(sinks) = context.option("sinks").and_then(|value| value.as_array()) {
+            configured_sinks.extend(
+                sinks.iter().filter_map(|value| value.as_str()).map(|sink| (normalize(sink), sink.to_string(), None)),
+            );
+        }
+
+        let mut configured_sanitizers = sanitizers.iter().map(|sanitizer| normalize(sanitizer)).collect::<Vec<_>>();
+        configured_sanitizers.extend(option("sanitizers"));
+
+        Self { vulnerability, help, sources, sinks: configured_sinks, sanitizers: configured_sanitizers }
+    }
+
+    fn is_source(&self, names: &[String]) -> bool {
+        names.iter().any(|name| self.sources.contains(name))
+    }
+
+    /// Returns the sinks matching any of the given names, along with the argument they check, if any.
+    fn get_sinks(&self, names: &[String]) -> Vec<(String, Option<usize>)> {
+        self.sinks
+            .iter()
+            .filter(|(sink, _, _)| names.contains(sink))
+            .map(|(_, name, argument)| (name.clone(), *argument))
+            .collect()
+    }
+
+    fn has_sink(&self, name: &str) -> bool {
+        self.sinks.iter().any(|(sink, _, _)| sink == name)
+    }
+
+    fn is_sanitizer(&self, names: &[String]) -> bool {
+        names.iter().any(|name| self.sanitizers.contains(name))
+    }
+}
+
+/// Reports the flows of user input into the sinks of the given configuration.
+///
+/// Data is followed through assignments, concatenation, interpolation, array accesses, and calls, and
+/// across the function-likes of the program using summaries of their parameters and return values.
+pub fn analyze(program: &Program, configuration: &TaintConfiguration, context: &mut LintContext<'_>) {
+    let mut analyzer = TaintAnalyzer::new(configuration);
+    for _ in 0..SUMMARY_PASSES {
+        analyzer.walk_program(program, context);
+    }
+
+    analyzer.reporting = true;
+    analyzer.walk_program(program, context);
+
+    let mut reported = HashSet::default();
+    for finding in analyzer.findings {
+        let TaintOrigin::Source(source, description) = &finding.taint.origin else {
+            continue;
+        };
+
+        if !reported.insert((finding.span, *source)) {
+            continue;
+        }
+
+        let mut issue = Issue::new(
+            context.level(),
+            format!("Possible {}: user input reaches `{}`.", configuration.vulnerability, finding.sink),
+        )
+        .with_annotation(
+            Annotation::primary(finding.span).with_message(format!("User input reaches `{}` here.", finding.sink)),
+        )
+        .with_annotation(
+            Annotation::secondary(*source).with_message(format!("User input is read from {} here.", description)),
+        );
+
+        for (span, message) in &finding.taint.path {
+            issue = issue.with_annotation(Annotation::secondary(*span).with_message(message.clone()));
+        }
+
+        context.report(
+            issue
+                .with_note("Data controlled by the user must be validated or sanitized before it reaches a sensitive function.")
+                .with_help(configuration.help),
+        );
+    }
+}
+
+/// Where the data of a tainted value comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TaintOrigin {
+    /// Data read from a source of user input, described by the given message.
+    Source(Span, String),
+    /// Data received through the parameter at the given position of the enclosing function-like.
+    Parameter(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Taint {
+    origin: TaintOrigin,
+    /// The steps the data went through since its origin.
+    path: Vec<(Span, String)>,
+}
+
+impl Taint {
+    fn source(span: Span, description: String) -> Self {
+        Self { origin: TaintOrigin::Source(span, description), path: vec![] }
+    }
+
+    fn with_step(mut self, span: Span, message: String) -> Self {
+        if self.path.len() < MAXIMUM_PATH_LENGTH && !self.path.iter().any(|(step, _)| *step == span) {
+            self.path.push((span, message));
+        }
+
+        self
+    }
+}
+
+/// A parameter of a function-like reaching a sink within it.
+#[derive(Debug, Clone)]
+struct SinkReach {
+    parameter: usize,
+    path: Vec<(Span, String)>,
+    span: Span,
+    sink: String,
+}
+
+/// How data flows through a function-like of the program.
+#[derive(Debug, Clone, Default)]
+struct Summary {
+    /// The origins of the returned values, where parameter origins mean the parameter is returned.
+    returns: Vec<Taint>,
+    sinks: Vec<SinkReach>,
+}
+
+#[derive(Debug)]
+struct Finding {
+    taint: Taint,
+    span: Span,
+    sink: String,
+}
+
+#[derive(Debug, Default)]
+struct Frame {
+    variables: HashMap<String, Vec<Taint>>,
+    /// The key of the summary of the function-like, if it can be called by name.
+    summary: Option<String>,
+    /// The number of conditional or loop statements the analysis is currently within.
+    conditional_depth: usize,
+}
+
+#[derive(Debug)]
+struct TaintAnalyzer<'c> {
+    configuration: &'c TaintConfiguration,
+    reporting: bool,
+    frames: Vec<Frame>,
+    class_likes: Vec<Option<String>>,
+    summaries: HashMap<String, Summary>,
+    properties: HashMap<String, Vec<Taint>>,
+    findings: Vec<Finding>,
+    after_echo_tag: bool,
+}
+
+impl<'c> TaintAnalyzer<'c> {
+    fn new(configuration: &'c TaintConfiguration) -> Self {
+        Self {
+            configuration,
+            reporting: false,
+            frames: vec![Frame::default()],
+            class_likes: vec![],
+            summaries: HashMap::default(),
+            properties: HashMap::default(),
+            findings: vec![],
+            after_echo_tag: false,
+        }
+    }
+
+    fn frame(&self) -> &Frame {
+        self.frames.last().expect("expected there to be at least one frame")
+    }
+
+    fn frame_mut(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("expected there to be at least one frame")
+    }
+
+    fn current_class_like(&self) -> Option<&String> {
+        self.class_likes.last().and_then(|class_like| class_like.as_ref())
+    }
+
+    fn enter_function_like(
+        &mut self,
+        summary: Option<String>,
+        parameters: &FunctionLikeParameterList,
+        context: &LintContext<'_>,
+    ) {
+        let mut frame = Frame { summary, ..Default::default() };
+        for (index, parameter) in parameters.parameters.iter().enumerate() {
+            frame.variables.insert(
+                context.lookup(&parameter.variable.name).to_string(),
+                vec![Taint { origin: TaintOrigin::Parameter(index), path: vec![] }],
+            );
+        }
+
+        self.frames.push(frame);
+    }
+
+    fn evaluate(&self, expression: &Expression, context: &LintContext<'_>) -> Vec<Taint> {
+        let taints = match expression {
+            Expression::Parenthesized(parenthesized) => return self.evaluate(&parenthesized.expression, context),
+            Expression::Variable(Variable::Direct(variable)) => {
+                let name = context.lookup(&variable.name);
+                if SUPERGLOBALS.contains(&name) {
+                    return vec![Taint::source(variable.span, format!("`{}`", name))];
+                }
+
+                let mut taints = self.frame().variables.get(name).cloned().unwrap_or_default();
+                merge(&mut taints, self.get_request_taint(expression, context));
+                taints
+            }
+            Expression::ArrayAccess(array_access) => self.evaluate(&array_access.array, context),
+            Expression::Binary(binary) => match binary.operator {
+                BinaryOperator::StringConcat(_) | BinaryOperator::NullCoalesce(_) | BinaryOperator::Elvis(_) => {
+                    let mut taints = self.evaluate(&binary.lhs, context);
+                    merge(&mut taints, self.evaluate(&binary.rhs, context));
+                    taints
+                }
+                _ => return vec![],
+            },
+            Expression::UnaryPrefix(unary_prefix) => match unary_prefix.operator {
+                UnaryPrefixOperator::ErrorControl(_)
+                | UnaryPrefixOperator::Reference(_)
+                | UnaryPrefixOperator::ArrayCast(_, _)
+                | UnaryPrefixOperator::ObjectCast(_, _)
+                | UnaryPrefixOperator::StringCast(_, _)
+                | UnaryPrefixOperator::BinaryCast(_, _) => return self.evaluate(&unary_prefix.operand, context),
+                _ => return vec![],
+            },
+            Expression::CompositeString(composite_string) => {
+                let mut taints = vec![];
+                for part in composite_string.parts().iter() {
+                    match part {
+                        StringPart::Expression(expression) => merge(&mut taints, self.evaluate(expression, context)),
+                        StringPart::BracedExpression(braced) => {
+                            merge(&mut taints, self.evaluate(&braced.expression, context))
+                        }
+                        StringPart::Literal(_) => {}
+                    }
+                }
+
+                return taints;
+            }
+            Expression::AssignmentOperation(assignment) => return self.evaluate(&assignment.rhs, context),
+            Expression::Conditional(conditional) => {
+                let mut taints = match &conditional.then {
+                    Some(then) => self.evaluate(then, context),
+                    None => self.evaluate(&conditional.condition, context),
+                };
+
+                merge(&mut taints, self.evaluate(&conditional.r#else, context));
+                return taints;
+            }
+            Expression::Array(array) => return self.evaluate_array_elements(array.elements.iter(), context),
+            Expression::LegacyArray(array) => return self.evaluate_array_elements(array.elements.iter(), context),
+            Expression::Match(r#match) => {
+                let mut taints = vec![];
+                for arm in r#match.arms.iter() {
+                    let expression = match arm {
+                        MatchArm::Expression(arm) => &arm.expression,
+                        MatchArm::Default(arm) => &arm.expression,
+                    };
+
+                    merge(&mut taints, self.evaluate(expression, context));
+                }
+
+                return taints;
+            }
+            Expression::Clone(clone) => return self.evaluate(&clone.object, context),
+            Expression::Call(call) => {
+                let mut taints = self.evaluate_call(call, context);
+                merge(&mut taints, self.get_request_taint(expression, context));
+                taints
+            }
+            Expression::Access(access) => match access.as_ref() {
+                Access::Property(PropertyAccess { object, property, .. })
+                | Access::NullSafeProperty(NullSafePropertyAccess { object, property, .. }) => {
+                    let mut taints = self.evaluate(object, context);
+                    if let Some(key) = self.get_property_key(object, property, context) {
+                        merge(&mut taints, self.properties.get(&key).cloned().unwrap_or_default());
+                    }
+
+                    merge(&mut taints, self.get_request_taint(expression, context));
+                    taints
+                }
+                _ => return vec![],
+            },
+            _ => return vec![],
+        };
+
+        if !taints.is_empty() && is_untainted_kind(&context.resolve_kind(expression)) {
+            return vec![];
+        }
+
+        taints
+    }
+
+    fn evaluate_array_elements<'ast>(
+        &self,
+        elements: impl Iterator<Item = &'ast ArrayElement>,
+        context: &LintContext<'_>,
+    ) -> Vec<Taint> {
+        let mut taints = vec![];
+        for element in elements {
+            match element {
+                ArrayElement::KeyValue(element) => {
+                    merge(&mut taints, self.evaluate(&element.key, context));
+                    merge(&mut taints, self.evaluate(&element.value, context));
+                }
+                ArrayElement::Value(element) => merge(&mut taints, self.evaluate(&element.value, context)),
+                ArrayElement::Variadic(element) => merge(&mut taints, self.evaluate(&element.value, context)),
+                ArrayElement::Missing(_) => {}
+            }
+        }
+
+        taints
+    }
+
+    fn evaluate_call(&self, call: &Call, context: &LintContext<'_>) -> Vec<Taint> {
+        let (names, arguments, object) = match call {
+            Call::Function(function_call) => {
+                let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+                    return vec![];
+                };
+
+                let names = get_function_names(identifier, context);
+                if UNTAINTED_FUNCTIONS.contains(&names[names.len() - 1].as_str()) {
+                    return vec![];
+                }
+
+                (names, &function_call.arguments, None)
+            }
+            Call::Method(MethodCall { object, method, arguments, .. })
+            | Call::NullSafeMethod(NullSafeMethodCall { object, method, arguments, .. }) => {
+                (self.get_method_names(object, method, context), arguments, Some(object))
+            }
+            Call::StaticMethod(static_method_call) => (
+                self.get_static_method_names(&static_method_call.class, &static_method_call.method, context),
+                &static_method_call.arguments,
+                None,
+            ),
+        };
+
+        let name = get_written_name(call, context);
+
+        if self.configuration.is_sanitizer(&names) {
+            return vec![];
+        }
+
+        if self.configuration.is_source(&names) {
+            return vec![Taint::source(call.span(), format!("`{}()`", name))];
+        }
+
+        let mut taints = vec![];
+        if let Some(object) = object {
+            merge(&mut taints, self.evaluate(object, context));
+        }
+
+        match names.iter().find_map(|name| self.summaries.get(name)) {
+            Some(summary) => {
+                for taint in &summary.returns {
+                    match taint.origin {
+                        TaintOrigin::Source(_, _) => {
+                            merge(
+                                &mut taints,
+                                vec![taint
+                                    .clone()
+                                    .with_step(call.span(), format!("It is returned by `{}()` here.", name))],
+                            );
+                        }
+                        TaintOrigin::Parameter(index) => {
+                            let Some(argument) = arguments.arguments.get(index) else {
+                                continue;
+                            };
+
+                            let argument_taints = self
+                                .evaluate(argument.value(), context)
+                                .into_iter()
+                                .map(|argument_taint| {
+                                    let mut argument_taint = argument_taint
+                                        .with_step(argument.span(), format!("It is passed to `{}()` here.", name));
+                                    for (span, message) in taint.path.iter() {
+                                        argument_taint = argument_taint.with_step(*span, message.clone());
+                                    }
+
+                                    argument_taint
+                                })
+                                .collect();
+
+                            merge(&mut taints, argument_taints);
+                        }
+                    }
+                }
+            }
+            None if matches!(call, Call::Function(_)) => {
+                // Functions that are not known are assumed to return data derived from their arguments.
+                let sanitized = get_sanitized_format_arguments(&names, arguments, context);
+                for (position, argument) in arguments.arguments.iter().enumerate() {
+                    if !sanitized.contains(&position) {
+                        merge(&mut taints, self.evaluate(argument.value(), context));
+                    }
+                }
+            }
+            None => {}
+        }
+
+        taints
+    }
+
+    /// Returns the taint of an expression holding an HTTP request object.
+    fn get_request_taint(&self, expression: &Expression, context: &LintContext<'_>) -> Vec<Taint> {
+        if !self.is_request_object(&context.resolve_kind(expression), context) {
+            return vec![];
+        }
+
+        vec![Taint::source(expression.span(), "the request".to_string())]
+    }
+
+    fn is_request_object(&self, kind: &TypeKind, context: &LintContext<'_>) -> bool {
+        match kind {
+            TypeKind::Union { kinds } | TypeKind::Intersection { kinds } => {
+                kinds.iter().any(|kind| self.is_request_object(kind, context))
+            }
+            TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => {
+                get_class_like_names(*name, context).iter().any(|name| REQUEST_CLASSES.contains(&name.as_str()))
+            }
+            _ => false,
+        }
+    }
+
+    fn get_method_names(
+        &self,
+        object: &Expression,
+        method: &ClassLikeMemberSelector,
+        context: &LintContext<'_>,
+    ) -> Vec<String> {
+        let ClassLikeMemberSelector::Identifier(method) = method else {
+            return vec![];
+        };
+
+        let method = context.lookup(&method.value).to_ascii_lowercase();
+        let class_like_names = match object {
+            Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this" => {
+                self.current_class_like().cloned().into_iter().collect()
+            }
+            _ => match context.resolve_kind(object) {
+                TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => get_class_like_names(name, context),
+                _ => vec![],
+            },
+        };
+
+        class_like_names.into_iter().map(|class_like_name| format!("{}::{}", class_like_name, method)).collect()
+    }
+
+    fn get_static_method_names(
+        &self,
+        class: &Expression,
+        method: &ClassLikeMemberSelector,
+        context: &LintContext<'_>,
+    ) -> Vec<String> {
+        let ClassLikeMemberSelector::Identifier(method) = method else {
+            return vec![];
+        };
+
+        let method = context.lookup(&method.value).to_ascii_lowercase();
+        let class_like_names = match class {
+            Expression::Identifier(identifier) => {
+                get_class_like_names(*context.semantics.names.get(identifier), context)
+            }
+            Expression::Self_(_) | Expression::Static(_) => self.current_class_like().cloned().into_iter().collect(),
+            _ => vec![],
+        };
+
+        class_like_names.into_iter().map(|class_like_name| format!("{}::{}", class_like_name, method)).collect()
+    }
+
+    /// Returns the key of a property of `$this`, used to follow data stored in it across methods.
+    fn get_property_key(
+        &self,
+        object: &Expression,
+        property: &ClassLikeMemberSelector,
+        context: &LintContext<'_>,
+    ) -> Option<String> {
+        let Expression::Variable(Variable::Direct(variable)) = object else {
+            return None;
+        };
+
+        let ClassLikeMemberSelector::Identifier(property) = property else {
+            return None;
+        };
+
+        if context.lookup(&variable.name) != "$this" {
+            return None;
+        }
+
+        Some(format!("{}::${}", self.current_class_like()?, context.lookup(&property.value)))
+    }
+
+    fn assign(&mut self, target: &Expression, taints: Vec<Taint>, replace: bool, context: &LintContext<'_>) {
+        match target {
+            Expression::Variable(Variable::Direct(variable)) => {
+                let name = context.lookup(&variable.name);
+                let taints = taints
+                    .into_iter()
+                    .map(|taint| taint.with_step(variable.span, format!("It is assigned to `{}` here.", name)))
+                    .collect::<Vec<_>>();
+
+                let frame = self.frame_mut();
+                if replace && frame.conditional_depth == 0 {
+                    frame.variables.insert(name.to_string(), taints);
+                } else {
+                    merge(frame.variables.entry(name.to_string()).or_default(), taints);
+                }
+            }
+            Expression::ArrayAccess(array_access) => self.assign(&array_access.array, taints, false, context),
+            Expression::ArrayAppend(array_append) => self.assign(&array_append.array, taints, false, context),
+            Expression::Access(access) => {
+                let Access::Property(property_access) = access.as_ref() else {
+                    return;
+                };
+
+                let Some(key) = self.get_property_key(&property_access.object, &property_access.property, context)
+                else {
+                    return;
+                };
+
+                // Only user input is followed across methods, as parameters differ from one method to another.
+                let taints = taints
+                    .into_iter()
+                    .filter(|taint| matches!(taint.origin, TaintOrigin::Source(_, _)))
+                    .map(|taint| {
+                        taint.with_step(property_access.span(), "It is stored in a property here.".to_string())
+                    })
+                    .collect();
+
+                merge(self.properties.entry(key).or_default(), taints);
+            }
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. }) => {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => {
+                            self.assign(&element.value, taints.clone(), replace, context)
+                        }
+                        ArrayElement::Value(element) => self.assign(&element.value, taints.clone(), replace, context),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_sink(&mut self, sink: &str, value: &Expression, context: &LintContext<'_>) {
+        let taints = self.evaluate(value, context);
+
+        self.reach_sink(sink, value.span(), taints);
+    }
+
+    fn reach_sink(&mut self, sink: &str, span: Span, taints: Vec<Taint>) {
+        for taint in taints {
+            match taint.origin {
+                TaintOrigin::Source(_, _) => {
+                    if self.reporting {
+                        self.findings.push(Finding { taint, span, sink: sink.to_string() });
+                    }
+                }
+                TaintOrigin::Parameter(parameter) => {
+                    let Some(key) = self.frame().summary.clone() else {
+                        continue;
+                    };
+
+                    let summary = self.summaries.entry(key).or_default();
+                    if summary.sinks.iter().any(|reach| reach.parameter == parameter && reach.span == span) {
+                        continue;
+                    }
+
+                    summary.sinks.push(SinkReach { parameter, path: taint.path, span, sink: sink.to_string() });
+                }
+            }
+        }
+    }
+
+    fn check_call(&mut self, name: &str, names: &[String], arguments: &ArgumentList, context: &LintContext<'_>) {
+        for (sink, checked_argument) in self.configuration.get_sinks(names) {
+            let sink = format!("{}()", sink);
+            for (index, argument) in arguments.arguments.iter().enumerate() {
+                if checked_argument.is_none_or(|checked_argument| checked_argument == index) {
+                    self.check_sink(&sink, argument.value(), context);
+                }
+            }
+        }
+
+        let Some(reaches) = names.iter().find_map(|name| self.summaries.get(name)).map(|summary| summary.sinks.clone())
+        else {
+            return;
+        };
+
+        for reach in reaches {
+            let Some(argument) = arguments.arguments.get(reach.parameter) else {
+                continue;
+            };
+
+            let taints = self
+                .evaluate(argument.value(), context)
+                .into_iter()
+                .map(|taint| {
+                    let mut taint = taint.with_step(argument.span(), format!("It is passed to `{}()` here.", name));
+                    for (span, message) in reach.path.iter() {
+                        taint = taint.with_step(*span, message.clone());
+                    }
+
+                    taint
+                })
+                .collect();
+
+            self.reach_sink(&reach.sink, reach.span, taints);
+        }
+    }
+}
+
+impl<'a> MutWalker<LintContext<'a>> for TaintAnalyzer<'_> {
+    fn walk_in_statement(&mut self, statement: &Statement, context: &mut LintContext<'a>) {
+        match statement {
+            Statement::OpeningTag(OpeningTag::Echo(_)) => {
+                self.after_echo_tag = true;
+
+                return;
+            }
+            Statement::Expression(expression_statement)
+                if self.after_echo_tag && self.configuration.has_sink("echo") =>
+            {
+                self.check_sink("echo", &expression_statement.expression, context);
+            }
+            _ => {}
+        }
+
+        self.after_echo_tag = false;
+    }
+
+    fn walk_in_class(&mut self, class: &Class, context: &mut LintContext<'a>) {
+        self.class_likes.push(Some(context.lookup_name(&class.name).to_ascii_lowercase()));
+    }
+
+    fn walk_out_class(&mut self, _class: &Class, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        self.class_likes.push(Some(context.lookup_name(&r#trait.name).to_ascii_lowercase()));
+    }
+
+    fn walk_out_trait(&mut self, _trait: &Trait, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        self.class_likes.push(Some(context.lookup_name(&r#enum.name).to_ascii_lowercase()));
+    }
+
+    fn walk_out_enum(&mut self, _enum: &Enum, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_interface(&mut self, interface: &Interface, context: &mut LintContext<'a>) {
+        self.class_likes.push(Some(context.lookup_name(&interface.name).to_ascii_lowercase()));
+    }
+
+    fn walk_out_interface(&mut self, _interface: &Interface, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_anonymous_class(&mut self, _anonymous_class: &AnonymousClass, _context: &mut LintContext<'a>) {
+        self.class_likes.push(None);
+    }
+
+    fn walk_out_anonymous_class(&mut self, _anonymous_class: &AnonymousClass, _context: &mut LintContext<'a>) {
+        self.class_likes.pop();
+    }
+
+    fn walk_in_function(&mut self, function: &Function, context: &mut LintContext<'a>) {
+        let key = context.lookup_name(&function.name).to_ascii_lowercase();
+
+        self.enter_function_like(Some(key), &function.parameters, context);
+    }
+
+    fn walk_out_function(&mut self, _function: &Function, _context: &mut LintContext<'a>) {
+        self.frames.pop();
+    }
+
+    fn walk_in_method(&mut self, method: &Method, context: &mut LintContext<'a>) {
+        let key = self
+            .current_class_like()
+            .map(|class_like| format!("{}::{}", class_like, context.lookup(&method.name.value).to_ascii_lowercase()));
+
+        self.enter_function_like(key, &method.parameters, context);
+    }
+
+    fn walk_out_method(&mut self, _method: &Method, _context: &mut LintContext<'a>) {
+        self.frames.pop();
+    }
+
+    fn walk_in_closure(&mut self, closure: &Closure, context: &mut LintContext<'a>) {
+        let mut inherited = vec![];
+        if let Some(use_clause) = &closure.use_clause {
+            for variable in use_clause.variables.iter() {
+                let name = context.lookup(&variable.variable.name).to_string();
+                if let Some(taints) = self.frame().variables.get(&name) {
+                    inherited.push((name, taints.clone()));
+                }
+            }
+        }
+
+        self.enter_function_like(None, &closure.parameters, context);
+        self.frame_mut().variables.extend(inherited);
+    }
+
+    fn walk_out_closure(&mut self, _closure: &Closure, _context: &mut LintContext<'a>) {
+        self.frames.pop();
+    }
+
+    fn walk_in_arrow_function(&mut self, arrow_function: &ArrowFunction, context: &mut LintContext<'a>) {
+        let variables = self.frame().variables.clone();
+
+        self.enter_function_like(None, &arrow_function.parameters, context);
+        for (name, taints) in variables {
+            self.frame_mut().variables.entry(name).or_insert(taints);
+        }
+    }
+
+    fn walk_out_arrow_function(&mut self, _arrow_function: &ArrowFunction, _context: &mut LintContext<'a>) {
+        self.frames.pop();
+    }
+
+    fn walk_in_if(&mut self, _if: &If, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_if(&mut self, _if: &If, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_switch(&mut self, _switch: &Switch, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_switch(&mut self, _switch: &Switch, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_try(&mut self, _try: &Try, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_try(&mut self, _try: &Try, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_while(&mut self, _while: &While, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_while(&mut self, _while: &While, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_do_while(&mut self, _do_while: &DoWhile, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_do_while(&mut self, _do_while: &DoWhile, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_for(&mut self, _for: &For, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+    }
+
+    fn walk_out_for(&mut self, _for: &For, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_foreach(&mut self, foreach: &Foreach, context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth += 1;
+
+        let taints = self.evaluate(&foreach.expression, context);
+        match &foreach.target {
+            ForeachTarget::Value(target) => self.assign(&target.value, taints, false, context),
+            ForeachTarget::KeyValue(target) => {
+                self.assign(&target.key, taints.clone(), false, context);
+                self.assign(&target.value, taints, false, context);
+            }
+        }
+    }
+
+    fn walk_out_foreach(&mut self, _foreach: &Foreach, _context: &mut LintContext<'a>) {
+        self.frame_mut().conditional_depth -= 1;
+    }
+
+    fn walk_in_assignment(&mut self, assignment: &Assignment, context: &mut LintContext<'a>) {
+        match assignment.operator {
+            AssignmentOperator::Assign(_) => {
+                let taints = self.evaluate(&assignment.rhs, context);
+
+                self.assign(&assignment.lhs, taints, true, context);
+            }
+            AssignmentOperator::Concat(_) | AssignmentOperator::Coalesce(_) => {
+                let taints = self.evaluate(&assignment.rhs, context);
+
+                self.assign(&assignment.lhs, taints, false, context);
+            }
+            _ => {
+                // Arithmetic and bitwise operations result in numbers.
+                self.assign(&assignment.lhs, vec![], true, context);
+            }
+        }
+    }
+
+    fn walk_in_return(&mut self, r#return: &Return, context: &mut LintContext<'a>) {
+        let Some(value) = &r#return.value else {
+            return;
+        };
+
+        let Some(key) = self.frame().summary.clone() else {
+            return;
+        };
+
+        let taints = self
+            .evaluate(value, context)
+            .into_iter()
+            .map(|taint| match taint.origin {
+                TaintOrigin::Source(_, _) => taint.with_step(value.span(), "It is returned here.".to_string()),
+                TaintOrigin::Parameter(_) => taint,
+            })
+            .collect();
+
+        merge(&mut self.summaries.entry(key).or_default().returns, taints);
+    }
+
+    fn walk_in_echo(&mut self, echo: &Echo, context: &mut LintContext<'a>) {
+        if !self.configuration.has_sink("echo") {
+            return;
+        }
+
+        for value in echo.values.iter() {
+            self.check_sink("echo", value, context);
+        }
+    }
+
+    fn walk_in_construct(&mut self, construct: &Construct, context: &mut LintContext<'a>) {
+        let (sink, values): (&str, Vec<&Expression>) = match construct {
+            Construct::Print(print) => ("print", vec![&print.value]),
+            Construct::Eval(eval) => ("eval", vec![&eval.value]),
+            Construct::Include(include) => ("include", vec![&include.value]),
+            Construct::IncludeOnce(include_once) => ("include_once", vec![&include_once.value]),
+            Construct::Require(require) => ("require", vec![&require.value]),
+            Construct::RequireOnce(require_once) => ("require_once", vec![&require_once.value]),
+            Construct::Exit(exit) => {
+                ("exit", exit.arguments.iter().flat_map(|list| list.arguments.iter()).map(|a| a.value()).collect())
+            }
+            Construct::Die(die) => {
+                ("die", die.arguments.iter().flat_map(|list| list.arguments.iter()).map(|a| a.value()).collect())
+            }
+            Construct::Isset(_) | Construct::Empty(_) => return,
+        };
+
+        if !self.configuration.has_sink(sink) {
+            return;
+        }
+
+        for value in values {
+            self.check_sink(sink, value, context);
+        }
+    }
+
+    fn walk_in_shell_execute_string(
+        &mut self,
+        shell_execute_string: &ShellExecuteString,
+        context: &mut LintContext<'a>,
+    ) {
+        if !self.configuration.has_sink("shell_exec") {
+            return;
+        }
+
+        for part in shell_execute_string.parts.iter() {
+            match part {
+                StringPart::Expression(expression) => self.check_sink("shell_exec()", expression, context),
+                StringPart::BracedExpression(braced) => self.check_sink("shell_exec()", &braced.expression, context),
+                StringPart::Literal(_) => {}
+            }
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let names = get_function_names(identifier, context);
+        let arguments = &function_call.arguments.arguments;
+
+        // Functions writing data derived from their input into arguments passed by reference.
+        let (input, output) = match names[names.len() - 1].as_str() {
+            "parse_str" | "mb_parse_str" => (0, 1),
+            "preg_match" | "preg_match_all" => (1, 2),
+            _ => (usize::MAX, usize::MAX),
+        };
+
+        if let (Some(input), Some(output)) = (arguments.get(input), arguments.get(output)) {
+            let taints = self.evaluate(input.value(), context);
+
+            self.assign(output.value(), taints, false, context);
+        }
+
+        self.check_call(&get_written_function_name(identifier, context), &names, &function_call.arguments, context);
+    }
+
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, context: &mut LintContext<'a>) {
+        let names = self.get_method_names(&method_call.object, &method_call.method, context);
+
+        self.check_call(
+            &get_written_member_name(&method_call.method, context),
+            &names,
+            &method_call.arguments,
+            context,
+        );
+    }
+
+    fn walk_in_null_safe_method_call(
+        &mut self,
+        null_safe_method_call: &NullSafeMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let names = self.get_method_names(&null_safe_method_call.object, &null_safe_method_call.method, context);
+
+        self.check_call(
+            &get_written_member_name(&null_safe_method_call.method, context),
+            &names,
+            &null_safe_method_call.arguments,
+            context,
+        );
+    }
+
+    fn walk_in_static_method_call(&mut self, static_method_call: &StaticMethodCall, context: &mut LintContext<'a>) {
+        let names = self.get_static_method_names(&static_method_call.class, &static_method_call.method, context);
+
+        self.check_call(
+            &get_written_member_name(&static_method_call.method, context),
+            &names,
+            &static_method_call.arguments,
+            context,
+        );
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim_start_matches('\\').to_ascii_lowercase()
+}
+
+/// Returns the name of the called function-like as written in the source, used in messages.
+fn get_written_name(call: &Call, context: &LintContext<'_>) -> String {
+    match call {
+        Call::Function(function_call) => match function_call.function.as_ref() {
+            Expression::Identifier(identifier) => get_written_function_name(identifier, context),
+            _ => "function".to_string(),
+        },
+        Call::Method(MethodCall { method, .. }) | Call::NullSafeMethod(NullSafeMethodCall { method, .. }) => {
+            get_written_member_name(method, context)
+        }
+        Call::StaticMethod(static_method_call) => get_written_member_name(&static_method_call.method, context),
+    }
+}
+
+fn get_written_function_name(identifier: &Identifier, context: &LintContext<'_>) -> String {
+    context.lookup(&identifier.value()).trim_start_matches('\\').to_string()
+}
+
+fn get_written_member_name(selector: &ClassLikeMemberSelector, context: &LintContext<'_>) -> String {
+    match selector {
+        ClassLikeMemberSelector::Identifier(identifier) => context.lookup(&identifier.value).to_string(),
+        _ => "method".to_string(),
+    }
+}
+
+/// Returns the lowercase names a called function may refer to: its resolved name, followed by its
+/// name in the global namespace when it is unqualified and not imported.
+fn get_function_names(identifier: &Identifier, context: &LintContext<'_>) -> Vec<String> {
+    let resolved = context.lookup_name(identifier).to_ascii_lowercase();
+    let fallback = context.lookup_function_name(identifier).to_ascii_lowercase();
+
+    if resolved == fallback {
+        vec![resolved]
+    } else {
+        vec![resolved, fallback]
+    }
+}
+
+/// Returns the positions of the arguments of a `sprintf()` call that are only formatted by numeric
+/// conversions, such as the `$id` of `sprintf('%d', $id)`.
+fn get_sanitized_format_arguments(
+    names: &[String],
+    arguments: &ArgumentList,
+    context: &LintContext<'_>,
+) -> HashSet<usize> {
+    let mut sanitized = HashSet::default();
+    if names[names.len() - 1] != "sprintf" {
+        return sanitized;
+    }
+
+    if arguments
+        .arguments
+        .iter()
+        .any(|argument| !matches!(argument, Argument::Positional(positional) if positional.ellipsis.is_none()))
+    {
+        return sanitized;
+    }
+
+    let Some(Argument::Positional(PositionalArgument { value: Expression::Literal(Literal::String(literal)), .. })) =
+        arguments.arguments.first()
+    else {
+        return sanitized;
+    };
+
+    let Some(decoded) = DecodedString::decode(literal, context) else {
+        return sanitized;
+    };
+
+    let format = parse_printf_format(&decoded.value);
+    if format.error.is_some() {
+        return sanitized;
+    }
+
+    for placeholder in &format.placeholders {
+        if placeholder.specifier == b'*' || NUMERIC_SPECIFIERS.contains(&placeholder.specifier) {
+            sanitized.insert(placeholder.argument + 1);
+        }
+    }
+
+    // An argument formatted by both a numeric and a string conversion, as in `sprintf('%1$d %1$s', $id)`, is
+    // still tainted.
+    for placeholder in &format.placeholders {
+        if placeholder.specifier != b'*' && !NUMERIC_SPECIFIERS.contains(&placeholder.specifier) {
+            sanitized.remove(&(placeholder.argument + 1));
+        }
+    }
+
+    sanitized
+}
+
+/// Returns the lowercase names of the given class-like, and of the class-likes it extends or implements.
+fn get_class_like_names(name: mago_interner::StringIdentifier, context: &LintContext<'_>) -> Vec<String> {
+    let mut names = vec![context.lookup(&name).to_ascii_lowercase()];
+    if let Some(reflection) = context.codebase.get_named_class_like(&name) {
+        let inheritance = &reflection.inheritance;
+        for ancestor in inheritance.all_extended_classes.iter().chain(inheritance.all_implemented_interfaces.iter()) {
+            names.push(context.lookup(&ancestor.value).to_ascii_lowercase());
+        }
+    }
+
+    names
+}
+
+/// Determines whether values of the given type cannot carry user data in a harmful form.
+fn is_untainted_kind(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().all(is_untainted_kind),
+        TypeKind::Scalar(scalar) => matches!(
+            scalar,
+            ScalarTypeKind::Bool
+                | ScalarTypeKind::Integer { .. }
+                | ScalarTypeKind::Float
+                | ScalarTypeKind::IntegerMask(_)
+                | ScalarTypeKind::IntegerMaskOf(_, _)
+                | ScalarTypeKind::NumericString
+        ),
+        TypeKind::Value(value) => matches!(
+            value,
+            ValueTypeKind::Integer { .. }
+                | ValueTypeKind::Float { .. }
+                | ValueTypeKind::Null
+                | ValueTypeKind::True
+                | ValueTypeKind::False
+        ),
+        TypeKind::Void | TypeKind::Never => true,
+        _ => false,
+    }
+}
+
+/// Merges the given taints into the existing ones, keeping a single taint per origin.
+fn merge(existing: &mut Vec<Taint>, taints: Vec<Taint>) {
+    for taint in taints {
+        if existing.len() >= MAXIMUM_ORIGINS {
+            return;
+        }
+
+        if !existing.iter().any(|other| other.origin == taint.origin) {
+            existing.push(taint);
+        }
+    }
+}
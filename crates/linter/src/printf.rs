@@ -0,0 +1,403 @@
+/// The conversion specifiers of `printf`-style format strings.
+const PRINTF_SPECIFIERS: &[u8] = b"bcdeEfFgGhHosuxX%";
+
+/// The conversion specifiers of `scanf`-style format strings, besides character sets.
+const SCANF_SPECIFIERS: &[u8] = b"ncdDioxXufeEgs";
+
+/// A placeholder of a format string that consumes an argument, such as `%s`, `%1$d`, or the `*` of `%*d`.
+#[derive(Debug, Clone, Copy)]
+pub struct Placeholder {
+    pub start: usize,
+    pub end: usize,
+    /// The index of the argument the placeholder consumes, among the arguments following the format string.
+    pub argument: usize,
+    /// Whether the placeholder refers to its argument by position, as in `%2$s`.
+    pub positional: bool,
+    /// The conversion specifier of the placeholder, or `*` for a width or precision taken from an argument.
+    pub specifier: u8,
+}
+
+/// An error found in a format string.
+#[derive(Debug, Clone)]
+pub struct FormatError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Format {
+    pub placeholders: Vec<Placeholder>,
+    pub error: Option<FormatError>,
+}
+
+impl Format {
+    fn fail(mut self, message: impl Into<String>, start: usize, end: usize) -> Self {
+        self.error = Some(FormatError { message: message.into(), start, end: end.max(start + 1) });
+
+        self
+    }
+}
+
+/// Parses a format string, as accepted by `sprintf()` and related functions.
+pub fn parse_printf_format(format: &[u8]) -> Format {
+    let mut result = Format::default();
+    let mut next_argument = 0;
+    let mut index = 0;
+
+    while index < format.len() {
+        if format[index] != b'%' {
+            index += 1;
+
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        if format.get(index) == Some(&b'%') {
+            index += 1;
+
+            continue;
+        }
+
+        let argument = match read_argument_number(format, &mut index) {
+            Ok(argument) => argument,
+            Err(()) => return result.fail("Argument number specifier must be greater than zero.", start, index),
+        };
+
+        loop {
+            match format.get(index) {
+                Some(b'-' | b'+' | b' ' | b'0') => index += 1,
+                Some(b'\'') if index + 1 < format.len() => index += 2,
+                Some(b'\'') => return result.fail("Missing padding character.", start, index + 1),
+                _ => break,
+            }
+        }
+
+        for prefix in [None, Some(b'.')] {
+            if let Some(prefix) = prefix {
+                if format.get(index) != Some(&prefix) {
+                    break;
+                }
+
+                index += 1;
+            }
+
+            if format.get(index) == Some(&b'*') {
+                let star = index;
+                index += 1;
+                let star_argument = match read_argument_number(format, &mut index) {
+                    Ok(argument) => argument,
+                    Err(()) => {
+                        return result.fail("Argument number specifier must be greater than zero.", star, index);
+                    }
+                };
+
+                result.placeholders.push(Placeholder {
+                    start: star,
+                    end: index,
+                    argument: take_argument(star_argument, &mut next_argument),
+                    positional: star_argument.is_some(),
+                    specifier: b'*',
+                });
+            } else {
+                let digits = format[index..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+                if digits > 0
+                    && read_number(&format[index..index + digits]).is_none_or(|number| number > i32::MAX as usize)
+                {
+                    let message = if prefix.is_none() {
+                        "Width must be greater than zero and less than 2147483647."
+                    } else {
+                        "Precision must be greater than zero and less than 2147483647."
+                    };
+
+                    return result.fail(message, index, index + digits);
+                }
+
+                index += digits;
+            }
+        }
+
+        if format.get(index) == Some(&b'l') {
+            index += 1;
+        }
+
+        match format.get(index) {
+            None => return result.fail("Missing format specifier at end of string.", start, index),
+            Some(specifier) if PRINTF_SPECIFIERS.contains(specifier) => {
+                index += 1;
+                result.placeholders.push(Placeholder {
+                    start,
+                    end: index,
+                    argument: take_argument(argument, &mut next_argument),
+                    positional: argument.is_some(),
+                    specifier: *specifier,
+                });
+            }
+            Some(_) => {
+                let (specifier, length) = read_character(&format[index..]);
+
+                return result.fail(format!("Unknown format specifier `{}`.", specifier), start, index + length);
+            }
+        }
+    }
+
+    result
+}
+
+/// Parses a format string, as accepted by `sscanf()` and `fscanf()`.
+pub fn parse_scanf_format(format: &[u8]) -> Format {
+    let mut result = Format::default();
+    let mut next_argument = 0;
+    let mut index = 0;
+
+    while index < format.len() {
+        if format[index] != b'%' {
+            index += 1;
+
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        if format.get(index) == Some(&b'%') {
+            index += 1;
+
+            continue;
+        }
+
+        let suppressed = format.get(index) == Some(&b'*');
+        let argument = if suppressed {
+            index += 1;
+
+            None
+        } else {
+            match read_argument_number(format, &mut index) {
+                Ok(argument) => argument,
+                Err(()) => return result.fail("Argument number specifier must be greater than zero.", start, index),
+            }
+        };
+
+        index += format[index..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if matches!(format.get(index), Some(b'l' | b'L' | b'h')) {
+            index += 1;
+        }
+
+        let Some(specifier) = format.get(index).copied() else {
+            return result.fail("Missing format specifier at end of string.", start, index);
+        };
+
+        match specifier {
+            b'[' => {
+                let mut end = index + 1;
+                if format.get(end) == Some(&b'^') {
+                    end += 1;
+                }
+
+                if format.get(end) == Some(&b']') {
+                    end += 1;
+                }
+
+                match format[end..].iter().position(|byte| *byte == b']') {
+                    Some(closing) => index = end + closing + 1,
+                    None => return result.fail("Unmatched `[` in format string.", index, format.len()),
+                }
+            }
+            _ if SCANF_SPECIFIERS.contains(&specifier) => index += 1,
+            _ => {
+                let (specifier, length) = read_character(&format[index..]);
+
+                return result.fail(format!("Bad scan conversion character `{}`.", specifier), start, index + length);
+            }
+        }
+
+        if !suppressed {
+            result.placeholders.push(Placeholder {
+                start,
+                end: index,
+                argument: take_argument(argument, &mut next_argument),
+                positional: argument.is_some(),
+                specifier,
+            });
+        }
+    }
+
+    result
+}
+
+/// Reads an argument number, such as the `2$` of `%2$s`, returning the index of the argument it refers to.
+fn read_argument_number(format: &[u8], index: &mut usize) -> Result<Option<usize>, ()> {
+    let digits = format[*index..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+    if format.get(*index + digits) != Some(&b'$') {
+        return Ok(None);
+    }
+
+    let number = read_number(&format[*index..*index + digits]);
+    *index += digits + 1;
+
+    match number {
+        Some(number) if number > 0 && number <= i32::MAX as usize => Ok(Some(number - 1)),
+        _ => Err(()),
+    }
+}
+
+/// Returns the index of the argument a placeholder consumes, taking the next one if it has no argument number.
+fn take_argument(argument: Option<usize>, next_argument: &mut usize) -> usize {
+    argument.unwrap_or_else(|| {
+        *next_argument += 1;
+
+        *next_argument - 1
+    })
+}
+
+fn read_number(digits: &[u8]) -> Option<usize> {
+    digits.iter().try_fold(0usize, |number, digit| number.checked_mul(10)?.checked_add((digit - b'0') as usize))
+}
+
+/// Reads the character at the start of the given bytes, returning it along with its length in bytes.
+fn read_character(bytes: &[u8]) -> (char, usize) {
+    let length = bytes.iter().skip(1).take(3).take_while(|byte| (0x80..0xC0).contains(*byte)).count() + 1;
+
+    match std::str::from_utf8(&bytes[..length]).ok().and_then(|character| character.chars().next()) {
+        Some(character) => (character, length),
+        None => (char::from(bytes[0]), 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the arguments consumed by each placeholder, along with the specifier of the placeholder.
+    fn get_arguments(format: &Format) -> Vec<(usize, char)> {
+        assert!(format.error.is_none(), "unexpected error: {:?}", format.error);
+
+        format
+            .placeholders
+            .iter()
+            .map(|placeholder| (placeholder.argument, char::from(placeholder.specifier)))
+            .collect()
+    }
+
+    /// Returns the number of arguments the format string requires.
+    fn get_argument_count(format: &Format) -> usize {
+        format.placeholders.iter().map(|placeholder| placeholder.argument + 1).max().unwrap_or(0)
+    }
+
+    fn get_error(format: Format) -> Option<(String, usize, usize)> {
+        format.error.map(|error| (error.message, error.start, error.end))
+    }
+
+    #[test]
+    fn test_printf_valid_specifiers() {
+        let format = parse_printf_format(b"%b %c %d %e %E %f %F %g %G %h %H %o %s %u %x %X");
+
+        assert_eq!(
+            get_arguments(&format).into_iter().map(|(_, specifier)| specifier).collect::<String>(),
+            "bcdeEfFgGhHosuxX"
+        );
+
+        let format = parse_printf_format(b"%'*10s|%-10s|%+d|%05.2f|% d|%ld|100%%");
+        assert_eq!(get_arguments(&format), vec![(0, 's'), (1, 's'), (2, 'd'), (3, 'f'), (4, 'd'), (5, 'd')]);
+        assert_eq!(format.placeholders[0].start, 0);
+        assert_eq!(format.placeholders[0].end, 6);
+
+        assert!(parse_printf_format(b"").placeholders.is_empty());
+        assert!(parse_printf_format(b"no placeholders %% here").placeholders.is_empty());
+    }
+
+    #[test]
+    fn test_printf_invalid_specifiers() {
+        assert_eq!(get_error(parse_printf_format(b"%y")), Some(("Unknown format specifier `y`.".to_string(), 0, 2)));
+        assert_eq!(
+            get_error(parse_printf_format("a %é".as_bytes())),
+            Some(("Unknown format specifier `é`.".to_string(), 2, 5))
+        );
+        assert_eq!(
+            get_error(parse_printf_format(b"abc %")),
+            Some(("Missing format specifier at end of string.".to_string(), 4, 5))
+        );
+        assert_eq!(
+            get_error(parse_printf_format(b"%5.2")),
+            Some(("Missing format specifier at end of string.".to_string(), 0, 4))
+        );
+        assert_eq!(get_error(parse_printf_format(b"%'")), Some(("Missing padding character.".to_string(), 0, 2)));
+        assert_eq!(
+            get_error(parse_printf_format(b"%0$s")),
+            Some(("Argument number specifier must be greater than zero.".to_string(), 0, 3))
+        );
+        assert_eq!(
+            get_error(parse_printf_format(b"%99999999999s")),
+            Some(("Width must be greater than zero and less than 2147483647.".to_string(), 1, 12))
+        );
+        assert_eq!(
+            get_error(parse_printf_format(b"%.99999999999f")),
+            Some(("Precision must be greater than zero and less than 2147483647.".to_string(), 2, 13))
+        );
+    }
+
+    #[test]
+    fn test_printf_positional_arguments() {
+        let format = parse_printf_format(b"%2$s %1$s %2$d");
+        assert_eq!(get_arguments(&format), vec![(1, 's'), (0, 's'), (1, 'd')]);
+        assert!(format.placeholders.iter().all(|placeholder| placeholder.positional));
+
+        // Placeholders without an argument number take the next argument, regardless of positional ones.
+        let format = parse_printf_format(b"%3$s %s %s");
+        assert_eq!(get_arguments(&format), vec![(2, 's'), (0, 's'), (1, 's')]);
+        assert!(!format.placeholders[1].positional);
+
+        // Widths and precisions can be taken from arguments, by position or not.
+        let format = parse_printf_format(b"%*.*f %1$*2$s");
+        assert_eq!(get_arguments(&format), vec![(0, '*'), (1, '*'), (2, 'f'), (1, '*'), (0, 's')]);
+    }
+
+    #[test]
+    fn test_printf_argument_count() {
+        assert_eq!(get_argument_count(&parse_printf_format(b"hello")), 0);
+        assert_eq!(get_argument_count(&parse_printf_format(b"%s and %d")), 2);
+        assert_eq!(get_argument_count(&parse_printf_format(b"%1$s and %1$s")), 1);
+        assert_eq!(get_argument_count(&parse_printf_format(b"%5$s")), 5);
+        assert_eq!(get_argument_count(&parse_printf_format(b"%*d")), 2);
+        assert_eq!(get_argument_count(&parse_printf_format(b"100%% of %s")), 1);
+    }
+
+    #[test]
+    fn test_scanf_valid_specifiers() {
+        let format = parse_scanf_format(b"%d-%s %5c %ld %[a-z] %[^]] %n");
+        assert_eq!(get_arguments(&format), vec![(0, 'd'), (1, 's'), (2, 'c'), (3, 'd'), (4, '['), (5, '['), (6, 'n')]);
+
+        // Suppressed assignments do not consume arguments.
+        let format = parse_scanf_format(b"%*d %s %*[0-9] %%");
+        assert_eq!(get_arguments(&format), vec![(0, 's')]);
+    }
+
+    #[test]
+    fn test_scanf_invalid_specifiers() {
+        assert_eq!(
+            get_error(parse_scanf_format(b"%y")),
+            Some(("Bad scan conversion character `y`.".to_string(), 0, 2))
+        );
+        assert_eq!(
+            get_error(parse_scanf_format(b"%5")),
+            Some(("Missing format specifier at end of string.".to_string(), 0, 2))
+        );
+        assert_eq!(
+            get_error(parse_scanf_format(b"ab %[a-z")),
+            Some(("Unmatched `[` in format string.".to_string(), 4, 8))
+        );
+        assert_eq!(
+            get_error(parse_scanf_format(b"%0$d")),
+            Some(("Argument number specifier must be greater than zero.".to_string(), 0, 3))
+        );
+    }
+
+    #[test]
+    fn test_scanf_positional_arguments() {
+        let format = parse_scanf_format(b"%2$s %1$d");
+
+        assert_eq!(get_arguments(&format), vec![(1, 's'), (0, 'd')]);
+        assert!(format.placeholders.iter().all(|placeholder| placeholder.positional));
+        assert_eq!(get_argument_count(&format), 2);
+    }
+}
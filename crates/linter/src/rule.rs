@@ -36,6 +36,15 @@ pub trait Rule: for<'a> Walker<LintContext<'a>> + Send + Sync + Debug {
         Some(Level::Error)
     }
 
+    /// Determines whether this rule requires the reference index of the codebase.
+    ///
+    /// Rules returning `true` can rely on [`LintContext::references`] being available, as the
+    /// index is only built when at least one enabled rule requires it.
+    #[inline]
+    fn requires_references(&self) -> bool {
+        false
+    }
+
     /// Lint the entire program for this rule.
     ///
     /// This method is called to apply the rule to the whole [`Program`] AST.
@@ -0,0 +1,69 @@
+use mago_interner::ThreadedInterner;
+use mago_linter::rule::Rule;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+mod rules;
+
+/// Lints the given code with the given rule, and returns the messages of the reported issues.
+pub fn lint(rule: impl Rule + 'static, code: &str) -> Vec<String> {
+    lint_issues(vec![Box::new(rule)], code).into_iter().map(|issue| issue.message).collect()
+}
+
+/// Lints the given code with the given rules, and returns the reported issues.
+pub fn lint_issues(rules: Vec<Box<dyn Rule>>, code: &str) -> Vec<Issue> {
+    lint_issues_with_externals(rules, code, &[])
+}
+
+/// Lints the given code with the given rules, along with the given external sources of the given categories, such
+/// as stubs or vendor packages, and returns the reported issues.
+pub fn lint_issues_with_externals(
+    rules: Vec<Box<dyn Rule>>,
+    code: &str,
+    externals: &[(SourceCategory, &str, &str)],
+) -> Vec<Issue> {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics);
+    for (category, name, content) in externals {
+        let external_id = manager.insert_content(name.to_string(), content.to_string(), *category);
+        let external = Semantics::build(&interner, manager.load(&external_id).expect("source should be loaded"));
+
+        codebase = mago_reflector::merge(codebase, mago_reflector::reflect(&interner, &external));
+    }
+
+    mago_reflector::populate(&interner, &mut codebase);
+
+    lint_semantics(&interner, rules, &semantics, &codebase)
+}
+
+/// Lints the given code with the given rules, and returns the code with the fixes of the reported issues applied.
+pub fn fix(rules: Vec<Box<dyn Rule>>, code: &str) -> String {
+    let issues = IssueCollection::from(lint_issues(rules, code));
+
+    issues.to_fix_plans().into_values().fold(code.to_string(), |code, plan| plan.execute(&code).get_fixed())
+}
+
+fn lint_semantics(
+    interner: &ThreadedInterner,
+    rules: Vec<Box<dyn Rule>>,
+    semantics: &Semantics,
+    codebase: &CodebaseReflection,
+) -> Vec<Issue> {
+    let mut linter = Linter::new(Settings::new(), interner.clone());
+    for rule in rules {
+        linter.add_rule("test", rule);
+    }
+
+    linter.lint(semantics, codebase, None).iter().cloned().collect()
+}
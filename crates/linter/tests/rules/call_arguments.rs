@@ -0,0 +1,91 @@
+use mago_linter::plugin::analysis::rules::call_arguments::CallArgumentsRule;
+
+use crate::lint;
+
+#[test]
+fn test_nullable_argument() {
+    let issues = lint(
+        CallArgumentsRule,
+        r#"<?php
+
+        function f(string $s): void {}
+
+        function g(?string $s): void {
+            f($s);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possibly invalid argument type for parameter `$s` of `f`: `null` is not accepted."]);
+}
+
+#[test]
+fn test_partial_union_argument() {
+    let issues = lint(
+        CallArgumentsRule,
+        r#"<?php
+
+        declare(strict_types=1);
+
+        function f(string $s): void {}
+
+        function g(int|string $value): void {
+            f($value);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possibly invalid argument type for parameter `$s` of `f`: `int` is not accepted."]);
+}
+
+#[test]
+fn test_union_argument_is_coerced_without_strict_types() {
+    let issues = lint(
+        CallArgumentsRule,
+        r#"<?php
+
+        function f(string $s): void {}
+
+        function g(int|string $value): void {
+            f($value);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_incompatible_argument() {
+    let issues = lint(
+        CallArgumentsRule,
+        r#"<?php
+
+        declare(strict_types=1);
+
+        function f(string $s): void {}
+
+        f(1);
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Invalid argument type for parameter `$s` of `f`."]);
+}
+
+#[test]
+fn test_compatible_arguments() {
+    let issues = lint(
+        CallArgumentsRule,
+        r#"<?php
+
+        function f(?string $s, int|string $value, mixed $anything): void {}
+
+        function g(?string $s, mixed $anything): void {
+            f($s, rand() ? 1 : 'a', $anything);
+            f(null, 1, null);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
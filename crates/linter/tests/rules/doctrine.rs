@@ -0,0 +1,343 @@
+use mago_linter::plugin::doctrine::rules::mapping::association_mapping::AssociationMappingRule;
+use mago_linter::plugin::doctrine::rules::mapping::collection_initialization::CollectionInitializationRule;
+use mago_linter::plugin::doctrine::rules::mapping::column_type::ColumnTypeRule;
+use mago_linter::plugin::doctrine::rules::query::dql_syntax::DqlSyntaxRule;
+
+use crate::lint;
+
+#[test]
+fn test_bidirectional_associations() {
+    let issues = lint(
+        AssociationMappingRule,
+        r#"<?php
+
+        use Doctrine\Common\Collections\Collection;
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\Entity]
+        class Post {
+            #[ORM\OneToMany(targetEntity: Comment::class, mappedBy: 'post')]
+            private Collection $comments;
+
+            #[ORM\ManyToOne(inversedBy: 'posts')]
+            private Author $author;
+        }
+
+        #[ORM\Entity]
+        class Comment {
+            #[ORM\ManyToOne(targetEntity: Post::class, inversedBy: 'comments')]
+            private Post $post;
+        }
+
+        #[ORM\Entity]
+        class Author {
+            #[ORM\OneToMany(targetEntity: Post::class, mappedBy: 'author')]
+            private Collection $posts;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_invalid_association_targets() {
+    let issues = lint(
+        AssociationMappingRule,
+        r#"<?php
+
+        use Doctrine\Common\Collections\Collection;
+        use Doctrine\ORM\Mapping as ORM;
+
+        final class Address {}
+
+        #[ORM\Entity]
+        class User {
+            #[ORM\ManyToMany]
+            private Collection $groups;
+
+            #[ORM\ManyToOne(targetEntity: Company::class)]
+            private $company;
+
+            #[ORM\OneToOne]
+            private Address $address;
+
+            #[ORM\OneToMany(targetEntity: User::class)]
+            private Collection $friends;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Association `$groups` does not declare its target entity.",
+            "Association `$company` targets `Company`, which does not exist.",
+            "Association `$address` targets `Address`, which is not an entity.",
+            "Association `$friends` does not declare the property it is mapped by.",
+        ]
+    );
+}
+
+#[test]
+fn test_mismatched_association_sides() {
+    let issues = lint(
+        AssociationMappingRule,
+        r#"<?php
+
+        use Doctrine\Common\Collections\Collection;
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\Entity]
+        class Post {
+            #[ORM\OneToMany(targetEntity: Comment::class, mappedBy: 'article')]
+            private Collection $comments;
+
+            #[ORM\OneToMany(targetEntity: Tag::class, mappedBy: 'name')]
+            private Collection $tags;
+
+            #[ORM\ManyToMany(targetEntity: Category::class, mappedBy: 'posts')]
+            private Collection $categories;
+
+            #[ORM\OneToMany(targetEntity: Vote::class, mappedBy: 'post')]
+            private Collection $votes;
+        }
+
+        #[ORM\Entity]
+        class Comment {
+            #[ORM\ManyToOne(targetEntity: Post::class, inversedBy: 'comments')]
+            private Post $post;
+        }
+
+        #[ORM\Entity]
+        class Tag {
+            #[ORM\Column]
+            private string $name;
+        }
+
+        #[ORM\Entity]
+        class Category {
+            #[ORM\OneToMany(targetEntity: Post::class, mappedBy: 'category')]
+            private Collection $posts;
+        }
+
+        #[ORM\Entity]
+        class Vote {
+            #[ORM\ManyToOne(targetEntity: Post::class, inversedBy: 'likes')]
+            private Post $post;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Association `$comments` is mapped by `Comment::$article`, which does not exist.",
+            "Association `$tags` refers to `Tag::$name`, which is not an association.",
+            "Association `$categories` is a `ManyToMany`, but `Category::$posts` is a `OneToMany`.",
+            "Association `$votes` and `Vote::$post` are not mapped as two sides of the same association.",
+            "Association `$post` and `Post::$comments` are not mapped as two sides of the same association.",
+            "Association `$posts` is mapped by `Post::$category`, which does not exist.",
+            "Association `$post` is inversed by `Post::$likes`, which does not exist.",
+        ]
+    );
+}
+
+#[test]
+fn test_collection_initialization() {
+    let issues = lint(
+        CollectionInitializationRule,
+        r#"<?php
+
+        use Doctrine\Common\Collections\ArrayCollection;
+        use Doctrine\Common\Collections\Collection;
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\Entity]
+        class Post {
+            #[ORM\OneToMany(targetEntity: Comment::class, mappedBy: 'post')]
+            private Collection $comments;
+
+            #[ORM\ManyToMany(targetEntity: Tag::class)]
+            private Collection $tags;
+
+            #[ORM\ManyToMany(targetEntity: Category::class)]
+            private Collection $categories;
+
+            #[ORM\ManyToOne(targetEntity: Author::class)]
+            private ?Author $author = null;
+
+            public function __construct(
+                #[ORM\ManyToMany(targetEntity: User::class)]
+                private Collection $readers = new ArrayCollection(),
+            ) {
+                $this->comments = new ArrayCollection();
+
+                if (true) {
+                    $this->categories = new ArrayCollection();
+                }
+            }
+        }
+
+        #[ORM\Entity]
+        class Author {
+            #[ORM\OneToMany(targetEntity: Post::class, mappedBy: 'author')]
+            private Collection $posts;
+        }
+
+        class NotAnEntity {
+            #[ORM\OneToMany(targetEntity: Post::class, mappedBy: 'author')]
+            private Collection $posts;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Collection `$tags` is not initialized in the constructor.",
+            "Collection `$categories` is not initialized in the constructor.",
+            "Collection `$posts` is not initialized in the constructor.",
+        ]
+    );
+}
+
+#[test]
+fn test_collection_initialization_in_parent_constructor() {
+    let issues = lint(
+        CollectionInitializationRule,
+        r#"<?php
+
+        use Doctrine\Common\Collections\ArrayCollection;
+        use Doctrine\Common\Collections\Collection;
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\MappedSuperclass]
+        abstract class Content {
+            public function __construct() {
+                $this->initialize();
+            }
+
+            abstract protected function initialize(): void;
+        }
+
+        #[ORM\Entity]
+        class Page extends Content {
+            #[ORM\ManyToMany(targetEntity: Page::class)]
+            private Collection $children;
+
+            protected function initialize(): void {
+                $this->children = new ArrayCollection();
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_column_types() {
+    let issues = lint(
+        ColumnTypeRule,
+        r#"<?php
+
+        use Doctrine\DBAL\Types\Types;
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\Entity]
+        class Product {
+            #[ORM\Id]
+            #[ORM\Column(type: 'integer')]
+            private ?int $id = null;
+
+            #[ORM\Column(type: Types::STRING)]
+            private string $name;
+
+            #[ORM\Column(type: 'integer')]
+            private string $stock;
+
+            #[ORM\Column(type: Types::DATETIME_IMMUTABLE)]
+            private DateTime $createdAt;
+
+            #[ORM\Column(type: 'bigint')]
+            private int|string $views;
+
+            #[ORM\Column(type: 'json')]
+            private array $metadata;
+
+            #[ORM\Column(type: 'my_custom_type')]
+            private Money $price;
+
+            #[ORM\Column]
+            private $untyped;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Property `$stock` is mapped to a column of type `integer`, but is declared as `string`.",
+            "Property `$createdAt` is mapped to a column of type `datetime_immutable`, but is declared as `DateTime`.",
+        ]
+    );
+}
+
+#[test]
+fn test_column_nullability() {
+    let issues = lint(
+        ColumnTypeRule,
+        r#"<?php
+
+        use Doctrine\ORM\Mapping as ORM;
+
+        #[ORM\Embeddable]
+        class Address {
+            #[ORM\Column(type: 'string', nullable: true)]
+            private string $street;
+
+            #[ORM\Column(type: 'string')]
+            private ?string $city;
+
+            #[ORM\Column(type: 'string', nullable: true)]
+            private ?string $country;
+
+            #[ORM\Column(type: 'string', nullable: false)]
+            private string $zip;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Property `$street` is mapped to a nullable column, but its type does not accept `null`.",
+            "Property `$city` accepts `null`, but is mapped to a column that is not nullable.",
+        ]
+    );
+}
+
+#[test]
+fn test_dql_syntax() {
+    let issues = lint(
+        DqlSyntaxRule,
+        r#"<?php
+
+        use Doctrine\ORM\EntityManagerInterface;
+
+        function f(EntityManagerInterface $em, Repository $repository): void {
+            $em->createQuery('SELECT u FROM App\Entity\User u WHERE u.id = :id');
+            $em->createQuery(dql: 'SELECT u FROM App\Entity\User u WHERE');
+            $em->createQuery($dynamic);
+            $repository->createQuery('SELECT FROM');
+        }
+
+        final class Repository {
+            public function createQuery(string $sql): void {}
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Invalid DQL query passed to `createQuery()`."]);
+}
@@ -0,0 +1,47 @@
+use mago_linter::plugin::analysis::rules::format_string::FormatStringRule;
+use mago_reporting::Level;
+
+use crate::lint_issues;
+
+fn lint_levels(code: &str) -> Vec<(Level, String)> {
+    lint_issues(vec![Box::new(FormatStringRule)], code).into_iter().map(|issue| (issue.level, issue.message)).collect()
+}
+
+#[test]
+fn test_missing_arguments_are_errors() {
+    let issues = lint_levels(
+        r#"<?php
+
+        printf('%s is %d years old.', $name);
+        vprintf('%1$s, %2$s', ['a']);
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            (Level::Error, "Format string passed to `printf()` requires 2 arguments, but 1 is given.".to_string()),
+            (Level::Error, "Format string passed to `vprintf()` requires 2 values, but 1 is given.".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_unused_arguments_are_warnings() {
+    let issues = lint_levels(
+        r#"<?php
+
+        $greeting = sprintf('Hello, %s!', $name, $title);
+        vprintf('%s', ['a', 'b']);
+        printf('%1$s and %1$s', $name);
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            (Level::Warning, "Value passed to `sprintf()` is not used by the format string.".to_string()),
+            (Level::Warning, "Value passed to `vprintf()` is not used by the format string.".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,71 @@
+use mago_linter::plugin::consistency::rules::ordered_imports::OrderedImportsRule;
+use mago_linter::plugin::redundancy::rules::duplicate_import::DuplicateImportRule;
+use mago_linter::plugin::redundancy::rules::unused_import::UnusedImportRule;
+
+use crate::fix;
+
+const CODE: &str = r#"<?php
+
+namespace App;
+
+use Zeta\Omega;
+use Foo\{Alpha, Beta};
+use function Foo\{helper, unused};
+use Foo\Alpha;
+use Bar\Gamma;
+
+new Alpha();
+new Gamma();
+new Omega();
+helper();
+"#;
+
+#[test]
+fn test_import_fixes_are_combined() {
+    let fixed =
+        fix(vec![Box::new(UnusedImportRule), Box::new(DuplicateImportRule), Box::new(OrderedImportsRule)], CODE);
+
+    assert_eq!(
+        fixed,
+        r#"<?php
+
+namespace App;
+
+use Bar\Gamma;
+use Foo\Alpha;
+use Zeta\Omega;
+
+use function Foo\helper;
+
+new Alpha();
+new Gamma();
+new Omega();
+helper();
+"#
+    );
+}
+
+#[test]
+fn test_import_fixes_skip_disabled_rules() {
+    let fixed = fix(vec![Box::new(UnusedImportRule)], CODE);
+
+    // The duplicate `Foo\Alpha` import is used, and the imports are not sorted.
+    assert_eq!(
+        fixed,
+        r#"<?php
+
+namespace App;
+
+use Zeta\Omega;
+use Foo\Alpha;
+use function Foo\helper;
+use Foo\Alpha;
+use Bar\Gamma;
+
+new Alpha();
+new Gamma();
+new Omega();
+helper();
+"#
+    );
+}
@@ -0,0 +1,219 @@
+use mago_linter::plugin::analysis::rules::inheritance::InheritanceRule;
+
+use crate::lint;
+
+#[test]
+fn test_static_return_type_narrowed_to_self() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Builder {
+            public function with(): static {}
+
+            public function maybe(): ?static {}
+        }
+
+        class QueryBuilder extends Builder {
+            public function with(): self {}
+
+            public function maybe(): ?QueryBuilder {}
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Return type of `QueryBuilder::with()` must be compatible with `static`, as it overrides `Builder::with()`.",
+            "Return type of `QueryBuilder::maybe()` must be compatible with `null|static`, as it overrides `Builder::maybe()`.",
+        ]
+    );
+}
+
+#[test]
+fn test_compatible_return_types() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Builder {
+            public function with(): static {}
+
+            public function copy(): self {}
+
+            public function build(): object {}
+        }
+
+        final class QueryBuilder extends Builder {
+            public function with(): static {}
+
+            public function copy(): static {}
+
+            public function build(): self {}
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_incompatible_return_type() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Builder {
+            public function build(): string {}
+        }
+
+        final class QueryBuilder extends Builder {
+            public function build(): int {}
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec!["Return type of `QueryBuilder::build()` must be compatible with `string`, as it overrides `Builder::build()`."]
+    );
+}
+
+#[test]
+fn test_parameter_contravariance() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        interface Handler {
+            public function handle(int|string $value, Countable $items): void;
+        }
+
+        final class WideHandler implements Handler {
+            public function handle(int|string|null $value, $items): void {}
+        }
+
+        final class NarrowHandler implements Handler {
+            public function handle(int $value, Countable $items): void {}
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec!["Parameter `$value` of `NarrowHandler::handle()` must accept `int|string`, as it overrides `Handler::handle()`."]
+    );
+}
+
+#[test]
+fn test_property_type_invariance() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Model {
+            public int $id;
+            public ?string $name;
+            public $attributes;
+            protected array $casts;
+        }
+
+        final class User extends Model {
+            public int $id;
+            public string $name;
+            public array $attributes;
+            protected $casts;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Type of property `User::$name` must be `null|string`, as it overrides `Model::$name`.",
+            "Property `User::$attributes` cannot declare a type, as it overrides untyped property `Model::$attributes`.",
+            "Type of property `User::$casts` must be `array<array-key, mixed>`, as it overrides `Model::$casts`.",
+        ]
+    );
+}
+
+#[test]
+fn test_property_visibility_narrowing() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Model {
+            public int $id;
+            protected int $version;
+            private int $secret;
+        }
+
+        final class User extends Model {
+            protected int $id;
+            public int $version;
+            private int $secret;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Property `User::$id` cannot be protected, as it overrides public property `Model::$id`."]);
+}
+
+#[test]
+fn test_constant_visibility_narrowing() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        interface HasStatus {
+            const ACTIVE = 'active';
+        }
+
+        class Model implements HasStatus {
+            public const TABLE = 'models';
+            protected const CONNECTION = 'default';
+            private const SECRET = 'secret';
+        }
+
+        final class User extends Model {
+            const ACTIVE = 'enabled';
+            private const TABLE = 'users';
+            public const CONNECTION = 'users';
+            private const SECRET = 'other';
+        }
+
+        final class Post implements HasStatus {
+            protected const ACTIVE = 'published';
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Constant `User::TABLE` cannot be private, as it overrides public constant `Model::TABLE`.",
+            "Constant `Post::ACTIVE` cannot be protected, as it overrides public constant `HasStatus::ACTIVE`.",
+        ]
+    );
+}
+
+#[test]
+fn test_final_constant_override() {
+    let issues = lint(
+        InheritanceRule,
+        r#"<?php
+
+        class Model {
+            final public const TABLE = 'models';
+        }
+
+        final class User extends Model {
+            public const TABLE = 'users';
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Cannot override final constant `Model::TABLE`."]);
+}
@@ -0,0 +1,169 @@
+use mago_linter::plugin::analysis::rules::match_exhaustiveness::MatchExhaustivenessRule;
+
+use crate::fix;
+use crate::lint;
+
+const SUIT: &str = r#"<?php
+
+        enum Suit {
+            case Hearts;
+            case Spades;
+            case Clubs;
+        }
+"#;
+
+#[test]
+fn test_exhaustive_enum_match() {
+    let code = format!(
+        "{}{}",
+        SUIT,
+        r#"
+        function color(Suit $suit): string {
+            return match ($suit) {
+                Suit::Hearts => 'red',
+                Suit::Spades, Suit::Clubs => 'black',
+            };
+        }
+    "#
+    );
+
+    assert!(lint(MatchExhaustivenessRule, &code).is_empty());
+}
+
+#[test]
+fn test_enum_match_missing_case() {
+    let code = format!(
+        "{}{}",
+        SUIT,
+        r#"
+        function color(Suit $suit): string {
+            return match ($suit) {
+                Suit::Hearts => 'red',
+                Suit::Spades => 'black',
+            };
+        }
+    "#
+    );
+
+    assert_eq!(
+        lint(MatchExhaustivenessRule, &code),
+        vec!["Match expression does not handle all possible values of type `Suit`."]
+    );
+
+    let fixed = fix(vec![Box::new(MatchExhaustivenessRule)], &code);
+    assert!(fixed.contains(
+        "                Suit::Spades => 'black',\n                \\Suit::Clubs => throw new \\LogicException('Not implemented.'),\n            };"
+    ));
+}
+
+#[test]
+fn test_enum_match_with_default_arm() {
+    let code = format!(
+        "{}{}",
+        SUIT,
+        r#"
+        function color(Suit $suit): string {
+            return match ($suit) {
+                Suit::Hearts => 'red',
+                default => 'black',
+            };
+        }
+    "#
+    );
+
+    assert!(lint(MatchExhaustivenessRule, &code).is_empty());
+}
+
+#[test]
+fn test_enum_match_duplicate_case() {
+    let code = format!(
+        "{}{}",
+        SUIT,
+        r#"
+        function color(Suit $suit): string {
+            return match ($suit) {
+                Suit::Hearts, Suit::Hearts => 'red',
+                default => 'black',
+            };
+        }
+    "#
+    );
+
+    assert_eq!(
+        lint(MatchExhaustivenessRule, &code),
+        vec!["Match arm condition `\\Suit::Hearts` is already handled by a previous arm."]
+    );
+}
+
+#[test]
+fn test_exhaustive_bool_match() {
+    let issues = lint(
+        MatchExhaustivenessRule,
+        r#"<?php
+
+        function label(bool $flag): string {
+            return match ($flag) {
+                true => 'yes',
+                false => 'no',
+            };
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_bool_match_missing_case() {
+    let code = r#"<?php
+
+        function label(bool $flag): string {
+            return match ($flag) { true => 'yes' };
+        }
+    "#;
+
+    assert_eq!(
+        lint(MatchExhaustivenessRule, code),
+        vec!["Match expression does not handle all possible values of type `bool`."]
+    );
+
+    let fixed = fix(vec![Box::new(MatchExhaustivenessRule)], code);
+    assert!(fixed.contains("match ($flag) { true => 'yes', false => throw new \\LogicException('Not implemented.') };"));
+}
+
+#[test]
+fn test_bool_match_with_default_arm() {
+    let issues = lint(
+        MatchExhaustivenessRule,
+        r#"<?php
+
+        function label(bool $flag): string {
+            return match ($flag) {
+                true => 'yes',
+                default => 'no',
+            };
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_unreachable_condition() {
+    let issues = lint(
+        MatchExhaustivenessRule,
+        r#"<?php
+
+        function label(bool $flag): string {
+            return match ($flag) {
+                true => 'yes',
+                false => 'no',
+                null => 'unknown',
+            };
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Match arm condition `null` can never match a subject of type `bool`."]);
+}
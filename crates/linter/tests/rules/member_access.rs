@@ -0,0 +1,199 @@
+use mago_linter::plugin::analysis::rules::member_access::MemberAccessRule;
+
+use crate::lint;
+use crate::lint_issues;
+
+#[test]
+fn test_undefined_members() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        final class A {}
+
+        function f(A $a): void {
+            $a->foo();
+            echo $a->bar;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Call to undefined method `A::foo()`.", "Access to undefined property `A::$bar`."]);
+}
+
+#[test]
+fn test_write_to_undeclared_property_is_not_reported() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        final class A {
+            public ?A $next = null;
+        }
+
+        function f(A $a): void {
+            $a->newprop = 3;
+            $a->next->other = 3;
+            $a->missing->other = 3;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Access to undefined property `A::$missing`."]);
+}
+
+#[test]
+fn test_write_to_private_property_is_reported() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        final class A {
+            private int $count = 0;
+        }
+
+        function f(A $a): void {
+            $a->count = 3;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Cannot access private property `A::$count` from this scope."]);
+}
+
+#[test]
+fn test_private_member_names_declaring_class() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        class Base {
+            private function secret(): void {}
+
+            protected function hidden(): void {}
+        }
+
+        final class Child extends Base {}
+
+        function f(Child $child): void {
+            $child->secret();
+            $child->hidden();
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Cannot access private method `Base::secret()` from this scope.",
+            "Cannot access protected method `Child::hidden()` from this scope.",
+        ]
+    );
+}
+
+#[test]
+fn test_anonymous_class_display_name() {
+    let issues = lint_issues(
+        vec![Box::new(MemberAccessRule)],
+        r#"<?php
+
+        $object = new class {
+            private function secret(): void {}
+        };
+
+        $object->foo();
+        $object->secret();
+    "#,
+    );
+
+    let messages = issues
+        .iter()
+        .flat_map(|issue| {
+            std::iter::once(issue.message.clone())
+                .chain(issue.annotations.iter().filter_map(|annotation| annotation.message.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    assert!(messages.contains(&"Call to undefined method `class@anonymous::foo()`.".to_string()), "{:?}", messages);
+    assert!(messages.contains(&"This expression is of type `class@anonymous`.".to_string()), "{:?}", messages);
+    assert!(messages.contains(&"Cannot access private method `class@anonymous::secret()` from this scope.".to_string()));
+    assert!(!messages.iter().any(|message| message.contains("anonymous-class@")), "{:?}", messages);
+}
+
+#[test]
+fn test_static_member_visibility() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        class M {
+            private const PC = 1;
+            protected const RC = 2;
+            public const C = 3;
+
+            private static int $pp = 0;
+            protected static int $rp = 0;
+
+            private static function ps(): void {}
+            protected static function rs(): void {}
+            public static function s(): void {}
+
+            public static function inside(): void {
+                self::ps();
+                static::rs();
+                echo self::$pp, static::$rp, self::PC, static::RC;
+            }
+        }
+
+        final class N extends M {
+            public static function child(): void {
+                parent::rs();
+                echo static::$rp, self::RC;
+                parent::ps();
+            }
+        }
+
+        function f(): void {
+            M::ps();
+            M::rs();
+            M::s();
+            echo M::$pp, M::$rp, M::PC, M::RC, M::C, M::class;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Cannot access private method `M::ps()` from this scope.",
+            "Cannot access private method `M::ps()` from this scope.",
+            "Cannot access protected method `M::rs()` from this scope.",
+            "Cannot access private property `M::$pp` from this scope.",
+            "Cannot access protected property `M::$rp` from this scope.",
+            "Cannot access private constant `M::PC` from this scope.",
+            "Cannot access protected constant `M::RC` from this scope.",
+        ]
+    );
+}
+
+#[test]
+fn test_static_access_to_unknown_members_is_not_reported() {
+    let issues = lint(
+        MemberAccessRule,
+        r#"<?php
+
+        abstract class Model {
+            public static function create(): static {
+                return static::make();
+            }
+        }
+
+        function f(): void {
+            Model::missing();
+            echo Model::MISSING, Unknown::FOO;
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
@@ -0,0 +1,269 @@
+use mago_linter::plugin::migration::rules::php83::json_validate::JsonValidateRule;
+use mago_linter::plugin::migration::rules::php83::override_attribute::OverrideAttributeRule;
+use mago_linter::plugin::migration::rules::php83::typed_class_constant::TypedClassConstantRule;
+use mago_linter::plugin::migration::rules::php84::array_find::ArrayFindRule;
+use mago_linter::plugin::migration::rules::php84::new_without_parentheses::NewWithoutParenthesesRule;
+use mago_linter::plugin::strictness::rules::require_constant_type::RequireConstantTypeRule;
+
+use crate::fix;
+use crate::lint;
+use crate::lint_issues;
+
+#[test]
+fn test_json_validate() {
+    let code = r#"<?php
+
+        function is_valid(string $json): bool {
+            json_decode($json);
+            return json_last_error() === JSON_ERROR_NONE;
+        }
+
+        function is_invalid(string $json): bool {
+            json_decode($json, true, 32);
+            return JSON_ERROR_NONE !== json_last_error();
+        }
+
+        function decode(string $json): mixed {
+            $data = json_decode($json);
+            if (json_last_error() !== JSON_ERROR_NONE) {
+                return null;
+            }
+
+            return $data;
+        }
+
+        function with_flags(string $json): bool {
+            json_decode($json, true, 32, JSON_BIGINT_AS_STRING);
+            return json_last_error() === JSON_ERROR_NONE;
+        }
+    "#;
+
+    assert_eq!(lint(JsonValidateRule, code).len(), 2);
+
+    let fixed = fix(vec![Box::new(JsonValidateRule)], code);
+    assert!(fixed.contains("return json_validate($json);"));
+    assert!(fixed.contains("return !json_validate($json, 32);"));
+    assert!(fixed.contains("$data = json_decode($json);"));
+}
+
+#[test]
+fn test_override_attribute() {
+    let code = r#"<?php
+
+        interface Shape {
+            public function area(): float;
+        }
+
+        abstract class Base implements Shape {
+            public function __construct() {}
+
+            public function name(): string {
+                return 'base';
+            }
+        }
+
+        final class Square extends Base {
+            public function __construct() {}
+
+            public function area(): float {
+                return 1.0;
+            }
+
+            #[\Override]
+            public function name(): string {
+                return 'square';
+            }
+
+            public function side(): float {
+                return 1.0;
+            }
+        }
+    "#;
+
+    assert_eq!(
+        lint(OverrideAttributeRule, code),
+        vec!["Method `area()` implements `Shape::area()`, but is not marked with the `#[\\Override]` attribute."]
+    );
+
+    let fixed = fix(vec![Box::new(OverrideAttributeRule)], code);
+    assert!(fixed.contains("#[\\Override]\n            public function area(): float {"));
+}
+
+#[test]
+fn test_typed_class_constant() {
+    let code = r#"<?php
+
+        final class Config {
+            const LIMIT = 10;
+            const RATIO = -1.5, SCALE = 2.0;
+            const NAME = 'config';
+            const MIXED = 1, OTHER = 'a';
+            const int TYPED = 1;
+            const COMPUTED = self::LIMIT * 2;
+        }
+    "#;
+
+    assert_eq!(
+        lint(TypedClassConstantRule, code),
+        vec![
+            "Class constant `LIMIT` can declare its type.",
+            "Class constants `RATIO`, `SCALE` can declare their type.",
+            "Class constant `NAME` can declare its type.",
+        ]
+    );
+
+    let fixed = fix(vec![Box::new(TypedClassConstantRule)], code);
+    assert!(fixed.contains("const int LIMIT = 10;"));
+    assert!(fixed.contains("const float RATIO = -1.5, SCALE = 2.0;"));
+    assert!(fixed.contains("const string NAME = 'config';"));
+    assert!(fixed.contains("const MIXED = 1, OTHER = 'a';"));
+}
+
+#[test]
+fn test_typed_class_constant_defers_to_require_constant_type() {
+    let code = r#"<?php
+
+        final class Config {
+            const LIMIT = 10;
+        }
+    "#;
+
+    let issues = lint_issues(vec![Box::new(TypedClassConstantRule), Box::new(RequireConstantTypeRule)], code);
+    let messages = issues.into_iter().map(|issue| issue.message).collect::<Vec<_>>();
+
+    assert_eq!(messages, vec!["Class constant `LIMIT` is missing a type hint."]);
+}
+
+#[test]
+fn test_array_find() {
+    let code = r#"<?php
+
+        /** @param list<int> $items */
+        function find(array $items): ?int {
+            foreach ($items as $item) {
+                if ($item > 10) {
+                    return $item;
+                }
+            }
+
+            return null;
+        }
+
+        /** @param array<string, int> $items */
+        function find_key(array $items): ?string {
+            foreach ($items as $key => $item) {
+                if ($item > 10) {
+                    return $key;
+                }
+            }
+
+            return null;
+        }
+
+        function any(array $items): bool {
+            foreach ($items as $item) {
+                if ($item > 10) {
+                    return true;
+                }
+            }
+
+            return false;
+        }
+
+        function all(array $items): bool {
+            foreach ($items as $item) {
+                if (!is_int($item)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+    "#;
+
+    assert_eq!(
+        lint(ArrayFindRule, code),
+        vec![
+            "Loop can be replaced with a call to `array_find()`.",
+            "Loop can be replaced with a call to `array_find_key()`.",
+            "Loop can be replaced with a call to `array_any()`.",
+            "Loop can be replaced with a call to `array_all()`.",
+        ]
+    );
+
+    let fixed = fix(vec![Box::new(ArrayFindRule)], code);
+    assert!(fixed.contains("return array_find($items, fn($item) => $item > 10);"));
+    assert!(fixed.contains("return array_find_key($items, fn($item, $key) => $item > 10);"));
+    assert!(fixed.contains("return array_any($items, fn($item) => $item > 10);"));
+    assert!(fixed.contains("return array_all($items, fn($item) => is_int($item));"));
+}
+
+#[test]
+fn test_array_find_requires_known_array() {
+    let issues = lint(
+        ArrayFindRule,
+        r#"<?php
+
+        function untyped($items): bool {
+            foreach ($items as $item) {
+                if ($item > 10) {
+                    return true;
+                }
+            }
+
+            return false;
+        }
+
+        function iterable(iterable $items): bool {
+            foreach ($items as $item) {
+                if ($item > 10) {
+                    return true;
+                }
+            }
+
+            return false;
+        }
+
+        function traversable(\Traversable $items): bool {
+            foreach ($items as $item) {
+                if ($item > 10) {
+                    return true;
+                }
+            }
+
+            return false;
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_new_without_parentheses() {
+    let code = r#"<?php
+
+        final class Builder {
+            const VERSION = 1;
+
+            public function build(): array {
+                return [];
+            }
+        }
+
+        function f(): void {
+            (new Builder())->build();
+            (new Builder)->build();
+            echo (new Builder())::VERSION;
+            new Builder()->build();
+            $builder = (new Builder());
+        }
+    "#;
+
+    assert_eq!(lint(NewWithoutParenthesesRule, code).len(), 3);
+
+    let fixed = fix(vec![Box::new(NewWithoutParenthesesRule)], code);
+    assert!(fixed.contains("            new Builder()->build();\n            new Builder()->build();\n"));
+    assert!(fixed.contains("echo new Builder()::VERSION;"));
+    assert!(fixed.contains("$builder = (new Builder());"));
+}
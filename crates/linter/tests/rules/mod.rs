@@ -0,0 +1,14 @@
+mod call_arguments;
+mod doctrine;
+mod format_string;
+mod imports;
+mod inheritance;
+mod match_exhaustiveness;
+mod member_access;
+mod migration;
+mod null_to_internal_parameter;
+mod phpunit;
+mod property_hooks;
+mod readonly_property;
+mod return_type;
+mod taint;
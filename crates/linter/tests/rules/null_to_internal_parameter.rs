@@ -0,0 +1,112 @@
+use mago_fixer::SafetyClassification;
+use mago_linter::plugin::deprecation::rules::php81::null_to_internal_parameter::NullToInternalParameterRule;
+use mago_source::SourceCategory;
+
+use crate::lint_issues_with_externals;
+
+const STUBS: (SourceCategory, &str, &str) = (
+    SourceCategory::BuiltIn,
+    "stubs/standard/standard_1.php",
+    r#"<?php
+
+function strlen(string $string): int {}
+
+function str_repeat(string $string, int $times): string {}
+
+class DateTime {
+    public function modify(string $modifier): DateTime|false {}
+}
+"#,
+);
+
+const VENDOR: (SourceCategory, &str, &str) = (
+    SourceCategory::External,
+    "vendor/acme/text/src/functions.php",
+    r#"<?php
+
+namespace Acme\Text;
+
+function slugify(string $text): string {}
+"#,
+);
+
+fn lint(code: &str) -> Vec<mago_reporting::Issue> {
+    lint_issues_with_externals(vec![Box::new(NullToInternalParameterRule)], code, &[STUBS, VENDOR])
+}
+
+#[test]
+fn test_null_passed_to_built_in_function() {
+    let issues = lint(
+        r#"<?php
+
+echo strlen(null);
+echo str_repeat('-', times: null);
+echo strlen('null');
+"#,
+    );
+
+    assert_eq!(
+        issues.iter().map(|issue| issue.message.as_str()).collect::<Vec<_>>(),
+        vec![
+            "Passing `null` to non-nullable parameter `$string` of internal function `strlen()` is deprecated since PHP 8.1.",
+            "Passing `null` to non-nullable parameter `$times` of internal function `str_repeat()` is deprecated since PHP 8.1.",
+        ]
+    );
+
+    let (_, plan) = &issues[0].suggestions[0];
+    assert_eq!(plan.get_minimum_safety_classification(), SafetyClassification::PotentiallyUnsafe);
+    assert_eq!(plan.execute("<?php\n\necho strlen(null);").get_fixed(), "<?php\n\necho strlen('');");
+}
+
+#[test]
+fn test_null_passed_to_built_in_method() {
+    let issues = lint(
+        r#"<?php
+
+final class Date extends DateTime {}
+
+function f(DateTime $date, Date $other): void {
+    $date->modify(null);
+    $other->modify(null);
+}
+"#,
+    );
+
+    assert_eq!(
+        issues.iter().map(|issue| issue.message.as_str()).collect::<Vec<_>>(),
+        vec![
+            "Passing `null` to non-nullable parameter `$modifier` of internal method `DateTime::modify()` is deprecated since PHP 8.1.",
+            "Passing `null` to non-nullable parameter `$modifier` of internal method `DateTime::modify()` is deprecated since PHP 8.1.",
+        ]
+    );
+}
+
+#[test]
+fn test_null_passed_to_userland_function() {
+    let issues = lint(
+        r#"<?php
+
+function local(string $text): string {
+    return $text;
+}
+
+echo local(null);
+echo Acme\Text\slugify(null);
+"#,
+    );
+
+    // Functions declared in PHP code, in the project or in its dependencies, never coerced `null`.
+    assert!(issues.is_empty(), "{:?}", issues.iter().map(|issue| &issue.message).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_built_in_functions_are_determined_by_source_category() {
+    // A copy of the stubs shipped by a dependency is not where PHP declares its functions.
+    let issues = lint_issues_with_externals(
+        vec![Box::new(NullToInternalParameterRule)],
+        "<?php\n\necho strlen(null);\n",
+        &[(SourceCategory::External, "vendor/jetbrains/phpstorm-stubs/standard/standard_1.php", STUBS.2)],
+    );
+
+    assert!(issues.is_empty(), "{:?}", issues.iter().map(|issue| &issue.message).collect::<Vec<_>>());
+}
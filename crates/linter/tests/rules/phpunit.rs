@@ -0,0 +1,131 @@
+use mago_linter::plugin::phpunit::rules::correctness::data_provider::DataProviderRule;
+use mago_linter::plugin::phpunit::rules::correctness::missing_assertions::MissingAssertionsRule;
+
+use crate::lint;
+
+#[test]
+fn test_missing_assertions_in_test_case() {
+    let issues = lint(
+        MissingAssertionsRule,
+        r#"<?php
+
+        use PHPUnit\Framework\TestCase;
+
+        final class UserTest extends TestCase {
+            public function testName(): void {
+                $user = new User('foo');
+            }
+
+            public function testEmail(): void {
+                $this->assertSame('foo@example.com', (new User('foo'))->email);
+            }
+
+            public function testAge(): void {
+                $this->expectException(InvalidArgumentException::class);
+
+                new User('foo', age: -1);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Test `testName()` does not perform any assertions."]);
+}
+
+#[test]
+fn test_missing_assertions_in_non_test_class() {
+    let issues = lint(
+        MissingAssertionsRule,
+        r#"<?php
+
+        final class UserFactory {
+            public function testName(): void {
+                $user = new User('foo');
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_missing_assertions_with_unresolved_parent() {
+    let issues = lint(
+        MissingAssertionsRule,
+        r#"<?php
+
+        use App\Testing\Base;
+
+        final class UserTest extends Base {
+            public function testName(): void {
+                $user = new User('foo');
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_data_provider_in_test_case() {
+    let issues = lint(
+        DataProviderRule,
+        r#"<?php
+
+        use PHPUnit\Framework\Attributes\DataProvider;
+        use PHPUnit\Framework\TestCase;
+
+        final class MathTest extends TestCase {
+            public static function provideSums(): iterable {
+                yield [1, 2, 3];
+                yield [1, 2];
+            }
+
+            #[DataProvider('provideSums')]
+            public function testSum(int $a, int $b, int $expected): void {
+                $this->assertSame($expected, $a + $b);
+            }
+
+            #[DataProvider('provideProducts')]
+            public function testProduct(int $a, int $b, int $expected): void {
+                $this->assertSame($expected, $a * $b);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Data set provided by `provideSums()` has 2 value(s), but test `testSum()` expects 3.",
+            "Data provider `provideProducts()` of test `testProduct()` does not exist.",
+        ]
+    );
+}
+
+#[test]
+fn test_missing_assertions_in_redeclared_class() {
+    let issues = lint(
+        MissingAssertionsRule,
+        r#"<?php
+
+        use PHPUnit\Framework\TestCase;
+
+        final class UserTest extends TestCase {
+            public function testName(): void {
+                $user = new User('foo');
+            }
+        }
+
+        final class UserTest {
+            public function testEmail(): void {
+                $user = new User('foo');
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Test `testName()` does not perform any assertions."]);
+}
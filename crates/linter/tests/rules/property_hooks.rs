@@ -0,0 +1,194 @@
+use mago_fixer::SafetyClassification;
+use mago_linter::plugin::migration::rules::php84::property_hooks::PropertyHooksRule;
+
+use crate::fix;
+use crate::lint;
+use crate::lint_issues;
+
+#[test]
+fn test_readonly_property() {
+    let code = r#"<?php
+
+final class User {
+    public function __construct(private readonly string $name) {}
+}
+
+final class Post {
+    private readonly string $title;
+
+    public function __construct(string $title) {
+        $this->title = $title;
+    }
+
+    public function getTitle(): string {
+        return $this->title;
+    }
+}
+"#;
+
+    assert_eq!(
+        lint(PropertyHooksRule, code),
+        vec!["Getter `getTitle()` can be replaced with a public readonly property."]
+    );
+
+    let fixed = fix(vec![Box::new(PropertyHooksRule)], code);
+
+    // Only the visibility is changed, the getter is kept for its callers.
+    assert!(fixed.contains("    public readonly string $title;"));
+    assert!(fixed.contains("public function getTitle(): string {"));
+}
+
+#[test]
+fn test_readonly_class_is_ignored() {
+    let issues = lint(
+        PropertyHooksRule,
+        r#"<?php
+
+final readonly class Post {
+    private string $title;
+
+    public function getTitle(): string {
+        return $this->title;
+    }
+}
+"#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_asymmetric_visibility() {
+    let code = r#"<?php
+
+final class Counter {
+    private int $count = 0;
+
+    public function getCount(): int {
+        return $this->count;
+    }
+
+    public function increment(): void {
+        $this->count++;
+    }
+}
+"#;
+
+    let issues = lint_issues(vec![Box::new(PropertyHooksRule)], code);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].message, "Getter `getCount()` can be replaced with asymmetric visibility.");
+    assert_eq!(
+        issues[0].help.as_deref(),
+        Some("Declare the property as `public private(set)`, then replace the calls to `getCount()` with property accesses, and remove it.")
+    );
+
+    let (_, plan) = &issues[0].suggestions[0];
+    assert_eq!(plan.get_minimum_safety_classification(), SafetyClassification::PotentiallyUnsafe);
+
+    let fixed = fix(vec![Box::new(PropertyHooksRule)], code);
+
+    assert!(fixed.contains("    public private(set) int $count = 0;"));
+    assert!(fixed.contains("public function getCount(): int {"));
+}
+
+#[test]
+fn test_protected_property_without_setter_is_ignored() {
+    let issues = lint(
+        PropertyHooksRule,
+        r#"<?php
+
+class Counter {
+    protected int $count = 0;
+
+    public function getCount(): int {
+        return $this->count;
+    }
+}
+"#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_trivial_setter() {
+    let code = r#"<?php
+
+final class User {
+    private string $name = '';
+
+    public function getName(): string {
+        return $this->name;
+    }
+
+    public function setName(string $name): void {
+        $this->name = $name;
+    }
+}
+"#;
+
+    assert_eq!(
+        lint(PropertyHooksRule, code),
+        vec!["Getter `getName()` and setter `setName()` can be replaced with a public property."]
+    );
+
+    let fixed = fix(vec![Box::new(PropertyHooksRule)], code);
+
+    assert!(fixed.contains("    public string $name = '';"));
+    assert!(fixed.contains("public function getName(): string {"));
+    assert!(fixed.contains("public function setName(string $name): void {"));
+}
+
+#[test]
+fn test_set_hook() {
+    let code = r#"<?php
+
+final class User {
+    private string $email = '';
+
+    public function getEmail(): string {
+        return $this->email;
+    }
+
+    public function setEmail(string $email): void {
+        $email = strtolower($email);
+        $this->email = $email;
+    }
+}
+"#;
+
+    let issues = lint_issues(vec![Box::new(PropertyHooksRule)], code);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].message, "Getter `getEmail()` and setter `setEmail()` can be replaced with a property hook.");
+
+    // Moving the logic of the setter to a hook would run it twice when the setter is called, so there is no fix.
+    assert!(issues[0].suggestions.is_empty());
+    assert_eq!(fix(vec![Box::new(PropertyHooksRule)], code), code);
+}
+
+#[test]
+fn test_fluent_setter_is_ignored() {
+    let issues = lint(
+        PropertyHooksRule,
+        r#"<?php
+
+final class User {
+    private string $name = '';
+
+    public function getName(): string {
+        return $this->name;
+    }
+
+    public function setName(string $name): static {
+        $this->name = $name;
+
+        return $this;
+    }
+}
+"#,
+    );
+
+    assert!(issues.is_empty());
+}
@@ -0,0 +1,171 @@
+use mago_linter::plugin::analysis::rules::readonly_property::ReadonlyPropertyRule;
+
+use crate::lint;
+
+#[test]
+fn test_write_from_outside_of_class() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class Point {
+            public function __construct(
+                public readonly int $x,
+            ) {}
+        }
+
+        function move(Point $point): void {
+            $point->x = 2;
+            $point->x++;
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Cannot modify readonly property `Point::$x` from outside of its class.",
+            "Cannot modify readonly property `Point::$x` from outside of its class.",
+        ]
+    );
+}
+
+#[test]
+fn test_write_twice_in_constructor() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class User {
+            public readonly string $name;
+
+            public function __construct(string $name) {
+                $this->name = $name;
+                $this->name = strtolower($name);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Cannot modify readonly property `User::$name`, as it is already initialized."]);
+}
+
+#[test]
+fn test_write_to_promoted_property_in_constructor() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class User {
+            public function __construct(
+                public readonly string $name,
+            ) {
+                $this->name = strtolower($name);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Cannot modify readonly property `User::$name`, as it is already initialized."]);
+}
+
+#[test]
+fn test_single_initialization_is_not_reported() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class User {
+            public readonly string $name;
+
+            public function __construct(string $name) {
+                if ($name === '') {
+                    throw new \InvalidArgumentException('The name cannot be empty.');
+                }
+
+                $this->name = $name;
+            }
+
+            public function getName(): string {
+                return $this->name;
+            }
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_modification_within_class() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class Counter {
+            public function __construct(
+                private readonly int $count,
+                private readonly array $items,
+            ) {}
+
+            public function increment(): void {
+                $this->count += 1;
+                $this->items[] = 1;
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Cannot modify readonly property `Counter::$count` using a compound assignment.",
+            "Cannot modify the elements of readonly property `Counter::$items`.",
+        ]
+    );
+}
+
+#[test]
+fn test_write_to_clone() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class Money {
+            public function __construct(
+                public readonly int $amount,
+            ) {}
+
+            public function withAmount(int $amount): static {
+                $clone = clone $this;
+                $clone->amount = $amount;
+
+                return $clone;
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Cannot modify readonly property `Money::$amount` of a cloned object."]);
+}
+
+#[test]
+fn test_reinitialization_in_clone_method_is_not_reported() {
+    let issues = lint(
+        ReadonlyPropertyRule,
+        r#"<?php
+
+        final class Money {
+            public function __construct(
+                public readonly int $amount,
+            ) {}
+
+            public function __clone() {
+                $this->amount = 0;
+            }
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
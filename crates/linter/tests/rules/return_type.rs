@@ -0,0 +1,303 @@
+use mago_linter::plugin::analysis::rules::return_type::ReturnTypeRule;
+
+use crate::lint;
+
+#[test]
+fn test_nullable_return() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function h(?string $s): string {
+            return $s;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`h` may return `null`, but is declared to return `string`."]);
+}
+
+#[test]
+fn test_partial_union_return() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function h(): string {
+            return rand() ? 'a' : null;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`h` may return `null`, but is declared to return `string`."]);
+}
+
+#[test]
+fn test_partial_union_return_names_every_incompatible_member() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        declare(strict_types=1);
+
+        function h(int|string|null $value): string {
+            return $value;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`h` may return `int|null`, but is declared to return `string`."]);
+}
+
+#[test]
+fn test_incompatible_return() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        declare(strict_types=1);
+
+        function h(): string {
+            return 1;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`h` returns `1`, but is declared to return `string`."]);
+}
+
+#[test]
+fn test_compatible_returns() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function f(?string $s): ?string {
+            return $s;
+        }
+
+        function g(): string|int {
+            return rand() ? 'a' : 1;
+        }
+
+        function h(mixed $value): string {
+            return $value;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_partial_union_yield() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        /** @return Generator<int, string, mixed, void> */
+        function h(?string $s): Generator {
+            yield $s;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`h` may yield a value of type `null`, but is declared to yield values of type `string`."]);
+}
+
+#[test]
+fn test_narrowed_returns() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function f(?string $s): string {
+            if ($s === null) {
+                return '';
+            }
+
+            return $s;
+        }
+
+        function g(?string $s): string {
+            if ($s !== null) {
+                return $s;
+            }
+
+            return '';
+        }
+
+        function h(?string $s): string {
+            return $s ?? '';
+        }
+
+        function i(int|string $value): string {
+            if (is_string($value)) {
+                return $value;
+            }
+
+            return (string) $value;
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_missing_return_in_branch() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function f(bool $flag): string {
+            if ($flag) {
+                return 'yes';
+            } else {
+                echo 'no';
+            }
+        }
+
+        function g(int $value): string {
+            if ($value > 0) {
+                return 'positive';
+            } elseif ($value < 0) {
+                return 'negative';
+            }
+        }
+
+        function h(int $value): string {
+            if ($value > 0) {
+                return 'positive';
+            } elseif ($value < 0) {
+                return 'negative';
+            } else {
+                return 'zero';
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Not all code paths of `f` return a value.", "Not all code paths of `g` return a value."]);
+}
+
+#[test]
+fn test_missing_return_in_switch() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function falls_through(int $value): string {
+            switch ($value) {
+                case 1:
+                    echo 'one';
+                case 2:
+                    return 'small';
+                default:
+                    return 'large';
+            }
+        }
+
+        function breaks_out(int $value): string {
+            switch ($value) {
+                case 1:
+                    echo 'one';
+                    break;
+                default:
+                    return 'large';
+            }
+        }
+
+        function without_default(int $value): string {
+            switch ($value) {
+                case 1:
+                    return 'one';
+                case 2:
+                    return 'two';
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(
+        issues,
+        vec![
+            "Not all code paths of `breaks_out` return a value.",
+            "Not all code paths of `without_default` return a value.",
+        ]
+    );
+}
+
+#[test]
+fn test_throw_and_exit_terminate() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function fail(string $message): never {
+            throw new RuntimeException($message);
+        }
+
+        function f(?string $value): string {
+            if ($value !== null) {
+                return $value;
+            }
+
+            throw new InvalidArgumentException('Missing value.');
+        }
+
+        function g(?string $value): string {
+            if ($value !== null) {
+                return $value;
+            }
+
+            exit(1);
+        }
+
+        function h(?string $value): string {
+            if ($value !== null) {
+                return $value;
+            }
+
+            fail('Missing value.');
+        }
+
+        function i(?string $value): string {
+            try {
+                return $value ?? '';
+            } catch (Throwable $e) {
+                throw new LogicException('Unreachable.', 0, $e);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(issues, Vec::<String>::new());
+}
+
+#[test]
+fn test_never_return_type() {
+    let issues = lint(
+        ReturnTypeRule,
+        r#"<?php
+
+        function f(): never {
+            while (true) {
+                sleep(1);
+            }
+        }
+
+        function g(bool $flag): never {
+            if ($flag) {
+                throw new RuntimeException('Failed.');
+            }
+        }
+
+        function h(): void {
+            echo 'done';
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["`g` is declared to never return, but may return implicitly."]);
+}
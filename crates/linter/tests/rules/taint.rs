@@ -0,0 +1,200 @@
+use mago_linter::plugin::taint::rules::command_injection::CommandInjectionRule;
+use mago_linter::plugin::taint::rules::cross_site_scripting::CrossSiteScriptingRule;
+use mago_linter::plugin::taint::rules::sql_injection::SqlInjectionRule;
+
+use crate::lint;
+
+#[test]
+fn test_source_reaching_sink() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            mysqli_query($connection, 'SELECT * FROM users WHERE id = ' . $_GET['id']);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possible SQL injection: user input reaches `mysqli_query()`."]);
+}
+
+#[test]
+fn test_source_passed_to_unchecked_argument_of_sink() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(): void {
+            mysqli_query($_GET['connection'], 'SELECT * FROM users');
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_sanitizer() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            $id = mysqli_real_escape_string($connection, $_GET['id']);
+
+            mysqli_query($connection, "SELECT * FROM users WHERE id = '$id'");
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_untainted_function() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            mysqli_query($connection, 'SELECT * FROM users WHERE id = ' . intval($_GET['id']));
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_propagation_through_assignments_and_interpolation() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            $name = $_POST['name'];
+            $filters = ['name' => $name];
+            $query = "SELECT * FROM users WHERE name = '{$filters['name']}'";
+            $query .= ' LIMIT 1';
+
+            mysqli_query($connection, $query);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possible SQL injection: user input reaches `mysqli_query()`."]);
+}
+
+#[test]
+fn test_propagation_through_function_summaries() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            run($connection, where_name(read_name()));
+        }
+
+        function read_name(): string {
+            return $_COOKIE['name'];
+        }
+
+        function where_name(string $name): string {
+            return "WHERE name = '" . $name . "'";
+        }
+
+        function run(mysqli $connection, string $clause): void {
+            $connection->query('SELECT * FROM users ' . $clause);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possible SQL injection: user input reaches `mysqli::query()`."]);
+}
+
+#[test]
+fn test_reassignment_clears_taint() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            $id = $_GET['id'];
+            $id = 1;
+
+            mysqli_query($connection, 'SELECT * FROM users WHERE id = ' . $id);
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_sprintf_numeric_conversions_sanitize() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE id = %d', $_GET['id']));
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE id = %u', $_GET['id']));
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE score > %.2f', $_GET['score']));
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE score > %e', $_GET['score']));
+            mysqli_query($connection, sprintf('SELECT * FROM users LIMIT %2$d OFFSET %1$d', $_GET['offset'], $_GET['limit']));
+        }
+    "#,
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_sprintf_string_conversions_propagate() {
+    let issues = lint(
+        SqlInjectionRule,
+        r#"<?php
+
+        function find(mysqli $connection): void {
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE id = %d AND name = %s', $_GET['id'], $_GET['name']));
+            mysqli_query($connection, sprintf('SELECT * FROM users WHERE id = %1$d OR code = %1$s', $_GET['id']));
+            mysqli_query($connection, sprintf($_GET['format'], 1));
+        }
+    "#,
+    );
+
+    assert_eq!(issues.len(), 3);
+}
+
+#[test]
+fn test_cross_site_scripting() {
+    let issues = lint(
+        CrossSiteScriptingRule,
+        r#"<?php
+
+        function greet(): void {
+            echo 'Hello, ' . $_GET['name'];
+            echo 'Hello, ' . htmlspecialchars($_GET['name']);
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possible cross-site scripting: user input reaches `echo`."]);
+}
+
+#[test]
+fn test_command_injection() {
+    let issues = lint(
+        CommandInjectionRule,
+        r#"<?php
+
+        function archive(): void {
+            exec('tar -czf archive.tar.gz ' . $_POST['path']);
+            exec('tar -czf archive.tar.gz ' . escapeshellarg($_POST['path']));
+        }
+    "#,
+    );
+
+    assert_eq!(issues, vec!["Possible command injection: user input reaches `exec()`."]);
+}
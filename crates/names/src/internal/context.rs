@@ -71,6 +71,13 @@ impl<'a> NameContext<'a> {
             constant_aliases: previous_context.constant_aliases.clone(),
         });
 
+        // The global namespace, declared using `namespace { ... }`, has an empty name.
+        if namespace_name.is_empty() {
+            self.namespace_name = None;
+
+            return;
+        }
+
         self.namespace_name = Some(if let Some(mut previous_namespace) = self.namespace_name.clone() {
             previous_namespace.push('\\');
             previous_namespace.push_str(namespace_name);
@@ -87,8 +94,11 @@ impl<'a> NameContext<'a> {
         }
 
         self.name_resolution_contexts.pop();
-        self.namespace_name =
-            self.name_resolution_contexts.last().map(|last_context| last_context.namespace_name.clone());
+        self.namespace_name = self
+            .name_resolution_contexts
+            .last()
+            .map(|last_context| last_context.namespace_name.clone())
+            .filter(|namespace_name| !namespace_name.is_empty());
     }
 
     pub fn add_name(&mut self, kind: NameKind, name_id: StringIdentifier, alias_id: Option<StringIdentifier>) {
@@ -172,7 +182,7 @@ impl<'a> NameContext<'a> {
                 resolved.push('\\');
                 resolved.push_str(&suffix);
 
-                return Some(self.interner.intern(alias));
+                return Some(self.interner.intern(resolved));
             }
         } else {
             let alias = match kind {
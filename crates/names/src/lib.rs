@@ -4,6 +4,7 @@ use ahash::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use mago_ast::Identifier;
 use mago_ast::Program;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
@@ -101,6 +102,26 @@ impl Names {
         self.names.get(&position.position().offset).map(|(_, imported)| *imported).unwrap_or(false)
     }
 
+    /// Returns the name in the global namespace that the given function or constant name falls back to.
+    ///
+    /// Unqualified function and constant names that are not imported fall back to the global namespace
+    /// when no function or constant exists under their resolved name, as they do in PHP.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - A reference to the identifier of a function or constant.
+    ///
+    /// # Returns
+    ///
+    /// The `StringIdentifier` of the name in the global namespace, or `None` if the name does not fall back.
+    pub fn get_global_fallback(&self, identifier: &Identifier) -> Option<StringIdentifier> {
+        if !matches!(identifier, Identifier::Local(_)) || self.is_imported(identifier) {
+            return None;
+        }
+
+        Some(identifier.value())
+    }
+
     /// Inserts a resolved name at the given position.
     ///
     /// This method is intended for internal use within the crate.
@@ -0,0 +1,115 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+use mago_walker::Walker;
+
+/// Resolves the names in the given code, and returns the name resolved at the first occurrence of `needle`.
+fn resolve_at(code: &str, needle: &str) -> (String, bool) {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+    let (program, error) = parse_source(&interner, &source);
+    assert!(error.is_none(), "unexpected parse error: {:?}", error);
+
+    let names = Names::resolve(&interner, &program);
+    let offset = code.find(needle).expect("needle should be in the code");
+    let (name, imported) = names
+        .all()
+        .into_iter()
+        .find_map(|(position, name)| if *position == offset { Some(*name) } else { None })
+        .expect("a name should be resolved at the needle");
+
+    (interner.lookup(&name).to_string(), imported)
+}
+
+#[test]
+fn test_resolve_in_namespace() {
+    let code = "<?php namespace App; new Foo();";
+
+    assert_eq!(resolve_at(code, "Foo"), ("App\\Foo".to_string(), false));
+}
+
+#[test]
+fn test_resolve_fully_qualified_name() {
+    let code = "<?php namespace App; new \\Lib\\Foo();";
+
+    assert_eq!(resolve_at(code, "\\Lib\\Foo"), ("Lib\\Foo".to_string(), true));
+}
+
+#[test]
+fn test_resolve_in_second_namespace() {
+    let code = "<?php namespace First; new Foo(); namespace Second; new Bar();";
+
+    assert_eq!(resolve_at(code, "Foo"), ("First\\Foo".to_string(), false));
+    assert_eq!(resolve_at(code, "Bar"), ("Second\\Bar".to_string(), false));
+}
+
+#[test]
+fn test_resolve_in_second_braced_namespace() {
+    let code = "<?php namespace First { new Foo(); } namespace Second { new Bar(); } namespace { new Baz(); }";
+
+    assert_eq!(resolve_at(code, "Bar"), ("Second\\Bar".to_string(), false));
+    assert_eq!(resolve_at(code, "Baz"), ("Baz".to_string(), false));
+}
+
+#[test]
+fn test_resolve_imported_name() {
+    let code = "<?php namespace App; use Lib\\Foo; new Foo();";
+
+    assert_eq!(resolve_at(code, "Foo()"), ("Lib\\Foo".to_string(), true));
+}
+
+#[test]
+fn test_resolve_qualified_name_using_alias() {
+    let code = "<?php namespace App; use Doctrine\\ORM\\Mapping as ORM; #[ORM\\Entity] class User {}";
+
+    assert_eq!(resolve_at(code, "ORM\\Entity"), ("Doctrine\\ORM\\Mapping\\Entity".to_string(), true));
+}
+
+#[test]
+fn test_resolve_qualified_name_using_import() {
+    let code = "<?php namespace App; use Lib\\Mapping; new Mapping\\Column\\Type();";
+
+    assert_eq!(resolve_at(code, "Mapping\\Column"), ("Lib\\Mapping\\Column\\Type".to_string(), true));
+}
+
+/// Collects the identifiers of the called functions, in order.
+struct FunctionCollector;
+
+impl Walker<Vec<Identifier>> for FunctionCollector {
+    fn walk_in_function_call(&self, function_call: &FunctionCall, identifiers: &mut Vec<Identifier>) {
+        if let Expression::Identifier(identifier) = function_call.function.as_ref() {
+            identifiers.push(identifier.clone());
+        }
+    }
+}
+
+/// Resolves the names in the given code, and returns the global fallback of each called function name, in order.
+fn get_global_fallbacks(code: &str) -> Vec<Option<String>> {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+    let (program, error) = parse_source(&interner, &source);
+    assert!(error.is_none(), "unexpected parse error: {:?}", error);
+
+    let names = Names::resolve(&interner, &program);
+    let mut identifiers = vec![];
+    FunctionCollector.walk_program(&program, &mut identifiers);
+
+    identifiers
+        .iter()
+        .map(|identifier| names.get_global_fallback(identifier).map(|name| interner.lookup(&name).to_string()))
+        .collect()
+}
+
+#[test]
+fn test_global_fallback_of_unqualified_names() {
+    let code = "<?php namespace App; use function Lib\\helper; strlen(''); helper(); Util\\format(); \\strlen('');";
+
+    assert_eq!(get_global_fallbacks(code), vec![Some("strlen".to_string()), None, None, None]);
+}
@@ -0,0 +1,112 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::constant::ConstantReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_typing::variable::VariableTypes;
+
+#[derive(Debug)]
+pub struct Context<'a> {
+    pub interner: &'a ThreadedInterner,
+    pub semantics: &'a Semantics,
+    pub codebase: &'a CodebaseReflection,
+    pub variables: &'a VariableTypes,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(
+        interner: &'a ThreadedInterner,
+        semantics: &'a Semantics,
+        codebase: &'a CodebaseReflection,
+        variables: &'a VariableTypes,
+    ) -> Self {
+        Self { interner, semantics, codebase, variables }
+    }
+
+    /// Retrieves the reflection of the class-like referred to by the given identifier, if it exists.
+    pub fn get_named_class_like(&self, identifier: &Identifier) -> Option<&'a ClassLikeReflection> {
+        self.codebase.get_named_class_like(self.semantics.names.get(identifier))
+    }
+
+    /// Retrieves the reflection of the function referred to by the given identifier, or by its global fallback.
+    pub fn get_function(&self, identifier: &Identifier) -> Option<&'a FunctionLikeReflection> {
+        let names = &self.semantics.names;
+
+        self.codebase
+            .get_function(names.get(identifier))
+            .or_else(|| self.codebase.get_function(&names.get_global_fallback(identifier)?))
+    }
+
+    /// Retrieves the reflection of the constant referred to by the given identifier, or by its global fallback.
+    pub fn get_constant(&self, identifier: &Identifier) -> Option<&'a ConstantReflection> {
+        let names = &self.semantics.names;
+
+        self.codebase
+            .get_constant(names.get(identifier))
+            .or_else(|| self.codebase.get_constant(&names.get_global_fallback(identifier)?))
+    }
+
+    /// Retrieves the reflection of the class-like referred to by the class expression of a static
+    /// access, e.g. `Foo`, `self`, `static`, or `parent` in `Foo::bar()`.
+    pub fn get_class_like(&self, class: &Expression) -> Option<&'a ClassLikeReflection> {
+        match class {
+            Expression::Identifier(identifier) => self.get_named_class_like(identifier),
+            Expression::Self_(keyword) | Expression::Static(keyword) => self.codebase.get_enclosing_class_like(keyword),
+            Expression::Parent(keyword) => {
+                let class_like = self.codebase.get_enclosing_class_like(keyword)?;
+                let parent = class_like.inheritance.direct_extended_class?;
+
+                self.codebase.get_named_class_like(&parent.value)
+            }
+            _ => match self.resolve_kind(class) {
+                TypeKind::Object(object) => self.get_object_class_like(&object),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the class-likes the given object expression may be an instance of.
+    pub fn get_object_class_likes(&self, object: &Expression) -> Vec<&'a ClassLikeReflection> {
+        let kinds = match self.resolve_kind(object) {
+            TypeKind::Union { kinds } => kinds,
+            kind => vec![kind],
+        };
+
+        kinds
+            .into_iter()
+            .filter_map(|kind| match kind {
+                TypeKind::Object(object) => self.get_object_class_like(&object),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn get_object_class_like(&self, object: &ObjectTypeKind) -> Option<&'a ClassLikeReflection> {
+        match object {
+            ObjectTypeKind::NamedObject { name, .. } => self.codebase.get_named_class_like(name),
+            ObjectTypeKind::AnonymousObject { span } => {
+                self.codebase.get_class_like(ClassLikeName::AnonymousClass(*span))
+            }
+            ObjectTypeKind::EnumCase { enum_name, .. } => self.codebase.get_enum(enum_name),
+            ObjectTypeKind::Static { scope } | ObjectTypeKind::Self_ { scope } | ObjectTypeKind::Parent { scope } => {
+                self.codebase.get_named_class_like(scope)
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve_kind(&self, expression: &Expression) -> TypeKind {
+        mago_typing::resolve_kind_with_variables(
+            self.interner,
+            self.semantics,
+            self.codebase,
+            self.variables,
+            expression,
+        )
+    }
+}
@@ -0,0 +1,170 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_span::HasPosition;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::context::Context;
+use crate::symbol::Symbol;
+use crate::walker::ReferenceWalker;
+
+mod context;
+mod walker;
+
+pub mod symbol;
+
+/// An index of the references to the symbols of a codebase.
+///
+/// The index is built once per run, by merging the indices of every program of the codebase, and can be
+/// queried by symbol, using [`Symbol::from_name`] to resolve a symbol by name, or by position, using
+/// [`ReferenceIndex::get_symbol_at`]. The declaration of a symbol can be found using [`Symbol::get_declaration_span`].
+///
+/// The index maps each symbol to the spans where it is referenced, and also keeps track of the
+/// names of the members accessed on any object, including those whose type could not be resolved,
+/// of the member accesses whose name is only known at runtime, and of the string literals used, which
+/// can be used to tell whether a symbol may be referenced dynamically.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReferenceIndex {
+    references: HashMap<Symbol, Vec<Span>>,
+    member_names: HashSet<StringIdentifier>,
+    unresolved_member_names: HashMap<StringIdentifier, Vec<Span>>,
+    dynamic_member_accesses: Vec<(Span, Vec<ClassLikeName>)>,
+    strings: HashMap<StringIdentifier, Vec<Span>>,
+}
+
+impl ReferenceIndex {
+    /// Creates a new, empty reference index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the reference index of the given semantics.
+    ///
+    /// # Parameters
+    ///
+    /// - `interner`: The interner to use for string interning.
+    /// - `semantics`: The semantics of the program to index.
+    /// - `codebase`: The reflection of the entire codebase, used to resolve the referenced symbols.
+    ///
+    /// # Returns
+    ///
+    /// The reference index of the given semantics, which can be merged with the indices of other
+    /// programs using [`ReferenceIndex::extend`].
+    pub fn build(interner: &ThreadedInterner, semantics: &Semantics, codebase: &CodebaseReflection) -> Self {
+        let variables = mago_typing::infer_variable_types(interner, semantics, codebase);
+
+        let mut context = Context::new(interner, semantics, codebase, &variables);
+        let mut walker = ReferenceWalker::new();
+
+        walker.walk_program(&semantics.program, &mut context);
+
+        walker.index
+    }
+
+    /// Merges another reference index into this one.
+    pub fn extend(&mut self, other: ReferenceIndex) {
+        for (symbol, spans) in other.references {
+            self.references.entry(symbol).or_default().extend(spans);
+        }
+
+        self.member_names.extend(other.member_names);
+        for (name, spans) in other.unresolved_member_names {
+            self.unresolved_member_names.entry(name).or_default().extend(spans);
+        }
+
+        self.dynamic_member_accesses.extend(other.dynamic_member_accesses);
+
+        for (value, spans) in other.strings {
+            self.strings.entry(value).or_default().extend(spans);
+        }
+    }
+
+    /// Records a reference to the given symbol.
+    pub fn add_reference(&mut self, symbol: Symbol, span: Span) {
+        self.references.entry(symbol).or_default().push(span);
+    }
+
+    /// Returns the spans where the given symbol is referenced.
+    pub fn get_references(&self, symbol: &Symbol) -> &[Span] {
+        self.references.get(symbol).map(|spans| spans.as_slice()).unwrap_or_default()
+    }
+
+    /// Returns the symbols referenced anywhere, along with the spans where they are referenced.
+    pub fn get_referenced_symbols(&self) -> impl Iterator<Item = (&Symbol, &[Span])> {
+        self.references.iter().map(|(symbol, spans)| (symbol, spans.as_slice()))
+    }
+
+    /// Returns the symbol at the given position, e.g. the symbol under the cursor of an editor.
+    ///
+    /// The position may either be within a reference to the symbol, or within the name of its declaration.
+    pub fn get_symbol_at(&self, codebase: &CodebaseReflection, position: &impl HasPosition) -> Option<Symbol> {
+        let position = position.position();
+
+        self.references
+            .iter()
+            .find(|(_, spans)| spans.iter().any(|span| span.contains(&position)))
+            .map(|(symbol, _)| *symbol)
+            .or_else(|| Symbol::from_declaration_at(codebase, &position))
+    }
+
+    /// Determines whether the given symbol is referenced anywhere.
+    pub fn is_referenced(&self, symbol: &Symbol) -> bool {
+        !self.get_references(symbol).is_empty()
+    }
+
+    /// Determines whether a member with the given name is accessed on any object or class-like,
+    /// whether or not the accessed member could be resolved.
+    ///
+    /// The comparison is case-insensitive, and the leading `$` of property names is ignored.
+    pub fn is_member_name_accessed(&self, interner: &ThreadedInterner, name: &str) -> bool {
+        self.member_names.contains(&interner.intern(name.trim_start_matches('$').to_ascii_lowercase()))
+    }
+
+    /// Determines whether a member with the given name is accessed on an object or class-like
+    /// whose type could not be resolved, in which case the access may refer to a member of any class-like.
+    ///
+    /// The comparison is case-insensitive, and the leading `$` of property names is ignored.
+    pub fn is_member_name_accessed_on_unresolved(&self, interner: &ThreadedInterner, name: &str) -> bool {
+        !self.get_unresolved_member_accesses(interner, name).is_empty()
+    }
+
+    /// Returns the spans of the names of the members with the given name that are accessed on an object
+    /// or class-like whose type could not be resolved.
+    ///
+    /// The comparison is case-insensitive, and the leading `$` of property names is ignored.
+    pub fn get_unresolved_member_accesses(&self, interner: &ThreadedInterner, name: &str) -> &[Span] {
+        self.unresolved_member_names
+            .get(&interner.intern(name.trim_start_matches('$').to_ascii_lowercase()))
+            .map(|spans| spans.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the spans of the member accesses whose name is only known at runtime, e.g. `$object->$name()`,
+    /// along with the class-likes of the accessed object or class, which are empty if its type could not be resolved.
+    pub fn get_dynamic_member_accesses(&self) -> &[(Span, Vec<ClassLikeName>)] {
+        &self.dynamic_member_accesses
+    }
+
+    /// Determines whether the given value is used as a string literal, e.g. as the name of a callable.
+    ///
+    /// The comparison is case-insensitive, and ignores leading backslashes.
+    pub fn is_string_referenced(&self, interner: &ThreadedInterner, value: &str) -> bool {
+        !self.get_string_references(interner, value).is_empty()
+    }
+
+    /// Returns the spans of the string literals with the given value, including their quotes.
+    ///
+    /// The comparison is case-insensitive, and ignores leading backslashes.
+    pub fn get_string_references(&self, interner: &ThreadedInterner, value: &str) -> &[Span] {
+        self.strings
+            .get(&interner.intern(value.trim_start_matches('\\').to_ascii_lowercase()))
+            .map(|spans| spans.as_slice())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,215 @@
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_span::HasPosition;
+use mago_span::Span;
+
+/// Represents a symbol that can be referenced across a project.
+///
+/// Members are identified by the class-like that declares them, so that a reference to an inherited
+/// member is a reference to the member of the parent class-like, and not of the class-like it was
+/// accessed on.
+///
+/// The names are the ones used by the codebase reflection, e.g. property names include the leading `$`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Symbol {
+    ClassLike(ClassLikeName),
+    Function(StringIdentifier),
+    Constant(StringIdentifier),
+    Method(ClassLikeName, StringIdentifier),
+    Property(ClassLikeName, StringIdentifier),
+    ClassLikeConstant(ClassLikeName, StringIdentifier),
+}
+
+impl Symbol {
+    /// Resolves the symbol with the given name.
+    ///
+    /// The name uses the same notation as issue messages: `Foo\Bar` for class-likes, or constants if no
+    /// class-like has that name, `Foo\bar()` for functions, `Foo\Bar::baz()` for methods, `Foo\Bar::$baz`
+    /// for properties, and `Foo\Bar::BAZ` for class-like constants and enum cases.
+    ///
+    /// Names of class-likes, functions and methods are matched case-insensitively, as they are in PHP.
+    pub fn from_name(interner: &ThreadedInterner, codebase: &CodebaseReflection, name: &str) -> Option<Self> {
+        let name = name.trim().trim_start_matches('\\');
+
+        if let Some((class_like_name, member_name)) = name.split_once("::") {
+            let class_like = find_class_like(interner, codebase, class_like_name)?;
+
+            return if let Some(method_name) = member_name.strip_suffix("()") {
+                class_like
+                    .methods
+                    .declaring_members
+                    .iter()
+                    .find(|(name, _)| interner.lookup(name).eq_ignore_ascii_case(method_name))
+                    .map(|(name, declaring_class_like)| Symbol::Method(*declaring_class_like, *name))
+            } else if member_name.starts_with('$') {
+                let property_name = interner.intern(member_name);
+
+                class_like
+                    .properties
+                    .declaring_members
+                    .get(&property_name)
+                    .map(|declaring_class_like| Symbol::Property(*declaring_class_like, property_name))
+            } else {
+                let constant_name = interner.intern(member_name);
+
+                match class_like.constants.get(&constant_name) {
+                    Some(constant) => Some(Symbol::ClassLikeConstant(constant.name.class_like, constant_name)),
+                    None => class_like
+                        .cases
+                        .declaring_members
+                        .get(&constant_name)
+                        .map(|declaring_class_like| Symbol::ClassLikeConstant(*declaring_class_like, constant_name)),
+                }
+            };
+        }
+
+        if let Some(function_name) = name.strip_suffix("()") {
+            let function = codebase.get_function(&interner.intern(function_name)).or_else(|| {
+                codebase.function_identifiers.iter().find_map(|(name, identifier)| {
+                    if interner.lookup(name).eq_ignore_ascii_case(function_name) {
+                        codebase.get_function_like(*identifier)
+                    } else {
+                        None
+                    }
+                })
+            })?;
+
+            return match function.name {
+                FunctionLikeName::Function(name) => Some(Symbol::Function(name.value)),
+                _ => None,
+            };
+        }
+
+        if let Some(class_like) = find_class_like(interner, codebase, name) {
+            return Some(Symbol::ClassLike(class_like.name));
+        }
+
+        codebase.get_constant(&interner.intern(name)).map(|constant| Symbol::Constant(constant.name.value))
+    }
+
+    /// Finds the symbol whose declaration name is at the given position, e.g. when the cursor is
+    /// on the name of a class or of a method in its declaration.
+    pub fn from_declaration_at(codebase: &CodebaseReflection, position: &impl HasPosition) -> Option<Self> {
+        let position = position.position();
+
+        for class_like in codebase.class_like_reflections.values() {
+            if !class_like.span.contains(&position) {
+                continue;
+            }
+
+            if class_like.name.inner().is_some_and(|name| name.span.contains(&position)) {
+                return Some(Symbol::ClassLike(class_like.name));
+            }
+
+            for (name, method) in &class_like.methods.members {
+                if let FunctionLikeName::Method(_, method_name) = method.name {
+                    if method_name.span.contains(&position) {
+                        return Some(Symbol::Method(class_like.name, *name));
+                    }
+                }
+            }
+
+            for (name, property) in &class_like.properties.members {
+                if property.name.member.span.contains(&position) {
+                    return Some(Symbol::Property(class_like.name, *name));
+                }
+            }
+
+            for (name, constant) in &class_like.constants {
+                if constant.name.class_like == class_like.name && constant.name.member.span.contains(&position) {
+                    return Some(Symbol::ClassLikeConstant(class_like.name, *name));
+                }
+            }
+
+            for (name, case) in &class_like.cases.members {
+                if case.name.member.span.contains(&position) {
+                    return Some(Symbol::ClassLikeConstant(class_like.name, *name));
+                }
+            }
+        }
+
+        for function_like in codebase.function_like_reflections.values() {
+            if let FunctionLikeName::Function(name) = function_like.name {
+                if name.span.contains(&position) {
+                    return Some(Symbol::Function(name.value));
+                }
+            }
+        }
+
+        codebase
+            .constant_reflections
+            .values()
+            .find(|constant| constant.name.span.contains(&position))
+            .map(|constant| Symbol::Constant(constant.name.value))
+    }
+
+    /// Returns the span of the name of the declaration of this symbol, if it is declared in the codebase.
+    pub fn get_declaration_span(&self, codebase: &CodebaseReflection) -> Option<Span> {
+        match self {
+            Symbol::ClassLike(class_like_name) => {
+                let class_like = codebase.get_class_like(*class_like_name)?;
+
+                Some(class_like.name.inner().map(|name| name.span).unwrap_or(class_like.span))
+            }
+            Symbol::Function(name) => match codebase.get_function(name)?.name {
+                FunctionLikeName::Function(name) => Some(name.span),
+                _ => None,
+            },
+            Symbol::Constant(name) => Some(codebase.get_constant(name)?.name.span),
+            Symbol::Method(class_like_name, name) => {
+                match codebase.get_class_like(*class_like_name)?.methods.members.get(name)?.name {
+                    FunctionLikeName::Method(_, name) => Some(name.span),
+                    _ => None,
+                }
+            }
+            Symbol::Property(class_like_name, name) => {
+                Some(codebase.get_class_like(*class_like_name)?.properties.members.get(name)?.name.member.span)
+            }
+            Symbol::ClassLikeConstant(class_like_name, name) => {
+                let class_like = codebase.get_class_like(*class_like_name)?;
+
+                match class_like.constants.get(name) {
+                    Some(constant) => Some(constant.name.member.span),
+                    None => Some(class_like.cases.members.get(name)?.name.member.span),
+                }
+            }
+        }
+    }
+
+    /// Returns the name of this symbol, in the notation accepted by [`Symbol::from_name`].
+    pub fn get_key(&self, interner: &ThreadedInterner) -> String {
+        match self {
+            Symbol::ClassLike(class_like_name) => class_like_name.get_key(interner),
+            Symbol::Function(name) => format!("{}()", interner.lookup(name)),
+            Symbol::Constant(name) => interner.lookup(name).to_string(),
+            Symbol::Method(class_like_name, name) => {
+                format!("{}::{}()", class_like_name.get_key(interner), interner.lookup(name))
+            }
+            Symbol::Property(class_like_name, name) | Symbol::ClassLikeConstant(class_like_name, name) => {
+                format!("{}::{}", class_like_name.get_key(interner), interner.lookup(name))
+            }
+        }
+    }
+}
+
+fn find_class_like<'a>(
+    interner: &ThreadedInterner,
+    codebase: &'a CodebaseReflection,
+    name: &str,
+) -> Option<&'a ClassLikeReflection> {
+    if let Some(class_like) = codebase.get_named_class_like(&interner.intern(name)) {
+        return Some(class_like);
+    }
+
+    codebase.class_like_names.iter().find_map(|(class_like_name, identifier)| {
+        if interner.lookup(class_like_name).eq_ignore_ascii_case(name) {
+            codebase.get_class_like(*identifier)
+        } else {
+            None
+        }
+    })
+}
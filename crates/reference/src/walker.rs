@@ -0,0 +1,404 @@
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::context::Context;
+use crate::symbol::Symbol;
+use crate::ReferenceIndex;
+
+#[derive(Debug)]
+pub struct ReferenceWalker {
+    pub index: ReferenceIndex,
+    claimed_identifiers: HashSet<Span>,
+}
+
+impl ReferenceWalker {
+    pub fn new() -> Self {
+        Self { index: ReferenceIndex::new(), claimed_identifiers: HashSet::default() }
+    }
+
+    fn add_class_like_reference(&mut self, identifier: &Identifier, context: &mut Context<'_>) {
+        self.claimed_identifiers.insert(identifier.span());
+
+        if let Some(class_like) = context.get_named_class_like(identifier) {
+            self.index.add_reference(Symbol::ClassLike(class_like.name), identifier.span());
+        }
+    }
+
+    fn add_class_expression_reference(&mut self, class: &Expression, context: &mut Context<'_>) {
+        if let Expression::Identifier(identifier) = class {
+            self.add_class_like_reference(identifier, context);
+        }
+    }
+
+    fn add_member_name(&mut self, name: &str, span: Span, is_resolved: bool, context: &mut Context<'_>) {
+        let name = context.interner.intern(name.trim_start_matches('$').to_ascii_lowercase());
+
+        self.index.member_names.insert(name);
+        if !is_resolved {
+            self.index.unresolved_member_names.entry(name).or_default().push(span);
+        }
+    }
+
+    fn add_dynamic_member_access(&mut self, span: Span, class_likes: &[&ClassLikeReflection]) {
+        self.index.dynamic_member_accesses.push((span, class_likes.iter().map(|class_like| class_like.name).collect()));
+    }
+
+    fn add_method_reference(
+        &mut self,
+        class_like: &ClassLikeReflection,
+        name: &str,
+        span: Span,
+        context: &Context<'_>,
+    ) {
+        let method = class_like
+            .methods
+            .declaring_members
+            .iter()
+            .find(|(method_name, _)| context.interner.lookup(method_name).eq_ignore_ascii_case(name));
+
+        if let Some((method_name, declaring_class_like)) = method {
+            self.index.add_reference(Symbol::Method(*declaring_class_like, *method_name), span);
+        }
+    }
+
+    fn add_property_reference(
+        &mut self,
+        class_like: &ClassLikeReflection,
+        name: &StringIdentifier,
+        span: Span,
+        context: &Context<'_>,
+    ) {
+        let property_name = context.interner.lookup(name);
+        let property_name =
+            if property_name.starts_with('$') { *name } else { context.interner.intern(format!("${}", property_name)) };
+
+        if let Some(declaring_class_like) = class_like.properties.declaring_members.get(&property_name) {
+            self.index.add_reference(Symbol::Property(*declaring_class_like, property_name), span);
+        }
+    }
+
+    fn add_constant_reference(&mut self, class_like: &ClassLikeReflection, name: &StringIdentifier, span: Span) {
+        if let Some(constant) = class_like.constants.get(name) {
+            self.index.add_reference(Symbol::ClassLikeConstant(constant.name.class_like, *name), span);
+        } else if let Some(declaring_class_like) = class_like.cases.declaring_members.get(name) {
+            self.index.add_reference(Symbol::ClassLikeConstant(*declaring_class_like, *name), span);
+        }
+    }
+
+    fn add_function_reference(&mut self, identifier: &Identifier, context: &mut Context<'_>) {
+        self.claimed_identifiers.insert(identifier.span());
+
+        let Some(function) = context.get_function(identifier) else {
+            return;
+        };
+
+        if let FunctionLikeName::Function(name) = function.name {
+            self.index.add_reference(Symbol::Function(name.value), identifier.span());
+        }
+    }
+
+    fn add_object_member_reference(
+        &mut self,
+        object: &Expression,
+        selector: &ClassLikeMemberSelector,
+        is_method: bool,
+        context: &mut Context<'_>,
+    ) {
+        let class_likes = context.get_object_class_likes(object);
+        let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+            self.add_dynamic_member_access(selector.span(), &class_likes);
+
+            return;
+        };
+
+        let name = context.interner.lookup(&identifier.value);
+        self.add_member_name(name, identifier.span, !class_likes.is_empty(), context);
+
+        for class_like in class_likes {
+            if is_method {
+                self.add_method_reference(class_like, name, identifier.span, context);
+            } else {
+                self.add_property_reference(class_like, &identifier.value, identifier.span, context);
+            }
+        }
+    }
+
+    fn add_static_method_reference(
+        &mut self,
+        class: &Expression,
+        selector: &ClassLikeMemberSelector,
+        context: &mut Context<'_>,
+    ) {
+        self.add_class_expression_reference(class, context);
+
+        let class_like = context.get_class_like(class);
+        let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+            self.add_dynamic_member_access(selector.span(), class_like.as_slice());
+
+            return;
+        };
+
+        let name = context.interner.lookup(&identifier.value);
+        self.add_member_name(name, identifier.span, class_like.is_some(), context);
+
+        if let Some(class_like) = class_like {
+            self.add_method_reference(class_like, name, identifier.span, context);
+        }
+    }
+}
+
+impl<'a> MutWalker<Context<'a>> for ReferenceWalker {
+    fn walk_in_use(&mut self, r#use: &Use, context: &mut Context<'a>) {
+        let (prefix, items): (Option<&Identifier>, Vec<(Option<&UseType>, &UseItem)>) = match &r#use.items {
+            UseItems::Sequence(sequence) => (None, sequence.items.iter().map(|item| (None, item)).collect()),
+            UseItems::TypedSequence(sequence) => {
+                (None, sequence.items.iter().map(|item| (Some(&sequence.r#type), item)).collect())
+            }
+            UseItems::TypedList(list) => {
+                (Some(&list.namespace), list.items.iter().map(|item| (Some(&list.r#type), item)).collect())
+            }
+            UseItems::MixedList(list) => {
+                (Some(&list.namespace), list.items.iter().map(|item| (item.r#type.as_ref(), &item.item)).collect())
+            }
+        };
+
+        for (r#type, item) in items {
+            let item_name = context.interner.lookup(&item.name.value());
+            let name = match prefix {
+                Some(prefix) => format!("{}\\{}", context.interner.lookup(&prefix.value()), item_name),
+                None => item_name.to_string(),
+            };
+
+            let name = context.interner.intern(name.trim_start_matches('\\'));
+            let span = item.name.span();
+
+            let symbol = match r#type {
+                None => {
+                    context.codebase.get_named_class_like(&name).map(|class_like| Symbol::ClassLike(class_like.name))
+                }
+                Some(UseType::Function(_)) => {
+                    context.codebase.get_function(&name).and_then(|function| match function.name {
+                        FunctionLikeName::Function(name) => Some(Symbol::Function(name.value)),
+                        _ => None,
+                    })
+                }
+                Some(UseType::Const(_)) => {
+                    context.codebase.get_constant(&name).map(|constant| Symbol::Constant(constant.name.value))
+                }
+            };
+
+            if let Some(symbol) = symbol {
+                self.index.add_reference(symbol, span);
+            }
+        }
+    }
+
+    fn walk_in_hint(&mut self, hint: &Hint, context: &mut Context<'a>) {
+        if let Hint::Identifier(identifier) = hint {
+            self.add_class_like_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_extends(&mut self, extends: &Extends, context: &mut Context<'a>) {
+        for identifier in extends.types.iter() {
+            self.add_class_like_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_implements(&mut self, implements: &Implements, context: &mut Context<'a>) {
+        for identifier in implements.types.iter() {
+            self.add_class_like_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_trait_use(&mut self, trait_use: &TraitUse, context: &mut Context<'a>) {
+        for identifier in trait_use.trait_names.iter() {
+            self.add_class_like_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_trait_use_absolute_method_reference(
+        &mut self,
+        reference: &TraitUseAbsoluteMethodReference,
+        context: &mut Context<'a>,
+    ) {
+        self.add_class_like_reference(&reference.trait_name, context);
+
+        let name = context.interner.lookup(&reference.method_name.value);
+        let class_like = context.get_named_class_like(&reference.trait_name);
+        self.add_member_name(name, reference.method_name.span, class_like.is_some(), context);
+
+        if let Some(class_like) = class_like {
+            self.add_method_reference(class_like, name, reference.method_name.span, context);
+        }
+    }
+
+    fn walk_in_attribute(&mut self, attribute: &Attribute, context: &mut Context<'a>) {
+        self.add_class_like_reference(&attribute.name, context);
+    }
+
+    fn walk_in_binary(&mut self, binary: &Binary, context: &mut Context<'a>) {
+        if let BinaryOperator::Instanceof(_) = binary.operator {
+            self.add_class_expression_reference(&binary.rhs, context);
+        }
+    }
+
+    fn walk_in_instantiation(&mut self, instantiation: &Instantiation, context: &mut Context<'a>) {
+        self.add_class_expression_reference(&instantiation.class, context);
+
+        if let Some(class_like) = context.get_class_like(&instantiation.class) {
+            self.add_method_reference(class_like, "__construct", instantiation.class.span(), context);
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, context: &mut Context<'a>) {
+        if let Expression::Identifier(identifier) = function_call.function.as_ref() {
+            self.add_function_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_function_closure_creation(
+        &mut self,
+        function_closure_creation: &FunctionClosureCreation,
+        context: &mut Context<'a>,
+    ) {
+        if let Expression::Identifier(identifier) = &function_closure_creation.function {
+            self.add_function_reference(identifier, context);
+        }
+    }
+
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, context: &mut Context<'a>) {
+        self.add_object_member_reference(&method_call.object, &method_call.method, true, context);
+    }
+
+    fn walk_in_null_safe_method_call(&mut self, null_safe_method_call: &NullSafeMethodCall, context: &mut Context<'a>) {
+        self.add_object_member_reference(&null_safe_method_call.object, &null_safe_method_call.method, true, context);
+    }
+
+    fn walk_in_method_closure_creation(
+        &mut self,
+        method_closure_creation: &MethodClosureCreation,
+        context: &mut Context<'a>,
+    ) {
+        self.add_object_member_reference(
+            &method_closure_creation.object,
+            &method_closure_creation.method,
+            true,
+            context,
+        );
+    }
+
+    fn walk_in_static_method_call(&mut self, static_method_call: &StaticMethodCall, context: &mut Context<'a>) {
+        self.add_static_method_reference(&static_method_call.class, &static_method_call.method, context);
+    }
+
+    fn walk_in_static_method_closure_creation(
+        &mut self,
+        static_method_closure_creation: &StaticMethodClosureCreation,
+        context: &mut Context<'a>,
+    ) {
+        self.add_static_method_reference(
+            &static_method_closure_creation.class,
+            &static_method_closure_creation.method,
+            context,
+        );
+    }
+
+    fn walk_in_property_access(&mut self, property_access: &PropertyAccess, context: &mut Context<'a>) {
+        self.add_object_member_reference(&property_access.object, &property_access.property, false, context);
+    }
+
+    fn walk_in_null_safe_property_access(
+        &mut self,
+        null_safe_property_access: &NullSafePropertyAccess,
+        context: &mut Context<'a>,
+    ) {
+        self.add_object_member_reference(
+            &null_safe_property_access.object,
+            &null_safe_property_access.property,
+            false,
+            context,
+        );
+    }
+
+    fn walk_in_static_property_access(
+        &mut self,
+        static_property_access: &StaticPropertyAccess,
+        context: &mut Context<'a>,
+    ) {
+        self.add_class_expression_reference(&static_property_access.class, context);
+
+        let class_like = context.get_class_like(&static_property_access.class);
+        let Variable::Direct(variable) = &static_property_access.property else {
+            self.add_dynamic_member_access(static_property_access.property.span(), class_like.as_slice());
+
+            return;
+        };
+
+        let name = context.interner.lookup(&variable.name);
+        self.add_member_name(name, variable.span, class_like.is_some(), context);
+
+        if let Some(class_like) = class_like {
+            self.add_property_reference(class_like, &variable.name, variable.span, context);
+        }
+    }
+
+    fn walk_in_class_constant_access(
+        &mut self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut Context<'a>,
+    ) {
+        self.add_class_expression_reference(&class_constant_access.class, context);
+
+        let class_like = context.get_class_like(&class_constant_access.class);
+        let ClassLikeConstantSelector::Identifier(identifier) = &class_constant_access.constant else {
+            self.add_dynamic_member_access(class_constant_access.constant.span(), class_like.as_slice());
+
+            return;
+        };
+
+        let name = context.interner.lookup(&identifier.value);
+        if name.eq_ignore_ascii_case("class") {
+            return;
+        }
+
+        self.add_member_name(name, identifier.span, class_like.is_some(), context);
+
+        if let Some(class_like) = class_like {
+            self.add_constant_reference(class_like, &identifier.value, identifier.span);
+        }
+    }
+
+    fn walk_in_expression(&mut self, expression: &Expression, context: &mut Context<'a>) {
+        let Expression::Identifier(identifier) = expression else {
+            return;
+        };
+
+        if self.claimed_identifiers.contains(&identifier.span()) {
+            return;
+        }
+
+        if let Some(constant) = context.get_constant(identifier) {
+            self.index.add_reference(Symbol::Constant(constant.name.value), identifier.span());
+        }
+    }
+
+    fn walk_in_literal_string(&mut self, literal_string: &LiteralString, context: &mut Context<'a>) {
+        let value = context.interner.lookup(&literal_string.value);
+        if value.len() < 2 {
+            return;
+        }
+
+        let value = value[1..value.len() - 1].replace("\\\\", "\\");
+        let value = value.trim_start_matches('\\').to_ascii_lowercase();
+
+        self.index.strings.entry(context.interner.intern(value)).or_default().push(literal_string.span);
+    }
+}
@@ -0,0 +1,322 @@
+use mago_interner::ThreadedInterner;
+use mago_reference::symbol::Symbol;
+use mago_reference::ReferenceIndex;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+use mago_span::Position;
+
+const CODE: &str = r#"<?php
+
+namespace App;
+
+const VERSION = '1.0';
+
+function helper(): void {}
+
+interface Greeter {
+    public function greet(): string;
+}
+
+abstract class Base implements Greeter {
+    public const PREFIX = 'Hello';
+
+    public string $name = '';
+
+    public function greet(): string {
+        return self::PREFIX . $this->name;
+    }
+}
+
+final class Child extends Base {
+    public function shout(): string {
+        return strtoupper($this->greet());
+    }
+}
+
+enum Status {
+    case Active;
+}
+
+function main(Child $child, $unknown): void {
+    helper();
+    echo VERSION;
+    echo $child->greet();
+    echo $child->name;
+    echo Child::PREFIX;
+    echo Status::Active->name;
+    $other = new Child();
+    $other->shout();
+    $unknown->whisper();
+    echo 'App\Base';
+}
+"#;
+
+struct Indexed {
+    code: &'static str,
+    interner: ThreadedInterner,
+    source: SourceIdentifier,
+    codebase: CodebaseReflection,
+    index: ReferenceIndex,
+}
+
+impl Indexed {
+    fn new(code: &'static str) -> Self {
+        let interner = ThreadedInterner::new();
+        let mut manager = SourceManager::new(interner.clone());
+        let source = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+        let loaded = manager.load(&source).expect("source should be loaded");
+
+        let semantics = Semantics::build(&interner, loaded);
+        let mut codebase = mago_reflector::reflect(&interner, &semantics);
+        mago_reflector::populate(&interner, &mut codebase);
+
+        let index = ReferenceIndex::build(&interner, &semantics, &codebase);
+
+        Self { code, interner, source, codebase, index }
+    }
+
+    fn class_like(&self, name: &str) -> &ClassLikeReflection {
+        self.codebase.get_named_class_like(&self.interner.intern(name)).expect("class-like should be reflected")
+    }
+
+    fn class_like_symbol(&self, name: &str) -> Symbol {
+        Symbol::ClassLike(self.class_like(name).name)
+    }
+
+    fn function_symbol(&self, name: &str) -> Symbol {
+        match self.codebase.get_function(&self.interner.intern(name)).expect("function should be reflected").name {
+            FunctionLikeName::Function(name) => Symbol::Function(name.value),
+            _ => unreachable!(),
+        }
+    }
+
+    fn constant_symbol(&self, name: &str) -> Symbol {
+        Symbol::Constant(self.codebase.get_constant(&self.interner.intern(name)).expect("constant").name.value)
+    }
+
+    /// Returns the symbol of the given method, as declared by the given class-like.
+    fn method_symbol(&self, class_like: &str, name: &str) -> Symbol {
+        let class_like = self.class_like(class_like);
+        let (name, _) = class_like
+            .methods
+            .members
+            .iter()
+            .find(|(key, _)| self.interner.lookup(key).eq_ignore_ascii_case(name))
+            .expect("method should be declared");
+
+        Symbol::Method(class_like.name, *name)
+    }
+
+    fn member_symbol(&self, class_like: &str, name: &str) -> (Symbol, Symbol) {
+        let class_like = self.class_like(class_like).name;
+        let name = self.interner.intern(name);
+
+        (Symbol::Property(class_like, name), Symbol::ClassLikeConstant(class_like, name))
+    }
+
+    fn symbol(&self, name: &str) -> Option<Symbol> {
+        Symbol::from_name(&self.interner, &self.codebase, name)
+    }
+
+    fn key(&self, name: &str) -> Option<String> {
+        self.symbol(name).map(|symbol| symbol.get_key(&self.interner))
+    }
+
+    /// Returns the position within the `occurrence`-th occurrence of the given needle in the code.
+    fn position_of(&self, needle: &str, occurrence: usize) -> Position {
+        let offset = self.code.match_indices(needle).nth(occurrence).expect("needle should be found").0;
+
+        Position::new(self.source, offset + 1)
+    }
+
+    /// Returns the referenced source text of the given symbol, in order.
+    fn references(&self, symbol: Symbol) -> Vec<&'static str> {
+        let mut spans = self.index.get_references(&symbol).to_vec();
+        spans.sort();
+
+        spans.into_iter().map(|span| &self.code[span.start.offset..span.end.offset]).collect()
+    }
+}
+
+#[test]
+fn test_index_population() {
+    let indexed = Indexed::new(CODE);
+
+    assert_eq!(indexed.references(indexed.function_symbol("App\\helper")), vec!["helper"]);
+    assert_eq!(indexed.references(indexed.constant_symbol("App\\VERSION")), vec!["VERSION"]);
+    assert_eq!(indexed.references(indexed.class_like_symbol("App\\Child")), vec!["Child", "Child", "Child"]);
+    assert_eq!(indexed.references(indexed.class_like_symbol("App\\Base")), vec!["Base"]);
+    assert_eq!(indexed.references(indexed.class_like_symbol("App\\Greeter")), vec!["Greeter"]);
+    assert_eq!(indexed.references(indexed.method_symbol("App\\Child", "shout")), vec!["shout"]);
+    assert_eq!(indexed.references(indexed.member_symbol("App\\Base", "PREFIX").1), vec!["PREFIX", "PREFIX"]);
+    assert_eq!(indexed.references(indexed.member_symbol("App\\Status", "Active").1), vec!["Active"]);
+}
+
+#[test]
+fn test_index_inherited_members_reference_declaring_class() {
+    let indexed = Indexed::new(CODE);
+
+    // `$child->greet()` and `$this->greet()` in `Child` both reference the method declared by `Base`.
+    assert_eq!(indexed.references(indexed.method_symbol("App\\Base", "greet")), vec!["greet", "greet"]);
+    assert_eq!(indexed.references(indexed.member_symbol("App\\Base", "$name").0), vec!["name", "name"]);
+
+    assert!(!indexed.index.is_referenced(&indexed.method_symbol("App\\Greeter", "greet")));
+}
+
+#[test]
+fn test_index_member_names_and_strings() {
+    let indexed = Indexed::new(CODE);
+
+    assert!(indexed.index.is_member_name_accessed(&indexed.interner, "$NAME"));
+    assert!(indexed.index.is_member_name_accessed(&indexed.interner, "greet"));
+    assert!(!indexed.index.is_member_name_accessed(&indexed.interner, "missing"));
+
+    // Members accessed on objects of unknown type may be any member with that name.
+    assert!(indexed.index.is_member_name_accessed_on_unresolved(&indexed.interner, "whisper"));
+    assert!(!indexed.index.is_member_name_accessed_on_unresolved(&indexed.interner, "shout"));
+
+    assert!(indexed.index.is_string_referenced(&indexed.interner, "App\\Base"));
+    assert!(!indexed.index.is_string_referenced(&indexed.interner, "App\\Child"));
+}
+
+#[test]
+fn test_index_extend() {
+    let indexed = Indexed::new(CODE);
+    let symbol = indexed.function_symbol("App\\helper");
+
+    let mut merged = ReferenceIndex::new();
+    merged.extend(indexed.index.clone());
+    merged.extend(indexed.index.clone());
+
+    assert_eq!(merged.get_references(&symbol).len(), 2);
+    assert!(merged.is_referenced(&symbol));
+    assert!(!ReferenceIndex::new().is_referenced(&symbol));
+}
+
+#[test]
+fn test_symbol_from_name_is_case_insensitive() {
+    let indexed = Indexed::new(CODE);
+
+    assert_eq!(indexed.key("app\\child").as_deref(), Some("App\\Child"));
+    assert_eq!(indexed.key("\\APP\\Child").as_deref(), Some("App\\Child"));
+    assert_eq!(indexed.key("app\\HELPER()").as_deref(), Some("App\\helper()"));
+    assert_eq!(indexed.key("App\\Child::SHOUT()").as_deref(), Some("App\\Child::shout()"));
+
+    // Properties and constants are case-sensitive, as they are in PHP.
+    assert_eq!(indexed.key("App\\Base::$NAME"), None);
+    assert_eq!(indexed.key("App\\Base::prefix"), None);
+}
+
+#[test]
+fn test_symbol_from_name_resolves_inherited_members_to_declaring_class() {
+    let indexed = Indexed::new(CODE);
+
+    assert_eq!(indexed.key("App\\Child::greet()").as_deref(), Some("App\\Base::greet()"));
+    assert_eq!(indexed.key("App\\Child::$name").as_deref(), Some("App\\Base::$name"));
+    assert_eq!(indexed.key("App\\Child::PREFIX").as_deref(), Some("App\\Base::PREFIX"));
+    assert_eq!(indexed.symbol("App\\Child::greet()"), indexed.symbol("App\\Base::greet()"));
+}
+
+#[test]
+fn test_symbol_from_name_call_suffix() {
+    let indexed = Indexed::new(CODE);
+
+    assert!(matches!(indexed.symbol("App\\helper()"), Some(Symbol::Function(_))));
+    assert_eq!(indexed.symbol("App\\helper"), None);
+
+    assert!(matches!(indexed.symbol("App\\VERSION"), Some(Symbol::Constant(_))));
+    assert_eq!(indexed.symbol("App\\VERSION()"), None);
+
+    assert!(matches!(indexed.symbol("App\\Child::shout()"), Some(Symbol::Method(_, _))));
+    assert_eq!(indexed.symbol("App\\Child::shout"), None);
+
+    assert_eq!(indexed.symbol("App\\Missing"), None);
+    assert_eq!(indexed.symbol("App\\Missing::foo()"), None);
+}
+
+#[test]
+fn test_symbol_from_declaration_at() {
+    let indexed = Indexed::new(CODE);
+    let at = |needle: &str, occurrence: usize| {
+        Symbol::from_declaration_at(&indexed.codebase, &indexed.position_of(needle, occurrence))
+            .map(|symbol| symbol.get_key(&indexed.interner))
+    };
+
+    assert_eq!(at("Child extends", 0).as_deref(), Some("App\\Child"));
+    assert_eq!(at("helper(): void", 0).as_deref(), Some("App\\helper()"));
+    assert_eq!(at("VERSION =", 0).as_deref(), Some("App\\VERSION"));
+    assert_eq!(at("shout(): string", 0).as_deref(), Some("App\\Child::shout()"));
+    assert_eq!(at("greet(): string {", 0).as_deref(), Some("App\\Base::greet()"));
+    assert_eq!(at("$name = ''", 0).as_deref(), Some("App\\Base::$name"));
+    assert_eq!(at("PREFIX =", 0).as_deref(), Some("App\\Base::PREFIX"));
+    assert_eq!(at("Active;", 0).as_deref(), Some("App\\Status::Active"));
+
+    // References are not declarations.
+    assert_eq!(at("helper();", 0), None);
+    assert_eq!(at("$child->greet()", 0), None);
+}
+
+#[test]
+fn test_get_symbol_at() {
+    let indexed = Indexed::new(CODE);
+    let at = |needle: &str| {
+        indexed
+            .index
+            .get_symbol_at(&indexed.codebase, &indexed.position_of(needle, 0))
+            .map(|symbol| symbol.get_key(&indexed.interner))
+    };
+
+    assert_eq!(at("greet();\n    echo").as_deref(), Some("App\\Base::greet()"));
+    assert_eq!(at("shout(): string").as_deref(), Some("App\\Child::shout()"));
+}
+
+#[test]
+fn test_index_use_imports() {
+    let indexed = Indexed::new(
+        r#"<?php
+
+namespace Lib {
+    const LIMIT = 1;
+
+    function helper(): void {}
+
+    final class Tool {}
+}
+
+namespace App {
+    use Lib\Tool;
+    use Lib\{Tool as Other};
+    use function Lib\helper;
+    use const Lib\LIMIT;
+
+    helper();
+    echo LIMIT;
+    new Tool();
+}
+"#,
+    );
+
+    assert_eq!(indexed.references(indexed.symbol("Lib\\Tool").unwrap()), vec!["Lib\\Tool", "Tool", "Tool"]);
+    assert_eq!(indexed.references(indexed.symbol("Lib\\helper()").unwrap()), vec!["Lib\\helper", "helper"]);
+    assert_eq!(indexed.references(indexed.symbol("Lib\\LIMIT").unwrap()), vec!["Lib\\LIMIT", "LIMIT"]);
+}
+
+#[test]
+fn test_get_referenced_symbols() {
+    let indexed = Indexed::new(CODE);
+
+    let mut keys = indexed
+        .index
+        .get_referenced_symbols()
+        .filter(|(symbol, _)| matches!(symbol, Symbol::Function(_) | Symbol::Constant(_)))
+        .map(|(symbol, spans)| (symbol.get_key(&indexed.interner), spans.len()))
+        .collect::<Vec<_>>();
+    keys.sort();
+
+    assert_eq!(keys, vec![("App\\VERSION".to_string(), 1), ("App\\helper()".to_string(), 1)]);
+}
@@ -5,6 +5,7 @@ use mago_span::Span;
 
 use crate::attribute::AttributeReflection;
 use crate::class_like::member::ClassLikeMemberVisibilityReflection;
+use crate::deprecation::DeprecationReflection;
 use crate::identifier::ClassLikeMemberName;
 use crate::r#type::TypeReflection;
 
@@ -16,6 +17,7 @@ pub struct ClassLikeConstantReflection {
     pub type_reflection: Option<TypeReflection>,
     pub inferred_type_reflection: TypeReflection,
     pub is_final: bool,
+    pub deprecation: Option<DeprecationReflection>,
     pub item_span: Span,
     pub definition_span: Span,
 }
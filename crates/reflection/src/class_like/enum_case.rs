@@ -4,6 +4,7 @@ use serde::Serialize;
 use mago_span::Span;
 
 use crate::attribute::AttributeReflection;
+use crate::deprecation::DeprecationReflection;
 use crate::identifier::ClassLikeMemberName;
 use crate::r#type::TypeReflection;
 
@@ -13,5 +14,6 @@ pub struct EnumCaseReflection {
     pub name: ClassLikeMemberName,
     pub type_reflection: Option<TypeReflection>,
     pub is_backed: bool,
+    pub deprecation: Option<DeprecationReflection>,
     pub span: Span,
 }
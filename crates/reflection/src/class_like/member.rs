@@ -54,4 +54,13 @@ impl ClassLikeMemberVisibilityReflection {
     pub fn is_private(&self) -> bool {
         matches!(self, ClassLikeMemberVisibilityReflection::Private { .. })
     }
+
+    /// Returns the span of the visibility modifier.
+    pub fn span(&self) -> Span {
+        match self {
+            ClassLikeMemberVisibilityReflection::Public { span }
+            | ClassLikeMemberVisibilityReflection::Protected { span }
+            | ClassLikeMemberVisibilityReflection::Private { span } => *span,
+        }
+    }
 }
@@ -12,9 +12,12 @@ use crate::class_like::enum_case::EnumCaseReflection;
 use crate::class_like::inheritance::InheritanceReflection;
 use crate::class_like::member::MemeberCollection;
 use crate::class_like::property::PropertyReflection;
+use crate::deprecation::DeprecationReflection;
 use crate::function_like::FunctionLikeReflection;
 use crate::identifier::ClassLikeName;
 use crate::identifier::Name;
+use crate::r#type::kind::Template;
+use crate::r#type::kind::TypeKind;
 use crate::r#type::TypeReflection;
 
 pub mod constant;
@@ -36,10 +39,28 @@ pub struct ClassLikeReflection {
     pub used_traits: HashSet<StringIdentifier>,
     pub used_trait_names: HashMap<StringIdentifier, Name>,
     pub backing_type: Option<TypeReflection>,
+
+    /// The templates declared by this class-like, using `@template` and its variants.
+    pub templates: Vec<Template>,
+
+    /// The type arguments provided to the templates of direct parents, interfaces, and traits
+    /// using `@extends`, `@implements`, and `@use`, keyed by the name of the parent.
+    pub template_type_arguments: HashMap<StringIdentifier, Vec<TypeKind>>,
+
+    /// The types bound to the templates of every ancestor of this class-like, keyed by the name
+    /// of the ancestor, then by the name of the template.
+    ///
+    /// This is computed from `template_type_arguments` when the codebase is populated.
+    pub template_extended_parameters: HashMap<StringIdentifier, HashMap<StringIdentifier, TypeKind>>,
+
     pub is_final: bool,
     pub is_readonly: bool,
     pub is_abstract: bool,
     pub is_anonymous: bool,
+
+    /// The deprecation of this class-like, if it is deprecated.
+    pub deprecation: Option<DeprecationReflection>,
+
     pub span: Span,
     pub is_populated: bool,
 }
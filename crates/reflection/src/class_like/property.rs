@@ -25,6 +25,7 @@ pub struct PropertyReflection {
     pub write_visibility_reflection: Option<ClassLikeMemberVisibilityReflection>,
     pub name: ClassLikeMemberName,
     pub type_reflection: Option<TypeReflection>,
+    pub docblock_type_reflection: Option<TypeReflection>,
     pub default_value_reflection: Option<PropertyDefaultValueReflection>,
     pub hooks: HashMap<StringIdentifier, FunctionLikeReflection>,
     pub is_readonly: bool,
@@ -34,3 +35,12 @@ pub struct PropertyReflection {
     pub item_span: Span,
     pub definition_span: Span,
 }
+
+impl PropertyReflection {
+    /// Returns the most specific known type of this property.
+    ///
+    /// The docblock type ( `@var` ) is preferred over the native type, if both are specified.
+    pub fn get_type_reflection(&self) -> Option<&TypeReflection> {
+        self.docblock_type_reflection.as_ref().or(self.type_reflection.as_ref())
+    }
+}
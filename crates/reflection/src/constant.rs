@@ -3,6 +3,7 @@ use serde::Serialize;
 
 use mago_span::Span;
 
+use crate::deprecation::DeprecationReflection;
 use crate::identifier::Name;
 use crate::r#type::TypeReflection;
 
@@ -10,6 +11,7 @@ use crate::r#type::TypeReflection;
 pub struct ConstantReflection {
     pub name: Name,
     pub type_reflection: TypeReflection,
+    pub deprecation: Option<DeprecationReflection>,
     pub item_span: Span,
     pub definition_span: Span,
     pub is_populated: bool,
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_interner::StringIdentifier;
+use mago_span::Span;
+
+/// Represents the deprecation of a symbol, declared using the `#[\Deprecated]` attribute
+/// or the `@deprecated` docblock tag.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct DeprecationReflection {
+    /// The message explaining the deprecation, if any.
+    pub message: Option<StringIdentifier>,
+
+    /// The span of the attribute or docblock tag declaring the deprecation.
+    pub span: Span,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_interner::StringIdentifier;
+use mago_span::Span;
+
+use crate::assertion::Assertion;
+
+/// Represents an assertion made by a function-like entity about one of its parameters,
+/// as declared in its docblock using `@assert`, `@assert-if-true`, or `@assert-if-false`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct FunctionLikeAssertionReflection {
+    /// The name of the parameter the assertion applies to, including the leading `$`.
+    pub parameter_name: StringIdentifier,
+
+    /// The assertion made about the parameter.
+    pub assertion: Assertion,
+
+    /// The location in the source code where the assertion is declared.
+    pub span: Span,
+}
@@ -1,15 +1,22 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use mago_interner::StringIdentifier;
 use mago_span::Span;
 
+use crate::assertion::Assertion;
 use crate::attribute::AttributeReflection;
 use crate::class_like::member::ClassLikeMemberVisibilityReflection;
+use crate::deprecation::DeprecationReflection;
+use crate::function_like::assertion::FunctionLikeAssertionReflection;
 use crate::function_like::parameter::FunctionLikeParameterReflection;
 use crate::function_like::r#return::FunctionLikeReturnTypeReflection;
 use crate::identifier::FunctionLikeName;
 use crate::r#type::kind::Template;
+use crate::r#type::kind::TypeKind;
+use crate::r#type::TypeReflection;
 
+pub mod assertion;
 pub mod parameter;
 pub mod r#return;
 
@@ -37,6 +44,20 @@ pub struct FunctionLikeReflection {
     /// The return type of this function or method, if specified.
     pub return_type_reflection: Option<FunctionLikeReturnTypeReflection>,
 
+    /// The return type of this function or method, as specified in its docblock using `@return`.
+    pub docblock_return_type_reflection: Option<TypeReflection>,
+
+    /// Assertions that hold for the arguments once this function or method returns, as specified using `@assert`.
+    pub assertions: Vec<FunctionLikeAssertionReflection>,
+
+    /// Assertions that hold for the arguments if this function or method returns `true`,
+    /// as specified using `@assert-if-true`.
+    pub if_true_assertions: Vec<FunctionLikeAssertionReflection>,
+
+    /// Assertions that hold for the arguments if this function or method returns `false`,
+    /// as specified using `@assert-if-false`.
+    pub if_false_assertions: Vec<FunctionLikeAssertionReflection>,
+
     /// Indicates whether the function or method returns by reference.
     pub returns_by_reference: bool,
 
@@ -75,6 +96,13 @@ pub struct FunctionLikeReflection {
     /// it depends on whether they override a parent method.
     pub is_overriding: bool,
 
+    /// Indicates if this method is a magic method declared using `@method` in the docblock of a class-like,
+    /// rather than an actual method.
+    pub is_magic: bool,
+
+    /// The deprecation of this function or method, if it is deprecated.
+    pub deprecation: Option<DeprecationReflection>,
+
     /// The span in the source code where this function or method is defined.
     pub span: Span,
 
@@ -102,4 +130,57 @@ impl FunctionLikeReflection {
     pub fn is_arrow_function(&self) -> bool {
         matches!(self.name, FunctionLikeName::ArrowFunction(_))
     }
+
+    /// Returns the most specific known return type of this function-like.
+    ///
+    /// The docblock return type is preferred over the native return type, if both are specified.
+    pub fn get_return_type_reflection(&self) -> Option<&TypeReflection> {
+        self.docblock_return_type_reflection
+            .as_ref()
+            .or_else(|| self.return_type_reflection.as_ref().map(|r| &r.type_reflection))
+    }
+
+    /// Returns a copy of this function-like, with generic parameters in its signature
+    /// replaced using the given resolver.
+    ///
+    /// See [`TypeKind::replace_generic_parameters`] for details.
+    pub fn replace_generic_parameters<F>(&self, resolver: &F) -> FunctionLikeReflection
+    where
+        F: Fn(&StringIdentifier, &StringIdentifier) -> Option<TypeKind>,
+    {
+        let replace = |reflection: &TypeReflection| TypeReflection {
+            kind: reflection.kind.replace_generic_parameters(resolver),
+            inferred: reflection.inferred,
+            span: reflection.span,
+        };
+
+        let mut reflection = self.clone();
+        for parameter in reflection.parameters.iter_mut() {
+            parameter.type_reflection = parameter.type_reflection.as_ref().map(replace);
+            parameter.docblock_type_reflection = parameter.docblock_type_reflection.as_ref().map(replace);
+        }
+
+        if let Some(return_type_reflection) = reflection.return_type_reflection.as_mut() {
+            return_type_reflection.type_reflection = replace(&return_type_reflection.type_reflection);
+        }
+
+        reflection.docblock_return_type_reflection = reflection.docblock_return_type_reflection.as_ref().map(replace);
+
+        for assertion in reflection
+            .assertions
+            .iter_mut()
+            .chain(reflection.if_true_assertions.iter_mut())
+            .chain(reflection.if_false_assertions.iter_mut())
+        {
+            assertion.assertion = match &assertion.assertion {
+                Assertion::IsType(kind) => Assertion::IsType(kind.replace_generic_parameters(resolver)),
+                Assertion::IsNotType(kind) => Assertion::IsNotType(kind.replace_generic_parameters(resolver)),
+                Assertion::IsEqual(kind) => Assertion::IsEqual(kind.replace_generic_parameters(resolver)),
+                Assertion::IsNotEqual(kind) => Assertion::IsNotEqual(kind.replace_generic_parameters(resolver)),
+                other => other.clone(),
+            };
+        }
+
+        reflection
+    }
 }
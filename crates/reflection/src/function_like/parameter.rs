@@ -29,6 +29,9 @@ pub struct FunctionLikeParameterReflection {
     /// The type of the parameter, if specified.
     pub type_reflection: Option<TypeReflection>,
 
+    /// The type of the parameter, as specified in the docblock of the function-like using `@param`.
+    pub docblock_type_reflection: Option<TypeReflection>,
+
     /// The name identifier of the parameter.
     pub name: StringIdentifier,
 
@@ -44,3 +47,12 @@ pub struct FunctionLikeParameterReflection {
     /// The default value of the parameter, if any, including its type and span in the source code.
     pub default: Option<FunctionLikeParameterDefaultValueReflection>,
 }
+
+impl FunctionLikeParameterReflection {
+    /// Returns the most specific known type of this parameter.
+    ///
+    /// The docblock type is preferred over the native type, if both are specified.
+    pub fn get_type_reflection(&self) -> Option<&TypeReflection> {
+        self.docblock_type_reflection.as_ref().or(self.type_reflection.as_ref())
+    }
+}
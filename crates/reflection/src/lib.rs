@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 
 use ahash::HashMap;
@@ -8,17 +9,20 @@ use serde::Serialize;
 use mago_interner::StringIdentifier;
 use mago_span::HasPosition;
 
+use crate::class_like::property::PropertyReflection;
 use crate::class_like::ClassLikeReflection;
 use crate::constant::ConstantReflection;
 use crate::function_like::FunctionLikeReflection;
 use crate::identifier::ClassLikeName;
 use crate::identifier::FunctionLikeName;
 use crate::identifier::Name;
+use crate::r#type::kind::TypeKind;
 
 pub mod assertion;
 pub mod attribute;
 pub mod class_like;
 pub mod constant;
+pub mod deprecation;
 pub mod function_like;
 pub mod identifier;
 pub mod r#type;
@@ -263,12 +267,109 @@ impl CodebaseReflection {
         }
     }
 
-    /// Returns the function-like reflection (function, closure, etc.) that encloses the given offset.
+    /// Retrieves a method of the given class-like by name, including inherited methods.
     ///
-    /// This method iterates through the reflections in the codebase, filtering for function-like reflections
-    /// that contain the given offset in their definition range. It returns the reflection with the
-    /// largest starting offset, effectively finding the innermost function-like reflection containing
-    /// the offset.
+    /// If the method is inherited from a templated parent, interface, or trait, the templates
+    /// of the declaring class-like are replaced in its signature by the types the given
+    /// class-like binds them to (using `@extends`, `@implements`, or `@use`).
+    ///
+    /// # Parameters
+    ///
+    /// - `class_like`: The class-like to look up the method in.
+    /// - `method_name`: The name of the method.
+    ///
+    /// # Returns
+    ///
+    /// The method reflection, specialized for the given class-like, if it exists.
+    pub fn get_method<'a>(
+        &'a self,
+        class_like: &'a ClassLikeReflection,
+        method_name: &StringIdentifier,
+    ) -> Option<Cow<'a, FunctionLikeReflection>> {
+        if let Some(method) = class_like.get_method(method_name) {
+            return Some(Cow::Borrowed(method));
+        }
+
+        let declaring_class_like_name = class_like.methods.declaring_members.get(method_name)?;
+        let declaring_class_like = self.get_class_like(*declaring_class_like_name)?;
+        let method = declaring_class_like.get_method(method_name)?;
+
+        let Some(parameters) = self.get_template_parameters_for(class_like, declaring_class_like) else {
+            return Some(Cow::Borrowed(method));
+        };
+
+        let declaring_class_like_id = declaring_class_like.name.inner().map(|n| n.value);
+
+        Some(Cow::Owned(method.replace_generic_parameters(&|name, defined_in| {
+            parameters.get(name).filter(|_| Some(*defined_in) == declaring_class_like_id).cloned()
+        })))
+    }
+
+    /// Retrieves a property of the given class-like by name, including inherited properties.
+    ///
+    /// Similar to [`CodebaseReflection::get_method`], the templates of the declaring class-like
+    /// are replaced in the type of the property by the types the given class-like binds them to.
+    ///
+    /// # Parameters
+    ///
+    /// - `class_like`: The class-like to look up the property in.
+    /// - `property_name`: The name of the property, including the leading `$`.
+    ///
+    /// # Returns
+    ///
+    /// The property reflection, specialized for the given class-like, if it exists.
+    pub fn get_property<'a>(
+        &'a self,
+        class_like: &'a ClassLikeReflection,
+        property_name: &StringIdentifier,
+    ) -> Option<Cow<'a, PropertyReflection>> {
+        if let Some(property) = class_like.get_property(property_name) {
+            return Some(Cow::Borrowed(property));
+        }
+
+        let declaring_class_like_name = class_like.properties.declaring_members.get(property_name)?;
+        let declaring_class_like = self.get_class_like(*declaring_class_like_name)?;
+        let property = declaring_class_like.get_property(property_name)?;
+
+        let Some(parameters) = self.get_template_parameters_for(class_like, declaring_class_like) else {
+            return Some(Cow::Borrowed(property));
+        };
+
+        let declaring_class_like_id = declaring_class_like.name.inner().map(|n| n.value);
+        let resolver = |name: &StringIdentifier, defined_in: &StringIdentifier| {
+            parameters.get(name).filter(|_| Some(*defined_in) == declaring_class_like_id).cloned()
+        };
+
+        let mut property = property.clone();
+        if let Some(type_reflection) = property.type_reflection.as_mut() {
+            type_reflection.kind = type_reflection.kind.replace_generic_parameters(&resolver);
+        }
+
+        if let Some(type_reflection) = property.docblock_type_reflection.as_mut() {
+            type_reflection.kind = type_reflection.kind.replace_generic_parameters(&resolver);
+        }
+
+        Some(Cow::Owned(property))
+    }
+
+    fn get_template_parameters_for<'a>(
+        &self,
+        class_like: &'a ClassLikeReflection,
+        ancestor: &ClassLikeReflection,
+    ) -> Option<&'a HashMap<StringIdentifier, TypeKind>> {
+        if ancestor.templates.is_empty() {
+            return None;
+        }
+
+        class_like.template_extended_parameters.get(&ancestor.name.inner()?.value)
+    }
+
+    /// Returns the function-like reflection (function, method, closure, etc.) that encloses the given offset.
+    ///
+    /// This method iterates through the function-like reflections in the codebase, and the methods of the
+    /// enclosing class-like, filtering for those that contain the given offset in their definition range.
+    /// It returns the reflection with the largest starting offset, effectively finding the innermost
+    /// function-like reflection containing the offset.
     ///
     /// # Arguments
     ///
@@ -278,11 +379,16 @@ impl CodebaseReflection {
     ///
     /// * `Option<&FunctionLikeReflection>` - The enclosing function-like reflection, if found.
     pub fn get_enclosing_function_like(&self, has_position: &impl HasPosition) -> Option<&FunctionLikeReflection> {
+        let methods = self
+            .get_enclosing_class_like(has_position)
+            .into_iter()
+            .flat_map(|class_like| class_like.methods.members.values());
+
         self.function_like_reflections
-            .iter()
-            .filter(|(_, function_like)| function_like.span.has_offset(has_position.offset()))
-            .max_by_key(|(_, function_like)| function_like.span.start.offset)
-            .map(|(_, function_like)| function_like)
+            .values()
+            .chain(methods)
+            .filter(|function_like| function_like.span.contains(has_position))
+            .max_by_key(|function_like| function_like.span.start.offset)
     }
 
     /// Returns the class-like reflection (class, trait, etc.) that encloses the given offset.
@@ -302,7 +408,7 @@ impl CodebaseReflection {
     pub fn get_enclosing_class_like(&self, has_position: &impl HasPosition) -> Option<&ClassLikeReflection> {
         self.class_like_reflections
             .iter()
-            .filter(|(_, class_like)| class_like.span.has_offset(has_position.offset()))
+            .filter(|(_, class_like)| class_like.span.contains(has_position))
             .max_by_key(|(_, class_like)| class_like.span.start.offset)
             .map(|(_, class_like)| class_like)
     }
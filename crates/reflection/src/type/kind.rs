@@ -15,10 +15,26 @@ use crate::identifier::ClassLikeName;
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub struct Template {
     /// The name of the template parameter.
-    name: StringIdentifier,
+    pub name: StringIdentifier,
 
     /// A list of type constraints that the template parameter must satisfy.
-    constraints: Vec<TypeKind>,
+    pub constraints: Vec<TypeKind>,
+
+    /// The variance of the template parameter.
+    pub variance: TemplateVariance,
+}
+
+/// Represents the variance of a template parameter.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum TemplateVariance {
+    /// The template parameter is declared using `@template`.
+    Invariant,
+
+    /// The template parameter is declared using `@template-covariant`.
+    Covariant,
+
+    /// The template parameter is declared using `@template-contravariant`.
+    Contravariant,
 }
 
 /// Represents scalar types, including specialized scalar types with additional properties.
@@ -398,6 +414,21 @@ pub enum TypeKind {
 }
 
 impl Template {
+    pub fn new(name: StringIdentifier, constraints: Vec<TypeKind>, variance: TemplateVariance) -> Self {
+        Self { name, constraints, variance }
+    }
+
+    /// Returns the type that the template parameter is bound to when no type is provided for it.
+    ///
+    /// This is the intersection of its constraints, or `mixed` if it has none.
+    pub fn get_bound(&self) -> TypeKind {
+        match self.constraints.len() {
+            0 => mixed_kind(true),
+            1 => self.constraints[0].clone(),
+            _ => intersection_kind(self.constraints.clone()),
+        }
+    }
+
     pub fn get_key(&self, interner: &ThreadedInterner) -> String {
         let mut key = String::from(interner.lookup(&self.name));
 
@@ -423,15 +454,23 @@ impl TypeKind {
     #[inline]
     pub fn is_nullable(&self) -> Trinary {
         match &self {
-            TypeKind::Union { kinds } => kinds.iter().map(|k| k.is_nullable()).collect(),
+            // A union is nullable if all of its members are, and may be nullable if any of them may be.
+            TypeKind::Union { kinds } => kinds
+                .iter()
+                .map(|k| k.is_nullable())
+                .reduce(|a, b| if a == b { a } else { Trinary::Maybe })
+                .unwrap_or(Trinary::False),
             TypeKind::Intersection { kinds } => kinds.iter().map(|k| k.is_nullable()).collect(),
             TypeKind::Value(ValueTypeKind::Null) => Trinary::True,
             TypeKind::Mixed { .. } => Trinary::Maybe,
             TypeKind::Scalar(_) => Trinary::False,
-            TypeKind::Object(_) => todo!(),
-            TypeKind::Array(_) => todo!(),
-            TypeKind::Callable(_) => todo!(),
-            TypeKind::Conditional { then, otherwise, .. } => then.is_nullable() & otherwise.is_nullable(),
+            TypeKind::Object(_) => Trinary::False,
+            TypeKind::Array(_) => Trinary::False,
+            TypeKind::Callable(_) => Trinary::False,
+            TypeKind::Conditional { then, otherwise, .. } => match (then.is_nullable(), otherwise.is_nullable()) {
+                (a, b) if a == b => a,
+                _ => Trinary::Maybe,
+            },
             TypeKind::KeyOf { .. } => Trinary::False,
             TypeKind::ValueOf { .. } => Trinary::Maybe,
             TypeKind::PropertiesOf { .. } => Trinary::Maybe,
@@ -522,9 +561,9 @@ impl TypeKind {
             TypeKind::Scalar(scalar_type_kind) => match scalar_type_kind {
                 ScalarTypeKind::Bool => Trinary::Maybe,
                 ScalarTypeKind::Integer { min, max } => {
-                    if min.map(|m| m > 0).unwrap_or(false) {
+                    if min.map(|m| m > 0).unwrap_or(false) || max.map(|m| m < 0).unwrap_or(false) {
                         Trinary::True
-                    } else if max.map(|m| m < 0).unwrap_or(false) {
+                    } else if *min == Some(0) && *max == Some(0) {
                         Trinary::False
                     } else {
                         Trinary::Maybe
@@ -533,9 +572,9 @@ impl TypeKind {
                 ScalarTypeKind::Float => Trinary::Maybe,
                 ScalarTypeKind::String => Trinary::Maybe,
                 ScalarTypeKind::IntegerMask(bits) => {
-                    if bits.iter().all(|b| *b > 0) {
+                    if bits.iter().all(|b| *b != 0) {
                         Trinary::True
-                    } else if bits.iter().all(|b| *b < 0) {
+                    } else if bits.iter().all(|b| *b == 0) {
                         Trinary::False
                     } else {
                         Trinary::Maybe
@@ -559,14 +598,14 @@ impl TypeKind {
             TypeKind::Value(value_type_kind) => match &value_type_kind {
                 ValueTypeKind::String { .. } => Trinary::Maybe,
                 ValueTypeKind::Integer { value } => {
-                    if *value > 0 {
+                    if *value != 0 {
                         Trinary::True
                     } else {
                         Trinary::False
                     }
                 }
                 ValueTypeKind::Float { value } => {
-                    if *value > OrderedFloat(0.0) {
+                    if *value != OrderedFloat(0.0) {
                         Trinary::True
                     } else {
                         Trinary::False
@@ -697,6 +736,122 @@ impl TypeKind {
         matches!(self, TypeKind::Object(ObjectTypeKind::Generator { .. }))
     }
 
+    /// Returns a copy of this type, with generic parameters replaced using the given resolver.
+    ///
+    /// The resolver receives the name of the generic parameter, and the name of the class-like or
+    /// function-like it is defined in, and returns the type to replace it with, if any.
+    ///
+    /// Generic parameters that are not resolved are kept as-is.
+    pub fn replace_generic_parameters<F>(&self, resolver: &F) -> TypeKind
+    where
+        F: Fn(&StringIdentifier, &StringIdentifier) -> Option<TypeKind>,
+    {
+        let replace = |kind: &TypeKind| kind.replace_generic_parameters(resolver);
+        let replace_boxed = |kind: &TypeKind| Box::new(kind.replace_generic_parameters(resolver));
+        let replace_template = |template: &Template| Template {
+            name: template.name,
+            constraints: template.constraints.iter().map(replace).collect(),
+            variance: template.variance,
+        };
+        let replace_parameter = |parameter: &CallableParameter| CallableParameter {
+            kind: replace(&parameter.kind),
+            optional: parameter.optional,
+            variadic: parameter.variadic,
+        };
+
+        match self {
+            TypeKind::GenericParameter { name, of, defined_in } => match resolver(name, defined_in) {
+                Some(kind) => kind,
+                None => TypeKind::GenericParameter { name: *name, of: replace_boxed(of), defined_in: *defined_in },
+            },
+            TypeKind::Union { kinds } => TypeKind::Union { kinds: kinds.iter().map(replace).collect() },
+            TypeKind::Intersection { kinds } => TypeKind::Intersection { kinds: kinds.iter().map(replace).collect() },
+            TypeKind::Object(object) => TypeKind::Object(match object {
+                ObjectTypeKind::TypedObject { properties } => ObjectTypeKind::TypedObject {
+                    properties: properties
+                        .iter()
+                        .map(|property| ObjectProperty {
+                            name: property.name,
+                            kind: replace(&property.kind),
+                            optional: property.optional,
+                        })
+                        .collect(),
+                },
+                ObjectTypeKind::NamedObject { name, type_parameters } => ObjectTypeKind::NamedObject {
+                    name: *name,
+                    type_parameters: type_parameters.iter().map(replace).collect(),
+                },
+                ObjectTypeKind::Generator { key, value, send, r#return } => ObjectTypeKind::Generator {
+                    key: replace_boxed(key),
+                    value: replace_boxed(value),
+                    send: replace_boxed(send),
+                    r#return: replace_boxed(r#return),
+                },
+                object => object.clone(),
+            }),
+            TypeKind::Array(array) => TypeKind::Array(match array {
+                ArrayTypeKind::Array { non_empty, key, value, known_size } => ArrayTypeKind::Array {
+                    non_empty: *non_empty,
+                    key: replace_boxed(key),
+                    value: replace_boxed(value),
+                    known_size: *known_size,
+                },
+                ArrayTypeKind::List { non_empty, value, known_size } => {
+                    ArrayTypeKind::List { non_empty: *non_empty, value: replace_boxed(value), known_size: *known_size }
+                }
+                ArrayTypeKind::CallableArray => ArrayTypeKind::CallableArray,
+                ArrayTypeKind::Shape(shape) => ArrayTypeKind::Shape(ArrayShape {
+                    properties: shape
+                        .properties
+                        .iter()
+                        .map(|property| ArrayShapeProperty {
+                            key: property.key,
+                            kind: replace(&property.kind),
+                            optional: property.optional,
+                        })
+                        .collect(),
+                    additional_properties: shape
+                        .additional_properties
+                        .as_ref()
+                        .map(|(key, value)| (replace_boxed(key), replace_boxed(value))),
+                }),
+            }),
+            TypeKind::Callable(callable) => TypeKind::Callable(match callable {
+                CallableTypeKind::Callable { pure, templates, parameters, return_kind } => CallableTypeKind::Callable {
+                    pure: *pure,
+                    templates: templates.iter().map(replace_template).collect(),
+                    parameters: parameters.iter().map(replace_parameter).collect(),
+                    return_kind: replace_boxed(return_kind),
+                },
+                CallableTypeKind::Closure { pure, templates, parameters, return_kind } => CallableTypeKind::Closure {
+                    pure: *pure,
+                    templates: templates.iter().map(replace_template).collect(),
+                    parameters: parameters.iter().map(replace_parameter).collect(),
+                    return_kind: replace_boxed(return_kind),
+                },
+            }),
+            TypeKind::Conditional { parameter, condition, then, otherwise } => TypeKind::Conditional {
+                parameter: replace_boxed(parameter),
+                condition: replace_boxed(condition),
+                then: replace_boxed(then),
+                otherwise: replace_boxed(otherwise),
+            },
+            TypeKind::KeyOf { kind } => TypeKind::KeyOf { kind: replace_boxed(kind) },
+            TypeKind::ValueOf { kind } => TypeKind::ValueOf { kind: replace_boxed(kind) },
+            TypeKind::PropertiesOf { kind } => TypeKind::PropertiesOf { kind: replace_boxed(kind) },
+            TypeKind::ClassStringMap { key, value_kind } => {
+                TypeKind::ClassStringMap { key: replace_template(key), value_kind: replace_boxed(value_kind) }
+            }
+            TypeKind::Index { base_kind, index_kind } => {
+                TypeKind::Index { base_kind: replace_boxed(base_kind), index_kind: replace_boxed(index_kind) }
+            }
+            TypeKind::Iterable { key, value } => {
+                TypeKind::Iterable { key: replace_boxed(key), value: replace_boxed(value) }
+            }
+            kind => kind.clone(),
+        }
+    }
+
     pub fn get_key(&self, interner: &ThreadedInterner) -> String {
         match &self {
             TypeKind::Union { kinds } => kinds.iter().map(|k| k.get_key(interner)).collect::<Vec<_>>().join("|"),
@@ -926,7 +1081,7 @@ impl TypeKind {
             },
             TypeKind::Value(value_type_kind) => match &value_type_kind {
                 ValueTypeKind::String { value, .. } => {
-                    format!("\"{}\"", value)
+                    format!("\"{}\"", interner.lookup(value))
                 }
                 ValueTypeKind::Integer { value } => value.to_string(),
                 ValueTypeKind::Float { value } => value.to_string(),
@@ -1101,6 +1256,11 @@ pub fn mixed_kind(explicit: bool) -> TypeKind {
     TypeKind::Mixed { explicit }
 }
 
+/// Creates a `TypeKind` representing a generic parameter with the given name, bound, and defining entity.
+pub fn generic_parameter_kind(name: StringIdentifier, of: TypeKind, defined_in: StringIdentifier) -> TypeKind {
+    TypeKind::GenericParameter { name, of: Box::new(of), defined_in }
+}
+
 /// Creates a `TypeKind` representing a union of the given types.
 pub fn union_kind(kinds: Vec<TypeKind>) -> TypeKind {
     TypeKind::Union { kinds }
@@ -1303,16 +1463,13 @@ impl From<&FunctionLikeReflection> for TypeKind {
             .iter()
             .map(|parameter| CallableParameter {
                 optional: parameter.default.is_some(),
-                kind: parameter.type_reflection.as_ref().map(|r| r.kind.clone()).unwrap_or_else(|| mixed_kind(false)),
+                kind: parameter.get_type_reflection().map(|r| r.kind.clone()).unwrap_or_else(|| mixed_kind(false)),
                 variadic: parameter.is_variadic,
             })
             .collect();
 
-        let return_kind = reflection
-            .return_type_reflection
-            .as_ref()
-            .map(|r| r.type_reflection.kind.clone())
-            .unwrap_or_else(|| mixed_kind(false));
+        let return_kind =
+            reflection.get_return_type_reflection().map(|r| r.kind.clone()).unwrap_or_else(|| mixed_kind(false));
 
         TypeKind::Callable(CallableTypeKind::Closure {
             pure: reflection.is_pure,
@@ -1,3 +1,6 @@
+use ahash::HashMap;
+
+use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 use mago_semantics::Semantics;
 
@@ -5,10 +8,53 @@ use mago_semantics::Semantics;
 pub struct Context<'a> {
     pub interner: &'a ThreadedInterner,
     pub semantics: &'a Semantics,
+    namespace: Option<String>,
+    aliases: HashMap<String, String>,
 }
 
 impl<'a> Context<'a> {
     pub fn new(interner: &'a ThreadedInterner, semantics: &'a Semantics) -> Self {
-        Self { interner, semantics }
+        Self { interner, semantics, namespace: None, aliases: HashMap::default() }
+    }
+
+    pub fn enter_namespace(&mut self, namespace: Option<&str>) {
+        self.namespace = namespace.filter(|n| !n.is_empty()).map(|n| n.to_string());
+        self.aliases.clear();
+    }
+
+    pub fn add_alias(&mut self, name: &str, alias: Option<&str>) {
+        let name = name.trim_start_matches('\\');
+        let alias = match alias {
+            Some(alias) => alias,
+            None => name.rsplit('\\').next().unwrap_or(name),
+        };
+
+        self.aliases.insert(alias.to_ascii_lowercase(), name.to_string());
+    }
+
+    /// Resolves a class-like name found in a docblock, using the current namespace and imports.
+    ///
+    /// Names in docblocks are not resolved by `mago_names`, as they are not part of the AST.
+    pub fn resolve_class_like_name(&self, name: &str) -> StringIdentifier {
+        if let Some(fully_qualified) = name.strip_prefix('\\') {
+            return self.interner.intern(fully_qualified);
+        }
+
+        let (first, rest) = match name.split_once('\\') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (name, None),
+        };
+
+        if let Some(imported) = self.aliases.get(&first.to_ascii_lowercase()) {
+            return match rest {
+                Some(rest) => self.interner.intern(format!("{}\\{}", imported, rest)),
+                None => self.interner.intern(imported),
+            };
+        }
+
+        match &self.namespace {
+            Some(namespace) => self.interner.intern(format!("{}\\{}", namespace, name)),
+            None => self.interner.intern(name),
+        }
     }
 }
@@ -2,7 +2,10 @@ use mago_ast::*;
 use mago_reflection::attribute::AttributeArgumentListReflection;
 use mago_reflection::attribute::AttributeArgumentReflection;
 use mago_reflection::attribute::AttributeReflection;
+use mago_reflection::deprecation::DeprecationReflection;
 use mago_reflection::identifier::Name;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::r#type::kind::ValueTypeKind;
 use mago_span::*;
 
 use crate::internal::context::Context;
@@ -53,3 +56,42 @@ pub fn reflect_attribute_arguments<'ast>(
 
     Some(AttributeArgumentListReflection { arguments })
 }
+
+/// Reflects the deprecation declared by the given attributes, if any.
+///
+/// Both the native `#[\Deprecated]` attribute, and the `#[\JetBrains\PhpStorm\Deprecated]` attribute
+/// used by PhpStorm stubs are recognized, along with their message ( or reason ) argument.
+pub fn reflect_deprecation_attribute(
+    attributes: &[AttributeReflection],
+    context: &Context<'_>,
+) -> Option<DeprecationReflection> {
+    let attribute = attributes.iter().find(|attribute| {
+        let name = context.interner.lookup(&attribute.name.value);
+
+        name.eq_ignore_ascii_case("Deprecated") || name.eq_ignore_ascii_case("JetBrains\\PhpStorm\\Deprecated")
+    })?;
+
+    let message = attribute.arguments.as_ref().and_then(|argument_list| {
+        argument_list.arguments.iter().enumerate().find_map(|(index, argument)| {
+            let value_type_reflection = match argument {
+                AttributeArgumentReflection::Positional { value_type_reflection, .. } if index == 0 => {
+                    value_type_reflection
+                }
+                AttributeArgumentReflection::Named { name, value_type_reflection, .. } => {
+                    match context.interner.lookup(&name.value) {
+                        "message" | "reason" => value_type_reflection,
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+
+            match value_type_reflection.kind {
+                TypeKind::Value(ValueTypeKind::String { value, .. }) if !value.is_empty() => Some(value),
+                _ => None,
+            }
+        })
+    });
+
+    Some(DeprecationReflection { message, span: attribute.span })
+}
@@ -18,6 +18,12 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflect::attribute::reflect_attributes;
+use crate::internal::reflect::attribute::reflect_deprecation_attribute;
+use crate::internal::reflect::docblock::reflect_class_like_docblock;
+use crate::internal::reflect::docblock::reflect_deprecation_docblock;
+use crate::internal::reflect::docblock::reflect_function_like_docblock;
+use crate::internal::reflect::docblock::reflect_property_docblock_type;
+use crate::internal::reflect::docblock::reflect_trait_use_docblock;
 
 use super::function_like::reflect_function_like_parameter_list;
 use super::function_like::reflect_function_like_return_type_hint;
@@ -53,6 +59,9 @@ pub fn reflect_class<'ast>(class: &'ast Class, context: &'ast mut Context<'_>) -
             reflection
         },
         backing_type: None,
+        templates: vec![],
+        template_type_arguments: Default::default(),
+        template_extended_parameters: Default::default(),
         is_final: class.modifiers.contains_final(),
         is_readonly: class.modifiers.contains_readonly(),
         is_abstract: class.modifiers.contains_abstract(),
@@ -65,8 +74,10 @@ pub fn reflect_class<'ast>(class: &'ast Class, context: &'ast mut Context<'_>) -
         used_trait_names: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        deprecation: None,
     };
 
+    reflect_class_like_docblock_into(&mut reflection, class, context);
     reflect_class_like_members(&mut reflection, &class.members, context);
 
     reflection
@@ -104,6 +115,9 @@ pub fn reflect_anonymous_class<'ast>(
             reflection
         },
         backing_type: None,
+        templates: vec![],
+        template_type_arguments: Default::default(),
+        template_extended_parameters: Default::default(),
         is_final: class.modifiers.contains_final(),
         is_readonly: class.modifiers.contains_readonly(),
         is_abstract: class.modifiers.contains_abstract(),
@@ -116,8 +130,10 @@ pub fn reflect_anonymous_class<'ast>(
         used_trait_names: Default::default(),
         is_populated: false,
         is_anonymous: true,
+        deprecation: None,
     };
 
+    reflect_class_like_docblock_into(&mut reflection, class, context);
     reflect_class_like_members(&mut reflection, &class.members, context);
 
     reflection
@@ -143,6 +159,9 @@ pub fn reflect_interface<'ast>(interface: &'ast Interface, context: &'ast mut Co
             reflection
         },
         backing_type: None,
+        templates: vec![],
+        template_type_arguments: Default::default(),
+        template_extended_parameters: Default::default(),
         is_final: false,
         is_readonly: false,
         is_abstract: true,
@@ -155,8 +174,10 @@ pub fn reflect_interface<'ast>(interface: &'ast Interface, context: &'ast mut Co
         used_trait_names: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        deprecation: None,
     };
 
+    reflect_class_like_docblock_into(&mut reflection, interface, context);
     reflect_class_like_members(&mut reflection, &interface.members, context);
 
     reflection
@@ -168,6 +189,9 @@ pub fn reflect_trait<'ast>(r#trait: &'ast Trait, context: &'ast mut Context<'_>)
         name: ClassLikeName::Trait(Name::new(*context.semantics.names.get(&r#trait.name), r#trait.name.span)),
         inheritance: InheritanceReflection::default(),
         backing_type: None,
+        templates: vec![],
+        template_type_arguments: Default::default(),
+        template_extended_parameters: Default::default(),
         is_final: false,
         is_readonly: false,
         is_abstract: true,
@@ -180,8 +204,10 @@ pub fn reflect_trait<'ast>(r#trait: &'ast Trait, context: &'ast mut Context<'_>)
         used_trait_names: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        deprecation: None,
     };
 
+    reflect_class_like_docblock_into(&mut reflection, r#trait, context);
     reflect_class_like_members(&mut reflection, &r#trait.members, context);
 
     reflection
@@ -210,6 +236,9 @@ pub fn reflect_enum<'ast>(r#enum: &'ast Enum, context: &'ast mut Context<'_>) ->
             .backing_type_hint
             .as_ref()
             .map(|backing_type_hint| reflect_hint(&backing_type_hint.hint, context, None)),
+        templates: vec![],
+        template_type_arguments: Default::default(),
+        template_extended_parameters: Default::default(),
         is_final: true,
         is_readonly: true,
         is_abstract: false,
@@ -222,13 +251,39 @@ pub fn reflect_enum<'ast>(r#enum: &'ast Enum, context: &'ast mut Context<'_>) ->
         used_trait_names: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        deprecation: None,
     };
 
+    reflect_class_like_docblock_into(&mut reflection, r#enum, context);
     reflect_class_like_members(&mut reflection, &r#enum.members, context);
 
     reflection
 }
 
+fn reflect_class_like_docblock_into(reflection: &mut ClassLikeReflection, node: &impl HasSpan, context: &Context<'_>) {
+    let docblock = reflect_class_like_docblock(node, &reflection.name, context);
+
+    reflection.templates = docblock.templates;
+    reflection.deprecation =
+        reflect_deprecation_attribute(&reflection.attribute_reflections, context).or(docblock.deprecation);
+    reflection.template_type_arguments = docblock.template_type_arguments;
+
+    // Magic members are reflected before the declared members, so that declared members take precedence.
+    for method in docblock.methods {
+        let FunctionLikeName::Method(_, name) = method.name else {
+            continue;
+        };
+
+        reflection.methods.inheritable_members.insert(name.value, reflection.name);
+        reflection.methods.members.insert(name.value, method);
+    }
+
+    for property in docblock.properties {
+        reflection.properties.inheritable_members.insert(property.name.member.value, reflection.name);
+        reflection.properties.members.insert(property.name.member.value, property);
+    }
+}
+
 fn reflect_class_like_members<'ast>(
     reflection: &mut ClassLikeReflection,
     members: &'ast Sequence<ClassLikeMember>,
@@ -237,6 +292,11 @@ fn reflect_class_like_members<'ast>(
     for member in members.iter() {
         match &member {
             ClassLikeMember::TraitUse(trait_use) => {
+                // Type arguments provided in the docblock of the class-like take precedence.
+                for (used_trait, arguments) in reflect_trait_use_docblock(trait_use, context, reflection) {
+                    reflection.template_type_arguments.entry(used_trait).or_insert(arguments);
+                }
+
                 for trait_name in trait_use.trait_names.iter() {
                     let name = Name::new(*context.semantics.names.get(trait_name), trait_name.span());
 
@@ -268,6 +328,19 @@ fn reflect_class_like_members<'ast>(
                     reflection.methods.inheritable_members.insert(name.value, reflection.name);
                 }
 
+                if name_value.eq_ignore_ascii_case("__construct") {
+                    for prop_ref in reflect_class_like_promoted_properties(reflection, method, &meth_ref, context) {
+                        if prop_ref.read_visibility_reflection.map(|v| !v.is_private()).unwrap_or(true) {
+                            reflection
+                                .properties
+                                .inheritable_members
+                                .insert(prop_ref.name.member.value, reflection.name);
+                        }
+
+                        reflection.properties.members.insert(prop_ref.name.member.value, prop_ref);
+                    }
+                }
+
                 reflection.methods.members.insert(name.value, meth_ref);
             }
             ClassLikeMember::Property(property) => {
@@ -299,6 +372,8 @@ fn reflect_class_like_constant<'ast>(
     };
     let type_reflection = maybe_reflect_hint(&constant.hint, context, Some(class_like));
     let is_final = constant.modifiers.contains_final();
+    let deprecation = reflect_deprecation_attribute(&attribute_reflections, context)
+        .or_else(|| reflect_deprecation_docblock(constant, context));
 
     let mut reflections = vec![];
 
@@ -312,6 +387,7 @@ fn reflect_class_like_constant<'ast>(
                 member: Name::new(item.name.value, item.name.span),
             },
             is_final,
+            deprecation,
             inferred_type_reflection: mago_typing::infere(context.interner, context.semantics, &item.value),
             item_span: item.span(),
             definition_span: constant.span(),
@@ -345,11 +421,16 @@ fn reflect_class_like_enum_case<'ast>(
         ),
     };
 
+    let attribut_reflections = reflect_attributes(&case.attributes, context);
+    let deprecation = reflect_deprecation_attribute(&attribut_reflections, context)
+        .or_else(|| reflect_deprecation_docblock(case, context));
+
     EnumCaseReflection {
-        attribut_reflections: reflect_attributes(&case.attributes, context),
+        attribut_reflections,
         name: identifier,
         type_reflection,
         is_backed,
+        deprecation,
         span: case.span(),
     }
 }
@@ -376,36 +457,99 @@ fn reflect_class_like_method<'ast>(
         method.modifiers.get_private().map(|m| ClassLikeMemberVisibilityReflection::Private { span: m.span() })
     };
 
+    let function_like_name = FunctionLikeName::Method(class_like.name, name);
+    let mut docblock = reflect_function_like_docblock(method, &function_like_name, context, Some(class_like));
+    let attribute_reflections = reflect_attributes(&method.attributes, context);
+    let deprecation = reflect_deprecation_attribute(&attribute_reflections, context).or(docblock.deprecation);
+
     (
         name,
         FunctionLikeReflection {
-            attribute_reflections: reflect_attributes(&method.attributes, context),
+            attribute_reflections,
             visibility_reflection,
-            name: FunctionLikeName::Method(class_like.name, name),
-            // TODO: parse docblock to get the template list
-            templates: vec![],
-            parameters: reflect_function_like_parameter_list(&method.parameters, context, Some(class_like)),
+            name: function_like_name,
+            parameters: docblock.apply_to_parameters(reflect_function_like_parameter_list(
+                &method.parameters,
+                context,
+                Some(class_like),
+            )),
+            templates: docblock.templates,
             return_type_reflection: reflect_function_like_return_type_hint(
                 &method.return_type_hint,
                 context,
                 Some(class_like),
             ),
+            docblock_return_type_reflection: docblock.return_type,
+            assertions: docblock.assertions,
+            if_true_assertions: docblock.if_true_assertions,
+            if_false_assertions: docblock.if_false_assertions,
             returns_by_reference: method.ampersand.is_some(),
             has_yield,
             has_throws,
             is_anonymous: false,
-            // TODO: parse docblock to determine if pure
-            is_pure: false,
+            is_pure: docblock.is_pure,
             is_static: method.modifiers.contains_static(),
             is_final: class_like.is_final || method.modifiers.contains_final(),
             is_abstract,
             is_overriding: false,
+            is_magic: false,
+            deprecation,
             span: method.span(),
             is_populated: false,
         },
     )
 }
 
+fn reflect_class_like_promoted_properties<'ast>(
+    class_like: &mut ClassLikeReflection,
+    constructor: &'ast Method,
+    constructor_reflection: &FunctionLikeReflection,
+    context: &'ast mut Context<'_>,
+) -> Vec<PropertyReflection> {
+    let mut reflections = vec![];
+
+    for (parameter, parameter_reflection) in
+        constructor.parameters.parameters.iter().zip(constructor_reflection.parameters.iter())
+    {
+        if !parameter.is_promoted_property() {
+            continue;
+        }
+
+        let read_visibility_reflection = if let Some(m) = parameter.modifiers.get_protected() {
+            Some(ClassLikeMemberVisibilityReflection::Protected { span: m.span() })
+        } else if let Some(m) = parameter.modifiers.get_private() {
+            Some(ClassLikeMemberVisibilityReflection::Private { span: m.span() })
+        } else {
+            parameter.modifiers.get_public().map(|m| ClassLikeMemberVisibilityReflection::Public { span: m.span() })
+        };
+
+        // TODO(azjezz): take `(set)` modifiers into account.
+        let write_visibility_reflection = read_visibility_reflection;
+
+        reflections.push(PropertyReflection {
+            attribut_reflections: reflect_attributes(&parameter.attributes, context),
+            read_visibility_reflection,
+            write_visibility_reflection,
+            name: ClassLikeMemberName {
+                class_like: class_like.name,
+                member: Name::new(parameter.variable.name, parameter.variable.span),
+            },
+            type_reflection: parameter_reflection.type_reflection.clone(),
+            docblock_type_reflection: parameter_reflection.docblock_type_reflection.clone(),
+            default_value_reflection: None,
+            hooks: HashMap::default(),
+            is_readonly: class_like.is_readonly || parameter.modifiers.contains_readonly(),
+            is_final: class_like.is_final || parameter.modifiers.contains_final(),
+            is_promoted: true,
+            is_static: false,
+            item_span: parameter.span(),
+            definition_span: parameter.span(),
+        });
+    }
+
+    reflections
+}
+
 fn reflect_class_like_property<'ast>(
     class_like: &mut ClassLikeReflection,
     property: &'ast Property,
@@ -430,6 +574,7 @@ fn reflect_class_like_property<'ast>(
             // TODO(azjezz): take `(set)` modifiers into account.
             let write_visibility_reflection = read_visibility_reflection;
             let type_reflection = maybe_reflect_hint(&plain_property.hint, context, Some(class_like));
+            let docblock_type_reflection = reflect_property_docblock_type(plain_property, context, Some(class_like));
             let is_readonly = class_like.is_readonly || plain_property.modifiers.contains_readonly();
             let is_final = class_like.is_final || plain_property.modifiers.contains_final();
             let is_static = plain_property.modifiers.contains_static();
@@ -465,6 +610,7 @@ fn reflect_class_like_property<'ast>(
                     write_visibility_reflection,
                     name: identifier,
                     type_reflection: type_reflection.clone(),
+                    docblock_type_reflection: docblock_type_reflection.clone(),
                     default_value_reflection,
                     hooks: HashMap::default(),
                     is_readonly,
@@ -517,6 +663,7 @@ fn reflect_class_like_property<'ast>(
                 write_visibility_reflection,
                 name,
                 type_reflection: maybe_reflect_hint(&hooked_property.hint, context, Some(class_like)),
+                docblock_type_reflection: reflect_property_docblock_type(hooked_property, context, Some(class_like)),
                 default_value_reflection,
                 hooks: {
                     let mut map = HashMap::default();
@@ -552,6 +699,10 @@ fn reflect_class_like_property<'ast>(
                                     None => vec![],
                                 },
                                 return_type_reflection: None,
+                                docblock_return_type_reflection: None,
+                                assertions: vec![],
+                                if_true_assertions: vec![],
+                                if_false_assertions: vec![],
                                 returns_by_reference: hook.ampersand.is_some(),
                                 has_yield,
                                 has_throws,
@@ -561,6 +712,8 @@ fn reflect_class_like_property<'ast>(
                                 is_pure: false,
                                 is_abstract: false,
                                 is_overriding: false,
+                                is_magic: false,
+                                deprecation: None,
                                 span: hook.span(),
                                 visibility_reflection: None,
                                 is_populated: false,
@@ -4,6 +4,7 @@ use mago_reflection::identifier::Name;
 use mago_span::*;
 
 use crate::internal::context::Context;
+use crate::internal::reflect::docblock::reflect_deprecation_docblock;
 
 pub fn reflect_constant<'ast>(constant: &'ast Constant, context: &'ast mut Context<'_>) -> Vec<ConstantReflection> {
     let mut reflections = vec![];
@@ -13,6 +14,7 @@ pub fn reflect_constant<'ast>(constant: &'ast Constant, context: &'ast mut Conte
         reflections.push(ConstantReflection {
             name: Name::new(*name, item.name.span),
             type_reflection: mago_typing::infere(context.interner, context.semantics, &item.value),
+            deprecation: reflect_deprecation_docblock(constant, context),
             item_span: item.span(),
             definition_span: constant.span(),
             is_populated: false,
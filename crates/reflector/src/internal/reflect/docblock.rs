@@ -0,0 +1,652 @@
+use ahash::HashMap;
+
+use mago_docblock::document::Document;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_docblock::document::TagKind;
+use mago_docblock::r#type::parse_type;
+use mago_docblock::r#type::Type;
+use mago_interner::StringIdentifier;
+use mago_reflection::assertion::Assertion;
+use mago_reflection::class_like::member::ClassLikeMemberVisibilityReflection;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::deprecation::DeprecationReflection;
+use mago_reflection::function_like::assertion::FunctionLikeAssertionReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterDefaultValueReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeMemberName;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::identifier::Name;
+use mago_reflection::r#type::kind::*;
+use mago_reflection::r#type::TypeReflection;
+use mago_span::HasSpan;
+
+use crate::internal::context::Context;
+use crate::internal::reflect::r#type::reflect_docblock_type;
+
+/// The templates that are visible when reflecting a docblock type.
+#[derive(Debug, Default)]
+pub struct TemplateScope {
+    templates: Vec<(StringIdentifier, StringIdentifier, TypeKind)>,
+}
+
+impl TemplateScope {
+    /// Creates a scope containing the templates of the given class-like, if any.
+    pub fn new(class_like: Option<&ClassLikeReflection>, context: &Context<'_>) -> Self {
+        let mut scope = Self::default();
+        if let Some(class_like) = class_like {
+            let defined_in = context.interner.intern(class_like.name.get_key(context.interner));
+
+            scope.add(&class_like.templates, defined_in);
+        }
+
+        scope
+    }
+
+    pub fn add(&mut self, templates: &[Template], defined_in: StringIdentifier) {
+        for template in templates {
+            self.templates.push((template.name, defined_in, template.get_bound()));
+        }
+    }
+
+    /// Returns the generic parameter type for the template with the given name, if it is in scope.
+    ///
+    /// Templates declared last take precedence, so method templates shadow class templates.
+    pub fn get(&self, name: &StringIdentifier) -> Option<TypeKind> {
+        self.templates
+            .iter()
+            .rev()
+            .find(|(template_name, _, _)| template_name == name)
+            .map(|(name, defined_in, bound)| generic_parameter_kind(*name, bound.clone(), *defined_in))
+    }
+}
+
+/// Information extracted from the docblock of a function-like.
+#[derive(Debug, Default)]
+pub struct FunctionLikeDocblock {
+    pub templates: Vec<Template>,
+    pub parameter_types: HashMap<StringIdentifier, TypeReflection>,
+    pub return_type: Option<TypeReflection>,
+    pub assertions: Vec<FunctionLikeAssertionReflection>,
+    pub if_true_assertions: Vec<FunctionLikeAssertionReflection>,
+    pub if_false_assertions: Vec<FunctionLikeAssertionReflection>,
+    pub is_pure: bool,
+    pub deprecation: Option<DeprecationReflection>,
+}
+
+impl FunctionLikeDocblock {
+    pub fn apply_to_parameters(
+        &mut self,
+        mut parameters: Vec<FunctionLikeParameterReflection>,
+    ) -> Vec<FunctionLikeParameterReflection> {
+        for parameter in parameters.iter_mut() {
+            parameter.docblock_type_reflection = self.parameter_types.remove(&parameter.name);
+        }
+
+        parameters
+    }
+}
+
+/// Information extracted from the docblock of a class-like.
+#[derive(Debug, Default)]
+pub struct ClassLikeDocblock {
+    pub templates: Vec<Template>,
+    pub template_type_arguments: HashMap<StringIdentifier, Vec<TypeKind>>,
+    /// The magic methods declared using `@method`.
+    pub methods: Vec<FunctionLikeReflection>,
+    /// The magic properties declared using `@property`, `@property-read`, and `@property-write`.
+    pub properties: Vec<PropertyReflection>,
+    pub deprecation: Option<DeprecationReflection>,
+}
+
+/// Retrieves and parses the docblock attached to the given node, if any.
+pub fn get_docblock(node: &impl HasSpan, context: &Context<'_>) -> Option<Document> {
+    let trivia = mago_docblock::get_docblock_for_node(&context.semantics.program.trivia, node)?;
+
+    mago_docblock::parse_trivia(context.interner, trivia).ok()
+}
+
+pub fn reflect_class_like_docblock(
+    node: &impl HasSpan,
+    name: &ClassLikeName,
+    context: &Context<'_>,
+) -> ClassLikeDocblock {
+    let mut docblock = ClassLikeDocblock::default();
+    let Some(document) = get_docblock(node, context) else {
+        return docblock;
+    };
+
+    let defined_in = context.interner.intern(name.get_key(context.interner));
+    let mut scope = TemplateScope::default();
+    docblock.templates = reflect_templates(&document, defined_in, &mut scope, context);
+
+    let scope_class_like_id = name.inner().map(|name| name.value);
+    for tag in get_tags(&document) {
+        match tag.kind.get_non_vendored_variant().unwrap_or(tag.kind) {
+            TagKind::Extends | TagKind::Implements | TagKind::Use => {
+                if let Some((parent, arguments)) = reflect_type_arguments_tag(tag, context, scope_class_like_id, &scope)
+                {
+                    docblock.template_type_arguments.insert(parent, arguments);
+                }
+            }
+            TagKind::Deprecated => {
+                docblock.deprecation = Some(reflect_deprecated_tag(tag));
+            }
+            TagKind::Method => {
+                if let Some(method) = reflect_method_tag(tag, name, context, scope_class_like_id, &scope) {
+                    docblock.methods.push(method);
+                }
+            }
+            kind @ (TagKind::Property | TagKind::PropertyRead | TagKind::PropertyWrite) => {
+                let Some((property_type, rest)) = parse_tag_type(tag, context) else {
+                    continue;
+                };
+
+                let Some(property_name) = get_variable_name(&rest) else {
+                    continue;
+                };
+
+                let visibility = Some(ClassLikeMemberVisibilityReflection::Public { span: tag.span });
+
+                docblock.properties.push(PropertyReflection {
+                    attribut_reflections: vec![],
+                    read_visibility_reflection: visibility,
+                    write_visibility_reflection: visibility,
+                    name: ClassLikeMemberName {
+                        class_like: *name,
+                        member: Name::new(context.interner.intern(property_name), tag.span),
+                    },
+                    type_reflection: None,
+                    docblock_type_reflection: Some(reflect_docblock_type(
+                        &property_type,
+                        context,
+                        scope_class_like_id,
+                        &scope,
+                    )),
+                    default_value_reflection: None,
+                    hooks: HashMap::default(),
+                    is_readonly: kind == TagKind::PropertyRead,
+                    is_final: false,
+                    is_promoted: false,
+                    is_static: false,
+                    item_span: tag.span,
+                    definition_span: tag.span,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    docblock
+}
+
+pub fn reflect_function_like_docblock(
+    node: &impl HasSpan,
+    name: &FunctionLikeName,
+    context: &Context<'_>,
+    class_like: Option<&ClassLikeReflection>,
+) -> FunctionLikeDocblock {
+    let mut docblock = FunctionLikeDocblock::default();
+    let Some(document) = get_docblock(node, context) else {
+        return docblock;
+    };
+
+    let defined_in = context.interner.intern(name.get_key(context.interner));
+    let mut scope = TemplateScope::new(class_like, context);
+    docblock.templates = reflect_templates(&document, defined_in, &mut scope, context);
+
+    let scope_class_like_id = get_scope_id(class_like, context);
+    let mut vendored_parameters = vec![];
+    let mut vendored_return_type = false;
+    for tag in get_tags(&document) {
+        let is_vendored = tag.kind.get_vendor().is_some();
+
+        match tag.kind.get_non_vendored_variant().unwrap_or(tag.kind) {
+            TagKind::Pure | TagKind::PhpstanPure => {
+                docblock.is_pure = true;
+            }
+            TagKind::Deprecated => {
+                docblock.deprecation = Some(reflect_deprecated_tag(tag));
+            }
+            TagKind::Param => {
+                let Some((parameter_type, rest)) = parse_tag_type(tag, context) else {
+                    continue;
+                };
+
+                let Some(parameter_name) = get_variable_name(&rest) else {
+                    continue;
+                };
+
+                let parameter_name = context.interner.intern(parameter_name);
+                if !is_vendored && vendored_parameters.contains(&parameter_name) {
+                    continue;
+                }
+
+                if is_vendored {
+                    vendored_parameters.push(parameter_name);
+                }
+
+                docblock.parameter_types.insert(
+                    parameter_name,
+                    reflect_docblock_type(&parameter_type, context, scope_class_like_id, &scope),
+                );
+            }
+            TagKind::Return => {
+                if !is_vendored && vendored_return_type {
+                    continue;
+                }
+
+                let Some((return_type, _)) = parse_tag_type(tag, context) else {
+                    continue;
+                };
+
+                vendored_return_type |= is_vendored;
+                docblock.return_type = Some(reflect_docblock_type(&return_type, context, scope_class_like_id, &scope));
+            }
+            kind @ (TagKind::Assert | TagKind::AssertIfTrue | TagKind::AssertIfFalse) => {
+                let Some(assertion) = reflect_assertion_tag(tag, context, scope_class_like_id, &scope) else {
+                    continue;
+                };
+
+                let assertions = match kind {
+                    TagKind::Assert => &mut docblock.assertions,
+                    TagKind::AssertIfTrue => &mut docblock.if_true_assertions,
+                    _ => &mut docblock.if_false_assertions,
+                };
+
+                // The same assertion is often declared for several tools, e.g. using both `@psalm-assert`
+                // and `@phpstan-assert`.
+                if !assertions.iter().any(|existing| {
+                    existing.parameter_name == assertion.parameter_name && existing.assertion == assertion.assertion
+                }) {
+                    assertions.push(assertion);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    docblock
+}
+
+/// Reflects the type arguments provided using `@use` in the docblock of a trait use, keyed by the name of the trait.
+pub fn reflect_trait_use_docblock(
+    node: &impl HasSpan,
+    context: &Context<'_>,
+    class_like: &ClassLikeReflection,
+) -> HashMap<StringIdentifier, Vec<TypeKind>> {
+    let mut type_arguments = HashMap::default();
+    let Some(document) = get_docblock(node, context) else {
+        return type_arguments;
+    };
+
+    let scope = TemplateScope::new(Some(class_like), context);
+    let scope_class_like_id = get_scope_id(Some(class_like), context);
+    for tag in get_tags(&document) {
+        if TagKind::Use != tag.kind.get_non_vendored_variant().unwrap_or(tag.kind) {
+            continue;
+        }
+
+        if let Some((used_trait, arguments)) = reflect_type_arguments_tag(tag, context, scope_class_like_id, &scope) {
+            type_arguments.insert(used_trait, arguments);
+        }
+    }
+
+    type_arguments
+}
+
+/// Reflects the `@deprecated` tag from the docblock of the given node, if any.
+///
+/// This is used for constants and enum cases, whose docblocks contain nothing else of interest.
+pub fn reflect_deprecation_docblock(node: &impl HasSpan, context: &Context<'_>) -> Option<DeprecationReflection> {
+    let document = get_docblock(node, context)?;
+
+    let deprecation = get_tags(&document).find(|tag| tag.kind == TagKind::Deprecated).map(reflect_deprecated_tag);
+
+    deprecation
+}
+
+/// Reflects the `@var` type of a property from its docblock.
+pub fn reflect_property_docblock_type(
+    node: &impl HasSpan,
+    context: &Context<'_>,
+    class_like: Option<&ClassLikeReflection>,
+) -> Option<TypeReflection> {
+    let document = get_docblock(node, context)?;
+    let scope = TemplateScope::new(class_like, context);
+    let scope_class_like_id = get_scope_id(class_like, context);
+
+    let mut result = None;
+    for tag in get_tags(&document) {
+        if TagKind::Var != tag.kind.get_non_vendored_variant().unwrap_or(tag.kind) {
+            continue;
+        }
+
+        if result.is_some() && tag.kind.get_vendor().is_none() {
+            continue;
+        }
+
+        if let Some((property_type, _)) = parse_tag_type(tag, context) {
+            result = Some(reflect_docblock_type(&property_type, context, scope_class_like_id, &scope));
+        }
+    }
+
+    result
+}
+
+/// Reflects an `@extends`, `@implements`, or `@use` tag into the name of the parent it refers to, and
+/// the type arguments it provides to the templates of that parent.
+fn reflect_type_arguments_tag(
+    tag: &Tag,
+    context: &Context<'_>,
+    scope_class_like_id: Option<StringIdentifier>,
+    scope: &TemplateScope,
+) -> Option<(StringIdentifier, Vec<TypeKind>)> {
+    let Some((Type::Reference(reference), _)) = parse_tag_type(tag, context) else {
+        return None;
+    };
+
+    let parent = context.resolve_class_like_name(&reference.name);
+    let arguments = reference
+        .parameters
+        .iter()
+        .map(|parameter| reflect_docblock_type(parameter, context, scope_class_like_id, scope).kind)
+        .collect();
+
+    Some((parent, arguments))
+}
+
+fn reflect_templates(
+    document: &Document,
+    defined_in: StringIdentifier,
+    scope: &mut TemplateScope,
+    context: &Context<'_>,
+) -> Vec<Template> {
+    let mut templates = vec![];
+    let scope_class_like_id = Some(defined_in);
+
+    for tag in get_tags(document) {
+        let variance = match tag.kind.get_non_vendored_variant().unwrap_or(tag.kind) {
+            TagKind::Template | TagKind::PsalmTemplate => TemplateVariance::Invariant,
+            TagKind::TemplateCovariant => TemplateVariance::Covariant,
+            TagKind::TemplateContravariant => TemplateVariance::Contravariant,
+            _ => continue,
+        };
+
+        let description = context.interner.lookup(&tag.description);
+        let name_length = description.find(char::is_whitespace).unwrap_or(description.len());
+        let name = &description[..name_length];
+        if name.is_empty() {
+            continue;
+        }
+
+        let rest = description[name_length..].trim_start();
+        let constraint_offset = ["of ", "as ", "extends "]
+            .iter()
+            .find(|keyword| rest.len() > keyword.len() && rest[..keyword.len()].eq_ignore_ascii_case(keyword))
+            .map(|keyword| description.len() - rest.len() + keyword.len());
+
+        let mut constraints = vec![];
+        if let Some(offset) = constraint_offset {
+            let position = tag.description_span.start.forward(offset);
+            if let Ok((constraint, _)) = parse_type(&description[offset..], position) {
+                constraints.push(reflect_docblock_type(&constraint, context, scope_class_like_id, scope).kind);
+            }
+        }
+
+        let template = Template::new(context.interner.intern(name), constraints, variance);
+        scope.add(std::slice::from_ref(&template), defined_in);
+        templates.push(template);
+    }
+
+    templates
+}
+
+/// Reflects an `@assert`, `@assert-if-true`, or `@assert-if-false` tag, e.g. `@assert !null $value`.
+///
+/// Besides types, the `truthy`, `falsy`, `empty`, and `non-empty` keywords are supported. Types may be
+/// prefixed with `!` to negate the assertion, and with `=` to assert equality rather than containment.
+fn reflect_assertion_tag(
+    tag: &Tag,
+    context: &Context<'_>,
+    scope_class_like_id: Option<StringIdentifier>,
+    scope: &TemplateScope,
+) -> Option<FunctionLikeAssertionReflection> {
+    let description = context.interner.lookup(&tag.description);
+    let mut offset = description.len() - description.trim_start().len();
+
+    let negated = description[offset..].starts_with('!');
+    if negated {
+        offset += 1;
+    }
+
+    let equality = description[offset..].starts_with('=');
+    if equality {
+        offset += 1;
+    }
+
+    let keyword_length = description[offset..].find(char::is_whitespace).unwrap_or(description.len() - offset);
+    let keyword_assertion = match description[offset..offset + keyword_length].to_ascii_lowercase().as_str() {
+        "truthy" | "non-empty" => Some(Assertion::Truthy),
+        "falsy" | "empty" => Some(Assertion::Falsy),
+        _ => None,
+    };
+
+    let (assertion, rest) = match keyword_assertion {
+        Some(assertion) if !equality => (assertion, &description[offset + keyword_length..]),
+        _ => {
+            let position = tag.description_span.start.forward(offset);
+            let (asserted_type, consumed) = parse_type(&description[offset..], position).ok()?;
+            let kind = reflect_docblock_type(&asserted_type, context, scope_class_like_id, scope).kind;
+
+            let assertion = if equality { Assertion::IsEqual(kind) } else { Assertion::IsType(kind) };
+
+            (assertion, &description[offset + consumed..])
+        }
+    };
+
+    let parameter_name = get_variable_name(rest.trim_start())?;
+    // Assertions about properties, e.g. `$this->value`, are not supported.
+    if parameter_name == "$this" {
+        return None;
+    }
+
+    Some(FunctionLikeAssertionReflection {
+        parameter_name: context.interner.intern(parameter_name),
+        assertion: if negated { assertion.get_negation() } else { assertion },
+        span: tag.span,
+    })
+}
+
+/// Reflects a `@method` tag, e.g. `@method static Foo create(string $name, int ...$values)`.
+fn reflect_method_tag(
+    tag: &Tag,
+    class_like_name: &ClassLikeName,
+    context: &Context<'_>,
+    scope_class_like_id: Option<StringIdentifier>,
+    scope: &TemplateScope,
+) -> Option<FunctionLikeReflection> {
+    let description = context.interner.lookup(&tag.description);
+    let parameters_offset = description.find('(')?;
+    let parameters_end = find_closing_parenthesis(description, parameters_offset)?;
+
+    let head = description[..parameters_offset].trim_end();
+    let name_offset = head.rfind(|c: char| !c.is_alphanumeric() && c != '_').map(|offset| offset + 1).unwrap_or(0);
+    let method_name = &head[name_offset..];
+    if method_name.is_empty() || method_name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut prefix = head[..name_offset].trim_end();
+    let mut prefix_offset = prefix.len() - prefix.trim_start().len();
+    prefix = prefix.trim_start();
+
+    let mut is_static = false;
+    if let Some(rest) = prefix.strip_prefix("static") {
+        // `@method static foo()` declares a method returning `static`, not a static method.
+        if rest.starts_with(char::is_whitespace) {
+            is_static = true;
+            prefix_offset += prefix.len() - rest.trim_start().len();
+            prefix = rest.trim_start();
+        }
+    }
+
+    let return_type = if prefix.is_empty() {
+        None
+    } else {
+        let (return_type, _) = parse_type(prefix, tag.description_span.start.forward(prefix_offset)).ok()?;
+
+        Some(reflect_docblock_type(&return_type, context, scope_class_like_id, scope))
+    };
+
+    let mut parameters = vec![];
+    let mut parameter_offset = parameters_offset + 1;
+    for segment in split_top_level(&description[parameters_offset + 1..parameters_end], ',') {
+        let segment_offset = parameter_offset + (segment.len() - segment.trim_start().len());
+        parameter_offset += segment.len() + 1;
+
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+
+        let (parameter_type, rest) = if segment.starts_with(['$', '&']) || segment.starts_with("...") {
+            (None, segment)
+        } else {
+            let (parameter_type, consumed) =
+                parse_type(segment, tag.description_span.start.forward(segment_offset)).ok()?;
+
+            (
+                Some(reflect_docblock_type(&parameter_type, context, scope_class_like_id, scope)),
+                segment[consumed..].trim_start(),
+            )
+        };
+
+        let is_passed_by_reference = rest.starts_with('&');
+        let rest = rest.trim_start_matches('&').trim_start();
+        let is_variadic = rest.starts_with("...");
+        let parameter_name = get_variable_name(rest)?;
+        let default = rest.find('=').map(|_| FunctionLikeParameterDefaultValueReflection {
+            type_reflection: TypeReflection { kind: mixed_kind(false), inferred: true, span: tag.span },
+            span: tag.span,
+        });
+
+        parameters.push(FunctionLikeParameterReflection {
+            attribute_reflections: vec![],
+            type_reflection: None,
+            docblock_type_reflection: parameter_type,
+            name: context.interner.intern(parameter_name),
+            is_variadic,
+            is_passed_by_reference,
+            is_promoted_property: false,
+            default,
+        });
+    }
+
+    Some(FunctionLikeReflection {
+        attribute_reflections: vec![],
+        visibility_reflection: Some(ClassLikeMemberVisibilityReflection::Public { span: tag.span }),
+        name: FunctionLikeName::Method(*class_like_name, Name::new(context.interner.intern(method_name), tag.span)),
+        templates: vec![],
+        parameters,
+        return_type_reflection: None,
+        docblock_return_type_reflection: return_type,
+        assertions: vec![],
+        if_true_assertions: vec![],
+        if_false_assertions: vec![],
+        returns_by_reference: false,
+        has_yield: false,
+        has_throws: false,
+        is_anonymous: false,
+        is_static,
+        is_final: false,
+        is_abstract: false,
+        is_pure: false,
+        is_overriding: false,
+        is_magic: true,
+        deprecation: None,
+        span: tag.span,
+        is_populated: false,
+    })
+}
+
+/// Finds the offset of the parenthesis closing the one at the given offset.
+fn find_closing_parenthesis(input: &str, offset: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, character) in input[offset..].char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(offset + index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits the given input on the given separator, ignoring separators nested in brackets.
+fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut segments = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, character) in input.char_indices() {
+        match character {
+            '(' | '<' | '[' | '{' => depth += 1,
+            ')' | '>' | ']' | '}' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                segments.push(&input[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    segments.push(&input[start..]);
+    segments
+}
+
+/// Reflects a `@deprecated` tag, whose description ( e.g. `@deprecated 2.0 Use bar() instead.` ) is
+/// used as the deprecation message.
+fn reflect_deprecated_tag(tag: &Tag) -> DeprecationReflection {
+    DeprecationReflection { message: (!tag.description.is_empty()).then_some(tag.description), span: tag.span }
+}
+
+fn get_tags(document: &Document) -> impl Iterator<Item = &Tag> {
+    document.elements.iter().filter_map(|element| match element {
+        Element::Tag(tag) => Some(tag),
+        _ => None,
+    })
+}
+
+fn parse_tag_type(tag: &Tag, context: &Context<'_>) -> Option<(Type, String)> {
+    let description = context.interner.lookup(&tag.description);
+    let (parsed, consumed) = parse_type(description, tag.description_span.start).ok()?;
+
+    Some((parsed, description[consumed..].trim_start().to_string()))
+}
+
+fn get_variable_name(description: &str) -> Option<&str> {
+    let description = description.trim_start_matches('&').trim_start_matches("...");
+    if !description.starts_with('$') {
+        return None;
+    }
+
+    let length = description[1..]
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .map(|length| length + 1)
+        .unwrap_or(description.len());
+
+    Some(&description[..length])
+}
+
+fn get_scope_id(class_like: Option<&ClassLikeReflection>, context: &Context<'_>) -> Option<StringIdentifier> {
+    class_like.map(|class_like| context.interner.intern(class_like.name.get_key(context.interner)))
+}
@@ -9,6 +9,8 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflect::attribute::reflect_attributes;
+use crate::internal::reflect::attribute::reflect_deprecation_attribute;
+use crate::internal::reflect::docblock::reflect_function_like_docblock;
 use crate::internal::reflect::r#type::maybe_reflect_hint;
 use crate::internal::reflect::r#type::reflect_hint;
 
@@ -17,24 +19,37 @@ pub fn reflect_function<'ast>(
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
+    let name = FunctionLikeName::Function(Name::new(*context.semantics.names.get(&function.name), function.name.span));
+    let mut docblock = reflect_function_like_docblock(function, &name, context, scope);
+    let attribute_reflections = reflect_attributes(&function.attributes, context);
+    let deprecation = reflect_deprecation_attribute(&attribute_reflections, context).or(docblock.deprecation);
+
     FunctionLikeReflection {
-        attribute_reflections: reflect_attributes(&function.attributes, context),
+        attribute_reflections,
         visibility_reflection: None,
-        name: FunctionLikeName::Function(Name::new(*context.semantics.names.get(&function.name), function.name.span)),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&function.parameters, context, scope),
+        name,
+        parameters: docblock.apply_to_parameters(reflect_function_like_parameter_list(
+            &function.parameters,
+            context,
+            scope,
+        )),
+        templates: docblock.templates,
         return_type_reflection: reflect_function_like_return_type_hint(&function.return_type_hint, context, scope),
+        docblock_return_type_reflection: docblock.return_type,
+        assertions: docblock.assertions,
+        if_true_assertions: docblock.if_true_assertions,
+        if_false_assertions: docblock.if_false_assertions,
         returns_by_reference: function.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&function.body),
         has_throws: mago_ast_utils::block_has_throws(&function.body),
         is_anonymous: false,
         is_static: true,
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: docblock.is_pure,
         is_abstract: false,
         is_overriding: false,
+        is_magic: false,
+        deprecation,
         span: function.span(),
         is_populated: false,
     }
@@ -45,24 +60,35 @@ pub fn reflect_closure<'ast>(
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
+    let name = FunctionLikeName::Closure(closure.span());
+    let mut docblock = reflect_function_like_docblock(closure, &name, context, scope);
+
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&closure.attributes, context),
         visibility_reflection: None,
-        name: FunctionLikeName::Closure(closure.span()),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&closure.parameters, context, scope),
+        name,
+        parameters: docblock.apply_to_parameters(reflect_function_like_parameter_list(
+            &closure.parameters,
+            context,
+            scope,
+        )),
+        templates: docblock.templates,
         return_type_reflection: reflect_function_like_return_type_hint(&closure.return_type_hint, context, scope),
+        docblock_return_type_reflection: docblock.return_type,
+        assertions: docblock.assertions,
+        if_true_assertions: docblock.if_true_assertions,
+        if_false_assertions: docblock.if_false_assertions,
         returns_by_reference: closure.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&closure.body),
         has_throws: mago_ast_utils::block_has_throws(&closure.body),
         is_anonymous: true,
         is_static: closure.r#static.is_some(),
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: docblock.is_pure,
         is_abstract: false,
         is_overriding: false,
+        is_magic: false,
+        deprecation: None,
         span: closure.span(),
         is_populated: false,
     }
@@ -73,28 +99,39 @@ pub fn reflect_arrow_function<'ast>(
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
+    let name = FunctionLikeName::ArrowFunction(arrow_function.span());
+    let mut docblock = reflect_function_like_docblock(arrow_function, &name, context, scope);
+
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&arrow_function.attributes, context),
         visibility_reflection: None,
-        name: FunctionLikeName::ArrowFunction(arrow_function.span()),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&arrow_function.parameters, context, scope),
+        name,
+        parameters: docblock.apply_to_parameters(reflect_function_like_parameter_list(
+            &arrow_function.parameters,
+            context,
+            scope,
+        )),
+        templates: docblock.templates,
         return_type_reflection: reflect_function_like_return_type_hint(
             &arrow_function.return_type_hint,
             context,
             scope,
         ),
+        docblock_return_type_reflection: docblock.return_type,
+        assertions: docblock.assertions,
+        if_true_assertions: docblock.if_true_assertions,
+        if_false_assertions: docblock.if_false_assertions,
         returns_by_reference: arrow_function.ampersand.is_some(),
         has_yield: mago_ast_utils::expression_has_yield(&arrow_function.expression),
         has_throws: mago_ast_utils::expression_has_throws(&arrow_function.expression),
         is_anonymous: true,
         is_static: arrow_function.r#static.is_some(),
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: docblock.is_pure,
         is_abstract: false,
         is_overriding: false,
+        is_magic: false,
+        deprecation: None,
         span: arrow_function.span(),
         is_populated: false,
     }
@@ -121,6 +158,7 @@ pub fn reflect_function_like_parameter<'ast>(
     FunctionLikeParameterReflection {
         attribute_reflections: reflect_attributes(&parameter.attributes, context),
         type_reflection: maybe_reflect_hint(&parameter.hint, context, scope),
+        docblock_type_reflection: None,
         name: parameter.variable.name,
         is_variadic: parameter.ellipsis.is_some(),
         is_passed_by_reference: parameter.ampersand.is_some(),
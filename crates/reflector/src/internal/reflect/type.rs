@@ -1,11 +1,21 @@
+use ordered_float::OrderedFloat;
+
 use mago_ast::*;
+use mago_docblock::r#type::LiteralType;
+use mago_docblock::r#type::ReferenceType;
+use mago_docblock::r#type::ShapeFieldKey;
+use mago_docblock::r#type::Type as DocblockType;
 use mago_interner::StringIdentifier;
 use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::Name;
 use mago_reflection::r#type::kind::*;
 use mago_reflection::r#type::*;
 use mago_span::*;
+use mago_trinary::Trinary;
 
 use crate::internal::context::Context;
+use crate::internal::reflect::docblock::TemplateScope;
 
 pub fn maybe_reflect_hint<'ast>(
     hint: &'ast Option<Hint>,
@@ -117,3 +127,315 @@ fn build_kind<'ast>(hint: &'ast Hint, context: &'ast mut Context<'_>, scope: Opt
         }
     }
 }
+
+pub fn reflect_docblock_type(
+    docblock_type: &DocblockType,
+    context: &Context<'_>,
+    scope: Option<StringIdentifier>,
+    templates: &TemplateScope,
+) -> TypeReflection {
+    TypeReflection {
+        kind: build_docblock_kind(docblock_type, context, scope, templates),
+        inferred: false,
+        span: docblock_type.span(),
+    }
+}
+
+fn build_docblock_kind(
+    docblock_type: &DocblockType,
+    context: &Context<'_>,
+    scope: Option<StringIdentifier>,
+    templates: &TemplateScope,
+) -> TypeKind {
+    let build = |inner: &DocblockType| build_docblock_kind(inner, context, scope, templates);
+    let scope_id = scope.unwrap_or_else(StringIdentifier::empty);
+
+    match docblock_type {
+        DocblockType::Reference(reference) => build_docblock_reference_kind(reference, context, scope, templates),
+        DocblockType::Nullable(nullable) => match build(&nullable.inner) {
+            TypeKind::Union { mut kinds } => {
+                kinds.insert(0, null_kind());
+
+                TypeKind::Union { kinds }
+            }
+            kind => union_kind(vec![null_kind(), kind]),
+        },
+        DocblockType::Union(union) => {
+            let mut kinds = vec![];
+            for inner in union.types.iter() {
+                match build(inner) {
+                    TypeKind::Union { kinds: inner_kinds } => kinds.extend(inner_kinds),
+                    kind => kinds.push(kind),
+                }
+            }
+
+            union_kind(kinds)
+        }
+        DocblockType::Intersection(intersection) => {
+            let mut kinds = vec![];
+            for inner in intersection.types.iter() {
+                match build(inner) {
+                    TypeKind::Intersection { kinds: inner_kinds } => kinds.extend(inner_kinds),
+                    kind => kinds.push(kind),
+                }
+            }
+
+            intersection_kind(kinds)
+        }
+        DocblockType::Array(array) => array_kind(array_key_kind(), build(&array.inner), None),
+        DocblockType::Shape(shape) => {
+            let name = shape.name.to_ascii_lowercase();
+            if name == "object" {
+                return TypeKind::Object(ObjectTypeKind::TypedObject {
+                    properties: shape
+                        .fields
+                        .iter()
+                        .filter_map(|field| match &field.key {
+                            Some(ShapeFieldKey::String(key)) => Some(ObjectProperty {
+                                name: context.interner.intern(key),
+                                kind: build(&field.value),
+                                optional: field.optional,
+                            }),
+                            _ => None,
+                        })
+                        .collect(),
+                });
+            }
+
+            let properties = shape
+                .fields
+                .iter()
+                .map(|field| {
+                    let kind = build(&field.value);
+
+                    match &field.key {
+                        Some(ShapeFieldKey::String(key)) => {
+                            string_shape_property(context.interner.intern(key), kind, field.optional)
+                        }
+                        Some(ShapeFieldKey::Integer(key)) => {
+                            integer_shape_property(*key as isize, kind, field.optional)
+                        }
+                        None => indexed_shape_property(kind, field.optional),
+                    }
+                })
+                .collect();
+
+            let additional_properties =
+                shape.additional_fields.as_ref().map(|parameters| match parameters.as_slice() {
+                    [] => (array_key_kind(), mixed_kind(false)),
+                    [value] => (array_key_kind(), build(value)),
+                    [key, value, ..] => (build(key), build(value)),
+                });
+
+            array_shape_kind(properties, additional_properties)
+        }
+        DocblockType::Callable(callable) => {
+            let parameters = callable
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    callable_parameter(
+                        parameter.parameter_type.as_ref().map(build).unwrap_or_else(|| mixed_kind(false)),
+                        parameter.is_optional,
+                        parameter.is_variadic,
+                    )
+                })
+                .collect();
+
+            let return_kind = callable.return_type.as_deref().map(build).unwrap_or_else(|| mixed_kind(false));
+            let name = callable.name.to_ascii_lowercase();
+            match name.trim_start_matches('\\') {
+                "closure" => closure_kind(false, vec![], parameters, return_kind),
+                "pure-closure" => closure_kind(true, vec![], parameters, return_kind),
+                "pure-callable" => callable_kind(true, vec![], parameters, return_kind),
+                _ => callable_kind(false, vec![], parameters, return_kind),
+            }
+        }
+        DocblockType::Literal(literal) => match literal {
+            LiteralType::String { value, .. } => {
+                let mut length = 0;
+                let mut is_uppercase = Trinary::Maybe;
+                let mut is_lowercase = Trinary::Maybe;
+                let mut is_ascii_uppercase = Trinary::Maybe;
+                let mut is_ascii_lowercase = Trinary::Maybe;
+                for c in value.chars() {
+                    length += 1;
+                    is_uppercase &= c.is_uppercase();
+                    is_lowercase &= c.is_lowercase();
+                    is_ascii_uppercase &= c.is_ascii_uppercase();
+                    is_ascii_lowercase &= c.is_ascii_lowercase();
+                }
+
+                if length == 0 {
+                    (is_uppercase, is_lowercase, is_ascii_uppercase, is_ascii_lowercase) =
+                        (Trinary::False, Trinary::False, Trinary::False, Trinary::False);
+                }
+
+                value_string_kind(
+                    context.interner.intern(value),
+                    length,
+                    is_uppercase,
+                    is_ascii_uppercase,
+                    is_lowercase,
+                    is_ascii_lowercase,
+                )
+            }
+            LiteralType::Integer { value, .. } => value_integer_kind(*value),
+            LiteralType::Float { value, .. } => value_float_kind(OrderedFloat(*value)),
+        },
+        DocblockType::ClassConstant(constant) => {
+            let class_like = match constant.class.to_ascii_lowercase().as_str() {
+                "self" | "static" => scope_id,
+                _ => context.resolve_class_like_name(&constant.class),
+            };
+
+            TypeKind::Value(ValueTypeKind::ClassLikeConstant {
+                class_like: ClassLikeName::Class(Name::new(class_like, constant.span)),
+                constant: context.interner.intern(&constant.constant),
+            })
+        }
+        DocblockType::Conditional(conditional) => {
+            let (then, otherwise) = if conditional.negated {
+                (build(&conditional.otherwise), build(&conditional.then))
+            } else {
+                (build(&conditional.then), build(&conditional.otherwise))
+            };
+
+            conditional_kind(build(&conditional.subject), build(&conditional.target), then, otherwise)
+        }
+        DocblockType::Variable(variable) => {
+            if variable.name.eq_ignore_ascii_case("$this") {
+                static_kind(scope_id)
+            } else {
+                variable_kind(context.interner.intern(&variable.name))
+            }
+        }
+    }
+}
+
+fn build_docblock_reference_kind(
+    reference: &ReferenceType,
+    context: &Context<'_>,
+    scope: Option<StringIdentifier>,
+    templates: &TemplateScope,
+) -> TypeKind {
+    let build = |inner: &DocblockType| build_docblock_kind(inner, context, scope, templates);
+    let scope_id = scope.unwrap_or_else(StringIdentifier::empty);
+    let parameters = reference.parameters.as_slice();
+    let get_integer_bound = |parameter: Option<&DocblockType>| match parameter {
+        Some(DocblockType::Literal(LiteralType::Integer { value, .. })) => Some(*value as isize),
+        _ => None,
+    };
+
+    let lowercase_name = reference.name.to_ascii_lowercase();
+    match lowercase_name.as_str() {
+        "int" | "integer" if parameters.len() == 2 => TypeKind::Scalar(ScalarTypeKind::Integer {
+            min: get_integer_bound(parameters.first()),
+            max: get_integer_bound(parameters.get(1)),
+        }),
+        "int" | "integer" => integer_kind(),
+        "positive-int" => positive_integer_kind(),
+        "negative-int" => negative_integer_kind(),
+        "non-negative-int" => non_negative_integer_kind(),
+        "non-positive-int" => non_positive_integer_kind(),
+        "literal-int" => TypeKind::Scalar(ScalarTypeKind::LiteralInt),
+        "int-mask" => TypeKind::Scalar(ScalarTypeKind::IntegerMask(
+            parameters.iter().filter_map(|parameter| get_integer_bound(Some(parameter))).collect(),
+        )),
+        "int-mask-of" => integer_kind(),
+        "float" | "double" => float_kind(),
+        "string" | "lowercase-string" | "uppercase-string" => string_kind(),
+        "non-empty-string" | "non-falsy-string" | "truthy-string" | "non-empty-lowercase-string" => {
+            non_empty_string_kind()
+        }
+        "numeric-string" => TypeKind::Scalar(ScalarTypeKind::NumericString),
+        "literal-string" | "non-empty-literal-string" => TypeKind::Scalar(ScalarTypeKind::LiteralString),
+        "callable-string" => TypeKind::Scalar(ScalarTypeKind::CallableString),
+        "trait-string" => TypeKind::Scalar(ScalarTypeKind::TraitString),
+        "enum-string" => TypeKind::Scalar(ScalarTypeKind::EnumString),
+        "class-string" | "interface-string" => {
+            TypeKind::Scalar(ScalarTypeKind::ClassString(match parameters.first() {
+                Some(DocblockType::Reference(class)) => match templates.get(&context.interner.intern(&class.name)) {
+                    Some(_) => None,
+                    None => Some(context.resolve_class_like_name(&class.name)),
+                },
+                _ => None,
+            }))
+        }
+        "bool" | "boolean" => bool_kind(),
+        "true" => true_kind(),
+        "false" => false_kind(),
+        "null" => null_kind(),
+        "void" => void_kind(),
+        "never" | "never-return" | "never-returns" | "no-return" => never_kind(),
+        "mixed" => mixed_kind(true),
+        "scalar" => TypeKind::Scalar(ScalarTypeKind::Scalar),
+        "numeric" => TypeKind::Scalar(ScalarTypeKind::Numeric),
+        "array-key" => array_key_kind(),
+        "resource" | "open-resource" => resource_kind(),
+        "closed-resource" => closed_resource_kind(),
+        "object" => any_object_kind(),
+        "callable" | "pure-callable" => any_callable_kind(),
+        "callable-array" => TypeKind::Array(ArrayTypeKind::CallableArray),
+        "closure" | "\\closure" if parameters.is_empty() => any_closure_kind(),
+        "self" => self_kind(scope_id),
+        "static" => static_kind(scope_id),
+        "parent" => parent_kind(scope_id),
+        "array" | "non-empty-array" | "associative-array" => {
+            let (key, value) = match parameters {
+                [] => (array_key_kind(), mixed_kind(false)),
+                [value] => (array_key_kind(), build(value)),
+                [key, value, ..] => (build(key), build(value)),
+            };
+
+            if lowercase_name == "non-empty-array" {
+                non_empty_array_kind(key, value, None)
+            } else {
+                array_kind(key, value, None)
+            }
+        }
+        "list" | "non-empty-list" => {
+            let value = parameters.first().map(build).unwrap_or_else(|| mixed_kind(false));
+
+            if lowercase_name == "non-empty-list" {
+                non_empty_list_kind(value, None)
+            } else {
+                list_kind(value, None)
+            }
+        }
+        "iterable" => {
+            let (key, value) = match parameters {
+                [] => (mixed_kind(false), mixed_kind(false)),
+                [value] => (mixed_kind(false), build(value)),
+                [key, value, ..] => (build(key), build(value)),
+            };
+
+            iterable_kind(key, value)
+        }
+        "key-of" => key_of_kind(parameters.first().map(build).unwrap_or_else(|| mixed_kind(false))),
+        "value-of" => value_of_kind(parameters.first().map(build).unwrap_or_else(|| mixed_kind(false))),
+        "properties-of" => properties_of_kind(parameters.first().map(build).unwrap_or_else(|| mixed_kind(false))),
+        "generator" | "\\generator" => {
+            let mut kinds = parameters.iter().map(build).collect::<Vec<_>>();
+            if kinds.len() == 1 {
+                kinds.insert(0, mixed_kind(false));
+            }
+
+            let mut kinds = kinds.into_iter();
+            TypeKind::Object(ObjectTypeKind::Generator {
+                key: Box::new(kinds.next().unwrap_or_else(|| mixed_kind(false))),
+                value: Box::new(kinds.next().unwrap_or_else(|| mixed_kind(false))),
+                send: Box::new(kinds.next().unwrap_or_else(|| mixed_kind(false))),
+                r#return: Box::new(kinds.next().unwrap_or_else(|| mixed_kind(false))),
+            })
+        }
+        _ => {
+            let name = context.interner.intern(&reference.name);
+            if let Some(template) = templates.get(&name) {
+                return template;
+            }
+
+            named_object_kind(context.resolve_class_like_name(&reference.name), parameters.iter().map(build).collect())
+        }
+    }
+}
@@ -21,6 +21,38 @@ impl ReflectionWalker {
 }
 
 impl<'a> MutWalker<Context<'a>> for ReflectionWalker {
+    fn walk_in_namespace(&mut self, namespace: &Namespace, context: &mut Context<'_>) {
+        let name = namespace.name.as_ref().map(|name| context.interner.lookup(&name.value()));
+
+        context.enter_namespace(name);
+    }
+
+    fn walk_in_use(&mut self, r#use: &Use, context: &mut Context<'_>) {
+        let interner = context.interner;
+
+        match &r#use.items {
+            UseItems::Sequence(sequence) => {
+                for item in sequence.items.iter() {
+                    let alias = item.alias.as_ref().map(|alias| interner.lookup(&alias.identifier.value));
+
+                    context.add_alias(interner.lookup(&item.name.value()), alias);
+                }
+            }
+            UseItems::MixedList(list) => {
+                let prefix = interner.lookup(&list.namespace.value());
+
+                for item in list.items.iter().filter(|item| item.r#type.is_none()) {
+                    let name = format!("{}\\{}", prefix, interner.lookup(&item.item.name.value()));
+                    let alias = item.item.alias.as_ref().map(|alias| interner.lookup(&alias.identifier.value));
+
+                    context.add_alias(&name, alias);
+                }
+            }
+            // function and constant imports are irrelevant to docblock types.
+            UseItems::TypedSequence(_) | UseItems::TypedList(_) => {}
+        }
+    }
+
     fn walk_in_function(&mut self, function: &Function, context: &mut Context<'_>) {
         let reflection = reflect_function(function, context, self.scope.last());
 
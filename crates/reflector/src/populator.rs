@@ -1,3 +1,5 @@
+use ahash::HashMap;
+
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 use mago_reflection::class_like::ClassLikeReflection;
@@ -22,6 +24,8 @@ pub fn populate(interner: &ThreadedInterner, codebase: &mut CodebaseReflection)
             reflection.properties.appering_members = Default::default();
             reflection.methods.declaring_members = Default::default();
             reflection.methods.appering_members = Default::default();
+            reflection.cases.declaring_members = Default::default();
+            reflection.cases.appering_members = Default::default();
         }
     }
 
@@ -86,6 +90,11 @@ fn populate_class_like_reflection(
         reflection.methods.declaring_members.insert(*method_id, reflection.name);
     }
 
+    for case_id in reflection.cases.members.keys() {
+        reflection.cases.appering_members.insert(*case_id, reflection.name);
+        reflection.cases.declaring_members.insert(*case_id, reflection.name);
+    }
+
     for trait_name in reflection.used_traits.clone() {
         populate_data_from_trait(interner, codebase, &mut reflection, trait_name);
     }
@@ -98,6 +107,10 @@ fn populate_class_like_reflection(
         populate_interface_data_from_parent_interface(interner, codebase, &mut reflection, parent_interface.value);
     }
 
+    for implemented_interface in reflection.inheritance.direct_implemented_interfaces.clone() {
+        populate_template_parameters_from_interface(interner, codebase, &mut reflection, implemented_interface.value);
+    }
+
     reflection.inheritance.all_extended_classes.shrink_to_fit();
     reflection.inheritance.all_implemented_interfaces.shrink_to_fit();
     reflection.inheritance.names.shrink_to_fit();
@@ -108,6 +121,9 @@ fn populate_class_like_reflection(
     reflection.methods.members.shrink_to_fit();
     reflection.methods.appering_members.shrink_to_fit();
     reflection.methods.declaring_members.shrink_to_fit();
+    reflection.cases.members.shrink_to_fit();
+    reflection.cases.appering_members.shrink_to_fit();
+    reflection.cases.declaring_members.shrink_to_fit();
 
     reflection.is_populated = true;
 
@@ -140,6 +156,7 @@ fn populate_interface_data_from_parent_interface(
     }
 
     inherit_methods_from_parent(reflection, parent_reflection);
+    inherit_template_parameters_from_parent(reflection, parent_reflection);
 
     for parent_interface_name in parent_reflection.inheritance.all_extended_interfaces.clone() {
         if reflection.inheritance.all_extended_interfaces.contains(&parent_interface_name) {
@@ -204,6 +221,7 @@ fn populate_data_from_parent_classlike(
 
     inherit_properties_from_parent(reflection, parent_reflection);
     inherit_methods_from_parent(reflection, parent_reflection);
+    inherit_template_parameters_from_parent(reflection, parent_reflection);
 
     parent_reflection.inheritance.children.insert(reflection.name);
 }
@@ -227,6 +245,83 @@ fn populate_data_from_trait(
 
     inherit_properties_from_parent(reflection, trait_reflection);
     inherit_methods_from_parent(reflection, trait_reflection);
+    inherit_template_parameters_from_parent(reflection, trait_reflection);
+}
+
+#[inline]
+fn populate_template_parameters_from_interface(
+    interner: &ThreadedInterner,
+    codebase: &mut CodebaseReflection,
+    reflection: &mut ClassLikeReflection,
+    interface_name_id: StringIdentifier,
+) {
+    let Some(interface_name) = codebase.class_like_names.get(&interface_name_id).cloned() else {
+        return;
+    };
+
+    populate_class_like_reflection(interner, codebase, interface_name);
+
+    let Some(interface_reflection) = codebase.class_like_reflections.get(&interface_name) else {
+        return;
+    };
+
+    inherit_template_parameters_from_parent(reflection, interface_reflection);
+}
+
+/// Resolves the types the given class-like binds to the templates of its parent, and
+/// to the templates of all the ancestors of its parent.
+///
+/// Templates without a type argument in `@extends`, `@implements`, or `@use` are bound to their constraint.
+#[inline]
+fn inherit_template_parameters_from_parent(
+    reflection: &mut ClassLikeReflection,
+    parent_reflection: &ClassLikeReflection,
+) {
+    let Some(parent_id) = parent_reflection.name.inner().map(|name| name.value) else {
+        return;
+    };
+
+    let type_arguments = reflection.template_type_arguments.get(&parent_id);
+    let parameters = parent_reflection
+        .templates
+        .iter()
+        .enumerate()
+        .map(|(index, template)| {
+            let kind = type_arguments
+                .and_then(|arguments| arguments.get(index))
+                .cloned()
+                .unwrap_or_else(|| template.get_bound());
+
+            (template.name, kind)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for (ancestor_id, ancestor_parameters) in &parent_reflection.template_extended_parameters {
+        if reflection.template_extended_parameters.contains_key(ancestor_id) {
+            continue;
+        }
+
+        let resolved_parameters = ancestor_parameters
+            .iter()
+            .map(|(name, kind)| {
+                let kind = kind.replace_generic_parameters(&|name, defined_in| {
+                    if *defined_in == parent_id {
+                        parameters.get(name).cloned()
+                    } else {
+                        None
+                    }
+                });
+
+                (*name, kind)
+            })
+            .collect();
+
+        reflection.template_extended_parameters.insert(*ancestor_id, resolved_parameters);
+    }
+
+    if !parameters.is_empty() {
+        reflection.template_extended_parameters.insert(parent_id, parameters);
+    }
 }
 
 #[inline]
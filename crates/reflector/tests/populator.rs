@@ -0,0 +1,244 @@
+use mago_interner::ThreadedInterner;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+fn reflect(code: &'static str) -> (ThreadedInterner, CodebaseReflection) {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    (interner, codebase)
+}
+
+fn get_method_return_type(
+    interner: &ThreadedInterner,
+    codebase: &CodebaseReflection,
+    class_name: &str,
+    method_name: &str,
+) -> String {
+    let class_like =
+        codebase.get_named_class_like(&interner.intern(class_name)).expect("class-like should be reflected");
+    let method = codebase.get_method(class_like, &interner.intern(method_name)).expect("method should be reflected");
+    let return_type = method.docblock_return_type_reflection.as_ref().expect("method should have a `@return` type");
+
+    return_type.kind.get_key(interner)
+}
+
+fn get_template_parameter(
+    interner: &ThreadedInterner,
+    codebase: &CodebaseReflection,
+    class_name: &str,
+    ancestor_name: &str,
+    template_name: &str,
+) -> Option<String> {
+    let class_like =
+        codebase.get_named_class_like(&interner.intern(class_name)).expect("class-like should be reflected");
+
+    class_like
+        .template_extended_parameters
+        .get(&interner.intern(ancestor_name))
+        .and_then(|parameters| parameters.get(&interner.intern(template_name)))
+        .map(|kind| kind.get_key(interner))
+}
+
+#[test]
+fn test_direct_inheritance() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @template T */
+        class Box {
+            /** @return T */
+            public function get(): mixed {}
+        }
+
+        /** @extends Box<int> */
+        class IntBox extends Box {}
+    "#,
+    );
+
+    assert_eq!(get_template_parameter(&interner, &codebase, "IntBox", "Box", "T").as_deref(), Some("int"));
+    assert_eq!(get_method_return_type(&interner, &codebase, "IntBox", "get"), "int");
+}
+
+#[test]
+fn test_missing_type_argument_is_bound_to_constraint() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @template T of string */
+        class Box {
+            /** @return T */
+            public function get(): mixed {}
+        }
+
+        class StringBox extends Box {}
+    "#,
+    );
+
+    assert_eq!(get_method_return_type(&interner, &codebase, "StringBox", "get"), "string");
+}
+
+#[test]
+fn test_chained_inheritance() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @template T */
+        class Base {
+            /** @return T */
+            public function get(): mixed {}
+        }
+
+        /**
+         * @template U
+         *
+         * @extends Base<U>
+         */
+        class Mid extends Base {}
+
+        /** @extends Mid<string> */
+        class Leaf extends Mid {}
+    "#,
+    );
+
+    assert_eq!(get_template_parameter(&interner, &codebase, "Leaf", "Mid", "U").as_deref(), Some("string"));
+    assert_eq!(get_template_parameter(&interner, &codebase, "Leaf", "Base", "T").as_deref(), Some("string"));
+    assert_eq!(get_method_return_type(&interner, &codebase, "Leaf", "get"), "string");
+}
+
+#[test]
+fn test_chained_inheritance_declared_out_of_order() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @extends Mid<list<int>> */
+        class Leaf extends Mid {}
+
+        /**
+         * @template U
+         *
+         * @extends Base<array<string, U>>
+         */
+        class Mid extends Base {}
+
+        /** @template T */
+        class Base {
+            /** @return T */
+            public function get(): mixed {}
+        }
+    "#,
+    );
+
+    assert_eq!(get_method_return_type(&interner, &codebase, "Leaf", "get"), "array<string, list<int>>");
+}
+
+#[test]
+fn test_chained_inheritance_across_namespaces() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        namespace Lib {
+            /** @template T */
+            abstract class Repository {
+                /** @return list<T> */
+                public function all(): array {}
+            }
+        }
+
+        namespace App {
+            use Lib\Repository;
+
+            /**
+             * @template TEntity of object
+             *
+             * @extends Repository<TEntity>
+             */
+            abstract class EntityRepository extends Repository {}
+
+            final class User {}
+
+            /** @extends EntityRepository<User> */
+            final class UserRepository extends EntityRepository {}
+        }
+    "#,
+    );
+
+    assert_eq!(get_method_return_type(&interner, &codebase, "App\\UserRepository", "all"), "list<App\\User>");
+}
+
+#[test]
+fn test_interface_inheritance() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @template T */
+        interface Collection {
+            /** @return T */
+            public function first(): mixed;
+        }
+
+        /**
+         * @template V
+         *
+         * @extends Collection<V>
+         */
+        interface Sequence extends Collection {}
+
+        /** @extends Sequence<float> */
+        interface FloatSequence extends Sequence {}
+
+        /** @implements Sequence<int> */
+        abstract class IntSequence implements Sequence {}
+    "#,
+    );
+
+    assert_eq!(
+        get_template_parameter(&interner, &codebase, "FloatSequence", "Sequence", "V").as_deref(),
+        Some("float")
+    );
+    assert_eq!(
+        get_template_parameter(&interner, &codebase, "FloatSequence", "Collection", "T").as_deref(),
+        Some("float")
+    );
+    assert_eq!(get_method_return_type(&interner, &codebase, "FloatSequence", "first"), "float");
+
+    assert_eq!(get_template_parameter(&interner, &codebase, "IntSequence", "Sequence", "V").as_deref(), Some("int"));
+    assert_eq!(get_template_parameter(&interner, &codebase, "IntSequence", "Collection", "T").as_deref(), Some("int"));
+}
+
+#[test]
+fn test_trait_inheritance() {
+    let (interner, codebase) = reflect(
+        r#"<?php
+
+        /** @template T */
+        trait Holds {
+            /** @return T */
+            public function held(): mixed {}
+        }
+
+        /**
+         * @template U
+         */
+        class Holder {
+            /** @use Holds<U> */
+            use Holds;
+        }
+
+        /** @extends Holder<bool> */
+        class BoolHolder extends Holder {}
+    "#,
+    );
+
+    assert_eq!(get_template_parameter(&interner, &codebase, "Holder", "Holds", "T").as_deref(), Some("U:Holder"));
+    assert_eq!(get_template_parameter(&interner, &codebase, "BoolHolder", "Holds", "T").as_deref(), Some("bool"));
+    assert_eq!(get_method_return_type(&interner, &codebase, "BoolHolder", "held"), "bool");
+}
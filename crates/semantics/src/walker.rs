@@ -2106,40 +2106,38 @@ impl Walker<Context<'_>> for SemanticsWalker {
 
     fn walk_in_hint(&self, hint: &Hint, context: &mut Context<'_>) {
         match hint {
-            Hint::Parenthesized(parenthesized_hint) => {
-                if !parenthesized_hint.hint.is_parenthesizable() {
-                    let val = context.lookup_hint(&parenthesized_hint.hint);
+            Hint::Parenthesized(parenthesized_hint) if !parenthesized_hint.hint.is_parenthesizable() => {
+                let val = context.lookup_hint(&parenthesized_hint.hint);
 
-                    context.report(
-                        Issue::error(format!("Type `{}` cannot be parenthesized.", val))
-                            .with_annotation(
-                                Annotation::primary(parenthesized_hint.hint.span())
-                                    .with_message("Invalid parenthesized type."),
-                            )
-                            .with_annotation(
-                                Annotation::secondary(parenthesized_hint.span())
-                                    .with_message("Parenthesized type defined here."),
-                            )
-                            .with_note("Only union or intersection types can be enclosed in parentheses.")
-                            .with_help("Remove the parentheses around the type."),
-                    );
-                }
+                context.report(
+                    Issue::error(format!("Type `{}` cannot be parenthesized.", val))
+                        .with_annotation(
+                            Annotation::primary(parenthesized_hint.hint.span())
+                                .with_message("Invalid parenthesized type."),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(parenthesized_hint.span())
+                                .with_message("Parenthesized type defined here."),
+                        )
+                        .with_note("Only union or intersection types can be enclosed in parentheses.")
+                        .with_help("Remove the parentheses around the type."),
+                );
             }
-            Hint::Nullable(nullable_hint) => {
-                if nullable_hint.hint.is_standalone() || nullable_hint.hint.is_complex() {
-                    let val = context.lookup_hint(&nullable_hint.hint);
+            Hint::Nullable(nullable_hint)
+                if (nullable_hint.hint.is_standalone() || nullable_hint.hint.is_complex()) =>
+            {
+                let val = context.lookup_hint(&nullable_hint.hint);
 
-                    context.report(
-                        Issue::error(format!("Type `{}` cannot be nullable.", val))
-                            .with_annotation(
-                                Annotation::primary(nullable_hint.hint.span()).with_message("Invalid nullable type."),
-                            )
-                            .with_annotation(
-                                Annotation::secondary(nullable_hint.span()).with_message("Nullable type defined here."),
-                            )
-                            .with_help("Replace the type or remove the nullable modifier."),
-                    );
-                }
+                context.report(
+                    Issue::error(format!("Type `{}` cannot be nullable.", val))
+                        .with_annotation(
+                            Annotation::primary(nullable_hint.hint.span()).with_message("Invalid nullable type."),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(nullable_hint.span()).with_message("Nullable type defined here."),
+                        )
+                        .with_help("Replace the type or remove the nullable modifier."),
+                );
             }
             Hint::Union(union_hint) => {
                 if !union_hint.left.is_unionable() {
@@ -13,9 +13,20 @@ use crate::error::SourceError;
 
 pub mod error;
 
-/// A unique identifier for a source, consisting of a string identifier and a user-defined flag.
+/// The category of a source, which determines how it is treated by the tools analyzing it.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
-pub struct SourceIdentifier(pub StringIdentifier, pub bool);
+pub enum SourceCategory {
+    /// A source declaring the symbols built into PHP and its extensions, such as the PhpStorm stubs.
+    BuiltIn,
+    /// A source external to the project, such as a dependency in the `vendor` directory.
+    External,
+    /// A source of the project itself.
+    UserDefined,
+}
+
+/// A unique identifier for a source, consisting of a string identifier and the category of the source.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct SourceIdentifier(pub StringIdentifier, pub SourceCategory);
 
 /// Represents a source file with an identifier, optional path, content, and line information.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -53,7 +64,7 @@ pub struct SourceManager {
 
 impl SourceIdentifier {
     pub fn dummy() -> Self {
-        Self(StringIdentifier::empty(), true)
+        Self(StringIdentifier::empty(), SourceCategory::UserDefined)
     }
 
     /// Returns the string identifier of the source.
@@ -62,16 +73,28 @@ impl SourceIdentifier {
         self.0
     }
 
-    /// Returns whether the source is external.
+    /// Returns the category of the source.
+    #[inline(always)]
+    pub const fn category(&self) -> SourceCategory {
+        self.1
+    }
+
+    /// Returns whether the source is built into PHP.
+    #[inline(always)]
+    pub const fn is_built_in(&self) -> bool {
+        matches!(self.1, SourceCategory::BuiltIn)
+    }
+
+    /// Returns whether the source is external, i.e. not user-defined, which includes built-in sources.
     #[inline(always)]
     pub const fn is_external(&self) -> bool {
-        !self.1
+        !self.is_user_defined()
     }
 
     /// Returns whether the source is user-defined.
     #[inline(always)]
     pub const fn is_user_defined(&self) -> bool {
-        self.1
+        matches!(self.1, SourceCategory::UserDefined)
     }
 }
 
@@ -125,14 +148,14 @@ impl SourceManager {
     ///
     /// - `name`: The name of the source.
     /// - `path`: The path to the source.
-    /// - `user_defined`: Whether the source is user-defined.
+    /// - `category`: The category of the source.
     ///
     /// # Returns
     ///
     /// The identifier of the inserted source.
-    pub fn insert_path(&self, name: String, path: PathBuf, user_defined: bool) -> SourceIdentifier {
+    pub fn insert_path(&self, name: String, path: PathBuf, category: SourceCategory) -> SourceIdentifier {
         let string_id = self.interner.intern(&name);
-        let source_id = SourceIdentifier(string_id, user_defined);
+        let source_id = SourceIdentifier(string_id, category);
 
         if self.sources.contains_key(&source_id) {
             return source_id;
@@ -149,17 +172,17 @@ impl SourceManager {
     ///
     /// - `name`: The name of the source.
     /// - `content`: The content of the source.
-    /// - `user_defined`: Whether the source is user-defined.
+    /// - `category`: The category of the source.
     ///
     /// # Returns
     ///
     /// The identifier of the inserted source.
-    pub fn insert_content(&mut self, name: String, content: String, user_defined: bool) -> SourceIdentifier {
+    pub fn insert_content(&mut self, name: String, content: String, category: SourceCategory) -> SourceIdentifier {
         if let Some(entry) = self.sources.iter().find(|entry| entry.name == name) {
             return *entry.key();
         }
 
-        let source_id = SourceIdentifier(self.interner.intern(&name), user_defined);
+        let source_id = SourceIdentifier(self.interner.intern(&name), category);
         let lines = line_starts(&content).collect();
         let size = content.len();
         let content = self.interner.intern(content);
@@ -197,6 +220,19 @@ impl SourceManager {
         self.sources.iter().filter(|entry| entry.key().is_external()).map(|entry| *entry.key())
     }
 
+    /// Retrieve the path of the source with the given identifier, if it was inserted from a path.
+    ///
+    /// # Parameters
+    ///
+    /// - `source_id`: The identifier of the source.
+    ///
+    /// # Returns
+    ///
+    /// The path of the source, or `None` if the source does not exist, or was inserted from its content.
+    pub fn get_path(&self, source_id: &SourceIdentifier) -> Option<PathBuf> {
+        self.sources.get(source_id).and_then(|entry| entry.path.clone())
+    }
+
     /// Retrieve the source with the given identifier from the manager.
     ///
     /// # Parameters
@@ -97,8 +97,11 @@ impl Span {
         Span::new(self.start, other.end)
     }
 
+    /// Checks if the given position is within this span, and in the same source.
     pub fn contains(&self, position: &impl HasPosition) -> bool {
-        self.has_offset(position.offset())
+        let position = position.position();
+
+        self.start.source == position.source && self.has_offset(position.offset)
     }
 
     pub fn has_offset(&self, offset: usize) -> bool {
@@ -0,0 +1,1169 @@
+use ahash::HashSet;
+
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::*;
+use mago_reflection::CodebaseReflection;
+use mago_trinary::Trinary;
+
+const NULL: u16 = 1 << 0;
+const TRUE: u16 = 1 << 1;
+const FALSE: u16 = 1 << 2;
+const INTEGER: u16 = 1 << 3;
+const FLOAT: u16 = 1 << 4;
+const STRING: u16 = 1 << 5;
+const ARRAY: u16 = 1 << 6;
+const OBJECT: u16 = 1 << 7;
+const RESOURCE: u16 = 1 << 8;
+const BOOL: u16 = TRUE | FALSE;
+const SCALAR: u16 = BOOL | INTEGER | FLOAT | STRING;
+
+/// Compares types to determine whether one is contained by ( i.e. is a subtype of ) another.
+///
+/// The result of a comparison is a `Trinary`:
+///
+/// - `Trinary::True` if every value of the input type is a value of the container type.
+/// - `Trinary::False` if no value of the input type is a value of the container type.
+/// - `Trinary::Maybe` if some values of the input type might be values of the container type,
+///   or if there is not enough information to tell.
+///
+/// Class-like types are compared using the inheritance information of the codebase, and
+/// generic type parameters are compared according to the variance of the templates they bind.
+pub struct TypeComparator<'i, 'c> {
+    interner: &'i ThreadedInterner,
+    codebase: &'c CodebaseReflection,
+}
+
+impl<'i, 'c> TypeComparator<'i, 'c> {
+    pub fn new(interner: &'i ThreadedInterner, codebase: &'c CodebaseReflection) -> Self {
+        Self { interner, codebase }
+    }
+
+    /// Determines whether the `input` type is contained by the `container` type.
+    pub fn is_contained_by(&self, input: &TypeKind, container: &TypeKind) -> Trinary {
+        if input == container {
+            return Trinary::True;
+        }
+
+        match (input, container) {
+            (_, TypeKind::Mixed { .. }) | (TypeKind::Never, _) => Trinary::True,
+            (TypeKind::Union { kinds }, _) => {
+                combine_all(kinds.iter().map(|kind| self.is_contained_by(kind, container)))
+            }
+            (TypeKind::Conditional { then, otherwise, .. }, _) => {
+                combine_all([self.is_contained_by(then, container), self.is_contained_by(otherwise, container)])
+            }
+            (
+                TypeKind::GenericParameter { name, defined_in, .. },
+                TypeKind::GenericParameter { name: container_name, defined_in: container_defined_in, .. },
+            ) if name == container_name && defined_in == container_defined_in => Trinary::True,
+            (TypeKind::GenericParameter { of, .. }, _) => self.is_contained_by(of, container),
+            (_, TypeKind::GenericParameter { of, .. }) => {
+                // The template could be bound to any subtype of its constraint, so we can only
+                // tell for sure when the input is not contained by the constraint.
+                self.is_contained_by(input, of) & Trinary::Maybe
+            }
+            (TypeKind::Mixed { .. }, _) => Trinary::Maybe,
+            (_, TypeKind::Never) => Trinary::False,
+            (TypeKind::Intersection { kinds }, _) => {
+                let mut result = Trinary::False;
+                for kind in kinds {
+                    match self.is_contained_by(kind, container) {
+                        Trinary::True => return Trinary::True,
+                        Trinary::Maybe => result = Trinary::Maybe,
+                        Trinary::False => {}
+                    }
+                }
+
+                result
+            }
+            (_, TypeKind::Union { kinds }) => self.is_contained_by_union(input, kinds),
+            (_, TypeKind::Intersection { kinds }) => {
+                kinds.iter().map(|kind| self.is_contained_by(input, kind)).collect()
+            }
+            (TypeKind::Void, TypeKind::Void) => Trinary::True,
+            (TypeKind::Void, _) => self.is_contained_by(&null_kind(), container),
+            (_, TypeKind::Void) => Trinary::False,
+            (
+                TypeKind::Variable { .. }
+                | TypeKind::KeyOf { .. }
+                | TypeKind::ValueOf { .. }
+                | TypeKind::PropertiesOf { .. }
+                | TypeKind::Index { .. }
+                | TypeKind::ClassStringMap { .. }
+                | TypeKind::Value(ValueTypeKind::ClassLikeConstant { .. }),
+                _,
+            )
+            | (
+                _,
+                TypeKind::Variable { .. }
+                | TypeKind::KeyOf { .. }
+                | TypeKind::ValueOf { .. }
+                | TypeKind::PropertiesOf { .. }
+                | TypeKind::Index { .. }
+                | TypeKind::ClassStringMap { .. }
+                | TypeKind::Conditional { .. }
+                | TypeKind::Value(ValueTypeKind::ClassLikeConstant { .. }),
+            ) => Trinary::Maybe,
+            (TypeKind::Value(value), _) => self.is_value_contained_by(value, container),
+            (TypeKind::Scalar(scalar), _) => self.is_scalar_contained_by(scalar, container),
+            (TypeKind::Array(array), _) => self.is_array_contained_by(array, container),
+            (TypeKind::Object(object), _) => self.is_object_contained_by(object, container),
+            (TypeKind::Callable(callable), _) => self.is_callable_contained_by(callable, container),
+            (TypeKind::Iterable { key, value }, TypeKind::Iterable { key: container_key, value: container_value }) => {
+                self.is_contained_by(key, container_key) & self.is_contained_by(value, container_value)
+            }
+            (TypeKind::Resource | TypeKind::ClosedResource, TypeKind::Resource) => Trinary::True,
+            (TypeKind::Resource, TypeKind::ClosedResource) => Trinary::Maybe,
+            _ => compare_families(input, container),
+        }
+    }
+
+    fn is_contained_by_union(&self, input: &TypeKind, kinds: &[TypeKind]) -> Trinary {
+        let result = kinds.iter().fold(Trinary::False, |result, kind| result | self.is_contained_by(input, kind));
+        if result.is_true() {
+            return result;
+        }
+
+        // The input might not be contained by any single member of the union, while each of its
+        // constituents is, e.g. `bool` is contained by `true|false`, and `array-key` by `int|string`.
+        let constituents = match input {
+            TypeKind::Scalar(ScalarTypeKind::Bool) => vec![true_kind(), false_kind()],
+            TypeKind::Scalar(ScalarTypeKind::ArrayKey) => vec![integer_kind(), string_kind()],
+            TypeKind::Scalar(ScalarTypeKind::Numeric) => {
+                vec![integer_kind(), float_kind(), TypeKind::Scalar(ScalarTypeKind::NumericString)]
+            }
+            TypeKind::Scalar(ScalarTypeKind::Scalar) => {
+                vec![true_kind(), false_kind(), integer_kind(), float_kind(), string_kind()]
+            }
+            _ => return result,
+        };
+
+        let container = TypeKind::Union { kinds: kinds.to_vec() };
+
+        combine_all(constituents.iter().map(|constituent| self.is_contained_by(constituent, &container)))
+    }
+
+    fn is_value_contained_by(&self, value: &ValueTypeKind, container: &TypeKind) -> Trinary {
+        let TypeKind::Scalar(scalar) = container else {
+            return match (value, container) {
+                (ValueTypeKind::String { .. }, TypeKind::Callable(CallableTypeKind::Callable { .. })) => Trinary::Maybe,
+                (_, TypeKind::Value(_)) => Trinary::False,
+                _ => compare_families(&TypeKind::Value(*value), container),
+            };
+        };
+
+        match value {
+            ValueTypeKind::Null => Trinary::False,
+            ValueTypeKind::True | ValueTypeKind::False => {
+                matches!(scalar, ScalarTypeKind::Bool | ScalarTypeKind::Scalar).into()
+            }
+            ValueTypeKind::Integer { value } => match scalar {
+                ScalarTypeKind::Integer { min, max } => {
+                    (min.is_none_or(|min| *value >= min as i64) && max.is_none_or(|max| *value <= max as i64)).into()
+                }
+                ScalarTypeKind::IntegerMask(values) => {
+                    let mask = values.iter().fold(0, |mask, value| mask | *value as i64);
+
+                    (*value >= 0 && *value & !mask == 0).into()
+                }
+                ScalarTypeKind::IntegerMaskOf(_, _) => Trinary::Maybe,
+                ScalarTypeKind::LiteralInt
+                | ScalarTypeKind::ArrayKey
+                | ScalarTypeKind::Numeric
+                | ScalarTypeKind::Scalar => Trinary::True,
+                _ => Trinary::False,
+            },
+            ValueTypeKind::Float { .. } => {
+                matches!(scalar, ScalarTypeKind::Float | ScalarTypeKind::Numeric | ScalarTypeKind::Scalar).into()
+            }
+            ValueTypeKind::String { value, length, .. } => {
+                let string = self.interner.lookup(value);
+
+                match scalar {
+                    ScalarTypeKind::String
+                    | ScalarTypeKind::LiteralString
+                    | ScalarTypeKind::ArrayKey
+                    | ScalarTypeKind::Scalar => Trinary::True,
+                    ScalarTypeKind::NonEmptyString => (*length > 0).into(),
+                    ScalarTypeKind::NumericString | ScalarTypeKind::Numeric => is_numeric_string(string).into(),
+                    ScalarTypeKind::ClassString(None) => {
+                        self.is_known_class_like_name(string, |name| matches!(name, ClassLikeName::Class(_)))
+                    }
+                    ScalarTypeKind::TraitString => {
+                        self.is_known_class_like_name(string, |name| matches!(name, ClassLikeName::Trait(_)))
+                    }
+                    ScalarTypeKind::EnumString => {
+                        self.is_known_class_like_name(string, |name| matches!(name, ClassLikeName::Enum(_)))
+                    }
+                    ScalarTypeKind::ClassString(Some(class_name)) => {
+                        let string = string.trim_start_matches('\\');
+                        let Some(class_like) = self.find_class_like(string) else {
+                            return Trinary::Maybe;
+                        };
+
+                        self.is_class_like_instance_of(class_like, class_name)
+                    }
+                    ScalarTypeKind::CallableString => Trinary::Maybe,
+                    _ => Trinary::False,
+                }
+            }
+            ValueTypeKind::ClassLikeConstant { .. } => Trinary::Maybe,
+        }
+    }
+
+    fn is_scalar_contained_by(&self, scalar: &ScalarTypeKind, container: &TypeKind) -> Trinary {
+        let container_scalar = match container {
+            TypeKind::Scalar(container_scalar) => container_scalar,
+            TypeKind::Value(value) => {
+                // A literal value is contained by a scalar type, only if some of the values of the
+                // scalar type are that literal.
+                return self.is_value_contained_by(value, &TypeKind::Scalar(scalar.clone())) & Trinary::Maybe;
+            }
+            TypeKind::Callable(CallableTypeKind::Callable { .. }) => {
+                return match scalar {
+                    ScalarTypeKind::CallableString => Trinary::True,
+                    scalar if is_string_scalar(scalar) => Trinary::Maybe,
+                    ScalarTypeKind::ArrayKey | ScalarTypeKind::Numeric | ScalarTypeKind::Scalar => Trinary::Maybe,
+                    _ => Trinary::False,
+                };
+            }
+            _ => return compare_families(&TypeKind::Scalar(scalar.clone()), container),
+        };
+
+        if scalar == container_scalar || matches!(container_scalar, ScalarTypeKind::Scalar) {
+            return Trinary::True;
+        }
+
+        match scalar {
+            ScalarTypeKind::Bool => Trinary::False,
+            ScalarTypeKind::Float => matches!(container_scalar, ScalarTypeKind::Numeric).into(),
+            scalar if is_integer_scalar(scalar) => match container_scalar {
+                ScalarTypeKind::ArrayKey | ScalarTypeKind::Numeric => Trinary::True,
+                ScalarTypeKind::Integer { min: container_min, max: container_max } => {
+                    let (min, max) = match scalar {
+                        ScalarTypeKind::Integer { min, max } => (*min, *max),
+                        _ => (None, None),
+                    };
+
+                    compare_integer_ranges((min, max), (*container_min, *container_max))
+                }
+                container_scalar if is_integer_scalar(container_scalar) => Trinary::Maybe,
+                _ => Trinary::False,
+            },
+            scalar if is_string_scalar(scalar) => match container_scalar {
+                ScalarTypeKind::String | ScalarTypeKind::ArrayKey => Trinary::True,
+                ScalarTypeKind::NonEmptyString => match scalar {
+                    ScalarTypeKind::NumericString
+                    | ScalarTypeKind::ClassString(_)
+                    | ScalarTypeKind::TraitString
+                    | ScalarTypeKind::EnumString
+                    | ScalarTypeKind::CallableString => Trinary::True,
+                    _ => Trinary::Maybe,
+                },
+                ScalarTypeKind::Numeric => match scalar {
+                    ScalarTypeKind::NumericString => Trinary::True,
+                    _ => Trinary::Maybe,
+                },
+                ScalarTypeKind::ClassString(container_class) => match (scalar, container_class) {
+                    (ScalarTypeKind::ClassString(_), None) => Trinary::True,
+                    (ScalarTypeKind::ClassString(Some(class)), Some(container_class)) => {
+                        self.is_named_object_contained_by(class, &[], container_class, &[])
+                    }
+                    _ => Trinary::Maybe,
+                },
+                container_scalar if is_string_scalar(container_scalar) => Trinary::Maybe,
+                _ => Trinary::False,
+            },
+            ScalarTypeKind::ArrayKey => {
+                Trinary::Maybe & (is_integer_scalar(container_scalar) || is_string_scalar(container_scalar))
+            }
+            ScalarTypeKind::Numeric => {
+                Trinary::Maybe
+                    & (is_integer_scalar(container_scalar)
+                        || is_string_scalar(container_scalar)
+                        || matches!(container_scalar, ScalarTypeKind::Float | ScalarTypeKind::ArrayKey))
+            }
+            _ => Trinary::Maybe,
+        }
+    }
+
+    fn is_array_contained_by(&self, array: &ArrayTypeKind, container: &TypeKind) -> Trinary {
+        match (array, container) {
+            (ArrayTypeKind::CallableArray, TypeKind::Callable(CallableTypeKind::Callable { .. })) => Trinary::True,
+            (_, TypeKind::Callable(CallableTypeKind::Callable { .. })) => Trinary::Maybe,
+            (ArrayTypeKind::CallableArray, TypeKind::Array(_)) => Trinary::Maybe,
+            (_, TypeKind::Array(ArrayTypeKind::CallableArray)) => Trinary::Maybe,
+            (_, TypeKind::Array(ArrayTypeKind::Array { non_empty, key, value, .. })) => {
+                let mut result = self.is_array_entries_contained_by(array, key, value);
+                if *non_empty && !is_non_empty_array(array) {
+                    result &= Trinary::Maybe;
+                }
+
+                result
+            }
+            (_, TypeKind::Array(ArrayTypeKind::List { non_empty, value, .. })) => {
+                let mut result = match array {
+                    ArrayTypeKind::List { value: input_value, .. } => self.is_contained_by(input_value, value),
+                    ArrayTypeKind::Array { key: input_key, value: input_value, .. } => {
+                        self.is_contained_by(input_key, &integer_kind())
+                            & self.is_contained_by(input_value, value)
+                            & Trinary::Maybe
+                    }
+                    ArrayTypeKind::Shape(shape) => {
+                        let mut result = Trinary::True;
+                        for (index, property) in shape.properties.iter().enumerate() {
+                            match property.key {
+                                None => {}
+                                Some(ArrayShapePropertyKey::Integer(key)) if key == index as isize => {}
+                                Some(ArrayShapePropertyKey::Integer(_)) => result &= Trinary::Maybe,
+                                Some(ArrayShapePropertyKey::String(_)) if property.optional => {
+                                    result &= Trinary::Maybe;
+                                }
+                                Some(ArrayShapePropertyKey::String(_)) => return Trinary::False,
+                            }
+
+                            if property.optional {
+                                result &= Trinary::Maybe;
+                            }
+
+                            result &= self.is_contained_by(&property.kind, value);
+                        }
+
+                        if let Some((additional_key, additional_value)) = &shape.additional_properties {
+                            result &= self.is_contained_by(additional_key, &integer_kind())
+                                & self.is_contained_by(additional_value, value)
+                                & Trinary::Maybe;
+                        }
+
+                        result
+                    }
+                    ArrayTypeKind::CallableArray => Trinary::Maybe,
+                };
+
+                if *non_empty && !is_non_empty_array(array) {
+                    result &= Trinary::Maybe;
+                }
+
+                result
+            }
+            (ArrayTypeKind::Shape(shape), TypeKind::Array(ArrayTypeKind::Shape(container_shape))) => {
+                self.is_shape_contained_by(shape, container_shape)
+            }
+            (_, TypeKind::Array(ArrayTypeKind::Shape(container_shape))) => {
+                let (_, value) = get_array_entries(self.interner, array);
+                for property in container_shape.properties.iter().filter(|property| !property.optional) {
+                    if self.is_contained_by(&value, &property.kind).is_false() {
+                        return Trinary::False;
+                    }
+                }
+
+                Trinary::Maybe
+            }
+            (_, TypeKind::Iterable { key, value }) => self.is_array_entries_contained_by(array, key, value),
+            _ => compare_families(&TypeKind::Array(array.clone()), container),
+        }
+    }
+
+    fn is_array_entries_contained_by(&self, array: &ArrayTypeKind, key: &TypeKind, value: &TypeKind) -> Trinary {
+        match array {
+            ArrayTypeKind::Shape(shape) => {
+                let mut result = Trinary::True;
+                for (index, property) in shape.properties.iter().enumerate() {
+                    result &= self.is_contained_by(&get_shape_property_key_kind(self.interner, property, index), key);
+                    result &= self.is_contained_by(&property.kind, value);
+                }
+
+                if let Some((additional_key, additional_value)) = &shape.additional_properties {
+                    result &= self.is_contained_by(additional_key, key);
+                    result &= self.is_contained_by(additional_value, value);
+                }
+
+                result
+            }
+            _ => {
+                let (input_key, input_value) = get_array_entries(self.interner, array);
+
+                self.is_contained_by(&input_key, key) & self.is_contained_by(&input_value, value)
+            }
+        }
+    }
+
+    fn is_shape_contained_by(&self, shape: &ArrayShape, container: &ArrayShape) -> Trinary {
+        let mut result = Trinary::True;
+        let mut matched = HashSet::default();
+
+        for (index, container_property) in container.properties.iter().enumerate() {
+            let container_key = get_shape_property_key(container_property, index);
+            let property = shape
+                .properties
+                .iter()
+                .enumerate()
+                .find(|(index, property)| get_shape_property_key(property, *index) == container_key);
+
+            match property {
+                Some((index, property)) => {
+                    matched.insert(index);
+
+                    if property.optional && !container_property.optional {
+                        result &= Trinary::Maybe;
+                    }
+
+                    result &= self.is_contained_by(&property.kind, &container_property.kind);
+                }
+                None if container_property.optional => {}
+                None if shape.additional_properties.is_some() => result &= Trinary::Maybe,
+                None => return Trinary::False,
+            }
+        }
+
+        if let Some((container_key, container_value)) = &container.additional_properties {
+            for (index, property) in shape.properties.iter().enumerate() {
+                if matched.contains(&index) {
+                    continue;
+                }
+
+                result &=
+                    self.is_contained_by(&get_shape_property_key_kind(self.interner, property, index), container_key);
+                result &= self.is_contained_by(&property.kind, container_value);
+            }
+
+            if let Some((key, value)) = &shape.additional_properties {
+                result &= self.is_contained_by(key, container_key);
+                result &= self.is_contained_by(value, container_value);
+            }
+        }
+
+        result
+    }
+
+    fn is_object_contained_by(&self, object: &ObjectTypeKind, container: &TypeKind) -> Trinary {
+        let object = match self.normalize_object(object) {
+            Some(object) => object,
+            None => return Trinary::Maybe,
+        };
+
+        match container {
+            TypeKind::Object(container_object) => {
+                if &object == container_object {
+                    return Trinary::True;
+                }
+
+                match (&object, container_object) {
+                    (_, ObjectTypeKind::AnyObject) => Trinary::True,
+                    (ObjectTypeKind::Static { scope }, ObjectTypeKind::Static { scope: container_scope })
+                        if scope == container_scope =>
+                    {
+                        Trinary::True
+                    }
+                    (_, ObjectTypeKind::Static { scope }) => {
+                        // `static` could be any subclass of its scope.
+                        self.is_object_contained_by(&object, &named_object_kind(*scope, vec![])) & Trinary::Maybe
+                    }
+                    (ObjectTypeKind::Static { scope }, _) => {
+                        self.is_object_contained_by(&named_object(*scope), container)
+                    }
+                    (
+                        ObjectTypeKind::TypedObject { properties },
+                        ObjectTypeKind::TypedObject { properties: container_properties },
+                    ) => {
+                        let mut result = Trinary::True;
+                        for container_property in container_properties {
+                            match properties.iter().find(|property| property.name == container_property.name) {
+                                Some(property) => {
+                                    if property.optional && !container_property.optional {
+                                        result &= Trinary::Maybe;
+                                    }
+
+                                    result &= self.is_contained_by(&property.kind, &container_property.kind);
+                                }
+                                None if container_property.optional => {}
+                                None => result &= Trinary::Maybe,
+                            }
+                        }
+
+                        result
+                    }
+                    (_, ObjectTypeKind::TypedObject { .. }) => Trinary::Maybe,
+                    (ObjectTypeKind::AnyObject | ObjectTypeKind::TypedObject { .. }, _) => Trinary::Maybe,
+                    (
+                        ObjectTypeKind::EnumCase { enum_name, case_name },
+                        ObjectTypeKind::EnumCase { enum_name: container_enum_name, case_name: container_case_name },
+                    ) => {
+                        if !self.is_same_name(enum_name, container_enum_name) {
+                            return Trinary::False;
+                        }
+
+                        (case_name == container_case_name).into()
+                    }
+                    (ObjectTypeKind::NamedObject { name, .. }, ObjectTypeKind::EnumCase { enum_name, .. }) => {
+                        self.is_named_object_contained_by(name, &[], enum_name, &[]) & Trinary::Maybe
+                    }
+                    (_, ObjectTypeKind::EnumCase { .. }) => Trinary::False,
+                    (
+                        ObjectTypeKind::Generator { key, value, send, r#return },
+                        ObjectTypeKind::Generator {
+                            key: container_key,
+                            value: container_value,
+                            send: container_send,
+                            r#return: container_return,
+                        },
+                    ) => {
+                        self.is_contained_by(key, container_key)
+                            & self.is_contained_by(value, container_value)
+                            & self.is_contained_by(container_send, send)
+                            & self.is_contained_by(r#return, container_return)
+                    }
+                    (ObjectTypeKind::NamedObject { name, .. }, ObjectTypeKind::Generator { .. }) => {
+                        // `Generator` is a final class, which only implements `Iterator` and `Traversable`.
+                        let is_generator_parent = ["Generator", "Iterator", "Traversable"]
+                            .iter()
+                            .any(|builtin| self.is_builtin(name, builtin));
+
+                        if is_generator_parent || self.find_named_class_like(name).is_none() {
+                            Trinary::Maybe
+                        } else {
+                            Trinary::False
+                        }
+                    }
+                    (_, ObjectTypeKind::Generator { .. }) => Trinary::False,
+                    (ObjectTypeKind::Generator { .. }, ObjectTypeKind::NamedObject { name, .. }) => {
+                        ["Generator", "Iterator", "Traversable"]
+                            .iter()
+                            .any(|builtin| self.is_builtin(name, builtin))
+                            .into()
+                    }
+                    (ObjectTypeKind::AnonymousObject { span }, ObjectTypeKind::NamedObject { name, .. }) => {
+                        match self.codebase.get_class_like(ClassLikeName::AnonymousClass(*span)) {
+                            Some(class_like) => self.is_class_like_instance_of(class_like, name),
+                            None => Trinary::Maybe,
+                        }
+                    }
+                    (ObjectTypeKind::AnonymousObject { .. }, ObjectTypeKind::AnonymousObject { .. }) => Trinary::False,
+                    (ObjectTypeKind::NamedObject { name, .. }, ObjectTypeKind::AnonymousObject { span }) => {
+                        match self.codebase.get_class_like(ClassLikeName::AnonymousClass(*span)) {
+                            Some(class_like) => self.is_class_like_instance_of(class_like, name) & Trinary::Maybe,
+                            None => Trinary::Maybe,
+                        }
+                    }
+                    (_, ObjectTypeKind::AnonymousObject { .. }) => Trinary::False,
+                    (
+                        ObjectTypeKind::NamedObject { name, type_parameters },
+                        ObjectTypeKind::NamedObject { name: container_name, type_parameters: container_parameters },
+                    ) => self.is_named_object_contained_by(name, type_parameters, container_name, container_parameters),
+                    (
+                        ObjectTypeKind::EnumCase { enum_name, .. },
+                        ObjectTypeKind::NamedObject { name, type_parameters },
+                    ) => self.is_named_object_contained_by(enum_name, &[], name, type_parameters),
+                    _ => Trinary::Maybe,
+                }
+            }
+            TypeKind::Callable(callable) => match &object {
+                ObjectTypeKind::NamedObject { name, .. } if self.is_builtin(name, "Closure") => match callable {
+                    CallableTypeKind::Closure { .. } | CallableTypeKind::Callable { .. }
+                        if is_any_callable(callable) =>
+                    {
+                        Trinary::True
+                    }
+                    _ => Trinary::Maybe,
+                },
+                ObjectTypeKind::NamedObject { name, .. } => match callable {
+                    // `Closure` is a final class, so no class-like of the codebase can be a closure.
+                    CallableTypeKind::Closure { .. } => match self.find_named_class_like(name) {
+                        Some(_) => Trinary::False,
+                        None => Trinary::Maybe,
+                    },
+                    CallableTypeKind::Callable { .. } => match self.find_named_class_like(name) {
+                        Some(class_like) if self.has_invoke_method(class_like) => Trinary::True,
+                        Some(class_like) if class_like.is_final || class_like.is_enum() => Trinary::False,
+                        _ => Trinary::Maybe,
+                    },
+                },
+                ObjectTypeKind::AnonymousObject { span } => match callable {
+                    CallableTypeKind::Closure { .. } => Trinary::False,
+                    CallableTypeKind::Callable { .. } => {
+                        match self.codebase.get_class_like(ClassLikeName::AnonymousClass(*span)) {
+                            Some(class_like) => self.has_invoke_method(class_like).into(),
+                            None => Trinary::Maybe,
+                        }
+                    }
+                },
+                ObjectTypeKind::AnyObject | ObjectTypeKind::TypedObject { .. } | ObjectTypeKind::Static { .. } => {
+                    Trinary::Maybe
+                }
+                _ => Trinary::False,
+            },
+            TypeKind::Iterable { key, value } => match &object {
+                ObjectTypeKind::Generator { key: input_key, value: input_value, .. } => {
+                    self.is_contained_by(input_key, key) & self.is_contained_by(input_value, value)
+                }
+                ObjectTypeKind::NamedObject { name, .. } => {
+                    let is_traversable = match self.find_named_class_like(name) {
+                        Some(class_like) => {
+                            let result = self.is_class_like_builtin_instance_of(class_like, "Traversable");
+                            if result.is_false() && !class_like.is_final && !class_like.is_enum() {
+                                Trinary::Maybe
+                            } else {
+                                result
+                            }
+                        }
+                        None if ["Traversable", "Iterator", "IteratorAggregate", "Generator"]
+                            .iter()
+                            .any(|builtin| self.is_builtin(name, builtin)) =>
+                        {
+                            Trinary::True
+                        }
+                        None => Trinary::Maybe,
+                    };
+
+                    let is_any_iterable = matches!(key.as_ref(), TypeKind::Mixed { .. })
+                        && matches!(value.as_ref(), TypeKind::Mixed { .. });
+
+                    if is_any_iterable {
+                        is_traversable
+                    } else {
+                        is_traversable & Trinary::Maybe
+                    }
+                }
+                ObjectTypeKind::EnumCase { .. } => Trinary::False,
+                _ => Trinary::Maybe,
+            },
+            _ => compare_families(&TypeKind::Object(object.clone()), container),
+        }
+    }
+
+    fn is_callable_contained_by(&self, callable: &CallableTypeKind, container: &TypeKind) -> Trinary {
+        match (callable, container) {
+            (_, TypeKind::Callable(container_callable)) => {
+                let result = self.is_signature_contained_by(callable, container_callable);
+
+                match (callable, container_callable) {
+                    (CallableTypeKind::Callable { .. }, CallableTypeKind::Closure { .. }) => result & Trinary::Maybe,
+                    _ => result,
+                }
+            }
+            (CallableTypeKind::Closure { .. }, TypeKind::Object(ObjectTypeKind::AnyObject)) => Trinary::True,
+            (CallableTypeKind::Closure { .. }, TypeKind::Object(ObjectTypeKind::NamedObject { name, .. })) => {
+                self.is_builtin(name, "Closure").into()
+            }
+            (CallableTypeKind::Closure { .. }, TypeKind::Object(_)) => Trinary::False,
+            (CallableTypeKind::Callable { .. }, TypeKind::Object(ObjectTypeKind::NamedObject { name, .. })) => {
+                match self.find_named_class_like(name) {
+                    Some(class_like) if class_like.is_final && !self.has_invoke_method(class_like) => Trinary::False,
+                    _ => Trinary::Maybe,
+                }
+            }
+            (CallableTypeKind::Callable { .. }, TypeKind::Object(ObjectTypeKind::EnumCase { .. })) => Trinary::False,
+            (CallableTypeKind::Callable { .. }, TypeKind::Object(_) | TypeKind::Array(_) | TypeKind::Scalar(_)) => {
+                compare_families(&TypeKind::Callable(callable.clone()), container) & Trinary::Maybe
+            }
+            _ => compare_families(&TypeKind::Callable(callable.clone()), container),
+        }
+    }
+
+    fn is_signature_contained_by(&self, callable: &CallableTypeKind, container: &CallableTypeKind) -> Trinary {
+        if is_any_callable(container) {
+            return Trinary::True;
+        }
+
+        let (parameters, return_kind) = get_signature(callable);
+        let (container_parameters, container_return_kind) = get_signature(container);
+
+        let mut result = self.is_contained_by(return_kind, container_return_kind);
+        let length = parameters.len().max(container_parameters.len());
+        for index in 0..length {
+            let parameter = parameters.get(index).or_else(|| parameters.last().filter(|p| p.variadic));
+            let container_parameter =
+                container_parameters.get(index).or_else(|| container_parameters.last().filter(|p| p.variadic));
+
+            match (parameter, container_parameter) {
+                (Some(parameter), Some(container_parameter)) => {
+                    if matches!(container_parameter.kind, TypeKind::Mixed { explicit: false }) {
+                        continue;
+                    }
+
+                    // Parameters are contravariant: the callable must accept everything the
+                    // container can be called with.
+                    result &= self.is_contained_by(&container_parameter.kind, &parameter.kind);
+                }
+                (Some(parameter), None) => {
+                    if !parameter.optional && !parameter.variadic {
+                        // The callable requires more arguments than it would be called with.
+                        return Trinary::False;
+                    }
+                }
+                (None, _) => {}
+            }
+        }
+
+        result
+    }
+
+    fn is_named_object_contained_by(
+        &self,
+        name: &StringIdentifier,
+        type_parameters: &[TypeKind],
+        container_name: &StringIdentifier,
+        container_type_parameters: &[TypeKind],
+    ) -> Trinary {
+        if self.is_same_name(name, container_name) {
+            return self.are_type_parameters_contained_by(
+                name,
+                type_parameters,
+                container_name,
+                container_type_parameters,
+            );
+        }
+
+        let Some(class_like) = self.find_named_class_like(name) else {
+            return Trinary::Maybe;
+        };
+
+        match self.is_class_like_instance_of(class_like, container_name) {
+            Trinary::True => {
+                self.are_type_parameters_contained_by(name, type_parameters, container_name, container_type_parameters)
+            }
+            Trinary::Maybe => Trinary::Maybe,
+            Trinary::False => {
+                // A subclass of the input might still be an instance of the container.
+                if class_like.is_final || class_like.is_enum() {
+                    return Trinary::False;
+                }
+
+                let Some(container_class_like) = self.find_named_class_like(container_name) else {
+                    return Trinary::Maybe;
+                };
+
+                if container_class_like.is_interface() || class_like.is_interface() {
+                    return Trinary::Maybe;
+                }
+
+                if container_class_like.is_enum() || container_class_like.is_trait() || class_like.is_trait() {
+                    return Trinary::False;
+                }
+
+                self.is_class_like_instance_of(container_class_like, name) & Trinary::Maybe
+            }
+        }
+    }
+
+    fn are_type_parameters_contained_by(
+        &self,
+        name: &StringIdentifier,
+        type_parameters: &[TypeKind],
+        container_name: &StringIdentifier,
+        container_type_parameters: &[TypeKind],
+    ) -> Trinary {
+        if container_type_parameters.is_empty() {
+            return Trinary::True;
+        }
+
+        let Some(container_class_like) = self.find_named_class_like(container_name) else {
+            return Trinary::True;
+        };
+
+        let arguments = if self.is_same_name(name, container_name) {
+            if type_parameters.is_empty() {
+                return Trinary::True;
+            }
+
+            type_parameters.to_vec()
+        } else {
+            let Some(class_like) = self.find_named_class_like(name) else {
+                return Trinary::True;
+            };
+
+            let Some(container_class_like_id) = container_class_like.name.inner().map(|name| name.value) else {
+                return Trinary::True;
+            };
+
+            let Some(parameters) = class_like.template_extended_parameters.get(&container_class_like_id) else {
+                return Trinary::True;
+            };
+
+            // Bind the templates of the input class-like to its type parameters.
+            let class_like_id = class_like.name.inner().map(|name| name.value);
+            let resolver = |template_name: &StringIdentifier, defined_in: &StringIdentifier| {
+                if Some(*defined_in) != class_like_id {
+                    return None;
+                }
+
+                class_like
+                    .templates
+                    .iter()
+                    .position(|template| &template.name == template_name)
+                    .and_then(|index| type_parameters.get(index))
+                    .cloned()
+            };
+
+            let mut arguments = vec![];
+            for template in &container_class_like.templates {
+                match parameters.get(&template.name) {
+                    Some(kind) => arguments.push(kind.replace_generic_parameters(&resolver)),
+                    None => return Trinary::True,
+                }
+            }
+
+            arguments
+        };
+
+        let mut result = Trinary::True;
+        for ((template, argument), container_argument) in
+            container_class_like.templates.iter().zip(arguments.iter()).zip(container_type_parameters.iter())
+        {
+            result &= match template.variance {
+                TemplateVariance::Covariant => self.is_contained_by(argument, container_argument),
+                TemplateVariance::Contravariant => self.is_contained_by(container_argument, argument),
+                TemplateVariance::Invariant => {
+                    let result = self.is_contained_by(argument, container_argument);
+                    if result.is_true() && !self.is_contained_by(container_argument, argument).is_true() {
+                        Trinary::Maybe
+                    } else {
+                        result
+                    }
+                }
+            };
+        }
+
+        result
+    }
+
+    /// Determines whether the given class-like is, extends, or implements the class-like with the given name.
+    fn is_class_like_instance_of(&self, class_like: &ClassLikeReflection, name: &StringIdentifier) -> Trinary {
+        let mut visited = HashSet::default();
+
+        self.is_class_like_instance_of_inner(class_like, &|candidate| self.is_same_name(candidate, name), &mut visited)
+    }
+
+    /// Determines whether the given class-like is, extends, or implements the built-in class-like with the given name.
+    fn is_class_like_builtin_instance_of(&self, class_like: &ClassLikeReflection, builtin: &str) -> Trinary {
+        let mut visited = HashSet::default();
+
+        self.is_class_like_instance_of_inner(class_like, &|candidate| self.is_builtin(candidate, builtin), &mut visited)
+    }
+
+    fn is_class_like_instance_of_inner<F>(
+        &self,
+        class_like: &ClassLikeReflection,
+        matches: &F,
+        visited: &mut HashSet<StringIdentifier>,
+    ) -> Trinary
+    where
+        F: Fn(&StringIdentifier) -> bool,
+    {
+        if let Some(class_like_name) = class_like.name.inner() {
+            if matches(&class_like_name.value) {
+                return Trinary::True;
+            }
+
+            if !visited.insert(class_like_name.value) {
+                return Trinary::False;
+            }
+        }
+
+        let inheritance = &class_like.inheritance;
+        let parents = inheritance
+            .direct_extended_class
+            .iter()
+            .chain(inheritance.direct_implemented_interfaces.iter())
+            .chain(inheritance.direct_extended_interfaces.iter());
+
+        let mut result = Trinary::False;
+        for parent in parents {
+            if matches(&parent.value) {
+                return Trinary::True;
+            }
+
+            match self.find_named_class_like(&parent.value) {
+                Some(parent) => match self.is_class_like_instance_of_inner(parent, matches, visited) {
+                    Trinary::True => return Trinary::True,
+                    Trinary::Maybe => result = Trinary::Maybe,
+                    Trinary::False => {}
+                },
+                // The parent is not part of the codebase, so we cannot tell what it extends.
+                None => result = Trinary::Maybe,
+            }
+        }
+
+        result
+    }
+
+    /// Replaces `self` and `parent` with the named object they refer to, and an
+    /// enum case of an unknown enum with the enum itself.
+    fn normalize_object(&self, object: &ObjectTypeKind) -> Option<ObjectTypeKind> {
+        Some(match object {
+            ObjectTypeKind::Self_ { scope } => named_object(*scope),
+            ObjectTypeKind::Parent { scope } => {
+                let class_like = self.find_named_class_like(scope)?;
+
+                named_object(class_like.inheritance.direct_extended_class?.value)
+            }
+            _ => object.clone(),
+        })
+    }
+
+    fn find_named_class_like(&self, name: &StringIdentifier) -> Option<&'c ClassLikeReflection> {
+        if let Some(class_like) = self.codebase.get_named_class_like(name) {
+            return Some(class_like);
+        }
+
+        self.find_class_like(self.interner.lookup(name))
+    }
+
+    fn find_class_like(&self, name: &str) -> Option<&'c ClassLikeReflection> {
+        let name = name.trim_start_matches('\\');
+
+        self.codebase
+            .class_like_names
+            .iter()
+            .find(|(candidate, _)| self.interner.lookup(candidate).eq_ignore_ascii_case(name))
+            .and_then(|(_, class_like_name)| self.codebase.get_class_like(*class_like_name))
+    }
+
+    fn is_known_class_like_name(&self, name: &str, predicate: fn(&ClassLikeName) -> bool) -> Trinary {
+        match self.find_class_like(name) {
+            Some(class_like) => predicate(&class_like.name).into(),
+            None => Trinary::Maybe,
+        }
+    }
+
+    fn has_invoke_method(&self, class_like: &ClassLikeReflection) -> bool {
+        class_like
+            .methods
+            .appering_members
+            .keys()
+            .chain(class_like.methods.members.keys())
+            .any(|method| self.interner.lookup(method).eq_ignore_ascii_case("__invoke"))
+    }
+
+    fn is_same_name(&self, a: &StringIdentifier, b: &StringIdentifier) -> bool {
+        a == b
+            || self
+                .interner
+                .lookup(a)
+                .trim_start_matches('\\')
+                .eq_ignore_ascii_case(self.interner.lookup(b).trim_start_matches('\\'))
+    }
+
+    fn is_builtin(&self, name: &StringIdentifier, builtin: &str) -> bool {
+        self.interner.lookup(name).trim_start_matches('\\').eq_ignore_ascii_case(builtin)
+    }
+}
+
+/// Combines the results of comparing each constituent of a type against a container.
+///
+/// The type is contained by the container if all of its constituents are, and is not
+/// contained by it if none of its constituents are.
+fn combine_all(results: impl IntoIterator<Item = Trinary>) -> Trinary {
+    let mut all_true = true;
+    let mut all_false = true;
+    for result in results {
+        all_true &= result.is_true();
+        all_false &= result.is_false();
+    }
+
+    if all_true {
+        Trinary::True
+    } else if all_false {
+        Trinary::False
+    } else {
+        Trinary::Maybe
+    }
+}
+
+/// Compares two types by the families of values they can hold ( null, booleans, integers, etc. ).
+///
+/// This is used as a fallback when there is no more specific comparison between the two types,
+/// and can only prove that a type is *not* contained by another.
+fn compare_families(input: &TypeKind, container: &TypeKind) -> Trinary {
+    match (get_families(input), get_families(container)) {
+        (Some(input), Some(container)) if input & container == 0 => Trinary::False,
+        _ => Trinary::Maybe,
+    }
+}
+
+fn get_families(kind: &TypeKind) -> Option<u16> {
+    Some(match kind {
+        TypeKind::Union { kinds } => {
+            let mut families = 0;
+            for kind in kinds {
+                families |= get_families(kind)?;
+            }
+
+            families
+        }
+        TypeKind::Intersection { kinds } => {
+            let mut families = u16::MAX;
+            for kind in kinds {
+                families &= get_families(kind)?;
+            }
+
+            families
+        }
+        TypeKind::Conditional { then, otherwise, .. } => get_families(then)? | get_families(otherwise)?,
+        TypeKind::GenericParameter { of, .. } => get_families(of)?,
+        TypeKind::Value(value) => match value {
+            ValueTypeKind::Null => NULL,
+            ValueTypeKind::True => TRUE,
+            ValueTypeKind::False => FALSE,
+            ValueTypeKind::Integer { .. } => INTEGER,
+            ValueTypeKind::Float { .. } => FLOAT,
+            ValueTypeKind::String { .. } => STRING,
+            ValueTypeKind::ClassLikeConstant { .. } => return None,
+        },
+        TypeKind::Scalar(scalar) => match scalar {
+            ScalarTypeKind::Bool => BOOL,
+            ScalarTypeKind::Float => FLOAT,
+            ScalarTypeKind::ArrayKey => INTEGER | STRING,
+            ScalarTypeKind::Numeric => INTEGER | FLOAT | STRING,
+            ScalarTypeKind::Scalar => SCALAR,
+            scalar if is_integer_scalar(scalar) => INTEGER,
+            _ => STRING,
+        },
+        TypeKind::Array(_) => ARRAY,
+        TypeKind::Object(_) => OBJECT,
+        TypeKind::Callable(CallableTypeKind::Closure { .. }) => OBJECT,
+        TypeKind::Callable(CallableTypeKind::Callable { .. }) => STRING | ARRAY | OBJECT,
+        TypeKind::Iterable { .. } => ARRAY | OBJECT,
+        TypeKind::Resource | TypeKind::ClosedResource => RESOURCE,
+        TypeKind::Void => NULL,
+        TypeKind::Never => 0,
+        _ => return None,
+    })
+}
+
+fn is_integer_scalar(scalar: &ScalarTypeKind) -> bool {
+    matches!(
+        scalar,
+        ScalarTypeKind::Integer { .. }
+            | ScalarTypeKind::IntegerMask(_)
+            | ScalarTypeKind::IntegerMaskOf(_, _)
+            | ScalarTypeKind::LiteralInt
+    )
+}
+
+fn is_string_scalar(scalar: &ScalarTypeKind) -> bool {
+    matches!(
+        scalar,
+        ScalarTypeKind::String
+            | ScalarTypeKind::ClassString(_)
+            | ScalarTypeKind::TraitString
+            | ScalarTypeKind::EnumString
+            | ScalarTypeKind::CallableString
+            | ScalarTypeKind::NumericString
+            | ScalarTypeKind::LiteralString
+            | ScalarTypeKind::NonEmptyString
+    )
+}
+
+/// Compares two inclusive integer ranges, where `None` represents an unbounded side.
+fn compare_integer_ranges(
+    (min, max): (Option<isize>, Option<isize>),
+    (container_min, container_max): (Option<isize>, Option<isize>),
+) -> Trinary {
+    let is_min_contained = match (min, container_min) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(min), Some(container_min)) => min >= container_min,
+    };
+
+    let is_max_contained = match (max, container_max) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(max), Some(container_max)) => max <= container_max,
+    };
+
+    if is_min_contained && is_max_contained {
+        return Trinary::True;
+    }
+
+    let is_disjoint = matches!((max, container_min), (Some(max), Some(container_min)) if max < container_min)
+        || matches!((min, container_max), (Some(min), Some(container_max)) if min > container_max);
+
+    if is_disjoint {
+        Trinary::False
+    } else {
+        Trinary::Maybe
+    }
+}
+
+/// Determines whether the given string is numeric, following the rules of PHP 8.
+fn is_numeric_string(string: &str) -> bool {
+    let string = string.trim_matches(|c: char| c.is_ascii_whitespace());
+    if string.is_empty() || !string.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')) {
+        return false;
+    }
+
+    string.parse::<f64>().is_ok()
+}
+
+fn is_any_callable(callable: &CallableTypeKind) -> bool {
+    let (parameters, return_kind) = get_signature(callable);
+
+    matches!(return_kind, TypeKind::Mixed { explicit: false })
+        && matches!(parameters, [parameter] if parameter.variadic && matches!(parameter.kind, TypeKind::Mixed { explicit: false }))
+}
+
+fn get_signature(callable: &CallableTypeKind) -> (&[CallableParameter], &TypeKind) {
+    match callable {
+        CallableTypeKind::Callable { parameters, return_kind, .. }
+        | CallableTypeKind::Closure { parameters, return_kind, .. } => (parameters, return_kind),
+    }
+}
+
+fn is_non_empty_array(array: &ArrayTypeKind) -> bool {
+    match array {
+        ArrayTypeKind::Array { non_empty, .. } | ArrayTypeKind::List { non_empty, .. } => *non_empty,
+        ArrayTypeKind::CallableArray => true,
+        ArrayTypeKind::Shape(shape) => shape.properties.iter().any(|property| !property.optional),
+    }
+}
+
+/// Returns the key and value types of the entries of the given array.
+fn get_array_entries(interner: &ThreadedInterner, array: &ArrayTypeKind) -> (TypeKind, TypeKind) {
+    match array {
+        ArrayTypeKind::Array { key, value, .. } => (key.as_ref().clone(), value.as_ref().clone()),
+        ArrayTypeKind::List { value, .. } => (non_negative_integer_kind(), value.as_ref().clone()),
+        ArrayTypeKind::CallableArray => (non_negative_integer_kind(), mixed_kind(false)),
+        ArrayTypeKind::Shape(shape) => {
+            let mut keys = vec![];
+            let mut values = vec![];
+            for (index, property) in shape.properties.iter().enumerate() {
+                keys.push(get_shape_property_key_kind(interner, property, index));
+                values.push(property.kind.clone());
+            }
+
+            if let Some((key, value)) = &shape.additional_properties {
+                keys.push(key.as_ref().clone());
+                values.push(value.as_ref().clone());
+            }
+
+            match (keys.len(), values.len()) {
+                (0, _) => (never_kind(), never_kind()),
+                (1, _) => (keys.remove(0), values.remove(0)),
+                _ => (union_kind(keys), union_kind(values)),
+            }
+        }
+    }
+}
+
+fn get_shape_property_key(property: &ArrayShapeProperty, index: usize) -> ArrayShapePropertyKey {
+    property.key.unwrap_or(ArrayShapePropertyKey::Integer(index as isize))
+}
+
+fn get_shape_property_key_kind(interner: &ThreadedInterner, property: &ArrayShapeProperty, index: usize) -> TypeKind {
+    match get_shape_property_key(property, index) {
+        ArrayShapePropertyKey::String(key) => {
+            let length = interner.lookup(&key).chars().count();
+
+            value_string_kind(key, length, Trinary::Maybe, Trinary::Maybe, Trinary::Maybe, Trinary::Maybe)
+        }
+        ArrayShapePropertyKey::Integer(key) => value_integer_kind(key as i64),
+    }
+}
+
+fn named_object(name: StringIdentifier) -> ObjectTypeKind {
+    ObjectTypeKind::NamedObject { name, type_parameters: vec![] }
+}
@@ -17,7 +17,7 @@ pub fn resolve_name<'i>(
 
     if value.contains('\\') {
         // take the last part of the path
-        let short_value: &'i str = unsafe { value.split('\\').last().unwrap_unchecked() };
+        let short_value: &'i str = unsafe { value.split('\\').next_back().unwrap_unchecked() };
 
         (value_id, interner.intern(short_value))
     } else {
@@ -413,7 +413,23 @@ where
             match left_kind.is_nullable() {
                 Trinary::False => left_kind,
                 Trinary::True => right_kind,
-                Trinary::Maybe => union_kind(vec![left_kind, right_kind]),
+                Trinary::Maybe => {
+                    // The right operand is only used when the left one is `null`.
+                    let mut kinds = match left_kind {
+                        TypeKind::Union { kinds } => kinds,
+                        kind => vec![kind],
+                    };
+
+                    kinds.retain(|kind| !matches!(kind, TypeKind::Value(ValueTypeKind::Null)));
+                    if !kinds.contains(&right_kind) {
+                        kinds.push(right_kind);
+                    }
+
+                    match kinds.len() {
+                        1 => kinds.remove(0),
+                        _ => union_kind(kinds),
+                    }
+                }
             }
         }
         BinaryOperator::Elvis(_) => match left_kind.is_truthy() {
@@ -5,13 +5,19 @@ use mago_reflection::r#type::TypeReflection;
 use mago_reflection::CodebaseReflection;
 use mago_semantics::Semantics;
 use mago_span::HasSpan;
+use mago_trinary::Trinary;
 
+use crate::comparator::TypeComparator;
 use crate::resolver::TypeResolver;
+use crate::variable::VariableTypes;
 
 mod internal;
 
+pub mod comparator;
 pub mod constant;
+pub mod narrowing;
 pub mod resolver;
+pub mod variable;
 
 /// Infers the type of a given expression by initializing a simple type reflection
 /// that includes the inferred type kind and its source location.
@@ -68,3 +74,61 @@ pub fn resolve_kind<'ast>(
 
     resolver.resolve(expression)
 }
+
+/// Infers the types of the variables in a program by following its flow, narrowing them
+/// using conditions and the assertions of called functions and methods.
+///
+/// - `interner`: Manages string interning.
+/// - `semantics`: Provides source and semantic data.
+/// - `codebase`: Codebase reflection used to resolve function/method types and assertions.
+///
+/// Returns the `VariableTypes` of the program, which can be passed to `resolve_kind_with_variables`.
+pub fn infer_variable_types(
+    interner: &ThreadedInterner,
+    semantics: &Semantics,
+    codebase: &CodebaseReflection,
+) -> VariableTypes {
+    VariableTypes::infer(interner, semantics, codebase)
+}
+
+/// Resolves the type kind of an expression with codebase context, using the inferred types
+/// of the variables it contains.
+///
+/// - `interner`: Manages string interning.
+/// - `semantics`: Provides source and semantic data.
+/// - `codebase`: Codebase reflection to resolve function/method types.
+/// - `variables`: The inferred variable types of the program containing the expression.
+/// - `expression`: The expression to resolve.
+///
+/// Returns a `TypeKind` that represents the resolved type of the expression.
+pub fn resolve_kind_with_variables<'ast>(
+    interner: &ThreadedInterner,
+    semantics: &'ast Semantics,
+    codebase: &'ast CodebaseReflection,
+    variables: &'ast VariableTypes,
+    expression: &'ast Expression,
+) -> TypeKind {
+    let resolver = TypeResolver::new(interner, semantics, Some(codebase)).with_variables(variables);
+
+    resolver.resolve(expression)
+}
+
+/// Determines whether a type is contained by ( i.e. is a subtype of ) another type.
+///
+/// - `interner`: Manages string interning.
+/// - `codebase`: Codebase reflection used to compare class-like types.
+/// - `input`: The type to check.
+/// - `container`: The type that should contain `input`.
+///
+/// Returns `Trinary::True` if every value of `input` is a value of `container`, `Trinary::False`
+/// if none of them are, and `Trinary::Maybe` if only some of them might be.
+pub fn is_contained_by(
+    interner: &ThreadedInterner,
+    codebase: &CodebaseReflection,
+    input: &TypeKind,
+    container: &TypeKind,
+) -> Trinary {
+    let comparator = TypeComparator::new(interner, codebase);
+
+    comparator.is_contained_by(input, container)
+}
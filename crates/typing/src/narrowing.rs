@@ -0,0 +1,113 @@
+use mago_reflection::assertion::Assertion;
+use mago_reflection::r#type::kind::*;
+use mago_trinary::Trinary;
+
+use crate::comparator::TypeComparator;
+
+/// Narrows the type of a value given an assertion known to hold for it.
+///
+/// `kind` is the type of the value before the assertion, or `None` if it is not known.
+///
+/// Returns the narrowed type, or `None` if nothing is known about the value after the assertion.
+pub fn narrow(comparator: &TypeComparator<'_, '_>, kind: Option<&TypeKind>, assertion: &Assertion) -> Option<TypeKind> {
+    let kind = match kind {
+        Some(TypeKind::Mixed { .. }) | None => {
+            return match assertion {
+                Assertion::IsType(asserted) | Assertion::IsEqual(asserted) if !is_mixed(asserted) => {
+                    Some(asserted.clone())
+                }
+                Assertion::IsNotIsset => Some(null_kind()),
+                _ => None,
+            };
+        }
+        Some(kind) => kind,
+    };
+
+    let members = get_members(kind);
+    let narrowed = match assertion {
+        Assertion::IsType(asserted) => {
+            if is_mixed(asserted) {
+                return Some(kind.clone());
+            }
+
+            let mut narrowed = vec![];
+            for member in members {
+                match comparator.is_contained_by(&member, asserted) {
+                    Trinary::True => narrowed.push(member),
+                    Trinary::Maybe => narrowed.extend(get_members(asserted)),
+                    Trinary::False => {}
+                }
+            }
+
+            // The value is of a type that was not expected, such as an object implementing an
+            // unrelated interface; the assertion is all we know about it.
+            if narrowed.is_empty() {
+                return Some(asserted.clone());
+            }
+
+            narrowed
+        }
+        Assertion::IsNotType(asserted) => members
+            .into_iter()
+            .filter_map(|member| match (&member, asserted) {
+                (TypeKind::Scalar(ScalarTypeKind::Bool), TypeKind::Value(ValueTypeKind::True)) => Some(false_kind()),
+                (TypeKind::Scalar(ScalarTypeKind::Bool), TypeKind::Value(ValueTypeKind::False)) => Some(true_kind()),
+                _ if comparator.is_contained_by(&member, asserted) == Trinary::True => None,
+                _ => Some(member),
+            })
+            .collect(),
+        Assertion::Truthy | Assertion::Falsy => {
+            let truthy = matches!(assertion, Assertion::Truthy);
+
+            members
+                .into_iter()
+                .filter_map(|member| match member {
+                    TypeKind::Scalar(ScalarTypeKind::Bool) => Some(if truthy { true_kind() } else { false_kind() }),
+                    member => match member.is_truthy() {
+                        Trinary::True if !truthy => None,
+                        Trinary::False if truthy => None,
+                        _ => Some(member),
+                    },
+                })
+                .collect()
+        }
+        Assertion::IsIsset => {
+            members.into_iter().filter(|member| !matches!(member, TypeKind::Value(ValueTypeKind::Null))).collect()
+        }
+        Assertion::IsNotIsset => vec![null_kind()],
+        Assertion::IsEqual(asserted) if comparator.is_contained_by(asserted, kind) != Trinary::False => {
+            vec![asserted.clone()]
+        }
+        _ => return Some(kind.clone()),
+    };
+
+    combine(narrowed)
+}
+
+/// Returns the members of the given type, flattening nested unions.
+pub(crate) fn get_members(kind: &TypeKind) -> Vec<TypeKind> {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().flat_map(get_members).collect(),
+        kind => vec![kind.clone()],
+    }
+}
+
+/// Combines the given members into a single type, or returns `None` if there are none.
+pub(crate) fn combine(members: Vec<TypeKind>) -> Option<TypeKind> {
+    let mut unique: Vec<TypeKind> = Vec::with_capacity(members.len());
+    for member in members {
+        if !unique.contains(&member) {
+            unique.push(member);
+        }
+    }
+
+    match unique.len() {
+        0 => None,
+        1 => unique.pop(),
+        _ => Some(union_kind(unique)),
+    }
+}
+
+fn is_mixed(kind: &TypeKind) -> bool {
+    matches!(kind, TypeKind::Mixed { .. })
+}
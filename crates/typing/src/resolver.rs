@@ -3,8 +3,12 @@ use ahash::HashSet;
 use mago_ast::*;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
 use mago_reflection::identifier::ClassLikeName;
 use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::identifier::Name;
 use mago_reflection::r#type::kind::*;
 use mago_reflection::CodebaseReflection;
 use mago_semantics::Semantics;
@@ -14,6 +18,7 @@ use mago_trinary::Trinary;
 
 use crate::constant::ConstantTypeResolver;
 use crate::internal::*;
+use crate::variable::VariableTypes;
 
 /// A basic type resolver designed to initialize types at the beginning of type checking.
 /// This resolver is intentionally simple and acts as a "bootstrap" to get type information
@@ -31,10 +36,16 @@ use crate::internal::*;
 ///
 /// If a codebase is available, this resolver can leverage it to retrieve types of functions,
 /// methods, and constants, making it slightly more powerful in providing initial type information.
+///
+/// ### Variable Types
+///
+/// If inferred variable types are provided using [`TypeResolver::with_variables`], variables are
+/// resolved to their inferred types instead of `mixed`.
 pub struct TypeResolver<'i, 'c> {
     interner: &'i ThreadedInterner,
     semantics: &'c Semantics,
     codebase: Option<&'c CodebaseReflection>,
+    variables: Option<&'c VariableTypes>,
     constant_resolver: ConstantTypeResolver<'i, 'c>,
 }
 
@@ -48,10 +59,18 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
             interner,
             semantics,
             codebase,
+            variables: None,
             constant_resolver: ConstantTypeResolver::new(interner, semantics, codebase),
         }
     }
 
+    /// Uses the given inferred variable types when resolving variables.
+    pub fn with_variables(mut self, variables: &'c VariableTypes) -> Self {
+        self.variables = Some(variables);
+
+        self
+    }
+
     pub fn resolve(&self, expression: &Expression) -> TypeKind {
         match expression {
             Expression::Parenthesized(parenthesized) => self.resolve(&parenthesized.expression),
@@ -72,8 +91,7 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
             Expression::AnonymousClass(anonymous_class) => anonymous_object_kind(anonymous_class.span()),
             Expression::Closure(closure) => {
                 if let Some(codebase) = self.codebase {
-                    if let Some(function) = codebase.get_function_like(FunctionLikeName::ArrowFunction(closure.span()))
-                    {
+                    if let Some(function) = codebase.get_function_like(FunctionLikeName::Closure(closure.span())) {
                         return TypeKind::from(function);
                     }
                 }
@@ -141,17 +159,15 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             let (full_name, short_name) = resolve_name(self.interner, identifier.value());
 
                             if let Some(function) = codebase.get_function(&full_name) {
-                                return function.return_type_reflection.as_ref().map_or_else(
-                                    || mixed_kind(false),
-                                    |return_type| return_type.type_reflection.kind.clone(),
-                                );
+                                return function
+                                    .get_return_type_reflection()
+                                    .map_or_else(|| mixed_kind(false), |return_type| return_type.kind.clone());
                             }
 
                             if let Some(function) = codebase.get_function(&short_name) {
-                                return function.return_type_reflection.as_ref().map_or_else(
-                                    || mixed_kind(false),
-                                    |return_type| return_type.type_reflection.kind.clone(),
-                                );
+                                return function
+                                    .get_return_type_reflection()
+                                    .map_or_else(|| mixed_kind(false), |return_type| return_type.kind.clone());
                             }
                         }
                     }
@@ -179,11 +195,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             };
 
                             if let Some(class_reflection) = class_like_reflection {
-                                if let Some(method) = class_reflection.get_method(&method.value) {
-                                    return method.return_type_reflection.as_ref().map_or_else(
-                                        || mixed_kind(false),
-                                        |return_type| return_type.type_reflection.kind.clone(),
-                                    );
+                                if let Some(method) = codebase.get_method(class_reflection, &method.value) {
+                                    return self.get_method_return_kind(class_reflection, &method, Some(&object_kind));
                                 }
                             }
                         }
@@ -212,11 +225,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             };
 
                             if let Some(class_reflection) = class_like_reflection {
-                                if let Some(method) = class_reflection.get_method(&method.value) {
-                                    return method.return_type_reflection.as_ref().map_or_else(
-                                        || mixed_kind(false),
-                                        |return_type| return_type.type_reflection.kind.clone(),
-                                    );
+                                if let Some(method) = codebase.get_method(class_reflection, &method.value) {
+                                    return self.get_method_return_kind(class_reflection, &method, Some(&object_kind));
                                 }
                             }
                         }
@@ -232,11 +242,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             let class_name = self.semantics.names.get(name);
 
                             if let Some(class_reflection) = codebase.get_named_class_like(class_name) {
-                                if let Some(method) = class_reflection.get_method(&method.value) {
-                                    return method.return_type_reflection.as_ref().map_or_else(
-                                        || mixed_kind(false),
-                                        |return_type| return_type.type_reflection.kind.clone(),
-                                    );
+                                if let Some(method) = codebase.get_method(class_reflection, &method.value) {
+                                    return self.get_method_return_kind(class_reflection, &method, None);
                                 }
                             }
                         }
@@ -268,18 +275,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
 
                             let property = self.interner.intern(format!("${}", self.interner.lookup(&property.value)));
                             if let Some(class_reflection) = class_like_reflection {
-                                if let Some(property) = class_reflection.get_property(&property) {
-                                    return property
-                                        .type_reflection
-                                        .as_ref()
-                                        .map(|t| t.kind.clone())
-                                        .or_else(|| {
-                                            property
-                                                .default_value_reflection
-                                                .as_ref()
-                                                .map(|v| v.inferred_type_reflection.kind.clone())
-                                        })
-                                        .unwrap_or_else(|| mixed_kind(false));
+                                if let Some(property) = codebase.get_property(class_reflection, &property) {
+                                    return self.get_property_kind(class_reflection, &property, Some(&object_kind));
                                 }
                             }
                         }
@@ -309,18 +306,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
 
                             let property = self.interner.intern(format!("${}", self.interner.lookup(&property.value)));
                             if let Some(class_reflection) = class_like_reflection {
-                                if let Some(property) = class_reflection.get_property(&property) {
-                                    return property
-                                        .type_reflection
-                                        .as_ref()
-                                        .map(|t| t.kind.clone())
-                                        .or_else(|| {
-                                            property
-                                                .default_value_reflection
-                                                .as_ref()
-                                                .map(|v| v.inferred_type_reflection.kind.clone())
-                                        })
-                                        .unwrap_or_else(|| mixed_kind(false));
+                                if let Some(property) = codebase.get_property(class_reflection, &property) {
+                                    return self.get_property_kind(class_reflection, &property, Some(&object_kind));
                                 }
                             }
                         }
@@ -336,18 +323,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             let class_name = self.semantics.names.get(name);
 
                             if let Some(class_reflection) = codebase.get_named_class_like(class_name) {
-                                if let Some(property) = class_reflection.get_property(&variable.name) {
-                                    return property
-                                        .type_reflection
-                                        .as_ref()
-                                        .map(|t| t.kind.clone())
-                                        .or_else(|| {
-                                            property
-                                                .default_value_reflection
-                                                .as_ref()
-                                                .map(|v| v.inferred_type_reflection.kind.clone())
-                                        })
-                                        .unwrap_or_else(|| mixed_kind(false));
+                                if let Some(property) = codebase.get_property(class_reflection, &variable.name) {
+                                    return self.get_property_kind(class_reflection, &property, None);
                                 }
                             }
                         }
@@ -356,25 +333,38 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                     mixed_kind(false)
                 }
                 Access::ClassConstant(class_constant_access) => {
-                    if let Some(codebase) = self.codebase {
-                        if let (Expression::Identifier(name), ClassLikeConstantSelector::Identifier(constant)) =
-                            (&class_constant_access.class, &class_constant_access.constant)
-                        {
-                            let class_name = self.semantics.names.get(name);
+                    let (Expression::Identifier(name), ClassLikeConstantSelector::Identifier(constant)) =
+                        (&class_constant_access.class, &class_constant_access.constant)
+                    else {
+                        return mixed_kind(false);
+                    };
 
-                            if let Some(class_reflection) = codebase.get_named_class_like(class_name) {
-                                if let Some(constant) = class_reflection.get_constant(&constant.value) {
-                                    return constant
-                                        .type_reflection
-                                        .as_ref()
-                                        .map(|t| t.kind.clone())
-                                        .unwrap_or_else(|| constant.inferred_type_reflection.kind.clone());
-                                }
+                    let class_name = self.semantics.names.get(name);
 
-                                if class_reflection.is_enum() && class_reflection.has_enum_case(&constant.value) {
-                                    return enum_case_kind(*class_name, constant.value);
-                                }
-                            }
+                    // `Foo::class` resolves to the fully qualified name of `Foo`, even without a codebase.
+                    if self.interner.lookup(&constant.value).eq_ignore_ascii_case("class") {
+                        return TypeKind::Scalar(ScalarTypeKind::ClassString(Some(*class_name)));
+                    }
+
+                    let Some(codebase) = self.codebase else {
+                        // Without a codebase, the constant is kept as a reference to be resolved later.
+                        return TypeKind::Value(ValueTypeKind::ClassLikeConstant {
+                            class_like: ClassLikeName::Class(Name::new(*class_name, name.span())),
+                            constant: constant.value,
+                        });
+                    };
+
+                    if let Some(class_reflection) = codebase.get_named_class_like(class_name) {
+                        if let Some(constant) = class_reflection.get_constant(&constant.value) {
+                            return constant
+                                .type_reflection
+                                .as_ref()
+                                .map(|t| t.kind.clone())
+                                .unwrap_or_else(|| constant.inferred_type_reflection.kind.clone());
+                        }
+
+                        if class_reflection.is_enum() && class_reflection.has_enum_case(&constant.value) {
+                            return enum_case_kind(*class_name, constant.value);
                         }
                     }
 
@@ -451,8 +441,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             _ => return any_closure_kind(),
                         };
 
-                        if let Some(method) = class_reflection.get_method(&method_name.value) {
-                            return TypeKind::from(method);
+                        if let Some(method) = codebase.get_method(class_reflection, &method_name.value) {
+                            return TypeKind::from(method.as_ref());
                         } else {
                             return any_closure_kind();
                         }
@@ -476,8 +466,8 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                             return any_closure_kind();
                         };
 
-                        if let Some(method) = class_reflection.get_method(&method_name.value) {
-                            return TypeKind::from(method);
+                        if let Some(method) = codebase.get_method(class_reflection, &method_name.value) {
+                            return TypeKind::from(method.as_ref());
                         } else {
                             return any_closure_kind();
                         }
@@ -486,6 +476,25 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                     any_closure_kind()
                 }
             },
+            Expression::Variable(Variable::Direct(variable)) if self.interner.lookup(&variable.name) == "$this" => {
+                let Some(class_like) = self.codebase.and_then(|codebase| codebase.get_enclosing_class_like(variable))
+                else {
+                    return mixed_kind(false);
+                };
+
+                match class_like.name {
+                    ClassLikeName::AnonymousClass(span) => anonymous_object_kind(span),
+                    ClassLikeName::Class(name) | ClassLikeName::Enum(name) | ClassLikeName::Trait(name) => {
+                        named_object_kind(name.value, vec![])
+                    }
+                    ClassLikeName::Interface(_) => mixed_kind(false),
+                }
+            }
+            Expression::Variable(Variable::Direct(variable)) => self
+                .variables
+                .and_then(|variables| variables.get(variable))
+                .cloned()
+                .unwrap_or_else(|| mixed_kind(false)),
             Expression::Parent(_) => TypeKind::Scalar(ScalarTypeKind::ClassString(None)),
             Expression::Static(_) => TypeKind::Scalar(ScalarTypeKind::ClassString(None)),
             Expression::Self_(_) => TypeKind::Scalar(ScalarTypeKind::ClassString(None)),
@@ -494,9 +503,9 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
                     return any_object_kind();
                 };
 
-                let (class_name, _) = resolve_name(self.interner, class_name.value());
+                let class_name = self.semantics.names.get(class_name);
 
-                TypeKind::Object(ObjectTypeKind::NamedObject { name: class_name, type_parameters: vec![] })
+                TypeKind::Object(ObjectTypeKind::NamedObject { name: *class_name, type_parameters: vec![] })
             }
             Expression::MagicConstant(magic_constant) => match &magic_constant {
                 MagicConstant::Line(local_identifier) => {
@@ -546,4 +555,61 @@ impl<'i, 'c> TypeResolver<'i, 'c> {
             _ => mixed_kind(false),
         }
     }
+
+    /// Returns the return type of the given method, replacing the templates of the class-like
+    /// with the type parameters of the object, if any.
+    fn get_method_return_kind(
+        &self,
+        class_like: &ClassLikeReflection,
+        method: &FunctionLikeReflection,
+        object_kind: Option<&ObjectTypeKind>,
+    ) -> TypeKind {
+        let kind = method.get_return_type_reflection().map_or_else(|| mixed_kind(false), |t| t.kind.clone());
+
+        specialize_kind(class_like, kind, object_kind)
+    }
+
+    /// Returns the type of the given property, replacing the templates of the class-like
+    /// with the type parameters of the object, if any.
+    fn get_property_kind(
+        &self,
+        class_like: &ClassLikeReflection,
+        property: &PropertyReflection,
+        object_kind: Option<&ObjectTypeKind>,
+    ) -> TypeKind {
+        let kind = property
+            .get_type_reflection()
+            .map(|t| t.kind.clone())
+            .or_else(|| property.default_value_reflection.as_ref().map(|v| v.inferred_type_reflection.kind.clone()))
+            .unwrap_or_else(|| mixed_kind(false));
+
+        specialize_kind(class_like, kind, object_kind)
+    }
+}
+
+fn specialize_kind(class_like: &ClassLikeReflection, kind: TypeKind, object_kind: Option<&ObjectTypeKind>) -> TypeKind {
+    let Some(ObjectTypeKind::NamedObject { type_parameters, .. }) = object_kind else {
+        return kind;
+    };
+
+    let Some(class_like_id) = class_like.name.inner().map(|name| name.value) else {
+        return kind;
+    };
+
+    if type_parameters.is_empty() || class_like.templates.is_empty() {
+        return kind;
+    }
+
+    kind.replace_generic_parameters(&|name, defined_in| {
+        if *defined_in != class_like_id {
+            return None;
+        }
+
+        class_like
+            .templates
+            .iter()
+            .position(|template| template.name == *name)
+            .and_then(|index| type_parameters.get(index))
+            .cloned()
+    })
 }
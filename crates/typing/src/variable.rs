@@ -0,0 +1,1568 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::assertion::Assertion;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::assertion::FunctionLikeAssertionReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::r#type::kind::*;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::walk_arrow_function_mut;
+use mago_walker::walk_binary_mut;
+use mago_walker::walk_closure_mut;
+use mago_walker::walk_function_call_mut;
+use mago_walker::walk_function_mut;
+use mago_walker::walk_method_call_mut;
+use mago_walker::walk_method_mut;
+use mago_walker::walk_null_safe_method_call_mut;
+use mago_walker::walk_property_hook_mut;
+use mago_walker::walk_static_method_call_mut;
+use mago_walker::MutWalker;
+
+use crate::comparator::TypeComparator;
+use crate::internal::resolve_name;
+use crate::narrowing::combine;
+use crate::narrowing::get_members;
+use crate::narrowing::narrow;
+use crate::resolver::TypeResolver;
+
+/// Built-in functions that take at least one of their arguments by reference.
+const BY_REFERENCE_FUNCTIONS: &[&str] = &[
+    "array_multisort",
+    "array_pop",
+    "array_push",
+    "array_shift",
+    "array_splice",
+    "array_unshift",
+    "array_walk",
+    "array_walk_recursive",
+    "arsort",
+    "asort",
+    "current",
+    "dns_get_record",
+    "each",
+    "end",
+    "exec",
+    "flock",
+    "fscanf",
+    "fsockopen",
+    "getimagesize",
+    "getmxrr",
+    "headers_sent",
+    "is_callable",
+    "key",
+    "krsort",
+    "ksort",
+    "mb_convert_variables",
+    "mb_parse_str",
+    "natcasesort",
+    "natsort",
+    "next",
+    "openssl_open",
+    "openssl_private_decrypt",
+    "openssl_private_encrypt",
+    "openssl_public_decrypt",
+    "openssl_public_encrypt",
+    "openssl_seal",
+    "openssl_sign",
+    "parse_str",
+    "passthru",
+    "pfsockopen",
+    "preg_match",
+    "preg_match_all",
+    "preg_replace",
+    "preg_replace_callback",
+    "preg_replace_callback_array",
+    "prev",
+    "proc_open",
+    "reset",
+    "rsort",
+    "settype",
+    "shuffle",
+    "similar_text",
+    "sodium_increment",
+    "sodium_memzero",
+    "sort",
+    "sscanf",
+    "str_ireplace",
+    "str_replace",
+    "stream_select",
+    "stream_socket_client",
+    "stream_socket_server",
+    "system",
+    "uasort",
+    "uksort",
+    "usort",
+];
+
+/// The types of variables at the locations they are read or assigned, inferred by following the
+/// flow of a program.
+///
+/// Variable types are narrowed using conditions such as `$value instanceof Foo`, `$value !== null`,
+/// or `is_string($value)`, and using the assertions declared by called functions and methods.
+///
+/// The inference is deliberately conservative: whenever a variable may have been modified in a way
+/// that is not followed, such as through a reference or in a previous iteration of a loop, its type
+/// is considered unknown.
+#[derive(Debug, Clone, Default)]
+pub struct VariableTypes {
+    kinds: HashMap<Span, TypeKind>,
+}
+
+impl VariableTypes {
+    /// Infers the types of the variables in the program of the given semantics.
+    pub fn infer(interner: &ThreadedInterner, semantics: &Semantics, codebase: &CodebaseReflection) -> Self {
+        let mut inferrer = VariableTypeInferrer { interner, semantics, codebase, types: VariableTypes::default() };
+
+        inferrer.walk_program(&semantics.program, &mut Scope::default());
+
+        inferrer.types
+    }
+
+    /// Returns the type of the given variable, if known.
+    pub fn get(&self, variable: &DirectVariable) -> Option<&TypeKind> {
+        self.kinds.get(&variable.span)
+    }
+}
+
+/// The variables known at a given point of a function-like, or of the global scope.
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    variables: HashMap<StringIdentifier, TypeKind>,
+    /// Variables bound by reference, which may be modified without being assigned to.
+    references: HashSet<StringIdentifier>,
+    /// Whether variables may be defined dynamically, e.g. using `extract()`, in which case none are tracked.
+    dynamic: bool,
+    /// Whether execution never proceeds past the current point.
+    terminated: bool,
+}
+
+impl Scope {
+    fn get(&self, name: &StringIdentifier) -> Option<&TypeKind> {
+        self.variables.get(name)
+    }
+
+    fn set(&mut self, name: StringIdentifier, kind: Option<TypeKind>) {
+        match kind {
+            Some(kind)
+                if !self.dynamic && !self.references.contains(&name) && !matches!(kind, TypeKind::Mixed { .. }) =>
+            {
+                self.variables.insert(name, kind);
+            }
+            _ => {
+                self.variables.remove(&name);
+            }
+        }
+    }
+
+    fn forget(&mut self, name: &StringIdentifier) {
+        self.variables.remove(name);
+    }
+
+    fn add_reference(&mut self, name: StringIdentifier) {
+        self.variables.remove(&name);
+        self.references.insert(name);
+    }
+
+    fn make_dynamic(&mut self) {
+        self.variables.clear();
+        self.dynamic = true;
+    }
+
+    /// Creates a scope for a branch starting at the current point.
+    fn branch(&self) -> Scope {
+        let mut scope = self.clone();
+        scope.terminated = false;
+
+        scope
+    }
+
+    /// Merges the scopes of the branches that may continue execution.
+    ///
+    /// Variables only known in some of the branches are unknown after the merge.
+    fn merge(&mut self, branches: Vec<Scope>) {
+        for branch in &branches {
+            self.references.extend(branch.references.iter().copied());
+            self.dynamic |= branch.dynamic;
+        }
+
+        let branches = branches.into_iter().filter(|branch| !branch.terminated).collect::<Vec<_>>();
+        let Some((first, rest)) = branches.split_first() else {
+            self.terminated = true;
+
+            return;
+        };
+
+        let mut variables = HashMap::default();
+        for (name, kind) in &first.variables {
+            let mut members = get_members(kind);
+            let mut known = true;
+            for branch in rest {
+                match branch.get(name) {
+                    Some(kind) => members.extend(get_members(kind)),
+                    None => {
+                        known = false;
+
+                        break;
+                    }
+                }
+            }
+
+            if known {
+                if let Some(kind) = combine(members) {
+                    variables.insert(*name, kind);
+                }
+            }
+        }
+
+        self.variables = variables;
+        if self.dynamic {
+            self.variables.clear();
+        }
+
+        for name in &self.references {
+            self.variables.remove(name);
+        }
+    }
+}
+
+/// Assertions about variables, each of which holds for the variable with the given name.
+type Assertions = Vec<(StringIdentifier, Assertion)>;
+
+struct VariableTypeInferrer<'i, 'c> {
+    interner: &'i ThreadedInterner,
+    semantics: &'c Semantics,
+    codebase: &'c CodebaseReflection,
+    types: VariableTypes,
+}
+
+impl<'i, 'c> VariableTypeInferrer<'i, 'c> {
+    fn resolve(&self, expression: &Expression) -> TypeKind {
+        TypeResolver::new(self.interner, self.semantics, Some(self.codebase))
+            .with_variables(&self.types)
+            .resolve(expression)
+    }
+
+    /// Creates the scope at the start of the body of the given function-like, containing its parameters.
+    fn create_function_like_scope(&self, function_like: Option<&FunctionLikeReflection>) -> Scope {
+        let mut scope = Scope::default();
+        let Some(function_like) = function_like else {
+            return scope;
+        };
+
+        for parameter in &function_like.parameters {
+            if parameter.is_variadic {
+                continue;
+            }
+
+            let Some(type_reflection) = parameter.get_type_reflection() else {
+                continue;
+            };
+
+            let mut members = get_members(&type_reflection.kind);
+            if let Some(default) = &parameter.default {
+                members.extend(get_members(&default.type_reflection.kind));
+            }
+
+            scope.set(parameter.name, combine(members));
+        }
+
+        scope
+    }
+
+    /// Assigns the given type to the given variable, and records it at the location of the assignment.
+    fn assign(&mut self, variable: &DirectVariable, kind: Option<TypeKind>, scope: &mut Scope) {
+        scope.set(variable.name, kind);
+
+        if let Some(kind) = scope.get(&variable.name) {
+            self.types.kinds.insert(variable.span, kind.clone());
+        }
+    }
+
+    /// Forgets the types of the variables written to by assigning to the given expression.
+    fn forget_assigned(&self, expression: &Expression, scope: &mut Scope) {
+        let mut dynamic = false;
+        for variable in get_assigned_variables(expression, &mut dynamic) {
+            scope.forget(&variable.name);
+        }
+
+        if dynamic {
+            scope.make_dynamic();
+        }
+    }
+
+    /// Forgets the types of the variables that may be written to within the given node, as is done
+    /// before walking loops, whose bodies may run after any of their iterations.
+    fn forget_written(&self, node: &impl Walkable, scope: &mut Scope) {
+        let mut collector = WrittenVariableCollector {
+            interner: self.interner,
+            semantics: self.semantics,
+            codebase: self.codebase,
+            dynamic: false,
+        };
+
+        let mut written = HashSet::default();
+        node.walk_with(&mut collector, &mut written);
+
+        for name in &written {
+            scope.forget(name);
+        }
+
+        if collector.dynamic {
+            scope.make_dynamic();
+        }
+    }
+
+    fn apply(&self, assertions: &Assertions, scope: &mut Scope) {
+        if scope.dynamic {
+            return;
+        }
+
+        let comparator = TypeComparator::new(self.interner, self.codebase);
+        for (name, assertion) in assertions {
+            if scope.references.contains(name) {
+                continue;
+            }
+
+            let kind = narrow(&comparator, scope.get(name), assertion);
+
+            scope.set(*name, kind);
+        }
+    }
+
+    /// Returns the assertions that hold when the given condition is truthy, and when it is falsy.
+    fn get_condition_assertions(&self, condition: &Expression) -> (Assertions, Assertions) {
+        match condition {
+            Expression::Parenthesized(parenthesized) => self.get_condition_assertions(&parenthesized.expression),
+            Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) => {
+                let (truthy, falsy) = self.get_condition_assertions(operand);
+
+                (falsy, truthy)
+            }
+            Expression::Variable(Variable::Direct(variable)) => {
+                (vec![(variable.name, Assertion::Truthy)], vec![(variable.name, Assertion::Falsy)])
+            }
+            Expression::AssignmentOperation(assignment)
+                if matches!(assignment.operator, AssignmentOperator::Assign(_)) =>
+            {
+                self.get_condition_assertions(&assignment.lhs)
+            }
+            Expression::Binary(binary) => self.get_binary_assertions(binary),
+            Expression::Construct(construct) => match construct.as_ref() {
+                Construct::Isset(isset) => {
+                    let variables = isset.values.iter().filter_map(get_direct_variable).collect::<Vec<_>>();
+                    let truthy = variables.iter().map(|variable| (variable.name, Assertion::IsIsset)).collect();
+                    let falsy = match variables.as_slice() {
+                        [variable] if isset.values.len() == 1 => vec![(variable.name, Assertion::IsNotIsset)],
+                        _ => vec![],
+                    };
+
+                    (truthy, falsy)
+                }
+                Construct::Empty(empty) => match get_direct_variable(&empty.value) {
+                    Some(variable) => {
+                        (vec![(variable.name, Assertion::Falsy)], vec![(variable.name, Assertion::Truthy)])
+                    }
+                    None => (vec![], vec![]),
+                },
+                _ => (vec![], vec![]),
+            },
+            Expression::Call(call) => self.get_call_condition_assertions(call),
+            _ => (vec![], vec![]),
+        }
+    }
+
+    fn get_binary_assertions(&self, binary: &Binary) -> (Assertions, Assertions) {
+        match &binary.operator {
+            BinaryOperator::And(_) | BinaryOperator::LowAnd(_) => {
+                let (mut lhs_truthy, lhs_falsy) = self.get_condition_assertions(&binary.lhs);
+                let (rhs_truthy, rhs_falsy) = self.get_condition_assertions(&binary.rhs);
+                lhs_truthy.extend(rhs_truthy);
+
+                (lhs_truthy, get_disjunction(&lhs_falsy, &rhs_falsy))
+            }
+            BinaryOperator::Or(_) | BinaryOperator::LowOr(_) => {
+                let (lhs_truthy, mut lhs_falsy) = self.get_condition_assertions(&binary.lhs);
+                let (rhs_truthy, rhs_falsy) = self.get_condition_assertions(&binary.rhs);
+                lhs_falsy.extend(rhs_falsy);
+
+                (get_disjunction(&lhs_truthy, &rhs_truthy), lhs_falsy)
+            }
+            BinaryOperator::Instanceof(_) => {
+                let Some(variable) = get_direct_variable(&binary.lhs) else {
+                    return (vec![], vec![]);
+                };
+
+                let class_name = match binary.rhs.as_ref() {
+                    Expression::Identifier(identifier) => *self.semantics.names.get(identifier),
+                    Expression::Self_(_) | Expression::Static(_) => {
+                        match self
+                            .codebase
+                            .get_enclosing_class_like(binary)
+                            .and_then(|class_like| class_like.name.inner())
+                        {
+                            Some(name) => name.value,
+                            None => return (vec![], vec![]),
+                        }
+                    }
+                    _ => return (vec![], vec![]),
+                };
+
+                let kind = named_object_kind(class_name, vec![]);
+
+                (
+                    vec![(variable.name, Assertion::IsType(kind.clone()))],
+                    vec![(variable.name, Assertion::IsNotType(kind))],
+                )
+            }
+            BinaryOperator::Identical(_) | BinaryOperator::NotIdentical(_) => {
+                let (variable, value) = match (get_direct_variable(&binary.lhs), get_direct_variable(&binary.rhs)) {
+                    (Some(variable), None) => (variable, &binary.rhs),
+                    (None, Some(variable)) => (variable, &binary.lhs),
+                    _ => return (vec![], vec![]),
+                };
+
+                let (truthy, falsy) = match self.resolve(value) {
+                    kind @ TypeKind::Value(ValueTypeKind::Null | ValueTypeKind::True | ValueTypeKind::False) => {
+                        (Assertion::IsType(kind.clone()), Assertion::IsNotType(kind))
+                    }
+                    kind @ (TypeKind::Value(_) | TypeKind::Object(ObjectTypeKind::EnumCase { .. })) => {
+                        (Assertion::IsEqual(kind.clone()), Assertion::IsNotEqual(kind))
+                    }
+                    _ => return (vec![], vec![]),
+                };
+
+                if matches!(binary.operator, BinaryOperator::Identical(_)) {
+                    (vec![(variable.name, truthy)], vec![(variable.name, falsy)])
+                } else {
+                    (vec![(variable.name, falsy)], vec![(variable.name, truthy)])
+                }
+            }
+            BinaryOperator::Equal(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => {
+                // `$value == null` also holds for other falsy values, so only the negation is useful.
+                let variable = match (get_direct_variable(&binary.lhs), get_direct_variable(&binary.rhs)) {
+                    (Some(variable), None) if is_null_literal(&binary.rhs) => variable,
+                    (None, Some(variable)) if is_null_literal(&binary.lhs) => variable,
+                    _ => return (vec![], vec![]),
+                };
+
+                let not_null = vec![(variable.name, Assertion::IsNotType(null_kind()))];
+                if matches!(binary.operator, BinaryOperator::Equal(_)) {
+                    (vec![], not_null)
+                } else {
+                    (not_null, vec![])
+                }
+            }
+            _ => (vec![], vec![]),
+        }
+    }
+
+    fn get_call_condition_assertions(&self, call: &Call) -> (Assertions, Assertions) {
+        if let Call::Function(function_call) = call {
+            if let Some(assertions) = self.get_type_check_assertions(function_call) {
+                return assertions;
+            }
+        }
+
+        let Some((function_like, arguments)) = self.get_call_reflection(call) else {
+            return (vec![], vec![]);
+        };
+
+        (
+            get_argument_assertions(&function_like, &function_like.if_true_assertions, arguments),
+            get_argument_assertions(&function_like, &function_like.if_false_assertions, arguments),
+        )
+    }
+
+    /// Returns the assertions made by calls to built-in type checking functions such as `is_string()`.
+    fn get_type_check_assertions(&self, function_call: &FunctionCall) -> Option<(Assertions, Assertions)> {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return None;
+        };
+
+        let (_, short_name) = resolve_name(self.interner, identifier.value());
+        let kind = match self.interner.lookup(&short_name).to_ascii_lowercase().as_str() {
+            "is_null" => null_kind(),
+            "is_string" => string_kind(),
+            "is_int" | "is_integer" | "is_long" => integer_kind(),
+            "is_float" | "is_double" => float_kind(),
+            "is_bool" => bool_kind(),
+            "is_array" => array_kind(array_key_kind(), mixed_kind(false), None),
+            "is_object" => any_object_kind(),
+            "is_resource" => resource_kind(),
+            _ => return None,
+        };
+
+        let Some(Argument::Positional(argument)) = function_call.arguments.arguments.first() else {
+            return Some((vec![], vec![]));
+        };
+
+        let Some(variable) = get_direct_variable(&argument.value) else {
+            return Some((vec![], vec![]));
+        };
+
+        Some((
+            vec![(variable.name, Assertion::IsType(kind.clone()))],
+            vec![(variable.name, Assertion::IsNotType(kind))],
+        ))
+    }
+
+    /// Returns the reflection of the function-like called by the given call, along with its arguments.
+    fn get_call_reflection<'a>(&self, call: &'a Call) -> Option<(Cow<'c, FunctionLikeReflection>, &'a ArgumentList)> {
+        match call {
+            Call::Function(function_call) => {
+                let function_like = get_function_reflection(self.semantics, self.codebase, &function_call.function)?;
+
+                Some((Cow::Borrowed(function_like), &function_call.arguments))
+            }
+            Call::Method(method_call) => {
+                let method = self.get_method_reflection(&method_call.object, &method_call.method)?;
+
+                Some((method, &method_call.arguments))
+            }
+            Call::NullSafeMethod(method_call) => {
+                let method = self.get_method_reflection(&method_call.object, &method_call.method)?;
+
+                Some((method, &method_call.arguments))
+            }
+            Call::StaticMethod(static_method_call) => {
+                let class_like = get_static_class_like(self.semantics, self.codebase, &static_method_call.class)?;
+                let ClassLikeMemberSelector::Identifier(method) = &static_method_call.method else {
+                    return None;
+                };
+
+                let method = self.codebase.get_method(class_like, &method.value)?;
+
+                Some((method, &static_method_call.arguments))
+            }
+        }
+    }
+
+    fn get_method_reflection(
+        &self,
+        object: &Expression,
+        selector: &ClassLikeMemberSelector,
+    ) -> Option<Cow<'c, FunctionLikeReflection>> {
+        let ClassLikeMemberSelector::Identifier(method) = selector else {
+            return None;
+        };
+
+        let class_like = match self.resolve(object) {
+            TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => self.codebase.get_named_class_like(&name),
+            TypeKind::Object(ObjectTypeKind::AnonymousObject { span }) => {
+                self.codebase.get_class_like(ClassLikeName::AnonymousClass(span))
+            }
+            TypeKind::Object(ObjectTypeKind::EnumCase { enum_name, .. }) => self.codebase.get_enum(&enum_name),
+            _ => None,
+        }?;
+
+        self.codebase.get_method(class_like, &method.value)
+    }
+
+    /// Handles the effects of a call on the variables passed to it, once the call has been walked.
+    fn walk_call_effects(
+        &mut self,
+        call: &Call,
+        function_like: Option<Cow<'c, FunctionLikeReflection>>,
+        arguments: &ArgumentList,
+        scope: &mut Scope,
+    ) {
+        let assume_by_reference = match call {
+            Call::Function(function_call) => match function_call.function.as_ref() {
+                Expression::Identifier(identifier) => {
+                    let (_, short_name) = resolve_name(self.interner, identifier.value());
+                    let name = self.interner.lookup(&short_name).to_ascii_lowercase();
+                    if name == "extract" {
+                        scope.make_dynamic();
+                    }
+
+                    BY_REFERENCE_FUNCTIONS.contains(&name.as_str())
+                }
+                _ => true,
+            },
+            _ => true,
+        };
+
+        for argument in get_referenced_arguments(function_like.as_deref(), arguments, assume_by_reference) {
+            self.forget_assigned(argument, scope);
+        }
+
+        let Some(function_like) = function_like else {
+            return;
+        };
+
+        let assertions = get_argument_assertions(&function_like, &function_like.assertions, arguments);
+        self.apply(&assertions, scope);
+
+        if matches!(function_like.get_return_type_reflection().map(|t| &t.kind), Some(TypeKind::Never)) {
+            scope.terminated = true;
+        }
+    }
+
+    fn walk_statements<'a>(&mut self, statements: impl IntoIterator<Item = &'a Statement>, scope: &mut Scope) {
+        for statement in statements {
+            self.walk_statement(statement, scope);
+        }
+    }
+
+    /// Walks a branch of a conditional expression, forgetting the variables it may write to.
+    fn walk_expression_branch(&mut self, expression: &Expression, assertions: &Assertions, scope: &mut Scope) {
+        let mut branch = scope.branch();
+        self.apply(assertions, &mut branch);
+        self.walk_expression(expression, &mut branch);
+
+        self.forget_written(expression, scope);
+        scope.references = branch.references;
+        if branch.dynamic {
+            scope.make_dynamic();
+        }
+    }
+
+    fn walk_if_branches(
+        &mut self,
+        condition: &Expression,
+        statements: &[&Statement],
+        else_if_clauses: Vec<(&Expression, Vec<&Statement>)>,
+        else_statements: Option<Vec<&Statement>>,
+        scope: &mut Scope,
+    ) {
+        self.walk_expression(condition, scope);
+
+        let (truthy, falsy) = self.get_condition_assertions(condition);
+        let mut branch = scope.branch();
+        self.apply(&truthy, &mut branch);
+        self.walk_statements(statements.iter().copied(), &mut branch);
+
+        let mut branches = vec![branch];
+        let mut otherwise = scope.branch();
+        self.apply(&falsy, &mut otherwise);
+
+        for (condition, statements) in else_if_clauses {
+            self.walk_expression(condition, &mut otherwise);
+
+            let (truthy, falsy) = self.get_condition_assertions(condition);
+            let mut branch = otherwise.branch();
+            self.apply(&truthy, &mut branch);
+            self.walk_statements(statements, &mut branch);
+
+            branches.push(branch);
+            self.apply(&falsy, &mut otherwise);
+        }
+
+        if let Some(statements) = else_statements {
+            self.walk_statements(statements, &mut otherwise);
+        }
+
+        branches.push(otherwise);
+        scope.merge(branches);
+    }
+
+    /// Walks the body of a loop, in which the variables written to by the loop are unknown.
+    fn walk_loop_body(
+        &mut self,
+        condition: Option<&Expression>,
+        body: impl FnOnce(&mut Self, &mut Scope),
+        scope: &mut Scope,
+    ) {
+        let mut branch = scope.branch();
+        if let Some(condition) = condition {
+            let (truthy, _) = self.get_condition_assertions(condition);
+
+            self.apply(&truthy, &mut branch);
+        }
+
+        body(self, &mut branch);
+
+        scope.references = branch.references;
+        if branch.dynamic {
+            scope.make_dynamic();
+        }
+    }
+
+    fn get_catch_kind(&self, hint: &Hint) -> Option<TypeKind> {
+        match hint {
+            Hint::Identifier(identifier) => Some(named_object_kind(*self.semantics.names.get(identifier), vec![])),
+            Hint::Union(union) => {
+                let mut members = get_members(&self.get_catch_kind(&union.left)?);
+                members.extend(get_members(&self.get_catch_kind(&union.right)?));
+
+                combine(members)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl MutWalker<Scope> for VariableTypeInferrer<'_, '_> {
+    fn walk_in_direct_variable(&mut self, direct_variable: &DirectVariable, scope: &mut Scope) {
+        if let Some(kind) = scope.get(&direct_variable.name) {
+            self.types.kinds.insert(direct_variable.span, kind.clone());
+        }
+    }
+
+    fn walk_function(&mut self, function: &Function, _scope: &mut Scope) {
+        let function_like = self.codebase.get_enclosing_function_like(&function.body.left_brace);
+        let mut scope = self.create_function_like_scope(function_like);
+
+        walk_function_mut(self, function, &mut scope);
+    }
+
+    fn walk_method(&mut self, method: &Method, _scope: &mut Scope) {
+        let function_like = match &method.body {
+            MethodBody::Concrete(body) => self.codebase.get_enclosing_function_like(&body.left_brace),
+            MethodBody::Abstract(_) => None,
+        };
+
+        let mut scope = self.create_function_like_scope(function_like);
+
+        walk_method_mut(self, method, &mut scope);
+    }
+
+    fn walk_property_hook(&mut self, property_hook: &PropertyHook, _scope: &mut Scope) {
+        walk_property_hook_mut(self, property_hook, &mut Scope::default());
+    }
+
+    fn walk_closure(&mut self, closure: &Closure, scope: &mut Scope) {
+        let function_like = self.codebase.get_function_like(FunctionLikeName::Closure(closure.span()));
+        let mut inner = self.create_function_like_scope(function_like);
+
+        if let Some(use_clause) = &closure.use_clause {
+            for variable in use_clause.variables.iter() {
+                let name = variable.variable.name;
+                if variable.ampersand.is_some() {
+                    scope.add_reference(name);
+                    inner.add_reference(name);
+                } else {
+                    inner.set(name, scope.get(&name).cloned());
+                }
+            }
+        }
+
+        walk_closure_mut(self, closure, &mut inner);
+    }
+
+    fn walk_arrow_function(&mut self, arrow_function: &ArrowFunction, scope: &mut Scope) {
+        let function_like = self.codebase.get_function_like(FunctionLikeName::ArrowFunction(arrow_function.span()));
+        let parameters = self.create_function_like_scope(function_like);
+
+        let mut inner = scope.branch();
+        for parameter in arrow_function.parameters.parameters.iter() {
+            let name = parameter.variable.name;
+
+            inner.set(name, parameters.get(&name).cloned());
+        }
+
+        walk_arrow_function_mut(self, arrow_function, &mut inner);
+    }
+
+    fn walk_assignment(&mut self, assignment: &Assignment, scope: &mut Scope) {
+        if let Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) =
+            assignment.rhs.as_ref()
+        {
+            self.walk_expression(&assignment.rhs, scope);
+            self.walk_expression(&assignment.lhs, scope);
+
+            let mut dynamic = false;
+            for variable in get_assigned_variables(&assignment.lhs, &mut dynamic)
+                .into_iter()
+                .chain(get_assigned_variables(operand, &mut dynamic))
+            {
+                scope.add_reference(variable.name);
+            }
+
+            if dynamic {
+                scope.make_dynamic();
+            }
+
+            return;
+        }
+
+        self.walk_expression(&assignment.rhs, scope);
+
+        match (&assignment.operator, assignment.lhs.as_ref()) {
+            (AssignmentOperator::Assign(_), Expression::Variable(Variable::Direct(variable))) => {
+                let kind = self.resolve(&assignment.rhs);
+
+                self.assign(variable, Some(kind), scope);
+            }
+            (_, lhs) => {
+                self.walk_expression(lhs, scope);
+                self.forget_assigned(lhs, scope);
+            }
+        }
+    }
+
+    fn walk_unary_prefix(&mut self, unary_prefix: &UnaryPrefix, scope: &mut Scope) {
+        self.walk_expression(&unary_prefix.operand, scope);
+
+        if matches!(unary_prefix.operator, UnaryPrefixOperator::PreIncrement(_) | UnaryPrefixOperator::PreDecrement(_))
+        {
+            self.forget_assigned(&unary_prefix.operand, scope);
+        }
+    }
+
+    fn walk_unary_postfix(&mut self, unary_postfix: &UnaryPostfix, scope: &mut Scope) {
+        self.walk_expression(&unary_postfix.operand, scope);
+        self.forget_assigned(&unary_postfix.operand, scope);
+    }
+
+    fn walk_binary(&mut self, binary: &Binary, scope: &mut Scope) {
+        match &binary.operator {
+            BinaryOperator::And(_) | BinaryOperator::LowAnd(_) => {
+                self.walk_expression(&binary.lhs, scope);
+                let (truthy, _) = self.get_condition_assertions(&binary.lhs);
+                self.walk_expression_branch(&binary.rhs, &truthy, scope);
+            }
+            BinaryOperator::Or(_) | BinaryOperator::LowOr(_) => {
+                self.walk_expression(&binary.lhs, scope);
+                let (_, falsy) = self.get_condition_assertions(&binary.lhs);
+                self.walk_expression_branch(&binary.rhs, &falsy, scope);
+            }
+            BinaryOperator::NullCoalesce(_) | BinaryOperator::Elvis(_) => {
+                self.walk_expression(&binary.lhs, scope);
+                self.walk_expression_branch(&binary.rhs, &vec![], scope);
+            }
+            _ => walk_binary_mut(self, binary, scope),
+        }
+    }
+
+    fn walk_conditional(&mut self, conditional: &Conditional, scope: &mut Scope) {
+        self.walk_expression(&conditional.condition, scope);
+
+        let (truthy, falsy) = self.get_condition_assertions(&conditional.condition);
+        if let Some(then) = &conditional.then {
+            self.walk_expression_branch(then, &truthy, scope);
+        }
+
+        self.walk_expression_branch(&conditional.r#else, &falsy, scope);
+    }
+
+    fn walk_match(&mut self, r#match: &Match, scope: &mut Scope) {
+        self.walk_expression(&r#match.expression, scope);
+
+        let mut branch = scope.branch();
+        for arm in r#match.arms.iter() {
+            let mut arm_scope = scope.branch();
+            self.walk_match_arm(arm, &mut arm_scope);
+
+            branch.references.extend(arm_scope.references);
+            branch.dynamic |= arm_scope.dynamic;
+        }
+
+        self.forget_written(r#match, scope);
+        scope.references = branch.references;
+        if branch.dynamic {
+            scope.make_dynamic();
+        }
+    }
+
+    fn walk_function_call(&mut self, function_call: &FunctionCall, scope: &mut Scope) {
+        walk_function_call_mut(self, function_call, scope);
+
+        let function_like = get_function_reflection(self.semantics, self.codebase, &function_call.function);
+        let call = Call::Function(function_call.clone());
+
+        self.walk_call_effects(&call, function_like.map(Cow::Borrowed), &function_call.arguments, scope);
+    }
+
+    fn walk_method_call(&mut self, method_call: &MethodCall, scope: &mut Scope) {
+        walk_method_call_mut(self, method_call, scope);
+
+        let method = self.get_method_reflection(&method_call.object, &method_call.method);
+        let call = Call::Method(method_call.clone());
+
+        self.walk_call_effects(&call, method, &method_call.arguments, scope);
+    }
+
+    fn walk_null_safe_method_call(&mut self, null_safe_method_call: &NullSafeMethodCall, scope: &mut Scope) {
+        walk_null_safe_method_call_mut(self, null_safe_method_call, scope);
+
+        let method = self.get_method_reflection(&null_safe_method_call.object, &null_safe_method_call.method);
+        let call = Call::NullSafeMethod(null_safe_method_call.clone());
+
+        self.walk_call_effects(&call, method, &null_safe_method_call.arguments, scope);
+    }
+
+    fn walk_static_method_call(&mut self, static_method_call: &StaticMethodCall, scope: &mut Scope) {
+        walk_static_method_call_mut(self, static_method_call, scope);
+
+        let call = Call::StaticMethod(static_method_call.clone());
+        let method = self.get_call_reflection(&call).map(|(method, _)| method);
+
+        self.walk_call_effects(&call, method, &static_method_call.arguments, scope);
+    }
+
+    fn walk_static_property_access(&mut self, static_property_access: &StaticPropertyAccess, scope: &mut Scope) {
+        // The property of a static property access is not a variable.
+        self.walk_expression(&static_property_access.class, scope);
+    }
+
+    fn walk_construct(&mut self, construct: &Construct, scope: &mut Scope) {
+        mago_walker::walk_construct_mut(self, construct, scope);
+
+        match construct {
+            Construct::Include(_)
+            | Construct::IncludeOnce(_)
+            | Construct::Require(_)
+            | Construct::RequireOnce(_)
+            | Construct::Eval(_) => scope.make_dynamic(),
+            Construct::Exit(_) | Construct::Die(_) => scope.terminated = true,
+            _ => {}
+        }
+    }
+
+    fn walk_throw(&mut self, throw: &Throw, scope: &mut Scope) {
+        self.walk_expression(&throw.exception, scope);
+
+        scope.terminated = true;
+    }
+
+    fn walk_return(&mut self, r#return: &Return, scope: &mut Scope) {
+        if let Some(value) = &r#return.value {
+            self.walk_expression(value, scope);
+        }
+
+        scope.terminated = true;
+    }
+
+    fn walk_in_break(&mut self, _break: &Break, scope: &mut Scope) {
+        scope.terminated = true;
+    }
+
+    fn walk_in_continue(&mut self, _continue: &Continue, scope: &mut Scope) {
+        scope.terminated = true;
+    }
+
+    fn walk_in_goto(&mut self, _goto: &Goto, scope: &mut Scope) {
+        scope.terminated = true;
+    }
+
+    fn walk_unset(&mut self, unset: &Unset, scope: &mut Scope) {
+        for value in unset.values.iter() {
+            self.walk_expression(value, scope);
+            self.forget_assigned(value, scope);
+        }
+    }
+
+    fn walk_global(&mut self, global: &Global, scope: &mut Scope) {
+        for variable in global.variables.iter() {
+            match variable {
+                Variable::Direct(variable) => scope.add_reference(variable.name),
+                _ => scope.make_dynamic(),
+            }
+        }
+    }
+
+    fn walk_static(&mut self, r#static: &Static, scope: &mut Scope) {
+        for item in r#static.items.iter() {
+            if let StaticItem::Concrete(item) = item {
+                self.walk_expression(&item.value, scope);
+            }
+
+            scope.add_reference(item.variable().name);
+        }
+    }
+
+    fn walk_if(&mut self, r#if: &If, scope: &mut Scope) {
+        match &r#if.body {
+            IfBody::Statement(body) => self.walk_if_branches(
+                &r#if.condition,
+                &[&body.statement],
+                body.else_if_clauses.iter().map(|clause| (&clause.condition, vec![&clause.statement])).collect(),
+                body.else_clause.as_ref().map(|clause| vec![&clause.statement]),
+                scope,
+            ),
+            IfBody::ColonDelimited(body) => self.walk_if_branches(
+                &r#if.condition,
+                &body.statements.iter().collect::<Vec<_>>(),
+                body.else_if_clauses
+                    .iter()
+                    .map(|clause| (&clause.condition, clause.statements.iter().collect()))
+                    .collect(),
+                body.else_clause.as_ref().map(|clause| clause.statements.iter().collect()),
+                scope,
+            ),
+        }
+    }
+
+    fn walk_while(&mut self, r#while: &While, scope: &mut Scope) {
+        self.forget_written(r#while, scope);
+        self.walk_expression(&r#while.condition, scope);
+        self.walk_loop_body(
+            Some(&r#while.condition),
+            |inferrer, body| inferrer.walk_while_body(&r#while.body, body),
+            scope,
+        );
+    }
+
+    fn walk_do_while(&mut self, do_while: &DoWhile, scope: &mut Scope) {
+        self.forget_written(do_while, scope);
+        self.walk_loop_body(
+            None,
+            |inferrer, body| {
+                inferrer.walk_statement(&do_while.statement, body);
+                body.terminated = false;
+                inferrer.walk_expression(&do_while.condition, body);
+            },
+            scope,
+        );
+    }
+
+    fn walk_for(&mut self, r#for: &For, scope: &mut Scope) {
+        for initialization in r#for.initializations.iter() {
+            self.walk_expression(initialization, scope);
+        }
+
+        self.forget_written(r#for, scope);
+        for condition in r#for.conditions.iter() {
+            self.walk_expression(condition, scope);
+        }
+
+        self.walk_loop_body(
+            r#for.conditions.last(),
+            |inferrer, body| {
+                inferrer.walk_for_body(&r#for.body, body);
+                body.terminated = false;
+                for increment in r#for.increments.iter() {
+                    inferrer.walk_expression(increment, body);
+                }
+            },
+            scope,
+        );
+    }
+
+    fn walk_foreach(&mut self, foreach: &Foreach, scope: &mut Scope) {
+        self.walk_expression(&foreach.expression, scope);
+
+        let targets = match &foreach.target {
+            ForeachTarget::Value(target) => vec![&target.value],
+            ForeachTarget::KeyValue(target) => vec![&target.key, &target.value],
+        };
+
+        for target in targets {
+            if let Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) =
+                target
+            {
+                let mut dynamic = false;
+                for variable in get_assigned_variables(operand, &mut dynamic)
+                    .into_iter()
+                    .chain(get_assigned_variables(&foreach.expression, &mut dynamic))
+                {
+                    scope.add_reference(variable.name);
+                }
+            }
+        }
+
+        self.forget_written(foreach, scope);
+        self.walk_loop_body(
+            None,
+            |inferrer, body| {
+                inferrer.walk_foreach_target(&foreach.target, body);
+                inferrer.walk_foreach_body(&foreach.body, body);
+            },
+            scope,
+        );
+    }
+
+    fn walk_switch(&mut self, switch: &Switch, scope: &mut Scope) {
+        self.walk_expression(&switch.expression, scope);
+        self.forget_written(switch, scope);
+
+        let cases = match &switch.body {
+            SwitchBody::BraceDelimited(body) => body.cases.iter().collect::<Vec<_>>(),
+            SwitchBody::ColonDelimited(body) => body.cases.iter().collect::<Vec<_>>(),
+        };
+
+        self.walk_loop_body(
+            None,
+            |inferrer, body| {
+                for case in cases {
+                    let mut case_scope = body.branch();
+                    inferrer.walk_switch_case(case, &mut case_scope);
+
+                    body.references.extend(case_scope.references);
+                    body.dynamic |= case_scope.dynamic;
+                }
+            },
+            scope,
+        );
+    }
+
+    fn walk_try(&mut self, r#try: &Try, scope: &mut Scope) {
+        let mut branch = scope.branch();
+        self.walk_block(&r#try.block, &mut branch);
+
+        // Any statement of the `try` block may have been executed when an exception is caught.
+        let mut before_catch = scope.branch();
+        self.forget_written(&r#try.block, &mut before_catch);
+
+        let mut branches = vec![branch];
+        for catch_clause in r#try.catch_clauses.iter() {
+            let mut catch_scope = before_catch.branch();
+            if let Some(variable) = &catch_clause.variable {
+                let kind = self.get_catch_kind(&catch_clause.hint);
+
+                self.assign(variable, kind, &mut catch_scope);
+            }
+
+            self.walk_block(&catch_clause.block, &mut catch_scope);
+            branches.push(catch_scope);
+        }
+
+        scope.merge(branches);
+
+        if let Some(finally_clause) = &r#try.finally_clause {
+            let terminated = scope.terminated;
+
+            // The `finally` block may also run after an exception that was not caught.
+            self.forget_written(&r#try.block, scope);
+            for catch_clause in r#try.catch_clauses.iter() {
+                self.forget_written(&catch_clause.block, scope);
+            }
+
+            scope.terminated = false;
+            self.walk_block(&finally_clause.block, scope);
+            scope.terminated |= terminated;
+        }
+    }
+}
+
+/// Collects the variables that may be written to within a node, without descending into nested
+/// function-likes and class-likes.
+struct WrittenVariableCollector<'i, 'c> {
+    interner: &'i ThreadedInterner,
+    semantics: &'c Semantics,
+    codebase: &'c CodebaseReflection,
+    dynamic: bool,
+}
+
+impl WrittenVariableCollector<'_, '_> {
+    fn collect(&mut self, expression: &Expression, written: &mut HashSet<StringIdentifier>) {
+        for variable in get_assigned_variables(expression, &mut self.dynamic) {
+            written.insert(variable.name);
+        }
+    }
+
+    fn collect_arguments(
+        &mut self,
+        function_like: Option<&FunctionLikeReflection>,
+        arguments: &ArgumentList,
+        assume_by_reference: bool,
+        written: &mut HashSet<StringIdentifier>,
+    ) {
+        for argument in get_referenced_arguments(function_like, arguments, assume_by_reference) {
+            self.collect(argument, written);
+        }
+    }
+}
+
+impl MutWalker<HashSet<StringIdentifier>> for WrittenVariableCollector<'_, '_> {
+    fn walk_in_assignment(&mut self, assignment: &Assignment, written: &mut HashSet<StringIdentifier>) {
+        self.collect(&assignment.lhs, written);
+
+        if let Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) =
+            assignment.rhs.as_ref()
+        {
+            self.collect(operand, written);
+        }
+    }
+
+    fn walk_in_unary_prefix(&mut self, unary_prefix: &UnaryPrefix, written: &mut HashSet<StringIdentifier>) {
+        if matches!(unary_prefix.operator, UnaryPrefixOperator::PreIncrement(_) | UnaryPrefixOperator::PreDecrement(_))
+        {
+            self.collect(&unary_prefix.operand, written);
+        }
+    }
+
+    fn walk_in_unary_postfix(&mut self, unary_postfix: &UnaryPostfix, written: &mut HashSet<StringIdentifier>) {
+        self.collect(&unary_postfix.operand, written);
+    }
+
+    fn walk_in_foreach_target(&mut self, foreach_target: &ForeachTarget, written: &mut HashSet<StringIdentifier>) {
+        match foreach_target {
+            ForeachTarget::Value(target) => self.collect(&target.value, written),
+            ForeachTarget::KeyValue(target) => {
+                self.collect(&target.key, written);
+                self.collect(&target.value, written);
+            }
+        }
+    }
+
+    fn walk_in_try_catch_clause(&mut self, try_catch_clause: &TryCatchClause, written: &mut HashSet<StringIdentifier>) {
+        if let Some(variable) = &try_catch_clause.variable {
+            written.insert(variable.name);
+        }
+    }
+
+    fn walk_in_unset(&mut self, unset: &Unset, written: &mut HashSet<StringIdentifier>) {
+        for value in unset.values.iter() {
+            self.collect(value, written);
+        }
+    }
+
+    fn walk_in_global(&mut self, global: &Global, written: &mut HashSet<StringIdentifier>) {
+        for variable in global.variables.iter() {
+            match variable {
+                Variable::Direct(variable) => {
+                    written.insert(variable.name);
+                }
+                _ => self.dynamic = true,
+            }
+        }
+    }
+
+    fn walk_in_static(&mut self, r#static: &Static, written: &mut HashSet<StringIdentifier>) {
+        for item in r#static.items.iter() {
+            written.insert(item.variable().name);
+        }
+    }
+
+    fn walk_in_construct(&mut self, construct: &Construct, _written: &mut HashSet<StringIdentifier>) {
+        if matches!(
+            construct,
+            Construct::Include(_)
+                | Construct::IncludeOnce(_)
+                | Construct::Require(_)
+                | Construct::RequireOnce(_)
+                | Construct::Eval(_)
+        ) {
+            self.dynamic = true;
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, written: &mut HashSet<StringIdentifier>) {
+        let function_like = get_function_reflection(self.semantics, self.codebase, &function_call.function);
+        let assume_by_reference = match function_call.function.as_ref() {
+            Expression::Identifier(identifier) => {
+                let (_, short_name) = resolve_name(self.interner, identifier.value());
+                let name = self.interner.lookup(&short_name).to_ascii_lowercase();
+                if name == "extract" {
+                    self.dynamic = true;
+                }
+
+                BY_REFERENCE_FUNCTIONS.contains(&name.as_str())
+            }
+            _ => true,
+        };
+
+        self.collect_arguments(function_like, &function_call.arguments, assume_by_reference, written);
+    }
+
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, written: &mut HashSet<StringIdentifier>) {
+        self.collect_arguments(None, &method_call.arguments, true, written);
+    }
+
+    fn walk_in_null_safe_method_call(
+        &mut self,
+        null_safe_method_call: &NullSafeMethodCall,
+        written: &mut HashSet<StringIdentifier>,
+    ) {
+        self.collect_arguments(None, &null_safe_method_call.arguments, true, written);
+    }
+
+    fn walk_in_static_method_call(
+        &mut self,
+        static_method_call: &StaticMethodCall,
+        written: &mut HashSet<StringIdentifier>,
+    ) {
+        let method = match (
+            get_static_class_like(self.semantics, self.codebase, &static_method_call.class),
+            &static_method_call.method,
+        ) {
+            (Some(class_like), ClassLikeMemberSelector::Identifier(method)) => {
+                self.codebase.get_method(class_like, &method.value)
+            }
+            _ => None,
+        };
+
+        self.collect_arguments(method.as_deref(), &static_method_call.arguments, true, written);
+    }
+
+    fn walk_in_instantiation(&mut self, instantiation: &Instantiation, written: &mut HashSet<StringIdentifier>) {
+        if let Some(arguments) = &instantiation.arguments {
+            self.collect_arguments(None, arguments, true, written);
+        }
+    }
+
+    fn walk_closure(&mut self, closure: &Closure, written: &mut HashSet<StringIdentifier>) {
+        for variable in closure.use_clause.iter().flat_map(|use_clause| use_clause.variables.iter()) {
+            if variable.ampersand.is_some() {
+                written.insert(variable.variable.name);
+            }
+        }
+    }
+
+    fn walk_arrow_function(&mut self, _arrow_function: &ArrowFunction, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_function(&mut self, _function: &Function, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_class(&mut self, _class: &Class, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_interface(&mut self, _interface: &Interface, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_trait(&mut self, _trait: &Trait, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_enum(&mut self, _enum: &Enum, _written: &mut HashSet<StringIdentifier>) {}
+
+    fn walk_anonymous_class(&mut self, anonymous_class: &AnonymousClass, written: &mut HashSet<StringIdentifier>) {
+        if let Some(arguments) = &anonymous_class.arguments {
+            self.collect_arguments(None, arguments, true, written);
+        }
+    }
+}
+
+/// A node whose written variables can be collected.
+trait Walkable {
+    fn walk_with(&self, collector: &mut WrittenVariableCollector<'_, '_>, written: &mut HashSet<StringIdentifier>);
+}
+
+macro_rules! walkable {
+    ($($node:ty => $method:ident),* $(,)?) => {
+        $(
+            impl Walkable for $node {
+                fn walk_with(&self, collector: &mut WrittenVariableCollector<'_, '_>, written: &mut HashSet<StringIdentifier>) {
+                    collector.$method(self, written);
+                }
+            }
+        )*
+    };
+}
+
+walkable! {
+    Expression => walk_expression,
+    Block => walk_block,
+    While => walk_while,
+    DoWhile => walk_do_while,
+    For => walk_for,
+    Foreach => walk_foreach,
+    Switch => walk_switch,
+    Match => walk_match,
+}
+
+/// Returns the variable the given expression consists of, if it is a variable.
+fn get_direct_variable(expression: &Expression) -> Option<&DirectVariable> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_direct_variable(&parenthesized.expression),
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        _ => None,
+    }
+}
+
+fn is_null_literal(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_null_literal(&parenthesized.expression),
+        Expression::Literal(Literal::Null(_)) => true,
+        _ => false,
+    }
+}
+
+/// Returns the variables written to when assigning to the given expression, including
+/// the variables holding arrays whose elements are assigned to.
+///
+/// Sets `dynamic` if a variable with a dynamic name may be written to.
+fn get_assigned_variables<'a>(expression: &'a Expression, dynamic: &mut bool) -> Vec<&'a DirectVariable> {
+    let mut variables = vec![];
+    let elements = match expression {
+        Expression::Parenthesized(parenthesized) => return get_assigned_variables(&parenthesized.expression, dynamic),
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) => {
+            return get_assigned_variables(operand, dynamic);
+        }
+        Expression::Variable(Variable::Direct(variable)) => return vec![variable],
+        Expression::Variable(_) => {
+            *dynamic = true;
+
+            return variables;
+        }
+        Expression::ArrayAccess(array_access) => return get_assigned_variables(&array_access.array, dynamic),
+        Expression::ArrayAppend(array_append) => return get_assigned_variables(&array_append.array, dynamic),
+        Expression::List(list) => &list.elements,
+        Expression::Array(array) => &array.elements,
+        Expression::LegacyArray(array) => &array.elements,
+        _ => return variables,
+    };
+
+    for element in elements.iter() {
+        match element {
+            ArrayElement::KeyValue(element) => variables.extend(get_assigned_variables(&element.value, dynamic)),
+            ArrayElement::Value(element) => variables.extend(get_assigned_variables(&element.value, dynamic)),
+            _ => {}
+        }
+    }
+
+    variables
+}
+
+/// Returns the arguments that may be passed by reference to the given function-like.
+///
+/// If the function-like is not known, all arguments are returned if `assume_by_reference` is set.
+fn get_referenced_arguments<'a>(
+    function_like: Option<&FunctionLikeReflection>,
+    arguments: &'a ArgumentList,
+    assume_by_reference: bool,
+) -> Vec<&'a Expression> {
+    let Some(function_like) = function_like else {
+        if !assume_by_reference {
+            return vec![];
+        }
+
+        return arguments.arguments.iter().map(|argument| argument.value()).collect();
+    };
+
+    arguments
+        .arguments
+        .iter()
+        .enumerate()
+        .filter(|(index, argument)| {
+            get_argument_parameter(function_like, *index, argument)
+                .map_or(assume_by_reference, |parameter| parameter.is_passed_by_reference)
+        })
+        .map(|(_, argument)| argument.value())
+        .collect()
+}
+
+fn get_argument_parameter<'a>(
+    function_like: &'a FunctionLikeReflection,
+    index: usize,
+    argument: &Argument,
+) -> Option<&'a mago_reflection::function_like::parameter::FunctionLikeParameterReflection> {
+    let parameters = &function_like.parameters;
+    match argument {
+        Argument::Positional(_) => {
+            parameters.get(index).or_else(|| parameters.last().filter(|parameter| parameter.is_variadic))
+        }
+        Argument::Named(argument) => {
+            let name = argument.name.value;
+
+            parameters
+                .iter()
+                .find(|parameter| parameter.name == name)
+                .or_else(|| parameters.last().filter(|parameter| parameter.is_variadic))
+        }
+    }
+}
+
+/// Returns the given assertions of a function-like, applied to the variables passed as the asserted arguments.
+fn get_argument_assertions(
+    function_like: &FunctionLikeReflection,
+    assertions: &[FunctionLikeAssertionReflection],
+    arguments: &ArgumentList,
+) -> Assertions {
+    let mut result = vec![];
+    for assertion in assertions {
+        // Assertions about templates can not be applied without inferring the template types.
+        if is_templated(&assertion.assertion) {
+            continue;
+        }
+
+        let Some(index) =
+            function_like.parameters.iter().position(|parameter| parameter.name == assertion.parameter_name)
+        else {
+            continue;
+        };
+
+        let mut positional_index = 0;
+        let argument = arguments.arguments.iter().find(|argument| match argument {
+            Argument::Positional(argument) => {
+                if argument.ellipsis.is_some() {
+                    return false;
+                }
+
+                positional_index += 1;
+
+                positional_index - 1 == index
+            }
+            Argument::Named(argument) => argument.ellipsis.is_none() && argument.name.value == assertion.parameter_name,
+        });
+
+        if let Some(variable) = argument.and_then(|argument| get_direct_variable(argument.value())) {
+            result.push((variable.name, assertion.assertion.clone()));
+        }
+    }
+
+    result
+}
+
+/// Returns the assertions that hold if either of the given assertions hold.
+///
+/// This is only known for variables asserted to be of a type on both sides, e.g.
+/// `$value instanceof Foo || $value instanceof Bar`.
+fn get_disjunction(lhs: &Assertions, rhs: &Assertions) -> Assertions {
+    let mut result = vec![];
+    for (name, assertion) in lhs {
+        let Assertion::IsType(lhs_kind) = assertion else {
+            continue;
+        };
+
+        if lhs.iter().filter(|(other, _)| other == name).count() != 1 {
+            continue;
+        }
+
+        let mut rhs_assertions = rhs.iter().filter(|(other, _)| other == name);
+        let (Some((_, Assertion::IsType(rhs_kind))), None) = (rhs_assertions.next(), rhs_assertions.next()) else {
+            continue;
+        };
+
+        let mut members = get_members(lhs_kind);
+        members.extend(get_members(rhs_kind));
+
+        if let Some(kind) = combine(members) {
+            result.push((*name, Assertion::IsType(kind)));
+        }
+    }
+
+    result
+}
+
+fn is_templated(assertion: &Assertion) -> bool {
+    let Some(kind) = assertion.get_type() else {
+        return false;
+    };
+
+    let templated = Cell::new(false);
+    kind.replace_generic_parameters(&|_, _| {
+        templated.set(true);
+
+        None
+    });
+
+    templated.get()
+}
+
+/// Retrieves the reflection of the function called by the given callee expression, if it is a named function,
+/// or by the global fallback of its name.
+fn get_function_reflection<'c>(
+    semantics: &Semantics,
+    codebase: &'c CodebaseReflection,
+    function: &Expression,
+) -> Option<&'c FunctionLikeReflection> {
+    let Expression::Identifier(identifier) = function else {
+        return None;
+    };
+
+    codebase
+        .get_function(semantics.names.get(identifier))
+        .or_else(|| codebase.get_function(&semantics.names.get_global_fallback(identifier)?))
+}
+
+fn get_static_class_like<'c>(
+    semantics: &Semantics,
+    codebase: &'c CodebaseReflection,
+    class: &Expression,
+) -> Option<&'c ClassLikeReflection> {
+    match class {
+        Expression::Identifier(identifier) => codebase.get_named_class_like(semantics.names.get(identifier)),
+        Expression::Self_(_) | Expression::Static(_) => codebase.get_enclosing_class_like(class),
+        _ => None,
+    }
+}
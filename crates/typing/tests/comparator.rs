@@ -0,0 +1,166 @@
+use mago_interner::ThreadedInterner;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+use mago_trinary::Trinary;
+use mago_typing::comparator::TypeComparator;
+
+const CLASSES: &str = r#"
+    interface Shape {}
+    interface Unrelated {}
+
+    class Base {}
+    class Child extends Base implements Shape {}
+    final class Other {}
+
+    /** @template T */
+    class Box {}
+
+    /**
+     * @template T
+     *
+     * @extends Box<T>
+     */
+    class SubBox extends Box {}
+
+    /** @extends Box<int> */
+    class IntBox extends Box {}
+
+    /** @template-covariant T */
+    class ReadOnlyBox {}
+"#;
+
+/// Determines whether the `input` type is contained by the `container` type, both written as docblock
+/// types of a function declared with the given additional tags, such as `@template`.
+fn is_contained_by_with_tags(tags: &str, input: &str, container: &str) -> Trinary {
+    let code = format!(
+        "<?php\n{}\n/**\n * {}\n * @param {} $input\n * @param {} $container\n */\nfunction compare($input, $container) {{}}\n",
+        CLASSES, tags, input, container
+    );
+
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code, SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let function = codebase.get_function(&interner.intern("compare")).expect("function should be reflected");
+    let [input, container] = [0, 1].map(|index| {
+        function.parameters[index].get_type_reflection().expect("parameter should have a type").kind.clone()
+    });
+
+    TypeComparator::new(&interner, &codebase).is_contained_by(&input, &container)
+}
+
+fn is_contained_by(input: &str, container: &str) -> Trinary {
+    is_contained_by_with_tags("", input, container)
+}
+
+#[test]
+fn test_scalars() {
+    assert_eq!(is_contained_by("int", "int"), Trinary::True);
+    assert_eq!(is_contained_by("1|2", "int"), Trinary::True);
+    assert_eq!(is_contained_by("'a'", "string"), Trinary::True);
+    assert_eq!(is_contained_by("int", "string"), Trinary::False);
+    assert_eq!(is_contained_by("int", "mixed"), Trinary::True);
+}
+
+#[test]
+fn test_unions() {
+    assert_eq!(is_contained_by("int", "int|string"), Trinary::True);
+    assert_eq!(is_contained_by("int|string", "int"), Trinary::Maybe);
+    assert_eq!(is_contained_by("int|string", "float|bool"), Trinary::False);
+    assert_eq!(is_contained_by("int|string", "string|int"), Trinary::True);
+}
+
+#[test]
+fn test_nullables() {
+    assert_eq!(is_contained_by("?int", "int"), Trinary::Maybe);
+    assert_eq!(is_contained_by("null", "?int"), Trinary::True);
+    assert_eq!(is_contained_by("null", "int"), Trinary::False);
+    assert_eq!(is_contained_by("int", "?int"), Trinary::True);
+    assert_eq!(is_contained_by("?int", "?int"), Trinary::True);
+    assert_eq!(is_contained_by("?int", "int|string|null"), Trinary::True);
+}
+
+#[test]
+fn test_shapes_and_arrays() {
+    assert_eq!(is_contained_by("array{a: int}", "array"), Trinary::True);
+    assert_eq!(is_contained_by("array{a: int}", "array<string, int>"), Trinary::True);
+    assert_eq!(is_contained_by("array{a: string}", "array<string, int>"), Trinary::False);
+    assert_eq!(is_contained_by("array", "array{a: int}"), Trinary::Maybe);
+    assert_eq!(is_contained_by("array{a: int}", "array{a: int, b?: string}"), Trinary::True);
+    assert_eq!(is_contained_by("array{a: int}", "array{a: int, b: string}"), Trinary::False);
+}
+
+#[test]
+fn test_lists() {
+    assert_eq!(is_contained_by("list<int>", "array"), Trinary::True);
+    assert_eq!(is_contained_by("list<int>", "array<int, int>"), Trinary::True);
+    assert_eq!(is_contained_by("list<int>", "list<int|string>"), Trinary::True);
+    assert_eq!(is_contained_by("list{int, string}", "list<int|string>"), Trinary::True);
+    assert_eq!(is_contained_by("non-empty-list<int>", "list<int>"), Trinary::True);
+    assert_eq!(is_contained_by("list<int>", "non-empty-list<int>"), Trinary::Maybe);
+    assert_eq!(is_contained_by("array<int, int>", "list<int>"), Trinary::Maybe);
+    assert_eq!(is_contained_by("array<string, int>", "list<int>"), Trinary::False);
+    assert_eq!(is_contained_by("list<string>", "list<int>"), Trinary::False);
+}
+
+#[test]
+fn test_callables_and_closures() {
+    assert_eq!(is_contained_by("Closure", "callable"), Trinary::True);
+    assert_eq!(is_contained_by("callable", "Closure"), Trinary::Maybe);
+    assert_eq!(is_contained_by("Closure(int): string", "callable(int): string"), Trinary::True);
+    assert_eq!(is_contained_by("Closure(int): string", "Closure(int): int"), Trinary::False);
+    assert_eq!(is_contained_by("callable-string", "callable"), Trinary::True);
+    assert_eq!(is_contained_by("string", "callable"), Trinary::Maybe);
+    assert_eq!(is_contained_by("array", "callable"), Trinary::Maybe);
+    assert_eq!(is_contained_by("int", "callable"), Trinary::False);
+}
+
+#[test]
+fn test_inheritance() {
+    assert_eq!(is_contained_by("Child", "Base"), Trinary::True);
+    assert_eq!(is_contained_by("Child", "Shape"), Trinary::True);
+    assert_eq!(is_contained_by("Child", "object"), Trinary::True);
+    assert_eq!(is_contained_by("Child&Shape", "Base"), Trinary::True);
+    assert_eq!(is_contained_by("Base", "Child"), Trinary::Maybe);
+    assert_eq!(is_contained_by("Shape", "Child"), Trinary::Maybe);
+    assert_eq!(is_contained_by("object", "Child"), Trinary::Maybe);
+    assert_eq!(is_contained_by("Child|Other", "Base"), Trinary::Maybe);
+
+    // A subclass of a non-final class may implement any interface.
+    assert_eq!(is_contained_by("Base", "Unrelated"), Trinary::Maybe);
+    assert_eq!(is_contained_by("Other", "Unrelated"), Trinary::False);
+    assert_eq!(is_contained_by("Other", "Base"), Trinary::False);
+}
+
+#[test]
+fn test_generic_objects() {
+    assert_eq!(is_contained_by("Box<int>", "Box<int>"), Trinary::True);
+    assert_eq!(is_contained_by("Box<string>", "Box<int>"), Trinary::False);
+    assert_eq!(is_contained_by("Box<int>", "Box"), Trinary::True);
+    assert_eq!(is_contained_by("SubBox<int>", "Box<int>"), Trinary::True);
+    assert_eq!(is_contained_by("SubBox<string>", "Box<int>"), Trinary::False);
+    assert_eq!(is_contained_by("IntBox", "Box<int>"), Trinary::True);
+    assert_eq!(is_contained_by("IntBox", "Box<string>"), Trinary::False);
+
+    // Templates are invariant unless declared otherwise.
+    assert_eq!(is_contained_by("Box<Child>", "Box<Base>"), Trinary::Maybe);
+    assert_eq!(is_contained_by("ReadOnlyBox<Child>", "ReadOnlyBox<Base>"), Trinary::True);
+    assert_eq!(is_contained_by("ReadOnlyBox<Base>", "ReadOnlyBox<Child>"), Trinary::Maybe);
+}
+
+#[test]
+fn test_template_parameters() {
+    let tags = "@template T of Base";
+
+    assert_eq!(is_contained_by_with_tags(tags, "T", "Base"), Trinary::True);
+    assert_eq!(is_contained_by_with_tags(tags, "T", "object"), Trinary::True);
+    assert_eq!(is_contained_by_with_tags(tags, "T", "Child"), Trinary::Maybe);
+    assert_eq!(is_contained_by_with_tags(tags, "T", "Other"), Trinary::False);
+    assert_eq!(is_contained_by_with_tags(tags, "list<T>", "list<Base>"), Trinary::True);
+}
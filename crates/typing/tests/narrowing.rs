@@ -0,0 +1,206 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+use mago_walker::Walker;
+
+/// Collects the arguments passed to `check()`, in order.
+struct CheckCollector;
+
+impl<'a> Walker<(&'a ThreadedInterner, Vec<Expression>)> for CheckCollector {
+    fn walk_in_function_call(
+        &self,
+        function_call: &FunctionCall,
+        (interner, arguments): &mut (&'a ThreadedInterner, Vec<Expression>),
+    ) {
+        let Expression::Identifier(Identifier::Local(identifier)) = function_call.function.as_ref() else {
+            return;
+        };
+
+        if interner.lookup(&identifier.value) != "check" {
+            return;
+        }
+
+        if let Some(Argument::Positional(argument)) = function_call.arguments.arguments.first() {
+            arguments.push(argument.value.clone());
+        }
+    }
+}
+
+/// Returns the types of the values passed to `check()` in the given code, in order.
+fn get_checked_types(code: &'static str) -> Vec<String> {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let variables = mago_typing::infer_variable_types(&interner, &semantics, &codebase);
+
+    let mut context = (&interner, vec![]);
+    CheckCollector.walk_program(&semantics.program, &mut context);
+
+    context
+        .1
+        .iter()
+        .map(|argument| {
+            mago_typing::resolve_kind_with_variables(&interner, &semantics, &codebase, &variables, argument)
+                .get_key(&interner)
+        })
+        .collect()
+}
+
+#[test]
+fn test_namespaced_function_assertions() {
+    let types = get_checked_types(
+        r#"<?php
+
+        namespace App;
+
+        /** @assert string $value */
+        function assertString(mixed $value): void {}
+
+        /** @assert-if-true int $value */
+        function isValid(mixed $value): bool {}
+
+        /** @assert-if-false null $value */
+        function isPresent(?string $value): bool {}
+
+        function f(mixed $a, mixed $b, ?string $c): void {
+            assertString($a);
+            check($a);
+
+            if (isValid($b)) {
+                check($b);
+            }
+
+            if (\App\isValid($b)) {
+                check($b);
+            }
+
+            if (!isPresent($c)) {
+                check($c);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["string", "int", "int", "null"]);
+}
+
+#[test]
+fn test_imported_function_assertions() {
+    let types = get_checked_types(
+        r#"<?php
+
+        namespace Lib {
+            /** @assert-if-true int $value */
+            function isValid(mixed $value): bool {}
+        }
+
+        namespace App {
+            use function Lib\isValid;
+
+            function f(mixed $value): void {
+                if (isValid($value)) {
+                    check($value);
+                }
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["int"]);
+}
+
+#[test]
+fn test_instanceof_narrowing() {
+    let types = get_checked_types(
+        r#"<?php
+
+        namespace App;
+
+        interface Shape {}
+        final class Circle implements Shape {}
+        final class Square implements Shape {}
+
+        function f(Circle|Square $shape): void {
+            if ($shape instanceof Circle) {
+                check($shape);
+            } else {
+                check($shape);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["App\\Circle", "App\\Square"]);
+}
+
+#[test]
+fn test_type_check_function_narrowing() {
+    let types = get_checked_types(
+        r#"<?php
+
+        namespace App;
+
+        function f(int|string|null $value): void {
+            if (is_string($value)) {
+                check($value);
+            } elseif (\is_int($value)) {
+                check($value);
+            } else {
+                check($value);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["string", "int", "null"]);
+}
+
+#[test]
+fn test_null_comparison_narrowing() {
+    let types = get_checked_types(
+        r#"<?php
+
+        function f(?string $value): void {
+            if ($value !== null) {
+                check($value);
+            } else {
+                check($value);
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["string", "null"]);
+}
+
+#[test]
+fn test_early_return_narrowing() {
+    let types = get_checked_types(
+        r#"<?php
+
+        function f(?string $value, int|string $other): void {
+            if (null === $value) {
+                return;
+            }
+
+            check($value);
+
+            if (!is_int($other)) {
+                throw new Exception();
+            }
+
+            check($other);
+        }
+    "#,
+    );
+
+    assert_eq!(types, vec!["string", "int"]);
+}
@@ -0,0 +1,53 @@
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+/// Infers the type of the last expression statement of the given code, without a codebase.
+fn infer_last_expression(code: &'static str) -> String {
+    let interner = ThreadedInterner::new();
+    let mut manager = SourceManager::new(interner.clone());
+    let source_id = manager.insert_content("code.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = manager.load(&source_id).expect("source should be loaded");
+    let semantics = Semantics::build(&interner, source);
+
+    let expression = semantics
+        .program
+        .statements
+        .iter()
+        .rev()
+        .find_map(|statement| match statement {
+            Statement::Expression(statement) => Some(&statement.expression),
+            _ => None,
+        })
+        .expect("code should contain an expression statement");
+
+    mago_typing::infere_kind(&interner, &semantics, expression).get_key(&interner)
+}
+
+#[test]
+fn test_class_constant_class_is_class_string() {
+    assert_eq!(infer_last_expression("<?php use App\\Entity\\User; User::class;"), "class-string<App\\Entity\\User>");
+    assert_eq!(infer_last_expression("<?php \\App\\Entity\\User::CLASS;"), "class-string<App\\Entity\\User>");
+}
+
+#[test]
+fn test_class_constant_without_codebase_is_kept_as_reference() {
+    assert_eq!(
+        infer_last_expression("<?php use Doctrine\\DBAL\\Types\\Types; Types::STRING;"),
+        "Doctrine\\DBAL\\Types\\Types::STRING"
+    );
+}
+
+#[test]
+fn test_dynamic_class_constant_is_unknown() {
+    assert_eq!(infer_last_expression("<?php $class::STRING;"), "unknown");
+}
+
+#[test]
+fn test_null_coalesce_removes_null_from_left_operand() {
+    assert_eq!(infer_last_expression("<?php (rand() ? 'a' : null) ?? 'b';"), "\"a\"|\"b\"");
+    assert_eq!(infer_last_expression("<?php (rand() ? 'a' : null) ?? 'a';"), "\"a\"");
+    assert_eq!(infer_last_expression("<?php null ?? 'b';"), "\"b\"");
+}
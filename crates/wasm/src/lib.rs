@@ -11,6 +11,7 @@ use mago_parser::parse_source;
 use mago_reporting::Issue;
 use mago_reporting::IssueCollection;
 use mago_semantics::Semantics;
+use mago_source::SourceCategory;
 use mago_source::SourceManager;
 use mago_symbol_table::table::SymbolTable;
 
@@ -123,7 +124,7 @@ pub fn mago_format(code: String, settings: Option<String>) -> Result<JsValue, Js
 
     let interner = ThreadedInterner::new();
     let mut manager = SourceManager::new(interner.clone());
-    let source_id = manager.insert_content("code.php".to_string(), code, true);
+    let source_id = manager.insert_content("code.php".to_string(), code, SourceCategory::UserDefined);
 
     let source = manager.load(&source_id).map_err(|e| JsValue::from_str(&e.to_string()))?;
     let (program, parse_error) = parse_source(&interner, &source);
@@ -206,7 +207,7 @@ pub fn mago_get_insight(code: String, format_settings: Option<String>) -> Result
     let settings = get_format_settings(format_settings);
     let interner = ThreadedInterner::new();
     let mut manager = SourceManager::new(interner.clone());
-    let source_id = manager.insert_content("code.php".to_string(), code, true);
+    let source_id = manager.insert_content("code.php".to_string(), code, SourceCategory::UserDefined);
     let source = manager.load(&source_id).map_err(|e| JsValue::from_str(&e.to_string()))?;
     let semantics = Semantics::build(&interner, source);
     let mut formatted = None;
@@ -9,6 +9,7 @@ use mago_reporting::reporter::Reporter;
 use mago_reporting::reporter::ReportingFormat;
 use mago_reporting::reporter::ReportingTarget;
 use mago_reporting::Issue;
+use mago_source::SourceCategory;
 use mago_source::SourceManager;
 
 use crate::enum_variants;
@@ -54,7 +55,7 @@ pub async fn execute(command: AstCommand) -> i32 {
     let interner = ThreadedInterner::new();
     let source_manager = SourceManager::new(interner.clone());
 
-    let source_id = source_manager.insert_path(command.file, file_path, true);
+    let source_id = source_manager.insert_path(command.file, file_path, SourceCategory::UserDefined);
 
     let service = AstService::new(interner.clone(), source_manager.clone());
 
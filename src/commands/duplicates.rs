@@ -0,0 +1,105 @@
+use clap::Parser;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::service::duplicates::DuplicatesService;
+use crate::service::duplicates::DuplicatesSettings;
+use crate::service::source::SourceService;
+use crate::utils::bail;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "duplicates",
+    about = "Find duplicated code fragments across the project",
+    long_about = r#"
+Find duplicated code fragments across the project.
+
+Sources are compared token by token, ignoring whitespace and comments. A fragment is reported when it
+is at least `--minimum-tokens` tokens long and spans at least `--minimum-lines` lines.
+
+With `--fuzzy`, the names of variables, functions and class-likes, as well as the values of literals,
+are ignored, so that fragments differing only in naming are reported as well.
+
+The command exits with a non-zero status code when duplicated fragments are found.
+    "#
+)]
+pub struct DuplicatesCommand {
+    #[arg(long, default_value_t = 70, help = "The minimum number of tokens of a duplicated fragment")]
+    pub minimum_tokens: usize,
+
+    #[arg(long, default_value_t = 5, help = "The minimum number of lines of a duplicated fragment")]
+    pub minimum_lines: usize,
+
+    #[arg(long, help = "Ignore the names of variables and identifiers, and the values of literals")]
+    pub fuzzy: bool,
+
+    #[arg(long, default_value_t, help = "The issue reporting target to use.", ignore_case = true, value_parser = enum_variants!(ReportingTarget))]
+    pub reporting_target: ReportingTarget,
+
+    #[arg(long, default_value_t, help = "The issue reporting format to use.", ignore_case = true, value_parser = enum_variants!(ReportingFormat))]
+    pub reporting_format: ReportingFormat,
+}
+
+pub async fn execute(command: DuplicatesCommand, configuration: Configuration) -> i32 {
+    let interner = ThreadedInterner::new();
+
+    let source_service = SourceService::new(interner.clone(), configuration.source);
+    let source_manager = source_service.load().await.unwrap_or_else(bail);
+
+    let service = DuplicatesService::new(interner.clone(), source_manager.clone());
+    let result = service
+        .run(DuplicatesSettings {
+            minimum_tokens: command.minimum_tokens,
+            minimum_lines: command.minimum_lines,
+            fuzzy: command.fuzzy,
+        })
+        .await
+        .unwrap_or_else(bail);
+
+    if result.duplicates.is_empty() {
+        mago_feedback::info!("No duplicated code found in {} files", result.files);
+
+        return 0;
+    }
+
+    let mut issues = IssueCollection::new();
+    for duplicate in &result.duplicates {
+        issues.push(
+            Issue::new(
+                Level::Warning,
+                format!("Duplicated code: {} lines ({} tokens) are duplicated.", duplicate.lines, duplicate.tokens),
+            )
+            .with_annotations([
+                Annotation::primary(duplicate.duplicate).with_message("This fragment is duplicated..."),
+                Annotation::secondary(duplicate.original).with_message("...from this fragment."),
+            ])
+            .with_help("Consider extracting the duplicated code into a function, a method, or a trait."),
+        );
+    }
+
+    Reporter::new(interner, source_manager, command.reporting_target)
+        .report(issues, command.reporting_format)
+        .unwrap_or_else(bail);
+
+    let percentage = if result.lines == 0 { 0.0 } else { result.duplicated_lines as f64 * 100.0 / result.lines as f64 };
+
+    mago_feedback::warn!(
+        "Found {} duplicated fragments, {} duplicated lines out of {} ({:.2}%) in {} files",
+        result.duplicates.len(),
+        result.duplicated_lines,
+        result.lines,
+        percentage,
+        result.files
+    );
+
+    1
+}
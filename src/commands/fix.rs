@@ -29,10 +29,11 @@ pub struct FixCommand {
 pub async fn execute(command: FixCommand, configuration: Configuration) -> i32 {
     let interner = ThreadedInterner::new();
 
+    let root = configuration.source.root.clone();
     let source_service = SourceService::new(interner.clone(), configuration.source);
     let source_manager = source_service.load().await.unwrap_or_else(bail);
 
-    let service = LintService::new(configuration.linter, interner.clone(), source_manager.clone());
+    let service = LintService::new(configuration.linter, interner.clone(), source_manager.clone(), root);
 
     let result = service.fix(command.r#unsafe, command.potentially_unsafe, command.dry_run).await.unwrap_or_else(bail);
 
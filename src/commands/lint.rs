@@ -38,10 +38,11 @@ pub struct LintCommand {
 pub async fn execute(command: LintCommand, configuration: Configuration) -> i32 {
     let interner = ThreadedInterner::new();
 
+    let root = configuration.source.root.clone();
     let source_service = SourceService::new(interner.clone(), configuration.source);
     let source_manager = source_service.load().await.unwrap_or_else(bail);
 
-    let lint_service = LintService::new(configuration.linter, interner.clone(), source_manager.clone());
+    let lint_service = LintService::new(configuration.linter, interner.clone(), source_manager.clone(), root);
     let issues = lint_service.run().await.unwrap_or_else(bail);
     let issues_contain_errors = issues.get_highest_level().is_some_and(|level| level >= Level::Error);
 
@@ -0,0 +1,294 @@
+use clap::Parser;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
+
+use mago_interner::ThreadedInterner;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::service::metrics::Metrics;
+use crate::service::metrics::MetricsService;
+use crate::service::source::SourceService;
+use crate::utils::bail;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "metrics",
+    about = "Compute code metrics for the files, class-likes, functions and methods of the project",
+    long_about = r#"
+Compute code metrics for the files, class-likes, functions and methods of the project.
+
+The following metrics are computed:
+
+  - Size: lines of code (LOC), logical lines of code (LLOC), comment lines (CLOC) and non-comment lines (NCLOC).
+  - Complexity: cyclomatic and cognitive complexity of functions and methods, summed for class-likes and files.
+  - Counts: methods, properties and constants of class-likes, and parameters of functions and methods.
+  - Coupling: afferent (Ca) and efferent (Ce) coupling, and instability of class-likes.
+  - Inheritance: the depth of the inheritance tree (DIT) of class-likes.
+  - Halstead measures: volume, difficulty, effort and estimated bugs of files, functions and methods.
+
+Only the sources of the project are measured, while external sources are used to resolve inheritance.
+    "#
+)]
+pub struct MetricsCommand {
+    #[arg(long, short, default_value_t, help = "The output format to use.", ignore_case = true, value_parser = enum_variants!(MetricsFormat))]
+    pub format: MetricsFormat,
+
+    #[arg(long, short, help = "Only output the metrics of the given scope, required for the CSV format.", ignore_case = true, value_parser = enum_variants!(MetricsScope))]
+    pub scope: Option<MetricsScope>,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, VariantNames)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum MetricsFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, VariantNames)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum MetricsScope {
+    Files,
+    ClassLikes,
+    FunctionLikes,
+}
+
+pub async fn execute(command: MetricsCommand, configuration: Configuration) -> i32 {
+    // A CSV document holds a single table, so the scope to output must be chosen.
+    if command.format == MetricsFormat::Csv && command.scope.is_none() {
+        mago_feedback::error!(
+            "the CSV format requires a scope, use `--scope` to choose one of: files, class-likes, function-likes."
+        );
+
+        return 1;
+    }
+
+    let interner = ThreadedInterner::new();
+
+    let root = configuration.source.root.clone();
+    let source_service = SourceService::new(interner.clone(), configuration.source);
+    let source_manager = source_service.load().await.unwrap_or_else(bail);
+
+    let service = MetricsService::new(interner, source_manager, root);
+    let metrics = service.run().await.unwrap_or_else(bail);
+
+    let scopes = match command.scope {
+        Some(scope) => vec![scope],
+        None => vec![MetricsScope::Files, MetricsScope::ClassLikes, MetricsScope::FunctionLikes],
+    };
+
+    match command.format {
+        MetricsFormat::Json => {
+            let mut output = serde_json::Map::new();
+            for scope in scopes {
+                let value = match scope {
+                    MetricsScope::Files => serde_json::to_value(&metrics.files),
+                    MetricsScope::ClassLikes => serde_json::to_value(&metrics.class_likes),
+                    MetricsScope::FunctionLikes => serde_json::to_value(&metrics.function_likes),
+                };
+
+                output.insert(scope.to_string(), value.unwrap_or_else(bail));
+            }
+
+            println!("{}", serde_json::to_string_pretty(&output).unwrap_or_else(bail));
+        }
+        MetricsFormat::Table | MetricsFormat::Csv => {
+            let tables = scopes.into_iter().map(|scope| get_table(&metrics, scope)).collect::<Vec<_>>();
+            let rendered = tables
+                .into_iter()
+                .map(|(headers, rows)| match command.format {
+                    MetricsFormat::Csv => render_csv(headers, rows),
+                    _ => render_table(headers, rows),
+                })
+                .collect::<Vec<_>>();
+
+            println!("{}", rendered.join("\n"));
+        }
+    }
+
+    0
+}
+
+fn get_table(metrics: &Metrics, scope: MetricsScope) -> (&'static [&'static str], Vec<Vec<String>>) {
+    match scope {
+        MetricsScope::Files => (
+            &[
+                "File",
+                "LOC",
+                "LLOC",
+                "CLOC",
+                "NCLOC",
+                "Class-likes",
+                "Function-likes",
+                "Cyclomatic",
+                "Cognitive",
+                "Volume",
+                "Difficulty",
+                "Effort",
+                "Bugs",
+            ],
+            metrics
+                .files
+                .iter()
+                .map(|file| {
+                    vec![
+                        file.file.clone(),
+                        file.lines.loc.to_string(),
+                        file.lines.lloc.to_string(),
+                        file.lines.cloc.to_string(),
+                        file.lines.ncloc.to_string(),
+                        file.class_likes.to_string(),
+                        file.function_likes.to_string(),
+                        file.cyclomatic_complexity.to_string(),
+                        file.cognitive_complexity.to_string(),
+                        format!("{:.2}", file.halstead.volume),
+                        format!("{:.2}", file.halstead.difficulty),
+                        format!("{:.2}", file.halstead.effort),
+                        format!("{:.2}", file.halstead.bugs),
+                    ]
+                })
+                .collect(),
+        ),
+        MetricsScope::ClassLikes => (
+            &[
+                "Class-like",
+                "Kind",
+                "File",
+                "Line",
+                "LOC",
+                "LLOC",
+                "Methods",
+                "Properties",
+                "Constants",
+                "Cyclomatic",
+                "Cognitive",
+                "Ca",
+                "Ce",
+                "Instability",
+                "DIT",
+            ],
+            metrics
+                .class_likes
+                .iter()
+                .map(|class_like| {
+                    vec![
+                        class_like.name.clone(),
+                        class_like.kind.to_string(),
+                        class_like.file.clone(),
+                        class_like.line.to_string(),
+                        class_like.lines.loc.to_string(),
+                        class_like.lines.lloc.to_string(),
+                        class_like.methods.to_string(),
+                        class_like.properties.to_string(),
+                        class_like.constants.to_string(),
+                        class_like.cyclomatic_complexity.to_string(),
+                        class_like.cognitive_complexity.to_string(),
+                        class_like.afferent_coupling.to_string(),
+                        class_like.efferent_coupling.to_string(),
+                        format!("{:.2}", class_like.instability),
+                        class_like.inheritance_depth.to_string(),
+                    ]
+                })
+                .collect(),
+        ),
+        MetricsScope::FunctionLikes => (
+            &[
+                "Function-like",
+                "File",
+                "Line",
+                "LOC",
+                "LLOC",
+                "Parameters",
+                "Cyclomatic",
+                "Cognitive",
+                "Volume",
+                "Difficulty",
+                "Effort",
+                "Bugs",
+            ],
+            metrics
+                .function_likes
+                .iter()
+                .map(|function_like| {
+                    vec![
+                        function_like.name.clone(),
+                        function_like.file.clone(),
+                        function_like.line.to_string(),
+                        function_like.lines.loc.to_string(),
+                        function_like.lines.lloc.to_string(),
+                        function_like.parameters.to_string(),
+                        function_like.cyclomatic_complexity.to_string(),
+                        function_like.cognitive_complexity.to_string(),
+                        format!("{:.2}", function_like.halstead.volume),
+                        format!("{:.2}", function_like.halstead.difficulty),
+                        format!("{:.2}", function_like.halstead.effort),
+                        format!("{:.2}", function_like.halstead.bugs),
+                    ]
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Renders the rows as a table, with numeric columns aligned to the right.
+fn render_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths = headers.iter().map(|header| header.len()).collect::<Vec<_>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let numeric = (0..headers.len())
+        .map(|column| !rows.is_empty() && rows.iter().all(|row| row[column].parse::<f64>().is_ok()))
+        .collect::<Vec<_>>();
+
+    let render_row = |cells: Vec<&str>| {
+        cells
+            .into_iter()
+            .enumerate()
+            .map(|(column, cell)| {
+                if numeric[column] {
+                    format!("{:>width$}", cell, width = widths[column])
+                } else {
+                    format!("{:<width$}", cell, width = widths[column])
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![render_row(headers.to_vec())];
+    lines.push(widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().join("  "));
+    for row in &rows {
+        lines.push(render_row(row.iter().map(|cell| cell.as_str()).collect()));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn render_csv(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let escape = |cell: &str| {
+        if cell.contains([',', '"', '\n']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    };
+
+    let mut lines = vec![headers.iter().map(|header| escape(header)).collect::<Vec<_>>().join(",")];
+    for row in rows {
+        lines.push(row.iter().map(|cell| escape(cell)).collect::<Vec<_>>().join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
@@ -4,15 +4,21 @@ use clap::builder::Styles;
 use clap::Parser;
 
 use crate::commands::ast::AstCommand;
+use crate::commands::duplicates::DuplicatesCommand;
 use crate::commands::fix::FixCommand;
 use crate::commands::format::FormatCommand;
 use crate::commands::lint::LintCommand;
+use crate::commands::metrics::MetricsCommand;
+use crate::commands::refactor::RefactorCommand;
 use crate::commands::self_update::SelfUpdateCommand;
 
 pub mod ast;
+pub mod duplicates;
 pub mod fix;
 pub mod format;
 pub mod lint;
+pub mod metrics;
+pub mod refactor;
 pub mod self_update;
 
 pub const CLAP_STYLING: Styles = Styles::styled()
@@ -48,6 +54,12 @@ pub enum MagoCommand {
     Lint(LintCommand),
     #[command(name = "fix")]
     Fix(FixCommand),
+    #[command(name = "duplicates")]
+    Duplicates(DuplicatesCommand),
+    #[command(name = "metrics")]
+    Metrics(MetricsCommand),
+    #[command(name = "refactor")]
+    Refactor(RefactorCommand),
     #[command(name = "format")]
     Format(FormatCommand),
     #[command(name = "ast")]
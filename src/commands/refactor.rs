@@ -0,0 +1,118 @@
+use clap::Parser;
+use clap::Subcommand;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::service::refactor::RefactorService;
+use crate::service::source::SourceService;
+use crate::utils::bail;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "refactor",
+    about = "Refactor the project, updating every reference to the changed symbols",
+    long_about = r#"
+Refactor the project, updating every reference to the changed symbols.
+
+Refactorings are applied across all the sources of the project, and are aborted without changing anything
+when a reference cannot be safely updated, such as a member accessed on an object of unknown type.
+    "#
+)]
+pub struct RefactorCommand {
+    #[command(subcommand)]
+    pub refactoring: Refactoring,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Refactoring {
+    #[command(name = "rename")]
+    Rename(RenameCommand),
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "rename",
+    about = "Rename a class-like, function, constant, method, property or class-like constant",
+    long_about = r#"
+Rename a class-like, function, constant, method, property or class-like constant everywhere it is referenced.
+
+Symbols are named as in issue messages: `Foo\Bar` for class-likes and constants, `Foo\bar()` for functions,
+`Foo\Bar::baz()` for methods, `Foo\Bar::$baz` for properties, and `Foo\Bar::BAZ` for class-like constants.
+
+The new name of a class-like, function or constant is its fully qualified name, while the new name of a member
+may omit its class-like, e.g. `mago refactor rename 'Foo\Bar::baz()' qux`.
+
+Imports, docblocks, attributes and `::class` references are updated, and the file declaring a renamed class-like
+is moved according to the PSR-4 mappings of `composer.json`.
+    "#
+)]
+pub struct RenameCommand {
+    #[arg(help = "The name of the symbol to rename")]
+    pub old: String,
+
+    #[arg(help = "The new name of the symbol")]
+    pub new: String,
+
+    #[arg(long, short, help = "Run the command without writing any changes to disk")]
+    pub dry_run: bool,
+
+    #[arg(long, default_value_t, help = "The issue reporting target to use.", ignore_case = true, value_parser = enum_variants!(ReportingTarget))]
+    pub reporting_target: ReportingTarget,
+
+    #[arg(long, default_value_t, help = "The issue reporting format to use.", ignore_case = true, value_parser = enum_variants!(ReportingFormat))]
+    pub reporting_format: ReportingFormat,
+}
+
+pub async fn execute(command: RefactorCommand, configuration: Configuration) -> i32 {
+    match command.refactoring {
+        Refactoring::Rename(command) => rename(command, configuration).await,
+    }
+}
+
+async fn rename(command: RenameCommand, configuration: Configuration) -> i32 {
+    let interner = ThreadedInterner::new();
+
+    let root = configuration.source.root.clone();
+    let source_service = SourceService::new(interner.clone(), configuration.source);
+    let source_manager = source_service.load().await.unwrap_or_else(bail);
+
+    let service = RefactorService::new(interner.clone(), source_manager.clone(), root);
+    let result = service.rename(&command.old, &command.new, command.dry_run).await.unwrap_or_else(bail);
+
+    if !result.issues.is_empty() {
+        let count = result.issues.len();
+
+        Reporter::new(interner, source_manager, command.reporting_target)
+            .report(result.issues, command.reporting_format)
+            .unwrap_or_else(bail);
+
+        mago_feedback::error!(
+            "Could not rename `{}`, as {} of its references cannot be safely updated. No changes were made.",
+            command.old,
+            count
+        );
+
+        return 1;
+    }
+
+    if let Some((from, to)) = &result.moved {
+        if command.dry_run {
+            mago_feedback::info!("Would move '{}' to '{}'", from.display(), to.display());
+        } else {
+            mago_feedback::info!("Moved '{}' to '{}'", from.display(), to.display());
+        }
+    }
+
+    if command.dry_run {
+        mago_feedback::info!("Found changes to apply in {} files", result.changed);
+    } else {
+        mago_feedback::info!("Renamed `{}` to `{}` in {} files", command.old, command.new, result.changed);
+    }
+
+    0
+}
@@ -6,6 +6,7 @@ pub enum ConfigurationError {
     CanonicalizingRootPath(std::path::PathBuf, std::io::Error),
     CanonicalizingSourcePath(std::path::PathBuf, std::io::Error),
     CanonicalizingIncludePath(std::path::PathBuf, std::io::Error),
+    CanonicalizingStubPath(std::path::PathBuf, std::io::Error),
 }
 
 impl std::fmt::Display for ConfigurationError {
@@ -29,6 +30,9 @@ impl std::fmt::Display for ConfigurationError {
             ConfigurationError::CanonicalizingIncludePath(path, error) => {
                 write!(f, "failed to canonicalize include path '{}': {}", path.display(), error)
             }
+            ConfigurationError::CanonicalizingStubPath(path, error) => {
+                write!(f, "failed to canonicalize stub path '{}': {}", path.display(), error)
+            }
         }
     }
 }
@@ -42,6 +46,7 @@ impl std::error::Error for ConfigurationError {
             ConfigurationError::CanonicalizingRootPath(_, error) => Some(error),
             ConfigurationError::CanonicalizingSourcePath(_, error) => Some(error),
             ConfigurationError::CanonicalizingIncludePath(_, error) => Some(error),
+            ConfigurationError::CanonicalizingStubPath(_, error) => Some(error),
         }
     }
 }
@@ -31,6 +31,11 @@ pub struct SourceConfiguration {
     /// Defaults to `[]`.
     pub includes: Vec<PathBuf>,
 
+    /// Paths to stub files declaring the symbols built into PHP and its extensions, such as the PhpStorm stubs.
+    ///
+    /// Defaults to `[]`.
+    pub stubs: Vec<PathBuf>,
+
     /// Patterns to exclude from the scan.
     ///
     /// Defaults to `[]`.
@@ -53,7 +58,7 @@ impl SourceConfiguration {
     ///
     /// A new `SourceConfiguration` with the given root directory.
     pub fn from_root(root: PathBuf) -> Self {
-        Self { root, paths: vec![], includes: vec![], excludes: vec![], extensions: vec![] }
+        Self { root, paths: vec![], includes: vec![], stubs: vec![], excludes: vec![], extensions: vec![] }
     }
 }
 
@@ -63,6 +68,7 @@ impl ConfigurationEntry for SourceConfiguration {
             .set_default("source.root", Value::new(None, ValueKind::String(self.root.to_string_lossy().to_string())))?
             .set_default("source.paths", Value::new(None, ValueKind::Array(vec![])))?
             .set_default("source.includes", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("source.stubs", Value::new(None, ValueKind::Array(vec![])))?
             .set_default("source.excludes", Value::new(None, ValueKind::Array(vec![])))?
             .set_default(
                 "source.extensions",
@@ -99,6 +105,17 @@ impl ConfigurationEntry for SourceConfiguration {
             })
             .collect::<Result<Vec<PathBuf>, ConfigurationError>>()?;
 
+        // Normalize stub paths
+        self.stubs = self
+            .stubs
+            .iter()
+            .map(|p| {
+                let path = if p.is_absolute() { p.clone() } else { self.root.join(p) };
+
+                path.canonicalize().map_err(|e| ConfigurationError::CanonicalizingStubPath(p.clone(), e))
+            })
+            .collect::<Result<Vec<PathBuf>, ConfigurationError>>()?;
+
         Ok(())
     }
 }
@@ -38,6 +38,9 @@ pub fn main() -> ! {
     let code = match MagoCommand::parse() {
         MagoCommand::Lint(cmd) => runtime.block_on(commands::lint::execute(cmd, configuration)),
         MagoCommand::Fix(cmd) => runtime.block_on(commands::fix::execute(cmd, configuration)),
+        MagoCommand::Duplicates(cmd) => runtime.block_on(commands::duplicates::execute(cmd, configuration)),
+        MagoCommand::Metrics(cmd) => runtime.block_on(commands::metrics::execute(cmd, configuration)),
+        MagoCommand::Refactor(cmd) => runtime.block_on(commands::refactor::execute(cmd, configuration)),
         MagoCommand::Format(cmd) => runtime.block_on(commands::format::execute(cmd, configuration)),
         MagoCommand::Ast(cmd) => runtime.block_on(commands::ast::execute(cmd)),
         MagoCommand::SelfUpdate(cmd) => commands::self_update::execute(cmd),
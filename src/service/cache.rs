@@ -0,0 +1,145 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use serde::Serialize;
+use xxhash_rust::xxh3::Xxh3;
+
+use mago_interner::StringTable;
+use mago_interner::ThreadedInterner;
+use mago_reflection::CodebaseReflection;
+use mago_source::SourceCategory;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+
+/// The directory, relative to the workspace root, in which caches are stored.
+const CACHE_DIRECTORY: &str = ".mago/cache";
+/// The name of the file in which the reflection of external sources is cached.
+const CODEBASE_CACHE_FILE: &str = "codebase.bin";
+
+/// A cache for the reflection of external sources ( e.g. `vendor/` ), which rarely change.
+///
+/// The cache is keyed by the content of `composer.lock`, the paths, categories and modification times
+/// of the external sources, and the version of mago; if any of them changes, the cache is discarded.
+#[derive(Debug)]
+pub struct CodebaseCache {
+    path: PathBuf,
+    key: u64,
+}
+
+/// The content of a cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct CodebaseCacheFile {
+    key: u64,
+    strings: StringTable,
+    codebase: Vec<u8>,
+}
+
+impl CodebaseCache {
+    /// Creates a cache for the reflection of the given external sources.
+    ///
+    /// Returns `None` if the workspace does not contain a `composer.lock` file, in which
+    /// case there is nothing to key the cache by.
+    pub fn new(manager: &SourceManager, root: &Path, external_source_ids: &[SourceIdentifier]) -> Option<Self> {
+        let lock = std::fs::read(root.join("composer.lock")).ok()?;
+
+        // External sources are not only vendor dependencies, but also the `includes` and `stubs` of
+        // the configuration, which are not covered by `composer.lock`.
+        let mut sources = external_source_ids
+            .iter()
+            .filter_map(|id| Some((manager.get_path(id)?, id.category())))
+            .map(|(path, category)| {
+                let modified = std::fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+
+                (path, category, modified)
+            })
+            .collect::<Vec<_>>();
+        sources.sort_unstable();
+
+        Some(Self { path: root.join(CACHE_DIRECTORY).join(CODEBASE_CACHE_FILE), key: compute_key(&lock, &sources) })
+    }
+
+    /// Loads the cached reflection, remapping its strings to the given interner.
+    ///
+    /// Returns `None` if there is no cache, if it is outdated, or if it can not be read.
+    pub fn load(&self, interner: &ThreadedInterner) -> Option<CodebaseReflection> {
+        let bytes = std::fs::read(&self.path).ok()?;
+        let file = match bincode::deserialize::<CodebaseCacheFile>(&bytes) {
+            Ok(file) => file,
+            Err(error) => {
+                tracing::warn!("failed to read the codebase cache at `{}`: {}", self.path.display(), error);
+
+                return None;
+            }
+        };
+
+        if file.key != self.key {
+            tracing::debug!("the codebase cache at `{}` is outdated", self.path.display());
+
+            return None;
+        }
+
+        match interner.import(&file.strings, || bincode::deserialize::<CodebaseReflection>(&file.codebase)) {
+            Ok(codebase) => {
+                tracing::debug!("loaded the codebase cache from `{}`", self.path.display());
+
+                Some(codebase)
+            }
+            Err(error) => {
+                tracing::warn!("failed to read the codebase cache at `{}`: {}", self.path.display(), error);
+
+                None
+            }
+        }
+    }
+
+    /// Stores the given reflection in the cache.
+    ///
+    /// Failing to write the cache is not fatal, and is only reported as a warning.
+    pub fn save(&self, interner: &ThreadedInterner, codebase: &CodebaseReflection) {
+        let (codebase, strings) = interner.export(|| bincode::serialize(codebase));
+
+        let result = codebase
+            .and_then(|codebase| bincode::serialize(&CodebaseCacheFile { key: self.key, strings, codebase }))
+            .map_err(|error| error.to_string())
+            .and_then(|bytes| {
+                if let Some(directory) = self.path.parent() {
+                    std::fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+                }
+
+                std::fs::write(&self.path, bytes).map_err(|error| error.to_string())
+            });
+
+        match result {
+            Ok(()) => tracing::debug!("stored the codebase cache at `{}`", self.path.display()),
+            Err(error) => tracing::warn!("failed to write the codebase cache at `{}`: {}", self.path.display(), error),
+        }
+    }
+}
+
+/// Computes the key of the cache.
+///
+/// The key is persisted, so it must not depend on the process or on the version of Rust mago was built with,
+/// as `DefaultHasher` and the `Hash` implementations of the standard library do; the bytes of each input are
+/// hashed with xxh3 instead, prefixed by their length to keep the boundaries between them unambiguous.
+fn compute_key(lock: &[u8], sources: &[(PathBuf, SourceCategory, Option<SystemTime>)]) -> u64 {
+    let mut hasher = Xxh3::new();
+    let mut write = |bytes: &[u8]| {
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(bytes);
+    };
+
+    write(env!("CARGO_PKG_VERSION").as_bytes());
+    write(lock);
+
+    for (path, category, modified) in sources {
+        let modified = modified.and_then(|modified| modified.duration_since(SystemTime::UNIX_EPOCH).ok());
+
+        write(path.as_os_str().as_encoded_bytes());
+        write(&[*category as u8]);
+        write(&modified.map(|modified| modified.as_nanos().to_le_bytes()).unwrap_or_default());
+    }
+
+    hasher.digest()
+}
@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mago_feedback::create_progress_bar;
+use mago_feedback::remove_progress_bar;
+use mago_feedback::ProgressBarTheme;
+use mago_interner::ThreadedInterner;
+use mago_reference::ReferenceIndex;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_source::error::SourceError;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+
+use crate::service::cache::CodebaseCache;
+
+/// Builds the semantics, the reflection and the reference index of a codebase, for the services
+/// that need to know about every symbol of the project, such as the linter and the refactoring service.
+#[derive(Debug)]
+pub struct CodebaseService {
+    interner: ThreadedInterner,
+    source_manager: SourceManager,
+    root: PathBuf,
+}
+
+impl CodebaseService {
+    pub fn new(interner: ThreadedInterner, source_manager: SourceManager, root: PathBuf) -> Self {
+        Self { interner, source_manager, root }
+    }
+
+    /// Builds the semantics of the given sources, and the reflection of the entire codebase.
+    ///
+    /// The codebase reflection includes the symbols defined in external sources (e.g. vendor dependencies),
+    /// so that symbols defined outside of the given sources can be looked up.
+    ///
+    /// The reflection of external sources is cached, keyed by `composer.lock` and the modification
+    /// times of the external sources; when the cache is up to date, external sources are not loaded at all.
+    #[inline]
+    pub async fn build(
+        &self,
+        source_ids: Vec<SourceIdentifier>,
+    ) -> Result<(Vec<Semantics>, CodebaseReflection), SourceError> {
+        let external_source_ids = self.source_manager.external_source_ids().collect::<Vec<_>>();
+        let cache = CodebaseCache::new(&self.source_manager, &self.root, &external_source_ids);
+        let cached_external_codebase = cache.as_ref().and_then(|cache| cache.load(&self.interner));
+        let external_source_ids = if cached_external_codebase.is_some() { vec![] } else { external_source_ids };
+
+        let length = source_ids.len() + external_source_ids.len();
+
+        let source_pb = create_progress_bar(length, "📂  Loading", ProgressBarTheme::Red);
+        let semantics_pb = create_progress_bar(length, "🔬  Building", ProgressBarTheme::Blue);
+        let reflection_pb = create_progress_bar(length, "🪞  Reflecting", ProgressBarTheme::Yellow);
+
+        let mut handles = Vec::with_capacity(length);
+        let sources =
+            source_ids.into_iter().map(|id| (id, true)).chain(external_source_ids.into_iter().map(|id| (id, false)));
+        for (source_id, is_user_defined) in sources {
+            handles.push(tokio::spawn({
+                let interner = self.interner.clone();
+                let manager = self.source_manager.clone();
+                let source_pb = source_pb.clone();
+                let semantics_pb = semantics_pb.clone();
+                let reflection_pb = reflection_pb.clone();
+
+                async move {
+                    // Step 1: load the source
+                    let source = manager.load(&source_id)?;
+                    source_pb.inc(1);
+
+                    // Step 2: build semantics
+                    let semantics = Semantics::build(&interner, source);
+                    semantics_pb.inc(1);
+
+                    // Step 3: reflect the symbols defined in the source
+                    let reflection = mago_reflector::reflect(&interner, &semantics);
+                    reflection_pb.inc(1);
+
+                    Result::<_, SourceError>::Ok((is_user_defined, semantics, reflection))
+                }
+            }));
+        }
+
+        let mut semantics = Vec::with_capacity(handles.len());
+        let mut codebase = CodebaseReflection::new();
+        let mut external_codebase = CodebaseReflection::new();
+        for handle in handles {
+            let (is_user_defined, source_semantics, reflection) =
+                handle.await.expect("failed to build semantics. this should never happen.")?;
+
+            if is_user_defined {
+                semantics.push(source_semantics);
+                codebase = mago_reflector::merge(codebase, reflection);
+            } else {
+                external_codebase = mago_reflector::merge(external_codebase, reflection);
+            }
+        }
+
+        let external_codebase = match cached_external_codebase {
+            Some(cached_external_codebase) => cached_external_codebase,
+            None => {
+                if let Some(cache) = &cache {
+                    cache.save(&self.interner, &external_codebase);
+                }
+
+                external_codebase
+            }
+        };
+
+        let mut codebase = mago_reflector::merge(codebase, external_codebase);
+        mago_reflector::populate(&self.interner, &mut codebase);
+
+        remove_progress_bar(source_pb);
+        remove_progress_bar(semantics_pb);
+        remove_progress_bar(reflection_pb);
+
+        Ok((semantics, codebase))
+    }
+
+    /// Builds the index of the references to the symbols of the codebase, from the given semantics.
+    ///
+    /// Only the given sources are indexed, as references from external sources do not make
+    /// a symbol of the project used.
+    #[inline]
+    pub async fn build_references(
+        &self,
+        semantics: &[Arc<Semantics>],
+        codebase: &Arc<CodebaseReflection>,
+    ) -> ReferenceIndex {
+        let index_pb = create_progress_bar(semantics.len(), "🔗  Indexing", ProgressBarTheme::Magenta);
+        let mut handles = Vec::with_capacity(semantics.len());
+        for semantics in semantics.iter() {
+            handles.push(tokio::spawn({
+                let interner = self.interner.clone();
+                let semantics = semantics.clone();
+                let codebase = codebase.clone();
+                let index_pb = index_pb.clone();
+
+                async move {
+                    let references = ReferenceIndex::build(&interner, &semantics, &codebase);
+                    index_pb.inc(1);
+
+                    references
+                }
+            }));
+        }
+
+        let mut references = ReferenceIndex::new();
+        for handle in handles {
+            references.extend(handle.await.expect("failed to index references. this should never happen."));
+        }
+
+        remove_progress_bar(index_pb);
+
+        references
+    }
+}
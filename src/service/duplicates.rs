@@ -0,0 +1,378 @@
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use ahash::HashMap;
+
+use mago_feedback::create_progress_bar;
+use mago_feedback::remove_progress_bar;
+use mago_feedback::ProgressBarTheme;
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_lexer::Lexer;
+use mago_source::error::SourceError;
+use mago_source::Source;
+use mago_source::SourceManager;
+use mago_span::Span;
+use mago_token::TokenKind;
+
+/// The base of the rolling hash of token windows.
+const BASE: u64 = 1_000_003;
+
+#[derive(Debug)]
+pub struct DuplicatesService {
+    interner: ThreadedInterner,
+    source_manager: SourceManager,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DuplicatesSettings {
+    /// The minimum number of tokens a fragment must have to be reported.
+    pub minimum_tokens: usize,
+    /// The minimum number of lines a fragment must span to be reported.
+    pub minimum_lines: usize,
+    /// Whether the names of variables and identifiers, and the values of literals, are ignored.
+    pub fuzzy: bool,
+}
+
+/// A code fragment duplicating an earlier one.
+#[derive(Debug, Clone, Copy)]
+pub struct Duplicate {
+    pub original: Span,
+    pub duplicate: Span,
+    pub tokens: usize,
+    pub lines: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct DuplicatesResult {
+    pub duplicates: Vec<Duplicate>,
+    pub files: usize,
+    pub lines: usize,
+    /// The number of lines of code that duplicate earlier code.
+    pub duplicated_lines: usize,
+}
+
+/// A token, normalized so that tokens differing only in ignored details are equal.
+#[derive(Debug, Clone, Copy)]
+struct NormalizedToken {
+    hash: u64,
+    span: Span,
+}
+
+struct TokenizedSource {
+    source: Source,
+    tokens: Vec<NormalizedToken>,
+}
+
+impl DuplicatesService {
+    pub fn new(interner: ThreadedInterner, source_manager: SourceManager) -> Self {
+        Self { interner, source_manager }
+    }
+
+    /// Finds the code fragments of the user-defined sources that duplicate other fragments.
+    ///
+    /// Fragments are compared token by token, ignoring whitespace and comments, using a rolling
+    /// hash of windows of the minimum number of tokens; matching windows are then extended as
+    /// far as the tokens keep matching.
+    pub async fn run(&self, settings: DuplicatesSettings) -> Result<DuplicatesResult, SourceError> {
+        let source_ids = self.source_manager.user_defined_source_ids().collect::<Vec<_>>();
+        let tokenize_pb = create_progress_bar(source_ids.len(), "🔤  Tokenizing", ProgressBarTheme::Yellow);
+
+        let mut handles = Vec::with_capacity(source_ids.len());
+        for source_id in source_ids {
+            handles.push(tokio::spawn({
+                let interner = self.interner.clone();
+                let manager = self.source_manager.clone();
+                let tokenize_pb = tokenize_pb.clone();
+
+                async move {
+                    let source = manager.load(&source_id)?;
+                    let tokens = tokenize(&interner, &source, settings.fuzzy);
+                    tokenize_pb.inc(1);
+
+                    Result::<_, SourceError>::Ok(TokenizedSource { source, tokens })
+                }
+            }));
+        }
+
+        let mut sources = Vec::with_capacity(handles.len());
+        for handle in handles {
+            sources.push(handle.await.expect("failed to tokenize source. this should never happen.")?);
+        }
+
+        remove_progress_bar(tokenize_pb);
+
+        // Sort the sources, so that the first occurrence of a fragment is always the same one.
+        sources.sort_by(|a, b| {
+            self.interner.lookup(&a.source.identifier.0).cmp(self.interner.lookup(&b.source.identifier.0))
+        });
+
+        Ok(find_duplicates(&sources, settings))
+    }
+}
+
+/// Finds the fragments of the given sources that duplicate earlier fragments.
+fn find_duplicates(sources: &[TokenizedSource], settings: DuplicatesSettings) -> DuplicatesResult {
+    let mut result = DuplicatesResult {
+        files: sources.len(),
+        lines: sources.iter().map(|source| source.source.lines.len()).sum(),
+        ..Default::default()
+    };
+
+    let minimum_tokens = settings.minimum_tokens.max(1);
+    let mut windows: HashMap<u64, Vec<(usize, usize)>> = HashMap::default();
+    let mut highest_power = 1u64;
+    for _ in 1..minimum_tokens {
+        highest_power = highest_power.wrapping_mul(BASE);
+    }
+
+    for (source_index, tokenized) in sources.iter().enumerate() {
+        let tokens = &tokenized.tokens;
+        if tokens.len() < minimum_tokens {
+            continue;
+        }
+
+        let mut hash =
+            tokens[..minimum_tokens].iter().fold(0u64, |hash, token| hash.wrapping_mul(BASE).wrapping_add(token.hash));
+
+        let mut start = 0;
+        loop {
+            let found = windows.get(&hash).and_then(|occurrences| {
+                occurrences.iter().find_map(|(original_source, original_start)| {
+                    let original = &sources[*original_source].tokens;
+                    let length = get_match_length(original, *original_start, tokens, start);
+                    let overlaps = *original_source == source_index && *original_start + length > start;
+
+                    (length >= minimum_tokens && !overlaps).then_some((*original_source, *original_start, length))
+                })
+            });
+
+            let next = match found {
+                Some((original_source, original_start, length)) => {
+                    let original = &sources[original_source];
+                    let duplicate = Span::between(tokens[start].span, tokens[start + length - 1].span);
+                    let lines = tokenized.source.line_number(duplicate.end.offset)
+                        - tokenized.source.line_number(duplicate.start.offset)
+                        + 1;
+
+                    if lines >= settings.minimum_lines {
+                        result.duplicated_lines += lines;
+                        result.duplicates.push(Duplicate {
+                            original: Span::between(
+                                original.tokens[original_start].span,
+                                original.tokens[original_start + length - 1].span,
+                            ),
+                            duplicate,
+                            tokens: length,
+                            lines,
+                        });
+
+                        start + length
+                    } else {
+                        windows.entry(hash).or_default().push((source_index, start));
+
+                        start + 1
+                    }
+                }
+                None => {
+                    windows.entry(hash).or_default().push((source_index, start));
+
+                    start + 1
+                }
+            };
+
+            if next + minimum_tokens > tokens.len() {
+                break;
+            }
+
+            if next == start + 1 {
+                hash = hash
+                    .wrapping_sub(tokens[start].hash.wrapping_mul(highest_power))
+                    .wrapping_mul(BASE)
+                    .wrapping_add(tokens[start + minimum_tokens].hash);
+            } else {
+                hash = tokens[next..next + minimum_tokens]
+                    .iter()
+                    .fold(0u64, |hash, token| hash.wrapping_mul(BASE).wrapping_add(token.hash));
+            }
+
+            start = next;
+        }
+    }
+
+    result
+}
+
+/// Returns the number of tokens matching from the given positions.
+fn get_match_length(a: &[NormalizedToken], a_start: usize, b: &[NormalizedToken], b_start: usize) -> usize {
+    a[a_start..].iter().zip(&b[b_start..]).take_while(|(a, b)| a.hash == b.hash).count()
+}
+
+fn tokenize(interner: &ThreadedInterner, source: &Source, fuzzy: bool) -> Vec<NormalizedToken> {
+    let content = interner.lookup(&source.content);
+    let mut lexer = Lexer::new(interner, Input::new(source.identifier, content.as_bytes()));
+
+    let mut tokens = vec![];
+    while let Some(Ok(token)) = lexer.advance() {
+        if token.kind.is_trivia()
+            || matches!(
+                token.kind,
+                TokenKind::OpenTag | TokenKind::ShortOpenTag | TokenKind::CloseTag | TokenKind::InlineShebang
+            )
+        {
+            continue;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        token.kind.hash(&mut hasher);
+        if !fuzzy || !is_name_or_literal(&token.kind) {
+            token.value.hash(&mut hasher);
+        }
+
+        tokens.push(NormalizedToken { hash: hasher.finish(), span: token.span });
+    }
+
+    tokens
+}
+
+fn is_name_or_literal(kind: &TokenKind) -> bool {
+    kind.is_literal()
+        || matches!(
+            kind,
+            TokenKind::Variable
+                | TokenKind::Identifier
+                | TokenKind::QualifiedIdentifier
+                | TokenKind::FullyQualifiedIdentifier
+                | TokenKind::StringPart
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_source::SourceCategory;
+    use mago_source::SourceManager;
+
+    use super::find_duplicates;
+    use super::tokenize;
+    use super::DuplicatesResult;
+    use super::DuplicatesSettings;
+    use super::TokenizedSource;
+    use super::BASE;
+
+    const FUNCTION: &str = "<?php
+
+function total(array $items): int
+{
+    $total = 0;
+    foreach ($items as $item) {
+        $total += $item->price * $item->quantity;
+    }
+
+    return $total;
+}
+";
+
+    const RENAMED_FUNCTION: &str = "<?php
+
+function sum(array $lines): int
+{
+    $sum = 0;
+    foreach ($lines as $line) {
+        $sum += $line->price * $line->quantity;
+    }
+
+    return $sum;
+}
+";
+
+    fn tokenize_sources(interner: &ThreadedInterner, files: &[(&str, &str)], fuzzy: bool) -> Vec<TokenizedSource> {
+        let mut manager = SourceManager::new(interner.clone());
+
+        files
+            .iter()
+            .map(|(name, content)| {
+                let source_id =
+                    manager.insert_content(name.to_string(), content.to_string(), SourceCategory::UserDefined);
+                let source = manager.load(&source_id).unwrap();
+                let tokens = tokenize(interner, &source, fuzzy);
+
+                TokenizedSource { source, tokens }
+            })
+            .collect()
+    }
+
+    fn find(files: &[(&str, &str)], minimum_tokens: usize, fuzzy: bool) -> DuplicatesResult {
+        let interner = ThreadedInterner::new();
+        let sources = tokenize_sources(&interner, files, fuzzy);
+
+        find_duplicates(&sources, DuplicatesSettings { minimum_tokens, minimum_lines: 1, fuzzy })
+    }
+
+    #[test]
+    fn test_exact_clones() {
+        let result = find(&[("a.php", FUNCTION), ("b.php", FUNCTION)], 20, false);
+
+        assert_eq!(result.files, 2);
+        assert_eq!(result.duplicates.len(), 1);
+
+        let duplicate = result.duplicates[0];
+        assert_eq!(duplicate.lines, 9);
+        assert_eq!(result.duplicated_lines, 9);
+        assert_eq!(duplicate.original.start.offset, duplicate.duplicate.start.offset);
+        assert_eq!(duplicate.original.end.offset, duplicate.duplicate.end.offset);
+        assert_ne!(duplicate.original.start.source, duplicate.duplicate.start.source);
+    }
+
+    #[test]
+    fn test_clones_within_a_single_source() {
+        let content = format!("{}\n{}", FUNCTION, FUNCTION.trim_start_matches("<?php"));
+        let result = find(&[("a.php", &content)], 20, false);
+
+        assert_eq!(result.duplicates.len(), 1);
+        assert!(result.duplicates[0].original.end.offset <= result.duplicates[0].duplicate.start.offset);
+    }
+
+    #[test]
+    fn test_renamed_clones_are_only_found_when_fuzzy() {
+        let result = find(&[("a.php", FUNCTION), ("b.php", RENAMED_FUNCTION)], 20, false);
+        assert!(result.duplicates.is_empty());
+
+        let result = find(&[("a.php", FUNCTION), ("b.php", RENAMED_FUNCTION)], 20, true);
+        assert_eq!(result.duplicates.len(), 1);
+        assert_eq!(result.duplicates[0].lines, 9);
+    }
+
+    #[test]
+    fn test_minimum_tokens() {
+        let files = [("a.php", "<?php $a = foo($b, $c);"), ("b.php", "<?php $a = foo($b, $c);")];
+
+        // `$a`, `=`, `foo`, `(`, `$b`, `,`, `$c`, `)` and `;`.
+        let result = find(&files, 9, false);
+        assert_eq!(result.duplicates.len(), 1);
+        assert_eq!(result.duplicates[0].tokens, 9);
+
+        let result = find(&files, 10, false);
+        assert!(result.duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_window_hash_collisions_are_not_reported() {
+        let interner = ThreadedInterner::new();
+        let mut sources = tokenize_sources(&interner, &[("a.php", "<?php $a;"), ("b.php", "<?php $b;")], false);
+
+        // Craft tokens whose windows share the same rolling hash, while the tokens themselves differ.
+        sources[0].tokens[0].hash = 1;
+        sources[0].tokens[1].hash = BASE;
+        sources[1].tokens[0].hash = 2;
+        sources[1].tokens[1].hash = 0;
+
+        let settings = DuplicatesSettings { minimum_tokens: 2, minimum_lines: 1, fuzzy: false };
+        assert!(find_duplicates(&sources, settings).duplicates.is_empty());
+
+        sources[1].tokens[0].hash = 1;
+        sources[1].tokens[1].hash = BASE;
+        assert_eq!(find_duplicates(&sources, settings).duplicates.len(), 1);
+    }
+}
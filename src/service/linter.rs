@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use mago_feedback::create_progress_bar;
 use mago_feedback::remove_progress_bar;
 use mago_feedback::ProgressBarTheme;
@@ -10,13 +13,13 @@ use mago_linter::Linter;
 use mago_reporting::Issue;
 use mago_reporting::IssueCollection;
 use mago_reporting::Level;
-use mago_semantics::Semantics;
 use mago_source::error::SourceError;
 use mago_source::SourceIdentifier;
 use mago_source::SourceManager;
 
 use crate::config::linter::LinterConfiguration;
 use crate::config::linter::LinterLevel;
+use crate::service::codebase::CodebaseService;
 use crate::service::utils;
 
 #[derive(Debug)]
@@ -24,6 +27,7 @@ pub struct LintService {
     configuration: LinterConfiguration,
     interner: ThreadedInterner,
     source_manager: SourceManager,
+    root: PathBuf,
 }
 
 #[derive(Debug)]
@@ -34,8 +38,13 @@ pub struct LinterFixResult {
 }
 
 impl LintService {
-    pub fn new(configuration: LinterConfiguration, interner: ThreadedInterner, source_manager: SourceManager) -> Self {
-        Self { configuration, interner, source_manager }
+    pub fn new(
+        configuration: LinterConfiguration,
+        interner: ThreadedInterner,
+        source_manager: SourceManager,
+        root: PathBuf,
+    ) -> Self {
+        Self { configuration, interner, source_manager, root }
     }
 
     /// Runs the linting process and returns a collection of issues.
@@ -159,50 +168,44 @@ impl LintService {
         linter: Linter,
         source_ids: Vec<SourceIdentifier>,
     ) -> Result<IssueCollection, SourceError> {
-        let mut handles = Vec::with_capacity(source_ids.len());
-
-        let source_pb = create_progress_bar(source_ids.len(), "📂  Loading", ProgressBarTheme::Red);
-        let semantics_pb = create_progress_bar(source_ids.len(), "🔬  Building", ProgressBarTheme::Blue);
-        let lint_pb = create_progress_bar(source_ids.len(), "🧹  Linting", ProgressBarTheme::Cyan);
+        let codebase_service =
+            CodebaseService::new(self.interner.clone(), self.source_manager.clone(), self.root.clone());
+        let (semantics, codebase) = codebase_service.build(source_ids).await?;
+        let semantics = semantics.into_iter().map(Arc::new).collect::<Vec<_>>();
+        let codebase = Arc::new(codebase);
+        let references = if linter.requires_references() {
+            Some(Arc::new(codebase_service.build_references(&semantics, &codebase).await))
+        } else {
+            None
+        };
 
-        for source_id in source_ids.into_iter() {
+        let lint_pb = create_progress_bar(semantics.len(), "🧹  Linting", ProgressBarTheme::Cyan);
+        let mut handles = Vec::with_capacity(semantics.len());
+        for semantics in semantics.into_iter() {
             handles.push(tokio::spawn({
-                let interner = self.interner.clone();
-                let manager = self.source_manager.clone();
                 let linter = linter.clone();
-                let source_pb = source_pb.clone();
-                let semantics_pb = semantics_pb.clone();
+                let codebase = codebase.clone();
+                let references = references.clone();
                 let lint_pb = lint_pb.clone();
 
                 async move {
-                    // Step 1: load the source
-                    let source = manager.load(&source_id)?;
-                    source_pb.inc(1);
-
-                    // Step 2: build semantics
-                    let semantics = Semantics::build(&interner, source);
-                    semantics_pb.inc(1);
-
-                    // Step 3: Collect issues
-                    let mut issues = linter.lint(&semantics);
-                    issues.extend(semantics.issues);
+                    let mut issues = linter.lint(&semantics, &codebase, references.as_deref());
+                    issues.extend(semantics.issues.clone());
                     if let Some(error) = &semantics.parse_error {
                         issues.push(Into::<Issue>::into(error));
                     }
                     lint_pb.inc(1);
 
-                    Result::<_, SourceError>::Ok(issues)
+                    issues
                 }
             }));
         }
 
         let mut results = Vec::with_capacity(handles.len());
         for handle in handles {
-            results.push(handle.await.expect("failed to collect issues. this should never happen.")?);
+            results.push(handle.await.expect("failed to collect issues. this should never happen."));
         }
 
-        remove_progress_bar(source_pb);
-        remove_progress_bar(semantics_pb);
         remove_progress_bar(lint_pb);
 
         Ok(IssueCollection::from(results.into_iter().flatten()))
@@ -0,0 +1,488 @@
+use std::collections::BTreeSet;
+
+use mago_ast::*;
+use mago_ast_utils::complexity::get_block_complexity;
+use mago_ast_utils::complexity::get_expression_complexity;
+use mago_ast_utils::complexity::Complexity;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_lexer::Lexer;
+use mago_reflection::CodebaseReflection;
+use mago_semantics::Semantics;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_token::Token;
+use mago_walker::MutWalker;
+
+use crate::service::metrics::halstead;
+use crate::service::metrics::ClassLikeMetrics;
+use crate::service::metrics::FileMetrics;
+use crate::service::metrics::FunctionLikeMetrics;
+use crate::service::metrics::LineMetrics;
+
+/// The name given to closures and arrow functions, as done by PHP in stack traces.
+const CLOSURE_NAME: &str = "{closure}";
+
+/// The metrics of a single source, before the coupling of its class-likes is known.
+#[derive(Debug)]
+pub struct SourceMetrics {
+    pub file: FileMetrics,
+    /// The class-likes declared in the source, along with the lowercased names of the class-likes they depend on.
+    pub class_likes: Vec<(ClassLikeMetrics, BTreeSet<String>)>,
+    pub function_likes: Vec<FunctionLikeMetrics>,
+}
+
+/// Computes the metrics of the given source.
+pub fn collect(interner: &ThreadedInterner, semantics: &Semantics, codebase: &CodebaseReflection) -> SourceMetrics {
+    let source = &semantics.source;
+    let file = interner.lookup(&source.identifier.0).to_string();
+    let content = interner.lookup(&source.content);
+
+    let mut tokens = vec![];
+    let mut lexer = Lexer::new(interner, Input::new(source.identifier, content.as_bytes()));
+    while let Some(Ok(token)) = lexer.advance() {
+        tokens.push(token);
+    }
+
+    let mut comment_lines = BTreeSet::new();
+    for trivia in semantics.program.trivia.iter().filter(|trivia| trivia.kind.is_comment()) {
+        let span = trivia.span();
+
+        comment_lines.extend(source.line_number(span.start.offset)..=last_line(semantics, span));
+    }
+
+    let mut collector = Collector {
+        interner,
+        semantics,
+        statements: vec![],
+        class_likes: vec![],
+        function_likes: vec![],
+        stack: vec![],
+    };
+    collector.walk_program(&semantics.program, &mut ());
+    collector.statements.sort_unstable();
+
+    let measurer =
+        Measurer { semantics, tokens: &tokens, comment_lines: &comment_lines, statements: &collector.statements };
+
+    let names = semantics.names.all().into_iter().map(|(offset, (name, _))| (*offset, *name)).collect::<Vec<_>>();
+
+    let mut function_likes = vec![];
+    for function_like in collector.function_likes {
+        function_likes.push(FunctionLikeMetrics {
+            name: function_like.name,
+            file: file.clone(),
+            line: source.line_number(function_like.span.start.offset) + 1,
+            lines: measurer.lines(function_like.span),
+            parameters: function_like.parameters,
+            cyclomatic_complexity: function_like.complexity.cyclomatic,
+            cognitive_complexity: function_like.complexity.cognitive,
+            halstead: halstead::measure(interner, measurer.tokens(function_like.span)),
+        });
+    }
+
+    let mut class_likes = vec![];
+    for class_like in collector.class_likes {
+        let name = interner.lookup(&class_like.name);
+        let inheritance_depth = match codebase.get_named_class_like(&class_like.name) {
+            Some(reflection) if class_like.kind == "interface" => reflection.inheritance.all_extended_interfaces.len(),
+            Some(reflection) => reflection.inheritance.all_extended_classes.len(),
+            None => 0,
+        };
+
+        let mut dependencies = BTreeSet::new();
+        for (offset, dependency) in names.iter() {
+            if !class_like.span.has_offset(*offset) || codebase.get_named_class_like(dependency).is_none() {
+                continue;
+            }
+
+            // Class-like names are case-insensitive, so `Foo` and `foo` are the same dependency.
+            let dependency = interner.lookup(dependency).to_ascii_lowercase();
+            if !dependency.eq_ignore_ascii_case(name) {
+                dependencies.insert(dependency);
+            }
+        }
+
+        let metrics = ClassLikeMetrics {
+            name: name.to_string(),
+            kind: class_like.kind,
+            file: file.clone(),
+            line: source.line_number(class_like.span.start.offset) + 1,
+            lines: measurer.lines(class_like.span),
+            methods: class_like.methods,
+            properties: class_like.properties,
+            constants: class_like.constants,
+            cyclomatic_complexity: class_like.cyclomatic_complexity,
+            cognitive_complexity: class_like.cognitive_complexity,
+            afferent_coupling: 0,
+            efferent_coupling: 0,
+            instability: 0.0,
+            inheritance_depth,
+        };
+
+        class_likes.push((metrics, dependencies));
+    }
+
+    let file = FileMetrics {
+        file,
+        lines: measurer.lines(semantics.program.span()),
+        class_likes: class_likes.len(),
+        function_likes: function_likes.len(),
+        cyclomatic_complexity: function_likes.iter().map(|function_like| function_like.cyclomatic_complexity).sum(),
+        cognitive_complexity: function_likes.iter().map(|function_like| function_like.cognitive_complexity).sum(),
+        halstead: halstead::measure(interner, &tokens),
+    };
+
+    SourceMetrics { file, class_likes, function_likes }
+}
+
+fn last_line(semantics: &Semantics, span: Span) -> usize {
+    semantics.source.line_number(span.end.offset.saturating_sub(1).max(span.start.offset))
+}
+
+struct Measurer<'a> {
+    semantics: &'a Semantics,
+    tokens: &'a [Token],
+    comment_lines: &'a BTreeSet<usize>,
+    /// The sorted offsets of the logical statements of the source.
+    statements: &'a [usize],
+}
+
+impl Measurer<'_> {
+    fn lines(&self, span: Span) -> LineMetrics {
+        if span.start.offset >= span.end.offset {
+            return LineMetrics::default();
+        }
+
+        let first_line = self.semantics.source.line_number(span.start.offset);
+        let last_line = last_line(self.semantics, span);
+
+        let loc = last_line - first_line + 1;
+        let cloc = self.comment_lines.range(first_line..=last_line).count();
+        let lloc = self.statements.partition_point(|offset| *offset < span.end.offset)
+            - self.statements.partition_point(|offset| *offset < span.start.offset);
+
+        LineMetrics { loc, lloc, cloc, ncloc: loc - cloc }
+    }
+
+    fn tokens(&self, span: Span) -> &[Token] {
+        let start = self.tokens.partition_point(|token| token.span.start.offset < span.start.offset);
+        let end = self.tokens.partition_point(|token| token.span.start.offset < span.end.offset);
+
+        &self.tokens[start..end]
+    }
+}
+
+struct PendingClassLike {
+    name: StringIdentifier,
+    kind: &'static str,
+    span: Span,
+    methods: usize,
+    properties: usize,
+    constants: usize,
+    cyclomatic_complexity: usize,
+    cognitive_complexity: usize,
+}
+
+struct PendingFunctionLike {
+    name: String,
+    span: Span,
+    parameters: usize,
+    complexity: Complexity,
+}
+
+struct Collector<'a> {
+    interner: &'a ThreadedInterner,
+    semantics: &'a Semantics,
+    statements: Vec<usize>,
+    class_likes: Vec<PendingClassLike>,
+    function_likes: Vec<PendingFunctionLike>,
+    /// The index of the enclosing class-likes, or `None` for anonymous classes.
+    stack: Vec<Option<usize>>,
+}
+
+impl Collector<'_> {
+    fn enter_class_like(
+        &mut self,
+        name: &LocalIdentifier,
+        kind: &'static str,
+        span: Span,
+        members: &[ClassLikeMember],
+    ) {
+        let mut class_like = PendingClassLike {
+            name: *self.semantics.names.get(name),
+            kind,
+            span,
+            methods: 0,
+            properties: 0,
+            constants: 0,
+            cyclomatic_complexity: 0,
+            cognitive_complexity: 0,
+        };
+
+        for member in members {
+            match member {
+                ClassLikeMember::Method(_) => class_like.methods += 1,
+                ClassLikeMember::Property(Property::Plain(property)) => class_like.properties += property.items.len(),
+                ClassLikeMember::Property(Property::Hooked(_)) => class_like.properties += 1,
+                ClassLikeMember::Constant(constant) => class_like.constants += constant.items.len(),
+                ClassLikeMember::EnumCase(_) => class_like.constants += 1,
+                ClassLikeMember::TraitUse(_) => {}
+            }
+        }
+
+        self.stack.push(Some(self.class_likes.len()));
+        self.class_likes.push(class_like);
+    }
+}
+
+impl MutWalker<()> for Collector<'_> {
+    fn walk_in_statement(&mut self, statement: &Statement, _: &mut ()) {
+        if !matches!(
+            statement,
+            Statement::OpeningTag(_)
+                | Statement::ClosingTag(_)
+                | Statement::Inline(_)
+                | Statement::Namespace(_)
+                | Statement::Block(_)
+                | Statement::Noop(_)
+        ) {
+            self.statements.push(statement.span().start.offset);
+        }
+    }
+
+    fn walk_in_class_like_member(&mut self, member: &ClassLikeMember, _: &mut ()) {
+        self.statements.push(member.span().start.offset);
+    }
+
+    fn walk_in_class(&mut self, class: &Class, _: &mut ()) {
+        self.enter_class_like(&class.name, "class", class.span(), class.members.as_slice());
+    }
+
+    fn walk_out_class(&mut self, _: &Class, _: &mut ()) {
+        self.stack.pop();
+    }
+
+    fn walk_in_interface(&mut self, interface: &Interface, _: &mut ()) {
+        self.enter_class_like(&interface.name, "interface", interface.span(), interface.members.as_slice());
+    }
+
+    fn walk_out_interface(&mut self, _: &Interface, _: &mut ()) {
+        self.stack.pop();
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, _: &mut ()) {
+        self.enter_class_like(&r#trait.name, "trait", r#trait.span(), r#trait.members.as_slice());
+    }
+
+    fn walk_out_trait(&mut self, _: &Trait, _: &mut ()) {
+        self.stack.pop();
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, _: &mut ()) {
+        self.enter_class_like(&r#enum.name, "enum", r#enum.span(), r#enum.members.as_slice());
+    }
+
+    fn walk_out_enum(&mut self, _: &Enum, _: &mut ()) {
+        self.stack.pop();
+    }
+
+    fn walk_in_anonymous_class(&mut self, _: &AnonymousClass, _: &mut ()) {
+        self.stack.push(None);
+    }
+
+    fn walk_out_anonymous_class(&mut self, _: &AnonymousClass, _: &mut ()) {
+        self.stack.pop();
+    }
+
+    fn walk_in_function(&mut self, function: &Function, _: &mut ()) {
+        self.function_likes.push(PendingFunctionLike {
+            name: self.interner.lookup(self.semantics.names.get(&function.name)).to_string(),
+            span: function.span(),
+            parameters: function.parameters.parameters.len(),
+            complexity: get_block_complexity(&function.body),
+        });
+    }
+
+    fn walk_in_method(&mut self, method: &Method, _: &mut ()) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        let class_like = self.stack.last().copied().flatten();
+        let class_like_name = match class_like {
+            Some(index) => self.interner.lookup(&self.class_likes[index].name),
+            None => "class@anonymous",
+        };
+
+        let complexity = get_block_complexity(block);
+        if let Some(index) = class_like {
+            self.class_likes[index].cyclomatic_complexity += complexity.cyclomatic;
+            self.class_likes[index].cognitive_complexity += complexity.cognitive;
+        }
+
+        self.function_likes.push(PendingFunctionLike {
+            name: format!("{}::{}", class_like_name, self.interner.lookup(&method.name.value)),
+            span: method.span(),
+            parameters: method.parameters.parameters.len(),
+            complexity,
+        });
+    }
+
+    fn walk_in_closure(&mut self, closure: &Closure, _: &mut ()) {
+        self.function_likes.push(PendingFunctionLike {
+            name: CLOSURE_NAME.to_string(),
+            span: closure.span(),
+            parameters: closure.parameters.parameters.len(),
+            complexity: get_block_complexity(&closure.body),
+        });
+    }
+
+    fn walk_in_arrow_function(&mut self, arrow_function: &ArrowFunction, _: &mut ()) {
+        self.function_likes.push(PendingFunctionLike {
+            name: CLOSURE_NAME.to_string(),
+            span: arrow_function.span(),
+            parameters: arrow_function.parameters.parameters.len(),
+            complexity: get_expression_complexity(&arrow_function.expression),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_semantics::Semantics;
+    use mago_source::SourceCategory;
+    use mago_source::SourceManager;
+
+    use super::collect;
+    use super::SourceMetrics;
+
+    fn measure(code: &str) -> SourceMetrics {
+        let interner = ThreadedInterner::new();
+        let mut manager = SourceManager::new(interner.clone());
+        let source_id = manager.insert_content("test.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+
+        let semantics = Semantics::build(&interner, manager.load(&source_id).unwrap());
+        let mut codebase = mago_reflector::reflect(&interner, &semantics);
+        mago_reflector::populate(&interner, &mut codebase);
+
+        collect(&interner, &semantics, &codebase)
+    }
+
+    #[test]
+    fn test_line_metrics() {
+        let metrics = measure(
+            "<?php\n\n// A comment.\n/**\n * A docblock.\n */\nfunction foo(): int\n{\n    $a = 1;\n\n    return $a;\n}\n",
+        );
+
+        assert_eq!(metrics.file.lines.loc, 12);
+        assert_eq!(metrics.file.lines.cloc, 4);
+        assert_eq!(metrics.file.lines.ncloc, 8);
+        assert_eq!(metrics.file.lines.lloc, 3);
+
+        let function = &metrics.function_likes[0];
+        assert_eq!(function.name, "foo");
+        assert_eq!(function.line, 7);
+        assert_eq!(function.lines.loc, 6);
+        assert_eq!(function.lines.lloc, 3);
+    }
+
+    #[test]
+    fn test_class_like_metrics() {
+        let metrics = measure(
+            "<?php
+            final class Foo
+            {
+                public const A = 1, B = 2;
+
+                private int $a, $b;
+
+                public function bar(int $a, int $b): int
+                {
+                    return $a > $b ? $a : $b;
+                }
+
+                public function baz(): void {}
+            }
+            ",
+        );
+
+        let (class_like, _) = &metrics.class_likes[0];
+        assert_eq!(class_like.name, "Foo");
+        assert_eq!(class_like.kind, "class");
+        assert_eq!(class_like.methods, 2);
+        assert_eq!(class_like.properties, 2);
+        assert_eq!(class_like.constants, 2);
+        assert_eq!(class_like.cyclomatic_complexity, 3);
+        assert_eq!(class_like.cognitive_complexity, 1);
+
+        let names = metrics.function_likes.iter().map(|function_like| function_like.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Foo::bar", "Foo::baz"]);
+        assert_eq!(metrics.function_likes[0].parameters, 2);
+    }
+
+    #[test]
+    fn test_dependencies_are_case_insensitive() {
+        let metrics = measure(
+            "<?php
+            class Bar {}
+            class Baz {}
+
+            class Foo extends Bar
+            {
+                public function a(bar $bar, BAR $other): baz
+                {
+                    return new Baz();
+                }
+
+                public function b(): foo
+                {
+                    return new FOO();
+                }
+            }
+            ",
+        );
+
+        let (_, dependencies) = metrics
+            .class_likes
+            .iter()
+            .find(|(class_like, _)| class_like.name == "Foo")
+            .expect("Foo should be measured");
+
+        assert_eq!(dependencies.iter().map(String::as_str).collect::<Vec<_>>(), vec!["bar", "baz"]);
+    }
+
+    #[test]
+    fn test_closures_and_arrow_functions_are_measured() {
+        let metrics = measure(
+            "<?php
+            function foo(array $items): array
+            {
+                $filter = function ($item) use ($items) {
+                    if ($item) {
+                        return true;
+                    }
+
+                    return false;
+                };
+
+                return array_map(fn($item) => $item ?? 0, array_filter($items, $filter));
+            }
+            ",
+        );
+
+        let function_likes = metrics
+            .function_likes
+            .iter()
+            .map(|function_like| {
+                (function_like.name.as_str(), function_like.parameters, function_like.cyclomatic_complexity)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(function_likes, vec![("foo", 1, 1), ("{closure}", 1, 2), ("{closure}", 1, 2)]);
+        assert_eq!(metrics.file.function_likes, 3);
+        assert_eq!(metrics.file.cyclomatic_complexity, 5);
+    }
+}
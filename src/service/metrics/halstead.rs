@@ -0,0 +1,165 @@
+use ahash::HashSet;
+use serde::Serialize;
+
+use mago_interner::ThreadedInterner;
+use mago_token::Token;
+use mago_token::TokenKind;
+
+/// The Halstead measures of a piece of code, derived from its operators and operands.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct HalsteadMetrics {
+    pub distinct_operators: usize,
+    pub distinct_operands: usize,
+    pub operators: usize,
+    pub operands: usize,
+    pub vocabulary: usize,
+    pub length: usize,
+    pub volume: f64,
+    pub difficulty: f64,
+    pub effort: f64,
+    /// The estimated number of delivered bugs.
+    pub bugs: f64,
+}
+
+/// Computes the Halstead measures of the given tokens.
+///
+/// Variables, identifiers and literals are operands, while every other token is an operator.
+/// Closing delimiters are not counted, as they always come with an opening one.
+pub fn measure(interner: &ThreadedInterner, tokens: &[Token]) -> HalsteadMetrics {
+    let mut distinct_operators = HashSet::default();
+    let mut distinct_operands = HashSet::default();
+    let mut operators = 0;
+    let mut operands = 0;
+
+    for token in tokens {
+        if is_ignored(&token.kind) {
+            continue;
+        }
+
+        if is_operand(&token.kind) {
+            distinct_operands.insert(token.value);
+            operands += 1;
+        } else {
+            distinct_operators.insert(interner.lookup(&token.value).to_ascii_lowercase());
+            operators += 1;
+        }
+    }
+
+    let vocabulary = distinct_operators.len() + distinct_operands.len();
+    let length = operators + operands;
+    let volume = if vocabulary == 0 { 0.0 } else { length as f64 * (vocabulary as f64).log2() };
+    let difficulty = if distinct_operands.is_empty() {
+        0.0
+    } else {
+        (distinct_operators.len() as f64 / 2.0) * (operands as f64 / distinct_operands.len() as f64)
+    };
+
+    let effort = difficulty * volume;
+
+    HalsteadMetrics {
+        distinct_operators: distinct_operators.len(),
+        distinct_operands: distinct_operands.len(),
+        operators,
+        operands,
+        vocabulary,
+        length,
+        volume,
+        difficulty,
+        effort,
+        bugs: volume / 3000.0,
+    }
+}
+
+fn is_ignored(kind: &TokenKind) -> bool {
+    kind.is_trivia()
+        || matches!(
+            kind,
+            TokenKind::OpenTag
+                | TokenKind::ShortOpenTag
+                | TokenKind::EchoTag
+                | TokenKind::CloseTag
+                | TokenKind::InlineText
+                | TokenKind::InlineShebang
+                | TokenKind::RightParenthesis
+                | TokenKind::RightBracket
+                | TokenKind::RightBrace
+        )
+}
+
+fn is_operand(kind: &TokenKind) -> bool {
+    kind.is_literal()
+        || kind.is_magic_constant()
+        || matches!(
+            kind,
+            TokenKind::Variable
+                | TokenKind::Identifier
+                | TokenKind::QualifiedIdentifier
+                | TokenKind::FullyQualifiedIdentifier
+                | TokenKind::StringPart
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_lexer::input::Input;
+    use mago_lexer::Lexer;
+    use mago_source::SourceIdentifier;
+
+    use super::measure;
+    use super::HalsteadMetrics;
+
+    fn measure_code(code: &str) -> HalsteadMetrics {
+        let interner = ThreadedInterner::new();
+        let mut lexer = Lexer::new(&interner, Input::new(SourceIdentifier::dummy(), code.as_bytes()));
+
+        let mut tokens = vec![];
+        while let Some(Ok(token)) = lexer.advance() {
+            tokens.push(token);
+        }
+
+        measure(&interner, &tokens)
+    }
+
+    #[test]
+    fn test_operators_and_operands() {
+        // Operators: `=` (x2), `+`, `;` (x2), and operands: `$a` (x2), `$b`, `1`, `2`.
+        let metrics = measure_code("<?php $a = 1; $b = $a + 2;");
+
+        assert_eq!(metrics.operators, 5);
+        assert_eq!(metrics.distinct_operators, 3);
+        assert_eq!(metrics.operands, 5);
+        assert_eq!(metrics.distinct_operands, 4);
+        assert_eq!(metrics.vocabulary, 7);
+        assert_eq!(metrics.length, 10);
+        assert!((metrics.volume - 10.0 * 7f64.log2()).abs() < f64::EPSILON);
+        assert!((metrics.difficulty - 1.5 * 1.25).abs() < f64::EPSILON);
+        assert!((metrics.effort - metrics.difficulty * metrics.volume).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_closing_delimiters_are_ignored() {
+        let metrics = measure_code("<?php foo([$a]);");
+
+        // `(`, `[` and `;`, while `]` and `)` are not counted.
+        assert_eq!(metrics.operators, 3);
+        assert_eq!(metrics.operands, 2);
+    }
+
+    #[test]
+    fn test_keywords_are_case_insensitive_operators() {
+        let metrics = measure_code("<?php ECHO $a; echo $a;");
+
+        assert_eq!(metrics.distinct_operators, 2);
+        assert_eq!(metrics.operators, 4);
+    }
+
+    #[test]
+    fn test_empty_code() {
+        let metrics = measure_code("<?php ");
+
+        assert_eq!(metrics.length, 0);
+        assert_eq!(metrics.volume, 0.0);
+        assert_eq!(metrics.difficulty, 0.0);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ahash::HashMap;
+use serde::Serialize;
+
+use mago_feedback::create_progress_bar;
+use mago_feedback::remove_progress_bar;
+use mago_feedback::ProgressBarTheme;
+use mago_interner::ThreadedInterner;
+use mago_source::error::SourceError;
+use mago_source::SourceManager;
+
+use crate::service::codebase::CodebaseService;
+use crate::service::metrics::halstead::HalsteadMetrics;
+
+mod collector;
+mod halstead;
+
+#[derive(Debug)]
+pub struct MetricsService {
+    interner: ThreadedInterner,
+    source_manager: SourceManager,
+    root: PathBuf,
+}
+
+/// The metrics of the user-defined sources of a project.
+#[derive(Debug, Default, Serialize)]
+pub struct Metrics {
+    pub files: Vec<FileMetrics>,
+    pub class_likes: Vec<ClassLikeMetrics>,
+    pub function_likes: Vec<FunctionLikeMetrics>,
+}
+
+/// The size of a piece of code, in lines.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct LineMetrics {
+    /// The number of lines.
+    pub loc: usize,
+    /// The number of logical lines: statements and class-like member declarations.
+    pub lloc: usize,
+    /// The number of lines containing comments.
+    pub cloc: usize,
+    /// The number of lines not containing comments.
+    pub ncloc: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileMetrics {
+    pub file: String,
+    pub lines: LineMetrics,
+    pub class_likes: usize,
+    pub function_likes: usize,
+    /// The sum of the cyclomatic complexities of the functions and methods declared in the file.
+    pub cyclomatic_complexity: usize,
+    /// The sum of the cognitive complexities of the functions and methods declared in the file.
+    pub cognitive_complexity: usize,
+    pub halstead: HalsteadMetrics,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClassLikeMetrics {
+    pub name: String,
+    pub kind: &'static str,
+    pub file: String,
+    pub line: usize,
+    pub lines: LineMetrics,
+    pub methods: usize,
+    pub properties: usize,
+    pub constants: usize,
+    /// The sum of the cyclomatic complexities of the methods, also known as weighted methods per class.
+    pub cyclomatic_complexity: usize,
+    /// The sum of the cognitive complexities of the methods.
+    pub cognitive_complexity: usize,
+    /// The number of class-likes of the project depending on this class-like.
+    pub afferent_coupling: usize,
+    /// The number of class-likes this class-like depends on.
+    pub efferent_coupling: usize,
+    /// The ratio of efferent coupling to total coupling, from 0 (stable) to 1 (unstable).
+    pub instability: f64,
+    /// The number of ancestors: extended classes for classes, and extended interfaces for interfaces.
+    pub inheritance_depth: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunctionLikeMetrics {
+    /// The name of the function, of the method prefixed by its class-like name, or `{closure}`.
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub lines: LineMetrics,
+    pub parameters: usize,
+    pub cyclomatic_complexity: usize,
+    pub cognitive_complexity: usize,
+    pub halstead: HalsteadMetrics,
+}
+
+impl MetricsService {
+    pub fn new(interner: ThreadedInterner, source_manager: SourceManager, root: PathBuf) -> Self {
+        Self { interner, source_manager, root }
+    }
+
+    /// Computes the metrics of the user-defined sources of the project.
+    ///
+    /// The reflection of the entire codebase is built first, so that the inheritance depth and
+    /// the coupling of class-likes take symbols defined outside of the project into account.
+    pub async fn run(&self) -> Result<Metrics, SourceError> {
+        let codebase_service =
+            CodebaseService::new(self.interner.clone(), self.source_manager.clone(), self.root.clone());
+
+        let (semantics, codebase) =
+            codebase_service.build(self.source_manager.user_defined_source_ids().collect()).await?;
+        let codebase = Arc::new(codebase);
+
+        let measure_pb = create_progress_bar(semantics.len(), "📏  Measuring", ProgressBarTheme::Cyan);
+        let mut handles = Vec::with_capacity(semantics.len());
+        for semantics in semantics.into_iter() {
+            handles.push(tokio::spawn({
+                let interner = self.interner.clone();
+                let codebase = codebase.clone();
+                let measure_pb = measure_pb.clone();
+
+                async move {
+                    let metrics = collector::collect(&interner, &semantics, &codebase);
+                    measure_pb.inc(1);
+
+                    metrics
+                }
+            }));
+        }
+
+        let mut metrics = Metrics::default();
+        let mut dependencies: Vec<BTreeSet<String>> = vec![];
+        for handle in handles {
+            let source_metrics = handle.await.expect("failed to compute metrics. this should never happen.");
+
+            metrics.files.push(source_metrics.file);
+            metrics.function_likes.extend(source_metrics.function_likes);
+            for (class_like, class_like_dependencies) in source_metrics.class_likes {
+                metrics.class_likes.push(class_like);
+                dependencies.push(class_like_dependencies);
+            }
+        }
+
+        remove_progress_bar(measure_pb);
+
+        // Afferent coupling only takes the class-likes of the project into account, as the
+        // dependents living outside of it are unknown.
+        let mut dependents: HashMap<String, usize> = HashMap::default();
+        for dependency in dependencies.iter().flatten() {
+            *dependents.entry(dependency.clone()).or_default() += 1;
+        }
+
+        for (class_like, dependencies) in metrics.class_likes.iter_mut().zip(dependencies) {
+            class_like.efferent_coupling = dependencies.len();
+            class_like.afferent_coupling = dependents.get(&class_like.name.to_ascii_lowercase()).copied().unwrap_or(0);
+
+            let coupling = class_like.afferent_coupling + class_like.efferent_coupling;
+            class_like.instability =
+                if coupling == 0 { 0.0 } else { class_like.efferent_coupling as f64 / coupling as f64 };
+        }
+
+        metrics.files.sort_by(|a, b| a.file.cmp(&b.file));
+        metrics.class_likes.sort_by(|a, b| a.name.cmp(&b.name));
+        metrics.function_likes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(metrics)
+    }
+}
@@ -1,6 +1,11 @@
 mod utils;
 
 pub mod ast;
+pub mod cache;
+pub mod codebase;
+pub mod duplicates;
 pub mod formatter;
 pub mod linter;
+pub mod metrics;
+pub mod refactor;
 pub mod source;
@@ -0,0 +1,56 @@
+use mago_source::error::SourceError;
+
+#[derive(Debug)]
+pub enum RefactorError {
+    Source(SourceError),
+    SymbolNotFound(String),
+    ExternalSymbol(String),
+    InvalidName(String),
+    SymbolAlreadyExists(String),
+    UnsupportedRename(String, String),
+    MovingFile(std::path::PathBuf, std::path::PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for RefactorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefactorError::Source(error) => {
+                write!(f, "failed to load sources: {}", error)
+            }
+            RefactorError::SymbolNotFound(name) => {
+                write!(f, "symbol `{}` could not be found in the project", name)
+            }
+            RefactorError::ExternalSymbol(name) => {
+                write!(f, "symbol `{}` is not defined in the project, and cannot be renamed", name)
+            }
+            RefactorError::InvalidName(name) => {
+                write!(f, "`{}` is not a valid name for the symbol", name)
+            }
+            RefactorError::SymbolAlreadyExists(name) => {
+                write!(f, "symbol `{}` already exists", name)
+            }
+            RefactorError::UnsupportedRename(name, reason) => {
+                write!(f, "cannot rename `{}`: {}", name, reason)
+            }
+            RefactorError::MovingFile(from, to, error) => {
+                write!(f, "failed to move '{}' to '{}': {}", from.display(), to.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RefactorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RefactorError::Source(error) => Some(error),
+            RefactorError::MovingFile(_, _, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<SourceError> for RefactorError {
+    fn from(error: SourceError) -> Self {
+        RefactorError::Source(error)
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::IssueCollection;
+use mago_source::SourceManager;
+
+use crate::service::codebase::CodebaseService;
+use crate::service::refactor::error::RefactorError;
+use crate::service::utils;
+
+pub mod error;
+
+mod psr4;
+mod rename;
+mod scope;
+
+#[derive(Debug)]
+pub struct RefactorService {
+    interner: ThreadedInterner,
+    source_manager: SourceManager,
+    root: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct RenameResult {
+    /// The references that prevented the rename; when not empty, no changes were made.
+    pub issues: IssueCollection,
+    pub changed: usize,
+    pub moved: Option<(PathBuf, PathBuf)>,
+}
+
+impl RefactorService {
+    pub fn new(interner: ThreadedInterner, source_manager: SourceManager, root: PathBuf) -> Self {
+        Self { interner, source_manager, root }
+    }
+
+    /// Renames the symbol named `old` to `new` across the project, along with the file declaring it
+    /// if it is a class-like autoloaded using PSR-4.
+    ///
+    /// Nothing is changed if any of the references to the symbol cannot be safely renamed, in which
+    /// case the issues describing those references are returned.
+    pub async fn rename(&self, old: &str, new: &str, dry_run: bool) -> Result<RenameResult, RefactorError> {
+        let codebase_service =
+            CodebaseService::new(self.interner.clone(), self.source_manager.clone(), self.root.clone());
+
+        let (semantics, codebase) =
+            codebase_service.build(self.source_manager.user_defined_source_ids().collect()).await?;
+        let semantics = semantics.into_iter().map(Arc::new).collect::<Vec<_>>();
+        let codebase = Arc::new(codebase);
+        let references = codebase_service.build_references(&semantics, &codebase).await;
+
+        let plan = rename::plan(&self.interner, &semantics, &codebase, &references, &self.root, old, new)?;
+        if !plan.refusals.is_empty() {
+            return Ok(RenameResult { issues: plan.refusals, changed: 0, moved: None });
+        }
+
+        let mut changed = 0;
+        for (source, fix_plan) in plan.fix_plans {
+            let source = self.source_manager.load(&source)?;
+            let content = self.interner.lookup(&source.content);
+
+            if utils::apply_changes(
+                &self.interner,
+                &self.source_manager,
+                &source,
+                fix_plan.execute(content).get_fixed(),
+                dry_run,
+            )? {
+                changed += 1;
+            }
+        }
+
+        if let Some((from, to)) = &plan.moved_file {
+            if !dry_run {
+                move_file(from, to).map_err(|error| RefactorError::MovingFile(from.clone(), to.clone(), error))?;
+            }
+        }
+
+        Ok(RenameResult { issues: IssueCollection::new(), changed, moved: plan.moved_file })
+    }
+}
+
+fn move_file(from: &PathBuf, to: &PathBuf) -> std::io::Result<()> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::rename(from, to)
+}
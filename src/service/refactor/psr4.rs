@@ -0,0 +1,91 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use mago_composer::AutoloadPsr4value;
+use mago_composer::ComposerPackage;
+use mago_composer::ComposerPackageAutoloadDevPsr4value;
+
+/// A PSR-4 mapping of a namespace prefix to the directories containing the class-likes of that namespace.
+#[derive(Debug, Clone)]
+pub struct Psr4Mapping {
+    /// The namespace prefix, without leading or trailing backslashes.
+    pub prefix: String,
+    pub directories: Vec<PathBuf>,
+}
+
+/// Loads the PSR-4 mappings of the `autoload` and `autoload-dev` sections of the `composer.json`
+/// file in the given root directory.
+///
+/// Returns no mappings if the workspace does not contain a `composer.json` file, or if it cannot be parsed.
+pub fn load_mappings(root: &Path) -> Vec<Psr4Mapping> {
+    let Ok(content) = std::fs::read_to_string(root.join("composer.json")) else {
+        return vec![];
+    };
+
+    let package = match ComposerPackage::from_str(&content) {
+        Ok(package) => package,
+        Err(error) => {
+            mago_feedback::warn!("Failed to parse `composer.json`, PSR-4 mappings will be ignored: {}", error);
+
+            return vec![];
+        }
+    };
+
+    let mut mappings = vec![];
+    if let Some(autoload) = package.autoload {
+        for (prefix, value) in autoload.psr_4 {
+            let directories = match value {
+                AutoloadPsr4value::Array(directories) => directories,
+                AutoloadPsr4value::String(directory) => vec![directory],
+            };
+
+            mappings.push(create_mapping(root, prefix, directories));
+        }
+    }
+
+    if let Some(autoload_dev) = package.autoload_dev {
+        for (prefix, value) in autoload_dev.psr_4 {
+            let directories = match value {
+                ComposerPackageAutoloadDevPsr4value::Array(directories) => directories,
+                ComposerPackageAutoloadDevPsr4value::String(directory) => vec![directory],
+            };
+
+            mappings.push(create_mapping(root, prefix, directories));
+        }
+    }
+
+    // Longer prefixes are more specific, and take precedence.
+    mappings.sort_by_key(|mapping| std::cmp::Reverse(mapping.prefix.len()));
+
+    mappings
+}
+
+/// Returns the paths where the given class-like is expected to be declared, according to the given mappings.
+pub fn get_expected_paths(mappings: &[Psr4Mapping], class_like_name: &str) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for mapping in mappings {
+        let relative_name = if mapping.prefix.is_empty() {
+            class_like_name
+        } else {
+            match class_like_name.strip_prefix(&mapping.prefix).and_then(|name| name.strip_prefix('\\')) {
+                Some(relative_name) => relative_name,
+                None => continue,
+            }
+        };
+
+        let relative_path = format!("{}.php", relative_name.replace('\\', "/"));
+        for directory in &mapping.directories {
+            paths.push(directory.join(&relative_path));
+        }
+    }
+
+    paths
+}
+
+fn create_mapping(root: &Path, prefix: String, directories: Vec<String>) -> Psr4Mapping {
+    Psr4Mapping {
+        prefix: prefix.trim_matches('\\').to_string(),
+        directories: directories.into_iter().map(|directory| root.join(directory)).collect(),
+    }
+}
@@ -0,0 +1,1373 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::TriviaKind;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reference::symbol::Symbol;
+use mago_reference::ReferenceIndex;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+use mago_semantics::Semantics;
+use mago_source::SourceIdentifier;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::service::refactor::error::RefactorError;
+use crate::service::refactor::psr4;
+use crate::service::refactor::scope::*;
+
+/// The changes needed to rename a symbol.
+#[derive(Debug, Default)]
+pub struct RenamePlan {
+    /// The changes to apply, combined into a single plan per source.
+    pub fix_plans: Vec<(SourceIdentifier, FixPlan)>,
+    /// The references that cannot be safely renamed; when not empty, no changes should be applied.
+    pub refusals: IssueCollection,
+    /// The file declaring the renamed class-like, and the path it should be moved to according to PSR-4.
+    pub moved_file: Option<(PathBuf, PathBuf)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberKind {
+    Method,
+    Property,
+    Constant,
+}
+
+/// Plans the renaming of the symbol named `old` to `new`, across the given sources.
+///
+/// The old name uses the notation of [`Symbol::from_name`]; the new name is either the fully qualified name of
+/// a class-like, function or constant, or the name of a member, optionally prefixed by its class-like.
+pub fn plan(
+    interner: &ThreadedInterner,
+    semantics: &[Arc<Semantics>],
+    codebase: &CodebaseReflection,
+    references: &ReferenceIndex,
+    root: &Path,
+    old: &str,
+    new: &str,
+) -> Result<RenamePlan, RefactorError> {
+    let symbol =
+        Symbol::from_name(interner, codebase, old).ok_or_else(|| RefactorError::SymbolNotFound(old.to_string()))?;
+
+    if !symbol.get_declaration_span(codebase).is_some_and(|span| span.start.source.is_user_defined()) {
+        return Err(RefactorError::ExternalSymbol(symbol.get_key(interner)));
+    }
+
+    let mut renamer = Renamer::new(interner, semantics, codebase, references, root);
+
+    // References within sources that failed to parse cannot be found.
+    for semantics in semantics {
+        if let Some(error) = &semantics.parse_error {
+            renamer.refusals.push(Into::<Issue>::into(error));
+        }
+    }
+
+    match symbol {
+        Symbol::ClassLike(name) => {
+            let class_like =
+                codebase.get_class_like(name).ok_or_else(|| RefactorError::SymbolNotFound(old.to_string()))?;
+
+            renamer.rename_class_like(class_like, new.trim().trim_start_matches('\\'))?;
+        }
+        Symbol::Function(name) => {
+            let new = new.trim().trim_start_matches('\\');
+
+            renamer.rename_function_or_constant(ImportKind::Function, name, new.strip_suffix("()").unwrap_or(new))?;
+        }
+        Symbol::Constant(name) => {
+            renamer.rename_function_or_constant(ImportKind::Constant, name, new.trim().trim_start_matches('\\'))?;
+        }
+        Symbol::Method(class_like_name, name) => {
+            renamer.rename_member(MemberKind::Method, class_like_name, name, old, new)?;
+        }
+        Symbol::Property(class_like_name, name) => {
+            renamer.rename_member(MemberKind::Property, class_like_name, name, old, new)?;
+        }
+        Symbol::ClassLikeConstant(class_like_name, name) => {
+            renamer.rename_member(MemberKind::Constant, class_like_name, name, old, new)?;
+        }
+    }
+
+    Ok(renamer.finish())
+}
+
+struct Renamer<'a> {
+    interner: &'a ThreadedInterner,
+    codebase: &'a CodebaseReflection,
+    references: &'a ReferenceIndex,
+    root: &'a Path,
+    sources: HashMap<SourceIdentifier, (&'a Semantics, ProgramScopes)>,
+    namespace_overrides: HashMap<SourceIdentifier, String>,
+    edits: HashMap<SourceIdentifier, BTreeMap<Span, String>>,
+    refusals: IssueCollection,
+    moved_file: Option<(PathBuf, PathBuf)>,
+}
+
+impl<'a> Renamer<'a> {
+    fn new(
+        interner: &'a ThreadedInterner,
+        semantics: &'a [Arc<Semantics>],
+        codebase: &'a CodebaseReflection,
+        references: &'a ReferenceIndex,
+        root: &'a Path,
+    ) -> Self {
+        let sources = semantics
+            .iter()
+            .map(|semantics| {
+                (semantics.source.identifier, (semantics.as_ref(), ProgramScopes::new(interner, &semantics.program)))
+            })
+            .collect();
+
+        Self {
+            interner,
+            codebase,
+            references,
+            root,
+            sources,
+            namespace_overrides: HashMap::default(),
+            edits: HashMap::default(),
+            refusals: IssueCollection::new(),
+            moved_file: None,
+        }
+    }
+
+    fn rename_class_like(&mut self, class_like: &ClassLikeReflection, new_name: &str) -> Result<(), RefactorError> {
+        let Some(old) = class_like.name.inner() else {
+            return Err(RefactorError::SymbolNotFound(class_like.name.get_key(self.interner)));
+        };
+
+        let old_name = self.interner.lookup(&old.value);
+        if !is_valid_name(new_name) {
+            return Err(RefactorError::InvalidName(new_name.to_string()));
+        }
+
+        if let Some(Symbol::ClassLike(existing)) = Symbol::from_name(self.interner, self.codebase, new_name) {
+            if existing != class_like.name {
+                return Err(RefactorError::SymbolAlreadyExists(existing.get_key(self.interner)));
+            }
+        }
+
+        let source = old.span.start.source;
+        let (old_namespace, _) = split_name(old_name);
+        let (new_namespace, new_short_name) = split_name(new_name);
+        let is_alone = self.is_alone_in_source(source);
+
+        self.add_edit(old.span, new_short_name);
+        if old_namespace != new_namespace {
+            self.change_namespace(source, old_name, new_namespace, is_alone)?;
+        }
+
+        for span in self.references.get_references(&Symbol::ClassLike(class_like.name)) {
+            self.rename_reference(*span, ImportKind::ClassLike, new_name);
+        }
+
+        for (span, name) in self.get_docblock_names() {
+            let scope = self.get_scope_at(span);
+            if scope.resolve_class_like_name(name).eq_ignore_ascii_case(old_name) {
+                self.rename_reference(span, ImportKind::ClassLike, new_name);
+            }
+        }
+
+        self.rename_string_references(old_name, new_name, old.span, "Use a `::class` constant instead of the string.");
+
+        if is_alone {
+            self.move_file(source, old_name, new_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn rename_function_or_constant(
+        &mut self,
+        kind: ImportKind,
+        name: StringIdentifier,
+        new_name: &str,
+    ) -> Result<(), RefactorError> {
+        let (symbol, existing) = match kind {
+            ImportKind::Function => (
+                Symbol::Function(name),
+                Symbol::from_name(self.interner, self.codebase, &format!("{}()", new_name))
+                    .map(|symbol| (symbol, "()")),
+            ),
+            _ => (
+                Symbol::Constant(name),
+                self.codebase
+                    .get_constant(&self.interner.intern(new_name))
+                    .map(|constant| (Symbol::Constant(constant.name.value), "")),
+            ),
+        };
+
+        let old_name = self.interner.lookup(&name);
+        if !is_valid_name(new_name) {
+            return Err(RefactorError::InvalidName(new_name.to_string()));
+        }
+
+        if let Some((existing, suffix)) = existing {
+            if existing != symbol {
+                return Err(RefactorError::SymbolAlreadyExists(format!("{}{}", new_name, suffix)));
+            }
+        }
+
+        if !split_name(old_name).0.eq_ignore_ascii_case(split_name(new_name).0) {
+            return Err(RefactorError::UnsupportedRename(
+                symbol.get_key(self.interner),
+                "functions and constants can only be renamed within their namespace".to_string(),
+            ));
+        }
+
+        let Some(declaration) = symbol.get_declaration_span(self.codebase) else {
+            return Err(RefactorError::SymbolNotFound(symbol.get_key(self.interner)));
+        };
+
+        // Constants defined using `define()` are declared by their fully qualified name, in a string.
+        let declaration_text = self.get_text(declaration);
+        if declaration_text.starts_with(['\'', '"']) {
+            self.add_edit(declaration, &get_string_replacement(declaration_text, new_name));
+        } else {
+            self.add_edit(declaration, split_name(new_name).1);
+        }
+
+        for span in self.references.get_references(&symbol) {
+            self.rename_reference(*span, kind, new_name);
+        }
+
+        let help = match kind {
+            ImportKind::Function => "Use a first-class callable, e.g. `foo(...)`, instead of the string.",
+            _ => "Reference the constant directly instead of using its name.",
+        };
+
+        self.rename_string_references(old_name, new_name, declaration, help);
+
+        Ok(())
+    }
+
+    fn rename_member(
+        &mut self,
+        kind: MemberKind,
+        class_like_name: ClassLikeName,
+        name: StringIdentifier,
+        old: &str,
+        new: &str,
+    ) -> Result<(), RefactorError> {
+        let symbol = get_member_symbol(kind, class_like_name, name);
+        let key = symbol.get_key(self.interner);
+        let old_member_name = self.interner.lookup(&name);
+        let new_member_name = parse_new_member_name(kind, old, new, &key)?;
+
+        if kind == MemberKind::Method && (old_member_name.starts_with("__") || new_member_name.starts_with("__")) {
+            return Err(RefactorError::UnsupportedRename(key, "magic methods cannot be renamed".to_string()));
+        }
+
+        let family = self.get_member_family(kind, class_like_name, old_member_name, &key)?;
+        for (family_member, member) in &family {
+            let Some(class_like) = self.codebase.get_class_like(*family_member) else {
+                continue;
+            };
+
+            if kind == MemberKind::Property
+                && class_like.properties.members.get(member).is_some_and(|property| property.is_promoted)
+            {
+                return Err(RefactorError::UnsupportedRename(
+                    key,
+                    "promoted properties cannot be renamed, as they are also constructor parameters".to_string(),
+                ));
+            }
+
+            let descendants = class_like
+                .name
+                .inner()
+                .and_then(|name| self.codebase.all_classlike_descendants.get(&name.value))
+                .into_iter()
+                .flatten()
+                .filter_map(|name| self.codebase.get_named_class_like(name));
+
+            for class_like in std::iter::once(class_like).chain(descendants) {
+                if self.has_member(kind, class_like, &new_member_name, old_member_name) {
+                    return Err(RefactorError::SymbolAlreadyExists(format!(
+                        "{}::{}",
+                        class_like.name.get_key(self.interner),
+                        new_member_name
+                    )));
+                }
+            }
+        }
+
+        let plain_name = old_member_name.trim_start_matches('$');
+        let unresolved_accesses = self.references.get_unresolved_member_accesses(self.interner, plain_name);
+        self.refuse_dynamic_references(
+            unresolved_accesses,
+            format!("`{}` may be accessed on objects of unknown type.", key),
+            format!("The type of this object is unknown, so this may access `{}`.", key),
+            "Add a type to the object, e.g. using a `@var` docblock, and try again.",
+        );
+
+        let dynamic_accesses = self
+            .references
+            .get_dynamic_member_accesses()
+            .iter()
+            .filter(|(_, class_likes)| {
+                class_likes.is_empty() || class_likes.iter().any(|class_like| self.is_in_family(*class_like, &family))
+            })
+            .map(|(span, _)| *span)
+            .collect::<Vec<_>>();
+
+        self.refuse_dynamic_references(
+            &dynamic_accesses,
+            format!("`{}` may be accessed dynamically.", key),
+            format!("The name of this member is only known at runtime, so this may access `{}`.", key),
+            "Access the member by its name instead, and try again.",
+        );
+
+        if kind != MemberKind::Constant {
+            let strings = self.references.get_string_references(self.interner, plain_name);
+            self.refuse_dynamic_references(
+                strings,
+                format!("`{}` may be referenced dynamically.", key),
+                format!("This string may refer to `{}`.", key),
+                "Access the member directly instead of using its name, and try again.",
+            );
+        }
+
+        for (class_like_name, name) in family {
+            let symbol = get_member_symbol(kind, class_like_name, name);
+            if let Some(span) = symbol.get_declaration_span(self.codebase) {
+                self.add_member_edit(span, &new_member_name);
+            }
+
+            for span in self.references.get_references(&symbol) {
+                self.add_member_edit(*span, &new_member_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the members that must be renamed along with the given one: the members of its ancestors it
+    /// overrides, and the members of its descendants that override it, transitively.
+    fn get_member_family(
+        &self,
+        kind: MemberKind,
+        class_like_name: ClassLikeName,
+        member_name: &str,
+        key: &str,
+    ) -> Result<Vec<(ClassLikeName, StringIdentifier)>, RefactorError> {
+        let mut family = vec![];
+        let mut visited = HashSet::default();
+        let mut queue = vec![class_like_name];
+
+        while let Some(name) = queue.pop() {
+            if !visited.insert(name) {
+                continue;
+            }
+
+            let Some(class_like) = self.codebase.get_class_like(name) else {
+                continue;
+            };
+
+            let Some(member) = self.get_own_member(kind, class_like, member_name) else {
+                continue;
+            };
+
+            if class_like.span.start.source.is_external() {
+                return Err(RefactorError::UnsupportedRename(
+                    key.to_string(),
+                    format!(
+                        "it is related to `{}`, which is declared outside of the project",
+                        get_member_symbol(kind, name, member).get_key(self.interner)
+                    ),
+                ));
+            }
+
+            family.push((name, member));
+
+            let inheritance = &class_like.inheritance;
+            let ancestors = inheritance
+                .all_extended_classes
+                .iter()
+                .chain(inheritance.all_implemented_interfaces.iter())
+                .chain(inheritance.all_extended_interfaces.iter())
+                .map(|name| name.value)
+                .chain(class_like.used_traits.iter().copied());
+
+            for ancestor in ancestors {
+                match self.codebase.get_named_class_like(&ancestor) {
+                    Some(ancestor) => queue.push(ancestor.name),
+                    None => {
+                        return Err(RefactorError::UnsupportedRename(
+                            key.to_string(),
+                            format!(
+                                "`{}` has an unknown ancestor `{}`, which may declare it",
+                                class_like.name.get_key(self.interner),
+                                self.interner.lookup(&ancestor)
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            let descendants = class_like
+                .name
+                .inner()
+                .and_then(|name| self.codebase.all_classlike_descendants.get(&name.value))
+                .into_iter()
+                .flatten()
+                .filter_map(|name| self.codebase.get_named_class_like(name));
+
+            queue.extend(descendants.map(|descendant| descendant.name));
+        }
+
+        Ok(family)
+    }
+
+    /// Determines whether the members of the given class-like include a member of the given family, either
+    /// declared by the class-like itself or inherited from one of its ancestors.
+    fn is_in_family(&self, class_like_name: ClassLikeName, family: &[(ClassLikeName, StringIdentifier)]) -> bool {
+        let Some(class_like) = self.codebase.get_class_like(class_like_name) else {
+            return true;
+        };
+
+        let inheritance = &class_like.inheritance;
+        let ancestors = inheritance
+            .all_extended_classes
+            .iter()
+            .chain(inheritance.all_implemented_interfaces.iter())
+            .chain(inheritance.all_extended_interfaces.iter())
+            .map(|name| name.value)
+            .chain(class_like.used_traits.iter().copied())
+            .filter_map(|name| self.codebase.get_named_class_like(&name))
+            .map(|ancestor| ancestor.name);
+
+        std::iter::once(class_like.name)
+            .chain(ancestors)
+            .any(|name| family.iter().any(|(family_member, _)| *family_member == name))
+    }
+
+    /// Returns the key of the member with the given name declared by the given class-like itself.
+    fn get_own_member(
+        &self,
+        kind: MemberKind,
+        class_like: &ClassLikeReflection,
+        name: &str,
+    ) -> Option<StringIdentifier> {
+        let lookup = |key: &&StringIdentifier| self.interner.lookup(key) == name;
+
+        match kind {
+            MemberKind::Method => class_like
+                .methods
+                .members
+                .keys()
+                .find(|key| self.interner.lookup(key).eq_ignore_ascii_case(name))
+                .copied(),
+            MemberKind::Property => class_like.properties.members.keys().find(lookup).copied(),
+            MemberKind::Constant => class_like
+                .constants
+                .iter()
+                .filter(|(_, constant)| constant.name.class_like == class_like.name)
+                .map(|(key, _)| key)
+                .find(lookup)
+                .or_else(|| class_like.cases.members.keys().find(lookup))
+                .copied(),
+        }
+    }
+
+    /// Determines whether the given class-like has a member with the given name, other than the one being renamed.
+    fn has_member(&self, kind: MemberKind, class_like: &ClassLikeReflection, name: &str, old_name: &str) -> bool {
+        match kind {
+            MemberKind::Method => {
+                !name.eq_ignore_ascii_case(old_name)
+                    && class_like
+                        .methods
+                        .appering_members
+                        .keys()
+                        .any(|key| self.interner.lookup(key).eq_ignore_ascii_case(name))
+            }
+            MemberKind::Property => {
+                class_like.properties.appering_members.keys().any(|key| self.interner.lookup(key) == name)
+            }
+            MemberKind::Constant => class_like
+                .constants
+                .keys()
+                .chain(class_like.cases.appering_members.keys())
+                .any(|key| self.interner.lookup(key) == name),
+        }
+    }
+
+    /// Changes the namespace of the file declaring the renamed class-like.
+    ///
+    /// Names in the file that were resolved relative to the old namespace are fully qualified, so that they
+    /// keep referring to the same symbols.
+    fn change_namespace(
+        &mut self,
+        source: SourceIdentifier,
+        old_name: &str,
+        new_namespace: &str,
+        is_alone: bool,
+    ) -> Result<(), RefactorError> {
+        let unsupported = |reason: &str| RefactorError::UnsupportedRename(old_name.to_string(), reason.to_string());
+
+        if !is_alone {
+            return Err(unsupported(
+                "its namespace can only be changed when it is the only symbol declared in its file",
+            ));
+        }
+
+        let Some((semantics, scopes)) = self.sources.get(&source) else {
+            return Err(unsupported("its file could not be found"));
+        };
+
+        let [scope] = scopes.namespaces.as_slice() else {
+            return Err(unsupported("its file declares several namespaces"));
+        };
+
+        let (Some(name_span), false) = (scope.name_span, new_namespace.is_empty()) else {
+            return Err(unsupported("class-likes cannot be moved from or to the global namespace"));
+        };
+
+        let old_namespace = scope.name.clone();
+        let content = self.interner.lookup(&semantics.source.content);
+
+        let mut edits = vec![(name_span, new_namespace.to_string())];
+        for (offset, (resolved, is_imported)) in semantics.names.all() {
+            let name = get_name_at(content, *offset);
+            if *is_imported || name.is_empty() || name.starts_with('\\') {
+                continue;
+            }
+
+            let resolved = self.interner.lookup(resolved);
+            if resolved.eq_ignore_ascii_case(old_name)
+                || !resolved.eq_ignore_ascii_case(&qualify(&old_namespace, name))
+                || !self.symbol_exists(resolved)
+            {
+                continue;
+            }
+
+            let span = Span::new(Position::new(source, *offset), Position::new(source, offset + name.len()));
+
+            edits.push((span, format!("\\{}", resolved)));
+        }
+
+        for (span, name) in self.get_docblock_names() {
+            if span.start.source != source
+                || name.starts_with('\\')
+                || scope.get_import(ImportKind::ClassLike, name.split('\\').next().unwrap_or(name)).is_some()
+            {
+                continue;
+            }
+
+            let resolved = qualify(&old_namespace, name);
+            if !resolved.eq_ignore_ascii_case(old_name)
+                && Symbol::from_name(self.interner, self.codebase, &resolved)
+                    .is_some_and(|symbol| matches!(symbol, Symbol::ClassLike(_)))
+            {
+                edits.push((span, format!("\\{}", resolved)));
+            }
+        }
+
+        for (span, replacement) in edits {
+            self.add_edit(span, &replacement);
+        }
+
+        self.namespace_overrides.insert(source, new_namespace.to_string());
+
+        Ok(())
+    }
+
+    /// Moves the file declaring the renamed class-like, if it is autoloaded using a PSR-4 mapping.
+    fn move_file(&mut self, source: SourceIdentifier, old_name: &str, new_name: &str) -> Result<(), RefactorError> {
+        let Some(path) = self.sources.get(&source).and_then(|(semantics, _)| semantics.source.path.as_ref()) else {
+            return Ok(());
+        };
+
+        let Ok(path) = path.canonicalize() else {
+            return Ok(());
+        };
+
+        let mappings = psr4::load_mappings(self.root);
+        let is_autoloaded = psr4::get_expected_paths(&mappings, old_name)
+            .iter()
+            .any(|expected_path| expected_path.canonicalize().is_ok_and(|expected_path| expected_path == path));
+
+        if !is_autoloaded {
+            return Ok(());
+        }
+
+        let Some(new_path) = psr4::get_expected_paths(&mappings, new_name).into_iter().next() else {
+            mago_feedback::warn!(
+                "`{}` is not covered by the PSR-4 mappings of `composer.json`, its file will not be moved.",
+                new_name
+            );
+
+            return Ok(());
+        };
+
+        let new_path = match (new_path.parent().and_then(|parent| parent.canonicalize().ok()), new_path.file_name()) {
+            (Some(parent), Some(file_name)) => parent.join(file_name),
+            _ => new_path,
+        };
+
+        if new_path == path {
+            return Ok(());
+        }
+
+        if new_path.exists() && !new_path.canonicalize().is_ok_and(|new_path| new_path == path) {
+            return Err(RefactorError::UnsupportedRename(
+                old_name.to_string(),
+                format!("'{}' already exists", new_path.display()),
+            ));
+        }
+
+        self.moved_file = Some((path, new_path));
+
+        Ok(())
+    }
+
+    fn rename_reference(&mut self, span: Span, kind: ImportKind, new_name: &str) {
+        let Some((_, scopes)) = self.sources.get(&span.start.source) else {
+            return;
+        };
+
+        let text = self.get_text(span);
+        if let Some(import) = scopes.get_import_at(span).filter(|import| import.kind == kind).cloned() {
+            self.rename_import(&import, text, new_name);
+
+            return;
+        }
+
+        let replacement = self.get_replacement(span, text, kind, new_name);
+
+        self.add_edit(span, &replacement);
+    }
+
+    fn rename_import(&mut self, import: &Import, text: &str, new_name: &str) {
+        let replacement = match &import.prefix {
+            Some(prefix) => match strip_namespace_prefix(new_name, prefix) {
+                Some(name) => name.to_string(),
+                None => {
+                    self.refuse(
+                        import.span,
+                        format!("The grouped import of `{}` cannot be updated.", import.name),
+                        format!("`{}` is not within the `{}` namespace.", new_name, prefix),
+                        "Import the symbol separately, and try again.",
+                    );
+
+                    return;
+                }
+            },
+            None if text.starts_with('\\') => format!("\\{}", new_name),
+            None => new_name.to_string(),
+        };
+
+        self.add_edit(import.span, &replacement);
+
+        let new_short_name = split_name(new_name).1;
+        if import.has_explicit_alias || import.alias.eq_ignore_ascii_case(new_short_name) {
+            return;
+        }
+
+        let conflict = self
+            .get_scope_at(import.span)
+            .get_import(import.kind, new_short_name)
+            .filter(|conflict| conflict.span != import.span)
+            .map(|conflict| conflict.name.clone());
+
+        if let Some(conflict) = conflict {
+            self.refuse(
+                import.span,
+                format!("Importing `{}` would conflict with the import of `{}`.", new_name, conflict),
+                format!("`{}` is already imported as `{}`.", conflict, new_short_name),
+                "Alias one of the imports, and try again.",
+            );
+        }
+    }
+
+    /// Returns the text replacing a reference to the renamed class-like, function or constant, written as `text`.
+    fn get_replacement(&self, span: Span, text: &str, kind: ImportKind, new_name: &str) -> String {
+        let (new_namespace, new_short_name) = split_name(new_name);
+        let fully_qualified_name = format!("\\{}", new_name);
+        if text.contains('\\') {
+            return fully_qualified_name;
+        }
+
+        let scope = self.get_scope_at(span);
+        if let Some(import) = scope.get_import(kind, text) {
+            return if import.has_explicit_alias { text.to_string() } else { new_short_name.to_string() };
+        }
+
+        // The new name must not be shadowed by an import of another symbol.
+        if scope.get_import(kind, new_short_name).is_some() {
+            return fully_qualified_name;
+        }
+
+        let namespace = self.namespace_overrides.get(&span.start.source).unwrap_or(&scope.name);
+        if namespace.eq_ignore_ascii_case(new_namespace) {
+            return new_short_name.to_string();
+        }
+
+        // Unqualified functions and constants fall back to the global namespace.
+        if kind != ImportKind::ClassLike && new_namespace.is_empty() {
+            let local_name = qualify(namespace, new_short_name);
+            let is_shadowed = match kind {
+                ImportKind::Function => {
+                    Symbol::from_name(self.interner, self.codebase, &format!("{}()", local_name)).is_some()
+                }
+                _ => self.codebase.get_constant(&self.interner.intern(&local_name)).is_some(),
+            };
+
+            if !is_shadowed {
+                return new_short_name.to_string();
+            }
+        }
+
+        fully_qualified_name
+    }
+
+    fn rename_string_references(&mut self, old_name: &str, new_name: &str, declaration: Span, help: &str) {
+        let spans = self
+            .references
+            .get_string_references(self.interner, old_name)
+            .iter()
+            .filter(|span| {
+                span.start.source != declaration.start.source
+                    || span.start.offset < declaration.start.offset
+                    || span.end.offset > declaration.end.offset
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        // Strings containing a namespaced name are assumed to refer to the renamed symbol, while
+        // strings containing a single word may as well be anything else.
+        if !old_name.contains('\\') {
+            self.refuse_dynamic_references(
+                &spans,
+                format!("`{}` may be referenced dynamically.", old_name),
+                format!("This string may refer to `{}`.", old_name),
+                help,
+            );
+
+            return;
+        }
+
+        for span in spans {
+            let replacement = get_string_replacement(self.get_text(span), new_name);
+
+            self.add_edit(span, &replacement);
+        }
+    }
+
+    fn refuse_dynamic_references(&mut self, spans: &[Span], message: String, annotation: String, help: &str) {
+        let Some((first, rest)) = spans.split_first() else {
+            return;
+        };
+
+        let issue = Issue::new(Level::Error, message)
+            .with_annotation(Annotation::primary(*first).with_message(annotation))
+            .with_annotations(rest.iter().map(|span| Annotation::secondary(*span)))
+            .with_note(
+                "The rename was aborted, as it cannot be proven that these references are safe to leave as they are.",
+            )
+            .with_help(help);
+
+        self.refusals.push(issue);
+    }
+
+    fn refuse(&mut self, span: Span, message: String, annotation: String, help: &str) {
+        self.refusals.push(
+            Issue::new(Level::Error, message)
+                .with_annotation(Annotation::primary(span).with_message(annotation))
+                .with_help(help),
+        );
+    }
+
+    /// Collects the names written in the tags of the docblocks of every source, e.g. the types in `@param` tags.
+    fn get_docblock_names(&self) -> Vec<(Span, &'a str)> {
+        let mut names = vec![];
+        for (source, (semantics, _)) in &self.sources {
+            let content = self.interner.lookup(&semantics.source.content);
+
+            for trivia in semantics.program.trivia.iter() {
+                if !matches!(trivia.kind, TriviaKind::DocBlockComment) {
+                    continue;
+                }
+
+                let mut offset = trivia.span.start.offset;
+                for line in content[trivia.span.start.offset..trivia.span.end.offset].split_inclusive('\n') {
+                    let line_offset = offset;
+                    offset += line.len();
+
+                    let tag =
+                        line.trim_start().trim_start_matches("/**").trim_start().trim_start_matches('*').trim_start();
+                    if !tag.starts_with('@') {
+                        continue;
+                    }
+
+                    let mut start = None;
+                    for (index, character) in line.char_indices().chain(std::iter::once((line.len(), ' '))) {
+                        if is_name_character(character) {
+                            start.get_or_insert(index);
+
+                            continue;
+                        }
+
+                        let Some(start) = start.take() else {
+                            continue;
+                        };
+
+                        let preceding = &line[..start];
+                        if preceding.ends_with('$') || preceding.ends_with("->") || preceding.ends_with("::") {
+                            continue;
+                        }
+
+                        let name = line[start..index].trim_end_matches('\\');
+                        if name
+                            .trim_start_matches('\\')
+                            .starts_with(|character: char| character.is_ascii_alphabetic() || character == '_')
+                        {
+                            let start = Position::new(*source, line_offset + start);
+
+                            names.push((Span::new(start, start.forward(name.len())), name));
+                        }
+                    }
+                }
+            }
+        }
+
+        names
+    }
+
+    fn add_member_edit(&mut self, span: Span, new_member_name: &str) {
+        let new_member_name = new_member_name.trim_start_matches('$');
+        let replacement = if self.get_text(span).starts_with('$') {
+            format!("${}", new_member_name)
+        } else {
+            new_member_name.to_string()
+        };
+
+        self.add_edit(span, &replacement);
+    }
+
+    fn add_edit(&mut self, span: Span, replacement: &str) {
+        if self.get_text(span) == replacement {
+            return;
+        }
+
+        self.edits.entry(span.start.source).or_default().insert(span, replacement.to_string());
+    }
+
+    fn get_text(&self, span: Span) -> &'a str {
+        match self.sources.get(&span.start.source) {
+            Some((semantics, _)) => {
+                &self.interner.lookup(&semantics.source.content)[span.start.offset..span.end.offset]
+            }
+            None => "",
+        }
+    }
+
+    fn get_scope_at(&self, span: Span) -> &NamespaceScope {
+        self.sources[&span.start.source].1.get_scope_at(span.start.offset)
+    }
+
+    fn symbol_exists(&self, name: &str) -> bool {
+        Symbol::from_name(self.interner, self.codebase, name).is_some()
+            || Symbol::from_name(self.interner, self.codebase, &format!("{}()", name)).is_some()
+    }
+
+    /// Determines whether the given source declares a single class-like, function or constant.
+    fn is_alone_in_source(&self, source: SourceIdentifier) -> bool {
+        let class_likes = self
+            .codebase
+            .class_like_reflections
+            .values()
+            .filter(|class_like| !class_like.is_anonymous && class_like.span.start.source == source)
+            .count();
+
+        let functions = self
+            .codebase
+            .function_like_reflections
+            .values()
+            .filter(|function| {
+                matches!(function.name, FunctionLikeName::Function(_)) && function.span.start.source == source
+            })
+            .count();
+
+        let constants = self
+            .codebase
+            .constant_reflections
+            .values()
+            .filter(|constant| constant.name.span.start.source == source)
+            .count();
+
+        class_likes + functions + constants == 1
+    }
+
+    fn finish(self) -> RenamePlan {
+        let mut fix_plans = self
+            .edits
+            .into_iter()
+            .map(|(source, edits)| {
+                let mut plan = FixPlan::new();
+                for (span, replacement) in edits {
+                    plan.replace(span.to_range(), replacement, SafetyClassification::Safe);
+                }
+
+                (source, plan)
+            })
+            .collect::<Vec<_>>();
+
+        fix_plans.sort_by_key(|(source, _)| *source);
+
+        RenamePlan { fix_plans, refusals: self.refusals, moved_file: self.moved_file }
+    }
+}
+
+fn get_member_symbol(kind: MemberKind, class_like_name: ClassLikeName, name: StringIdentifier) -> Symbol {
+    match kind {
+        MemberKind::Method => Symbol::Method(class_like_name, name),
+        MemberKind::Property => Symbol::Property(class_like_name, name),
+        MemberKind::Constant => Symbol::ClassLikeConstant(class_like_name, name),
+    }
+}
+
+/// Parses the new name of a member, which may be prefixed by the name of its class-like, returning it
+/// in the notation of the codebase reflection, e.g. with a leading `$` for properties.
+fn parse_new_member_name(kind: MemberKind, old: &str, new: &str, key: &str) -> Result<String, RefactorError> {
+    let new = new.trim();
+    let member_name = match new.split_once("::") {
+        Some((class_like_name, member_name)) => {
+            let old_class_like_name = old.trim().split_once("::").map(|(name, _)| name).unwrap_or_default();
+            if !class_like_name
+                .trim_start_matches('\\')
+                .eq_ignore_ascii_case(old_class_like_name.trim_start_matches('\\'))
+            {
+                return Err(RefactorError::UnsupportedRename(
+                    key.to_string(),
+                    "members can only be renamed within their class-like".to_string(),
+                ));
+            }
+
+            member_name
+        }
+        None => new,
+    };
+
+    let member_name = match kind {
+        MemberKind::Method => member_name.strip_suffix("()").unwrap_or(member_name),
+        MemberKind::Property => member_name.strip_prefix('$').unwrap_or(member_name),
+        MemberKind::Constant => member_name,
+    };
+
+    if member_name.contains('\\') || !is_valid_name(member_name) {
+        return Err(RefactorError::InvalidName(new.to_string()));
+    }
+
+    Ok(match kind {
+        MemberKind::Property => format!("${}", member_name),
+        _ => member_name.to_string(),
+    })
+}
+
+/// Returns the string literal `literal`, with its value replaced by `new_name`, preserving its quotes and the escaping
+/// of its backslashes.
+fn get_string_replacement(literal: &str, new_name: &str) -> String {
+    let quote = &literal[..1];
+    let value = &literal[1..literal.len() - 1];
+    let name = value.trim_start_matches('\\');
+    let leading_backslashes = &value[..value.len() - name.len()];
+
+    // In double-quoted strings, a backslash followed by some characters forms an escape sequence.
+    let forms_escape_sequence = quote == "\""
+        && new_name.split('\\').skip(1).any(|segment| segment.starts_with(['n', 'r', 't', 'v', 'e', 'f', 'x', 'u']));
+
+    if name.contains("\\\\") || forms_escape_sequence {
+        format!("{}{}{}{}", quote, leading_backslashes, new_name.replace('\\', "\\\\"), quote)
+    } else {
+        format!("{}{}{}{}", quote, leading_backslashes, new_name, quote)
+    }
+}
+
+/// Strips the given namespace prefix from the given name, case-insensitively.
+fn strip_namespace_prefix<'n>(name: &'n str, prefix: &str) -> Option<&'n str> {
+    let (name_prefix, rest) = name.split_at_checked(prefix.len())?;
+
+    if name_prefix.eq_ignore_ascii_case(prefix) {
+        rest.strip_prefix('\\')
+    } else {
+        None
+    }
+}
+
+/// Returns the name written at the given offset of the given content.
+fn get_name_at(content: &str, offset: usize) -> &str {
+    let name = &content[offset..];
+    let length = name.find(|character| !is_name_character(character)).unwrap_or(name.len());
+
+    &name[..length]
+}
+
+fn is_name_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_' || character == '\\' || !character.is_ascii()
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('\\').all(|segment| {
+            let mut characters = segment.chars();
+
+            characters
+                .next()
+                .is_some_and(|character| character.is_ascii_alphabetic() || character == '_' || !character.is_ascii())
+                && characters
+                    .all(|character| character.is_ascii_alphanumeric() || character == '_' || !character.is_ascii())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use mago_interner::ThreadedInterner;
+    use mago_reference::ReferenceIndex;
+    use mago_reflection::CodebaseReflection;
+    use mago_semantics::Semantics;
+    use mago_source::SourceCategory;
+    use mago_source::SourceManager;
+
+    use crate::service::refactor::RefactorService;
+    use crate::service::refactor::RenameResult;
+
+    use super::plan;
+    use super::RenamePlan;
+
+    /// A temporary project directory, removed once dropped.
+    struct Project {
+        root: PathBuf,
+    }
+
+    impl Project {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let root = std::env::temp_dir().join(format!("mago-rename-{}-{}", std::process::id(), name));
+            let _ = std::fs::remove_dir_all(&root);
+
+            for (path, content) in files {
+                let path = root.join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, content).unwrap();
+            }
+
+            Self { root: root.canonicalize().unwrap() }
+        }
+
+        /// Plans the rename, and returns the plan along with the new contents of the changed files.
+        fn rename(&self, old: &str, new: &str) -> (RenamePlan, Vec<(String, String)>) {
+            let interner = ThreadedInterner::new();
+            let manager = SourceManager::new(interner.clone());
+
+            let mut paths = vec![];
+            collect_php_files(&self.root, &mut paths);
+            paths.sort();
+            for path in paths {
+                let name = path.strip_prefix(&self.root).unwrap().to_string_lossy().to_string();
+                manager.insert_path(name, path, SourceCategory::UserDefined);
+            }
+
+            let mut semantics = vec![];
+            let mut codebase = CodebaseReflection::new();
+            for source_id in manager.source_ids().collect::<Vec<_>>() {
+                let source_semantics = Semantics::build(&interner, manager.load(&source_id).unwrap());
+                codebase = mago_reflector::merge(codebase, mago_reflector::reflect(&interner, &source_semantics));
+                semantics.push(Arc::new(source_semantics));
+            }
+
+            mago_reflector::populate(&interner, &mut codebase);
+
+            let mut references = ReferenceIndex::new();
+            for semantics in &semantics {
+                references.extend(ReferenceIndex::build(&interner, semantics, &codebase));
+            }
+
+            let plan = plan(&interner, &semantics, &codebase, &references, &self.root, old, new).unwrap();
+            let changes = plan
+                .fix_plans
+                .iter()
+                .map(|(source_id, fix_plan)| {
+                    let source = manager.load(source_id).unwrap();
+                    let content = interner.lookup(&source.content);
+
+                    (interner.lookup(&source.identifier.0).to_string(), fix_plan.clone().execute(content).get_fixed())
+                })
+                .collect();
+
+            (plan, changes)
+        }
+    }
+
+    impl Project {
+        /// Renames the symbol using the refactor service, writing the changes to disk.
+        fn rename_on_disk(&self, old: &str, new: &str) -> RenameResult {
+            let interner = ThreadedInterner::new();
+            let manager = SourceManager::new(interner.clone());
+
+            let mut paths = vec![];
+            collect_php_files(&self.root, &mut paths);
+            for path in paths {
+                let name = path.strip_prefix(&self.root).unwrap().to_string_lossy().to_string();
+                manager.insert_path(name, path, SourceCategory::UserDefined);
+            }
+
+            let service = RefactorService::new(interner, manager, self.root.clone());
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+            runtime.block_on(service.rename(old, new, false)).unwrap()
+        }
+
+        fn read(&self, path: &str) -> String {
+            std::fs::read_to_string(self.root.join(path)).unwrap()
+        }
+    }
+
+    impl Drop for Project {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn collect_php_files(directory: &Path, paths: &mut Vec<PathBuf>) {
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                collect_php_files(&path, paths);
+            } else if path.extension().is_some_and(|extension| extension == "php") {
+                paths.push(path);
+            }
+        }
+    }
+
+    const COMPOSER: &str = r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#;
+
+    #[test]
+    fn test_rename_inherited_method() {
+        let project = Project::new(
+            "inherited-method",
+            &[
+                (
+                    "src/Shape.php",
+                    "<?php\n\nnamespace App;\n\ninterface Shape\n{\n    public function area(): float;\n}\n",
+                ),
+                (
+                    "src/Square.php",
+                    "<?php\n\nnamespace App;\n\nclass Square implements Shape\n{\n    public function area(): float\n    {\n        return 1.0;\n    }\n}\n",
+                ),
+                (
+                    "src/Big.php",
+                    "<?php\n\nnamespace App;\n\nfinal class Big extends Square\n{\n    public function area(): float\n    {\n        return parent::area() * 2;\n    }\n}\n",
+                ),
+                (
+                    "src/main.php",
+                    "<?php\n\nnamespace App;\n\nfunction total(Shape $shape, Big $big): float\n{\n    return $shape->area() + $big->AREA();\n}\n",
+                ),
+            ],
+        );
+
+        // Renaming through a descendant renames the whole family of overriding methods.
+        let (plan, changes) = project.rename("App\\Big::area()", "surface");
+
+        assert!(plan.refusals.is_empty());
+        assert!(plan.moved_file.is_none());
+        assert_eq!(changes.len(), 4);
+        for (name, content) in &changes {
+            assert!(!content.to_lowercase().contains("area"), "`{}` still references `area`:\n{}", name, content);
+        }
+
+        let main = changes.iter().find(|(name, _)| name == "src/main.php").unwrap();
+        assert!(main.1.contains("return $shape->surface() + $big->surface();"));
+    }
+
+    #[test]
+    fn test_rename_inherited_property_and_constant() {
+        let project = Project::new(
+            "inherited-property",
+            &[(
+                "src/main.php",
+                "<?php\n\nnamespace App;\n\nclass Base\n{\n    public const LIMIT = 1;\n\n    public int $count = 0;\n}\n\nfinal class Child extends Base\n{\n    public function check(): bool\n    {\n        return $this->count < static::LIMIT;\n    }\n}\n",
+            )],
+        );
+
+        let (plan, changes) = project.rename("App\\Child::$count", "$total");
+        assert!(plan.refusals.is_empty());
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].1.contains("public int $total = 0;"));
+        assert!(changes[0].1.contains("return $this->total < static::LIMIT;"));
+
+        let (plan, changes) = project.rename("App\\Child::LIMIT", "MAXIMUM");
+        assert!(plan.refusals.is_empty());
+        assert!(changes[0].1.contains("public const MAXIMUM = 1;"));
+        assert!(changes[0].1.contains("return $this->count < static::MAXIMUM;"));
+    }
+
+    #[test]
+    fn test_rename_class_moves_psr4_file() {
+        let project = Project::new(
+            "psr4-move",
+            &[
+                ("composer.json", COMPOSER),
+                ("src/Service/Mailer.php", "<?php\n\nnamespace App\\Service;\n\nfinal class Mailer\n{\n}\n"),
+                (
+                    "src/main.php",
+                    "<?php\n\nnamespace App;\n\nuse App\\Service\\Mailer;\n\nfunction send(Mailer $mailer): void\n{\n}\n",
+                ),
+            ],
+        );
+
+        let (plan, changes) = project.rename("App\\Service\\Mailer", "App\\Mail\\Sender");
+
+        assert!(plan.refusals.is_empty());
+        assert_eq!(
+            plan.moved_file,
+            Some((project.root.join("src/Service/Mailer.php"), project.root.join("src/Mail/Sender.php")))
+        );
+
+        let declaration = changes.iter().find(|(name, _)| name == "src/Service/Mailer.php").unwrap();
+        assert!(declaration.1.contains("namespace App\\Mail;"));
+        assert!(declaration.1.contains("final class Sender"));
+
+        let main = changes.iter().find(|(name, _)| name == "src/main.php").unwrap();
+        assert!(main.1.contains("use App\\Mail\\Sender;"));
+        assert!(main.1.contains("function send(Sender $mailer): void"));
+    }
+
+    #[test]
+    fn test_rename_class_does_not_move_file_outside_psr4() {
+        let project = Project::new(
+            "psr4-no-move",
+            &[
+                ("composer.json", COMPOSER),
+                // The file name does not match the class name, so it is not autoloaded using PSR-4.
+                ("src/helpers.php", "<?php\n\nnamespace App;\n\nfinal class Helper\n{\n}\n"),
+            ],
+        );
+
+        let (plan, changes) = project.rename("App\\Helper", "App\\Utility");
+
+        assert!(plan.refusals.is_empty());
+        assert!(plan.moved_file.is_none());
+        assert!(changes[0].1.contains("final class Utility"));
+    }
+
+    #[test]
+    fn test_rename_class_without_composer_does_not_move_file() {
+        let project = Project::new(
+            "psr4-no-composer",
+            &[("src/Mailer.php", "<?php\n\nnamespace App;\n\nfinal class Mailer\n{\n}\n")],
+        );
+
+        let (plan, _) = project.rename("App\\Mailer", "App\\Sender");
+
+        assert!(plan.refusals.is_empty());
+        assert!(plan.moved_file.is_none());
+    }
+
+    #[test]
+    fn test_rename_refuses_member_accessed_on_unknown_type() {
+        let project = Project::new(
+            "unknown-receiver",
+            &[(
+                "src/main.php",
+                "<?php\n\nnamespace App;\n\nfinal class Mailer\n{\n    public function send(): void\n    {\n    }\n}\n\nfunction notify($mailer): void\n{\n    $mailer->send();\n}\n",
+            )],
+        );
+
+        let (plan, _) = project.rename("App\\Mailer::send()", "deliver");
+
+        let messages = plan.refusals.iter().map(|issue| issue.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(messages, vec!["`App\\Mailer::send()` may be accessed on objects of unknown type."]);
+    }
+
+    #[test]
+    fn test_rename_refuses_dynamic_member_access_without_changes() {
+        let main = "<?php\n\nnamespace App;\n\nfinal class Mailer\n{\n    public function send(): void\n    {\n    }\n}\n\nfunction notify(Mailer $mailer, string $method): void\n{\n    $mailer->send();\n    $mailer->$method();\n}\n";
+        let project = Project::new("dynamic-access", &[("src/main.php", main)]);
+
+        let result = project.rename_on_disk("App\\Mailer::send()", "deliver");
+
+        let messages = result.issues.iter().map(|issue| issue.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(messages, vec!["`App\\Mailer::send()` may be accessed dynamically."]);
+        assert_eq!(result.changed, 0);
+        assert_eq!(project.read("src/main.php"), main);
+    }
+
+    #[test]
+    fn test_rename_ignores_dynamic_member_access_on_unrelated_class() {
+        let project = Project::new(
+            "dynamic-access-unrelated",
+            &[(
+                "src/main.php",
+                "<?php\n\nnamespace App;\n\nfinal class Mailer\n{\n    public function send(): void\n    {\n    }\n}\n\nfinal class Logger\n{\n}\n\nfunction notify(Mailer $mailer, Logger $logger, string $method): void\n{\n    $mailer->send();\n    $logger->$method();\n}\n",
+            )],
+        );
+
+        let result = project.rename_on_disk("App\\Mailer::send()", "deliver");
+
+        assert!(result.issues.is_empty());
+        assert_eq!(result.changed, 1);
+        assert!(project.read("src/main.php").contains("$mailer->deliver();"));
+    }
+
+    #[test]
+    fn test_rename_refuses_sources_with_parse_errors() {
+        let mailer = "<?php\n\nnamespace App;\n\nfinal class Mailer\n{\n}\n";
+        let project = Project::new(
+            "parse-error",
+            &[
+                ("src/Mailer.php", mailer),
+                ("src/broken.php", "<?php\n\nnamespace App;\n\nfunction broken(Mailer $mailer\n"),
+            ],
+        );
+
+        let (plan, _) = project.rename("App\\Mailer", "App\\Sender");
+        assert_eq!(plan.refusals.len(), 1);
+
+        let result = project.rename_on_disk("App\\Mailer", "App\\Sender");
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.changed, 0);
+        assert_eq!(project.read("src/Mailer.php"), mailer);
+    }
+
+    #[test]
+    fn test_rename_class_updates_docblocks_attributes_class_constants_and_strings() {
+        let project = Project::new(
+            "class-references",
+            &[
+                ("src/Mailer.php", "<?php\n\nnamespace App;\n\n#[\\Attribute]\nfinal class Mailer\n{\n}\n"),
+                (
+                    "src/main.php",
+                    "<?php\n\nnamespace App;\n\n/**\n * @see Mailer\n * @param class-string<Mailer> $class\n */\n#[Mailer]\nfunction send(string $class): void\n{\n    $name = Mailer::class;\n    $single = 'App\\Mailer';\n    $double = \"\\\\App\\\\Mailer\";\n}\n",
+                ),
+            ],
+        );
+
+        let (plan, changes) = project.rename("App\\Mailer", "App\\Sender");
+
+        assert!(plan.refusals.is_empty());
+        let main = &changes.iter().find(|(name, _)| name == "src/main.php").unwrap().1;
+        assert_eq!(
+            main,
+            "<?php\n\nnamespace App;\n\n/**\n * @see Sender\n * @param class-string<Sender> $class\n */\n#[Sender]\nfunction send(string $class): void\n{\n    $name = Sender::class;\n    $single = 'App\\Sender';\n    $double = \"\\\\App\\\\Sender\";\n}\n"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// The kind of symbol imported by a `use` statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    ClassLike,
+    Function,
+    Constant,
+}
+
+/// A single item of a `use` statement.
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub kind: ImportKind,
+    /// The fully qualified name of the imported symbol, without a leading backslash.
+    pub name: String,
+    /// The name the symbol is imported as, either explicitly or implicitly.
+    pub alias: String,
+    pub has_explicit_alias: bool,
+    /// The namespace prefix of a grouped `use` statement, e.g. `Foo` in `use Foo\{Bar, Baz};`.
+    pub prefix: Option<String>,
+    /// The span of the name of the item, as written in the `use` statement.
+    pub span: Span,
+}
+
+/// A namespace of a program, along with the imports declared within it.
+#[derive(Debug, Clone)]
+pub struct NamespaceScope {
+    /// The name of the namespace, empty for the global namespace.
+    pub name: String,
+    /// The span of the name of the namespace in its declaration, if any.
+    pub name_span: Option<Span>,
+    pub span: Span,
+    pub imports: Vec<Import>,
+}
+
+impl NamespaceScope {
+    /// Finds the import of the given kind with the given alias.
+    ///
+    /// Aliases of class-likes and functions are case-insensitive, while aliases of constants are not.
+    pub fn get_import(&self, kind: ImportKind, alias: &str) -> Option<&Import> {
+        self.imports.iter().find(|import| {
+            import.kind == kind
+                && match kind {
+                    ImportKind::Constant => import.alias == alias,
+                    _ => import.alias.eq_ignore_ascii_case(alias),
+                }
+        })
+    }
+
+    /// Resolves a class-like name written in this scope, e.g. within a docblock.
+    pub fn resolve_class_like_name(&self, name: &str) -> String {
+        if let Some(name) = name.strip_prefix('\\') {
+            return name.to_string();
+        }
+
+        let (first, rest) = match name.split_once('\\') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (name, None),
+        };
+
+        if let Some(import) = self.get_import(ImportKind::ClassLike, first) {
+            return match rest {
+                Some(rest) => format!("{}\\{}", import.name, rest),
+                None => import.name.clone(),
+            };
+        }
+
+        qualify(&self.name, name)
+    }
+}
+
+/// The namespaces of a program, in the order they are declared.
+#[derive(Debug, Clone)]
+pub struct ProgramScopes {
+    pub namespaces: Vec<NamespaceScope>,
+}
+
+impl ProgramScopes {
+    pub fn new(interner: &ThreadedInterner, program: &Program) -> Self {
+        let mut namespaces = vec![];
+        let mut global = NamespaceScope { name: String::new(), name_span: None, span: program.span(), imports: vec![] };
+
+        for statement in program.statements.iter() {
+            match statement {
+                Statement::Namespace(namespace) => {
+                    let mut scope = NamespaceScope {
+                        name: namespace
+                            .name
+                            .as_ref()
+                            .map(|name| interner.lookup(&name.value()).trim_start_matches('\\').to_string())
+                            .unwrap_or_default(),
+                        name_span: namespace.name.as_ref().map(|name| name.span()),
+                        span: namespace.span(),
+                        imports: vec![],
+                    };
+
+                    for statement in namespace.statements().iter() {
+                        if let Statement::Use(r#use) = statement {
+                            collect_imports(interner, r#use, &mut scope.imports);
+                        }
+                    }
+
+                    namespaces.push(scope);
+                }
+                Statement::Use(r#use) => {
+                    collect_imports(interner, r#use, &mut global.imports);
+                }
+                _ => {}
+            }
+        }
+
+        if namespaces.is_empty() {
+            namespaces.push(global);
+        }
+
+        Self { namespaces }
+    }
+
+    /// Returns the namespace the given offset belongs to.
+    pub fn get_scope_at(&self, offset: usize) -> &NamespaceScope {
+        self.namespaces
+            .iter()
+            .rev()
+            .find(|scope| scope.span.start.offset <= offset)
+            .unwrap_or_else(|| &self.namespaces[0])
+    }
+
+    /// Finds the import whose name is written at the given span.
+    pub fn get_import_at(&self, span: Span) -> Option<&Import> {
+        self.namespaces.iter().flat_map(|scope| scope.imports.iter()).find(|import| import.span == span)
+    }
+}
+
+/// Qualifies the given name with the given namespace.
+pub fn qualify(namespace: &str, name: &str) -> String {
+    if namespace.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}\\{}", namespace, name)
+    }
+}
+
+/// Splits a fully qualified name into its namespace and its short name.
+pub fn split_name(name: &str) -> (&str, &str) {
+    match name.rsplit_once('\\') {
+        Some((namespace, name)) => (namespace, name),
+        None => ("", name),
+    }
+}
+
+fn collect_imports(interner: &ThreadedInterner, r#use: &Use, imports: &mut Vec<Import>) {
+    let (prefix, items): (Option<&Identifier>, Vec<(Option<&UseType>, &UseItem)>) = match &r#use.items {
+        UseItems::Sequence(sequence) => (None, sequence.items.iter().map(|item| (None, item)).collect()),
+        UseItems::TypedSequence(sequence) => {
+            (None, sequence.items.iter().map(|item| (Some(&sequence.r#type), item)).collect())
+        }
+        UseItems::TypedList(list) => {
+            (Some(&list.namespace), list.items.iter().map(|item| (Some(&list.r#type), item)).collect())
+        }
+        UseItems::MixedList(list) => {
+            (Some(&list.namespace), list.items.iter().map(|item| (item.r#type.as_ref(), &item.item)).collect())
+        }
+    };
+
+    let prefix = prefix.map(|prefix| interner.lookup(&prefix.value()).trim_start_matches('\\').to_string());
+
+    for (r#type, item) in items {
+        let item_name = interner.lookup(&item.name.value()).trim_start_matches('\\');
+        let name = match &prefix {
+            Some(prefix) => format!("{}\\{}", prefix, item_name),
+            None => item_name.to_string(),
+        };
+
+        let (alias, has_explicit_alias) = match &item.alias {
+            Some(alias) => (interner.lookup(&alias.identifier.value).to_string(), true),
+            None => (split_name(&name).1.to_string(), false),
+        };
+
+        let kind = match r#type {
+            None => ImportKind::ClassLike,
+            Some(UseType::Function(_)) => ImportKind::Function,
+            Some(UseType::Const(_)) => ImportKind::Constant,
+        };
+
+        imports.push(Import { kind, name, alias, has_explicit_alias, prefix: prefix.clone(), span: item.name.span() });
+    }
+}
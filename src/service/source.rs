@@ -7,6 +7,7 @@ use async_walkdir::WalkDir;
 use futures::StreamExt;
 
 use mago_interner::ThreadedInterner;
+use mago_source::SourceCategory;
 use mago_source::SourceManager;
 
 use crate::config::source::SourceConfiguration;
@@ -30,31 +31,35 @@ impl SourceService {
     /// A `Result` containing the new source manager or a `SourceError` if
     /// an error occurred during the build process.
     pub async fn load(&self) -> Result<SourceManager, Error> {
-        let SourceConfiguration { root, paths, includes, excludes, extensions } = &self.configuration;
+        let SourceConfiguration { root, paths, includes, stubs, excludes, extensions } = &self.configuration;
 
         let mut starting_paths = Vec::new();
 
         if paths.is_empty() {
-            starting_paths.push((root.clone(), true));
+            starting_paths.push((root.clone(), SourceCategory::UserDefined));
         } else {
             for source in paths {
-                starting_paths.push((source.clone(), true));
+                starting_paths.push((source.clone(), SourceCategory::UserDefined));
             }
         }
 
         for include in includes {
-            starting_paths.push((include.clone(), false));
+            starting_paths.push((include.clone(), SourceCategory::External));
+        }
+
+        for stub in stubs {
+            starting_paths.push((stub.clone(), SourceCategory::BuiltIn));
         }
 
         if paths.is_empty() && includes.is_empty() {
-            starting_paths.push((root.clone(), true));
+            starting_paths.push((root.clone(), SourceCategory::UserDefined));
         }
 
         let excludes_set: HashSet<&String> = excludes.iter().collect();
         let extensions: HashSet<&String> = extensions.iter().collect();
 
         let manager = SourceManager::new(self.interner.clone());
-        for (path, user_defined) in starting_paths.into_iter() {
+        for (path, category) in starting_paths.into_iter() {
             let mut entries = WalkDir::new(path)
                 // filter out .git directories
                 .filter(|entry| async move {
@@ -73,6 +78,11 @@ impl SourceService {
                     continue;
                 }
 
+                // Stubs are often installed as dependencies, in which case they are only loaded as stubs.
+                if category != SourceCategory::BuiltIn && stubs.iter().any(|stub| path.starts_with(stub)) {
+                    continue;
+                }
+
                 if path.is_file() && is_accepted_file(&path, &extensions) {
                     let name = match path.strip_prefix(root) {
                         Ok(rel_path) => rel_path.to_path_buf(),
@@ -81,7 +91,7 @@ impl SourceService {
 
                     let name_str = name.to_string_lossy().to_string();
 
-                    manager.insert_path(name_str, path.clone(), user_defined);
+                    manager.insert_path(name_str, path.clone(), category);
                 }
             }
         }